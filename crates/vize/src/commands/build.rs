@@ -1,10 +1,16 @@
 //! Build command - Compile Vue SFC files
 
-use clap::{Args, ValueEnum};
+use super::progress::{self, Progress};
+use crate::config::{
+    self, BuildConfig, BuildOverride, OutputFormat, ScriptExtension, SourceMapMode,
+};
+use clap::Args;
+use glob::Pattern;
 use ignore::Walk;
 use rayon::prelude::*;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
@@ -13,56 +19,68 @@ use vize_atelier_sfc::{
     StyleCompileOptions, TemplateCompileOptions,
 };
 
-#[derive(Debug, Clone, Copy, ValueEnum, Default)]
-pub enum OutputFormat {
-    /// Output compiled JavaScript
-    #[default]
-    Js,
-    /// Output JSON with code and metadata
-    Json,
-    /// Only show statistics (no output)
-    Stats,
-}
-
-#[derive(Debug, Clone, Copy, ValueEnum, Default)]
-pub enum ScriptExtension {
-    /// Preserve original script language extension (.ts -> .ts, .tsx -> .tsx, .jsx -> .jsx)
-    Preserve,
-    /// Downcompile all scripts to JavaScript (.ts -> .js, .tsx -> .js, .jsx -> .js)
-    #[default]
-    Downcompile,
-}
-
 #[derive(Args, Default)]
 pub struct BuildArgs {
-    /// Glob pattern(s) to match .vue files (default: ./**/*.vue)
-    #[arg(default_value = "./**/*.vue")]
+    /// Glob pattern(s) to match .vue files (default: ./**/*.vue), or `-` to read from stdin
     pub patterns: Vec<String>,
 
+    /// Read a single SFC from stdin and write the result to stdout
+    #[arg(long)]
+    pub stdin: bool,
+
+    /// File name to use for stdin input (scope ids, error messages)
+    #[arg(long, default_value = "anonymous.vue")]
+    pub filename: String,
+
+    /// Glob pattern of files to skip; repeatable (node_modules is always skipped)
+    #[arg(long = "exclude", value_name = "GLOB")]
+    pub exclude: Vec<String>,
+
     /// Output directory (default: ./dist)
-    #[arg(short, long, default_value = "./dist")]
-    pub output: PathBuf,
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Write every output directly into the output directory, named after its
+    /// relative path (src/components/Foo.vue -> src_components_Foo.js)
+    #[arg(long)]
+    pub flatten: bool,
+
+    /// Output format (default: js)
+    #[arg(short, long, value_enum)]
+    pub format: Option<OutputFormat>,
 
-    /// Output format
-    #[arg(short, long, value_enum, default_value = "js")]
-    pub format: OutputFormat,
+    /// Enable SSR mode (`--ssr=false` turns off SSR enabled in the config)
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub ssr: Option<bool>,
 
-    /// Enable SSR mode
+    /// Compile templates with the Vapor compiler
     #[arg(long)]
-    pub ssr: bool,
+    pub vapor: bool,
 
-    /// Script extension handling: 'preserve' keeps original extension (.ts/.tsx/.jsx), 'downcompile' converts to .js
-    #[arg(long, value_enum, default_value = "downcompile")]
-    pub script_ext: ScriptExtension,
+    /// Script extension handling: 'preserve' keeps original extension (.ts/.tsx/.jsx), 'downcompile' converts to .js (default: downcompile)
+    #[arg(long, value_enum)]
+    pub script_ext: Option<ScriptExtension>,
+
+    /// Source map output for JavaScript files (default: none)
+    #[arg(long, value_enum)]
+    pub sourcemap: Option<SourceMapMode>,
 
     /// Number of threads (default: number of CPUs)
     #[arg(short = 'j', long)]
     pub threads: Option<usize>,
 
+    /// Directory containing vize.config.json / vize.config.toml (default: CWD)
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+
     /// Show timing profile breakdown
     #[arg(long)]
     pub profile: bool,
 
+    /// Show a live progress bar (periodic lines when stderr is not a terminal)
+    #[arg(long)]
+    pub progress: bool,
+
     /// Slow file threshold in milliseconds (default: 100)
     #[arg(long, default_value = "100")]
     pub slow_threshold: u64,
@@ -70,6 +88,147 @@ pub struct BuildArgs {
     /// Continue on errors (collect all errors and show at end)
     #[arg(long)]
     pub continue_on_error: bool,
+
+    /// Write a JSON summary (counts, sizes, per-file timings) to this path
+    #[arg(long)]
+    pub report: Option<PathBuf>,
+
+    /// Maximum number of warnings before failing (default: no limit)
+    #[arg(long)]
+    pub max_warnings: Option<usize>,
+}
+
+/// Exclude globs applied to every build, before config and CLI excludes.
+const DEFAULT_EXCLUDE: &[&str] = &["**/node_modules/**"];
+
+/// Build options after merging CLI flags over the `build` config section.
+#[derive(Debug)]
+struct BuildOptions {
+    patterns: Vec<String>,
+    exclude: Vec<Pattern>,
+    output: PathBuf,
+    flatten: bool,
+    format: OutputFormat,
+    ssr: bool,
+    vapor: bool,
+    script_ext: ScriptExtension,
+    sourcemap: SourceMapMode,
+    threads: Option<usize>,
+    overrides: Vec<(Vec<Pattern>, BuildOverride)>,
+    /// Values given explicitly on the command line, which beat overrides.
+    cli_ssr: Option<bool>,
+    cli_script_ext: Option<ScriptExtension>,
+}
+
+impl BuildOptions {
+    /// Resolve options: built-in defaults, then config, then CLI flags.
+    fn resolve(args: &BuildArgs, config: &BuildConfig) -> Result<Self, String> {
+        let patterns = if !args.patterns.is_empty() {
+            args.patterns.clone()
+        } else {
+            config
+                .include
+                .clone()
+                .filter(|include| !include.is_empty())
+                .unwrap_or_else(|| vec!["./**/*.vue".to_string()])
+        };
+
+        let overrides = config
+            .overrides
+            .iter()
+            .map(|o| Ok((compile_globs(&o.files)?, o.clone())))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let exclude: Vec<String> = DEFAULT_EXCLUDE
+            .iter()
+            .map(|p| p.to_string())
+            .chain(config.exclude.iter().cloned())
+            .chain(args.exclude.iter().cloned())
+            .collect();
+
+        Ok(Self {
+            patterns,
+            exclude: compile_globs(&exclude)?,
+            output: args
+                .output
+                .clone()
+                .or_else(|| config.output.clone())
+                .unwrap_or_else(|| PathBuf::from("./dist")),
+            flatten: args.flatten,
+            format: args.format.or(config.format).unwrap_or_default(),
+            ssr: args.ssr.or(config.ssr).unwrap_or(false),
+            vapor: args.vapor || config.vapor.unwrap_or(false),
+            script_ext: args.script_ext.or(config.script_ext).unwrap_or_default(),
+            sourcemap: args.sourcemap.or(config.sourcemap).unwrap_or_default(),
+            threads: args.threads.or(config.threads),
+            overrides,
+            cli_ssr: args.ssr,
+            cli_script_ext: args.script_ext,
+        })
+    }
+
+    fn is_excluded(&self, path: &Path) -> bool {
+        let path = normalize_glob_path(path);
+        self.exclude.iter().any(|p| p.matches(&path))
+    }
+
+    /// Compile options for a single file, after applying overrides.
+    ///
+    /// Explicit CLI flags still win over per-glob overrides.
+    fn file_options(&self, path: &Path) -> FileOptions {
+        let normalized = normalize_glob_path(path);
+        let mut ssr = self.ssr;
+        let mut script_ext = self.script_ext;
+
+        for (globs, o) in &self.overrides {
+            if globs.iter().any(|p| p.matches(&normalized)) {
+                if let Some(v) = o.ssr {
+                    ssr = v;
+                }
+                if let Some(v) = o.script_ext {
+                    script_ext = v;
+                }
+            }
+        }
+
+        if let Some(v) = self.cli_ssr {
+            ssr = v;
+        }
+        if let Some(v) = self.cli_script_ext {
+            script_ext = v;
+        }
+
+        FileOptions {
+            ssr,
+            vapor: self.vapor,
+            script_ext,
+            with_map: self.sourcemap != SourceMapMode::None,
+        }
+    }
+}
+
+/// Per-file compile options resolved from [`BuildOptions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileOptions {
+    ssr: bool,
+    vapor: bool,
+    script_ext: ScriptExtension,
+    with_map: bool,
+}
+
+fn compile_globs(patterns: &[String]) -> Result<Vec<Pattern>, String> {
+    patterns
+        .iter()
+        .map(|p| {
+            Pattern::new(p.trim_start_matches("./"))
+                .map_err(|e| format!("invalid glob pattern '{}': {}", p, e))
+        })
+        .collect()
+}
+
+fn normalize_glob_path(path: &Path) -> String {
+    let path_str = path.to_string_lossy().replace('\\', "/");
+    path_str.trim_start_matches("./").to_string()
 }
 
 #[derive(Debug)]
@@ -77,6 +236,8 @@ struct CompileStats {
     total_files: usize,
     success: AtomicUsize,
     failed: AtomicUsize,
+    warnings: AtomicUsize,
+    vapor: AtomicUsize,
     total_bytes: AtomicUsize,
     output_bytes: AtomicUsize,
     total_parse_time: Mutex<Duration>,
@@ -89,6 +250,8 @@ impl CompileStats {
             total_files,
             success: AtomicUsize::new(0),
             failed: AtomicUsize::new(0),
+            warnings: AtomicUsize::new(0),
+            vapor: AtomicUsize::new(0),
             total_bytes: AtomicUsize::new(0),
             output_bytes: AtomicUsize::new(0),
             total_parse_time: Mutex::new(Duration::ZERO),
@@ -109,6 +272,11 @@ impl CompileStats {
     }
 }
 
+/// Whether `warnings` is over the `--max-warnings` threshold (`None` never fails).
+fn exceeds_max_warnings(warnings: usize, max: Option<usize>) -> bool {
+    max.is_some_and(|max| warnings > max)
+}
+
 #[derive(Debug, serde::Serialize)]
 struct CompileOutput {
     filename: String,
@@ -117,6 +285,8 @@ struct CompileOutput {
     errors: Vec<String>,
     warnings: Vec<String>,
     script_lang: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    map: Option<serde_json::Value>,
 }
 
 /// Detailed timing information for a single file
@@ -172,6 +342,80 @@ impl FileProfile {
     }
 }
 
+/// Machine-readable build summary written by `--report`
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BuildReport {
+    total_files: usize,
+    success: usize,
+    failed: usize,
+    input_bytes: usize,
+    output_bytes: usize,
+    wall_time_ms: f64,
+    files: Vec<FileReport>,
+}
+
+impl BuildReport {
+    fn new(mut files: Vec<FileReport>, wall_time: Duration) -> Self {
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        let success = files.iter().filter(|f| f.success).count();
+        Self {
+            total_files: files.len(),
+            success,
+            failed: files.len() - success,
+            input_bytes: files.iter().map(|f| f.input_bytes).sum(),
+            output_bytes: files.iter().map(|f| f.output_bytes).sum(),
+            wall_time_ms: wall_time.as_secs_f64() * 1000.0,
+            files,
+        }
+    }
+
+    fn write(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        fs::write(path, json)
+    }
+}
+
+/// Per-file entry of a [`BuildReport`]
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FileReport {
+    path: String,
+    success: bool,
+    duration_ms: f64,
+    input_bytes: usize,
+    output_bytes: usize,
+    errors: usize,
+    warnings: usize,
+}
+
+impl FileReport {
+    fn new(
+        path: &Path,
+        input_bytes: usize,
+        duration: Duration,
+        result: &Result<(CompileOutput, FileProfile), CompileError>,
+    ) -> Self {
+        let (output_bytes, errors, warnings) = match result {
+            Ok((output, _)) => (
+                output.code.len(),
+                output.errors.len(),
+                output.warnings.len(),
+            ),
+            Err(_) => (0, 1, 0),
+        };
+        Self {
+            path: normalize_glob_path(path),
+            success: result.is_ok(),
+            duration_ms: duration.as_secs_f64() * 1000.0,
+            input_bytes,
+            output_bytes,
+            errors,
+            warnings,
+        }
+    }
+}
+
 /// Collected error information
 #[derive(Debug, Clone)]
 struct CompileError {
@@ -201,20 +445,52 @@ pub fn run(args: BuildArgs) {
     let start = Instant::now();
     let slow_threshold = Duration::from_millis(args.slow_threshold);
 
-    if let Some(threads) = args.threads {
+    let config = match config::try_load_config(args.config.as_deref()) {
+        Ok(config) => config.build,
+        Err(e) => {
+            eprintln!("\x1b[31mError:\x1b[0m Invalid config file {}", e);
+            std::process::exit(1);
+        }
+    };
+    let options = match BuildOptions::resolve(&args, &config) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("\x1b[31mError:\x1b[0m Invalid build config: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if args.stdin || args.patterns.iter().any(|p| p == "-") {
+        let stdin = std::io::stdin();
+        let stdout = std::io::stdout();
+        if let Err(e) = compile_stdin(stdin.lock(), stdout.lock(), &args.filename, &options) {
+            eprintln!("\x1b[31mError:\x1b[0m {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(threads) = options.threads {
         rayon::ThreadPoolBuilder::new()
             .num_threads(threads)
             .build_global()
             .expect("Failed to configure thread pool");
     }
 
-    let files = collect_files(&args.patterns);
+    let files = collect_files(&options.patterns, |path| options.is_excluded(path));
 
     if files.is_empty() {
         eprintln!("No .vue files found matching the patterns");
         std::process::exit(1);
     }
 
+    if options.flatten && options.format != OutputFormat::Stats {
+        if let Err(e) = check_flattened_names(&files) {
+            eprintln!("\x1b[31mError:\x1b[0m {}", e);
+            std::process::exit(1);
+        }
+    }
+
     let stats = CompileStats::new(files.len());
     let collect_elapsed = start.elapsed();
 
@@ -232,6 +508,15 @@ pub fn run(args: BuildArgs) {
     let errors: Mutex<Vec<CompileError>> = Mutex::new(Vec::new());
     let slow_files: Mutex<Vec<FileProfile>> = Mutex::new(Vec::new());
     let profiles: Mutex<Vec<FileProfile>> = Mutex::new(Vec::new());
+    let file_reports: Mutex<Vec<FileReport>> = Mutex::new(Vec::new());
+
+    let progress = args.progress.then(|| {
+        Progress::start(
+            files.len(),
+            progress::stderr_renderer(),
+            Duration::from_millis(100),
+        )
+    });
 
     let compile_start = Instant::now();
     let results: Vec<_> = files
@@ -240,9 +525,25 @@ pub fn run(args: BuildArgs) {
             let source_size = fs::metadata(path).map(|m| m.len() as usize).unwrap_or(0);
             stats.total_bytes.fetch_add(source_size, Ordering::Relaxed);
 
-            match compile_file_with_profile(path, args.ssr, args.script_ext, &stats) {
+            let file_options = options.file_options(path);
+            let file_start = Instant::now();
+            let result = compile_file_with_profile(path, file_options, &stats);
+            if let Some(progress) = &progress {
+                progress.inc();
+            }
+            if args.report.is_some() {
+                let report = FileReport::new(path, source_size, file_start.elapsed(), &result);
+                if let Ok(mut reports) = file_reports.lock() {
+                    reports.push(report);
+                }
+            }
+
+            match result {
                 Ok((output, profile)) => {
                     stats.success.fetch_add(1, Ordering::Relaxed);
+                    stats
+                        .warnings
+                        .fetch_add(output.warnings.len(), Ordering::Relaxed);
                     stats
                         .output_bytes
                         .fetch_add(output.code.len(), Ordering::Relaxed);
@@ -260,7 +561,7 @@ pub fn run(args: BuildArgs) {
                         }
                     }
 
-                    Some((path.clone(), output))
+                    Some((path.clone(), output, file_options.script_ext))
                 }
                 Err(err) => {
                     stats.failed.fetch_add(1, Ordering::Relaxed);
@@ -276,36 +577,28 @@ pub fn run(args: BuildArgs) {
         .collect();
     let compile_elapsed = compile_start.elapsed();
 
+    // Clear the bar before anything else is printed
+    if let Some(progress) = progress {
+        progress.finish();
+    }
+
     let io_start = Instant::now();
-    match args.format {
+    match options.format {
         OutputFormat::Stats => {}
         OutputFormat::Js | OutputFormat::Json => {
-            fs::create_dir_all(&args.output).expect("Failed to create output directory");
-
-            for (path, output) in results.into_iter().flatten() {
-                let ext = match args.format {
-                    OutputFormat::Js => get_output_extension(&output.script_lang, args.script_ext),
-                    OutputFormat::Json => "json",
-                    OutputFormat::Stats => unreachable!(),
-                };
-
-                let filename = path
-                    .file_name()
-                    .map(|f| PathBuf::from(f).with_extension(ext))
-                    .unwrap_or_else(|| PathBuf::from("output").with_extension(ext));
-                let out_path = args.output.join(filename);
-
-                if let Some(parent) = out_path.parent() {
-                    fs::create_dir_all(parent).expect("Failed to create output subdirectory");
-                }
-
-                let content = match args.format {
-                    OutputFormat::Js => output.code,
-                    OutputFormat::Json => serde_json::to_string_pretty(&output).unwrap_or_default(),
-                    OutputFormat::Stats => unreachable!(),
-                };
-
-                fs::write(&out_path, content).unwrap_or_else(|e| {
+            fs::create_dir_all(&options.output).expect("Failed to create output directory");
+
+            for (path, output, script_ext) in results.into_iter().flatten() {
+                write_output(
+                    &options.output,
+                    &path,
+                    output,
+                    options.format,
+                    script_ext,
+                    options.sourcemap,
+                    options.flatten,
+                )
+                .unwrap_or_else(|(out_path, e)| {
                     eprintln!("Failed to write {}: {}", out_path.display(), e);
                 });
             }
@@ -316,6 +609,7 @@ pub fn run(args: BuildArgs) {
     let total_elapsed = start.elapsed();
     let success = stats.success.load(Ordering::Relaxed);
     let failed = stats.failed.load(Ordering::Relaxed);
+    let warnings = stats.warnings.load(Ordering::Relaxed);
 
     // Show slow file warnings
     let slow_files = slow_files.into_inner().unwrap_or_default();
@@ -459,6 +753,7 @@ pub fn run(args: BuildArgs) {
         let output_bytes = stats.output_bytes.load(Ordering::Relaxed);
         eprintln!("Statistics:");
         eprintln!("  Files processed: {}/{}", success, stats.total_files);
+        eprintln!("  Vapor compiled:  {}", stats.vapor.load(Ordering::Relaxed));
         eprintln!(
             "  Input size:  {} bytes ({:.2} KB)",
             total_bytes,
@@ -478,27 +773,267 @@ pub fn run(args: BuildArgs) {
         eprintln!();
     }
 
+    if let Some(report_path) = &args.report {
+        let report = BuildReport::new(
+            file_reports.into_inner().unwrap_or_default(),
+            start.elapsed(),
+        );
+        if let Err(e) = report.write(report_path) {
+            eprintln!(
+                "\x1b[31mError:\x1b[0m Failed to write report {}: {}",
+                report_path.display(),
+                e
+            );
+        }
+    }
+
     // Final summary
+    let warning_note = if warnings > 0 {
+        format!(", {} warning(s)", warnings)
+    } else {
+        String::new()
+    };
     if failed > 0 {
         eprintln!(
-            "\x1b[31m✗ {} file(s) failed\x1b[0m, {} compiled in {:.4}s",
+            "\x1b[31m✗ {} file(s) failed\x1b[0m, {} compiled{} in {:.4}s",
             failed,
             success,
+            warning_note,
             total_elapsed.as_secs_f64()
         );
         std::process::exit(1);
     } else {
         let file_word = if success == 1 { "file" } else { "files" };
+        let vapor = stats.vapor.load(Ordering::Relaxed);
+        let vapor_note = if vapor > 0 {
+            format!(" ({} Vapor)", vapor)
+        } else {
+            String::new()
+        };
         eprintln!(
-            "\x1b[32m✓ {} {} compiled in {:.4}s\x1b[0m",
+            "\x1b[32m✓ {} {} compiled{}{} in {:.4}s\x1b[0m",
             success,
             file_word,
+            vapor_note,
+            warning_note,
             total_elapsed.as_secs_f64()
         );
     }
+
+    if exceeds_max_warnings(warnings, args.max_warnings) {
+        eprintln!(
+            "\nToo many warnings ({} > max {})",
+            warnings,
+            args.max_warnings.unwrap_or_default()
+        );
+        std::process::exit(1);
+    }
+}
+
+/// Compile one SFC read from `reader` and write the result to `writer`.
+///
+/// `--format js` writes the code (with an inline source map when requested),
+/// `--format json` writes the full `CompileOutput`, and `--format stats` writes nothing.
+fn compile_stdin(
+    mut reader: impl Read,
+    mut writer: impl Write,
+    filename: &str,
+    options: &BuildOptions,
+) -> Result<(), String> {
+    let mut source = String::new();
+    reader
+        .read_to_string(&mut source)
+        .map_err(|e| format!("Failed to read stdin: {}", e))?;
+
+    let path = PathBuf::from(filename);
+    let file_options = FileOptions {
+        with_map: options.sourcemap == SourceMapMode::Inline,
+        ..options.file_options(&path)
+    };
+    let stats = CompileStats::new(1);
+    let (mut output, _) = compile_source(&path, &source, Instant::now(), file_options, &stats)
+        .map_err(|e| format!("{} ({} error): {}", e.path.display(), e.phase, e.error))?;
+
+    let content = match options.format {
+        OutputFormat::Js => {
+            let mut code = std::mem::take(&mut output.code);
+            if let Some(map) = output.map.take() {
+                if !code.ends_with('\n') {
+                    code.push('\n');
+                }
+                let map_json = serde_json::to_string(&map).unwrap_or_default();
+                code.push_str(&inline_sourcemap_comment(&map_json));
+            }
+            code
+        }
+        OutputFormat::Json => serde_json::to_string_pretty(&output).unwrap_or_default(),
+        OutputFormat::Stats => return Ok(()),
+    };
+
+    writer
+        .write_all(content.as_bytes())
+        .and_then(|_| writer.flush())
+        .map_err(|e| format!("Failed to write stdout: {}", e))
+}
+
+/// Write a compiled file (and its source map, if requested) into `out_dir`.
+fn write_output(
+    out_dir: &Path,
+    path: &Path,
+    mut output: CompileOutput,
+    format: OutputFormat,
+    script_ext: ScriptExtension,
+    sourcemap: SourceMapMode,
+    flatten: bool,
+) -> Result<PathBuf, (PathBuf, std::io::Error)> {
+    let ext = match format {
+        OutputFormat::Js => get_output_extension(&output.script_lang, script_ext),
+        OutputFormat::Json => "json",
+        OutputFormat::Stats => unreachable!(),
+    };
+
+    let out_path = out_dir.join(output_name(path, flatten).with_extension(ext));
+
+    if let Some(parent) = out_path.parent() {
+        fs::create_dir_all(parent).expect("Failed to create output subdirectory");
+    }
+
+    let content = match format {
+        OutputFormat::Js => {
+            let map = output
+                .map
+                .take()
+                .filter(|_| sourcemap != SourceMapMode::None);
+            let mut code = output.code;
+            if let Some(map) = map {
+                let map_json = serde_json::to_string(&map).unwrap_or_default();
+                if !code.ends_with('\n') {
+                    code.push('\n');
+                }
+                match sourcemap {
+                    SourceMapMode::External => {
+                        let mut map_path = out_path.clone().into_os_string();
+                        map_path.push(".map");
+                        let map_path = PathBuf::from(map_path);
+                        fs::write(&map_path, map_json).map_err(|e| (map_path.clone(), e))?;
+                        let map_name = map_path
+                            .file_name()
+                            .map(|n| n.to_string_lossy().into_owned())
+                            .unwrap_or_default();
+                        code.push_str(&format!("//# sourceMappingURL={}\n", map_name));
+                    }
+                    SourceMapMode::Inline => {
+                        code.push_str(&inline_sourcemap_comment(&map_json));
+                    }
+                    SourceMapMode::None => {}
+                }
+            }
+            code
+        }
+        OutputFormat::Json => serde_json::to_string_pretty(&output).unwrap_or_default(),
+        OutputFormat::Stats => unreachable!(),
+    };
+
+    fs::write(&out_path, content).map_err(|e| (out_path.clone(), e))?;
+    Ok(out_path)
 }
 
-fn collect_files(patterns: &[String]) -> Vec<PathBuf> {
+/// Output path of `path` relative to the output directory.
+///
+/// Mirrors the path relative to the working directory; files outside of it
+/// keep only their name. `flatten` joins the components with `_` instead.
+fn output_name(path: &Path, flatten: bool) -> PathBuf {
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path);
+    let components: Vec<_> = if relative.is_absolute() {
+        relative.file_name().into_iter().collect()
+    } else {
+        relative
+            .components()
+            .filter_map(|c| match c {
+                Component::Normal(part) => Some(part),
+                _ => None,
+            })
+            .collect()
+    };
+
+    if components.is_empty() {
+        return PathBuf::from("output.vue");
+    }
+    if flatten {
+        let parts: Vec<_> = components.iter().map(|c| c.to_string_lossy()).collect();
+        PathBuf::from(parts.join("_"))
+    } else {
+        components.iter().collect()
+    }
+}
+
+/// Fail if two files would be written to the same flattened name.
+fn check_flattened_names(files: &[PathBuf]) -> Result<(), String> {
+    let mut seen: std::collections::HashMap<PathBuf, &PathBuf> = std::collections::HashMap::new();
+    for path in files {
+        let name = output_name(path, true).with_extension("");
+        if let Some(other) = seen.insert(name.clone(), path) {
+            return Err(format!(
+                "{} and {} both flatten to {}",
+                other.display(),
+                path.display(),
+                name.display()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Source map for compilers that don't produce one: references the original
+/// file and its content, without mappings.
+fn source_only_map(filename: &str, source: &str) -> serde_json::Value {
+    serde_json::json!({
+        "version": 3,
+        "sources": [filename],
+        "sourcesContent": [source],
+        "names": [],
+        "mappings": "",
+    })
+}
+
+fn inline_sourcemap_comment(map_json: &str) -> String {
+    format!(
+        "//# sourceMappingURL=data:application/json;charset=utf-8;base64,{}\n",
+        base64_encode(map_json.as_bytes())
+    )
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        out.push(TABLE[(n >> 18) as usize & 63] as char);
+        out.push(TABLE[(n >> 12) as usize & 63] as char);
+        out.push(if chunk.len() > 1 {
+            TABLE[(n >> 6) as usize & 63] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            TABLE[n as usize & 63] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
+
+/// Collect `.vue` files matching the include patterns, minus excluded ones.
+fn collect_files(patterns: &[String], is_excluded: impl Fn(&Path) -> bool) -> Vec<PathBuf> {
     let mut files = Vec::new();
 
     for pattern in patterns {
@@ -515,6 +1050,7 @@ fn collect_files(patterns: &[String]) -> Vec<PathBuf> {
         }
     }
 
+    files.retain(|path| !is_excluded(path));
     files.sort();
     files.dedup();
     files
@@ -590,8 +1126,7 @@ fn detect_script_lang(source: &str) -> String {
 
 fn compile_file_with_profile(
     path: &PathBuf,
-    ssr: bool,
-    script_ext: ScriptExtension,
+    options: FileOptions,
     stats: &CompileStats,
 ) -> Result<(CompileOutput, FileProfile), CompileError> {
     let file_start = Instant::now();
@@ -603,6 +1138,18 @@ fn compile_file_with_profile(
         phase: ErrorPhase::Read,
     })?;
 
+    compile_source(path, &source, file_start, options, stats)
+}
+
+/// Compile SFC source already in memory. `path` names the file in the output,
+/// scope ids and error messages.
+fn compile_source(
+    path: &PathBuf,
+    source: &str,
+    file_start: Instant,
+    options: FileOptions,
+    stats: &CompileStats,
+) -> Result<(CompileOutput, FileProfile), CompileError> {
     let file_size = source.len();
 
     let filename = path
@@ -611,7 +1158,7 @@ fn compile_file_with_profile(
         .unwrap_or("anonymous.vue")
         .to_string();
 
-    let script_lang = detect_script_lang(&source);
+    let script_lang = detect_script_lang(source);
 
     // Parse
     let parse_start = Instant::now();
@@ -620,7 +1167,7 @@ fn compile_file_with_profile(
         ..Default::default()
     };
 
-    let descriptor = parse_sfc(&source, parse_opts).map_err(|e| CompileError {
+    let descriptor = parse_sfc(source, parse_opts).map_err(|e| CompileError {
         path: path.clone(),
         error: e.message,
         phase: ErrorPhase::Parse,
@@ -649,7 +1196,13 @@ fn compile_file_with_profile(
     // Compile
     let compile_start = Instant::now();
     let has_scoped = descriptor.styles.iter().any(|s| s.scoped);
-    let is_ts = matches!(script_ext, ScriptExtension::Preserve);
+    let is_ts = matches!(options.script_ext, ScriptExtension::Preserve);
+    let is_vapor = options.vapor
+        || descriptor
+            .script_setup
+            .iter()
+            .chain(descriptor.script.iter())
+            .any(|s| s.attrs.contains_key("vapor"));
     let compile_opts = SfcCompileOptions {
         parse: SfcParseOptions {
             filename: filename.clone(),
@@ -663,8 +1216,9 @@ fn compile_file_with_profile(
         template: TemplateCompileOptions {
             id: Some(filename.clone()),
             scoped: has_scoped,
-            ssr,
+            ssr: options.ssr,
             is_ts,
+            vapor: options.vapor,
             ..Default::default()
         },
         style: StyleCompileOptions {
@@ -672,6 +1226,7 @@ fn compile_file_with_profile(
             scoped: has_scoped,
             ..Default::default()
         },
+        ..Default::default()
     };
 
     let result = compile_sfc(&descriptor, compile_opts).map_err(|e| CompileError {
@@ -695,6 +1250,18 @@ fn compile_file_with_profile(
         style_count,
     };
 
+    if is_vapor {
+        stats.vapor.fetch_add(1, Ordering::Relaxed);
+    }
+
+    let map = if options.with_map {
+        result
+            .map
+            .or_else(|| Some(source_only_map(&filename, source)))
+    } else {
+        None
+    };
+
     let output = CompileOutput {
         filename,
         code: result.code,
@@ -702,6 +1269,7 @@ fn compile_file_with_profile(
         errors: result.errors.into_iter().map(|e| e.message).collect(),
         warnings: result.warnings.into_iter().map(|e| e.message).collect(),
         script_lang,
+        map,
     };
 
     Ok((output, profile))
@@ -718,3 +1286,312 @@ fn get_output_extension(script_lang: &str, script_ext: ScriptExtension) -> &'sta
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> BuildConfig {
+        BuildConfig {
+            include: Some(vec!["src/**/*.vue".to_string()]),
+            exclude: vec!["src/legacy/**".to_string()],
+            output: Some(PathBuf::from("out")),
+            format: Some(OutputFormat::Json),
+            ssr: Some(true),
+            vapor: None,
+            script_ext: Some(ScriptExtension::Preserve),
+            sourcemap: None,
+            threads: Some(2),
+            overrides: vec![BuildOverride {
+                files: vec!["src/client/**".to_string()],
+                ssr: Some(false),
+                script_ext: None,
+            }],
+        }
+    }
+
+    fn file_options(with_map: bool) -> FileOptions {
+        FileOptions {
+            ssr: false,
+            vapor: false,
+            script_ext: ScriptExtension::Downcompile,
+            with_map,
+        }
+    }
+
+    #[test]
+    fn resolve_uses_defaults_without_config() {
+        let options =
+            BuildOptions::resolve(&BuildArgs::default(), &BuildConfig::default()).unwrap();
+        assert_eq!(options.patterns, vec!["./**/*.vue"]);
+        assert_eq!(options.output, PathBuf::from("./dist"));
+        assert_eq!(options.format, OutputFormat::Js);
+        assert!(!options.ssr);
+        assert_eq!(options.script_ext, ScriptExtension::Downcompile);
+        assert_eq!(options.threads, None);
+    }
+
+    #[test]
+    fn resolve_uses_config_values() {
+        let options = BuildOptions::resolve(&BuildArgs::default(), &config()).unwrap();
+        assert_eq!(options.patterns, vec!["src/**/*.vue"]);
+        assert_eq!(options.output, PathBuf::from("out"));
+        assert_eq!(options.format, OutputFormat::Json);
+        assert!(options.ssr);
+        assert_eq!(options.script_ext, ScriptExtension::Preserve);
+        assert_eq!(options.threads, Some(2));
+    }
+
+    #[test]
+    fn cli_flags_override_config() {
+        let args = BuildArgs {
+            patterns: vec!["app/**/*.vue".to_string()],
+            output: Some(PathBuf::from("build")),
+            format: Some(OutputFormat::Stats),
+            script_ext: Some(ScriptExtension::Downcompile),
+            threads: Some(8),
+            ..Default::default()
+        };
+        let options = BuildOptions::resolve(&args, &config()).unwrap();
+        assert_eq!(options.patterns, vec!["app/**/*.vue"]);
+        assert_eq!(options.output, PathBuf::from("build"));
+        assert_eq!(options.format, OutputFormat::Stats);
+        assert_eq!(options.script_ext, ScriptExtension::Downcompile);
+        assert_eq!(options.threads, Some(8));
+    }
+
+    #[test]
+    fn overrides_apply_per_file() {
+        let options = BuildOptions::resolve(&BuildArgs::default(), &config()).unwrap();
+        let server = options.file_options(Path::new("./src/server/App.vue"));
+        assert!(server.ssr);
+        assert_eq!(server.script_ext, ScriptExtension::Preserve);
+        let client = options.file_options(Path::new("./src/client/App.vue"));
+        assert!(!client.ssr);
+        assert_eq!(client.script_ext, ScriptExtension::Preserve);
+    }
+
+    #[test]
+    fn cli_ssr_flag_beats_overrides() {
+        let args = BuildArgs {
+            ssr: Some(true),
+            ..Default::default()
+        };
+        let options = BuildOptions::resolve(&args, &config()).unwrap();
+        assert!(options.file_options(Path::new("src/client/App.vue")).ssr);
+    }
+
+    #[test]
+    fn cli_ssr_false_beats_config() {
+        let args = BuildArgs {
+            ssr: Some(false),
+            ..Default::default()
+        };
+        let options = BuildOptions::resolve(&args, &config()).unwrap();
+        assert!(!options.ssr);
+        assert!(!options.file_options(Path::new("src/server/App.vue")).ssr);
+    }
+
+    #[test]
+    fn exclude_globs_filter_files() {
+        let options = BuildOptions::resolve(&BuildArgs::default(), &config()).unwrap();
+        assert!(options.is_excluded(Path::new("./src/legacy/Old.vue")));
+        assert!(!options.is_excluded(Path::new("./src/App.vue")));
+    }
+
+    #[test]
+    fn cli_exclude_omits_subtrees() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in [
+            "src/App.vue",
+            "src/generated/Api.vue",
+            "node_modules/pkg/Lib.vue",
+        ] {
+            let path = dir.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "<template><div /></template>").unwrap();
+        }
+
+        let args = BuildArgs {
+            exclude: vec!["**/generated/**".to_string()],
+            ..Default::default()
+        };
+        let options = BuildOptions::resolve(&args, &BuildConfig::default()).unwrap();
+        let pattern = format!("{}/**/*.vue", dir.path().display());
+        let files = collect_files(&[pattern], |path| options.is_excluded(path));
+
+        assert_eq!(files, vec![dir.path().join("src/App.vue")]);
+    }
+
+    #[test]
+    fn resolve_rejects_invalid_glob() {
+        let config = BuildConfig {
+            exclude: vec!["src/[".to_string()],
+            ..Default::default()
+        };
+        let err = BuildOptions::resolve(&BuildArgs::default(), &config).unwrap_err();
+        assert!(err.contains("src/["));
+    }
+
+    #[test]
+    fn external_sourcemap_writes_map_sibling() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("Foo.vue");
+        fs::write(
+            &src,
+            "<script setup>\nconst msg = 'hi'\n</script>\n<template><p>{{ msg }}</p></template>\n",
+        )
+        .unwrap();
+
+        let stats = CompileStats::new(1);
+        let (output, _) = compile_file_with_profile(&src, file_options(true), &stats).unwrap();
+        let out_dir = dir.path().join("dist");
+        let out_path = write_output(
+            &out_dir,
+            &src,
+            output,
+            OutputFormat::Js,
+            ScriptExtension::Downcompile,
+            SourceMapMode::External,
+            false,
+        )
+        .unwrap();
+
+        let map_path = out_dir.join("Foo.js.map");
+        assert!(map_path.exists());
+        let map: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&map_path).unwrap()).unwrap();
+        assert_eq!(map["version"], 3);
+        let code = fs::read_to_string(out_path).unwrap();
+        assert!(code.ends_with("//# sourceMappingURL=Foo.js.map\n"));
+    }
+
+    #[test]
+    fn flatten_names_outputs_by_relative_path() {
+        let path = Path::new("./src/components/Foo.vue");
+        assert_eq!(
+            output_name(path, false),
+            PathBuf::from("src/components/Foo.vue")
+        );
+        assert_eq!(
+            output_name(path, true),
+            PathBuf::from("src_components_Foo.vue")
+        );
+    }
+
+    #[test]
+    fn flatten_rejects_duplicate_names() {
+        let files = vec![
+            PathBuf::from("src/a_b/Foo.vue"),
+            PathBuf::from("src/a/b_Foo.vue"),
+        ];
+        let err = check_flattened_names(&files).unwrap_err();
+        assert!(err.contains("src_a_b_Foo"), "{}", err);
+        assert!(check_flattened_names(&files[..1]).is_ok());
+    }
+
+    #[test]
+    fn base64_encode_pads_output() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"{\"version\":3}"), "eyJ2ZXJzaW9uIjozfQ==");
+    }
+
+    #[test]
+    fn stdin_compiles_to_writer() {
+        let source =
+            "<script setup>\nconst msg = 'hi'\n</script>\n<template><p>{{ msg }}</p></template>\n";
+        let options =
+            BuildOptions::resolve(&BuildArgs::default(), &BuildConfig::default()).unwrap();
+        let mut out = Vec::new();
+        compile_stdin(source.as_bytes(), &mut out, "Foo.vue", &options).unwrap();
+
+        let code = String::from_utf8(out).unwrap();
+        assert!(code.contains("msg"));
+        assert!(!code.contains("sourceMappingURL"));
+    }
+
+    #[test]
+    fn stdin_json_format_emits_compile_output() {
+        let source = "<template><div>hello</div></template>\n";
+        let args = BuildArgs {
+            format: Some(OutputFormat::Json),
+            ..Default::default()
+        };
+        let options = BuildOptions::resolve(&args, &BuildConfig::default()).unwrap();
+        let mut out = Vec::new();
+        compile_stdin(source.as_bytes(), &mut out, "Foo.vue", &options).unwrap();
+
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(json["filename"], "Foo.vue");
+        assert!(json["code"].as_str().unwrap().contains("hello"));
+    }
+
+    #[test]
+    fn report_has_one_entry_per_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let good = dir.path().join("Good.vue");
+        fs::write(&good, "<template><div>hello</div></template>\n").unwrap();
+        let missing = dir.path().join("Missing.vue");
+
+        let stats = CompileStats::new(2);
+        let files = [&good, &missing]
+            .into_iter()
+            .map(|path| {
+                let start = Instant::now();
+                let result = compile_file_with_profile(path, file_options(false), &stats);
+                let size = fs::metadata(path).map(|m| m.len() as usize).unwrap_or(0);
+                FileReport::new(path, size, start.elapsed(), &result)
+            })
+            .collect();
+
+        let report_path = dir.path().join("report.json");
+        BuildReport::new(files, Duration::from_millis(5))
+            .write(&report_path)
+            .unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&report_path).unwrap()).unwrap();
+        assert_eq!(json["totalFiles"], 2);
+        assert_eq!(json["success"], 1);
+        assert_eq!(json["failed"], 1);
+        let entries = json["files"].as_array().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0]["path"].as_str().unwrap().ends_with("Good.vue"));
+        assert_eq!(entries[0]["errors"], 0);
+        assert!(entries[0]["outputBytes"].as_u64().unwrap() > 0);
+        assert!(entries[0]["durationMs"].is_number());
+        assert_eq!(entries[1]["success"], false);
+    }
+
+    #[test]
+    fn max_warnings_threshold() {
+        assert!(!exceeds_max_warnings(100, None));
+        assert!(!exceeds_max_warnings(0, Some(0)));
+        assert!(!exceeds_max_warnings(3, Some(3)));
+        assert!(exceeds_max_warnings(1, Some(0)));
+        assert!(exceeds_max_warnings(4, Some(3)));
+    }
+
+    #[test]
+    fn vapor_flag_uses_vapor_template_compiler() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("Foo.vue");
+        fs::write(
+            &src,
+            "<script setup>\nconst msg = 'hi'\n</script>\n<template><p>{{ msg }}</p></template>\n",
+        )
+        .unwrap();
+
+        let stats = CompileStats::new(1);
+        let options = FileOptions {
+            vapor: true,
+            ..file_options(false)
+        };
+        let (output, _) = compile_file_with_profile(&src, options, &stats).unwrap();
+        assert!(output.code.contains("_template("));
+        assert_eq!(stats.vapor.load(Ordering::Relaxed), 1);
+    }
+}
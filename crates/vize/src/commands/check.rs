@@ -431,7 +431,9 @@ fn run_direct(args: &CheckArgs) {
     use vize_atelier_core::parser::parse;
     use vize_atelier_sfc::{parse_sfc, SfcParseOptions};
     use vize_canon::lsp_client::TsgoLspClient;
-    use vize_canon::virtual_ts::{generate_virtual_ts_with_offsets, VirtualTsOptions};
+    use vize_canon::virtual_ts::{
+        diagnostic_code, generate_virtual_ts_with_offsets, VirtualTsOptions,
+    };
     use vize_carton::Bump;
     use vize_croquis::{Analyzer, AnalyzerOptions};
 
@@ -777,14 +779,9 @@ fn run_direct(args: &CheckArgs) {
                                     "error"
                                 }
                             };
-                            let code_str = diag
-                                .code
-                                .as_ref()
-                                .map(|c| match c {
-                                    serde_json::Value::Number(n) => format!(" [TS{}]", n),
-                                    serde_json::Value::String(s) => format!(" [{}]", s),
-                                    _ => String::new(),
-                                })
+                            // Errors raised by generated helpers carry a vize code
+                            let code_str = diagnostic_code(&diag.message, diag.code.as_ref())
+                                .map(|code| format!(" [{}]", code))
                                 .unwrap_or_default();
                             // Map virtual TS position -> SFC position
                             let (line, col) = map_diagnostic_position(
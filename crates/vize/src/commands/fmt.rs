@@ -33,7 +33,7 @@ pub struct FmtArgs {
     pub single_quote: Option<bool>,
 
     /// Print width (line length) for formatting
-    #[arg(long)]
+    #[arg(long, value_parser = parse_print_width)]
     pub print_width: Option<u32>,
 
     /// Number of spaces per indentation level
@@ -187,6 +187,11 @@ fn build_format_options(args: &FmtArgs) -> FormatOptions {
     opts
 }
 
+fn parse_print_width(value: &str) -> Result<u32, String> {
+    let width = value.parse::<u32>().map_err(|e| e.to_string())?;
+    FormatOptions::validate_print_width(width)
+}
+
 fn collect_files(patterns: &[String]) -> Vec<PathBuf> {
     let mut files = Vec::new();
 
@@ -0,0 +1,225 @@
+//! Live progress reporting for long-running commands.
+//!
+//! Workers bump an atomic counter; a timer thread redraws the renderer from
+//! it, so reporting never blocks compilation.
+
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Width of the bar in characters.
+const BAR_WIDTH: usize = 30;
+
+/// Minimum time between plain text progress lines.
+const TEXT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Draws progress updates.
+pub(crate) trait ProgressRenderer: Send {
+    /// Show that `done` of `total` items are finished at `rate` items per second.
+    fn render(&mut self, done: usize, total: usize, rate: f64);
+
+    /// Remove any live output before the caller prints its summary.
+    fn finish(&mut self);
+}
+
+/// Pick a renderer for stderr: a live bar on a TTY, periodic lines otherwise.
+pub(crate) fn stderr_renderer() -> Box<dyn ProgressRenderer> {
+    if std::io::stderr().is_terminal() {
+        Box::new(TerminalBar)
+    } else {
+        Box::new(TextProgress::default())
+    }
+}
+
+/// A progress bar redrawn in place.
+struct TerminalBar;
+
+impl ProgressRenderer for TerminalBar {
+    fn render(&mut self, done: usize, total: usize, rate: f64) {
+        let mut stderr = std::io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[2K{}", format_bar(done, total, rate));
+        let _ = stderr.flush();
+    }
+
+    fn finish(&mut self) {
+        let mut stderr = std::io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[2K");
+        let _ = stderr.flush();
+    }
+}
+
+/// Plain progress lines, printed at most once per [`TEXT_INTERVAL`] and only
+/// when the count changed.
+#[derive(Default)]
+struct TextProgress {
+    last: Option<(usize, Instant)>,
+}
+
+impl ProgressRenderer for TextProgress {
+    fn render(&mut self, done: usize, total: usize, rate: f64) {
+        if let Some((last_done, at)) = self.last {
+            if last_done == done || (done < total && at.elapsed() < TEXT_INTERVAL) {
+                return;
+            }
+        }
+        self.last = Some((done, Instant::now()));
+        eprintln!("Compiled {}/{} files ({:.0} files/s)", done, total, rate);
+    }
+
+    fn finish(&mut self) {}
+}
+
+/// Format a bar line like `[#####-----] 50/100 (1200 files/s)`.
+fn format_bar(done: usize, total: usize, rate: f64) -> String {
+    let filled = (done * BAR_WIDTH).checked_div(total).unwrap_or(BAR_WIDTH);
+    let filled = filled.min(BAR_WIDTH);
+    format!(
+        "[{}{}] {}/{} ({:.0} files/s)",
+        "#".repeat(filled),
+        "-".repeat(BAR_WIDTH - filled),
+        done,
+        total,
+        rate
+    )
+}
+
+/// A running progress report.
+pub(crate) struct Progress {
+    done: Arc<AtomicUsize>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+    renderer: Arc<Mutex<Box<dyn ProgressRenderer>>>,
+    total: usize,
+    start: Instant,
+}
+
+impl Progress {
+    /// Start reporting progress over `total` items, redrawing every `interval`.
+    pub(crate) fn start(
+        total: usize,
+        renderer: Box<dyn ProgressRenderer>,
+        interval: Duration,
+    ) -> Self {
+        let done = Arc::new(AtomicUsize::new(0));
+        let stop = Arc::new(AtomicBool::new(false));
+        let renderer = Arc::new(Mutex::new(renderer));
+        let start = Instant::now();
+
+        let thread = {
+            let (done, stop, renderer) = (done.clone(), stop.clone(), renderer.clone());
+            std::thread::spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let done = done.load(Ordering::Relaxed);
+                    if let Ok(mut renderer) = renderer.lock() {
+                        renderer.render(done, total, rate(done, start.elapsed()));
+                    }
+                    std::thread::park_timeout(interval);
+                }
+            })
+        };
+
+        Self {
+            done,
+            stop,
+            thread: Some(thread),
+            renderer,
+            total,
+            start,
+        }
+    }
+
+    /// Record one finished item.
+    pub(crate) fn inc(&self) {
+        self.done.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Stop the timer thread, draw the final count and clear the output.
+    ///
+    /// Returns the number of finished items.
+    pub(crate) fn finish(mut self) -> usize {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+
+        let done = self.done.load(Ordering::Relaxed);
+        if let Ok(mut renderer) = self.renderer.lock() {
+            renderer.render(done, self.total, rate(done, self.start.elapsed()));
+            renderer.finish();
+        }
+        done
+    }
+}
+
+/// Items per second.
+fn rate(done: usize, elapsed: Duration) -> f64 {
+    let secs = elapsed.as_secs_f64();
+    if secs > 0.0 {
+        done as f64 / secs
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rayon::prelude::*;
+
+    #[derive(Default)]
+    struct Recorded {
+        renders: Vec<(usize, usize)>,
+        finished: bool,
+    }
+
+    struct RecordingRenderer(Arc<Mutex<Recorded>>);
+
+    impl ProgressRenderer for RecordingRenderer {
+        fn render(&mut self, done: usize, total: usize, _rate: f64) {
+            self.0.lock().unwrap().renders.push((done, total));
+        }
+
+        fn finish(&mut self) {
+            self.0.lock().unwrap().finished = true;
+        }
+    }
+
+    #[test]
+    fn counter_reaches_total() {
+        let recorded = Arc::new(Mutex::new(Recorded::default()));
+        let files: Vec<usize> = (0..500).collect();
+        let progress = Progress::start(
+            files.len(),
+            Box::new(RecordingRenderer(recorded.clone())),
+            Duration::from_millis(1),
+        );
+
+        files.par_iter().for_each(|_| progress.inc());
+        assert_eq!(progress.finish(), files.len());
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(recorded.renders.last(), Some(&(500, 500)));
+        assert!(recorded.renders.windows(2).all(|w| w[0].0 <= w[1].0));
+        assert!(recorded.finished);
+    }
+
+    #[test]
+    fn bar_fills_with_progress() {
+        assert_eq!(
+            format_bar(0, 10, 0.0),
+            format!("[{}] 0/10 (0 files/s)", "-".repeat(BAR_WIDTH))
+        );
+        assert_eq!(
+            format_bar(5, 10, 12.4),
+            format!(
+                "[{}{}] 5/10 (12 files/s)",
+                "#".repeat(BAR_WIDTH / 2),
+                "-".repeat(BAR_WIDTH / 2)
+            )
+        );
+        assert!(format_bar(10, 10, 1.0).starts_with(&format!("[{}]", "#".repeat(BAR_WIDTH))));
+    }
+}
@@ -1,10 +1,14 @@
 //! Configuration file loading for vize.
 //!
-//! Reads `vize.config.json` from the current working directory.
+//! Reads `vize.config.json` (or `vize.config.toml`) from the current working directory.
 //! Also provides JSON Schema generation for editor autocompletion.
 
+use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Config file names, in lookup order.
+const CONFIG_FILES: &[&str] = &["vize.config.json", "vize.config.toml"];
 
 /// Top-level vize configuration.
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -13,6 +17,10 @@ pub struct VizeConfig {
     #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
     pub schema: Option<String>,
 
+    /// Build configuration.
+    #[serde(default)]
+    pub build: BuildConfig,
+
     /// Type checking configuration.
     #[serde(default)]
     pub check: CheckConfig,
@@ -23,6 +31,106 @@ pub struct VizeConfig {
     pub fmt: vize_glyph::FormatOptions,
 }
 
+/// What `build` writes for each compiled file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Output compiled JavaScript
+    #[default]
+    Js,
+    /// Output JSON with code and metadata
+    Json,
+    /// Only show statistics (no output)
+    Stats,
+}
+
+/// How `build` names output files for TypeScript/JSX scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptExtension {
+    /// Preserve original script language extension (.ts -> .ts, .tsx -> .tsx, .jsx -> .jsx)
+    Preserve,
+    /// Downcompile all scripts to JavaScript (.ts -> .js, .tsx -> .js, .jsx -> .js)
+    #[default]
+    Downcompile,
+}
+
+/// Whether and how `build` emits source maps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceMapMode {
+    /// Do not emit source maps
+    #[default]
+    None,
+    /// Write a `.map` file next to each output and reference it with `sourceMappingURL`
+    External,
+    /// Embed the source map as a base64 data URL
+    Inline,
+}
+
+/// Configuration for the `build` command.
+///
+/// Every field is optional; CLI flags take precedence over values set here.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BuildConfig {
+    /// Glob patterns of `.vue` files to compile (default: `["./**/*.vue"]`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<String>>,
+
+    /// Glob patterns of files to skip.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+
+    /// Output directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<PathBuf>,
+
+    /// Output format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<OutputFormat>,
+
+    /// Enable SSR mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssr: Option<bool>,
+
+    /// Compile templates with the Vapor compiler.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vapor: Option<bool>,
+
+    /// Script extension handling.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script_ext: Option<ScriptExtension>,
+
+    /// Source map output for JavaScript files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sourcemap: Option<SourceMapMode>,
+
+    /// Number of threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threads: Option<usize>,
+
+    /// Per-glob option overrides, applied in order (later entries win).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<BuildOverride>,
+}
+
+/// Compile options applied to files matching `files`.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BuildOverride {
+    /// Glob patterns this override applies to.
+    pub files: Vec<String>,
+
+    /// Enable SSR mode for matching files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssr: Option<bool>,
+
+    /// Script extension handling for matching files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script_ext: Option<ScriptExtension>,
+}
+
 /// Configuration for the `check` command.
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct CheckConfig {
@@ -37,41 +145,75 @@ pub struct CheckConfig {
     pub globals: Option<Vec<String>>,
 }
 
-/// Load `vize.config.json` from the given directory (or CWD if None).
-pub fn load_config(dir: Option<&Path>) -> VizeConfig {
+/// Error raised when a config file exists but cannot be read or parsed.
+#[derive(Debug)]
+pub struct ConfigError {
+    /// Path of the offending config file.
+    pub path: PathBuf,
+    /// Human-readable reason.
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Find the config file in the given directory (or CWD if None).
+fn find_config_file(dir: Option<&Path>) -> Option<PathBuf> {
     let base = dir
         .map(|d| d.to_path_buf())
         .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
-    let config_path = base.join("vize.config.json");
+    CONFIG_FILES
+        .iter()
+        .map(|name| base.join(name))
+        .find(|path| path.exists())
+}
 
-    if !config_path.exists() {
-        return VizeConfig::default();
-    }
+/// Load the config file from the given directory (or CWD if None).
+///
+/// Returns defaults when no config file exists, and an error when one exists
+/// but is malformed.
+pub fn try_load_config(dir: Option<&Path>) -> Result<VizeConfig, ConfigError> {
+    let Some(config_path) = find_config_file(dir) else {
+        return Ok(VizeConfig::default());
+    };
+
+    let content = std::fs::read_to_string(&config_path).map_err(|e| ConfigError {
+        path: config_path.clone(),
+        message: format!("failed to read: {}", e),
+    })?;
 
-    match std::fs::read_to_string(&config_path) {
-        Ok(content) => match serde_json::from_str(&content) {
-            Ok(config) => config,
-            Err(e) => {
-                eprintln!(
-                    "\x1b[33mWarning:\x1b[0m Failed to parse {}: {}",
-                    config_path.display(),
-                    e
-                );
-                VizeConfig::default()
-            }
-        },
+    let is_toml = config_path.extension().is_some_and(|ext| ext == "toml");
+    let parsed = if is_toml {
+        toml::from_str(&content).map_err(|e| e.to_string())
+    } else {
+        serde_json::from_str(&content).map_err(|e| e.to_string())
+    };
+
+    parsed.map_err(|message| ConfigError {
+        path: config_path,
+        message: format!("failed to parse: {}", message),
+    })
+}
+
+/// Load the config file from the given directory (or CWD if None).
+///
+/// Malformed files are reported as a warning and defaults are used instead.
+pub fn load_config(dir: Option<&Path>) -> VizeConfig {
+    match try_load_config(dir) {
+        Ok(config) => config,
         Err(e) => {
-            eprintln!(
-                "\x1b[33mWarning:\x1b[0m Failed to read {}: {}",
-                config_path.display(),
-                e
-            );
+            eprintln!("\x1b[33mWarning:\x1b[0m {}", e);
             VizeConfig::default()
         }
     }
 }
 
-/// JSON Schema for `vize.config.json`.
+/// JSON Schema for `vize.config.json` / `vize.config.toml`.
 pub const VIZE_CONFIG_SCHEMA: &str = r#"{
   "$schema": "http://json-schema.org/draft-07/schema#",
   "title": "Vize Configuration",
@@ -82,6 +224,36 @@ pub const VIZE_CONFIG_SCHEMA: &str = r#"{
       "type": "string",
       "description": "JSON Schema reference for editor autocompletion"
     },
+    "build": {
+      "type": "object",
+      "description": "Build configuration (CLI flags take precedence)",
+      "properties": {
+        "include": { "type": "array", "items": { "type": "string" }, "default": ["./**/*.vue"], "description": "Glob patterns of .vue files to compile" },
+        "exclude": { "type": "array", "items": { "type": "string" }, "description": "Glob patterns of files to skip" },
+        "output": { "type": "string", "default": "./dist", "description": "Output directory" },
+        "format": { "type": "string", "enum": ["js", "json", "stats"], "default": "js", "description": "Output format" },
+        "ssr": { "type": "boolean", "default": false, "description": "Enable SSR mode" },
+        "vapor": { "type": "boolean", "default": false, "description": "Compile templates with the Vapor compiler" },
+        "scriptExt": { "type": "string", "enum": ["preserve", "downcompile"], "default": "downcompile", "description": "Script extension handling" },
+        "sourcemap": { "type": "string", "enum": ["none", "external", "inline"], "default": "none", "description": "Source map output for JavaScript files" },
+        "threads": { "type": "integer", "minimum": 1, "description": "Number of threads" },
+        "overrides": {
+          "type": "array",
+          "description": "Per-glob option overrides, applied in order",
+          "items": {
+            "type": "object",
+            "properties": {
+              "files": { "type": "array", "items": { "type": "string" }, "description": "Glob patterns this override applies to" },
+              "ssr": { "type": "boolean", "description": "Enable SSR mode for matching files" },
+              "scriptExt": { "type": "string", "enum": ["preserve", "downcompile"], "description": "Script extension handling for matching files" }
+            },
+            "required": ["files"],
+            "additionalProperties": false
+          }
+        }
+      },
+      "additionalProperties": false
+    },
     "check": {
       "type": "object",
       "description": "Type checking configuration",
@@ -226,6 +398,83 @@ mod tests {
         assert!(config.fmt.single_quote);
         assert_eq!(config.fmt.max_attributes_per_line, Some(3));
     }
+
+    #[test]
+    fn load_config_parses_toml() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("vize.config.toml"),
+            r#"
+[build]
+include = ["src/**/*.vue"]
+exclude = ["src/legacy/**"]
+output = "out"
+format = "json"
+ssr = true
+scriptExt = "preserve"
+
+[[build.overrides]]
+files = ["src/client/**"]
+ssr = false
+
+[fmt]
+printWidth = 80
+"#,
+        )
+        .unwrap();
+
+        let config = try_load_config(Some(dir.path())).unwrap();
+        assert_eq!(config.build.include, Some(vec!["src/**/*.vue".to_string()]));
+        assert_eq!(config.build.exclude, vec!["src/legacy/**"]);
+        assert_eq!(config.build.output, Some(PathBuf::from("out")));
+        assert_eq!(config.build.format, Some(OutputFormat::Json));
+        assert_eq!(config.build.ssr, Some(true));
+        assert_eq!(config.build.script_ext, Some(ScriptExtension::Preserve));
+        assert_eq!(config.build.overrides.len(), 1);
+        assert_eq!(config.build.overrides[0].ssr, Some(false));
+        assert_eq!(config.fmt.print_width, 80);
+    }
+
+    #[test]
+    fn load_config_prefers_json_over_toml() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("vize.config.json"),
+            r#"{ "build": { "ssr": true } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("vize.config.toml"),
+            "[build]\nssr = false\n",
+        )
+        .unwrap();
+
+        let config = try_load_config(Some(dir.path())).unwrap();
+        assert_eq!(config.build.ssr, Some(true));
+    }
+
+    #[test]
+    fn try_load_config_errors_on_malformed_toml() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("vize.config.toml"), "[build\nssr = ").unwrap();
+
+        let err = try_load_config(Some(dir.path())).unwrap_err();
+        assert!(err.path.ends_with("vize.config.toml"));
+        assert!(err.to_string().contains("failed to parse"));
+    }
+
+    #[test]
+    fn try_load_config_errors_on_unknown_build_key() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("vize.config.json"),
+            r#"{ "build": { "sourceMaps": true } }"#,
+        )
+        .unwrap();
+
+        let err = try_load_config(Some(dir.path())).unwrap_err();
+        assert!(err.message.contains("sourceMaps"));
+    }
 }
 
 /// Write the JSON Schema to `node_modules/.vize/vize.config.schema.json`.
@@ -0,0 +1,39 @@
+//! `vize build --max-warnings` exit status.
+
+use std::path::Path;
+use std::process::{Command, ExitStatus};
+
+/// `:total` has no matching binding, so compiling this emits one warning.
+const ONE_WARNING: &str = r#"<script setup vapor>
+const count = 1
+</script>
+
+<template>
+  <Foo :count :total />
+</template>
+"#;
+
+fn build(dir: &Path, max_warnings: &str) -> ExitStatus {
+    Command::new(env!("CARGO_BIN_EXE_vize"))
+        .current_dir(dir)
+        .args(["build", ".", "--format", "stats", "--max-warnings"])
+        .arg(max_warnings)
+        .status()
+        .expect("failed to run vize")
+}
+
+#[test]
+fn build_fails_over_max_warnings() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("App.vue"), ONE_WARNING).unwrap();
+
+    assert!(!build(dir.path(), "0").success());
+}
+
+#[test]
+fn build_passes_within_max_warnings() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("App.vue"), ONE_WARNING).unwrap();
+
+    assert!(build(dir.path(), "1").success());
+}
@@ -374,10 +374,12 @@ impl<'a> Parser<'a> {
 
     /// Check if tag is a component
     fn is_component(&self, tag: &str) -> bool {
-        // Core built-in components
+        // Core built-in components; `<component>` renders whatever `is` resolves to
         if matches!(
             tag,
-            "Teleport"
+            "component"
+                | "Component"
+                | "Teleport"
                 | "Suspense"
                 | "KeepAlive"
                 | "BaseTransition"
@@ -11,7 +11,7 @@ mod node;
 mod patch_flag;
 mod props;
 mod slots;
-mod v_for;
+pub(crate) mod v_for;
 mod v_if;
 
 use crate::ast::*;
@@ -19,7 +19,7 @@ use crate::options::CodegenOptions;
 
 pub use context::{CodegenContext, CodegenResult};
 use element::generate_root_node;
-use helpers::escape_js_string;
+pub use helpers::escape_js_string;
 use node::generate_node;
 
 fn is_ignorable_root_text(child: &TemplateChildNode<'_>) -> bool {
@@ -109,10 +109,12 @@ pub fn generate(root: &RootNode<'_>, options: CodegenOptions) -> CodegenResult {
         preamble.push_str(&hoists_code);
     }
 
+    let mappings = ctx.expression_offsets.take().unwrap_or_default();
     CodegenResult {
         code: ctx.into_code(),
         preamble,
         map: None,
+        mappings,
     }
 }
 
@@ -740,4 +742,174 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_codegen_cache_inline_handler() {
+        let options = super::CodegenOptions {
+            cache_handlers: true,
+            ..Default::default()
+        };
+        let result = compile!(r#"<button @click="count++">+</button>"#, options);
+        assert!(
+            result.code.contains("_cache[0] || (_cache[0] ="),
+            "Inline handler should be cached. Got:\n{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_codegen_skip_cache_for_v_for_scoped_handler() {
+        let options = super::CodegenOptions {
+            cache_handlers: true,
+            ..Default::default()
+        };
+        let result = compile!(
+            r#"<div v-for="item in items" :key="item.id"><button @click="remove(item)">x</button></div>"#,
+            options
+        );
+        assert!(
+            !result.code.contains("_cache["),
+            "Handler using v-for scope should not be cached. Got:\n{}",
+            result.code
+        );
+        assert!(
+            result.code.contains("[\"onClick\"]"),
+            "Uncached handler should be a dynamic prop. Got:\n{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_codegen_v_bind_object_merges_in_source_order() {
+        let result = compile!(r#"<div id="a" v-bind="attrs"></div>"#);
+        assert!(
+            result.code.contains(r#"_mergeProps({ id: "a" }, attrs)"#),
+            "Object spread should override earlier props. Got:\n{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_codegen_multiple_v_bind_objects() {
+        let result = compile!(r#"<div v-bind="first" :title="t" v-bind="second"></div>"#);
+        assert!(
+            result
+                .code
+                .contains("_mergeProps(first, { title: t }, second)"),
+            "Both spreads should be merged in order. Got:\n{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_codegen_v_for_keyed_fragment() {
+        let result = compile!(r#"<div v-for="item in items" :key="item.id">{{ item.name }}</div>"#);
+        assert!(
+            result.code.contains("key: item.id"),
+            "Key should be emitted in the renderList callback. Got:\n{}",
+            result.code
+        );
+        assert!(
+            result.code.contains("128 /* KEYED_FRAGMENT */"),
+            "Keyed v-for should be a keyed fragment. Got:\n{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_codegen_v_for_static_key_is_keyed() {
+        let result = compile!(r#"<div v-for="item in items" key="row"></div>"#);
+        assert!(
+            result.code.contains("128 /* KEYED_FRAGMENT */"),
+            "Static key should mark the fragment as keyed. Got:\n{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_codegen_nested_v_for_keys() {
+        let result = compile!(
+            r#"<div v-for="row in rows" :key="row.id"><span v-for="cell in row.cells" :key="cell.id">{{ cell.value }}</span></div>"#
+        );
+        assert!(
+            result.code.contains("key: row.id") && result.code.contains("key: cell.id"),
+            "Each v-for should bind its own key. Got:\n{}",
+            result.code
+        );
+        assert!(
+            !result.code.contains("_ctx.cell") && !result.code.contains("_ctx.row"),
+            "Nested keys should resolve to their own scope. Got:\n{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_codegen_scoped_handler_detection_walks_expression() {
+        let options = super::CodegenOptions {
+            cache_handlers: true,
+            ..Default::default()
+        };
+        for handler in [
+            "log(`${item}`)",
+            "open({ ...item })",
+            "select(item); close()",
+        ] {
+            let source = format!(
+                r#"<div v-for="item in items"><button @click="{handler}">x</button></div>"#
+            );
+            let result = compile!(&source, options.clone());
+            assert!(
+                !result.code.contains("_cache["),
+                "Handler `{}` uses the v-for scope. Got:\n{}",
+                handler,
+                result.code
+            );
+        }
+
+        let result = compile!(
+            r#"<div v-for="item in items"><button @click="state.item = 'item'">x</button></div>"#,
+            options
+        );
+        assert!(
+            result.code.contains("_cache[0]"),
+            "Property and string named like the alias should still cache. Got:\n{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_codegen_records_expression_offsets() {
+        let source = "<div :id=\"foo\">{{ msg }} {{ msg }}</div>";
+        let allocator = bumpalo::Bump::new();
+        let (mut root, _) = crate::parser::parse(&allocator, source);
+        crate::transform::transform(
+            &allocator,
+            &mut root,
+            crate::options::TransformOptions::default(),
+            None,
+        );
+        let options = crate::options::CodegenOptions {
+            source_map: true,
+            ..Default::default()
+        };
+        let result = crate::codegen::generate(&root, options);
+
+        assert_eq!(result.mappings.len(), 3, "Got:\n{}", result.code);
+        for &(generated, offset) in result.mappings.iter() {
+            let expected = &source[offset as usize..offset as usize + 3];
+            assert!(
+                expected == "foo" || expected == "msg",
+                "Offset {} should point at an expression, got {:?}",
+                offset,
+                expected
+            );
+            assert!(
+                result.code[generated as usize..].starts_with(expected),
+                "Expression at {} should start with {}. Got:\n{}",
+                generated,
+                expected,
+                result.code
+            );
+        }
+    }
 }
@@ -36,6 +36,8 @@ pub struct CodegenContext {
     pub(super) skip_scope_id: bool,
     /// When true, skip normalizeClass/normalizeStyle wrappers (inside mergeProps)
     pub(super) skip_normalize: bool,
+    /// `(generated offset, template offset)` of each expression, when source maps are on
+    pub(super) expression_offsets: Option<Vec<(u32, u32)>>,
 }
 
 /// Code generation result
@@ -46,6 +48,9 @@ pub struct CodegenResult {
     pub preamble: String,
     /// Source map (JSON)
     pub map: Option<String>,
+    /// Byte offsets `(in code, in template)` of each template expression,
+    /// recorded when `source_map` is enabled
+    pub mappings: Vec<(u32, u32)>,
 }
 
 impl CodegenContext {
@@ -58,6 +63,7 @@ impl CodegenContext {
             helper_alias: default_helper_alias,
             runtime_global_name: options.runtime_global_name.to_string(),
             runtime_module_name: options.runtime_module_name.to_string(),
+            expression_offsets: options.source_map.then(Vec::new),
             options,
             pure: false,
             used_helpers: std::collections::HashSet::new(),
@@ -143,6 +149,15 @@ impl CodegenContext {
         }
     }
 
+    /// Record that the template expression at `source_offset` starts at the
+    /// current position
+    #[inline]
+    pub fn mark_expression(&mut self, source_offset: u32) {
+        if let Some(offsets) = self.expression_offsets.as_mut() {
+            offsets.push((self.code.len() as u32, source_offset));
+        }
+    }
+
     /// Add pure annotation /*#__PURE__*/
     #[inline]
     pub fn push_pure(&mut self) {
@@ -327,6 +327,39 @@ pub fn has_renderable_props(el: &ElementNode<'_>) -> bool {
     el.props.iter().any(|prop| is_renderable_prop(prop))
 }
 
+/// Whether only the static subset of an element's props was hoisted, leaving
+/// bindings on the element
+fn is_partially_hoisted(el: &ElementNode<'_>) -> bool {
+    el.hoisted_props_index.is_some()
+        && el
+            .props
+            .iter()
+            .any(|prop| matches!(prop, PropNode::Directive(dir) if is_supported_directive(dir)))
+}
+
+/// Generate the props of an element with a hoisted props object
+///
+/// Bindings left on a partially hoisted element are merged over the shared
+/// object, which already carries the scope id.
+fn generate_hoisted_props(ctx: &mut CodegenContext, el: &ElementNode<'_>, hoisted_index: usize) {
+    if !is_partially_hoisted(el) {
+        ctx.push("_hoisted_");
+        ctx.push(&hoisted_index.to_string());
+        return;
+    }
+
+    ctx.use_helper(RuntimeHelper::MergeProps);
+    ctx.push(ctx.helper(RuntimeHelper::MergeProps));
+    ctx.push("(_hoisted_");
+    ctx.push(&hoisted_index.to_string());
+    ctx.push(", ");
+    let prev_skip_scope_id = ctx.skip_scope_id;
+    ctx.skip_scope_id = true;
+    generate_props(ctx, &el.props);
+    ctx.skip_scope_id = prev_skip_scope_id;
+    ctx.push(")");
+}
+
 /// Generate v-once element with cache wrapper
 pub fn generate_v_once_element(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
     let cache_index = ctx.next_cache_index();
@@ -399,6 +432,7 @@ pub fn generate_v_once_element(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
             el,
             ctx.options.binding_metadata.as_ref(),
             ctx.options.cache_handlers,
+            &ctx.slot_params,
         );
         if let Some(flag) = patch_flag {
             // Only emit CLASS/STYLE flags for v-once, ignore PROPS
@@ -628,14 +662,15 @@ pub fn generate_element_block(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
                 el,
                 ctx.options.binding_metadata.as_ref(),
                 ctx.options.cache_handlers,
+                &ctx.slot_params,
             );
             let has_patch_info = patch_flag.is_some() || dynamic_props.is_some();
 
             // Generate props (only if there are renderable props, not just v-show)
             // If props are hoisted, use the hoisted reference
             if let Some(hoisted_index) = el.hoisted_props_index {
-                ctx.push(", _hoisted_");
-                ctx.push(&hoisted_index.to_string());
+                ctx.push(", ");
+                generate_hoisted_props(ctx, el, hoisted_index);
             } else if has_renderable_props(el) {
                 ctx.push(", ");
                 generate_props(ctx, &el.props);
@@ -647,7 +682,7 @@ pub fn generate_element_block(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
             // When props are hoisted and only TEXT flag is set, omit the patch flag
             // (Vue optimizes block elements with hoisted static props)
             let should_emit_patch_flag = if let Some(flag) = patch_flag {
-                !(el.hoisted_props_index.is_some() && flag == 1)
+                !(el.hoisted_props_index.is_some() && !is_partially_hoisted(el) && flag == 1)
             } else {
                 false
             };
@@ -779,12 +814,14 @@ pub fn generate_element_block(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
                     el,
                     ctx.options.binding_metadata.as_ref(),
                     ctx.options.cache_handlers,
+                    &ctx.slot_params,
                 )
             } else {
                 calculate_element_patch_info(
                     el,
                     ctx.options.binding_metadata.as_ref(),
                     ctx.options.cache_handlers,
+                    &ctx.slot_params,
                 )
             };
 
@@ -976,14 +1013,15 @@ pub fn generate_element(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
                 el,
                 ctx.options.binding_metadata.as_ref(),
                 ctx.options.cache_handlers,
+                &ctx.slot_params,
             );
             let has_patch_info = patch_flag.is_some() || dynamic_props.is_some();
 
             // Generate props (only if there are renderable props, not just v-show)
             // If props are hoisted, use the hoisted reference
             if let Some(hoisted_index) = el.hoisted_props_index {
-                ctx.push(", _hoisted_");
-                ctx.push(&hoisted_index.to_string());
+                ctx.push(", ");
+                generate_hoisted_props(ctx, el, hoisted_index);
             } else if has_renderable_props(el) {
                 ctx.push(", ");
                 generate_props(ctx, &el.props);
@@ -447,6 +447,11 @@ pub fn generate_simple_expression(ctx: &mut CodegenContext, exp: &SimpleExpressi
             content = convert_line_comments_to_block(&content);
         }
 
+        // Generated nodes have no template location to map back to
+        if exp.loc.end.offset > exp.loc.start.offset {
+            ctx.mark_expression(exp.loc.start.offset);
+        }
+
         // Replace _ctx.X with X when X is a known slot/v-for parameter.
         // This handles destructured variables that the transform phase
         // incorrectly prefixed with _ctx. because it didn't know the scope.
@@ -588,6 +593,65 @@ pub fn is_simple_member_expression(s: &str) -> bool {
     false
 }
 
+/// Check if an event handler references slot or v-for scope parameters
+/// Such handlers capture per-iteration values, so they must not be cached.
+/// Walks the original source for identifier references; handlers that fail to
+/// parse are treated as scoped so they are never cached incorrectly.
+pub fn handler_references_scope(
+    exp: &ExpressionNode<'_>,
+    scope_params: &std::collections::HashSet<String>,
+) -> bool {
+    use oxc_allocator::Allocator as OxcAllocator;
+    use oxc_ast_visit::Visit;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    struct ScopeReferenceVisitor<'a> {
+        scope_params: &'a std::collections::HashSet<String>,
+        found: bool,
+    }
+
+    impl Visit<'_> for ScopeReferenceVisitor<'_> {
+        fn visit_identifier_reference(&mut self, ident: &oxc_ast::ast::IdentifierReference<'_>) {
+            if self.scope_params.contains(ident.name.as_str()) {
+                self.found = true;
+            }
+        }
+    }
+
+    if scope_params.is_empty() {
+        return false;
+    }
+    let source = match exp {
+        ExpressionNode::Simple(simple) => simple.loc.source.as_str(),
+        ExpressionNode::Compound(compound) => compound.loc.source.as_str(),
+    };
+
+    let allocator = OxcAllocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let mut visitor = ScopeReferenceVisitor {
+        scope_params,
+        found: false,
+    };
+
+    let mut wrapped = String::with_capacity(source.len() + 2);
+    wrapped.push('(');
+    wrapped.push_str(source);
+    wrapped.push(')');
+    if let Ok(expr) = Parser::new(&allocator, &wrapped, source_type).parse_expression() {
+        visitor.visit_expression(&expr);
+        return visitor.found;
+    }
+
+    // Multi-statement handlers like `select(item); close()`
+    let parsed = Parser::new(&allocator, source, source_type).parse();
+    if !parsed.errors.is_empty() {
+        return true;
+    }
+    visitor.visit_program(&parsed.program);
+    visitor.found
+}
+
 /// Check if an event handler expression is an inline handler
 /// Inline handlers are expressions that are NOT simple identifiers or member expressions
 /// Note: This is kept for potential future use (e.g., optimizations)
@@ -1,8 +1,10 @@
 //! Patch flag calculation and naming functions.
 
+use super::expression::handler_references_scope;
 use super::helpers::camelize;
 use crate::ast::*;
 use crate::options::{BindingMetadata, BindingType};
+use std::collections::HashSet;
 use vize_carton::is_builtin_directive;
 
 /// Check if an interpolation references only constant bindings (LiteralConst or SetupConst)
@@ -57,8 +59,9 @@ pub fn calculate_element_patch_info(
     el: &ElementNode<'_>,
     bindings: Option<&BindingMetadata>,
     cache_handlers: bool,
+    scope_params: &HashSet<String>,
 ) -> (Option<i32>, Option<Vec<String>>) {
-    calculate_element_patch_info_inner(el, bindings, cache_handlers, false)
+    calculate_element_patch_info_inner(el, bindings, cache_handlers, scope_params, false)
 }
 
 /// Same as `calculate_element_patch_info` but allows skipping the `is` prop.
@@ -66,14 +69,16 @@ pub fn calculate_element_patch_info_skip_is(
     el: &ElementNode<'_>,
     bindings: Option<&BindingMetadata>,
     cache_handlers: bool,
+    scope_params: &HashSet<String>,
 ) -> (Option<i32>, Option<Vec<String>>) {
-    calculate_element_patch_info_inner(el, bindings, cache_handlers, true)
+    calculate_element_patch_info_inner(el, bindings, cache_handlers, scope_params, true)
 }
 
 fn calculate_element_patch_info_inner(
     el: &ElementNode<'_>,
     bindings: Option<&BindingMetadata>,
     cache_handlers: bool,
+    scope_params: &HashSet<String>,
     skip_is: bool,
 ) -> (Option<i32>, Option<Vec<String>>) {
     let mut flag: i32 = 0;
@@ -230,9 +235,13 @@ fn calculate_element_patch_info_inner(
                                 };
 
                                 // Check if the handler will be cached
-                                // When cache_handlers is true, ALL handlers are cached (including simple identifiers)
+                                // When cache_handlers is true, handlers are cached (including simple identifiers)
+                                // unless they reference v-for/slot scope variables
                                 // Cached handlers become stable references, so no PROPS flag needed
-                                let handler_is_cached = cache_handlers && dir.exp.is_some();
+                                let handler_is_cached = cache_handlers
+                                    && dir.exp.as_ref().is_some_and(|exp| {
+                                        !handler_references_scope(exp, scope_params)
+                                    });
 
                                 // Only add PROPS flag if handler is neither const nor cached
                                 if !handler_is_const && !handler_is_cached {
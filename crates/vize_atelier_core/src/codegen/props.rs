@@ -3,7 +3,10 @@
 use crate::ast::*;
 
 use super::context::CodegenContext;
-use super::expression::{generate_event_handler, generate_expression, generate_simple_expression};
+use super::expression::{
+    generate_event_handler, generate_expression, generate_simple_expression,
+    handler_references_scope,
+};
 use super::helpers::{camelize, capitalize_first, escape_js_string, is_valid_js_identifier};
 
 /// Check if there's a v-bind without argument (object spread)
@@ -44,6 +47,19 @@ fn has_other_props(props: &[PropNode<'_>]) -> bool {
     })
 }
 
+/// Check if a directive is a v-bind/v-on object spread (`v-bind="obj"`, `v-on="obj"`)
+fn is_object_spread(dir: &DirectiveNode<'_>) -> bool {
+    dir.arg.is_none() && dir.exp.is_some() && (dir.name == "bind" || dir.name == "on")
+}
+
+/// Count the v-bind/v-on object spreads
+fn count_object_spreads(props: &[PropNode<'_>]) -> usize {
+    props
+        .iter()
+        .filter(|p| matches!(p, PropNode::Directive(dir) if is_object_spread(dir)))
+        .count()
+}
+
 /// Generate the v-bind object expression
 fn generate_vbind_object_exp(ctx: &mut CodegenContext, props: &[PropNode<'_>]) {
     for p in props {
@@ -77,6 +93,80 @@ fn generate_von_object_exp(ctx: &mut CodegenContext, props: &[PropNode<'_>]) {
     ctx.push(")");
 }
 
+/// A `_mergeProps` argument: a run of regular props or a single object spread
+enum MergeArg<'p, 'a> {
+    Object(&'p [PropNode<'a>]),
+    Spread(&'p PropNode<'a>),
+}
+
+/// Generate `_mergeProps(...)` with arguments in source order.
+///
+/// Runs of regular props between object spreads become separate objects, so
+/// later bindings override earlier ones like in Vue. The scope id attribute is
+/// added to the last object, or as a trailing object if there is none.
+fn generate_merge_props(ctx: &mut CodegenContext, props: &[PropNode<'_>], scope_id: Option<&str>) {
+    let mut args = Vec::new();
+    let mut run_start = 0;
+    for (i, prop) in props.iter().enumerate() {
+        if matches!(prop, PropNode::Directive(dir) if is_object_spread(dir)) {
+            let run = &props[run_start..i];
+            if has_other_props(run) {
+                args.push(MergeArg::Object(run));
+            }
+            args.push(MergeArg::Spread(prop));
+            run_start = i + 1;
+        }
+    }
+    let run = &props[run_start..];
+    if has_other_props(run) {
+        args.push(MergeArg::Object(run));
+    }
+    let last_object = args
+        .iter()
+        .rposition(|arg| matches!(arg, MergeArg::Object(_)));
+
+    ctx.use_helper(RuntimeHelper::MergeProps);
+    ctx.push(ctx.helper(RuntimeHelper::MergeProps));
+    ctx.push("(");
+
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 {
+            ctx.push(", ");
+        }
+        match arg {
+            // Inside mergeProps, skip normalizeClass/normalizeStyle - mergeProps handles it
+            MergeArg::Object(run) => {
+                let prev_skip_scope_id = ctx.skip_scope_id;
+                if last_object != Some(i) {
+                    ctx.skip_scope_id = true;
+                }
+                generate_props_object_inner(ctx, run, true, true);
+                ctx.skip_scope_id = prev_skip_scope_id;
+            }
+            MergeArg::Spread(prop) => {
+                let spread = std::slice::from_ref(*prop);
+                match prop {
+                    PropNode::Directive(dir) if dir.name == "on" => {
+                        generate_von_object_exp(ctx, spread)
+                    }
+                    _ => generate_vbind_object_exp(ctx, spread),
+                }
+            }
+        }
+    }
+
+    // No regular props to carry the scope id, add it as separate object
+    if last_object.is_none() {
+        if let Some(sid) = scope_id {
+            ctx.push(", { \"");
+            ctx.push(sid);
+            ctx.push("\": \"\" }");
+        }
+    }
+
+    ctx.push(")");
+}
+
 /// Generate props object
 pub fn generate_props(ctx: &mut CodegenContext, props: &[PropNode<'_>]) {
     // Clone scope_id to avoid borrow checker issues.
@@ -106,47 +196,9 @@ pub fn generate_props(ctx: &mut CodegenContext, props: &[PropNode<'_>]) {
 
     // Handle cases with object spreads (v-bind="obj" or v-on="obj")
     if has_vbind_obj || has_von_obj {
-        if has_other || (has_vbind_obj && has_von_obj) {
+        if has_other || count_object_spreads(props) > 1 {
             // Multiple spreads or spread with other props: _mergeProps(...)
-            ctx.use_helper(RuntimeHelper::MergeProps);
-            ctx.push(ctx.helper(RuntimeHelper::MergeProps));
-            ctx.push("(");
-
-            let mut first_merge_arg = true;
-
-            // Add v-bind object spread
-            if has_vbind_obj {
-                generate_vbind_object_exp(ctx, props);
-                first_merge_arg = false;
-            }
-
-            // Add v-on object spread (wrapped with toHandlers)
-            if has_von_obj {
-                if !first_merge_arg {
-                    ctx.push(", ");
-                }
-                generate_von_object_exp(ctx, props);
-                first_merge_arg = false;
-            }
-
-            // Add other props as object (includes scope_id)
-            // Inside mergeProps, skip normalizeClass/normalizeStyle - mergeProps handles it
-            if has_other {
-                if !first_merge_arg {
-                    ctx.push(", ");
-                }
-                generate_props_object_inner(ctx, props, true, true);
-            } else if let Some(ref sid) = scope_id {
-                // No other props but we have scope_id, add it as separate object
-                if !first_merge_arg {
-                    ctx.push(", ");
-                }
-                ctx.push("{ \"");
-                ctx.push(sid);
-                ctx.push("\": \"\" }");
-            }
-
-            ctx.push(")");
+            generate_merge_props(ctx, props, scope_id.as_deref());
         } else if has_vbind_obj {
             // v-bind="attrs" alone
             // If we have scope_id, we need to merge it with the bound object
@@ -1064,7 +1116,16 @@ pub fn generate_directive_prop_with_static(
                 }
                 false
             });
-            let needs_cache = ctx.options.cache_handlers && dir.exp.is_some() && !is_const_handler;
+            // Handlers referencing v-for/slot scope variables capture per-iteration
+            // values and must be recreated on each render
+            let references_scope = dir
+                .exp
+                .as_ref()
+                .is_some_and(|exp| handler_references_scope(exp, &ctx.slot_params));
+            let needs_cache = ctx.options.cache_handlers
+                && dir.exp.is_some()
+                && !is_const_handler
+                && !references_scope;
 
             if needs_cache {
                 let cache_index = ctx.next_cache_index();
@@ -1,6 +1,7 @@
 //! v-for generation functions.
 
 use crate::ast::*;
+use crate::transforms::v_for::has_key;
 
 use super::children::generate_children;
 use super::context::CodegenContext;
@@ -157,7 +158,7 @@ pub fn generate_for(ctx: &mut CodegenContext, for_node: &ForNode<'_>) {
     // Check if children have keys
     let has_key = for_node.children.iter().any(|child| {
         if let TemplateChildNode::Element(el) = child {
-            get_element_key(el).is_some()
+            has_key(el)
         } else {
             false
         }
@@ -614,6 +615,7 @@ pub fn generate_for_item(ctx: &mut CodegenContext, node: &TemplateChildNode<'_>,
                         el,
                         ctx.options.binding_metadata.as_ref(),
                         ctx.options.cache_handlers,
+                        &ctx.slot_params,
                     );
                     // If no children were emitted but we have patch info, emit null for children
                     if el.children.is_empty() && (patch_flag.is_some() || dynamic_props.is_some()) {
@@ -647,6 +649,7 @@ pub fn generate_for_item(ctx: &mut CodegenContext, node: &TemplateChildNode<'_>,
                         flag_el,
                         ctx.options.binding_metadata.as_ref(),
                         ctx.options.cache_handlers,
+                        &ctx.slot_params,
                     );
                     if let Some(flag) = patch_flag {
                         ctx.push(", ");
@@ -8,7 +8,7 @@ use crate::ast::*;
 use crate::errors::{CompilerError, ErrorCode};
 use crate::options::TransformOptions;
 
-use super::TransformContext;
+use super::{DirectiveTransform, TransformContext};
 
 impl<'a> TransformContext<'a> {
     /// Create a new transform context
@@ -34,8 +34,10 @@ impl<'a> TransformContext<'a> {
             in_v_once: false,
             in_ssr: ssr,
             errors: std::vec::Vec::new(),
+            warnings: std::vec::Vec::new(),
             node_removed: false,
             analysis: None,
+            directive_transforms: vize_carton::FxHashMap::default(),
         }
     }
 
@@ -51,6 +53,25 @@ impl<'a> TransformContext<'a> {
         ctx
     }
 
+    /// Register a transform for a user-defined directive (e.g. `focus` for `v-focus`).
+    ///
+    /// The transform runs for every element using the directive. Props in its
+    /// result are added to the element; unless it sets `remove_directive`, the
+    /// directive is still emitted via `_withDirectives` and `_resolveDirective`.
+    pub fn register_directive_transform(
+        &mut self,
+        name: impl Into<String>,
+        transform: DirectiveTransform<'a>,
+    ) {
+        self.directive_transforms.insert(name.into(), transform);
+    }
+
+    /// Get the registered transform for a directive, if any
+    #[inline]
+    pub fn directive_transform(&self, name: &str) -> Option<DirectiveTransform<'a>> {
+        self.directive_transforms.get(name).copied()
+    }
+
     /// Set the analysis summary
     pub fn set_analysis(&mut self, analysis: &'a Croquis) {
         self.analysis = Some(analysis);
@@ -251,6 +272,7 @@ impl<'a> TransformContext<'a> {
                 index_alias: index_alias.map(CompactString::new),
                 source: CompactString::new(source),
                 key_expression: None,
+                alias_pattern: None,
             },
             0,
             0,
@@ -282,6 +304,13 @@ impl<'a> TransformContext<'a> {
         self.errors.push(CompilerError::new(code, loc));
     }
 
+    /// Report a dev-mode warning (skipped in production mode)
+    pub fn on_warn(&mut self, code: ErrorCode, loc: Option<SourceLocation>) {
+        if !self.options.is_prod {
+            self.warnings.push(CompilerError::new(code, loc));
+        }
+    }
+
     /// Replace current node with a new node
     pub fn replace_node(&mut self, new_node: TemplateChildNode<'a>) {
         if let Some(parent) = &self.parent {
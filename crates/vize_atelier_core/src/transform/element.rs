@@ -1,8 +1,9 @@
 //! Element transformation functions.
 
-use vize_carton::{is_builtin_directive, Box, String, Vec};
+use vize_carton::{is_builtin_directive, Box, Bump, String, Vec};
 
 use crate::ast::*;
+use crate::transforms::transform_element::is_dynamic_component_tag;
 use crate::transforms::transform_expression::process_inline_handler;
 
 use super::{ExitFn, TransformContext};
@@ -20,6 +21,11 @@ pub fn transform_element<'a>(
         ElementType::Element => {
             ctx.helper(RuntimeHelper::CreateElementVNode);
         }
+        ElementType::Component if is_dynamic_component_tag(&el.tag) => {
+            // `<component :is>` resolves at runtime; `is` elsewhere stays an attribute
+            ctx.helper(RuntimeHelper::CreateVNode);
+            ctx.helper(RuntimeHelper::ResolveDynamicComponent);
+        }
         ElementType::Component => {
             ctx.helper(RuntimeHelper::CreateVNode);
             // Only add ResolveComponent if component is not in binding metadata
@@ -97,6 +103,51 @@ fn process_directive_expressions<'a>(
     }
 }
 
+/// Run registered transforms for custom directives on an element
+fn apply_directive_transforms<'a>(
+    ctx: &mut TransformContext<'a>,
+    el: &mut Box<'a, ElementNode<'a>>,
+) {
+    let indices: std::vec::Vec<usize> = el
+        .props
+        .iter()
+        .enumerate()
+        .filter_map(|(i, prop)| match prop {
+            PropNode::Directive(dir) if !is_builtin_directive(&dir.name) => ctx
+                .directive_transforms
+                .contains_key(dir.name.as_str())
+                .then_some(i),
+            _ => None,
+        })
+        .collect();
+
+    // Walk backwards so reinserted directives keep their original positions
+    for idx in indices.into_iter().rev() {
+        // An earlier transform may have reshaped the props
+        if idx >= el.props.len() {
+            continue;
+        }
+        let prop = el.props.remove(idx);
+        let result = match &prop {
+            PropNode::Directive(dir) => ctx
+                .directive_transform(&dir.name)
+                .and_then(|transform| transform(ctx, el, dir)),
+            _ => None,
+        };
+
+        let keep = match result {
+            Some(result) => {
+                el.props.extend(result.props);
+                !result.remove_directive
+            }
+            None => true,
+        };
+        if keep {
+            el.props.insert(idx.min(el.props.len()), prop);
+        }
+    }
+}
+
 /// Process element properties and directives
 fn process_element_props<'a>(ctx: &mut TransformContext<'a>, el: &mut Box<'a, ElementNode<'a>>) {
     let allocator = ctx.allocator;
@@ -107,6 +158,11 @@ fn process_element_props<'a>(ctx: &mut TransformContext<'a>, el: &mut Box<'a, El
         process_directive_expressions(ctx, el);
     }
 
+    // Run transforms registered for user-defined directives
+    if !ctx.directive_transforms.is_empty() {
+        apply_directive_transforms(ctx, el);
+    }
+
     // Collect indices of v-model directives to process
     let mut model_indices: std::vec::Vec<usize> = std::vec::Vec::new();
     for (i, prop) in el.props.iter().enumerate() {
@@ -326,6 +382,7 @@ fn process_element_props<'a>(ctx: &mut TransformContext<'a>, el: &mut Box<'a, El
                     ))),
                     modifiers: Vec::new_in(allocator),
                     for_parse_result: None,
+                    model_expansion: true,
                     loc: data.dir_loc.clone(),
                 },
                 allocator,
@@ -354,6 +411,7 @@ fn process_element_props<'a>(ctx: &mut TransformContext<'a>, el: &mut Box<'a, El
                     exp: Some(processed_handler),
                     modifiers: Vec::new_in(allocator),
                     for_parse_result: None,
+                    model_expansion: true,
                     loc: data.dir_loc.clone(),
                 },
                 allocator,
@@ -386,6 +444,7 @@ fn process_element_props<'a>(ctx: &mut TransformContext<'a>, el: &mut Box<'a, El
                         ))),
                         modifiers: Vec::new_in(allocator),
                         for_parse_result: None,
+                        model_expansion: true,
                         loc: SourceLocation::STUB,
                     },
                     allocator,
@@ -423,6 +482,7 @@ fn process_element_props<'a>(ctx: &mut TransformContext<'a>, el: &mut Box<'a, El
                     exp: Some(processed_handler),
                     modifiers: Vec::new_in(allocator),
                     for_parse_result: None,
+                    model_expansion: true,
                     loc: data.dir_loc.clone(),
                 },
                 allocator,
@@ -433,6 +493,100 @@ fn process_element_props<'a>(ctx: &mut TransformContext<'a>, el: &mut Box<'a, El
     }
 }
 
+/// Handle `v-pre` on an element.
+///
+/// Strips the directive and turns the element and all of its descendants
+/// into plain static markup: interpolations become literal text, directives
+/// become raw attributes and components are treated as native elements.
+/// Returns `true` if the element had `v-pre`.
+pub fn transform_v_pre<'a>(
+    ctx: &mut TransformContext<'a>,
+    el: &mut Box<'a, ElementNode<'a>>,
+) -> bool {
+    let Some(idx) = el
+        .props
+        .iter()
+        .position(|p| matches!(p, PropNode::Directive(dir) if dir.name == "pre"))
+    else {
+        return false;
+    };
+    el.props.remove(idx);
+    make_static(ctx.allocator, el);
+    true
+}
+
+/// Recursively convert an element subtree into static markup.
+fn make_static<'a>(allocator: &'a Bump, el: &mut ElementNode<'a>) {
+    el.tag_type = ElementType::Element;
+
+    for prop in el.props.iter_mut() {
+        if let PropNode::Directive(dir) = prop {
+            let attr = raw_attribute(&dir.loc);
+            *prop = PropNode::Attribute(Box::new_in(attr, allocator));
+        }
+    }
+
+    let children = std::mem::replace(&mut el.children, Vec::new_in(allocator));
+    for child in children {
+        match child {
+            TemplateChildNode::Interpolation(interp) => {
+                let source = interp.loc.source.as_str();
+                push_static_text(allocator, &mut el.children, source, &interp.loc);
+            }
+            TemplateChildNode::Text(text) => {
+                push_static_text(allocator, &mut el.children, &text.content, &text.loc);
+            }
+            TemplateChildNode::Element(mut child_el) => {
+                make_static(allocator, &mut child_el);
+                el.children.push(TemplateChildNode::Element(child_el));
+            }
+            other => el.children.push(other),
+        }
+    }
+}
+
+/// Append literal text, merging it into a preceding text node.
+fn push_static_text<'a>(
+    allocator: &'a Bump,
+    children: &mut Vec<'a, TemplateChildNode<'a>>,
+    content: &str,
+    loc: &SourceLocation,
+) {
+    if let Some(TemplateChildNode::Text(prev)) = children.last_mut() {
+        prev.content.push_str(content);
+        prev.loc.source.push_str(&loc.source);
+        prev.loc.end = loc.end;
+        return;
+    }
+    children.push(TemplateChildNode::Text(Box::new_in(
+        TextNode::new(content, loc.clone()),
+        allocator,
+    )));
+}
+
+/// Build a plain attribute from a directive's raw source (e.g. `:id="foo"`).
+fn raw_attribute(loc: &SourceLocation) -> AttributeNode {
+    let source = loc.source.as_str();
+    let (name, value) = match source.split_once('=') {
+        Some((name, value)) => (name.trim_end(), Some(value.trim_start())),
+        None => (source, None),
+    };
+
+    let mut attr = AttributeNode::new(name, loc.clone());
+    attr.value = value.map(|value| {
+        // The location may stop before the closing quote, so strip each side separately
+        let unquoted = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let inner = &value[1..];
+                inner.strip_suffix(quote).unwrap_or(inner)
+            }
+            _ => value,
+        };
+        TextNode::new(unquoted, loc.clone())
+    });
+    attr
+}
+
 /// Transform interpolation node
 pub fn transform_interpolation<'a>(
     ctx: &mut TransformContext<'a>,
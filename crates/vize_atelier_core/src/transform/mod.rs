@@ -7,7 +7,7 @@ pub mod element;
 pub mod structural;
 pub mod traverse;
 
-use vize_carton::{Box, Bump, FxHashSet, String, Vec};
+use vize_carton::{Box, Bump, FxHashMap, FxHashSet, String, Vec};
 use vize_croquis::{Croquis, ScopeChain};
 
 use crate::ast::*;
@@ -84,10 +84,14 @@ pub struct TransformContext<'a> {
     pub in_ssr: bool,
     /// Errors collected
     pub errors: std::vec::Vec<CompilerError>,
+    /// Dev-mode warnings collected
+    pub warnings: std::vec::Vec<CompilerError>,
     /// Node was removed flag
     pub(crate) node_removed: bool,
     /// Semantic analysis summary (optional, for enhanced transforms)
     pub(crate) analysis: Option<&'a Croquis>,
+    /// Transforms registered for user-defined directives, keyed by name
+    pub(crate) directive_transforms: FxHashMap<String, DirectiveTransform<'a>>,
 }
 
 /// Enum for parent node types
@@ -122,20 +126,44 @@ impl<'a> ParentNode<'a> {
 }
 
 /// Transform the root AST node
+///
+/// Returns the errors reported during traversal (e.g. a `v-else` without an
+/// adjacent `v-if`). Dev-mode warnings are left in [`RootNode::warnings`].
 pub fn transform<'a>(
     allocator: &'a Bump,
     root: &mut RootNode<'a>,
     options: TransformOptions,
     analysis: Option<&'a Croquis>,
-) {
+) -> std::vec::Vec<CompilerError> {
     let source = root.source.clone();
-    let mut ctx = if let Some(analysis) = analysis {
+    let ctx = if let Some(analysis) = analysis {
         TransformContext::with_analysis(allocator, source, options, analysis)
     } else {
         TransformContext::new(allocator, source, options)
     };
+    transform_with_context(ctx, root)
+}
+
+/// Transform the root AST node with a prepared context.
+///
+/// Use this instead of [`transform`] to register custom directive transforms
+/// (see [`TransformContext::register_directive_transform`]) before traversal.
+pub fn transform_with_context<'a>(
+    mut ctx: TransformContext<'a>,
+    root: &mut RootNode<'a>,
+) -> std::vec::Vec<CompilerError> {
     ctx.root = Some(root as *mut _);
 
+    if !ctx.options.keep_comments() {
+        strip_comments(&mut root.children);
+    }
+
+    // Normalize text whitespace before other transforms see it
+    crate::transforms::transform_text::apply_whitespace_strategy(
+        &mut root.children,
+        ctx.options.whitespace,
+    );
+
     // Transform the root children
     traverse_children(&mut ctx, ParentNode::Root(root as *mut _));
 
@@ -146,8 +174,10 @@ pub fn transform<'a>(
     // Create root codegen node
     create_root_codegen(&mut ctx, root);
 
-    // Update root with context results
-    for helper in ctx.helpers.into_iter() {
+    // Update root with context results (helpers in a stable order)
+    let mut helpers: Vec<_> = ctx.helpers.into_iter().collect();
+    helpers.sort();
+    for helper in helpers {
         root.helpers.push(helper);
     }
     for component in ctx.components.into_iter() {
@@ -162,6 +192,23 @@ pub fn transform<'a>(
     }
     root.temps = ctx.temps;
     root.transformed = true;
+    root.warnings = ctx.warnings;
+
+    ctx.errors
+}
+
+/// Remove HTML comments from the tree.
+///
+/// Directive comments (`@vize:` prefix) are kept; codegen never emits them.
+fn strip_comments(children: &mut Vec<'_, TemplateChildNode<'_>>) {
+    children.retain(|child| {
+        !matches!(child, TemplateChildNode::Comment(comment) if comment.directive.is_none())
+    });
+    for child in children.iter_mut() {
+        if let TemplateChildNode::Element(el) = child {
+            strip_comments(&mut el.children);
+        }
+    }
 }
 
 /// Create codegen node for root
@@ -334,4 +381,319 @@ mod tests {
             "Should contain openBlock"
         );
     }
+
+    #[test]
+    fn test_registered_directive_transform_runs() {
+        use super::{transform_with_context, DirectiveTransformResult, TransformContext};
+        use crate::ast::{DirectiveNode, ElementNode};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        fn noop<'a>(
+            ctx: &mut TransformContext<'a>,
+            _el: &mut ElementNode<'a>,
+            _dir: &DirectiveNode<'a>,
+        ) -> Option<DirectiveTransformResult<'a>> {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            Some(DirectiveTransformResult {
+                props: vize_carton::Vec::new_in(ctx.allocator),
+                remove_directive: false,
+                ssr_tag_type: None,
+            })
+        }
+
+        let allocator = Bump::new();
+        let (mut root, _) = parse(&allocator, r#"<input v-focus /><p v-focus></p>"#);
+        let mut ctx =
+            TransformContext::new(&allocator, root.source.clone(), TransformOptions::default());
+        ctx.register_directive_transform("focus", noop);
+        transform_with_context(ctx, &mut root);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+        // Default lowering still applies when the directive is kept
+        assert!(root.directives.iter().any(|d| d.as_str() == "focus"));
+        let result = generate(&root, CodegenOptions::default());
+        assert!(result.code.contains("_resolveDirective(\"focus\")"));
+    }
+
+    #[test]
+    fn test_registered_directive_transform_removes_directive() {
+        use super::{transform_with_context, DirectiveTransformResult, TransformContext};
+        use crate::ast::{DirectiveNode, ElementNode};
+
+        fn strip<'a>(
+            ctx: &mut TransformContext<'a>,
+            _el: &mut ElementNode<'a>,
+            _dir: &DirectiveNode<'a>,
+        ) -> Option<DirectiveTransformResult<'a>> {
+            Some(DirectiveTransformResult {
+                props: vize_carton::Vec::new_in(ctx.allocator),
+                remove_directive: true,
+                ssr_tag_type: None,
+            })
+        }
+
+        let allocator = Bump::new();
+        let (mut root, _) = parse(&allocator, r#"<input v-focus />"#);
+        let mut ctx =
+            TransformContext::new(&allocator, root.source.clone(), TransformOptions::default());
+        ctx.register_directive_transform("focus", strip);
+        transform_with_context(ctx, &mut root);
+
+        assert!(root.directives.is_empty());
+        let result = generate(&root, CodegenOptions::default());
+        assert!(!result.code.contains("_withDirectives"));
+    }
+
+    #[test]
+    fn test_registered_directive_transform_reshaping_props() {
+        use super::{transform_with_context, DirectiveTransformResult, TransformContext};
+        use crate::ast::{DirectiveNode, ElementNode};
+
+        // Drops every other prop, invalidating the pending directive indices
+        fn take_over<'a>(
+            _ctx: &mut TransformContext<'a>,
+            el: &mut ElementNode<'a>,
+            _dir: &DirectiveNode<'a>,
+        ) -> Option<DirectiveTransformResult<'a>> {
+            el.props.clear();
+            None
+        }
+
+        let allocator = Bump::new();
+        let (mut root, _) = parse(&allocator, r#"<input id="a" v-focus v-select />"#);
+        let mut ctx =
+            TransformContext::new(&allocator, root.source.clone(), TransformOptions::default());
+        ctx.register_directive_transform("focus", take_over);
+        ctx.register_directive_transform("select", take_over);
+        transform_with_context(ctx, &mut root);
+
+        let result = generate(&root, CodegenOptions::default());
+        assert!(result.code.contains("_withDirectives"));
+    }
+
+    #[test]
+    fn test_v_pre_keeps_mustache_literal() {
+        let allocator = Bump::new();
+        let (mut root, _) = parse(&allocator, r#"<div v-pre>{{ raw }}</div>"#);
+        let options = TransformOptions {
+            hoist_static: true,
+            ..Default::default()
+        };
+        transform(&allocator, &mut root, options, None);
+
+        let result = generate(&root, CodegenOptions::default());
+        assert!(result.code.contains("\"{{ raw }}\""), "{}", result.code);
+        assert!(!result.code.contains("_toDisplayString"), "{}", result.code);
+        assert!(!result.code.contains("v-pre"), "{}", result.code);
+    }
+
+    #[test]
+    fn test_v_pre_skips_components_and_directives() {
+        let allocator = Bump::new();
+        let (mut root, _) = parse(
+            &allocator,
+            r#"<div v-pre><MyComp :id="foo" @click="bar">{{ raw }}</MyComp></div>"#,
+        );
+        transform(&allocator, &mut root, TransformOptions::default(), None);
+
+        assert!(root.components.is_empty());
+        let result = generate(&root, CodegenOptions::default());
+        assert!(
+            !result.code.contains("_resolveComponent"),
+            "{}",
+            result.code
+        );
+        assert!(result.code.contains("\":id\": \"foo\""), "{}", result.code);
+    }
+
+    #[test]
+    fn test_comments_preserved() {
+        let allocator = Bump::new();
+        let (mut root, _) = parse(&allocator, r#"<div><!--the comment text--><span /></div>"#);
+        transform(&allocator, &mut root, TransformOptions::default(), None);
+
+        let result = generate(&root, CodegenOptions::default());
+        assert!(
+            result
+                .code
+                .contains("_createCommentVNode(\"the comment text\")"),
+            "{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_comments_stripped() {
+        let allocator = Bump::new();
+        let (mut root, _) = parse(&allocator, r#"<div><!--the comment text--><span /></div>"#);
+        let options = TransformOptions {
+            comments: Some(false),
+            ..Default::default()
+        };
+        transform(&allocator, &mut root, options, None);
+
+        let result = generate(&root, CodegenOptions::default());
+        assert!(
+            !result.code.contains("_createCommentVNode"),
+            "{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_comments_stripped_in_production_by_default() {
+        let allocator = Bump::new();
+        let (mut root, _) = parse(&allocator, r#"<div><!--the comment text--><span /></div>"#);
+        let options = TransformOptions {
+            is_prod: true,
+            ..Default::default()
+        };
+        transform(&allocator, &mut root, options, None);
+
+        let result = generate(&root, CodegenOptions::default());
+        assert!(
+            !result.code.contains("_createCommentVNode"),
+            "{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_nested_branch_removal_keeps_sibling_traversal() {
+        let allocator = Bump::new();
+        // The v-else inside the first div is removed at index 3, which must
+        // not leak into the root loop and skip the root's remaining children
+        let (mut root, _) = parse(
+            &allocator,
+            r#"<div><a /><a /><p v-if="a" /><p v-else /></div><span>{{ x }}</span><b>{{ y }}</b>"#,
+        );
+        let options = TransformOptions {
+            prefix_identifiers: true,
+            ..Default::default()
+        };
+        transform(&allocator, &mut root, options, None);
+
+        let result = generate(&root, CodegenOptions::default());
+        assert!(result.code.contains("_ctx.x"), "{}", result.code);
+        assert!(result.code.contains("_ctx.y"), "{}", result.code);
+    }
+
+    #[test]
+    fn test_comment_moved_into_branch_keeps_sibling_traversal() {
+        let allocator = Bump::new();
+        let (mut root, _) = parse(
+            &allocator,
+            r#"<p v-if="a" /><!-- one --><!-- two --><p v-else /><span>{{ x }}</span><b>{{ y }}</b>"#,
+        );
+        let options = TransformOptions {
+            prefix_identifiers: true,
+            ..Default::default()
+        };
+        transform(&allocator, &mut root, options, None);
+
+        assert_eq!(root.children.len(), 3);
+        let result = generate(&root, CodegenOptions::default());
+        assert!(result.code.contains("_ctx.x"), "{}", result.code);
+        assert!(result.code.contains("_ctx.y"), "{}", result.code);
+    }
+
+    #[test]
+    fn test_comment_between_v_if_branches() {
+        let allocator = Bump::new();
+        let (mut root, _) = parse(
+            &allocator,
+            r#"<div v-if="ok">a</div><!-- otherwise --><div v-else>b</div>"#,
+        );
+        transform(&allocator, &mut root, TransformOptions::default(), None);
+
+        assert_eq!(root.children.len(), 1);
+        match &root.children[0] {
+            crate::ast::TemplateChildNode::If(if_node) => {
+                assert_eq!(if_node.branches.len(), 2);
+                assert!(matches!(
+                    if_node.branches[1].children[0],
+                    crate::ast::TemplateChildNode::Comment(_)
+                ));
+            }
+            _ => panic!("Expected IfNode"),
+        }
+        let result = generate(&root, CodegenOptions::default());
+        assert!(
+            result.code.contains("_createCommentVNode(\" otherwise \")"),
+            "{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_stray_v_else_reports_error() {
+        use crate::errors::ErrorCode;
+
+        let allocator = Bump::new();
+        let source = r#"<span>a</span><div v-else></div>"#;
+        let (mut root, _) = parse(&allocator, source);
+        let errors = transform(&allocator, &mut root, TransformOptions::default(), None);
+
+        let error = errors
+            .iter()
+            .find(|e| e.code == ErrorCode::VElseNoAdjacentIf)
+            .expect("expected VElseNoAdjacentIf");
+        let loc = error.loc.as_ref().unwrap();
+        assert_eq!(loc.start.offset, 14);
+        assert_eq!(loc.end.offset, source.len() as u32);
+    }
+
+    #[test]
+    fn test_v_else_after_v_else_reports_error() {
+        use crate::errors::ErrorCode;
+
+        let allocator = Bump::new();
+        let (mut root, _) = parse(
+            &allocator,
+            r#"<div v-if="a"></div><div v-else></div><div v-else></div>"#,
+        );
+        let errors = transform(&allocator, &mut root, TransformOptions::default(), None);
+        assert!(errors
+            .iter()
+            .any(|e| e.code == ErrorCode::VElseNoAdjacentIf));
+    }
+
+    #[test]
+    fn test_v_if_v_else_pair_has_no_error() {
+        let allocator = Bump::new();
+        let (mut root, _) = parse(
+            &allocator,
+            r#"<div v-if="a"></div>
+<!-- between -->
+<div v-else-if="b"></div>
+<div v-else></div>"#,
+        );
+        let errors = transform(&allocator, &mut root, TransformOptions::default(), None);
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_unkeyed_v_for_is_a_dev_warning() {
+        use crate::errors::ErrorCode;
+
+        let source = r#"<ul><li v-for="item in items">{{ item }}</li></ul>"#;
+        let allocator = Bump::new();
+        let (mut root, _) = parse(&allocator, source);
+        let errors = transform(&allocator, &mut root, TransformOptions::default(), None);
+        assert!(errors.is_empty(), "{:?}", errors);
+        assert!(root
+            .warnings
+            .iter()
+            .any(|w| w.code == ErrorCode::VForMissingKey));
+
+        let (mut root, _) = parse(&allocator, source);
+        let options = TransformOptions {
+            is_prod: true,
+            ..Default::default()
+        };
+        transform(&allocator, &mut root, options, None);
+        assert!(root.warnings.is_empty(), "{:?}", root.warnings);
+    }
 }
@@ -194,16 +194,32 @@ pub fn transform_v_if<'a>(
         // Find previous v-if node and add branch to it
         let child_index = ctx.child_index;
 
+        // Span of the branch element, for error reporting
+        let branch_loc =
+            ctx.parent
+                .as_ref()
+                .and_then(|parent| match parent.children_mut().get(child_index) {
+                    Some(TemplateChildNode::Element(el)) => Some(el.loc.clone()),
+                    _ => None,
+                });
+
         // First, find the if node index
         let found_if_idx = if let Some(parent) = &ctx.parent {
             let children = parent.children_mut();
             let mut found = None;
 
-            // Look backwards for v-if node
+            // Look backwards for v-if node. A chain already closed by v-else
+            // cannot take further branches (X_V_ELSE_NO_ADJACENT_IF).
             for j in (0..child_index).rev() {
                 match &children[j] {
-                    TemplateChildNode::If(_) => {
-                        found = Some(j);
+                    TemplateChildNode::If(if_node) => {
+                        let closed = if_node
+                            .branches
+                            .last()
+                            .is_some_and(|branch| branch.condition.is_none());
+                        if !closed {
+                            found = Some(j);
+                        }
                         break;
                     }
                     TemplateChildNode::Comment(_) => continue,
@@ -217,6 +233,23 @@ pub fn transform_v_if<'a>(
         };
 
         if let Some(if_idx) = found_if_idx {
+            // Move comments between branches into this branch so they keep
+            // their position without breaking branch grouping
+            let mut comments = std::vec::Vec::new();
+            if let Some(parent) = &ctx.parent {
+                let children = parent.children_mut();
+                let mut j = if_idx + 1;
+                while j < ctx.child_index {
+                    if matches!(&children[j], TemplateChildNode::Comment(c) if c.directive.is_none())
+                    {
+                        comments.push(children.remove(j));
+                        ctx.child_index -= 1;
+                    } else {
+                        j += 1;
+                    }
+                }
+            }
+
             // Take current element
             let taken = ctx.take_current_node();
             let taken_node = taken?;
@@ -305,11 +338,12 @@ pub fn transform_v_if<'a>(
             };
 
             if has_key_collision {
-                ctx.on_error(ErrorCode::VIfSameKey, None);
+                ctx.on_error(ErrorCode::VIfSameKey, Some(element_loc.clone()));
             }
 
             // Create new branch
             let mut branch_children = Vec::new_in(allocator);
+            branch_children.extend(comments);
             branch_children.push(taken_node);
 
             let branch = IfBranchNode {
@@ -345,7 +379,7 @@ pub fn transform_v_if<'a>(
             // Remove the placeholder we left
             ctx.remove_node();
         } else {
-            ctx.on_error(ErrorCode::VElseNoAdjacentIf, None);
+            ctx.on_error(ErrorCode::VElseNoAdjacentIf, branch_loc);
         }
 
         None
@@ -369,8 +403,8 @@ pub fn transform_v_for<'a>(
     let taken = ctx.take_current_node();
     let taken_node = taken?;
 
-    let element_loc = match &taken_node {
-        TemplateChildNode::Element(el) => el.loc.clone(),
+    let (element_loc, keyed) = match &taken_node {
+        TemplateChildNode::Element(el) => (el.loc.clone(), crate::transforms::v_for::has_key(el)),
         _ => return None,
     };
 
@@ -378,6 +412,12 @@ pub fn transform_v_for<'a>(
     let (mut source, value_alias, key_alias, index_alias) =
         parse_v_for_expression(allocator, &exp.content, &exp.loc);
 
+    // Numeric ranges are stable fragments and never reorder, so only warn
+    // for list sources without a key
+    if !keyed && !crate::codegen::v_for::is_numeric_source(&source) {
+        ctx.on_warn(ErrorCode::VForMissingKey, Some(element_loc.clone()));
+    }
+
     // Process source expression with binding-aware identifier prefixing
     // This ensures imports and refs are correctly handled (e.g., _unref(PRESETS) instead of _ctx.PRESETS)
     if ctx.options.prefix_identifiers || ctx.options.is_ts {
@@ -481,7 +521,7 @@ fn parse_v_for_expression<'a>(
     // Parse aliases
     let (value, key, index) = if alias_str.starts_with('(') && alias_str.ends_with(')') {
         let inner = &alias_str[1..alias_str.len() - 1];
-        let aliases: std::vec::Vec<&str> = inner.split(',').map(|s| s.trim()).collect();
+        let aliases = crate::transforms::v_for::split_aliases(inner);
 
         let value = if !aliases.is_empty() && !aliases[0].is_empty() {
             Some(ExpressionNode::Simple(Box::new_in(
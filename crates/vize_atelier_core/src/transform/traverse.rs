@@ -2,7 +2,7 @@
 
 use crate::ast::*;
 
-use super::element::{transform_element, transform_interpolation};
+use super::element::{transform_element, transform_interpolation, transform_v_pre};
 use super::structural::{
     check_structural_directive, remove_structural_directive, transform_v_for, transform_v_if,
 };
@@ -22,11 +22,17 @@ pub fn traverse_children<'a>(ctx: &mut TransformContext<'a>, parent: ParentNode<
         traverse_node(ctx, &mut children[i]);
 
         if ctx.was_node_removed() {
-            // Node was removed, don't increment i
+            // Node was removed, don't increment i. Transforms may also remove
+            // preceding siblings, so resume from the adjusted index.
+            i = ctx.child_index;
         } else {
             i += 1;
         }
     }
+
+    // A removal among these children must not read as a removal of the
+    // node whose children they are
+    ctx.reset_node_removed();
 }
 
 /// Traverse a single node
@@ -39,6 +45,10 @@ pub fn traverse_node<'a>(ctx: &mut TransformContext<'a>, node: &mut TemplateChil
     // Apply node transforms based on node type
     match node {
         TemplateChildNode::Element(el) => {
+            // v-pre subtrees are left uncompiled: once normalized into static
+            // markup, the remaining transforms have nothing dynamic to process
+            transform_v_pre(ctx, el);
+
             // Check for structural directives first
             let structural_result = check_structural_directive(el);
 
@@ -19,8 +19,8 @@ pub mod v_slot;
 pub use hoist_static::*;
 pub use transform_element::*;
 pub use transform_expression::{
-    is_simple_identifier, prefix_identifiers_in_expression, process_expression,
-    process_inline_handler, strip_typescript_from_expression,
+    is_simple_identifier, prefix_identifiers_in_expression, prefix_identifiers_in_scope,
+    process_expression, process_inline_handler, strip_typescript_from_expression,
 };
 pub use transform_text::*;
 pub use v_bind::*;
@@ -2,7 +2,7 @@
 //!
 //! Hoists static nodes to reduce runtime overhead.
 
-use vize_carton::{Box, Bump, Vec};
+use vize_carton::{is_builtin_directive, Box, Bump, Vec};
 
 use crate::ast::*;
 use crate::transform::TransformContext;
@@ -192,6 +192,15 @@ fn hoist_static_inner<'a>(
                 // Cannot hoist, but check children recursively (not as root)
                 match &mut children[i] {
                     TemplateChildNode::Element(el) => {
+                        // The element is dynamic, but its static attributes can
+                        // still be shared across renders
+                        if el.tag_type == ElementType::Element {
+                            if has_static_props(el) {
+                                hoist_element_props(ctx, el, allocator);
+                            } else if has_partially_static_props(el) {
+                                hoist_static_subset(ctx, el, allocator);
+                            }
+                        }
                         hoist_static_inner(ctx, &mut el.children, false);
                     }
                     TemplateChildNode::If(if_node) => {
@@ -351,26 +360,76 @@ fn create_children_expression<'a>(
 }
 
 /// Check if an element has static props (all attributes, no dynamic bindings)
+///
+/// Directives that never contribute to the generated props object (`v-show`,
+/// `v-cloak` and custom directives) are allowed. Props mixing static attributes
+/// with bindings are handled by [`has_partially_static_props`].
 fn has_static_props(el: &ElementNode<'_>) -> bool {
-    if el.props.is_empty() {
-        return false;
-    }
+    let mut has_attribute = false;
 
     for prop in el.props.iter() {
         match prop {
-            PropNode::Directive(_) => {
-                return false;
+            PropNode::Directive(dir) => {
+                let renders_props = match dir.name.as_str() {
+                    "show" | "cloak" => false,
+                    name => is_builtin_directive(name),
+                };
+                if renders_props {
+                    return false;
+                }
             }
             PropNode::Attribute(attr) => {
                 // `ref` attribute must not be hoisted - it needs runtime resolution
                 if attr.name == "ref" {
                     return false;
                 }
+                has_attribute = true;
             }
         }
     }
 
-    true
+    has_attribute
+}
+
+/// Check if an element mixes static attributes with bindings that can be
+/// merged over them at runtime.
+///
+/// Only `v-bind`/`v-on` with a static argument qualify, besides the directives
+/// [`has_static_props`] allows; object spreads and structural directives
+/// depend on prop order or are generated elsewhere.
+fn has_partially_static_props(el: &ElementNode<'_>) -> bool {
+    let mut has_binding = false;
+
+    for prop in el.props.iter() {
+        if let PropNode::Directive(dir) = prop {
+            match dir.name.as_str() {
+                "bind" | "on" if matches!(&dir.arg, Some(ExpressionNode::Simple(arg)) if arg.is_static) => {
+                    has_binding = true
+                }
+                "show" | "cloak" => {}
+                name if is_builtin_directive(name) => return false,
+                _ => {}
+            }
+        }
+    }
+
+    has_binding && el.props.iter().any(|prop| is_hoistable_attribute(prop, el))
+}
+
+/// Whether a static attribute can move into the hoisted props object.
+///
+/// `ref` needs runtime resolution, and an attribute also bound dynamically
+/// (`class` with `:class`) is merged with its binding when generated.
+fn is_hoistable_attribute(prop: &PropNode<'_>, el: &ElementNode<'_>) -> bool {
+    let PropNode::Attribute(attr) = prop else {
+        return false;
+    };
+    attr.name != "ref"
+        && !el.props.iter().any(|other| {
+            matches!(other, PropNode::Directive(dir)
+                if dir.name == "bind"
+                    && matches!(&dir.arg, Some(ExpressionNode::Simple(arg)) if arg.content == attr.name))
+        })
 }
 
 /// Hoist the props of an element with static props
@@ -379,28 +438,74 @@ fn hoist_element_props<'a>(
     el: &mut ElementNode<'a>,
     allocator: &'a Bump,
 ) {
+    let attrs = el.props.iter().filter_map(|prop| match prop {
+        PropNode::Attribute(attr) => Some(&**attr),
+        PropNode::Directive(_) => None,
+    });
+    if let Some(index) = hoist_props_object(ctx, attrs, allocator) {
+        el.hoisted_props_index = Some(index);
+    }
+}
+
+/// Hoist the static attributes of an element that also has bindings.
+///
+/// The hoisted attributes are removed from the element; codegen merges the
+/// remaining props over the shared object.
+fn hoist_static_subset<'a>(
+    ctx: &mut TransformContext<'a>,
+    el: &mut ElementNode<'a>,
+    allocator: &'a Bump,
+) {
+    let hoistable: std::vec::Vec<bool> = el
+        .props
+        .iter()
+        .map(|prop| is_hoistable_attribute(prop, el))
+        .collect();
+    let attrs = el
+        .props
+        .iter()
+        .zip(&hoistable)
+        .filter_map(|(prop, &hoist)| match prop {
+            PropNode::Attribute(attr) if hoist => Some(&**attr),
+            _ => None,
+        });
+    let Some(index) = hoist_props_object(ctx, attrs, allocator) else {
+        return;
+    };
+
+    let mut hoisted = hoistable.into_iter();
+    el.props.retain(|_| !hoisted.next().unwrap_or(false));
+    el.hoisted_props_index = Some(index);
+}
+
+/// Hoist a props object built from static attributes.
+///
+/// Returns the 1-based index for `_hoisted_N`.
+fn hoist_props_object<'a, 'n>(
+    ctx: &mut TransformContext<'a>,
+    attrs: impl Iterator<Item = &'n AttributeNode>,
+    allocator: &'a Bump,
+) -> Option<usize> {
     // Build props object from element attributes
     let mut obj_props = Vec::new_in(allocator);
 
-    for prop in el.props.iter() {
-        if let PropNode::Attribute(attr) = prop {
-            let key = ExpressionNode::Simple(Box::new_in(
-                SimpleExpressionNode::new(attr.name.clone(), true, attr.loc.clone()),
-                allocator,
-            ));
-            let value_exp = if let Some(v) = &attr.value {
-                SimpleExpressionNode::new(v.content.clone(), true, v.loc.clone())
-            } else {
-                SimpleExpressionNode::new("true", true, attr.loc.clone())
-            };
-            let value = JsChildNode::SimpleExpression(Box::new_in(value_exp, allocator));
+    for attr in attrs {
+        let key = ExpressionNode::Simple(Box::new_in(
+            SimpleExpressionNode::new(attr.name.clone(), true, attr.loc.clone()),
+            allocator,
+        ));
+        let value_exp = if let Some(v) = &attr.value {
+            SimpleExpressionNode::new(v.content.clone(), true, v.loc.clone())
+        } else {
+            SimpleExpressionNode::new("true", true, attr.loc.clone())
+        };
+        let value = JsChildNode::SimpleExpression(Box::new_in(value_exp, allocator));
 
-            obj_props.push(Property {
-                key,
-                value,
-                loc: attr.loc.clone(),
-            });
-        }
+        obj_props.push(Property {
+            key,
+            value,
+            loc: attr.loc.clone(),
+        });
     }
 
     // Add scope_id attribute for scoped CSS if present
@@ -421,7 +526,7 @@ fn hoist_element_props<'a>(
     }
 
     if obj_props.is_empty() {
-        return;
+        return None;
     }
 
     // Create the object expression to hoist
@@ -431,10 +536,7 @@ fn hoist_element_props<'a>(
     };
 
     let js_node = JsChildNode::Object(Box::new_in(obj_expr, allocator));
-    let hoist_index = ctx.hoist(js_node);
-
-    // Mark the element as having hoisted props (1-based index for _hoisted_N)
-    el.hoisted_props_index = Some(hoist_index + 1);
+    Some(ctx.hoist(js_node) + 1)
 }
 
 /// Check if children should use a block
@@ -525,6 +627,114 @@ mod tests {
         assert!(!is_static_node(&root.children[0]));
     }
 
+    #[test]
+    fn test_hoist_static_props_of_dynamic_element() {
+        use crate::codegen::generate;
+        use crate::options::{CodegenOptions, TransformOptions};
+        use crate::transform::transform;
+
+        let allocator = Bump::new();
+        let (mut root, _) = parse(
+            &allocator,
+            r#"<div class="a" id="b"><span>{{ msg }}</span></div>"#,
+        );
+        let options = TransformOptions {
+            hoist_static: true,
+            ..Default::default()
+        };
+        transform(&allocator, &mut root, options, None);
+
+        let result = generate(&root, CodegenOptions::default());
+        let output = format!("{}\n{}", result.preamble, result.code);
+        assert!(
+            output.contains(r#"const _hoisted_1 = { class: "a", id: "b" }"#),
+            "{output}"
+        );
+        assert!(
+            result
+                .code
+                .contains(r#"_createElementBlock("div", _hoisted_1, "#),
+            "{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_static_subset_of_mixed_props_hoisted() {
+        use crate::codegen::generate;
+        use crate::options::{CodegenOptions, TransformOptions};
+        use crate::transform::transform;
+
+        let allocator = Bump::new();
+        let (mut root, _) = parse(
+            &allocator,
+            r#"<div class="a" v-show="visible"><p class="b" :class="c" title="t" :id="id"><i /></p></div>"#,
+        );
+        let options = TransformOptions {
+            hoist_static: true,
+            ..Default::default()
+        };
+        transform(&allocator, &mut root, options, None);
+
+        // `v-show` is not part of the props object, so the outer props hoist
+        let TemplateChildNode::Element(outer) = &root.children[0] else {
+            panic!("expected element");
+        };
+        assert_eq!(outer.hoisted_props_index, Some(1));
+        // The inner element binds `:id`, so only `title` hoists; the static
+        // class stays to be merged with `:class`
+        let TemplateChildNode::Element(inner) = &outer.children[0] else {
+            panic!("expected element");
+        };
+        assert_eq!(inner.hoisted_props_index, Some(2));
+        assert!(!inner
+            .props
+            .iter()
+            .any(|p| matches!(p, PropNode::Attribute(attr) if attr.name == "title")));
+        assert!(inner
+            .props
+            .iter()
+            .any(|p| matches!(p, PropNode::Attribute(attr) if attr.name == "class")));
+
+        let result = generate(&root, CodegenOptions::default());
+        let output = format!("{}\n{}", result.preamble, result.code);
+        assert!(
+            output.contains(r#"const _hoisted_2 = { title: "t" }"#),
+            "{output}"
+        );
+        assert!(
+            result.code.contains("_mergeProps(_hoisted_2, {"),
+            "{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_props_with_object_spread_not_hoisted() {
+        use crate::options::TransformOptions;
+        use crate::transform::transform;
+
+        let allocator = Bump::new();
+        let (mut root, _) = parse(
+            &allocator,
+            r#"<div><p title="t" v-bind="attrs"><i /></p></div>"#,
+        );
+        let options = TransformOptions {
+            hoist_static: true,
+            ..Default::default()
+        };
+        transform(&allocator, &mut root, options, None);
+
+        // Spread order decides which value wins, so nothing is hoisted
+        let TemplateChildNode::Element(outer) = &root.children[0] else {
+            panic!("expected element");
+        };
+        let TemplateChildNode::Element(inner) = &outer.children[0] else {
+            panic!("expected element");
+        };
+        assert_eq!(inner.hoisted_props_index, None);
+    }
+
     #[test]
     fn test_sibling_with_v_if() {
         let allocator = Bump::new();
@@ -16,8 +16,13 @@ pub fn resolve_element_type<'a>(
 
     // Check if it's a component
     if is_component(tag, el) {
-        ctx.helper(RuntimeHelper::ResolveComponent);
-        ctx.add_component(tag.clone());
+        if is_dynamic_component_tag(tag) {
+            // `<component is>` is resolved at runtime, not registered as an asset
+            ctx.helper(RuntimeHelper::ResolveDynamicComponent);
+        } else {
+            ctx.helper(RuntimeHelper::ResolveComponent);
+            ctx.add_component(tag.clone());
+        }
         ElementType::Component
     } else if tag == "slot" {
         ElementType::Slot
@@ -28,30 +33,26 @@ pub fn resolve_element_type<'a>(
     }
 }
 
+/// Check if tag is `<component>`, whose `is` selects what to render
+pub fn is_dynamic_component_tag(tag: &str) -> bool {
+    matches!(tag, "component" | "Component")
+}
+
 /// Check if tag is a component
-fn is_component(tag: &str, el: &ElementNode<'_>) -> bool {
+///
+/// `is` only makes `<component>` dynamic. On any other tag it is a
+/// customized built-in element (`<button is="my-button">`), which stays a
+/// native element and passes `is` through as an attribute.
+fn is_component(tag: &str, _el: &ElementNode<'_>) -> bool {
+    if is_dynamic_component_tag(tag) {
+        return true;
+    }
     // Components start with uppercase or contain -
     let first_char = tag.chars().next().unwrap_or('a');
     if first_char.is_uppercase() {
         return true;
     }
-    if tag.contains('-') {
-        return true;
-    }
-    // Check for is attribute
-    for prop in el.props.iter() {
-        if let PropNode::Directive(dir) = prop {
-            if dir.name == "is" {
-                return true;
-            }
-        }
-        if let PropNode::Attribute(attr) = prop {
-            if attr.name == "is" {
-                return true;
-            }
-        }
-    }
-    false
+    tag.contains('-')
 }
 
 /// Build element props for codegen
@@ -263,6 +264,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_resolve_is_attribute_type() {
+        let allocator = Bump::new();
+        let mut ctx = TransformContext::new(&allocator, "".into(), Default::default());
+
+        // Customized built-in element stays native
+        let (root, _) = parse(&allocator, r#"<button is="my-button"></button>"#);
+        if let TemplateChildNode::Element(el) = &root.children[0] {
+            assert_eq!(resolve_element_type(&mut ctx, el), ElementType::Element);
+        }
+        assert!(!ctx.has_helper(RuntimeHelper::ResolveComponent));
+
+        let (root, _) = parse(&allocator, r#"<component :is="view"></component>"#);
+        if let TemplateChildNode::Element(el) = &root.children[0] {
+            assert_eq!(resolve_element_type(&mut ctx, el), ElementType::Component);
+        }
+        assert!(ctx.has_helper(RuntimeHelper::ResolveDynamicComponent));
+        assert!(!ctx.has_helper(RuntimeHelper::ResolveComponent));
+    }
+
     #[test]
     fn test_resolve_component_type() {
         let allocator = Bump::new();
@@ -422,6 +422,59 @@ pub fn prefix_identifiers_in_expression(content: &str) -> std::string::String {
     }
 }
 
+/// Prefix identifiers in expression with _ctx. for codegen, leaving names
+/// bound by an enclosing template scope (`v-for` aliases, slot props) as is.
+///
+/// Shorthand properties are expanded, so `{ on }` becomes `{ on: _ctx.on }`.
+pub fn prefix_identifiers_in_scope(content: &str, scope: &[StdString]) -> std::string::String {
+    let allocator = OxcAllocator::default();
+    let source_type = SourceType::default().with_module(true);
+
+    let mut wrapped = String::with_capacity(content.len() + 2);
+    wrapped.push('(');
+    wrapped.push_str(content);
+    wrapped.push(')');
+    let Ok(expr) = Parser::new(&allocator, &wrapped, source_type).parse_expression() else {
+        return content.to_string();
+    };
+
+    let mut rewrites: Vec<(usize, usize, std::string::String)> = Vec::new();
+    let mut local_vars: FxHashSet<StdString> = scope.iter().cloned().collect();
+    collect_identifiers_for_prefix(&expr, &mut rewrites, &mut local_vars, content);
+
+    let mut shorthands = ShorthandCollector::default();
+    shorthands.visit_expression(&expr);
+
+    rewrites.sort_by(|a, b| b.0.cmp(&a.0));
+    let mut result = content.to_string();
+    for (start, end, replacement) in rewrites {
+        if start < result.len() && end <= result.len() {
+            if shorthands.starts.contains(&(start + 1)) {
+                let key = &content[start..end];
+                result.replace_range(start..end, &[key, ": ", &replacement].concat());
+            } else {
+                result.replace_range(start..end, &replacement);
+            }
+        }
+    }
+    result
+}
+
+/// Collects the start offsets of shorthand object properties (`{ a }`).
+#[derive(Default)]
+struct ShorthandCollector {
+    starts: FxHashSet<usize>,
+}
+
+impl Visit<'_> for ShorthandCollector {
+    fn visit_object_property(&mut self, prop: &oxc_ast_types::ObjectProperty<'_>) {
+        if prop.shorthand {
+            self.starts.insert(prop.span.start as usize);
+        }
+        walk_object_property(self, prop);
+    }
+}
+
 /// Collect identifiers that need _ctx. prefix
 fn collect_identifiers_for_prefix(
     expr: &oxc_ast::ast::Expression<'_>,
@@ -5,6 +5,7 @@
 use vize_carton::{String, Vec};
 
 use crate::ast::*;
+use crate::options::WhitespaceStrategy;
 use crate::transform::TransformContext;
 
 /// Transform text and interpolation children
@@ -57,9 +58,40 @@ pub fn transform_text_children(
     }
 }
 
+/// Apply the whitespace strategy to text nodes in a template tree.
+///
+/// - `Condense` is the parser's default handling, so nothing changes here.
+/// - `Preserve` keeps text verbatim, except that leading and trailing
+///   newlines of the root are trimmed.
+pub fn apply_whitespace_strategy(
+    children: &mut Vec<'_, TemplateChildNode<'_>>,
+    strategy: WhitespaceStrategy,
+) {
+    if strategy == WhitespaceStrategy::Preserve {
+        trim_root_newlines(children);
+    }
+}
+
+fn trim_root_newlines(children: &mut Vec<'_, TemplateChildNode<'_>>) {
+    let is_newline = |c: char| c == '\n' || c == '\r';
+
+    if let Some(TemplateChildNode::Text(text)) = children.first_mut() {
+        text.content = text.content.trim_start_matches(is_newline).into();
+        if text.content.is_empty() {
+            children.remove(0);
+        }
+    }
+    if let Some(TemplateChildNode::Text(text)) = children.last_mut() {
+        text.content = text.content.trim_end_matches(is_newline).into();
+        if text.content.is_empty() {
+            children.pop();
+        }
+    }
+}
+
 /// Check if text is all whitespace
 pub fn is_whitespace_only(text: &str) -> bool {
-    text.chars().all(|c| c.is_whitespace())
+    text.chars().all(|c| c.is_ascii_whitespace())
 }
 
 /// Check if text is condensible whitespace
@@ -74,7 +106,7 @@ pub fn condense_whitespace(text: &str) -> String {
     let mut prev_was_space = false;
 
     for c in text.chars() {
-        if c.is_whitespace() {
+        if c.is_ascii_whitespace() {
             if !prev_was_space {
                 result.push(' ');
                 prev_was_space = true;
@@ -179,6 +211,8 @@ mod tests {
         assert!(is_whitespace_only("\n\t"));
         assert!(!is_whitespace_only("hello"));
         assert!(!is_whitespace_only(" hello "));
+        // NBSP (`&nbsp;`) is content, not condensible whitespace
+        assert!(!is_whitespace_only("\u{a0}"));
     }
 
     #[test]
@@ -195,4 +229,56 @@ mod tests {
         let dynamic_part = TextPart::Dynamic("msg".into());
         assert_eq!(dynamic_part.to_code(), "_toDisplayString(msg)");
     }
+
+    fn compile(source: &str, whitespace: WhitespaceStrategy) -> std::string::String {
+        use crate::codegen::generate;
+        use crate::options::{CodegenOptions, ParserOptions, TransformOptions};
+        use crate::transform::transform;
+
+        let allocator = vize_carton::Bump::new();
+        let parser_options = ParserOptions {
+            whitespace,
+            ..Default::default()
+        };
+        let (mut root, _) = crate::parse_with_options(&allocator, source, parser_options);
+        let options = TransformOptions {
+            whitespace,
+            ..Default::default()
+        };
+        transform(&allocator, &mut root, options, None);
+        generate(&root, CodegenOptions::default()).code.to_string()
+    }
+
+    #[test]
+    fn test_whitespace_condense() {
+        // Condense keeps the default output: indentation between elements is dropped
+        let code = compile(
+            "<div>\n  <span> a  b </span>\n</div>",
+            WhitespaceStrategy::Condense,
+        );
+        assert!(code.contains("\" a  b \""), "{}", code);
+        assert!(!code.contains("_createTextVNode"), "{}", code);
+    }
+
+    #[test]
+    fn test_whitespace_preserve() {
+        let code = compile(
+            "<div>\n  <span> a  b </span>\n</div>",
+            WhitespaceStrategy::Preserve,
+        );
+        assert!(code.contains("\" a  b \""), "{}", code);
+        assert!(code.contains("_createTextVNode"), "{}", code);
+    }
+
+    #[test]
+    fn test_whitespace_preserve_trims_root_newlines() {
+        let code = compile("\n<pre>x</pre>\n", WhitespaceStrategy::Preserve);
+        assert!(!code.contains("_createTextVNode"), "{}", code);
+    }
+
+    #[test]
+    fn test_whitespace_condense_skips_pre() {
+        let code = compile("<pre> a  b </pre>", WhitespaceStrategy::Condense);
+        assert!(code.contains("\" a  b \""), "{}", code);
+    }
 }
@@ -26,6 +26,17 @@ pub fn get_for_expression<'a>(el: &'a ElementNode<'a>) -> Option<&'a ExpressionN
     None
 }
 
+/// Check if a v-for element is keyed, by `:key` or a static `key` attribute
+pub fn has_key(el: &ElementNode<'_>) -> bool {
+    el.props.iter().any(|prop| match prop {
+        PropNode::Attribute(attr) => attr.name == "key",
+        PropNode::Directive(dir) => {
+            dir.name == "bind"
+                && matches!(&dir.arg, Some(ExpressionNode::Simple(arg)) if arg.content == "key")
+        }
+    })
+}
+
 /// Remove v-for directive from element props
 pub fn remove_for_directive(el: &mut ElementNode<'_>) {
     let mut i = 0;
@@ -75,7 +86,7 @@ pub fn parse_for_expression<'a>(
 
     let (value, key, index) = if alias_str.starts_with('(') && alias_str.ends_with(')') {
         let inner = &alias_str[1..alias_str.len() - 1];
-        let aliases: Vec<&str> = inner.split(',').map(|s| s.trim()).collect();
+        let aliases = split_aliases(inner);
 
         let value = if !aliases.is_empty() && !aliases[0].is_empty() {
             Some(ExpressionNode::Simple(Box::new_in(
@@ -122,6 +133,27 @@ pub fn parse_for_expression<'a>(
     }
 }
 
+/// Split `value, key, index` at top-level commas, keeping destructuring
+/// patterns (`{ id, name }, index`) intact
+pub(crate) fn split_aliases(inner: &str) -> Vec<&str> {
+    let mut aliases = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '{' | '[' | '(' => depth += 1,
+            '}' | ']' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                aliases.push(inner[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    aliases.push(inner[start..].trim());
+    aliases
+}
+
 /// Process v-for structural directive - adds helpers
 pub fn process_v_for(ctx: &mut TransformContext<'_>) {
     ctx.helper(RuntimeHelper::RenderList);
@@ -156,6 +188,25 @@ mod tests {
         assert!(result.value.is_some());
     }
 
+    #[test]
+    fn test_parse_for_destructured_value() {
+        let allocator = Bump::new();
+        let result = parse_for_expression(
+            &allocator,
+            "({ id, name }, index) in items",
+            &SourceLocation::STUB,
+        );
+
+        let Some(ExpressionNode::Simple(value)) = &result.value else {
+            panic!("expected a value alias");
+        };
+        assert_eq!(value.content.as_str(), "{ id, name }");
+        let Some(ExpressionNode::Simple(key)) = &result.key else {
+            panic!("expected a key alias");
+        };
+        assert_eq!(key.content.as_str(), "index");
+    }
+
     #[test]
     fn test_parse_for_with_index() {
         let allocator = Bump::new();
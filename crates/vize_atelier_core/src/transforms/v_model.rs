@@ -103,6 +103,7 @@ pub fn transform_v_model<'a>(
                 ))),
                 modifiers: Vec::new_in(allocator),
                 for_parse_result: None,
+                model_expansion: true,
                 loc: dir.loc.clone(),
             },
             allocator,
@@ -133,6 +134,7 @@ pub fn transform_v_model<'a>(
                 ))),
                 modifiers: Vec::new_in(allocator),
                 for_parse_result: None,
+                model_expansion: true,
                 loc: dir.loc.clone(),
             },
             allocator,
@@ -160,6 +162,7 @@ pub fn transform_v_model<'a>(
                 ))),
                 modifiers: Vec::new_in(allocator),
                 for_parse_result: None,
+                model_expansion: true,
                 loc: dir.loc.clone(),
             },
             allocator,
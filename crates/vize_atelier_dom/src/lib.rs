@@ -52,6 +52,7 @@ pub fn compile_template_with_options<'a>(
         is_pre_tag: |tag| tag == "pre",
         get_namespace,
         comments: options.comments,
+        whitespace: options.whitespace,
         ..ParserOptions::default()
     };
 
@@ -63,6 +64,7 @@ pub fn compile_template_with_options<'a>(
             code: String::new(),
             preamble: String::new(),
             map: None,
+            mappings: Vec::new(),
         };
         return (root, errors.to_vec(), codegen_result);
     }
@@ -77,12 +79,16 @@ pub fn compile_template_with_options<'a>(
         ssr: options.ssr,
         is_ts: options.is_ts,
         inline: options.inline,
+        whitespace: options.whitespace,
+        comments: Some(options.comments),
         binding_metadata: options.binding_metadata.clone(),
+        is_prod: options.is_prod,
         ..Default::default()
     };
     // Allocate Croquis in the arena so it shares the allocator lifetime
     let analysis: Option<&Croquis> = options.croquis.map(|c| &*allocator.alloc(*c));
-    do_transform(allocator, &mut root, transform_opts, analysis);
+    let mut errors = errors.to_vec();
+    errors.extend(do_transform(allocator, &mut root, transform_opts, analysis));
 
     // Codegen
     let codegen_opts = CodegenOptions {
@@ -94,11 +100,12 @@ pub fn compile_template_with_options<'a>(
         inline: options.inline,
         cache_handlers: options.cache_handlers,
         binding_metadata: options.binding_metadata,
+        runtime_module_name: options.runtime_module_name,
         ..Default::default()
     };
     let codegen_result = generate(&root, codegen_opts);
 
-    (root, errors.to_vec(), codegen_result)
+    (root, errors, codegen_result)
 }
 
 /// Get the namespace for an element based on its parent
@@ -131,6 +138,20 @@ mod tests {
     use super::*;
     use vize_atelier_core::options::CodegenMode;
 
+    #[test]
+    fn test_compile_reports_transform_errors() {
+        let allocator = Bump::new();
+        let (_, errors, _) = compile_template(&allocator, "<span>a</span><div v-else>b</div>");
+
+        assert!(
+            errors
+                .iter()
+                .any(|e| e.code == vize_atelier_core::ErrorCode::VElseNoAdjacentIf),
+            "{:?}",
+            errors
+        );
+    }
+
     #[test]
     fn test_compile_simple_element() {
         let allocator = Bump::new();
@@ -171,4 +192,91 @@ mod tests {
         // Empty div generates minimal code
         assert!(!result.code.is_empty());
     }
+
+    #[test]
+    fn test_compile_with_runtime_module_name() {
+        let allocator = Bump::new();
+        let opts = DomCompilerOptions {
+            mode: CodegenMode::Module,
+            runtime_module_name: "@my/runtime".into(),
+            ..Default::default()
+        };
+        let (_, errors, result) =
+            compile_template_with_options(&allocator, "<div>{{ msg }}</div>", opts);
+
+        assert!(errors.is_empty());
+        assert!(
+            result.preamble.contains("from \"@my/runtime\""),
+            "{}",
+            result.preamble
+        );
+        assert!(!result.preamble.contains("from \"vue\""));
+    }
+
+    #[test]
+    fn test_compile_dynamic_component_is() {
+        let allocator = Bump::new();
+        let (_, errors, result) =
+            compile_template(&allocator, r#"<component :is="view" msg="hi" />"#);
+
+        assert!(errors.is_empty());
+        let full_output = format!("{}\n{}", result.preamble, result.code);
+        assert!(
+            full_output.contains("_resolveDynamicComponent(") && full_output.contains("view)"),
+            "{}",
+            full_output
+        );
+        assert!(
+            !full_output.contains("_resolveComponent("),
+            "{}",
+            full_output
+        );
+        assert!(!full_output.contains("is:"), "{}", full_output);
+    }
+
+    #[test]
+    fn test_compile_customized_builtin_is() {
+        let allocator = Bump::new();
+        let (root, errors, result) =
+            compile_template(&allocator, r#"<button is="my-button">Go</button>"#);
+
+        assert!(errors.is_empty());
+        if let TemplateChildNode::Element(el) = &root.children[0] {
+            assert_eq!(el.tag_type, ast::ElementType::Element);
+        }
+        let full_output = format!("{}\n{}", result.preamble, result.code);
+        assert!(
+            full_output.contains("_createElementBlock(\"button\""),
+            "{}",
+            full_output
+        );
+        assert!(full_output.contains("is: \"my-button\""), "{}", full_output);
+        assert!(
+            !full_output.contains("resolveDynamicComponent"),
+            "{}",
+            full_output
+        );
+        assert!(!full_output.contains("resolveComponent"), "{}", full_output);
+    }
+
+    #[test]
+    fn test_compile_imports_are_deterministic() {
+        let source = r#"<div v-if="ok" :class="cls" @click="onClick"><Comp v-for="i in items" :key="i" /><span v-show="visible">{{ msg }}</span><input v-model="text"></div>"#;
+        let preamble = || {
+            let allocator = Bump::new();
+            let options = DomCompilerOptions {
+                mode: CodegenMode::Module,
+                ..Default::default()
+            };
+            let (_, errors, result) = compile_template_with_options(&allocator, source, options);
+            assert!(errors.is_empty());
+            result.preamble
+        };
+
+        let first = preamble();
+        assert!(first.contains("import {"), "{}", first);
+        for _ in 0..8 {
+            assert_eq!(preamble(), first);
+        }
+    }
 }
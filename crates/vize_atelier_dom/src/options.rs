@@ -1,7 +1,7 @@
 //! DOM compiler options.
 
 use serde::{Deserialize, Serialize};
-use vize_atelier_core::options::{BindingMetadata, CodegenMode};
+use vize_atelier_core::options::{BindingMetadata, CodegenMode, WhitespaceStrategy};
 use vize_carton::String;
 use vize_croquis::Croquis;
 
@@ -45,6 +45,10 @@ pub struct DomCompilerOptions {
     #[serde(default)]
     pub inline: bool,
 
+    /// Whitespace handling strategy
+    #[serde(default)]
+    pub whitespace: WhitespaceStrategy,
+
     /// Binding metadata from script setup
     #[serde(skip)]
     pub binding_metadata: Option<BindingMetadata>,
@@ -53,11 +57,23 @@ pub struct DomCompilerOptions {
     #[serde(default)]
     pub is_ts: bool,
 
+    /// Production mode: dev-only warnings are skipped
+    #[serde(default)]
+    pub is_prod: bool,
+
+    /// Module generated imports are taken from
+    #[serde(default = "default_runtime_module_name")]
+    pub runtime_module_name: String,
+
     /// Semantic analysis data from Croquis (optional, enhances transforms)
     #[serde(skip)]
     pub croquis: Option<Box<Croquis>>,
 }
 
+fn default_runtime_module_name() -> String {
+    String::from("vue")
+}
+
 impl Clone for DomCompilerOptions {
     fn clone(&self) -> Self {
         Self {
@@ -70,8 +86,11 @@ impl Clone for DomCompilerOptions {
             source_map: self.source_map,
             comments: self.comments,
             inline: self.inline,
+            whitespace: self.whitespace,
             binding_metadata: self.binding_metadata.clone(),
             is_ts: self.is_ts,
+            is_prod: self.is_prod,
+            runtime_module_name: self.runtime_module_name.clone(),
             // Croquis is not cloneable; it will be consumed when passed to the compiler
             croquis: None,
         }
@@ -90,8 +109,11 @@ impl Default for DomCompilerOptions {
             source_map: false,
             comments: false,
             inline: false,
+            whitespace: WhitespaceStrategy::Condense,
             binding_metadata: None,
             is_ts: false,
+            is_prod: false,
+            runtime_module_name: default_runtime_module_name(),
             croquis: None,
         }
     }
@@ -9,8 +9,12 @@ use crate::compile_template::{
     compile_template_block, compile_template_block_vapor, extract_template_parts,
     extract_template_parts_full,
 };
-use crate::rewrite_default::rewrite_default;
+use crate::rewrite_default::rewrite_default_mapped;
 use crate::script::ScriptCompileContext;
+use crate::source_map::{
+    copied_script_lines, decode_json_mappings, generate_sfc_source_map, script_line_offsets,
+    MappedString, SfcOrigins,
+};
 use crate::types::*;
 
 // Re-export ScriptCompileResult for public API
@@ -27,17 +31,26 @@ pub fn compile_sfc(
     let mut css = None;
 
     let filename = options.script.id.as_deref().unwrap_or("anonymous.vue");
+    // Name of the original source in emitted source maps
+    let map_filename = if !descriptor.filename.is_empty() {
+        descriptor.filename.to_string()
+    } else if !options.parse.filename.is_empty() {
+        options.parse.filename.clone()
+    } else {
+        filename.to_string()
+    };
 
     // Generate scope ID from filename
     let scope_id = generate_scope_id(filename);
     let has_scoped = descriptor.styles.iter().any(|s| s.scoped);
 
-    // Detect vapor mode from script attrs
-    let is_vapor = descriptor
-        .script_setup
-        .as_ref()
-        .map(|s| s.attrs.contains_key("vapor"))
-        .unwrap_or(false)
+    // Detect vapor mode from options or script attrs
+    let is_vapor = options.template.vapor
+        || descriptor
+            .script_setup
+            .as_ref()
+            .map(|s| s.attrs.contains_key("vapor"))
+            .unwrap_or(false)
         || descriptor
             .script
             .as_ref()
@@ -78,6 +91,7 @@ pub fn compile_sfc(
         let mut template_opts = options.template.clone();
         let mut dom_opts = template_opts.compiler_options.take().unwrap_or_default();
         dom_opts.hoist_static = true;
+        dom_opts.source_map = options.parse.source_map;
         template_opts.compiler_options = Some(dom_opts);
         let template_result = compile_template_block(
             template,
@@ -87,13 +101,24 @@ pub fn compile_sfc(
             is_ts,
             None,
             None,
+            &mut warnings,
         );
+        let mut origins = SfcOrigins::default();
 
         match template_result {
-            Ok(template_code) => {
+            Ok(compiled) => {
+                let template_code = compiled.code;
                 // Wrap template-only SFC in a proper component with export default.
                 // Convert "export function render(" to "function render(" and add component wrapper.
                 let wrapped = template_code.replace("export function render(", "function render(");
+                // Every expression follows the render signature, so they all move back
+                // by the removed `export `
+                let removed = template_code.len() - wrapped.len();
+                origins.template = compiled
+                    .expression_offsets
+                    .iter()
+                    .map(|&(offset, source)| (offset.saturating_sub(removed), source))
+                    .collect();
                 let mut output = String::with_capacity(wrapped.len() + 128);
                 output.push_str(&wrapped);
                 output.push_str("\nconst _sfc_main = {};\n");
@@ -104,16 +129,29 @@ pub fn compile_sfc(
             Err(e) => errors.push(e),
         }
 
+        compile_custom_blocks(
+            &descriptor.custom_blocks,
+            &options.custom_block_transforms,
+            &mut code,
+            &mut errors,
+        );
+
         // Compile styles
         let all_css = compile_styles(&descriptor.styles, &scope_id, &options.style, &mut warnings);
         if !all_css.is_empty() {
             css = Some(all_css);
         }
 
+        let map = options
+            .parse
+            .source_map
+            .then(|| generate_sfc_source_map(descriptor, &code, &origins, &map_filename))
+            .flatten();
+
         return Ok(SfcCompileResult {
             code,
             css,
-            map: None,
+            map,
             errors,
             warnings,
             bindings: None,
@@ -133,10 +171,22 @@ pub fn compile_sfc(
         // Rewrite `export default` to `const _sfc_main = ...`
         // Parse as TypeScript if source is TypeScript
         let (rewritten_script, _has_default) =
-            rewrite_default(&script.content, "_sfc_main", source_is_ts);
+            rewrite_default_mapped(&script.content, "_sfc_main", source_is_ts);
+        let mut origins = SfcOrigins {
+            script_lines: copied_script_lines(script, &rewritten_script),
+            ..Default::default()
+        };
+        let rewritten_script = rewritten_script.into_string();
 
         // Transpile TypeScript to JavaScript if needed
-        let final_script = if source_is_ts && !is_ts {
+        let mut transpile_map = None;
+        let final_script = if source_is_ts && !is_ts && options.parse.source_map {
+            let (js, map) = crate::compile_script::typescript::transform_typescript_to_js_with_map(
+                &rewritten_script,
+            );
+            transpile_map = map;
+            js
+        } else if source_is_ts && !is_ts {
             crate::compile_script::typescript::transform_typescript_to_js(&rewritten_script)
         } else {
             rewritten_script
@@ -148,6 +198,7 @@ pub fn compile_sfc(
             let mut template_opts = options.template.clone();
             let mut dom_opts = template_opts.compiler_options.take().unwrap_or_default();
             dom_opts.hoist_static = true;
+            dom_opts.source_map = options.parse.source_map;
             template_opts.compiler_options = Some(dom_opts);
 
             let template_result = compile_template_block(
@@ -158,19 +209,21 @@ pub fn compile_sfc(
                 is_ts,
                 None, // No bindings for normal scripts
                 None, // No Croquis for normal scripts
+                &mut warnings,
             );
 
             match template_result {
-                Ok(template_code) => {
+                Ok(compiled) => {
                     // Extract template parts (imports, hoisted, render function)
-                    let (template_imports, template_hoisted, render_fn) =
-                        extract_template_parts_full(&template_code);
+                    let (template_imports, template_hoisted, render_fn, template_offsets) =
+                        extract_template_parts_full(&compiled);
 
                     // Build output: imports + script + hoisted + render + export
                     code.push_str(&template_imports);
                     if !template_imports.is_empty() {
                         code.push('\n');
                     }
+                    origins.script_line = code.matches('\n').count() as u32;
                     code.push_str(&final_script);
                     code.push('\n');
 
@@ -181,6 +234,11 @@ pub fn compile_sfc(
                     }
 
                     // Add render function (without imports - they're already at top)
+                    let render_start = code.len();
+                    origins.template = template_offsets
+                        .iter()
+                        .map(|&(offset, source)| (render_start + offset, source))
+                        .collect();
                     code.push_str(&render_fn);
                     code.push('\n');
 
@@ -193,24 +251,47 @@ pub fn compile_sfc(
                     // Fall back to just the script
                     code = script.content.to_string();
                     code.push('\n');
+                    transpile_map = None;
+                    origins.script_lines = script_line_offsets(script)
+                        .into_iter()
+                        .enumerate()
+                        .filter_map(|(line, offset)| Some((line as u32, offset?)))
+                        .collect();
                 }
             }
         } else {
             // No template - just output rewritten script and export
+            origins.script_line = code.matches('\n').count() as u32;
             code.push_str(&final_script);
             code.push_str("\nexport default _sfc_main\n");
         }
 
+        compile_custom_blocks(
+            &descriptor.custom_blocks,
+            &options.custom_block_transforms,
+            &mut code,
+            &mut errors,
+        );
+
         // Compile styles
         let all_css = compile_styles(&descriptor.styles, &scope_id, &options.style, &mut warnings);
         if !all_css.is_empty() {
             css = Some(all_css);
         }
 
+        let map = options
+            .parse
+            .source_map
+            .then(|| {
+                origins.transpiled = transpile_map.as_ref();
+                generate_sfc_source_map(descriptor, &code, &origins, &map_filename)
+            })
+            .flatten();
+
         return Ok(SfcCompileResult {
             code,
             css,
-            map: None,
+            map,
             errors,
             warnings,
             bindings: None,
@@ -235,20 +316,17 @@ pub fn compile_sfc(
     // Extract normal script content if present (for type definitions, imports, etc.)
     // When both <script> and <script setup> exist, normal script content should be preserved
     // (except for export default which is handled by script setup)
-    let normal_script_content = if has_script {
+    let (normal_script_content, normal_script_lines) = if has_script {
         let script = descriptor.script.as_ref().unwrap();
         // Check if source is TypeScript
         let source_is_ts = script
             .lang
             .as_ref()
             .is_some_and(|l| l == "ts" || l == "tsx");
-        Some(extract_normal_script_content(
-            &script.content,
-            source_is_ts,
-            is_ts,
-        ))
+        let (content, lines) = extract_normal_script_content(&script.content, source_is_ts, is_ts);
+        (Some(content), lines)
     } else {
-        None
+        (None, Vec::new())
     };
 
     // 1. Croquis parser: rich analysis with ReactivityTracker
@@ -302,32 +380,55 @@ pub fn compile_sfc(
     let template_result = if let Some(template) = &descriptor.template {
         if is_vapor {
             Some(compile_template_block_vapor(
-                template, &scope_id, has_scoped,
+                template,
+                &options.template,
+                &scope_id,
+                has_scoped,
+                Some(&script_bindings),
+                Some(croquis),
+                &mut warnings,
+                options.parse.source_map,
             ))
         } else {
+            let mut template_opts = options.template.clone();
+            let mut dom_opts = template_opts.compiler_options.take().unwrap_or_default();
+            dom_opts.source_map = options.parse.source_map;
+            template_opts.compiler_options = Some(dom_opts);
             Some(compile_template_block(
                 template,
-                &options.template,
+                &template_opts,
                 &scope_id,
                 has_scoped,
                 is_ts,
                 Some(&script_bindings), // Pass bindings for proper ref handling
                 Some(croquis),          // Pass Croquis for enhanced transforms
+                &mut warnings,
             ))
         }
     } else {
         None
     };
 
+    // SSR output is a standalone `ssrRender` function reading bindings through
+    // `_ctx`, so setup returns its bindings instead of an inline render
+    let mut ssr_render = None;
+    let template_result = match template_result {
+        Some(Ok(compiled)) if is_vapor && options.template.ssr => {
+            ssr_render = Some(compiled.code);
+            None
+        }
+        result => result,
+    };
+
     // Extract template parts for inline mode (imports, hoisted, preamble, render_body)
-    let (template_imports, template_hoisted, template_preamble, render_body) =
+    let (template_imports, template_hoisted, template_preamble, render_body, template_offsets) =
         match &template_result {
-            Some(Ok(template_code)) => extract_template_parts(template_code),
+            Some(Ok(compiled)) => extract_template_parts(compiled),
             Some(Err(e)) => {
                 errors.push(e.clone());
-                (String::new(), String::new(), String::new(), String::new())
+                Default::default()
             }
-            None => (String::new(), String::new(), String::new(), String::new()),
+            None => Default::default(),
         };
 
     // Compile script setup using inline mode to match Vue's @vue/compiler-sfc output format:
@@ -351,30 +452,126 @@ pub fn compile_sfc(
             hoisted: &template_hoisted,
             preamble: &template_preamble,
             render_body: &render_body,
+            render_offsets: &template_offsets,
         },
         normal_script_content.as_deref(),
+        options.parse.source_map,
+        options.template.runtime_module_name(),
     )?;
 
     // The inline mode compile_script_setup_inline generates a complete output
     // including imports, hoisted vars, and `export default { ... }` with inline render
+    let script_line = code.matches('\n').count() as u32;
+    let script_start = code.len();
     code.push_str(&script_result.code);
 
+    if let Some(render) = ssr_render {
+        if let Some(export) = code[script_start..].find("export default ") {
+            let export = script_start + export;
+            code.replace_range(
+                export..export + "export default ".len(),
+                "const _sfc_main = ",
+            );
+            code.push('\n');
+            code.push_str(&render);
+            code.push_str("_sfc_main.ssrRender = ssrRender\n");
+            code.push_str("export default _sfc_main\n");
+        }
+    }
+
+    compile_custom_blocks(
+        &descriptor.custom_blocks,
+        &options.custom_block_transforms,
+        &mut code,
+        &mut errors,
+    );
+
     // Compile styles
     let all_css = compile_styles(&descriptor.styles, &scope_id, &options.style, &mut warnings);
     if !all_css.is_empty() {
         css = Some(all_css);
     }
 
+    let map = options
+        .parse
+        .source_map
+        .then(|| {
+            let line_offsets = script_line_offsets(script_setup);
+            let mut script_lines: Vec<(u32, usize)> = script_result
+                .setup_lines
+                .iter()
+                .filter_map(|&(line, origin)| Some((line, (*line_offsets.get(origin as usize)?)?)))
+                .collect();
+            if let (Some(script), Some(start)) =
+                (descriptor.script.as_ref(), script_result.normal_script_line)
+            {
+                let normal_offsets = script_line_offsets(script);
+                script_lines.extend(normal_script_lines.iter().filter_map(|&(line, origin)| {
+                    Some((start + line, (*normal_offsets.get(origin as usize)?)?))
+                }));
+                script_lines.sort_unstable();
+            }
+            let transpiled = script_result.transpile_map.as_ref();
+            let origins = SfcOrigins {
+                script_line,
+                transpiled,
+                script_lines,
+                // Without the transform the render function is copied as-is
+                template: match transpiled {
+                    Some(_) => script_result.template_offsets.clone(),
+                    None => script_result
+                        .template_offsets
+                        .iter()
+                        .map(|&(offset, source)| (script_start + offset, source))
+                        .collect(),
+                },
+                template_transpiled: transpiled.is_some(),
+            };
+            generate_sfc_source_map(descriptor, &code, &origins, &map_filename)
+        })
+        .flatten();
+
     Ok(SfcCompileResult {
         code,
         css,
-        map: None,
+        map,
         errors,
         warnings,
         bindings: script_result.bindings,
     })
 }
 
+/// Append the output of registered custom block handlers to the module code
+fn compile_custom_blocks(
+    blocks: &[SfcCustomBlock],
+    transforms: &vize_carton::FxHashMap<String, CustomBlockTransform>,
+    code: &mut String,
+    errors: &mut Vec<SfcError>,
+) {
+    for block in blocks {
+        let Some(transform) = transforms.get(block.block_type.as_ref()) else {
+            continue;
+        };
+        match transform(block) {
+            Ok(snippet) => {
+                if !code.is_empty() && !code.ends_with('\n') {
+                    code.push('\n');
+                }
+                code.push_str(&snippet);
+                if !snippet.ends_with('\n') {
+                    code.push('\n');
+                }
+            }
+            Err(mut e) => {
+                if e.loc.is_none() {
+                    e.loc = Some(block.loc.clone());
+                }
+                errors.push(e);
+            }
+        }
+    }
+}
+
 /// Helper to compile all style blocks
 fn compile_styles(
     styles: &[SfcStyleBlock],
@@ -439,10 +636,17 @@ fn extract_component_name(filename: &str) -> String {
 /// - `content`: The script content
 /// - `source_is_ts`: Whether the source script is TypeScript (has lang="ts")
 /// - `output_is_ts`: Whether to preserve TypeScript in output (false = transpile to JS)
-fn extract_normal_script_content(content: &str, source_is_ts: bool, output_is_ts: bool) -> String {
+///
+/// Also returns the `(output line, content line)` of each output line that
+/// comes from the script.
+fn extract_normal_script_content(
+    content: &str,
+    source_is_ts: bool,
+    output_is_ts: bool,
+) -> (String, Vec<(u32, u32)>) {
     use oxc_allocator::Allocator;
     use oxc_ast::ast::Statement;
-    use oxc_codegen::Codegen;
+    use oxc_codegen::{Codegen, CodegenOptions};
     use oxc_parser::Parser;
     use oxc_semantic::SemanticBuilder;
     use oxc_span::{GetSpan, SourceType};
@@ -460,15 +664,25 @@ fn extract_normal_script_content(content: &str, source_is_ts: bool, output_is_ts
 
     if !ret.errors.is_empty() {
         // If parsing fails, return original content minus any obvious export default
-        return content
-            .lines()
-            .filter(|line| !line.trim().starts_with("export default"))
-            .collect::<Vec<_>>()
-            .join("\n");
+        let mut output = MappedString::new();
+        let mut line_start = 0;
+        for line in content.split('\n') {
+            let start = line_start;
+            line_start += line.len() + 1;
+            if line.trim().starts_with("export default") {
+                continue;
+            }
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            output.push_copied(content, start..start + line.trim_end_matches('\r').len());
+        }
+        let lines = output.line_origins(content);
+        return (output.into_string(), lines);
     }
 
     let program = ret.program;
-    let mut output = String::new();
+    let mut output = MappedString::new();
     let mut last_end = 0;
 
     // Collect spans of statements to skip (export default declarations)
@@ -516,17 +730,25 @@ fn extract_normal_script_content(content: &str, source_is_ts: bool, output_is_ts
     modifications.sort_by_key(|m| m.0);
 
     for (start, end, replacement) in &modifications {
-        output.push_str(&content[last_end..*start as usize]);
+        output.push_copied(content, last_end..*start as usize);
         if let Some(repl) = replacement {
             output.push_str(repl);
         }
         last_end = *end as usize;
     }
     if last_end < content.len() {
-        output.push_str(&content[last_end..]);
+        output.push_copied(content, last_end..content.len());
     }
 
-    let extracted = output.trim().to_string();
+    let extracted = output.as_str().trim().to_string();
+    // Lines dropped by the trim shift every remaining line up
+    let leading = output.as_str().len() - output.as_str().trim_start().len();
+    let trimmed_lines = output.as_str()[..leading].matches('\n').count() as u32;
+    let extracted_lines: Vec<(u32, u32)> = output
+        .line_origins(content)
+        .into_iter()
+        .filter_map(|(line, origin)| Some((line.checked_sub(trimmed_lines)?, origin)))
+        .collect();
 
     // If source is TypeScript and we need JavaScript output, transpile
     if source_is_ts && !output_is_ts {
@@ -555,14 +777,37 @@ fn extract_normal_script_content(content: &str, source_is_ts: bool, output_is_ts
                         .build_with_scoping(scoping, &mut program2);
 
                 if transform_ret.errors.is_empty() {
-                    // Generate JavaScript code
-                    return Codegen::new().build(&program2).code;
+                    // Generate JavaScript code, following its map back to the extracted lines
+                    let options = CodegenOptions {
+                        source_map_path: Some(std::path::PathBuf::from("script.ts")),
+                        ..CodegenOptions::default()
+                    };
+                    let generated = Codegen::new().with_options(options).build(&program2);
+                    let mut lines: Vec<(u32, u32)> = Vec::new();
+                    if let Some(map) = generated.map {
+                        for mapping in decode_json_mappings(&map.to_json_string()) {
+                            if lines
+                                .last()
+                                .is_some_and(|&(line, _)| line == mapping.generated_line)
+                            {
+                                continue;
+                            }
+                            let origin = extracted_lines
+                                .iter()
+                                .find(|&&(line, _)| line == mapping.source_line)
+                                .map(|&(_, origin)| origin);
+                            if let Some(origin) = origin {
+                                lines.push((mapping.generated_line, origin));
+                            }
+                        }
+                    }
+                    return (generated.code, lines);
                 }
             }
         }
     }
 
-    extracted
+    (extracted, extracted_lines)
 }
 
 /// Convert Croquis BindingMetadata (CompactString keys) to legacy BindingMetadata (String keys)
@@ -1237,4 +1482,432 @@ export default {
             result.code
         );
     }
+
+    #[test]
+    fn test_custom_block_transform() {
+        fn i18n(block: &SfcCustomBlock<'_>) -> Result<String, SfcError> {
+            Ok(format!("export const __i18n = {}", block.content.trim()))
+        }
+
+        let source = r#"<script setup>
+const msg = 'hi'
+</script>
+
+<template>
+  <div>{{ msg }}</div>
+</template>
+
+<i18n>
+{ "en": { "hello": "Hello" } }
+</i18n>
+
+<docs>
+Not handled.
+</docs>"#;
+
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let mut opts = SfcCompileOptions::default();
+        opts.custom_block_transforms
+            .insert("i18n".to_string(), i18n as CustomBlockTransform);
+        let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let snippet = r#"export const __i18n = { "en": { "hello": "Hello" } }"#;
+        let render_at = result.code.find("_toDisplayString").unwrap();
+        let snippet_at = result
+            .code
+            .find(snippet)
+            .unwrap_or_else(|| panic!("Custom block output missing. Got:\n{}", result.code));
+        assert!(snippet_at > render_at, "{}", result.code);
+        assert!(!result.code.contains("Not handled."), "{}", result.code);
+    }
+
+    #[test]
+    fn test_custom_block_transform_error() {
+        fn fail(_: &SfcCustomBlock<'_>) -> Result<String, SfcError> {
+            Err(SfcError {
+                message: "invalid i18n block".to_string(),
+                code: None,
+                loc: None,
+            })
+        }
+
+        let source = "<template><div /></template>\n<i18n>{</i18n>";
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let mut opts = SfcCompileOptions::default();
+        opts.custom_block_transforms
+            .insert("i18n".to_string(), fail as CustomBlockTransform);
+        let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].message, "invalid i18n block");
+        assert!(result.errors[0].loc.is_some());
+    }
+
+    #[test]
+    fn test_props_destructure_rewrites_references() {
+        let source = r#"<script setup>
+import { computed } from 'vue'
+const { count = 1, label = 'none', title } = defineProps({ count: Number, label: String, title: String })
+const doubled = computed(() => count * 2)
+function echo(count) {
+  return count
+}
+</script>
+
+<template>
+  <div>{{ count }} {{ label }}</div>
+</template>"#;
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let result =
+            compile_sfc(&descriptor, SfcCompileOptions::default()).expect("Failed to compile SFC");
+
+        assert!(result.code.contains("__props.count * 2"), "{}", result.code);
+        // Shadowed by the parameter, so left alone
+        assert!(result.code.contains("return count"), "{}", result.code);
+        assert!(
+            result.code.contains("_mergeDefaults(")
+                && result.code.contains("  count: 1,\n  label: 'none'\n}"),
+            "{}",
+            result.code
+        );
+        assert!(result.code.contains("__props.label"), "{}", result.code);
+    }
+
+    #[test]
+    fn test_vapor_same_name_bind_without_binding_warns() {
+        let source = r#"<script setup vapor>
+const count = 1
+</script>
+
+<template>
+  <Foo :count :total />
+</template>"#;
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let result =
+            compile_sfc(&descriptor, SfcCompileOptions::default()).expect("Failed to compile SFC");
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let unresolved: Vec<_> = result
+            .warnings
+            .iter()
+            .filter(|w| w.code.as_deref() == Some("VAPOR_TEMPLATE_WARNING"))
+            .collect();
+        assert_eq!(unresolved.len(), 1, "{:?}", result.warnings);
+    }
+
+    #[test]
+    fn test_vapor_ssr_awaits_async_components() {
+        let source = r#"<script setup vapor>
+import { defineAsyncComponent } from 'vue'
+const LazyChart = defineAsyncComponent(() => import('./Chart.vue'))
+</script>
+
+<template>
+  <LazyChart />
+</template>"#;
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let mut opts = SfcCompileOptions::default();
+        opts.template.ssr = true;
+        let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(
+            result.code.contains("LazyChart.__asyncLoader().then(() =>"),
+            "{}",
+            result.code
+        );
+        assert!(
+            result.code.contains("_sfc_main.ssrRender = ssrRender"),
+            "{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_vapor_ssr_hydration_hints() {
+        let source = r#"<script setup vapor>
+const now = Date.now()
+const title = 'hello'
+</script>
+
+<template>
+  <div><time :datetime="now"></time><span :title="title"></span></div>
+</template>"#;
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let mut opts = SfcCompileOptions::default();
+        opts.template.ssr = true;
+        opts.template.ssr_hydration_hints = true;
+        let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(
+            result
+                .code
+                .contains("<time data-allow-mismatch=\"attribute\""),
+            "{}",
+            result.code
+        );
+        assert_eq!(
+            result.code.matches("data-allow-mismatch").count(),
+            1,
+            "{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_custom_runtime_module_name() {
+        let source = r#"<script setup lang="ts">
+const props = withDefaults(defineProps<{ msg?: string }>(), { msg: 'hi' })
+const model = defineModel<string>()
+</script>
+
+<template>
+  <input v-model="model" :placeholder="props.msg">
+</template>"#;
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let mut opts = SfcCompileOptions::default();
+        opts.template.compiler_options = Some(vize_atelier_dom::DomCompilerOptions {
+            runtime_module_name: "@my/runtime".into(),
+            ..Default::default()
+        });
+        let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(
+            result
+                .code
+                .contains("import { useModel as _useModel } from '@my/runtime'"),
+            "{}",
+            result.code
+        );
+        assert!(
+            result
+                .code
+                .lines()
+                .any(|l| l.contains("_defineComponent") && l.ends_with("from '@my/runtime'")),
+            "{}",
+            result.code
+        );
+        assert!(!result.code.contains("from 'vue'"), "{}", result.code);
+    }
+
+    #[test]
+    fn test_source_map_covers_script_and_template() {
+        use vize_atelier_vapor::source_map::decode_mappings;
+
+        let source = r#"<script setup lang="ts">
+const msg: string = 'hello'
+</script>
+
+<template>
+  <div>{{ msg }}</div>
+</template>"#;
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let mut opts = SfcCompileOptions::default();
+        opts.parse.source_map = true;
+        let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+        let map = result.map.expect("source map should be generated");
+        assert_eq!(map["version"], 3);
+        let mappings = decode_mappings(map["mappings"].as_str().unwrap());
+
+        // `msg` in the template: line 5, column 10
+        let in_template = mappings
+            .iter()
+            .find(|m| m.source_line == 5 && m.source_column == 10)
+            .expect("template expression should be mapped");
+        let line = result.code.lines().nth(in_template.generated_line as usize);
+        assert!(
+            line.is_some_and(|l| l[in_template.generated_column as usize..].starts_with("msg")),
+            "{}",
+            result.code
+        );
+
+        // `const msg` in the script setup block
+        assert!(
+            mappings
+                .iter()
+                .any(|m| m.source_line == 1 && m.source_column == 0),
+            "{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_source_map_keeps_repeated_lines_and_identifiers_apart() {
+        use vize_atelier_vapor::source_map::decode_mappings;
+
+        let source = r#"<script setup>
+const count = ref(0)
+console.log(count)
+console.log(count)
+</script>
+
+<template>
+  <p>{{ count }}</p>
+  <p>{{ count }}</p>
+</template>"#;
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let mut opts = SfcCompileOptions::default();
+        opts.parse.source_map = true;
+        let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+        let map = result.map.expect("source map should be generated");
+        let mappings = decode_mappings(map["mappings"].as_str().unwrap());
+        let lines: Vec<&str> = result.code.lines().collect();
+        let generated = |source_line: u32, source_column: u32| {
+            let found: Vec<_> = mappings
+                .iter()
+                .filter(|m| m.source_line == source_line && m.source_column == source_column)
+                .collect();
+            assert_eq!(found.len(), 1, "line {}:\n{}", source_line, result.code);
+            let m = found[0];
+            (
+                m.generated_line,
+                &lines[m.generated_line as usize][m.generated_column as usize..],
+            )
+        };
+
+        // Identical script lines map to their own output line, in order
+        let (first_log, first_text) = generated(2, 0);
+        let (second_log, second_text) = generated(3, 0);
+        assert!(first_text.starts_with("console.log(count)"));
+        assert!(second_text.starts_with("console.log(count)"));
+        assert!(first_log < second_log, "{}", result.code);
+
+        // Each `count` in the template maps to its own interpolation
+        let (_, first_count) = generated(7, 8);
+        let (_, second_count) = generated(8, 8);
+        assert!(first_count.contains("count"), "{}", first_count);
+        assert!(second_count.contains("count"), "{}", second_count);
+        assert_ne!(
+            generated(7, 8),
+            generated(8, 8),
+            "Repeated identifiers should not share a position"
+        );
+    }
+
+    /// Generated text at each mapping whose source is `(line, UTF-16 column)`
+    fn mapped_text<'c>(
+        code: &'c str,
+        map: &serde_json::Value,
+        line: u32,
+        column: u32,
+    ) -> Vec<&'c str> {
+        let lines: Vec<&str> = code.lines().collect();
+        vize_atelier_vapor::source_map::decode_mappings(map["mappings"].as_str().unwrap())
+            .into_iter()
+            .filter(|m| m.source_line == line && m.source_column == column)
+            .filter_map(|m| {
+                let text = lines.get(m.generated_line as usize)?;
+                let mut units = 0;
+                let start = text
+                    .char_indices()
+                    .find(|&(_, c)| {
+                        let reached = units >= m.generated_column as usize;
+                        units += c.len_utf16();
+                        reached
+                    })
+                    .map_or(text.len(), |(i, _)| i);
+                Some(&text[start..])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_source_map_columns_are_utf16() {
+        let source = "<script setup>\nconst msg = 'x'\n</script>\n\n<template>\n  <p title=\"🎉\">{{ msg }}</p>\n</template>";
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let mut opts = SfcCompileOptions::default();
+        opts.parse.source_map = true;
+        let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+        let map = result.map.expect("source map should be generated");
+
+        // `🎉` is two UTF-16 units, so `msg` sits at column 19
+        let texts = mapped_text(&result.code, &map, 5, 19);
+        assert!(
+            texts.iter().any(|text| text.starts_with("msg")),
+            "{:?}\n{}",
+            texts,
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_source_map_covers_normal_script_with_setup() {
+        let source = r#"<script lang="ts">
+export const shared: number = 1
+</script>
+
+<script setup lang="ts">
+const msg: string = 'x'
+</script>
+
+<template>
+  <p>{{ msg }} {{ shared }}</p>
+</template>"#;
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let mut opts = SfcCompileOptions::default();
+        opts.parse.source_map = true;
+        let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+        let map = result.map.expect("source map should be generated");
+
+        let texts = mapped_text(&result.code, &map, 1, 0);
+        assert!(
+            texts
+                .iter()
+                .any(|text| text.starts_with("export const shared")),
+            "{:?}\n{}",
+            texts,
+            result.code
+        );
+        let texts = mapped_text(&result.code, &map, 5, 0);
+        assert!(
+            texts.iter().any(|text| text.starts_with("const msg")),
+            "{:?}\n{}",
+            texts,
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_source_map_follows_props_destructure() {
+        let source = r#"<script setup>
+const { label } = defineProps(['label'])
+console.log(label)
+</script>
+
+<template>
+  <p>{{ label }}</p>
+</template>"#;
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        let mut opts = SfcCompileOptions::default();
+        opts.parse.source_map = true;
+        let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+        let map = result.map.expect("source map should be generated");
+
+        let texts = mapped_text(&result.code, &map, 2, 0);
+        assert!(
+            texts
+                .iter()
+                .any(|text| text.starts_with("console.log(__props.label)")),
+            "{:?}\n{}",
+            texts,
+            result.code
+        );
+    }
 }
@@ -33,6 +33,17 @@ pub use self::props::{
 pub struct ScriptCompileResult {
     pub code: String,
     pub bindings: Option<BindingMetadata>,
+    /// Map of the TypeScript transform, when types were stripped with source maps on
+    pub transpile_map: Option<typescript::TranspileMap>,
+    /// `(output line, script setup line)` of each line copied from `<script setup>`,
+    /// counted before the TypeScript transform
+    pub setup_lines: Vec<(u32, u32)>,
+    /// Output line the normal `<script>` content starts on, before the
+    /// TypeScript transform
+    pub normal_script_line: Option<u32>,
+    /// `(output offset, template offset)` of each template expression in the
+    /// inline render function, before the TypeScript transform
+    pub template_offsets: Vec<(usize, u32)>,
 }
 
 /// Template parts for inline compilation
@@ -42,6 +53,8 @@ pub struct TemplateParts<'a> {
     /// Component/directive resolution statements (inside render function, before return)
     pub preamble: &'a str,
     pub render_body: &'a str,
+    /// `(render_body offset, template offset)` of each template expression
+    pub render_offsets: &'a [(usize, u32)],
 }
 
 /// Compile script block(s)
@@ -62,6 +75,7 @@ pub fn compile_script(
             is_vapor,
             is_ts,
             template_content,
+            "vue",
         )
     } else if let Some(script) = &descriptor.script {
         // Use regular script, wrapped in __sfc__
@@ -81,6 +95,10 @@ pub fn compile_script(
         Ok(ScriptCompileResult {
             code: final_code,
             bindings: None,
+            transpile_map: None,
+            setup_lines: Vec::new(),
+            normal_script_line: None,
+            template_offsets: Vec::new(),
         })
     } else {
         // No script - generate empty component
@@ -88,11 +106,19 @@ pub fn compile_script(
             Ok(ScriptCompileResult {
                 code: "const __sfc__ = { __vapor: true }\n".to_string(),
                 bindings: None,
+                transpile_map: None,
+                setup_lines: Vec::new(),
+                normal_script_line: None,
+                template_offsets: Vec::new(),
             })
         } else {
             Ok(ScriptCompileResult {
                 code: "const __sfc__ = {}\n".to_string(),
                 bindings: None,
+                transpile_map: None,
+                setup_lines: Vec::new(),
+                normal_script_line: None,
+                template_offsets: Vec::new(),
             })
         }
     }
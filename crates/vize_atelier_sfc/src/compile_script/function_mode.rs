@@ -36,6 +36,7 @@ pub fn compile_script_setup(
     is_vapor: bool,
     is_ts: bool,
     template_content: Option<&str>,
+    runtime_module_name: &str,
 ) -> Result<ScriptCompileResult, SfcError> {
     let mut ctx = ScriptCompileContext::new(content);
     ctx.analyze();
@@ -373,17 +374,25 @@ pub fn compile_script_setup(
             .as_ref()
             .is_some_and(|p| p.type_args.is_some());
 
+    // Runtime helper imports: `import { name as _name } from '<runtime>'`
+    let push_runtime_import = |output: &mut vize_carton::Vec<u8>, specifiers: &str| {
+        output.extend_from_slice(b"import { ");
+        output.extend_from_slice(specifiers.as_bytes());
+        output.extend_from_slice(b" } from '");
+        output.extend_from_slice(runtime_module_name.as_bytes());
+        output.extend_from_slice(b"'\n");
+    };
+
     // Add Vapor-specific import or defineComponent import
     if is_vapor {
-        output.extend_from_slice(
-            b"import { defineVaporComponent as _defineVaporComponent } from 'vue'\n",
-        );
+        push_runtime_import(&mut output, "defineVaporComponent as _defineVaporComponent");
     } else if needs_prop_type {
-        output.extend_from_slice(
-            b"import { defineComponent as _defineComponent, type PropType } from 'vue'\n",
+        push_runtime_import(
+            &mut output,
+            "defineComponent as _defineComponent, type PropType",
         );
     } else {
-        output.extend_from_slice(b"import { defineComponent as _defineComponent } from 'vue'\n");
+        push_runtime_import(&mut output, "defineComponent as _defineComponent");
     }
 
     // Add mergeDefaults import if props destructure has defaults
@@ -395,13 +404,13 @@ pub fn compile_script_setup(
             .map(|d| d.bindings.values().any(|b| b.default.is_some()))
             .unwrap_or(false);
     if needs_merge_defaults {
-        output.extend_from_slice(b"import { mergeDefaults as _mergeDefaults } from 'vue'\n");
+        push_runtime_import(&mut output, "mergeDefaults as _mergeDefaults");
     }
 
     // Add useModel import if defineModel was used
     let has_define_model = !ctx.macros.define_models.is_empty();
     if has_define_model {
-        output.extend_from_slice(b"import { useModel as _useModel } from 'vue'\n");
+        push_runtime_import(&mut output, "useModel as _useModel");
     }
 
     // Output imports (filtering out type-only imports + dedupe)
@@ -468,19 +477,9 @@ pub fn compile_script_setup(
 
             output.extend_from_slice(b"  props: /*@__PURE__*/_mergeDefaults(");
             output.extend_from_slice(original_props.as_bytes());
-            output.extend_from_slice(b", {\n");
-
-            // Add defaults
-            for (key, binding) in &destructure.bindings {
-                if let Some(ref default_val) = binding.default {
-                    output.extend_from_slice(b"  ");
-                    output.extend_from_slice(key.as_bytes());
-                    output.extend_from_slice(b": ");
-                    output.extend_from_slice(default_val.as_bytes());
-                    output.push(b'\n');
-                }
-            }
-            output.extend_from_slice(b"}),\n");
+            output.extend_from_slice(b", ");
+            output.extend_from_slice(destructure.gen_defaults_object().as_bytes());
+            output.extend_from_slice(b"),\n");
         } else {
             // No defaults - just use the original props array
             if let Some(ref props_macro) = ctx.macros.define_props {
@@ -879,6 +878,10 @@ pub fn compile_script_setup(
     Ok(ScriptCompileResult {
         code: final_code,
         bindings: Some(ctx.bindings),
+        transpile_map: None,
+        setup_lines: Vec::new(),
+        normal_script_line: None,
+        template_offsets: Vec::new(),
     })
 }
 
@@ -3,7 +3,8 @@
 //! This module handles compilation of script setup with inline template mode,
 //! where the render function is inlined into the setup function.
 
-use crate::script::{transform_destructured_props, ScriptCompileContext};
+use crate::script::{transform_destructured_props_mapped, ScriptCompileContext};
+use crate::source_map::MappedString;
 use crate::types::SfcError;
 
 use super::function_mode::{contains_top_level_await, dedupe_imports};
@@ -13,7 +14,7 @@ use super::macros::{
 use super::props::{
     extract_emit_names_from_type, extract_prop_types_from_type, extract_with_defaults_defaults,
 };
-use super::typescript::transform_typescript_to_js;
+use super::typescript::{transform_typescript_to_js, transform_typescript_to_js_with_map};
 use super::{ScriptCompileResult, TemplateParts};
 
 /// Compile script setup with inline template (Vue's inline template mode)
@@ -24,6 +25,8 @@ pub fn compile_script_setup_inline(
     source_is_ts: bool,
     template: TemplateParts<'_>,
     normal_script_content: Option<&str>,
+    source_map: bool,
+    runtime_module_name: &str,
 ) -> Result<ScriptCompileResult, SfcError> {
     let mut ctx = ScriptCompileContext::new(content);
     ctx.analyze();
@@ -51,14 +54,23 @@ pub fn compile_script_setup_inline(
     // Check if defineModel was used
     let has_define_model = !ctx.macros.define_models.is_empty();
 
+    // Runtime helper imports: `import { name as _name } from '<runtime>'`
+    let push_runtime_import = |output: &mut vize_carton::Vec<u8>, specifiers: &str| {
+        output.extend_from_slice(b"import { ");
+        output.extend_from_slice(specifiers.as_bytes());
+        output.extend_from_slice(b" } from '");
+        output.extend_from_slice(runtime_module_name.as_bytes());
+        output.extend_from_slice(b"'\n");
+    };
+
     // mergeDefaults import comes first if needed
     if needs_merge_defaults {
-        output.extend_from_slice(b"import { mergeDefaults as _mergeDefaults } from 'vue'\n");
+        push_runtime_import(&mut output, "mergeDefaults as _mergeDefaults");
     }
 
     // useModel import if defineModel was used
     if has_define_model {
-        output.extend_from_slice(b"import { useModel as _useModel } from 'vue'\n");
+        push_runtime_import(&mut output, "useModel as _useModel");
     }
 
     // Check if we need PropType import (type-based defineProps in TS mode)
@@ -72,12 +84,12 @@ pub fn compile_script_setup_inline(
     // defineComponent import for TypeScript
     if is_ts {
         if needs_prop_type {
-            output.extend_from_slice(
-                b"import { defineComponent as _defineComponent, type PropType } from 'vue'\n",
+            push_runtime_import(
+                &mut output,
+                "defineComponent as _defineComponent, type PropType",
             );
         } else {
-            output
-                .extend_from_slice(b"import { defineComponent as _defineComponent } from 'vue'\n");
+            push_runtime_import(&mut output, "defineComponent as _defineComponent");
         }
     }
 
@@ -91,6 +103,8 @@ pub fn compile_script_setup_inline(
     // Extract user imports
     let mut user_imports = Vec::new();
     let mut setup_lines = Vec::new();
+    // Script setup line each entry of `setup_lines` was copied from
+    let mut setup_line_origins: Vec<Option<u32>> = Vec::new();
     // Collect TypeScript interfaces/types to preserve at module level (before export default)
     let mut ts_declarations: Vec<String> = Vec::new();
 
@@ -120,7 +134,8 @@ pub fn compile_script_setup_inline(
                                     // Track template literals (backtick strings) to skip content inside them
     let mut in_template_literal = false;
 
-    for line in content.lines() {
+    for (line_index, line) in content.lines().enumerate() {
+        let line_index = line_index as u32;
         let trimmed = line.trim();
 
         // Handle multi-line macro calls
@@ -200,8 +215,10 @@ pub fn compile_script_setup_inline(
                 in_destructure = false;
                 if !is_props_macro {
                     // Not a props destructure - add to setup lines
-                    for buf_line in destructure_buffer.lines() {
+                    let buffered = destructure_buffer.lines().count() as u32;
+                    for (i, buf_line) in destructure_buffer.lines().enumerate() {
                         setup_lines.push(buf_line.to_string());
+                        setup_line_origins.push(Some(line_index + 1 + i as u32 - buffered));
                     }
                 }
                 // Check if the destructure's RHS has an unclosed function call:
@@ -305,8 +322,10 @@ pub fn compile_script_setup_inline(
             object_literal_brace_depth -= trimmed.matches('}').count() as i32;
             if object_literal_brace_depth <= 0 {
                 // Object literal is complete, add to setup_lines
-                for buf_line in object_literal_buffer.lines() {
+                let buffered = object_literal_buffer.lines().count() as u32;
+                for (i, buf_line) in object_literal_buffer.lines().enumerate() {
                     setup_lines.push(buf_line.to_string());
+                    setup_line_origins.push(Some(line_index + 1 + i as u32 - buffered));
                 }
                 in_object_literal = false;
                 object_literal_buffer.clear();
@@ -362,6 +381,7 @@ pub fn compile_script_setup_inline(
             // This line is inside (or closes) a template literal
             if !trimmed.is_empty() && !is_macro_call_line(trimmed) {
                 setup_lines.push(line.to_string());
+                setup_line_origins.push(Some(line_index));
             }
             continue;
         }
@@ -543,6 +563,7 @@ pub fn compile_script_setup_inline(
             // Hoisting user-defined consts is problematic without proper AST-based scope tracking
             // Template-generated _hoisted_X consts are handled separately by template.hoisted
             setup_lines.push(line.to_string());
+            setup_line_origins.push(Some(line_index));
         }
     }
 
@@ -570,8 +591,10 @@ pub fn compile_script_setup_inline(
 
     // Normal script content goes AFTER imports/hoisted, BEFORE component definition
     // This matches Vue's @vue/compiler-sfc output order
+    let mut normal_script_line = None;
     let has_default_export = if let Some(ref normal_script) = preserved_normal_script {
         output.push(b'\n');
+        normal_script_line = Some(output.iter().filter(|&&b| b == b'\n').count() as u32);
         output.extend_from_slice(normal_script.as_bytes());
         output.push(b'\n');
         normal_script.contains("const __default__")
@@ -713,23 +736,9 @@ pub fn compile_script_setup_inline(
                 let destructure = ctx.macros.props_destructure.as_ref().unwrap();
                 props_emits_buf.extend_from_slice(b"  props: /*@__PURE__*/_mergeDefaults(");
                 props_emits_buf.extend_from_slice(props_macro.args.as_bytes());
-                props_emits_buf.extend_from_slice(b", {\n");
-                let defaults: Vec<_> = destructure
-                    .bindings
-                    .iter()
-                    .filter_map(|(k, b)| b.default.as_ref().map(|d| (k.as_str(), d.as_str())))
-                    .collect();
-                for (i, (key, default_val)) in defaults.iter().enumerate() {
-                    props_emits_buf.extend_from_slice(b"  ");
-                    props_emits_buf.extend_from_slice(key.as_bytes());
-                    props_emits_buf.extend_from_slice(b": ");
-                    props_emits_buf.extend_from_slice(default_val.as_bytes());
-                    if i < defaults.len() - 1 {
-                        props_emits_buf.push(b',');
-                    }
-                    props_emits_buf.push(b'\n');
-                }
-                props_emits_buf.extend_from_slice(b"}),\n");
+                props_emits_buf.extend_from_slice(b", ");
+                props_emits_buf.extend_from_slice(destructure.gen_defaults_object().as_bytes());
+                props_emits_buf.extend_from_slice(b"),\n");
             } else {
                 props_emits_buf.extend_from_slice(b"  props: ");
                 props_emits_buf.extend_from_slice(props_macro.args.as_bytes());
@@ -811,20 +820,30 @@ pub fn compile_script_setup_inline(
     // Setup code body - transform props destructure references and separate hoisted/setup code
     let setup_code = setup_lines.join("\n");
     let transformed_setup = if let Some(ref destructure) = ctx.macros.props_destructure {
-        transform_destructured_props(&setup_code, destructure)
+        let transformed = transform_destructured_props_mapped(&setup_code, destructure);
+        // Follow each rewritten line back to the setup line it came from
+        let mut origins = vec![None; transformed.as_str().lines().count()];
+        for (line, origin) in transformed.line_origins(&setup_code) {
+            if let Some(slot) = origins.get_mut(line as usize) {
+                *slot = setup_line_origins.get(origin as usize).copied().flatten();
+            }
+        }
+        setup_line_origins = origins;
+        transformed.into_string()
     } else {
         setup_code
     };
 
     // Separate hoisted consts (literal consts that can be module-level) from setup code
-    let mut hoisted_lines: Vec<String> = Vec::new();
-    let mut setup_body_lines: Vec<String> = Vec::new();
+    let mut hoisted_lines: Vec<(String, Option<u32>)> = Vec::new();
+    let mut setup_body_lines: Vec<(String, Option<u32>)> = Vec::new();
     let mut in_multiline_value = false;
-    for line in transformed_setup.lines() {
+    for (i, line) in transformed_setup.lines().enumerate() {
+        let origin = setup_line_origins.get(i).copied().flatten();
         let trimmed = line.trim();
         // Track multi-line template literals / strings - don't hoist individual lines
         if in_multiline_value {
-            setup_body_lines.push(line.to_string());
+            setup_body_lines.push((line.to_string(), origin));
             // Count unescaped backticks to detect end of template literal
             let backticks = trimmed
                 .chars()
@@ -867,7 +886,7 @@ pub fn compile_script_setup_inline(
                 if backticks % 2 == 1 {
                     // Unclosed template literal - don't hoist, mark as multi-line
                     in_multiline_value = true;
-                    setup_body_lines.push(line.to_string());
+                    setup_body_lines.push((line.to_string(), origin));
                     continue;
                 }
             }
@@ -877,19 +896,27 @@ pub fn compile_script_setup_inline(
                     ctx.bindings.bindings.get(&name),
                     Some(crate::types::BindingType::LiteralConst)
                 ) {
-                    hoisted_lines.push(line.to_string());
+                    hoisted_lines.push((line.to_string(), origin));
                     continue;
                 }
             }
         }
-        setup_body_lines.push(line.to_string());
+        setup_body_lines.push((line.to_string(), origin));
     }
 
+    // Output line each copied script setup line ends up on
+    let mut setup_lines_out: Vec<(u32, u32)> = Vec::new();
+    let mut output_line = output.iter().filter(|&&b| b == b'\n').count() as u32;
+
     // Output hoisted literal consts (before export default)
     if !hoisted_lines.is_empty() {
-        for line in &hoisted_lines {
+        for (line, origin) in &hoisted_lines {
+            if let Some(origin) = origin {
+                setup_lines_out.push((output_line, *origin));
+            }
             output.extend_from_slice(line.as_bytes());
             output.push(b'\n');
+            output_line += 1;
         }
     }
 
@@ -1011,9 +1038,14 @@ pub fn compile_script_setup_inline(
     }
 
     // Output setup code lines (non-hoisted)
-    for line in &setup_body_lines {
+    output_line = output.iter().filter(|&&b| b == b'\n').count() as u32;
+    for (line, origin) in &setup_body_lines {
+        if let Some(origin) = origin {
+            setup_lines_out.push((output_line, *origin));
+        }
         output.extend_from_slice(line.as_bytes());
         output.push(b'\n');
+        output_line += 1;
     }
 
     // defineExpose: transform to __expose(...)
@@ -1026,6 +1058,7 @@ pub fn compile_script_setup_inline(
 
     // Inline render function as return (blank line before)
     output.push(b'\n');
+    let mut template_offsets = Vec::new();
     if !template.render_body.is_empty() {
         if is_ts {
             output.extend_from_slice(b"return (_ctx: any,_cache: any) => {\n");
@@ -1046,21 +1079,30 @@ pub fn compile_script_setup_inline(
         }
 
         // Indent the render body properly
+        let mut render = MappedString::new();
         let mut first_line = true;
+        let mut line_start = 0;
         for line in template.render_body.lines() {
             if first_line {
-                output.extend_from_slice(b"  return ");
-                output.extend_from_slice(line.as_bytes());
+                render.push_str("  return ");
                 first_line = false;
             } else {
-                output.push(b'\n');
+                render.push('\n');
                 // Preserve existing indentation by adding 2 spaces (setup indent)
                 if !line.trim().is_empty() {
-                    output.extend_from_slice(b"  ");
+                    render.push_str("  ");
                 }
-                output.extend_from_slice(line.as_bytes());
             }
+            render.push_copied(template.render_body, line_start..line_start + line.len());
+            line_start += line.len() + 1;
         }
+        let render_start = output.len();
+        template_offsets = render
+            .translate_all(template.render_offsets)
+            .into_iter()
+            .map(|(offset, source)| (render_start + offset, source))
+            .collect();
+        output.extend_from_slice(render.as_str().as_bytes());
         output.push(b'\n');
         output.extend_from_slice(b"}\n");
     } else {
@@ -1109,6 +1151,7 @@ pub fn compile_script_setup_inline(
     let output_str = unsafe { String::from_utf8_unchecked(output.into_iter().collect()) };
 
     // Normal script content is already embedded in the output buffer (after imports, before component def)
+    let mut transpile_map = None;
     let final_code = if is_ts || !source_is_ts {
         // Preserve output as-is when:
         // - is_ts: output should be TypeScript (preserve for downstream toolchains)
@@ -1117,17 +1160,35 @@ pub fn compile_script_setup_inline(
         let mut code = output_str;
         // Add TypeScript annotations to $event parameters in event handlers
         if is_ts {
-            code = code.replace("$event => (", "($event: any) => (");
+            const HANDLER: &str = "$event => (";
+            const TYPED_HANDLER: &str = "($event: any) => (";
+            // Shift template expressions past each rewritten handler
+            let handlers: Vec<usize> = code.match_indices(HANDLER).map(|(i, _)| i).collect();
+            for (offset, _) in template_offsets.iter_mut() {
+                let before = handlers.partition_point(|&i| i < *offset);
+                *offset += before * (TYPED_HANDLER.len() - HANDLER.len());
+            }
+            code = code.replace(HANDLER, TYPED_HANDLER);
         }
         code
     } else {
         // Source is TypeScript but output should be JavaScript - transform to strip TS syntax
-        transform_typescript_to_js(&output_str)
+        if source_map {
+            let (code, map) = transform_typescript_to_js_with_map(&output_str);
+            transpile_map = map;
+            code
+        } else {
+            transform_typescript_to_js(&output_str)
+        }
     };
 
     Ok(ScriptCompileResult {
         code: final_code,
         bindings: Some(ctx.bindings),
+        transpile_map,
+        setup_lines: setup_lines_out,
+        normal_script_line,
+        template_offsets,
     })
 }
 
@@ -1224,6 +1285,7 @@ mod tests {
             hoisted: "",
             preamble: "",
             render_body: "null",
+            render_offsets: &[],
         };
         let result = compile_script_setup_inline(
             script_content,
@@ -1232,6 +1294,8 @@ mod tests {
             true,  // source_is_ts = true
             empty_template,
             None,
+            false,
+            "vue",
         )
         .expect("compilation should succeed");
         result.code
@@ -1244,6 +1308,7 @@ mod tests {
             hoisted: "",
             preamble: "",
             render_body: "null",
+            render_offsets: &[],
         };
         let result = compile_script_setup_inline(
             script_content,
@@ -1252,6 +1317,8 @@ mod tests {
             true, // source_is_ts = true
             empty_template,
             None,
+            false,
+            "vue",
         )
         .expect("compilation should succeed");
         result.code
@@ -1501,6 +1568,7 @@ const x = ref(1)
             hoisted: "",
             preamble: "",
             render_body: "",
+            render_offsets: &[],
         };
         let result = compile_script_setup_inline(
             script_content,
@@ -1509,6 +1577,8 @@ const x = ref(1)
             true,
             empty_template,
             None,
+            false,
+            "vue",
         )
         .expect("compilation should succeed");
         result.code
@@ -3,14 +3,44 @@
 //! This module handles transforming TypeScript code to JavaScript using OXC.
 
 use oxc_allocator::Allocator;
-use oxc_codegen::Codegen;
+use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
 use oxc_transformer::{TransformOptions, Transformer, TypeScriptOptions};
 
+/// TypeScript-stripped output paired with its source map
+#[derive(Debug, Clone)]
+pub struct TranspileMap {
+    /// Code before the TypeScript transform
+    pub source: String,
+    /// OXC source map (JSON) from the transformed code back to `source`
+    pub map: String,
+    /// Indentation tabs expanded to two spaces on each transformed line
+    pub indent_tabs: Vec<u32>,
+}
+
 /// Transform TypeScript code to JavaScript using OXC
 pub fn transform_typescript_to_js(code: &str) -> String {
+    transform_typescript(code, false).0
+}
+
+/// Transform TypeScript code to JavaScript, also returning OXC's source map
+///
+/// The map is `None` when the code is returned unchanged (e.g. parse errors).
+/// Its generated columns count the tab indentation OXC emits, before tabs are
+/// expanded to two spaces.
+pub fn transform_typescript_to_js_with_map(code: &str) -> (String, Option<TranspileMap>) {
+    let (js, map) = transform_typescript(code, true);
+    let map = map.map(|(map, indent_tabs)| TranspileMap {
+        source: code.to_string(),
+        map,
+        indent_tabs,
+    });
+    (js, map)
+}
+
+fn transform_typescript(code: &str, source_map: bool) -> (String, Option<(String, Vec<u32>)>) {
     let allocator = Allocator::default();
     let source_type = SourceType::ts();
     let parser = Parser::new(&allocator, code, source_type);
@@ -18,7 +48,7 @@ pub fn transform_typescript_to_js(code: &str) -> String {
 
     if !parse_result.errors.is_empty() {
         // If parsing fails, return original code
-        return code.to_string();
+        return (code.to_string(), None);
     }
 
     let mut program = parse_result.program;
@@ -30,7 +60,7 @@ pub fn transform_typescript_to_js(code: &str) -> String {
 
     if !semantic_ret.errors.is_empty() {
         // If semantic analysis fails, return original code
-        return code.to_string();
+        return (code.to_string(), None);
     }
 
     let scoping = semantic_ret.semantic.into_scoping();
@@ -49,10 +79,37 @@ pub fn transform_typescript_to_js(code: &str) -> String {
 
     if !ret.errors.is_empty() {
         // If transformation fails, return original code
-        return code.to_string();
+        return (code.to_string(), None);
     }
 
     // Generate JavaScript code
-    // Replace tabs with 2 spaces for consistent indentation
-    Codegen::new().build(&program).code.replace('\t', "  ")
+    let options = CodegenOptions {
+        source_map_path: source_map.then(|| std::path::PathBuf::from("script.ts")),
+        ..CodegenOptions::default()
+    };
+    let ret = Codegen::new().with_options(options).build(&program);
+    // Replace indentation tabs with 2 spaces; tabs inside strings and
+    // template literals are part of their values
+    let (js, indent_tabs) = expand_indentation(&ret.code);
+    (js, ret.map.map(|map| (map.to_json_string(), indent_tabs)))
+}
+
+/// Expand leading tabs to two spaces each, returning how many were expanded
+/// on every line
+fn expand_indentation(code: &str) -> (String, Vec<u32>) {
+    let mut js = String::with_capacity(code.len() + code.len() / 8);
+    let mut indent_tabs = Vec::new();
+    for (i, line) in code.split('\n').enumerate() {
+        if i > 0 {
+            js.push('\n');
+        }
+        let rest = line.trim_start_matches('\t');
+        let tabs = line.len() - rest.len();
+        for _ in 0..tabs {
+            js.push_str("  ");
+        }
+        js.push_str(rest);
+        indent_tabs.push(tabs as u32);
+    }
+    (js, indent_tabs)
 }
@@ -6,9 +6,20 @@
 use vize_atelier_vapor::{compile_vapor, VaporCompilerOptions};
 use vize_carton::Bump;
 
+use crate::source_map::{expression_offsets_from_map, MappedString};
 use crate::types::*;
 
+/// Compiled template code
+pub(crate) struct CompiledTemplate {
+    pub code: String,
+    /// `(code offset, template offset)` of each template expression, recorded
+    /// when source maps are enabled
+    pub expression_offsets: Vec<(usize, u32)>,
+}
+
 /// Compile template block
+///
+/// Expression offsets are recorded when the compiler options enable source maps.
 pub(crate) fn compile_template_block(
     template: &SfcTemplateBlock,
     options: &TemplateCompileOptions,
@@ -17,7 +28,8 @@ pub(crate) fn compile_template_block(
     is_ts: bool,
     bindings: Option<&BindingMetadata>,
     croquis: Option<vize_croquis::analysis::Croquis>,
-) -> Result<String, SfcError> {
+    warnings: &mut Vec<SfcError>,
+) -> Result<CompiledTemplate, SfcError> {
     let allocator = Bump::new();
 
     // Build DOM compiler options
@@ -34,6 +46,7 @@ pub(crate) fn compile_template_block(
     };
     dom_opts.ssr = options.ssr;
     dom_opts.is_ts = is_ts;
+    dom_opts.is_prod = options.is_prod;
 
     // For script setup, use inline mode to match Vue's actual compiler behavior
     // Inline mode generates direct closure references (e.g., msg instead of $setup.msg)
@@ -53,8 +66,9 @@ pub(crate) fn compile_template_block(
     }
 
     // Compile template
-    let (_, errors, result) =
+    let (root, errors, result) =
         vize_atelier_dom::compile_template_with_options(&allocator, &template.content, dom_opts);
+    warnings.extend(root.warnings.into_iter().map(SfcError::from));
 
     if !errors.is_empty() {
         let mut message = String::from("Template compilation errors: ");
@@ -76,34 +90,72 @@ pub(crate) fn compile_template_block(
 
     // The codegen already generates a complete function with closing brace,
     // so we just need to use it directly
+    let code_start = output.len();
+    let expression_offsets = result
+        .mappings
+        .iter()
+        .map(|&(generated, source)| (code_start + generated as usize, source))
+        .collect();
     output.push_str(&result.code);
     output.push('\n');
 
-    Ok(output)
+    Ok(CompiledTemplate {
+        code: output,
+        expression_offsets,
+    })
 }
 
 /// Compile template block using Vapor mode
+///
+/// Expression offsets are recorded when `source_map` is set.
 pub(crate) fn compile_template_block_vapor(
     template: &SfcTemplateBlock,
+    options: &TemplateCompileOptions,
     scope_id: &str,
     has_scoped: bool,
-) -> Result<String, SfcError> {
+    bindings: Option<&BindingMetadata>,
+    croquis: Option<vize_croquis::analysis::Croquis>,
+    warnings: &mut Vec<SfcError>,
+    source_map: bool,
+) -> Result<CompiledTemplate, SfcError> {
     let allocator = Bump::new();
 
+    let scope_attr = if has_scoped {
+        let mut attr = String::with_capacity(scope_id.len() + 7);
+        attr.push_str("data-v-");
+        attr.push_str(scope_id);
+        attr
+    } else {
+        String::new()
+    };
+
     // Build Vapor compiler options
+    // SSR output renders elements as strings, so the scope attribute is added there
     let vapor_opts = VaporCompilerOptions {
         prefix_identifiers: false,
-        ssr: false,
+        ssr: options.ssr,
+        scope_id: (options.ssr && has_scoped).then(|| scope_attr.as_str().into()),
+        ssr_hydration_hints: options.ssr_hydration_hints,
+        binding_metadata: bindings.cloned(),
+        runtime_module_name: options.runtime_module_name().into(),
+        source_map,
+        // The SSR compiler uses Croquis to find async components
+        croquis: croquis.map(Box::new),
         ..Default::default()
     };
 
     // Compile template with Vapor
     let result = compile_vapor(&allocator, &template.content, vapor_opts);
+    warnings.extend(result.warnings.iter().map(|warning| SfcError {
+        message: warning.message.clone(),
+        code: Some("VAPOR_TEMPLATE_WARNING".to_string()),
+        loc: None,
+    }));
 
-    if !result.error_messages.is_empty() {
+    if !result.errors.is_empty() {
         let mut message = String::from("Vapor template compilation errors: ");
         use std::fmt::Write as _;
-        let _ = write!(&mut message, "{:?}", result.error_messages);
+        let _ = write!(&mut message, "{:?}", result.error_messages());
         return Err(SfcError {
             message,
             code: Some("VAPOR_TEMPLATE_ERROR".to_string()),
@@ -111,19 +163,23 @@ pub(crate) fn compile_template_block_vapor(
         });
     }
 
-    // Process the Vapor output to extract imports and render function
-    let mut output = String::new();
-    let scope_attr = if has_scoped {
-        let mut attr = String::with_capacity(scope_id.len() + 7);
-        attr.push_str("data-v-");
-        attr.push_str(scope_id);
-        attr
-    } else {
-        String::new()
-    };
-
-    // Parse the Vapor output to separate imports and function body
     let code = &result.code;
+    let mapped = result
+        .map
+        .as_deref()
+        .map(|map| expression_offsets_from_map(map, code, &template.content))
+        .unwrap_or_default();
+
+    // SSR output is already a complete `ssrRender` function
+    if options.ssr {
+        return Ok(CompiledTemplate {
+            code: result.code,
+            expression_offsets: mapped,
+        });
+    }
+
+    // Process the Vapor output to extract imports and render function
+    let mut output = MappedString::new();
 
     // Extract import line
     if let Some(import_end) = code.find('\n') {
@@ -166,11 +222,17 @@ pub(crate) fn compile_template_block_vapor(
             output.push_str("function render(_ctx, $props, $emit, $attrs, $slots) {\n");
 
             // Copy function body (skip "export default () => {" and final "}")
-            for line in lines.iter().skip(func_start + 1) {
+            let mut line_start = import_end + 1;
+            for (i, line) in lines.iter().enumerate() {
+                let start = line_start;
+                line_start += line.len() + 1;
+                if i <= func_start {
+                    continue;
+                }
                 if *line == "}" {
                     break;
                 }
-                output.push_str(line);
+                output.push_copied(code, start..start + line.len());
                 output.push('\n');
             }
 
@@ -178,7 +240,10 @@ pub(crate) fn compile_template_block_vapor(
         }
     }
 
-    Ok(output)
+    Ok(CompiledTemplate {
+        expression_offsets: output.translate_all(&mapped),
+        code: output.into_string(),
+    })
 }
 
 /// Add scope ID to template string
@@ -246,10 +311,12 @@ fn count_braces_outside_strings(line: &str) -> i32 {
 }
 
 /// Compact render body by removing unnecessary line breaks inside function calls and arrays
+///
+/// Every character kept as-is is recorded as copied from `render_body`.
 #[allow(dead_code)]
-fn compact_render_body(render_body: &str) -> String {
-    let mut result = String::new();
-    let mut chars = render_body.chars().peekable();
+fn compact_render_body(render_body: &str) -> MappedString {
+    let mut result = MappedString::new();
+    let mut chars = render_body.char_indices().peekable();
     let mut paren_depth: i32 = 0;
     let mut bracket_depth: i32 = 0;
     let mut brace_depth: i32 = 0;
@@ -257,7 +324,8 @@ fn compact_render_body(render_body: &str) -> String {
     let mut string_char = '\0';
     let mut in_template = false;
 
-    while let Some(ch) = chars.next() {
+    while let Some((index, ch)) = chars.next() {
+        let copied = index..index + ch.len_utf8();
         match ch {
             '"' | '\'' if !in_template => {
                 if !in_string {
@@ -266,35 +334,35 @@ fn compact_render_body(render_body: &str) -> String {
                 } else if string_char == ch {
                     in_string = false;
                 }
-                result.push(ch);
+                result.push_copied(render_body, copied);
             }
             '`' => {
                 in_template = !in_template;
-                result.push(ch);
+                result.push_copied(render_body, copied);
             }
             '(' if !in_string && !in_template => {
                 paren_depth += 1;
-                result.push(ch);
+                result.push_copied(render_body, copied);
             }
             ')' if !in_string && !in_template => {
                 paren_depth = paren_depth.saturating_sub(1);
-                result.push(ch);
+                result.push_copied(render_body, copied);
             }
             '[' if !in_string && !in_template => {
                 bracket_depth += 1;
-                result.push(ch);
+                result.push_copied(render_body, copied);
             }
             ']' if !in_string && !in_template => {
                 bracket_depth = bracket_depth.saturating_sub(1);
-                result.push(ch);
+                result.push_copied(render_body, copied);
             }
             '{' if !in_string && !in_template => {
                 brace_depth += 1;
-                result.push(ch);
+                result.push_copied(render_body, copied);
             }
             '}' if !in_string && !in_template => {
                 brace_depth = brace_depth.saturating_sub(1);
-                result.push(ch);
+                result.push_copied(render_body, copied);
             }
             '\n' => {
                 // If inside braces (block bodies), keep newlines to preserve statement separation
@@ -303,7 +371,7 @@ fn compact_render_body(render_body: &str) -> String {
                 } else if (paren_depth > 0 || bracket_depth > 0) && !in_string && !in_template {
                     result.push(' ');
                     // Skip following whitespace
-                    while let Some(&next_ch) = chars.peek() {
+                    while let Some(&(_, next_ch)) = chars.peek() {
                         if next_ch.is_whitespace() && next_ch != '\n' {
                             chars.next();
                         } else {
@@ -312,10 +380,10 @@ fn compact_render_body(render_body: &str) -> String {
                     }
                 } else {
                     // Keep newline outside of function calls/arrays or inside strings
-                    result.push(ch);
+                    result.push_copied(render_body, copied);
                 }
             }
-            _ => result.push(ch),
+            _ => result.push_copied(render_body, copied),
         }
     }
 
@@ -323,15 +391,25 @@ fn compact_render_body(render_body: &str) -> String {
 }
 
 /// Extract imports, hoisted consts, and render function from compiled template code
-/// Returns (imports, hoisted, render_function) where render_function is the full function definition
-pub(crate) fn extract_template_parts_full(template_code: &str) -> (String, String, String) {
+/// Returns (imports, hoisted, render_function, render_offsets) where render_function is
+/// the full function definition
+///
+/// `render_offsets` are the template expression offsets within `render_function`;
+/// expressions outside of it are dropped.
+pub(crate) fn extract_template_parts_full(
+    template: &CompiledTemplate,
+) -> (String, String, String, Vec<(usize, u32)>) {
+    let template_code = template.code.as_str();
     let mut imports = String::new();
     let mut hoisted = String::new();
-    let mut render_fn = String::new();
+    let mut render_fn = MappedString::new();
     let mut in_render = false;
     let mut brace_depth = 0;
+    let mut line_start = 0;
 
     for line in template_code.lines() {
+        let start = line_start;
+        line_start += line.len() + 1;
         let trimmed = line.trim();
 
         if trimmed.starts_with("import ") {
@@ -350,7 +428,7 @@ pub(crate) fn extract_template_parts_full(template_code: &str) -> (String, Strin
             render_fn.push('\n');
         } else if in_render {
             brace_depth += count_braces_outside_strings(line);
-            render_fn.push_str(line);
+            render_fn.push_copied(template_code, start..start + line.len());
             render_fn.push('\n');
 
             if brace_depth == 0 {
@@ -359,18 +437,25 @@ pub(crate) fn extract_template_parts_full(template_code: &str) -> (String, Strin
         }
     }
 
-    (imports, hoisted, render_fn)
+    let render_offsets = render_fn.translate_all(&template.expression_offsets);
+    (imports, hoisted, render_fn.into_string(), render_offsets)
 }
 
 /// Extract imports, hoisted consts, preamble (component/directive resolution), and render body
 /// from compiled template code.
-/// Returns (imports, hoisted, preamble, render_body)
+/// Returns (imports, hoisted, preamble, render_body, render_offsets)
+///
+/// `render_offsets` are the template expression offsets within `render_body`;
+/// expressions outside of it are dropped.
 #[allow(dead_code)]
-pub(crate) fn extract_template_parts(template_code: &str) -> (String, String, String, String) {
+pub(crate) fn extract_template_parts(
+    template: &CompiledTemplate,
+) -> (String, String, String, String, Vec<(usize, u32)>) {
+    let template_code = template.code.as_str();
     let mut imports = String::new();
     let mut hoisted = String::new();
     let mut preamble = String::new(); // Component/directive resolution statements
-    let mut render_body = String::new();
+    let mut render_body = MappedString::new();
     let mut in_render = false;
     let mut in_return = false;
     let mut brace_depth = 0;
@@ -378,8 +463,11 @@ pub(crate) fn extract_template_parts(template_code: &str) -> (String, String, St
 
     // Collect all lines for look-ahead
     let lines: Vec<&str> = template_code.lines().collect();
+    let mut line_start = 0;
 
     for (i, line) in lines.iter().enumerate() {
+        let start = line_start;
+        line_start += line.len() + 1;
         let trimmed = line.trim();
 
         if trimmed.starts_with("import ") {
@@ -402,7 +490,7 @@ pub(crate) fn extract_template_parts(template_code: &str) -> (String, String, St
             if in_return {
                 // Continue collecting return body
                 render_body.push('\n');
-                render_body.push_str(line);
+                render_body.push_copied(template_code, start..start + line.len());
                 return_paren_depth += line.matches('(').count() as i32;
                 return_paren_depth -= line.matches(')').count() as i32;
 
@@ -422,14 +510,16 @@ pub(crate) fn extract_template_parts(template_code: &str) -> (String, String, St
                     if !next_continues_ternary {
                         in_return = false;
                         // Remove trailing semicolon if present
-                        let trimmed_body = render_body.trim_end();
-                        if let Some(stripped) = trimmed_body.strip_suffix(';') {
-                            render_body = stripped.to_string();
+                        let trimmed_body = render_body.as_str().trim_end();
+                        if trimmed_body.ends_with(';') {
+                            render_body.truncate(trimmed_body.len() - 1);
                         }
                     }
                 }
             } else if let Some(stripped) = trimmed.strip_prefix("return ") {
-                render_body = stripped.to_string();
+                let body_start = start + line.len() - line.trim_start().len() + "return ".len();
+                render_body = MappedString::new();
+                render_body.push_copied(template_code, body_start..body_start + stripped.len());
                 // Count parentheses to handle multi-line return
                 return_paren_depth =
                     stripped.matches('(').count() as i32 - stripped.matches(')').count() as i32;
@@ -449,8 +539,8 @@ pub(crate) fn extract_template_parts(template_code: &str) -> (String, String, St
                         in_return = true;
                     } else {
                         // Single line return - remove trailing semicolon if present
-                        if render_body.ends_with(';') {
-                            render_body.pop();
+                        if render_body.as_str().ends_with(';') {
+                            render_body.truncate(render_body.len() - 1);
                         }
                     }
                 }
@@ -469,15 +559,30 @@ pub(crate) fn extract_template_parts(template_code: &str) -> (String, String, St
     }
 
     // Compact the render body to remove unnecessary line breaks inside function calls
-    let compacted = compact_render_body(&render_body);
-
-    (imports, hoisted, preamble, compacted)
+    let compacted = compact_render_body(render_body.as_str());
+    let render_offsets =
+        compacted.translate_all(&render_body.translate_all(&template.expression_offsets));
+
+    (
+        imports,
+        hoisted,
+        preamble,
+        compacted.into_string(),
+        render_offsets,
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn compiled(code: &str, expression_offsets: Vec<(usize, u32)>) -> CompiledTemplate {
+        CompiledTemplate {
+            code: code.to_string(),
+            expression_offsets,
+        }
+    }
+
     #[test]
     fn test_add_scope_id_to_template() {
         let input = r#"const t0 = _template("<div class='container'>Hello</div>")"#;
@@ -523,7 +628,8 @@ export function render(_ctx, _cache) {
   return _toDisplayString(isArray.value ? ']' : '}')
 }"#;
 
-        let (imports, _hoisted, render_fn) = extract_template_parts_full(template_code);
+        let (imports, _hoisted, render_fn, _) =
+            extract_template_parts_full(&compiled(template_code, Vec::new()));
 
         assert!(imports.contains("import"));
         assert!(
@@ -549,10 +655,45 @@ export function render(_ctx, _cache) {
   return _createVNode("div", _hoisted_1, "Hello")
 }"#;
 
-        let (imports, hoisted, _preamble, render_body) = extract_template_parts(template_code);
+        let (imports, hoisted, _preamble, render_body, _) =
+            extract_template_parts(&compiled(template_code, Vec::new()));
 
         assert!(imports.contains("import"));
         assert!(hoisted.contains("_hoisted_1"));
         assert!(render_body.contains("_createVNode"));
     }
+
+    #[test]
+    fn test_extract_template_parts_carries_expression_offsets() {
+        let template_code = r#"import { toDisplayString as _toDisplayString, openBlock as _openBlock, createElementBlock as _createElementBlock } from 'vue'
+
+export function render(_ctx, _cache) {
+  return (_openBlock(), _createElementBlock("div", {
+    id: _ctx.id
+  }, _toDisplayString(_ctx.msg), 9 /* TEXT, PROPS */, ["id"]))
+}"#;
+        let id = template_code.find("_ctx.id").unwrap();
+        let msg = template_code.find("_ctx.msg").unwrap();
+        let template = compiled(template_code, vec![(id, 0), (msg, 1), (0, 2)]);
+
+        let (_imports, _hoisted, _preamble, render_body, offsets) =
+            extract_template_parts(&template);
+
+        assert_eq!(offsets.len(), 2, "Import offsets should be dropped");
+        assert!(render_body[offsets[0].0..].starts_with("_ctx.id"));
+        assert!(render_body[offsets[1].0..].starts_with("_ctx.msg"));
+    }
+
+    #[test]
+    fn test_extract_template_parts_strips_semicolon_keeps_offsets() {
+        let template_code = "export function render(_ctx, _cache) {\n  return _ctx.msg;\n}";
+        let msg = template_code.find("_ctx.msg").unwrap();
+        let template = compiled(template_code, vec![(msg, 0)]);
+
+        let (_imports, _hoisted, _preamble, render_body, offsets) =
+            extract_template_parts(&template);
+
+        assert_eq!(render_body, "_ctx.msg");
+        assert_eq!(offsets, vec![(0, 0)]);
+    }
 }
@@ -45,6 +45,7 @@ pub mod css;
 pub mod parse;
 pub mod rewrite_default;
 pub mod script;
+mod source_map;
 pub mod style;
 pub mod types;
 
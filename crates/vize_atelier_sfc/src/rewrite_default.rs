@@ -8,9 +8,22 @@ use oxc_ast::ast::{ExportDefaultDeclarationKind, Statement};
 use oxc_parser::Parser;
 use oxc_span::{GetSpan, SourceType};
 
+use crate::source_map::MappedString;
+
 /// Rewrite `export default` to a const declaration with the given name.
 /// Returns (rewritten_code, has_default_export)
 pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool) {
+    let (output, has_default) = rewrite_default_mapped(input, as_name, is_ts);
+    (output.into_string(), has_default)
+}
+
+/// Like [`rewrite_default`], also recording the parts of `input` copied unchanged
+pub(crate) fn rewrite_default_mapped(
+    input: &str,
+    as_name: &str,
+    is_ts: bool,
+) -> (MappedString, bool) {
+    let mut output = MappedString::new();
     let source_type = if is_ts {
         SourceType::ts()
     } else {
@@ -22,7 +35,8 @@ pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool
 
     if !ret.errors.is_empty() {
         // If parsing fails, return original code
-        return (input.to_string(), false);
+        output.push_copied(input, 0..input.len());
+        return (output, false);
     }
 
     let program = ret.program;
@@ -39,7 +53,7 @@ pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool
 
     if !has_default {
         // No default export - append empty object
-        let mut output = input.to_string();
+        output.push_copied(input, 0..input.len());
         output.push_str("\nconst ");
         output.push_str(as_name);
         output.push_str(" = {}");
@@ -47,14 +61,13 @@ pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool
     }
 
     // Find and rewrite the default export
-    let mut output = String::new();
     let mut last_end = 0;
 
     for stmt in program.body.iter() {
         match stmt {
             Statement::ExportDefaultDeclaration(decl) => {
                 // Copy everything before this statement
-                output.push_str(&input[last_end..decl.span.start as usize]);
+                output.push_copied(input, last_end..decl.span.start as usize);
 
                 match &decl.declaration {
                     ExportDefaultDeclarationKind::ClassDeclaration(class_decl) => {
@@ -64,8 +77,7 @@ pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool
                             output.push_str(id.name.as_str());
                             // Copy the rest of the class declaration
                             let class_body_start = id.span.end as usize;
-                            let class_body = &input[class_body_start..decl.span.end as usize];
-                            output.push_str(class_body);
+                            output.push_copied(input, class_body_start..decl.span.end as usize);
                             output.push_str("\nconst ");
                             output.push_str(as_name);
                             output.push_str(" = ");
@@ -76,7 +88,7 @@ pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool
                             output.push_str(as_name);
                             output.push_str(" = ");
                             let class_start = class_decl.span.start as usize;
-                            output.push_str(&input[class_start..decl.span.end as usize]);
+                            output.push_copied(input, class_start..decl.span.end as usize);
                         }
                     }
                     ExportDefaultDeclarationKind::FunctionDeclaration(func_decl) => {
@@ -86,8 +98,7 @@ pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool
                             output.push_str(id.name.as_str());
                             // Copy the rest of the function
                             let func_body_start = id.span.end as usize;
-                            let func_body = &input[func_body_start..decl.span.end as usize];
-                            output.push_str(func_body);
+                            output.push_copied(input, func_body_start..decl.span.end as usize);
                             output.push_str("\nconst ");
                             output.push_str(as_name);
                             output.push_str(" = ");
@@ -98,7 +109,7 @@ pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool
                             output.push_str(as_name);
                             output.push_str(" = ");
                             let func_start = func_decl.span.start as usize;
-                            output.push_str(&input[func_start..decl.span.end as usize]);
+                            output.push_copied(input, func_start..decl.span.end as usize);
                         }
                     }
                     _ => {
@@ -108,7 +119,7 @@ pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool
                         output.push_str(" = ");
                         let expr_start = decl.declaration.span().start as usize;
                         let expr_end = decl.declaration.span().end as usize;
-                        output.push_str(&input[expr_start..expr_end]);
+                        output.push_copied(input, expr_start..expr_end);
                     }
                 }
 
@@ -123,7 +134,7 @@ pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool
 
                 if has_default_specifier {
                     // Copy everything before this statement
-                    output.push_str(&input[last_end..named_decl.span.start as usize]);
+                    output.push_copied(input, last_end..named_decl.span.start as usize);
 
                     if let Some(source) = &named_decl.source {
                         // export { default } from '...' or export { foo as default } from '...'
@@ -289,7 +300,7 @@ pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool
 
     // Copy remaining content
     if last_end < input.len() {
-        output.push_str(&input[last_end..]);
+        output.push_copied(input, last_end..input.len());
     }
 
     (output, has_default)
@@ -24,6 +24,7 @@ pub use context::ScriptCompileContext;
 pub use define_emits::{
     extract_runtime_emits, gen_runtime_emits, process_define_emits, DefineEmitsResult,
 };
+pub(crate) use define_props_destructure::transform_destructured_props_mapped;
 pub use define_props_destructure::{
     gen_props_access_exp, process_props_destructure, transform_destructured_props,
     PropsDestructureBinding, PropsDestructuredBindings,
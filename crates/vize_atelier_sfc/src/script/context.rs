@@ -175,6 +175,7 @@ impl ScriptCompileContext {
                     name: name.clone(),
                     local_name: CompactString::new(binding_name),
                     model_type: None,
+                    runtime_type: None,
                     required: false,
                     default_value: None,
                 });
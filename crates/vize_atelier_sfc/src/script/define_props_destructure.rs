@@ -11,6 +11,7 @@ use oxc_parser::Parser;
 use oxc_span::{GetSpan, SourceType};
 use vize_carton::FxHashMap;
 
+use crate::source_map::MappedString;
 use crate::types::BindingType;
 
 /// Props destructure binding info
@@ -27,6 +28,8 @@ pub struct PropsDestructureBinding {
 pub struct PropsDestructuredBindings {
     /// Map of prop key -> binding info
     pub bindings: FxHashMap<String, PropsDestructureBinding>,
+    /// Prop keys in declaration order
+    pub order: Vec<String>,
     /// Rest spread identifier (if any)
     pub rest_id: Option<String>,
 }
@@ -35,6 +38,50 @@ impl PropsDestructuredBindings {
     pub fn is_empty(&self) -> bool {
         self.bindings.is_empty() && self.rest_id.is_none()
     }
+
+    /// Destructure defaults as `(key, default)` pairs, in declaration order
+    pub fn defaults(&self) -> Vec<(&str, &str)> {
+        let mut keys: Vec<&str> = self.order.iter().map(String::as_str).collect();
+        if keys.len() != self.bindings.len() {
+            let mut rest: Vec<&str> = self
+                .bindings
+                .keys()
+                .map(String::as_str)
+                .filter(|key| !keys.contains(key))
+                .collect();
+            rest.sort_unstable();
+            keys.extend(rest);
+        }
+        keys.into_iter()
+            .filter_map(|key| {
+                let default = self.bindings.get(key)?.default.as_deref()?;
+                Some((key, default))
+            })
+            .collect()
+    }
+
+    /// Generate the defaults object passed to `_mergeDefaults`
+    pub fn gen_defaults_object(&self) -> String {
+        use std::fmt::Write as _;
+        let defaults = self.defaults();
+        let mut out = String::from("{\n");
+        for (i, (key, default)) in defaults.iter().enumerate() {
+            out.push_str("  ");
+            if is_simple_identifier(key) {
+                out.push_str(key);
+            } else {
+                let _ = write!(&mut out, "{:?}", key);
+            }
+            out.push_str(": ");
+            out.push_str(default);
+            if i + 1 < defaults.len() {
+                out.push(',');
+            }
+            out.push('\n');
+        }
+        out.push('}');
+        out
+    }
 }
 
 /// Process props destructure from an ObjectPattern
@@ -54,6 +101,9 @@ pub fn process_props_destructure(
         let key = resolve_object_key(&prop.key, source);
 
         if let Some(key) = key {
+            if !result.order.contains(&key) {
+                result.order.push(key.clone());
+            }
             match &prop.value {
                 // Default value: { foo = 123 }
                 BindingPattern::AssignmentPattern(assign) => {
@@ -136,8 +186,19 @@ pub fn transform_destructured_props(
     source: &str,
     destructured: &PropsDestructuredBindings,
 ) -> String {
+    transform_destructured_props_mapped(source, destructured).into_string()
+}
+
+/// Like [`transform_destructured_props`], also recording the parts of `source`
+/// kept unchanged
+pub(crate) fn transform_destructured_props_mapped(
+    source: &str,
+    destructured: &PropsDestructuredBindings,
+) -> MappedString {
+    let mut result = MappedString::new();
     if destructured.is_empty() {
-        return source.to_string();
+        result.push_copied(source, 0..source.len());
+        return result;
     }
 
     // Build map of local name -> prop key
@@ -158,25 +219,40 @@ pub fn transform_destructured_props(
         // Walk the AST to find identifier references
         collect_identifier_rewrites(&ret.program, source, &local_to_key, &mut rewrites);
 
-        // Apply rewrites if any found (empty rewrites means all props are shadowed or unused)
-        if !rewrites.is_empty() {
-            // Apply rewrites in reverse order to preserve positions
-            rewrites.sort_by(|a, b| b.0.cmp(&a.0));
-
-            let mut result = source.to_string();
-            for (start, end, replacement) in rewrites {
-                result.replace_range(start..end, &replacement);
+        // Apply rewrites in order, copying the source between them
+        // (no rewrites means all props are shadowed or unused)
+        rewrites.sort_by_key(|rewrite| rewrite.0);
+        let mut last_end = 0;
+        for (start, end, replacement) in rewrites {
+            if start < last_end {
+                continue;
             }
-            return result;
+            result.push_copied(source, last_end..start);
+            result.push_str(&replacement);
+            last_end = end;
         }
-
-        // AST parsing succeeded but no rewrites needed (props are shadowed or unused)
-        return source.to_string();
+        result.push_copied(source, last_end..source.len());
+        return result;
     }
 
     // Fallback: Simple text-based transformation
-    // This handles cases where AST parsing failed
-    transform_props_text_based(source, &local_to_key)
+    // This handles cases where AST parsing failed; identifiers never span
+    // lines, so each line is rewritten on its own
+    let mut line_start = 0;
+    for line in source.split_inclusive('\n') {
+        let text = line.strip_suffix('\n').unwrap_or(line);
+        let transformed = transform_props_text_based(text, &local_to_key);
+        if transformed == text {
+            result.push_copied(source, line_start..line_start + text.len());
+        } else {
+            result.push_str(&transformed);
+        }
+        if text.len() < line.len() {
+            result.push_copied(source, line_start + text.len()..line_start + line.len());
+        }
+        line_start += line.len();
+    }
+    result
 }
 
 /// Text-based transformation fallback
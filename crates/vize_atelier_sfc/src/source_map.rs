@@ -0,0 +1,359 @@
+//! Source map generation for compiled SFCs.
+//!
+//! The compiler records where it copies script lines and template
+//! expressions while assembling its output. This module turns those
+//! positions into a single map against the `.vue` source, composing with the
+//! TypeScript transform map when types were stripped.
+
+use vize_atelier_vapor::source_map::{decode_mappings, Mapping, SourceMapBuilder};
+
+use crate::compile_script::typescript::TranspileMap;
+use crate::types::{SfcDescriptor, SfcScriptBlock};
+
+/// Positions recorded while assembling the compiled code
+#[derive(Default)]
+pub(crate) struct SfcOrigins<'m> {
+    /// Line of the compiled code where the script output starts
+    pub script_line: u32,
+    /// TypeScript transform applied to the script output, if any
+    pub transpiled: Option<&'m TranspileMap>,
+    /// `(script output line, SFC source offset)` for each line copied from a
+    /// script block, counted before the TypeScript transform
+    pub script_lines: Vec<(u32, usize)>,
+    /// `(generated offset, template content offset)` of each template expression
+    pub template: Vec<(usize, u32)>,
+    /// Whether `template` offsets point into the TypeScript transform input
+    /// rather than into the compiled code
+    pub template_transpiled: bool,
+}
+
+/// Text assembled from generated code and pieces copied unchanged from an
+/// input, remembering where each copied piece came from so positions can be
+/// carried through the assembly
+#[derive(Debug, Default)]
+pub(crate) struct MappedString {
+    text: String,
+    /// `(input start, output start, length)` of each copied piece
+    spans: Vec<(usize, usize, usize)>,
+}
+
+impl MappedString {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append generated text
+    pub fn push_str(&mut self, s: &str) {
+        self.text.push_str(s);
+    }
+
+    /// Append a generated character
+    pub fn push(&mut self, c: char) {
+        self.text.push(c);
+    }
+
+    /// Append `input[range]`, recording where it was copied from
+    pub fn push_copied(&mut self, input: &str, range: std::ops::Range<usize>) {
+        let (start, output, len) = (range.start, self.text.len(), range.len());
+        self.text.push_str(&input[range]);
+        if len == 0 {
+            return;
+        }
+        if let Some(last) = self.spans.last_mut() {
+            if last.0 + last.2 == start && last.1 + last.2 == output {
+                last.2 += len;
+                return;
+            }
+        }
+        self.spans.push((start, output, len));
+    }
+
+    /// Drop the output from `len` on
+    pub fn truncate(&mut self, len: usize) {
+        self.text.truncate(len);
+        self.spans.retain_mut(|(_, output, copied)| {
+            *copied = (*copied).min(len.saturating_sub(*output));
+            *copied > 0
+        });
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn into_string(self) -> String {
+        self.text
+    }
+
+    /// Output offset of an input offset, if that byte was copied
+    pub fn output_offset(&self, input_offset: usize) -> Option<usize> {
+        self.spans
+            .iter()
+            .find(|&&(input, _, len)| (input..input + len).contains(&input_offset))
+            .map(|&(input, output, _)| output + input_offset - input)
+    }
+
+    /// Carry `(input offset, template offset)` pairs over to the output,
+    /// dropping the ones that weren't copied
+    pub fn translate_all(&self, offsets: &[(usize, u32)]) -> Vec<(usize, u32)> {
+        offsets
+            .iter()
+            .filter_map(|&(offset, source)| Some((self.output_offset(offset)?, source)))
+            .collect()
+    }
+
+    /// `(output line, input line)` of each output line holding copied text,
+    /// taken from the first byte copied onto it
+    pub fn line_origins(&self, input: &str) -> Vec<(u32, u32)> {
+        let input_index = LineIndex::new(input);
+        line_spans(&self.text)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(line, (start, text))| {
+                let found = self
+                    .spans
+                    .partition_point(|&(_, output, len)| output + len <= start);
+                let &(input_start, output, _) = self.spans.get(found)?;
+                if output > start + text.len() {
+                    return None;
+                }
+                let offset = input_start + start.saturating_sub(output);
+                Some((line as u32, input_index.position(offset).0))
+            })
+            .collect()
+    }
+}
+
+/// SFC source offset of the first non-blank character of each block line
+pub(crate) fn script_line_offsets(block: &SfcScriptBlock) -> Vec<Option<usize>> {
+    let mut offset = block.loc.start;
+    block
+        .content
+        .split('\n')
+        .map(|text| {
+            let start = offset + text.len() - text.trim_start().len();
+            offset += text.len() + 1;
+            (!text.trim().is_empty()).then_some(start)
+        })
+        .collect()
+}
+
+/// `(output line, SFC source offset)` of the block lines copied into `output`
+pub(crate) fn copied_script_lines(
+    block: &SfcScriptBlock,
+    output: &MappedString,
+) -> Vec<(u32, usize)> {
+    let line_offsets = script_line_offsets(block);
+    output
+        .line_origins(&block.content)
+        .into_iter()
+        .filter_map(|(line, origin)| Some((line, (*line_offsets.get(origin as usize)?)?)))
+        .collect()
+}
+
+/// Build a Source Map v3 object for `code` against the descriptor source
+pub(crate) fn generate_sfc_source_map(
+    descriptor: &SfcDescriptor,
+    code: &str,
+    origins: &SfcOrigins<'_>,
+    filename: &str,
+) -> Option<serde_json::Value> {
+    let source_index = LineIndex::new(&descriptor.source);
+    let code_lines: Vec<&str> = code.split('\n').collect();
+    let mut builder = SourceMapBuilder::new();
+
+    let tokens = origins
+        .transpiled
+        .map(|map| decode_json_mappings(&map.map))
+        .unwrap_or_default();
+
+    match origins.transpiled {
+        Some(map) => {
+            // Anchor each transform input line, then follow the transform's own tokens
+            let input_lines: Vec<&str> = map.source.split('\n').collect();
+            let mut anchors = vec![None; input_lines.len()];
+            for &(line, offset) in origins.script_lines.iter() {
+                if let Some(anchor) = anchors.get_mut(line as usize) {
+                    *anchor = Some(source_index.position(offset));
+                }
+            }
+            for token in tokens.iter() {
+                let Some(Some((line, column))) = anchors.get(token.source_line as usize) else {
+                    continue;
+                };
+                let indent = indentation(input_lines[token.source_line as usize]);
+                let generated_line = origins.script_line + token.generated_line;
+                builder.add(Mapping {
+                    generated_line,
+                    generated_column: expand_tabs(
+                        map,
+                        token.generated_line,
+                        token.generated_column,
+                    ),
+                    source_line: *line,
+                    source_column: column + token.source_column.saturating_sub(indent),
+                });
+            }
+        }
+        None => {
+            for &(line, offset) in origins.script_lines.iter() {
+                let generated_line = origins.script_line + line;
+                let Some(text) = code_lines.get(generated_line as usize) else {
+                    continue;
+                };
+                let (source_line, source_column) = source_index.position(offset);
+                builder.add(Mapping {
+                    generated_line,
+                    generated_column: indentation(text),
+                    source_line,
+                    source_column,
+                });
+            }
+        }
+    }
+
+    if let Some(template) = descriptor.template.as_ref() {
+        let transpiled = origins
+            .transpiled
+            .filter(|_| origins.template_transpiled)
+            .map(|map| {
+                let mut by_input = tokens.clone();
+                by_input.sort_by_key(|token| (token.source_line, token.source_column));
+                (map, LineIndex::new(&map.source), by_input)
+            });
+        let code_index = LineIndex::new(code);
+
+        for &(generated, offset) in origins.template.iter() {
+            let (generated_line, generated_column) = match transpiled.as_ref() {
+                // Only follow positions the transform mapped itself
+                Some((map, input_index, by_input)) => {
+                    let position = input_index.position(generated);
+                    let Ok(found) = by_input.binary_search_by_key(&position, |token| {
+                        (token.source_line, token.source_column)
+                    }) else {
+                        continue;
+                    };
+                    let token = &by_input[found];
+                    let generated_line = origins.script_line + token.generated_line;
+                    (
+                        generated_line,
+                        expand_tabs(map, token.generated_line, token.generated_column),
+                    )
+                }
+                None if generated < code.len() => code_index.position(generated),
+                None => continue,
+            };
+            let (source_line, source_column) =
+                source_index.position(template.loc.start + offset as usize);
+            builder.add(Mapping {
+                generated_line,
+                generated_column,
+                source_line,
+                source_column,
+            });
+        }
+    }
+
+    serde_json::from_str(&builder.to_json(filename, &descriptor.source)).ok()
+}
+
+/// `(generated offset, template offset)` pairs from a template-level source map
+///
+/// Columns in `map` are UTF-16 code units, as emitted by the Vapor compiler.
+pub(crate) fn expression_offsets_from_map(
+    map: &str,
+    code: &str,
+    template: &str,
+) -> Vec<(usize, u32)> {
+    let code_lines: Vec<(usize, &str)> = line_spans(code);
+    let template_lines: Vec<(usize, &str)> = line_spans(template);
+    decode_json_mappings(map)
+        .into_iter()
+        .filter_map(|mapping| {
+            let (code_start, code_line) = code_lines.get(mapping.generated_line as usize)?;
+            let (template_start, template_line) =
+                template_lines.get(mapping.source_line as usize)?;
+            Some((
+                code_start + utf16_to_byte(code_line, mapping.generated_column),
+                (template_start + utf16_to_byte(template_line, mapping.source_column)) as u32,
+            ))
+        })
+        .collect()
+}
+
+/// Decode the `mappings` of a Source Map v3 JSON string
+pub(crate) fn decode_json_mappings(map: &str) -> Vec<Mapping> {
+    serde_json::from_str::<serde_json::Value>(map)
+        .ok()
+        .and_then(|map| map.get("mappings")?.as_str().map(decode_mappings))
+        .unwrap_or_default()
+}
+
+fn indentation(line: &str) -> u32 {
+    (line.len() - line.trim_start().len()) as u32
+}
+
+/// Column in the final code of a transform output column, whose indentation
+/// tabs were each expanded to two spaces
+fn expand_tabs(map: &TranspileMap, line: u32, column: u32) -> u32 {
+    let tabs = map.indent_tabs.get(line as usize).copied().unwrap_or(0);
+    column + tabs.min(column)
+}
+
+/// Each line of `text` with its starting byte offset
+fn line_spans(text: &str) -> Vec<(usize, &str)> {
+    let mut start = 0;
+    text.split('\n')
+        .map(|line| {
+            let span = (start, line);
+            start += line.len() + 1;
+            span
+        })
+        .collect()
+}
+
+fn utf16_to_byte(line: &str, column: u32) -> usize {
+    let mut units = 0;
+    for (index, c) in line.char_indices() {
+        if units >= column as usize {
+            return index;
+        }
+        units += c.len_utf16();
+    }
+    line.len()
+}
+
+/// Byte offset to 0-based line and UTF-16 column lookup
+struct LineIndex<'t> {
+    text: &'t str,
+    line_starts: Vec<usize>,
+}
+
+impl<'t> LineIndex<'t> {
+    fn new(text: &'t str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        Self { text, line_starts }
+    }
+
+    fn position(&self, offset: usize) -> (u32, u32) {
+        let line = self
+            .line_starts
+            .partition_point(|&start| start <= offset)
+            .saturating_sub(1);
+        let start = self.line_starts[line];
+        let column = match self.text.get(start..offset) {
+            Some(prefix) => prefix.encode_utf16().count(),
+            None => offset - start,
+        };
+        (line as u32, column as u32)
+    }
+}
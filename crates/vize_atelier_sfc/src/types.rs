@@ -375,6 +375,9 @@ pub enum PadOption {
     Space,
 }
 
+/// Handler emitting module code for a custom block
+pub type CustomBlockTransform = fn(&SfcCustomBlock<'_>) -> Result<String, SfcError>;
+
 /// SFC compilation options
 #[derive(Debug, Clone, Default)]
 pub struct SfcCompileOptions {
@@ -389,6 +392,11 @@ pub struct SfcCompileOptions {
 
     /// Style compile options
     pub style: StyleCompileOptions,
+
+    /// Custom block handlers keyed by block type (e.g. `i18n`).
+    /// Their output is appended to the module after the render function;
+    /// blocks without a handler are dropped.
+    pub custom_block_transforms: FxHashMap<String, CustomBlockTransform>,
 }
 
 /// Script compile options
@@ -437,6 +445,9 @@ pub struct TemplateCompileOptions {
     /// SSR CSS vars
     pub ssr_css_vars: Option<String>,
 
+    /// Mark non-deterministic bindings with `data-allow-mismatch` in SSR output
+    pub ssr_hydration_hints: bool,
+
     /// Scoped
     pub scoped: bool,
 
@@ -446,10 +457,22 @@ pub struct TemplateCompileOptions {
     /// Whether TypeScript mode
     pub is_ts: bool,
 
+    /// Compile with the Vapor compiler even without `<script vapor>`
+    pub vapor: bool,
+
     /// Compiler options
     pub compiler_options: Option<vize_atelier_dom::DomCompilerOptions>,
 }
 
+impl TemplateCompileOptions {
+    /// Module the generated helper imports come from (`vue` unless configured)
+    pub fn runtime_module_name(&self) -> &str {
+        self.compiler_options
+            .as_ref()
+            .map_or("vue", |opts| opts.runtime_module_name.as_str())
+    }
+}
+
 /// Style compile options
 #[derive(Debug, Clone, Default)]
 pub struct StyleCompileOptions {
@@ -8,6 +8,7 @@ use vize_atelier_core::ast::{
     CommentNode, ElementNode, ElementType, ForNode, IfNode, InterpolationNode, RootNode,
     RuntimeHelper, TemplateChildNode, TextNode,
 };
+use vize_atelier_core::codegen::escape_js_string;
 use vize_carton::{Bump, FxHashSet};
 
 /// SSR codegen result
@@ -197,6 +198,13 @@ impl<'a> SsrCodegenContext<'a> {
         self.push_string_part_static("<");
         self.push_string_part_static(tag);
 
+        // Hydration mismatch hint
+        if let Some(kinds) = self.hydration_mismatch_kinds(el) {
+            self.push_string_part_static(" data-allow-mismatch=\"");
+            self.push_string_part_static(&kinds);
+            self.push_string_part_static("\"");
+        }
+
         // Process attributes
         self.process_element_attrs(el);
 
@@ -227,6 +235,11 @@ impl<'a> SsrCodegenContext<'a> {
     fn process_element_attrs(&mut self, el: &ElementNode) {
         use vize_atelier_core::ast::PropNode;
 
+        if has_object_bind(el) {
+            self.process_merged_attrs(el);
+            return;
+        }
+
         for prop in &el.props {
             match prop {
                 PropNode::Attribute(attr) => {
@@ -246,6 +259,154 @@ impl<'a> SsrCodegenContext<'a> {
         }
     }
 
+    /// Render the attributes of an element carrying `v-bind="obj"` (including the
+    /// injected `_attrs`) through a single `_ssrRenderAttrs(_mergeProps(...))`, so
+    /// `class` and `style` from the object merge with the element's own values
+    fn process_merged_attrs(&mut self, el: &ElementNode) {
+        use vize_atelier_core::ast::{ExpressionNode, PropNode};
+
+        let mut args: Vec<String> = Vec::new();
+        let mut entries: Vec<(String, Vec<String>)> = Vec::new();
+        let flush = |entries: &mut Vec<(String, Vec<String>)>, args: &mut Vec<String>| {
+            if !entries.is_empty() {
+                args.push(object_literal(entries));
+                entries.clear();
+            }
+        };
+
+        for prop in &el.props {
+            match prop {
+                PropNode::Attribute(attr) => {
+                    let value = attr
+                        .value
+                        .as_ref()
+                        .map(|v| escape_js_string(&v.content))
+                        .unwrap_or_default();
+                    push_merged_entry(&mut entries, &attr.name, format!("\"{}\"", value));
+                }
+                PropNode::Directive(dir) if dir.name == "bind" => {
+                    let Some(exp) = &dir.exp else { continue };
+                    match &dir.arg {
+                        Some(ExpressionNode::Simple(arg)) if arg.is_static => {
+                            push_merged_entry(
+                                &mut entries,
+                                &arg.content,
+                                expression_to_string(exp),
+                            );
+                        }
+                        Some(arg) => {
+                            flush(&mut entries, &mut args);
+                            args.push(format!(
+                                "{{ [{}]: {} }}",
+                                expression_to_string(arg),
+                                expression_to_string(exp)
+                            ));
+                        }
+                        None => {
+                            flush(&mut entries, &mut args);
+                            args.push(expression_to_string(exp));
+                        }
+                    }
+                }
+                PropNode::Directive(_) => {}
+            }
+        }
+        flush(&mut entries, &mut args);
+
+        self.use_ssr_helper(RuntimeHelper::SsrRenderAttrs);
+        if args.len() == 1 {
+            self.push_string_part_dynamic(&format!("_ssrRenderAttrs({})", args[0]));
+        } else {
+            self.use_core_helper(RuntimeHelper::MergeProps);
+            self.push_string_part_dynamic(&format!(
+                "_ssrRenderAttrs(_mergeProps({}))",
+                args.join(", ")
+            ));
+        }
+
+        // v-model, v-show and custom directives still render on their own
+        for prop in &el.props {
+            if let PropNode::Directive(dir) = prop {
+                if dir.name != "bind" {
+                    self.process_directive_on_element(el, dir);
+                }
+            }
+        }
+    }
+
+    /// Get the `data-allow-mismatch` kinds for an element whose bindings are
+    /// non-deterministic (e.g. derived from `Date.now()`), when hints are enabled
+    fn hydration_mismatch_kinds(&self, el: &ElementNode) -> Option<String> {
+        use vize_atelier_core::ast::{ExpressionNode, PropNode};
+
+        if !self.options.ssr_hydration_hints {
+            return None;
+        }
+
+        let mut kinds: Vec<&str> = Vec::new();
+        for prop in &el.props {
+            let PropNode::Directive(dir) = prop else {
+                continue;
+            };
+            if dir.name != "bind" || !dir.exp.as_ref().is_some_and(|e| self.is_impure(e)) {
+                continue;
+            }
+            let kind = match &dir.arg {
+                Some(ExpressionNode::Simple(arg)) if arg.content == "class" => "class",
+                Some(ExpressionNode::Simple(arg)) if arg.content == "style" => "style",
+                _ => "attribute",
+            };
+            if !kinds.contains(&kind) {
+                kinds.push(kind);
+            }
+        }
+        let has_impure_text = el.children.iter().any(|child| {
+            matches!(child, TemplateChildNode::Interpolation(interp) if self.is_impure(&interp.content))
+        });
+        if has_impure_text {
+            kinds.push("text");
+        }
+
+        (!kinds.is_empty()).then(|| kinds.join(","))
+    }
+
+    /// Check if an expression is non-deterministic: it calls `Date.now()` and
+    /// the like directly, or references a binding Croquis flagged as impure
+    fn is_impure(&self, expr: &vize_atelier_core::ast::ExpressionNode) -> bool {
+        let content = expression_to_string(expr);
+        if vize_croquis::builtins::is_nondeterministic_expression(&content) {
+            return true;
+        }
+        let Some(croquis) = &self.options.croquis else {
+            return false;
+        };
+        if croquis.impure_bindings.is_empty() {
+            return false;
+        }
+
+        let bytes = content.as_bytes();
+        let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'$';
+        let mut i = 0;
+        while i < bytes.len() {
+            if !is_ident(bytes[i]) || bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < bytes.len() && is_ident(bytes[i]) {
+                i += 1;
+            }
+            // Member properties only count when accessed through the render context
+            let before = &content[..start];
+            let is_reference =
+                !before.ends_with('.') || before.ends_with("_ctx.") || before.ends_with("$setup.");
+            if is_reference && croquis.impure_bindings.contains(&content[start..i]) {
+                return true;
+            }
+        }
+        false
+    }
+
     /// Process a directive on an element
     fn process_directive_on_element(
         &mut self,
@@ -435,16 +596,41 @@ impl<'a> SsrCodegenContext<'a> {
         None
     }
 
+    /// Check if a component tag refers to a binding Croquis saw created with
+    /// `defineAsyncComponent()`
+    fn is_async_component(&self, tag: &str) -> bool {
+        let Some(croquis) = &self.options.croquis else {
+            return false;
+        };
+        !croquis.async_components.is_empty()
+            && (croquis.async_components.contains(tag)
+                || croquis
+                    .async_components
+                    .contains(&vize_croquis::naming::to_pascal_case(tag)))
+    }
+
     /// Process a component
+    ///
+    /// Async components chain their render onto `__asyncLoader()`, the loader
+    /// `defineAsyncComponent` attaches to its wrapper. The server renderer
+    /// awaits promises pushed to the buffer in order, so the chunk is loaded
+    /// before the component renders.
     fn process_component(&mut self, el: &ElementNode, _disable_nested_fragments: bool) {
         self.flush_push();
         self.use_ssr_helper(RuntimeHelper::SsrRenderComponent);
         self.use_core_helper(RuntimeHelper::ResolveComponent);
 
         let tag = &el.tag;
+        let is_async = self.is_async_component(tag);
 
         self.push_indent();
-        self.push("_push(_ssrRenderComponent(_component_");
+        if is_async {
+            self.push("_push(_component_");
+            self.push(tag);
+            self.push(".__asyncLoader().then(() => _ssrRenderComponent(_component_");
+        } else {
+            self.push("_push(_ssrRenderComponent(_component_");
+        }
         self.push(tag);
         self.push(", _attrs, ");
 
@@ -474,10 +660,17 @@ impl<'a> SsrCodegenContext<'a> {
             self.push("}");
         }
 
-        self.push(", _parent))\n");
+        if is_async {
+            self.push(", _parent)))\n");
+        } else {
+            self.push(", _parent))\n");
+        }
     }
 
     /// Process a slot outlet (<slot>)
+    ///
+    /// Lowers to `_ssrRenderSlot(_ctx.$slots, name, props, fallback, _push, _parent)`,
+    /// where the fallback children render into a nested buffer function.
     fn process_slot_outlet(&mut self, el: &ElementNode) {
         self.flush_push();
         self.use_ssr_helper(RuntimeHelper::SsrRenderSlot);
@@ -485,14 +678,13 @@ impl<'a> SsrCodegenContext<'a> {
         self.push_indent();
         self.push("_ssrRenderSlot(_ctx.$slots, ");
 
-        // Get slot name
-        let slot_name = self.get_slot_name(el);
-        self.push("\"");
-        self.push(&slot_name);
-        self.push("\", ");
+        // Slot name (static or dynamic)
+        self.push_slot_name(el);
+        self.push(", ");
 
         // Slot props
-        self.push("{}, ");
+        self.push_slot_props(el);
+        self.push(", ");
 
         // Fallback content
         if el.children.is_empty() {
@@ -521,30 +713,100 @@ impl<'a> SsrCodegenContext<'a> {
         self.push(")\n");
     }
 
-    /// Get the name of a slot
-    fn get_slot_name(&self, el: &ElementNode) -> String {
+    /// Push the name of a slot outlet: a string literal for `name="..."`,
+    /// the expression for `:name="..."`, or `"default"`.
+    fn push_slot_name(&mut self, el: &ElementNode) {
+        use vize_atelier_core::ast::PropNode;
+
+        for prop in &el.props {
+            match prop {
+                PropNode::Attribute(attr) if attr.name == "name" => {
+                    if let Some(value) = &attr.value {
+                        self.push("\"");
+                        self.push(&escape_js_string(&value.content));
+                        self.push("\"");
+                        return;
+                    }
+                }
+                PropNode::Directive(dir) if is_slot_name_binding(dir) => {
+                    if let Some(exp) = &dir.exp {
+                        self.push_expression(exp);
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.push("\"default\"");
+    }
+
+    /// Push the props object passed to a slot outlet (all props except `name`)
+    fn push_slot_props(&mut self, el: &ElementNode) {
         use vize_atelier_core::ast::{ExpressionNode, PropNode};
 
+        let mut objects = Vec::new();
+        let mut entries = Vec::new();
         for prop in &el.props {
-            if let PropNode::Directive(dir) = prop {
-                if dir.name == "bind" {
-                    if let Some(ExpressionNode::Simple(arg)) = &dir.arg {
-                        if arg.content == "name" {
-                            if let Some(ExpressionNode::Simple(exp)) = &dir.exp {
-                                return exp.content.to_string();
-                            }
+            match prop {
+                PropNode::Attribute(attr) if attr.name != "name" => {
+                    let value = attr
+                        .value
+                        .as_ref()
+                        .map(|v| escape_js_string(&v.content))
+                        .unwrap_or_default();
+                    entries.push((
+                        vize_carton::camelize(&attr.name).to_string(),
+                        format!("\"{}\"", value),
+                    ));
+                }
+                PropNode::Directive(dir) if dir.name == "bind" && !is_slot_name_binding(dir) => {
+                    let Some(exp) = &dir.exp else { continue };
+                    match &dir.arg {
+                        Some(ExpressionNode::Simple(arg)) if arg.is_static => {
+                            entries.push((
+                                vize_carton::camelize(&arg.content).to_string(),
+                                expression_to_string(exp),
+                            ));
                         }
+                        None => objects.push(expression_to_string(exp)),
+                        _ => {}
                     }
                 }
-            } else if let PropNode::Attribute(attr) = prop {
-                if attr.name == "name" {
-                    if let Some(value) = &attr.value {
-                        return value.content.to_string();
-                    }
+                _ => {}
+            }
+        }
+
+        let mut literal = String::new();
+        if !entries.is_empty() || objects.is_empty() {
+            literal.push('{');
+            for (i, (key, value)) in entries.iter().enumerate() {
+                literal.push_str(if i == 0 { " " } else { ", " });
+                if is_simple_identifier(key) {
+                    literal.push_str(key);
+                } else {
+                    literal.push('"');
+                    literal.push_str(key);
+                    literal.push('"');
                 }
+                literal.push_str(": ");
+                literal.push_str(value);
+            }
+            literal.push_str(if entries.is_empty() { "}" } else { " }" });
+        }
+
+        if objects.is_empty() {
+            self.push(&literal);
+        } else {
+            // v-bind="obj" on the outlet merges the object into the props
+            self.use_core_helper(RuntimeHelper::MergeProps);
+            self.push("_mergeProps(");
+            self.push(&objects.join(", "));
+            if !literal.is_empty() {
+                self.push(", ");
+                self.push(&literal);
             }
+            self.push(")");
         }
-        "default".to_string()
     }
 
     /// Process a text node
@@ -788,8 +1050,9 @@ impl<'a> SsrCodegenContext<'a> {
         // SSR helpers from @vue/server-renderer
         if !self.ssr_helpers.is_empty() {
             preamble.push_str("import { ");
-            let helpers: Vec<_> = self
-                .ssr_helpers
+            let mut helpers: Vec<_> = self.ssr_helpers.iter().copied().collect();
+            helpers.sort();
+            let helpers: Vec<_> = helpers
                 .iter()
                 .map(|h| format!("{} as _{}", h.name(), h.name()))
                 .collect();
@@ -797,22 +1060,95 @@ impl<'a> SsrCodegenContext<'a> {
             preamble.push_str(" } from \"@vue/server-renderer\"\n");
         }
 
-        // Core helpers from vue
+        // Core helpers from the runtime module
         if !self.core_helpers.is_empty() {
             preamble.push_str("import { ");
-            let helpers: Vec<_> = self
-                .core_helpers
+            let mut helpers: Vec<_> = self.core_helpers.iter().copied().collect();
+            helpers.sort();
+            let helpers: Vec<_> = helpers
                 .iter()
                 .map(|h| format!("{} as _{}", h.name(), h.name()))
                 .collect();
             preamble.push_str(&helpers.join(", "));
-            preamble.push_str(" } from \"vue\"\n");
+            preamble.push_str(" } from \"");
+            preamble.push_str(&self.options.runtime_module_name);
+            preamble.push_str("\"\n");
         }
 
         preamble
     }
 }
 
+/// Check if an element carries an argument-less `v-bind`
+fn has_object_bind(el: &ElementNode) -> bool {
+    use vize_atelier_core::ast::PropNode;
+
+    el.props
+        .iter()
+        .any(|p| matches!(p, PropNode::Directive(dir) if dir.name == "bind" && dir.arg.is_none()))
+}
+
+/// Add a key to a pending props object, collecting repeated `class`/`style`
+/// values into an array the way `mergeProps` would
+fn push_merged_entry(entries: &mut Vec<(String, Vec<String>)>, key: &str, value: String) {
+    if let Some((_, values)) = entries
+        .iter_mut()
+        .find(|(k, _)| k == key && (key == "class" || key == "style"))
+    {
+        values.push(value);
+    } else {
+        entries.push((key.to_string(), vec![value]));
+    }
+}
+
+/// Build an object literal from merged prop entries
+fn object_literal(entries: &[(String, Vec<String>)]) -> String {
+    let props: Vec<String> = entries
+        .iter()
+        .map(|(key, values)| {
+            let key = if is_simple_identifier(key) {
+                key.clone()
+            } else {
+                format!("\"{}\"", key)
+            };
+            let value = if values.len() == 1 {
+                values[0].clone()
+            } else {
+                format!("[{}]", values.join(", "))
+            };
+            format!("{}: {}", key, value)
+        })
+        .collect();
+    format!("{{ {} }}", props.join(", "))
+}
+
+/// Check if a directive is the `:name` binding of a slot outlet
+fn is_slot_name_binding(dir: &vize_atelier_core::ast::DirectiveNode) -> bool {
+    use vize_atelier_core::ast::ExpressionNode;
+
+    dir.name == "bind"
+        && matches!(&dir.arg, Some(ExpressionNode::Simple(arg)) if arg.content == "name")
+}
+
+/// Flatten an expression node into source code
+fn expression_to_string(expr: &vize_atelier_core::ast::ExpressionNode) -> String {
+    use vize_atelier_core::ast::ExpressionNode;
+
+    match expr {
+        ExpressionNode::Simple(simple) => simple.content.to_string(),
+        ExpressionNode::Compound(compound) => compound.loc.source.to_string(),
+    }
+}
+
+/// Check if a string can be used as an object key without quotes
+fn is_simple_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
 /// Escape HTML special characters
 fn escape_html(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
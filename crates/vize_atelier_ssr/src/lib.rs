@@ -85,13 +85,15 @@ pub fn compile_ssr_with_options<'a>(
         inline: options.inline,
         ..Default::default()
     };
-    do_transform(allocator, &mut root, transform_opts, None);
+    let mut errors = errors.to_vec();
+    errors.extend(do_transform(allocator, &mut root, transform_opts, None));
+    inject_fallthrough_attrs(allocator, &mut root);
 
     // SSR codegen
     let codegen_ctx = SsrCodegenContext::new(allocator, &options);
     let codegen_result = codegen_ctx.generate(&root);
 
-    (root, errors.to_vec(), codegen_result)
+    (root, errors, codegen_result)
 }
 
 /// Get the namespace for an element based on its parent
@@ -151,4 +153,178 @@ mod tests {
             result.code
         );
     }
+
+    #[test]
+    fn test_compile_named_slot_with_fallback() {
+        let allocator = Bump::new();
+        let (_, errors, result) = compile_ssr(
+            &allocator,
+            r#"<div><slot name="header"><p>fallback</p></slot></div>"#,
+        );
+
+        assert!(errors.is_empty());
+        assert!(
+            result
+                .code
+                .contains("_ssrRenderSlot(_ctx.$slots, \"header\", {}, () => {"),
+            "Expected named slot render, got:\n{}",
+            result.code
+        );
+        assert!(result.code.contains("<p>fallback</p>"), "{}", result.code);
+        assert!(result.preamble.contains("ssrRenderSlot"));
+    }
+
+    #[test]
+    fn test_compile_dynamic_slot_with_props() {
+        let allocator = Bump::new();
+        let (_, errors, result) =
+            compile_ssr(&allocator, r#"<slot :name="slotName" :item="item" />"#);
+
+        assert!(errors.is_empty());
+        assert!(
+            result
+                .code
+                .contains("_ssrRenderSlot(_ctx.$slots, _ctx.slotName, { item: _ctx.item }, null"),
+            "Expected dynamic slot render, got:\n{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_async_component_awaits_its_loader() {
+        let mut analyzer = vize_croquis::Analyzer::new();
+        analyzer.analyze_script_setup(
+            "import Eager from './Eager.vue'\n\
+             const LazyChart = defineAsyncComponent(() => import('./Chart.vue'))",
+        );
+        let options = SsrCompilerOptions {
+            croquis: Some(Box::new(analyzer.finish())),
+            ..Default::default()
+        };
+
+        let allocator = Bump::new();
+        let (_, errors, result) =
+            compile_ssr_with_options(&allocator, r#"<div><Eager /><LazyChart /></div>"#, options);
+
+        assert!(errors.is_empty());
+        assert!(
+            result.code.contains(
+                "_push(_component_LazyChart.__asyncLoader().then(() => \
+                 _ssrRenderComponent(_component_LazyChart, _attrs, null, _parent)))"
+            ),
+            "Expected async component to await its loader, got:\n{}",
+            result.code
+        );
+        assert!(
+            result
+                .code
+                .contains("_push(_ssrRenderComponent(_component_Eager, _attrs, null, _parent))"),
+            "Only the async component should await a loader:\n{}",
+            result.code
+        );
+        assert!(!result.preamble.contains("ssrRenderSuspense"));
+    }
+
+    #[test]
+    fn test_hydration_hints_for_impure_bindings() {
+        let mut analyzer = vize_croquis::Analyzer::new();
+        analyzer.analyze_script_setup("const now = Date.now()\nconst title = 'hello'");
+        let options = SsrCompilerOptions {
+            ssr_hydration_hints: true,
+            croquis: Some(Box::new(analyzer.finish())),
+            ..Default::default()
+        };
+
+        let allocator = Bump::new();
+        let (_, errors, result) = compile_ssr_with_options(
+            &allocator,
+            r#"<div><time :datetime="now"></time><span :title="title"></span></div>"#,
+            options,
+        );
+
+        assert!(errors.is_empty());
+        assert!(
+            result
+                .code
+                .contains("<time data-allow-mismatch=\"attribute\""),
+            "Expected hint on impure binding, got:\n{}",
+            result.code
+        );
+        assert_eq!(
+            result.code.matches("data-allow-mismatch").count(),
+            1,
+            "Pure binding should not get a hint:\n{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_fallthrough_attrs_on_single_root() {
+        let allocator = Bump::new();
+        let (_, errors, result) = compile_ssr(&allocator, r#"<div class="wrapper"><slot /></div>"#);
+
+        assert!(errors.is_empty());
+        assert!(
+            result
+                .code
+                .contains(r#"<div${_ssrRenderAttrs(_mergeProps({ class: "wrapper" }, _attrs))}>"#),
+            "Expected fallthrough attrs merged into the root, got:\n{}",
+            result.code
+        );
+        assert!(result.preamble.contains("ssrRenderAttrs"));
+        assert!(result.preamble.contains("mergeProps"));
+    }
+
+    #[test]
+    fn test_no_fallthrough_attrs_on_multiple_roots() {
+        let allocator = Bump::new();
+        let (_, errors, result) = compile_ssr(&allocator, "<header></header><main></main>");
+
+        assert!(errors.is_empty());
+        assert!(
+            !result.code.contains("_ssrRenderAttrs"),
+            "Multi-root template should not inherit attrs, got:\n{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_scope_id_in_element_strings() {
+        let options = SsrCompilerOptions {
+            scope_id: Some("data-v-7ba5bd90".into()),
+            ..Default::default()
+        };
+
+        let allocator = Bump::new();
+        let (_, errors, result) = compile_ssr_with_options(
+            &allocator,
+            r#"<div class="card"><p v-if="ok">{{ msg }}</p><img src="a.png"></div>"#,
+            options,
+        );
+
+        assert!(errors.is_empty());
+        for tag in ["<p", "<img src=\"a.png\""] {
+            assert!(
+                result.code.contains(&format!("{tag} data-v-7ba5bd90")),
+                "Expected scope attribute on {tag}, got:\n{}",
+                result.code
+            );
+        }
+        // The root element also carries it, after the fallthrough attrs
+        assert_eq!(
+            result.code.matches(" data-v-7ba5bd90").count(),
+            3,
+            "{}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_hydration_hints_disabled_by_default() {
+        let allocator = Bump::new();
+        let (_, errors, result) = compile_ssr(&allocator, "<p>{{ Date.now() }}</p>");
+
+        assert!(errors.is_empty());
+        assert!(!result.code.contains("data-allow-mismatch"));
+    }
 }
@@ -2,9 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 use vize_carton::String;
+use vize_croquis::Croquis;
 
 /// SSR compiler options
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SsrCompilerOptions {
     /// Scope ID for scoped CSS (data-v-xxx)
@@ -26,6 +27,54 @@ pub struct SsrCompilerOptions {
     /// CSS variables to inject (from SFC <style> blocks with v-bind)
     #[serde(default)]
     pub ssr_css_vars: Option<String>,
+
+    /// Mark elements with non-deterministic bindings (e.g. `Date.now()`) with
+    /// `data-allow-mismatch`. A heuristic debugging aid intended for dev builds.
+    #[serde(default)]
+    pub ssr_hydration_hints: bool,
+
+    /// Module core runtime helpers are imported from
+    #[serde(default = "default_runtime_module_name")]
+    pub runtime_module_name: String,
+
+    /// Semantic analysis data from Croquis (used for hydration hints)
+    #[serde(skip)]
+    pub croquis: Option<Box<Croquis>>,
+}
+
+fn default_runtime_module_name() -> String {
+    String::from("vue")
+}
+
+impl Default for SsrCompilerOptions {
+    fn default() -> Self {
+        Self {
+            scope_id: None,
+            comments: false,
+            inline: false,
+            is_ts: false,
+            ssr_css_vars: None,
+            ssr_hydration_hints: false,
+            runtime_module_name: default_runtime_module_name(),
+            croquis: None,
+        }
+    }
+}
+
+impl Clone for SsrCompilerOptions {
+    fn clone(&self) -> Self {
+        Self {
+            scope_id: self.scope_id.clone(),
+            comments: self.comments,
+            inline: self.inline,
+            is_ts: self.is_ts,
+            ssr_css_vars: self.ssr_css_vars.clone(),
+            ssr_hydration_hints: self.ssr_hydration_hints,
+            runtime_module_name: self.runtime_module_name.clone(),
+            // Croquis is not cloneable
+            croquis: None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -40,5 +89,7 @@ mod tests {
         assert!(!opts.inline);
         assert!(!opts.is_ts);
         assert!(opts.ssr_css_vars.is_none());
+        assert!(!opts.ssr_hydration_hints);
+        assert_eq!(opts.runtime_module_name, "vue");
     }
 }
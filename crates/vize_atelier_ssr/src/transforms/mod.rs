@@ -3,11 +3,63 @@
 //! This module contains SSR-specific transform passes that modify the AST
 //! for optimal SSR code generation.
 
-use vize_atelier_core::ast::{ElementNode, ExpressionNode, PropNode};
+use vize_atelier_core::ast::{
+    DirectiveNode, ElementNode, ElementType, ExpressionNode, PropNode, RootNode,
+    SimpleExpressionNode, SourceLocation, TemplateChildNode,
+};
+use vize_carton::{Box, Bump};
 
 // For now, most SSR-specific transforms are integrated directly into the codegen.
 // This module will be expanded as we add more sophisticated transforms.
 
+/// Inject fallthrough attributes into the root element of a single-root template.
+///
+/// Adds `v-bind="_attrs"` to the root element (or to each `v-if` branch root), so
+/// its opening tag renders `_ssrRenderAttrs(_attrs)`. Multi-root templates are left
+/// untouched, since Vue does not inherit attributes automatically for them. Runs
+/// after the core transform so the `_attrs` parameter is not prefixed.
+pub fn inject_fallthrough_attrs<'a>(allocator: &'a Bump, root: &mut RootNode<'a>) {
+    let mut roots = root
+        .children
+        .iter_mut()
+        .filter(|child| !matches!(child, TemplateChildNode::Comment(_)));
+    let (Some(child), None) = (roots.next(), roots.next()) else {
+        return;
+    };
+
+    match child {
+        TemplateChildNode::Element(el) => inject_attrs_into_element(allocator, el),
+        TemplateChildNode::If(if_node) => {
+            for branch in if_node.branches.iter_mut() {
+                let mut branch_roots = branch
+                    .children
+                    .iter_mut()
+                    .filter(|child| !matches!(child, TemplateChildNode::Comment(_)));
+                if let (Some(TemplateChildNode::Element(el)), None) =
+                    (branch_roots.next(), branch_roots.next())
+                {
+                    inject_attrs_into_element(allocator, el);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Add `v-bind="_attrs"` to a plain element.
+///
+/// Components already receive `_attrs` through `_ssrRenderComponent`.
+fn inject_attrs_into_element<'a>(allocator: &'a Bump, el: &mut ElementNode<'a>) {
+    if el.tag_type != ElementType::Element {
+        return;
+    }
+    let mut dir = DirectiveNode::new(allocator, "bind", SourceLocation::STUB);
+    let exp = SimpleExpressionNode::new("_attrs", false, SourceLocation::STUB);
+    dir.exp = Some(ExpressionNode::Simple(Box::new_in(exp, allocator)));
+    el.props
+        .push(PropNode::Directive(Box::new_in(dir, allocator)));
+}
+
 /// Check if an element has v-model directive
 pub fn has_v_model(el: &ElementNode) -> bool {
     el.props
@@ -87,10 +139,6 @@ pub fn get_v_text_exp<'a>(el: &'a ElementNode<'a>) -> Option<&'a ExpressionNode<
 #[cfg(test)]
 mod tests {
     use super::*;
-    use vize_atelier_core::ast::{
-        DirectiveNode, ElementNode, ExpressionNode, SimpleExpressionNode, SourceLocation,
-    };
-    use vize_carton::{Box, Bump};
 
     fn make_element_with_directive<'a>(
         allocator: &'a Bump,
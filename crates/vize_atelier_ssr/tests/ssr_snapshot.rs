@@ -9,7 +9,7 @@ use vize_carton::Bump;
 /// Helper to get the compiled string content (the template literal part)
 fn get_compiled_string(src: &str) -> String {
     let allocator = Bump::new();
-    // Wrap in a div to avoid root-level attr injection
+    // Wrap in a div so the element under test is not the root receiving `_attrs`
     let wrapped = format!("<div>{}</div>", src);
     let (_, errors, result) = compile_ssr(&allocator, &wrapped);
 
@@ -0,0 +1,134 @@
+//! Reusable Vapor compiler for batch compilation.
+//!
+//! [`compile_vapor`] expects a caller-provided arena, which usually means a
+//! fresh [`Bump`] per file. When compiling thousands of templates, a single
+//! [`VaporCompiler`] keeps one arena alive and resets it between files, so its
+//! chunks are reused instead of being freed and allocated again. Runtime
+//! helper import lines are cached the same way, since most templates in a
+//! project import the same helpers.
+
+use crate::generate::HelperImportCache;
+use crate::{compile_vapor_with_cache, VaporCompileResult, VaporCompilerOptions};
+use vize_carton::Bump;
+
+/// A Vapor compiler that owns and reuses its arena allocator.
+///
+/// # Lifetimes
+///
+/// The AST and IR of a file live in the arena only while [`Self::compile`]
+/// runs. The returned [`VaporCompileResult`] owns all of its data (code,
+/// templates, diagnostics, source map) and borrows neither the source nor the
+/// compiler, so results may be kept after later calls reset the arena.
+///
+/// ```ignore
+/// let mut compiler = VaporCompiler::new();
+/// for (path, source) in files {
+///     let result = compiler.compile(&source, VaporCompilerOptions::default());
+///     write_output(path, result.code);
+/// }
+/// ```
+#[derive(Default)]
+pub struct VaporCompiler {
+    allocator: Bump,
+    /// Import lines keyed by the helpers a template uses
+    helper_imports: HelperImportCache,
+}
+
+impl VaporCompiler {
+    /// Create a compiler with an empty arena.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a compiler whose arena starts with `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            allocator: Bump::with_capacity(capacity),
+            helper_imports: HelperImportCache::default(),
+        }
+    }
+
+    /// Compile a template, reusing the arena and helper caches of previous calls.
+    pub fn compile(&mut self, source: &str, options: VaporCompilerOptions) -> VaporCompileResult {
+        // Nothing borrows the arena across calls, so resetting here is sound
+        self.allocator.reset();
+        compile_vapor_with_cache(&self.allocator, source, options, &mut self.helper_imports)
+    }
+
+    /// Bytes currently held by the arena, including unused capacity.
+    ///
+    /// After the first few files this stays at the size needed by the largest
+    /// template compiled so far.
+    pub fn allocated_bytes(&self) -> usize {
+        self.allocator.allocated_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_many_templates_with_one_instance() {
+        let mut compiler = VaporCompiler::new();
+        let mut last = None;
+
+        for i in 0..100 {
+            let source = format!(r#"<div class="item-{i}">{{{{ msg{i} }}}}</div>"#);
+            last = Some(compiler.compile(&source, VaporCompilerOptions::default()));
+        }
+
+        let result = last.unwrap();
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(result.code.contains("item-99"), "{}", result.code);
+        assert!(result.code.contains("_ctx.msg99"), "{}", result.code);
+        assert!(!result.code.contains("msg98"), "{}", result.code);
+    }
+
+    #[test]
+    fn test_results_outlive_arena_reset() {
+        let mut compiler = VaporCompiler::with_capacity(4096);
+        let first = compiler.compile("<p>{{ a }}</p>", VaporCompilerOptions::default());
+        let second = compiler.compile("<span>{{ b }}</span>", VaporCompilerOptions::default());
+
+        assert!(first.code.contains("<p>"), "{}", first.code);
+        assert!(second.code.contains("<span>"), "{}", second.code);
+        assert!(compiler.allocated_bytes() > 0);
+    }
+
+    #[test]
+    fn test_helper_imports_are_cached() {
+        let mut compiler = VaporCompiler::new();
+        let first = compiler.compile("<p>{{ a }}</p>", VaporCompilerOptions::default());
+        let second = compiler.compile("<p>{{ b }}</p>", VaporCompilerOptions::default());
+        assert_eq!(compiler.helper_imports.len(), 1);
+        assert_eq!(first.code.lines().next(), second.code.lines().next());
+
+        // A different helper set or runtime module builds a new import line
+        compiler.compile("<p v-if=\"ok\">x</p>", VaporCompilerOptions::default());
+        compiler.compile(
+            "<p>{{ a }}</p>",
+            VaporCompilerOptions {
+                runtime_module_name: "vue/vapor".into(),
+                ..Default::default()
+            },
+        );
+        assert_eq!(compiler.helper_imports.len(), 3);
+    }
+
+    #[test]
+    fn test_helper_import_cache_is_bounded() {
+        let mut compiler = VaporCompiler::new();
+        for i in 0..100 {
+            let result = compiler.compile(
+                "<p>{{ a }}</p>",
+                VaporCompilerOptions {
+                    runtime_module_name: format!("vue-{i}").into(),
+                    ..Default::default()
+                },
+            );
+            assert!(result.code.contains(&format!("from 'vue-{i}'")));
+        }
+        assert!(compiler.helper_imports.len() <= 64);
+    }
+}
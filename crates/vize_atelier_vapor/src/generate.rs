@@ -4,21 +4,106 @@
 
 use std::fmt::Write;
 
+use crate::generators::{
+    escape_text, generate_apply_model, generate_memo_deps, generate_model_setter, get_model_helper,
+    get_slot_scope_identifiers, modifier_wrappers,
+};
 use crate::ir::*;
-use vize_atelier_core::ExpressionNode;
+use crate::source_map::{Mapping, SourceMapBuilder, DEFAULT_SOURCE_NAME};
+use vize_atelier_core::{
+    prefix_identifiers_in_scope, ExpressionNode, Position, SimpleExpressionNode,
+};
 use vize_carton::FxHashMap;
 
+/// Vapor code generation options
+#[derive(Debug, Clone)]
+pub struct VaporGenerateOptions {
+    /// Whether to generate a source map
+    pub source_map: bool,
+    /// Module runtime helpers are imported from
+    pub runtime_module_name: vize_carton::String,
+}
+
+impl Default for VaporGenerateOptions {
+    fn default() -> Self {
+        Self {
+            source_map: false,
+            runtime_module_name: "vue".into(),
+        }
+    }
+}
+
 /// Vapor code generation result
 pub struct VaporGenerateResult {
     /// Generated code
     pub code: std::string::String,
     /// Static templates
     pub templates: std::vec::Vec<vize_carton::String>,
+    /// Source map (v3 JSON)
+    pub map: Option<std::string::String>,
 }
 
 /// Generate Vapor code from IR
 pub fn generate_vapor(ir: &RootIRNode<'_>) -> VaporGenerateResult {
-    let mut ctx = GenerateContext::new(&ir.element_template_map);
+    generate_vapor_with_options(ir, &VaporGenerateOptions::default())
+}
+
+/// Generate Vapor code from IR with options
+pub fn generate_vapor_with_options(
+    ir: &RootIRNode<'_>,
+    options: &VaporGenerateOptions,
+) -> VaporGenerateResult {
+    generate_vapor_with_cache(ir, options, &mut HelperImportCache::default())
+}
+
+/// Import statements already built for a set of runtime helpers
+///
+/// Templates compiled by one [`crate::VaporCompiler`] mostly use the same
+/// helpers, so the sorted import line is kept instead of rebuilt per file.
+/// Entries are keyed on an order-independent hash of the helper set and
+/// module, so a lookup neither sorts nor allocates.
+#[derive(Debug, Default)]
+pub(crate) struct HelperImportCache {
+    imports: FxHashMap<u64, CachedImport>,
+}
+
+#[derive(Debug)]
+struct CachedImport {
+    helpers: std::vec::Vec<&'static str>,
+    module: String,
+    line: String,
+}
+
+/// Distinct import lines kept before the cache starts over
+const MAX_HELPER_IMPORTS: usize = 64;
+
+impl HelperImportCache {
+    /// Number of distinct import lines built so far
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.imports.len()
+    }
+
+    fn key(&self, helpers: &std::collections::HashSet<&'static str>, module: &str) -> u64 {
+        use std::hash::BuildHasher;
+
+        let hasher = self.imports.hasher();
+        helpers.iter().fold(hasher.hash_one(module), |key, helper| {
+            key.wrapping_add(hasher.hash_one(helper))
+        })
+    }
+}
+
+/// Generate Vapor code from IR, reusing import lines from `cache`
+pub(crate) fn generate_vapor_with_cache(
+    ir: &RootIRNode<'_>,
+    options: &VaporGenerateOptions,
+    cache: &mut HelperImportCache,
+) -> VaporGenerateResult {
+    let mut ctx = GenerateContext::new(&ir.element_template_map, &ir.source);
+    if options.source_map {
+        ctx.source_map = Some(SourceMapBuilder::new());
+    }
 
     // Template helper is always used if we have templates
     if !ir.templates.is_empty() {
@@ -44,6 +129,11 @@ pub fn generate_vapor(ir: &RootIRNode<'_>) -> VaporGenerateResult {
     ctx.push_line("export function render(_ctx) {");
     ctx.indent();
 
+    if ir.has_template_ref {
+        ctx.use_helper("createTemplateRefSetter");
+        ctx.push_line("const _setTemplateRef = _createTemplateRefSetter()");
+    }
+
     // Generate block content (includes template instantiation, text nodes, operations, effects, return)
     generate_block(&mut ctx, &ir.block, &ir.element_template_map);
 
@@ -61,11 +151,10 @@ pub fn generate_vapor(ir: &RootIRNode<'_>) -> VaporGenerateResult {
         }
     }
 
-    // Now generate imports at the front with only used helpers
-    let imports = generate_imports(&ctx);
-
     // Combine: imports + templates + delegate events + function body
-    let mut final_code = imports;
+    let mut final_code = String::new();
+    // Now generate imports at the front with only used helpers
+    final_code.push_str(generate_imports(&ctx, &options.runtime_module_name, cache));
     if !template_code.is_empty() {
         final_code.push_str(&template_code);
     }
@@ -76,11 +165,19 @@ pub fn generate_vapor(ir: &RootIRNode<'_>) -> VaporGenerateResult {
     if !final_code.is_empty() {
         final_code.push('\n');
     }
+
+    // Mappings were recorded relative to the function body
+    let map = ctx.source_map.take().map(|mut builder| {
+        builder.offset_lines(final_code.matches('\n').count() as u32);
+        builder.to_json(DEFAULT_SOURCE_NAME, &ir.source)
+    });
+
     final_code.push_str(&ctx.code);
 
     VaporGenerateResult {
         code: final_code,
         templates: ir.templates.iter().cloned().collect(),
+        map,
     }
 }
 
@@ -95,12 +192,65 @@ fn collect_delegate_events(ctx: &mut GenerateContext, block: &BlockIRNode<'_>) {
     }
 }
 
+/// Generated code for one output line, with the template expressions written into it
+///
+/// Each expression's column is recorded (in UTF-16 code units) as it is
+/// written, so mapping never has to search the generated text.
+#[derive(Default)]
+struct MappedCode {
+    code: String,
+    len_utf16: u32,
+    mappings: std::vec::Vec<(u32, Position)>,
+}
+
+impl MappedCode {
+    fn new(s: &str) -> Self {
+        let mut code = Self::default();
+        code.push(s);
+        code
+    }
+
+    fn push(&mut self, s: &str) {
+        self.code.push_str(s);
+        self.len_utf16 += s.encode_utf16().count() as u32;
+    }
+
+    /// Write the generated code of a template expression
+    ///
+    /// When the code is the expression behind a prefix (`_ctx.msg`), the
+    /// mapping points past the prefix at the expression itself.
+    fn push_expression(&mut self, code: &str, exp: &SimpleExpressionNode<'_>) {
+        // Skip static parts and generated nodes without a real location
+        if !exp.is_static && exp.loc.end.offset > exp.loc.start.offset {
+            let prefix = code
+                .strip_suffix(exp.content.trim())
+                .map_or(0, |prefix| prefix.encode_utf16().count() as u32);
+            self.mappings.push((self.len_utf16 + prefix, exp.loc.start));
+        }
+        self.push(code);
+    }
+
+    /// Append another piece of mapped code, shifting its mappings
+    fn append(&mut self, other: MappedCode) {
+        let shift = self.len_utf16;
+        self.mappings.extend(
+            other
+                .mappings
+                .into_iter()
+                .map(|(column, position)| (shift + column, position)),
+        );
+        self.push(&other.code);
+    }
+}
+
 /// Generate context
 struct GenerateContext<'a> {
     code: String,
     indent_level: u32,
     #[allow(dead_code)]
     element_template_map: &'a FxHashMap<usize, usize>,
+    /// Template source, for UTF-16 source columns
+    source: &'a str,
     temp_count: usize,
     /// Used helpers for import generation
     used_helpers: std::collections::HashSet<&'static str>,
@@ -108,18 +258,28 @@ struct GenerateContext<'a> {
     delegate_events: std::collections::HashSet<std::string::String>,
     /// Text node references (element_id -> text_node_var)
     text_nodes: FxHashMap<usize, std::string::String>,
+    /// Current line in the generated body
+    line: u32,
+    /// Source map builder (when source maps are enabled)
+    source_map: Option<SourceMapBuilder>,
+    /// Identifiers bound by enclosing slot scopes
+    scope: std::vec::Vec<std::string::String>,
 }
 
 impl<'a> GenerateContext<'a> {
-    fn new(element_template_map: &'a FxHashMap<usize, usize>) -> Self {
+    fn new(element_template_map: &'a FxHashMap<usize, usize>, source: &'a str) -> Self {
         Self {
             code: String::with_capacity(4096),
             indent_level: 0,
             element_template_map,
+            source,
             temp_count: 0,
             used_helpers: std::collections::HashSet::new(),
             delegate_events: std::collections::HashSet::new(),
             text_nodes: FxHashMap::default(),
+            line: 0,
+            source_map: None,
+            scope: std::vec::Vec::new(),
         }
     }
 
@@ -136,18 +296,55 @@ impl<'a> GenerateContext<'a> {
         var_name
     }
 
+    /// Prefix an expression with `_ctx.` unless it reads a slot scope binding
+    fn prefix_expression(&self, content: &str) -> String {
+        let head_end = content
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+            .unwrap_or(content.len());
+        if self.scope.iter().any(|name| name == &content[..head_end]) {
+            content.to_string()
+        } else {
+            ["_ctx.", content].concat()
+        }
+    }
+
     fn use_helper(&mut self, name: &'static str) {
         self.used_helpers.insert(name);
     }
 
     fn push(&mut self, s: &str) {
         self.code.push_str(s);
+        self.line += s.matches('\n').count() as u32;
     }
 
     fn push_line(&mut self, s: &str) {
         self.push_indent();
         self.code.push_str(s);
         self.code.push('\n');
+        self.line += 1;
+    }
+
+    /// Push a line, mapping each expression back to its template location
+    fn push_line_mapped(&mut self, line: MappedCode) {
+        if let Some(builder) = self.source_map.as_mut() {
+            let indent = self.indent_level * 2;
+            for (column, position) in line.mappings {
+                // Template columns count bytes; source maps count UTF-16 code units
+                let byte_column = position.column.saturating_sub(1);
+                let line_start = position.offset.saturating_sub(byte_column) as usize;
+                let source_column = self
+                    .source
+                    .get(line_start..position.offset as usize)
+                    .map_or(byte_column, |prefix| prefix.encode_utf16().count() as u32);
+                builder.add(Mapping {
+                    generated_line: self.line,
+                    generated_column: indent + column,
+                    source_line: position.line.saturating_sub(1),
+                    source_column,
+                });
+            }
+        }
+        self.push_line(&line.code);
     }
 
     fn push_indent(&mut self) {
@@ -174,9 +371,13 @@ impl<'a> GenerateContext<'a> {
 }
 
 /// Generate imports based on used helpers
-fn generate_imports(ctx: &GenerateContext) -> String {
+fn generate_imports<'c>(
+    ctx: &GenerateContext,
+    module: &str,
+    cache: &'c mut HelperImportCache,
+) -> &'c str {
     if ctx.used_helpers.is_empty() {
-        return String::new();
+        return "";
     }
 
     // Define priority order for helpers (lower = earlier in import)
@@ -193,6 +394,8 @@ fn generate_imports(ctx: &GenerateContext) -> String {
             "setProp" => 31,
             "setStyle" => 32,
             "setAttr" => 33,
+            "setDisplay" => 34,
+            "setHtml" => 35,
             "createInvoker" => 40,
             "delegateEvents" => 41,
             "setInsertionState" => 78,
@@ -204,16 +407,39 @@ fn generate_imports(ctx: &GenerateContext) -> String {
         }
     }
 
-    let mut helpers: Vec<_> = ctx.used_helpers.iter().copied().collect();
-    helpers.sort_by_key(|h| helper_priority(h));
+    let key = cache.key(&ctx.used_helpers, module);
+    // A hash collision must not hand back another set's import line
+    let hit = cache.imports.get(&key).is_some_and(|cached| {
+        cached.module == module
+            && cached.helpers.len() == ctx.used_helpers.len()
+            && cached.helpers.iter().all(|h| ctx.used_helpers.contains(h))
+    });
+    if !hit {
+        // Break priority ties by name so the order doesn't depend on hashing
+        let mut helpers: Vec<_> = ctx.used_helpers.iter().copied().collect();
+        helpers.sort_by_key(|h| (helper_priority(h), *h));
+
+        let imports = helpers
+            .iter()
+            .map(|h| format!("{} as _{}", h, h))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let line = format!("import {{ {} }} from '{}';\n", imports, module);
 
-    let imports = helpers
-        .iter()
-        .map(|h| format!("{} as _{}", h, h))
-        .collect::<Vec<_>>()
-        .join(", ");
+        if cache.imports.len() >= MAX_HELPER_IMPORTS {
+            cache.imports.clear();
+        }
+        cache.imports.insert(
+            key,
+            CachedImport {
+                helpers,
+                module: module.into(),
+                line,
+            },
+        );
+    }
 
-    format!("import {{ {} }} from 'vue';\n", imports)
+    &cache.imports[&key].line
 }
 
 /// Generate block
@@ -235,20 +461,29 @@ fn generate_block(
         }
     }
 
-    // Generate text node references for effects in this block
-    for effect in block.effect.iter() {
-        for op in effect.operations.iter() {
-            if let OperationNode::SetText(set_text) = op {
-                ctx.use_helper("txt");
-                let var_name = ctx.next_text_node(set_text.element);
-                let mut line = std::string::String::with_capacity(32);
-                line.push_str("const ");
-                line.push_str(&var_name);
-                line.push_str(" = _txt(n");
-                line.push_str(&set_text.element.to_string());
-                line.push(')');
-                ctx.push_line(&line);
-            }
+    // v-memo caches are owned by the block, so each v-for item gets its own
+    if block.effect.iter().any(|effect| effect.memo.is_some()) {
+        ctx.push_line("const _cache = []");
+    }
+
+    // Generate text node references for text set in this block
+    let text_ops = block.operation.iter().chain(
+        block
+            .effect
+            .iter()
+            .flat_map(|effect| effect.operations.iter()),
+    );
+    for op in text_ops {
+        if let OperationNode::SetText(set_text) = op {
+            ctx.use_helper("txt");
+            let var_name = ctx.next_text_node(set_text.element);
+            let mut line = std::string::String::with_capacity(32);
+            line.push_str("const ");
+            line.push_str(&var_name);
+            line.push_str(" = _txt(n");
+            line.push_str(&set_text.element.to_string());
+            line.push(')');
+            ctx.push_line(&line);
         }
     }
 
@@ -299,7 +534,12 @@ fn generate_operation(
             generate_set_event(ctx, set_event);
         }
         OperationNode::SetHtml(set_html) => {
-            generate_set_html(ctx, set_html);
+            let line = generate_set_html(ctx, set_html);
+            ctx.push_line_mapped(line);
+        }
+        OperationNode::SetDisplay(set_display) => {
+            let line = generate_set_display(ctx, set_display);
+            ctx.push_line_mapped(line);
         }
         OperationNode::SetTemplateRef(set_ref) => {
             generate_set_template_ref(ctx, set_ref);
@@ -319,8 +559,17 @@ fn generate_operation(
         OperationNode::For(for_node) => {
             generate_for(ctx, for_node, element_template_map);
         }
+        OperationNode::Teleport(teleport) => {
+            generate_teleport(ctx, teleport, element_template_map);
+        }
+        OperationNode::Transition(transition) => {
+            generate_transition(ctx, transition, element_template_map);
+        }
+        OperationNode::Suspense(suspense) => {
+            generate_suspense(ctx, suspense, element_template_map);
+        }
         OperationNode::CreateComponent(component) => {
-            generate_create_component(ctx, component);
+            generate_create_component(ctx, component, element_template_map);
         }
         OperationNode::SlotOutlet(slot) => {
             generate_slot_outlet(ctx, slot);
@@ -339,11 +588,18 @@ fn generate_effect(
 ) {
     ctx.use_helper("renderEffect");
 
+    if let Some(ref memo) = effect.memo {
+        generate_memo_effect(ctx, effect, memo, element_template_map);
+        return;
+    }
+
     // If only one operation, use single-line format
     if effect.operations.len() == 1 {
         let op = &effect.operations[0];
-        let op_code = generate_operation_inline(ctx, op);
-        ctx.push_line(&format!("_renderEffect(() => {})", op_code));
+        let mut line = MappedCode::new("_renderEffect(() => ");
+        line.append(generate_operation_inline(ctx, op));
+        line.push(")");
+        ctx.push_line_mapped(line);
     } else {
         ctx.push_line("_renderEffect(() => {");
         ctx.indent();
@@ -357,111 +613,118 @@ fn generate_effect(
     }
 }
 
-/// Generate operation inline (returns code string)
-fn generate_operation_inline(ctx: &mut GenerateContext, op: &OperationNode<'_>) -> String {
-    match op {
-        OperationNode::SetProp(set_prop) => {
-            let element = format!("n{}", set_prop.element);
-            let key = &set_prop.prop.key.content;
-            let is_svg = is_svg_tag(set_prop.tag.as_str());
-            let value = if let Some(first) = set_prop.prop.values.first() {
-                if first.is_static {
-                    format!("\"{}\"", first.content)
-                } else {
-                    format!("_ctx.{}", first.content)
-                }
-            } else {
-                String::from("undefined")
-            };
+/// Generate an effect guarded by v-memo
+fn generate_memo_effect<'a>(
+    ctx: &mut GenerateContext,
+    effect: &IREffect<'a>,
+    memo: &IRMemo<'a>,
+    element_template_map: &FxHashMap<usize, usize>,
+) {
+    ctx.use_helper("withMemo");
+    let deps = generate_memo_deps(&memo.deps.content, |dep| ctx.prefix_expression(dep));
 
-            if key.as_str() == "class" {
-                if is_svg {
-                    ctx.use_helper("setAttr");
-                    format!("_setAttr({}, \"class\", {})", element, value)
-                } else {
-                    ctx.use_helper("setClass");
-                    format!("_setClass({}, {})", element, value)
-                }
-            } else if key.as_str() == "style" {
-                if is_svg {
-                    ctx.use_helper("setAttr");
-                    format!("_setAttr({}, \"style\", {})", element, value)
-                } else {
-                    ctx.use_helper("setStyle");
-                    format!("_setStyle({}, {})", element, value)
-                }
-            } else {
-                ctx.use_helper("setProp");
-                format!("_setProp({}, \"{}\", {})", element, key, value)
-            }
+    if effect.operations.len() == 1 {
+        let op = &effect.operations[0];
+        let mut line = MappedCode::new("_renderEffect(() => _withMemo(");
+        line.push_expression(&deps, &memo.deps);
+        line.push(", () => ");
+        line.append(generate_operation_inline(ctx, op));
+        line.push(&[", _cache, ", &memo.cache_index.to_string(), "))"].concat());
+        ctx.push_line_mapped(line);
+    } else {
+        ctx.push_line(&["_renderEffect(() => _withMemo(", &deps, ", () => {"].concat());
+        ctx.indent();
+
+        for op in effect.operations.iter() {
+            generate_operation(ctx, op, element_template_map);
         }
-        OperationNode::SetText(set_text) => {
-            ctx.use_helper("setText");
-            let text_ref = if let Some(text_var) = ctx.text_nodes.get(&set_text.element) {
-                text_var.clone()
-            } else {
-                format!("n{}", set_text.element)
-            };
 
-            let values: Vec<String> = set_text
-                .values
-                .iter()
-                .map(|v| {
-                    ctx.use_helper("toDisplayString");
-                    if v.is_static {
-                        format!("\"{}\"", v.content)
-                    } else {
-                        format!("_toDisplayString(_ctx.{})", v.content)
-                    }
-                })
-                .collect();
-
-            if values.len() == 1 {
-                format!("_setText({}, {})", text_ref, values[0])
-            } else {
-                format!("_setText({}, {})", text_ref, values.join(" + "))
-            }
+        ctx.deindent();
+        ctx.push_line(&["}, _cache, ", &memo.cache_index.to_string(), "))"].concat());
+    }
+}
+
+/// Generate operation inline (returns mapped code)
+fn generate_operation_inline(ctx: &mut GenerateContext, op: &OperationNode<'_>) -> MappedCode {
+    match op {
+        OperationNode::SetProp(set_prop) => generate_set_prop_call(ctx, set_prop),
+        OperationNode::SetText(set_text) => generate_set_text_call(ctx, set_text),
+        OperationNode::SetDisplay(set_display) => generate_set_display(ctx, set_display),
+        OperationNode::SetHtml(set_html) => generate_set_html(ctx, set_html),
+        _ => MappedCode::new("/* unsupported */"),
+    }
+}
+
+/// Generate the value of a prop
+///
+/// A static `class`/`style` merged with its binding yields an array
+/// (`["a", _ctx.b]`), which `setClass`/`setStyle` normalize like Vue's
+/// `normalizeClass`/`normalizeStyle`, including nested array and object forms.
+fn generate_prop_value(ctx: &GenerateContext, prop: &IRProp<'_>) -> MappedCode {
+    if prop.values.is_empty() {
+        return MappedCode::new("undefined");
+    }
+
+    let is_array = prop.values.len() > 1;
+    let mut out = MappedCode::new(if is_array { "[" } else { "" });
+    for (i, value) in prop.values.iter().enumerate() {
+        if i > 0 {
+            out.push(", ");
         }
-        _ => String::from("/* unsupported */"),
+        let content = value.content.trim();
+        let code = if value.is_static {
+            format!("\"{}\"", escape_text(&value.content))
+        } else if content.starts_with('{') || content.starts_with('[') {
+            // Object and array forms: prefix every identifier they read
+            // that is not bound by an enclosing `v-for` or slot scope
+            prefix_identifiers_in_scope(content, &ctx.scope)
+        } else {
+            ctx.prefix_expression(content)
+        };
+        out.push_expression(&code, value);
     }
+    if is_array {
+        out.push("]");
+    }
+    out
 }
 
 /// Generate SetProp
 fn generate_set_prop(ctx: &mut GenerateContext, set_prop: &SetPropIRNode<'_>) {
+    let line = generate_set_prop_call(ctx, set_prop);
+    ctx.push_line_mapped(line);
+}
+
+/// Generate the `setClass` / `setStyle` / `setAttr` / `setProp` call of a SetProp
+fn generate_set_prop_call(ctx: &mut GenerateContext, set_prop: &SetPropIRNode<'_>) -> MappedCode {
     let element = format!("n{}", set_prop.element);
     let key = &set_prop.prop.key.content;
     let is_svg = is_svg_tag(set_prop.tag.as_str());
+    let value = generate_prop_value(ctx, &set_prop.prop);
 
-    let value = if let Some(first) = set_prop.prop.values.first() {
-        if first.is_static {
-            format!("\"{}\"", first.content)
-        } else {
-            format!("_ctx.{}", first.content)
-        }
-    } else {
-        String::from("undefined")
-    };
-
-    if key.as_str() == "class" {
+    let mut line = if key.as_str() == "class" {
         if is_svg {
             ctx.use_helper("setAttr");
-            ctx.push_line(&format!("_setAttr({}, \"class\", {})", element, value));
+            MappedCode::new(&format!("_setAttr({}, \"class\", ", element))
         } else {
             ctx.use_helper("setClass");
-            ctx.push_line(&format!("_setClass({}, {})", element, value));
+            MappedCode::new(&format!("_setClass({}, ", element))
         }
     } else if key.as_str() == "style" {
         if is_svg {
             ctx.use_helper("setAttr");
-            ctx.push_line(&format!("_setAttr({}, \"style\", {})", element, value));
+            MappedCode::new(&format!("_setAttr({}, \"style\", ", element))
         } else {
             ctx.use_helper("setStyle");
-            ctx.push_line(&format!("_setStyle({}, {})", element, value));
+            MappedCode::new(&format!("_setStyle({}, ", element))
         }
     } else {
         ctx.use_helper("setProp");
-        ctx.push_line(&format!("_setProp({}, \"{}\", {})", element, key, value));
-    }
+        MappedCode::new(&format!("_setProp({}, \"{}\", ", element, key))
+    };
+    line.append(value);
+    line.push(")");
+    line
 }
 
 /// Generate SetDynamicProps
@@ -470,7 +733,7 @@ fn generate_set_dynamic_props(ctx: &mut GenerateContext, set_props: &SetDynamicP
 
     for prop in set_props.props.iter() {
         let expr = if prop.is_static {
-            format!("\"{}\"", prop.content)
+            format!("\"{}\"", escape_text(&prop.content))
         } else {
             prop.content.to_string()
         };
@@ -480,6 +743,12 @@ fn generate_set_dynamic_props(ctx: &mut GenerateContext, set_props: &SetDynamicP
 
 /// Generate SetText
 fn generate_set_text(ctx: &mut GenerateContext, set_text: &SetTextIRNode<'_>) {
+    let line = generate_set_text_call(ctx, set_text);
+    ctx.push_line_mapped(line);
+}
+
+/// Generate the `setText` call of a SetText
+fn generate_set_text_call(ctx: &mut GenerateContext, set_text: &SetTextIRNode<'_>) -> MappedCode {
     ctx.use_helper("setText");
 
     // Use text node reference if available, otherwise use element directly
@@ -489,24 +758,22 @@ fn generate_set_text(ctx: &mut GenerateContext, set_text: &SetTextIRNode<'_>) {
         format!("n{}", set_text.element)
     };
 
-    let values: Vec<String> = set_text
-        .values
-        .iter()
-        .map(|v| {
-            ctx.use_helper("toDisplayString");
-            if v.is_static {
-                format!("\"{}\"", v.content)
-            } else {
-                format!("_toDisplayString(_ctx.{})", v.content)
-            }
-        })
-        .collect();
-
-    if values.len() == 1 {
-        ctx.push_line(&format!("_setText({}, {})", text_ref, values[0]));
-    } else {
-        ctx.push_line(&format!("_setText({}, {})", text_ref, values.join(" + ")));
+    let mut line = MappedCode::new(&format!("_setText({}, ", text_ref));
+    for (i, v) in set_text.values.iter().enumerate() {
+        if i > 0 {
+            line.push(" + ");
+        }
+        ctx.use_helper("toDisplayString");
+        if v.is_static {
+            line.push(&format!("\"{}\"", escape_text(&v.content)));
+        } else {
+            line.push("_toDisplayString(");
+            line.push_expression(&ctx.prefix_expression(&v.content), v);
+            line.push(")");
+        }
     }
+    line.push(")");
+    line
 }
 
 /// Generate SetEvent
@@ -522,28 +789,44 @@ fn generate_set_event(ctx: &mut GenerateContext, set_event: &SetEventIRNode<'_>)
         String::from("() => {}")
     };
 
-    // Determine handler format based on content
-    let invoker_body = if handler.contains("$event") {
-        // Handler uses $event - pass it as parameter
-        format!("$event => (_ctx.{})", handler)
-    } else if handler.contains("?.") {
-        // Optional call expression like foo?.() or foo?.bar() - cache it
-        format!("(...args) => (_ctx.{})", handler)
-    } else if is_inline_statement(&handler) {
-        // Inline statement like count++ or foo = bar
-        format!("() => (_ctx.{})", handler)
-    } else if handler.contains("(") {
-        // Handler is a call expression like handler()
-        format!("e => _ctx.{}(e)", handler)
-    } else {
-        // Handler is a method reference like handler
-        format!("e => _ctx.{}(e)", handler)
-    };
-
-    ctx.push_line(&format!(
-        "{}.$evt{} = _createInvoker({})",
-        element, event_name, invoker_body
-    ));
+    // Guard and key modifiers wrap the handler
+    if !set_event.modifiers.non_keys.is_empty() {
+        ctx.use_helper("withModifiers");
+    }
+    if !set_event.modifiers.keys.is_empty() {
+        ctx.use_helper("withKeys");
+    }
+    let (open, close) = modifier_wrappers(&set_event.modifiers);
+
+    let mut line = MappedCode::new(&format!("{}.$evt{} = _createInvoker(", element, event_name));
+    line.push(&open);
+    match set_event.value {
+        // Modifier-only listener like @submit.prevent
+        None => line.push(&handler),
+        Some(ref value) => {
+            // Determine handler format based on content
+            let handler_ref = ctx.prefix_expression(&handler);
+            let (before, after) = if handler.contains("$event") {
+                // Handler uses $event - pass it as parameter
+                ("$event => (", ")")
+            } else if handler.contains("?.") {
+                // Optional call expression like foo?.() or foo?.bar() - cache it
+                ("(...args) => (", ")")
+            } else if is_inline_statement(&handler) {
+                // Inline statement like count++ or foo = bar
+                ("() => (", ")")
+            } else {
+                // Call expression like handler() or method reference like handler
+                ("e => ", "(e)")
+            };
+            line.push(before);
+            line.push_expression(&handler_ref, value);
+            line.push(after);
+        }
+    }
+    line.push(&close);
+    line.push(")");
+    ctx.push_line_mapped(line);
 }
 
 /// Check if handler is an inline statement (not a function reference)
@@ -556,30 +839,76 @@ fn is_inline_statement(handler: &str) -> bool {
         || handler.contains("=")
 }
 
-/// Generate SetHtml
-fn generate_set_html(ctx: &mut GenerateContext, set_html: &SetHtmlIRNode<'_>) {
-    let element = format!("n{}", set_html.element);
+/// Generate SetHtml (v-html)
+fn generate_set_html(ctx: &mut GenerateContext, set_html: &SetHtmlIRNode<'_>) -> MappedCode {
+    ctx.use_helper("setHtml");
 
     let value = if set_html.value.is_static {
-        format!("\"{}\"", set_html.value.content)
+        format!("\"{}\"", escape_text(&set_html.value.content))
+    } else {
+        ctx.prefix_expression(&set_html.value.content)
+    };
+
+    let mut line = MappedCode::new(&format!("_setHtml(n{}, ", set_html.element));
+    line.push_expression(&value, &set_html.value);
+    line.push(")");
+    line
+}
+
+/// Generate SetDisplay (v-show)
+fn generate_set_display(
+    ctx: &mut GenerateContext,
+    set_display: &SetDisplayIRNode<'_>,
+) -> MappedCode {
+    ctx.use_helper("setDisplay");
+
+    let value = if set_display.value.is_static {
+        format!("\"{}\"", escape_text(&set_display.value.content))
     } else {
-        set_html.value.content.to_string()
+        ctx.prefix_expression(&set_display.value.content)
     };
 
-    ctx.push_line(&format!("{}.innerHTML = {}", element, value));
+    let mut line = MappedCode::new(&format!("_setDisplay(n{}, ", set_display.element));
+    line.push_expression(&value, &set_display.value);
+    line.push(")");
+    line
 }
 
 /// Generate SetTemplateRef
+///
+/// A bound ref runs in an effect and passes back the previous ref so the
+/// runtime can clear it; refs inside `v-for` collect into an array.
 fn generate_set_template_ref(ctx: &mut GenerateContext, set_ref: &SetTemplateRefIRNode<'_>) {
     let element = format!("n{}", set_ref.element);
+    let content = set_ref.value.content.as_str();
 
     let value = if set_ref.value.is_static {
-        format!("\"{}\"", set_ref.value.content)
+        format!("\"{}\"", escape_text(content))
     } else {
-        set_ref.value.content.to_string()
+        // Function refs keep their own parameters unprefixed
+        prefix_identifiers_in_scope(content, &ctx.scope)
     };
-
-    ctx.push_line(&format!("_setRef({}, {})", element, value));
+    let ref_for = if set_ref.ref_for { ", true" } else { "" };
+
+    if set_ref.effect {
+        ctx.use_helper("renderEffect");
+        let old_ref = format!("r{}", set_ref.element);
+        ctx.push_line(&format!("let {}", old_ref));
+        let mut line = MappedCode::new(&format!(
+            "_renderEffect(() => {} = _setTemplateRef({}, ",
+            old_ref, element
+        ));
+        line.push_expression(&value, &set_ref.value);
+        line.push(&format!(", {}{}))", old_ref, ref_for));
+        ctx.push_line_mapped(line);
+    } else if set_ref.ref_for {
+        ctx.push_line(&format!(
+            "_setTemplateRef({}, {}, void 0{})",
+            element, value, ref_for
+        ));
+    } else {
+        ctx.push_line(&format!("_setTemplateRef({}, {})", element, value));
+    }
 }
 
 /// Generate InsertNode
@@ -617,11 +946,16 @@ fn generate_directive(ctx: &mut GenerateContext, directive: &DirectiveIRNode<'_>
     let element = format!("n{}", directive.element);
     let name = &directive.name;
 
+    if let Some(model_type) = directive.model_type {
+        generate_model_directive(ctx, directive, model_type);
+        return;
+    }
+
     let arg = if let Some(ref arg) = directive.dir.arg {
         match arg {
             ExpressionNode::Simple(exp) => {
                 if exp.is_static {
-                    format!("\"{}\"", exp.content)
+                    format!("\"{}\"", escape_text(&exp.content))
                 } else {
                     exp.content.to_string()
                 }
@@ -636,7 +970,7 @@ fn generate_directive(ctx: &mut GenerateContext, directive: &DirectiveIRNode<'_>
         match exp {
             ExpressionNode::Simple(e) => {
                 if e.is_static {
-                    format!("\"{}\"", e.content)
+                    format!("\"{}\"", escape_text(&e.content))
                 } else {
                     e.content.to_string()
                 }
@@ -653,6 +987,34 @@ fn generate_directive(ctx: &mut GenerateContext, directive: &DirectiveIRNode<'_>
     ));
 }
 
+/// Generate v-model on a native element
+fn generate_model_directive(
+    ctx: &mut GenerateContext,
+    directive: &DirectiveIRNode<'_>,
+    model_type: VModelType,
+) {
+    let helper = get_model_helper(model_type);
+    ctx.use_helper(helper);
+
+    let element = format!("n{}", directive.element);
+    // The binding may index with `v-for` aliases (`list[i]`), so prefix each identifier
+    let value = match directive.dir.exp {
+        Some(ExpressionNode::Simple(ref e)) => prefix_identifiers_in_scope(&e.content, &ctx.scope),
+        Some(ExpressionNode::Compound(ref c)) => {
+            prefix_identifiers_in_scope(&c.loc.source, &ctx.scope)
+        }
+        None => String::from("undefined"),
+    };
+    let modifiers: Vec<&str> = directive
+        .dir
+        .modifiers
+        .iter()
+        .map(|m| m.content.as_str())
+        .collect();
+
+    ctx.push_line(&generate_apply_model(&element, helper, &value, &modifiers));
+}
+
 /// Generate If
 fn generate_if(
     ctx: &mut GenerateContext,
@@ -671,9 +1033,9 @@ fn generate_if_inner(
     ctx.use_helper("createIf");
 
     let condition = if if_node.condition.is_static {
-        ["\"", if_node.condition.content.as_str(), "\""].concat()
+        ["\"", &escape_text(&if_node.condition.content), "\""].concat()
     } else {
-        ["(_ctx.", if_node.condition.content.as_str(), ")"].concat()
+        ["(", &ctx.prefix_expression(&if_node.condition.content), ")"].concat()
     };
 
     ctx.push_line(
@@ -722,9 +1084,9 @@ fn generate_nested_if(
     ctx.use_helper("createIf");
 
     let condition = if if_node.condition.is_static {
-        ["\"", if_node.condition.content.as_str(), "\""].concat()
+        ["\"", &escape_text(&if_node.condition.content), "\""].concat()
     } else {
-        ["(_ctx.", if_node.condition.content.as_str(), ")"].concat()
+        ["(", &ctx.prefix_expression(&if_node.condition.content), ")"].concat()
     };
 
     // Start inline - no leading indent or newline
@@ -758,6 +1120,10 @@ fn generate_nested_if(
 }
 
 /// Generate For
+///
+/// The source may be an array, an object (`(val, key, index) in obj`) or a
+/// number (`n in 5`, iterating `1..=5`); the runtime handles each. Aliases,
+/// including destructured ones, shadow `_ctx` inside the loop body.
 fn generate_for(
     ctx: &mut GenerateContext,
     for_node: &ForIRNode<'_>,
@@ -765,10 +1131,17 @@ fn generate_for(
 ) {
     ctx.use_helper("createFor");
 
+    let source_content = for_node.source.content.trim();
     let source = if for_node.source.is_static {
-        ["\"", for_node.source.content.as_str(), "\""].concat()
+        ["\"", &escape_text(source_content), "\""].concat()
+    } else if source_content
+        .parse::<f64>()
+        .is_ok_and(|bound| bound.is_finite())
+    {
+        // Numeric range bound
+        source_content.to_string()
     } else {
-        ["(_ctx.", for_node.source.content.as_str(), " || [])"].concat()
+        ["(", &ctx.prefix_expression(source_content), " || [])"].concat()
     };
 
     let value_name = for_node
@@ -782,86 +1155,325 @@ fn generate_for(
     let params = match (key_name, index_name) {
         (Some(k), Some(i)) => [value_name, ", ", k, ", ", i].concat(),
         (Some(k), None) => [value_name, ", ", k].concat(),
-        _ => value_name.to_string(),
+        (None, Some(i)) => [value_name, ", _key, ", i].concat(),
+        (None, None) => value_name.to_string(),
     };
 
     ctx.push_line(&["_createFor(() => ", &source, ", (", &params, ") => {"].concat());
+
+    let scope_len = ctx.scope.len();
+    // Read the parameter list as an array pattern to collect every alias
+    ctx.scope
+        .extend(get_slot_scope_identifiers(&["[", &params, "]"].concat()));
     ctx.indent();
     generate_block(ctx, &for_node.render, element_template_map);
     ctx.deindent();
-    ctx.push_line("})");
+    // The key getter receives the same aliases as the render function
+    match &for_node.key_prop {
+        Some(key) => {
+            let key = if key.is_static {
+                ["\"", &escape_text(&key.content), "\""].concat()
+            } else {
+                ctx.prefix_expression(&key.content)
+            };
+            ctx.push_line(&["}, (", &params, ") => (", &key, "))"].concat());
+        }
+        None => ctx.push_line("})"),
+    }
+    ctx.scope.truncate(scope_len);
 }
 
-/// Generate CreateComponent
-fn generate_create_component(ctx: &mut GenerateContext, component: &CreateComponentIRNode<'_>) {
-    ctx.use_helper("resolveComponent");
-    ctx.use_helper("createComponentWithFallback");
-
-    let tag = &component.tag;
-    let component_var = ["_component_", tag.as_str()].concat();
+/// Generate Teleport
+///
+/// Bound props are passed as getters so the runtime re-evaluates the target
+/// inside an effect and moves the content when it changes.
+fn generate_teleport(
+    ctx: &mut GenerateContext,
+    teleport: &TeleportIRNode<'_>,
+    element_template_map: &FxHashMap<usize, usize>,
+) {
+    ctx.use_helper("createTeleport");
 
-    // Resolve component
-    ctx.push_line(
-        &[
-            "const ",
-            &component_var,
-            " = _resolveComponent(\"",
-            tag.as_str(),
-            "\")",
-        ]
-        .concat(),
-    );
+    let mut line =
+        MappedCode::new(&["const n", &teleport.id.to_string(), " = _createTeleport("].concat());
+    if teleport.to.is_none() && teleport.disabled.is_none() {
+        line.push("null");
+    } else {
+        line.push("{ ");
+        let props = [("to", &teleport.to), ("disabled", &teleport.disabled)];
+        for (i, (key, value)) in props
+            .into_iter()
+            .filter_map(|(key, prop)| prop.as_ref().map(|value| (key, value)))
+            .enumerate()
+        {
+            if i > 0 {
+                line.push(", ");
+            }
+            line.push(key);
+            line.push(": ");
+            if value.is_static {
+                line.push(&["\"", &escape_text(&value.content), "\""].concat());
+            } else {
+                line.push("() => (");
+                line.push_expression(&ctx.prefix_expression(&value.content), value);
+                line.push(")");
+            }
+        }
+        line.push(" }");
+    }
+    line.push(", () => {");
+    ctx.push_line_mapped(line);
+    ctx.indent();
+    generate_block(ctx, &teleport.block, element_template_map);
+    ctx.deindent();
+    ctx.push_line("})");
+}
 
-    // Props object
-    let props = if component.props.is_empty() {
-        "null".to_string()
+/// Generate Transition / TransitionGroup
+///
+/// Props are passed as getters like component props; the child block (for a
+/// group, its keyed `v-for`) renders inside the transition.
+fn generate_transition(
+    ctx: &mut GenerateContext,
+    transition: &TransitionIRNode<'_>,
+    element_template_map: &FxHashMap<usize, usize>,
+) {
+    let helper = if transition.group {
+        "createTransitionGroup"
     } else {
-        let prop_strs: Vec<String> = component
-            .props
-            .iter()
-            .map(|p| {
-                let key = &p.key.content;
-                let is_event = key.as_str().starts_with("on") && key.len() > 2;
-
-                let value = if let Some(first) = p.values.first() {
-                    if first.is_static {
-                        ["() => (\"", first.content.as_str(), "\")"].concat()
-                    } else if is_event {
-                        // Event handlers: () => _ctx.handler
-                        ["() => _ctx.", first.content.as_str()].concat()
-                    } else {
-                        // Regular props: () => (_ctx.value)
-                        ["() => (_ctx.", first.content.as_str(), ")"].concat()
-                    }
-                } else {
-                    "undefined".to_string()
-                };
-                [key.as_str(), ": ", &value].concat()
-            })
-            .collect();
-        ["{ ", &prop_strs.join(", "), " }"].concat()
+        "createTransition"
     };
+    ctx.use_helper(helper);
 
-    // Generate component creation
+    let props = generate_component_props(ctx, &transition.props);
     ctx.push_line(
         &[
             "const n",
-            &component.id.to_string(),
-            " = _createComponentWithFallback(",
-            &component_var,
-            ", ",
+            &transition.id.to_string(),
+            " = _",
+            helper,
+            "(",
             &props,
-            ", null, true)",
+            ", () => {",
         ]
         .concat(),
     );
+    ctx.indent();
+    generate_block(ctx, &transition.block, element_template_map);
+    ctx.deindent();
+    ctx.push_line("})");
+}
+
+/// Generate Suspense
+///
+/// Props are passed as getters like component props; each slot becomes its
+/// own render function in the slots object.
+fn generate_suspense(
+    ctx: &mut GenerateContext,
+    suspense: &SuspenseIRNode<'_>,
+    element_template_map: &FxHashMap<usize, usize>,
+) {
+    ctx.use_helper("createSuspense");
+
+    let props = generate_component_props(ctx, &suspense.props);
+    let head = [
+        "const n",
+        &suspense.id.to_string(),
+        " = _createSuspense(",
+        &props,
+        ", ",
+    ]
+    .concat();
+    if suspense.slots.is_empty() {
+        ctx.push_line(&[&head, "null)"].concat());
+        return;
+    }
+
+    ctx.push_line(&[&head, "{"].concat());
+    ctx.indent();
+    generate_component_slots(ctx, &suspense.slots, element_template_map);
+    ctx.deindent();
+    ctx.push_line("})");
+}
+
+/// Generate CreateComponent
+fn generate_create_component(
+    ctx: &mut GenerateContext,
+    component: &CreateComponentIRNode<'_>,
+    element_template_map: &FxHashMap<usize, usize>,
+) {
+    let callee = if let Some(dynamic) = &component.dynamic {
+        // The runtime re-resolves the getter in an effect, swapping the
+        // rendered component when it changes
+        ctx.use_helper("createDynamicComponent");
+        let getter = if dynamic.is_static {
+            ["() => (\"", &escape_text(&dynamic.content), "\")"].concat()
+        } else {
+            ["() => (", &ctx.prefix_expression(&dynamic.content), ")"].concat()
+        };
+        ["_createDynamicComponent(", &getter].concat()
+    } else {
+        ctx.use_helper("resolveComponent");
+        ctx.use_helper("createComponentWithFallback");
+
+        let tag = &component.tag;
+        let component_var = ["_component_", tag.as_str()].concat();
+
+        // Resolve component
+        ctx.push_line(
+            &[
+                "const ",
+                &component_var,
+                " = _resolveComponent(\"",
+                tag.as_str(),
+                "\")",
+            ]
+            .concat(),
+        );
+        ["_createComponentWithFallback(", &component_var].concat()
+    };
+
+    // Props object
+    let props = generate_component_props(ctx, &component.props);
+
+    let head = [
+        "const n",
+        &component.id.to_string(),
+        " = ",
+        &callee,
+        ", ",
+        &props,
+        ", ",
+    ]
+    .concat();
+
+    // Generate component creation
+    if component.slots.is_empty() {
+        ctx.push_line(&[&head, "null, true)"].concat());
+        return;
+    }
+
+    ctx.push_line(&[&head, "{"].concat());
+    ctx.indent();
+    generate_component_slots(ctx, &component.slots, element_template_map);
+    ctx.deindent();
+    ctx.push_line("}, true)");
+}
+
+/// Generate the props object of a component, with each value as a getter
+fn generate_component_props(ctx: &GenerateContext, props: &[IRProp<'_>]) -> String {
+    if props.is_empty() {
+        return "null".to_string();
+    }
+
+    let prop_strs: Vec<String> = props
+        .iter()
+        .map(|p| {
+            let key = &p.key.content;
+            let is_event = key.as_str().starts_with("on") && key.len() > 2;
+
+            let value = if let Some(first) = p.values.first() {
+                if p.model && is_event {
+                    // v-model update listener: assign the emitted value back
+                    let binding = prefix_identifiers_in_scope(&first.content, &ctx.scope);
+                    ["() => ", &generate_model_setter(&binding)].concat()
+                } else if p.model && key.ends_with("Modifiers") {
+                    // v-model modifiers object literal
+                    ["() => (", first.content.as_str(), ")"].concat()
+                } else if p.model {
+                    let binding = prefix_identifiers_in_scope(&first.content, &ctx.scope);
+                    ["() => (", &binding, ")"].concat()
+                } else if first.is_static {
+                    ["() => (\"", &escape_text(&first.content), "\")"].concat()
+                } else if is_event {
+                    // Event handlers: () => _ctx.handler
+                    ["() => ", &ctx.prefix_expression(&first.content)].concat()
+                } else {
+                    // Regular props: () => (_ctx.value)
+                    ["() => (", &ctx.prefix_expression(&first.content), ")"].concat()
+                }
+            } else {
+                "undefined".to_string()
+            };
+            if is_simple_identifier(key) {
+                [key.as_str(), ": ", &value].concat()
+            } else {
+                ["\"", &escape_text(key), "\": ", &value].concat()
+            }
+        })
+        .collect();
+    ["{ ", &prop_strs.join(", "), " }"].concat()
+}
+
+/// Generate the slots object passed to a component
+///
+/// Static slots become `name: (scope) => { ... }` entries; slots with dynamic
+/// names are collected under the `$` key as `{ name, fn }` records.
+fn generate_component_slots(
+    ctx: &mut GenerateContext,
+    slots: &[IRSlot<'_>],
+    element_template_map: &FxHashMap<usize, usize>,
+) {
+    for slot in slots.iter().filter(|slot| slot.name.is_static) {
+        let name = slot.name.content.as_str();
+        let key = if is_simple_identifier(name) {
+            name.to_string()
+        } else {
+            ["\"", &escape_text(name), "\""].concat()
+        };
+        generate_slot_fn(ctx, &[&key, ": "].concat(), slot, element_template_map);
+        ctx.push_line("},");
+    }
+
+    if slots.iter().any(|slot| !slot.name.is_static) {
+        ctx.push_line("$: [");
+        ctx.indent();
+        for slot in slots.iter().filter(|slot| !slot.name.is_static) {
+            ctx.push_line("() => ({");
+            ctx.indent();
+            let name = ctx.prefix_expression(&slot.name.content);
+            let mut line = MappedCode::new("name: ");
+            line.push_expression(&name, &slot.name);
+            line.push(",");
+            ctx.push_line_mapped(line);
+            generate_slot_fn(ctx, "fn: ", slot, element_template_map);
+            ctx.push_line("}");
+            ctx.deindent();
+            ctx.push_line("}),");
+        }
+        ctx.deindent();
+        ctx.push_line("]");
+    }
+}
+
+/// Generate a slot function, leaving its closing brace to the caller
+fn generate_slot_fn(
+    ctx: &mut GenerateContext,
+    prefix: &str,
+    slot: &IRSlot<'_>,
+    element_template_map: &FxHashMap<usize, usize>,
+) {
+    let params = slot
+        .fn_exp
+        .as_ref()
+        .map(|p| p.content.as_str())
+        .unwrap_or("");
+    ctx.push_line(&[prefix, "(", params, ") => {"].concat());
+
+    // Scope bindings shadow `_ctx` inside the slot body
+    let scope_len = ctx.scope.len();
+    ctx.scope.extend(get_slot_scope_identifiers(params));
+    ctx.indent();
+    generate_block(ctx, &slot.block, element_template_map);
+    ctx.deindent();
+    ctx.scope.truncate(scope_len);
 }
 
 /// Generate SlotOutlet
 fn generate_slot_outlet(ctx: &mut GenerateContext, slot: &SlotOutletIRNode<'_>) {
     let name = ctx.next_temp();
     let slot_name = if slot.name.is_static {
-        format!("\"{}\"", slot.name.content)
+        format!("\"{}\"", escape_text(&slot.name.content))
     } else {
         slot.name.content.to_string()
     };
@@ -888,6 +1500,15 @@ fn escape_template(s: &str) -> String {
         .replace('\r', "\\r")
 }
 
+/// Check if a prop key can be written as an unquoted object key
+fn is_simple_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
 /// Check if a tag is an SVG element
 fn is_svg_tag(tag: &str) -> bool {
     matches!(
@@ -7,6 +7,8 @@ pub mod component;
 pub mod directive;
 pub mod event;
 pub mod for_node;
+pub mod generate_memo;
+pub mod generate_model;
 pub mod generate_slot;
 pub mod generate_text;
 pub mod if_node;
@@ -17,6 +19,8 @@ pub use component::*;
 pub use directive::*;
 pub use event::*;
 pub use for_node::*;
+pub use generate_memo::*;
+pub use generate_model::*;
 pub use generate_slot::*;
 pub use generate_text::*;
 pub use if_node::*;
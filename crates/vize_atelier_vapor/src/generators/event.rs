@@ -28,17 +28,28 @@ pub fn generate_set_event(ctx: &mut GenerateContext, set_event: &SetEventIRNode<
 }
 
 /// Apply event modifiers to handler
-fn apply_modifiers(handler: &str, modifiers: &EventModifiers) -> String {
-    let mut result = handler.to_string();
+///
+/// Guard modifiers wrap the handler in `_withModifiers`, and key modifiers
+/// wrap that result in `_withKeys`, matching the DOM compiler's nesting.
+pub fn apply_modifiers(handler: &str, modifiers: &EventModifiers) -> String {
+    let (open, close) = modifier_wrappers(modifiers);
+    [open.as_str(), handler, close.as_str()].concat()
+}
+
+/// The code written before and after a handler to apply its modifiers
+pub fn modifier_wrappers(modifiers: &EventModifiers) -> (String, String) {
+    let mut open = String::new();
+    let mut close = String::new();
 
-    // Apply key modifiers
+    // Key modifiers wrap the guard modifiers
     if !modifiers.keys.is_empty() {
         let keys: Vec<String> = modifiers
             .keys
             .iter()
             .map(|k| format!("\"{}\"", k))
             .collect();
-        result = format!("_withKeys({}, [{}])", result, keys.join(", "));
+        open.push_str("_withKeys(");
+        close = format!(", [{}])", keys.join(", "));
     }
 
     // Apply non-key modifiers
@@ -48,10 +59,11 @@ fn apply_modifiers(handler: &str, modifiers: &EventModifiers) -> String {
             .iter()
             .map(|m| format!("\"{}\"", m))
             .collect();
-        result = format!("_withModifiers({}, [{}])", result, mods.join(", "));
+        open.push_str("_withModifiers(");
+        close = format!(", [{}]){}", mods.join(", "), close);
     }
 
-    result
+    (open, close)
 }
 
 /// Generate event options
@@ -125,6 +137,20 @@ mod tests {
         assert_eq!(result, "handleClick");
     }
 
+    #[test]
+    fn test_apply_modifiers_nesting() {
+        let modifiers = EventModifiers {
+            keys: vec![vize_carton::String::new("enter")],
+            non_keys: vec![vize_carton::String::new("stop")],
+            ..Default::default()
+        };
+        let result = apply_modifiers("handleKey", &modifiers);
+        assert_eq!(
+            result,
+            "_withKeys(_withModifiers(handleKey, [\"stop\"]), [\"enter\"])"
+        );
+    }
+
     #[test]
     fn test_capitalize_event_name() {
         assert_eq!(capitalize_event_name("click"), "onClick");
@@ -0,0 +1,55 @@
+//! v-memo code generation for Vapor mode.
+
+use super::generate_slot::split_top_level;
+
+/// Generate the dependency getter passed to `_withMemo`
+///
+/// Array literals are prefixed element by element so `[a, b]` reads
+/// `[_ctx.a, _ctx.b]`; any other expression is prefixed as a whole.
+pub fn generate_memo_deps(deps: &str, prefix: impl Fn(&str) -> String) -> String {
+    let deps = deps.trim();
+    let value = match deps.strip_prefix('[').and_then(|d| d.strip_suffix(']')) {
+        Some(items) => {
+            let items: Vec<String> = split_top_level(items)
+                .into_iter()
+                .map(|item| prefix(item.trim()))
+                .collect();
+            format!("[{}]", items.join(", "))
+        }
+        None => prefix(deps),
+    };
+    format!("() => {}", value)
+}
+
+/// Wrap a render callback with `_withMemo` using the block's `_cache` slot
+pub fn generate_with_memo(deps_getter: &str, render: &str, cache_index: usize) -> String {
+    format!(
+        "_withMemo({}, {}, _cache, {})",
+        deps_getter, render, cache_index
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prefix(s: &str) -> String {
+        format!("_ctx.{}", s)
+    }
+
+    #[test]
+    fn test_generate_memo_deps_array() {
+        assert_eq!(
+            generate_memo_deps("[a, b.c]", prefix),
+            "() => [_ctx.a, _ctx.b.c]"
+        );
+    }
+
+    #[test]
+    fn test_generate_with_memo() {
+        assert_eq!(
+            generate_with_memo("() => [_ctx.a]", "() => _setText(x0, 1)", 2),
+            "_withMemo(() => [_ctx.a], () => _setText(x0, 1), _cache, 2)"
+        );
+    }
+}
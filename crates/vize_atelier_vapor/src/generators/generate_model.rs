@@ -0,0 +1,70 @@
+//! v-model code generation for Vapor mode.
+
+use crate::ir::VModelType;
+
+/// Get the runtime helper applying v-model for an element kind
+pub fn get_model_helper(model_type: VModelType) -> &'static str {
+    match model_type {
+        VModelType::Text => "applyTextModel",
+        VModelType::Checkbox => "applyCheckboxModel",
+        VModelType::Radio => "applyRadioModel",
+        VModelType::Select => "applySelectModel",
+        VModelType::Dynamic => "applyDynamicModel",
+    }
+}
+
+/// Generate v-model wiring for a native element
+///
+/// Emits a getter reading the binding and a setter assigning back to it,
+/// plus the `.lazy` / `.number` / `.trim` modifiers when present.
+pub fn generate_apply_model(
+    element_var: &str,
+    helper: &str,
+    value: &str,
+    modifiers: &[&str],
+) -> String {
+    let mut args = vec![
+        element_var.to_string(),
+        format!("() => ({})", value),
+        generate_model_setter(value),
+    ];
+
+    if !modifiers.is_empty() {
+        let mods: Vec<String> = modifiers.iter().map(|m| format!("{}: true", m)).collect();
+        args.push(format!("{{ {} }}", mods.join(", ")));
+    }
+
+    format!("_{}({})", helper, args.join(", "))
+}
+
+/// Generate the setter assigning the new value back to the binding
+pub fn generate_model_setter(value: &str) -> String {
+    format!("_value => ({} = _value)", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_model_helper() {
+        assert_eq!(get_model_helper(VModelType::Text), "applyTextModel");
+        assert_eq!(get_model_helper(VModelType::Checkbox), "applyCheckboxModel");
+        assert_eq!(get_model_helper(VModelType::Select), "applySelectModel");
+    }
+
+    #[test]
+    fn test_generate_apply_model() {
+        let result = generate_apply_model("n0", "applyTextModel", "_ctx.msg", &[]);
+        assert_eq!(
+            result,
+            "_applyTextModel(n0, () => (_ctx.msg), _value => (_ctx.msg = _value))"
+        );
+    }
+
+    #[test]
+    fn test_generate_apply_model_with_modifiers() {
+        let result = generate_apply_model("n0", "applyTextModel", "_ctx.msg", &["trim", "lazy"]);
+        assert!(result.ends_with(", { trim: true, lazy: true })"));
+    }
+}
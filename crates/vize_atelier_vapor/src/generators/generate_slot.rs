@@ -2,6 +2,7 @@
 
 use super::block::GenerateContext;
 use crate::ir::SlotOutletIRNode;
+use vize_croquis::analyzer::extract_slot_props;
 
 /// Generate SlotOutlet code
 pub fn generate_slot_outlet(ctx: &mut GenerateContext, slot: &SlotOutletIRNode<'_>) {
@@ -107,6 +108,45 @@ pub fn is_dynamic_slot_name(name: &str) -> bool {
     name.starts_with('[') && name.ends_with(']')
 }
 
+/// Collect the identifiers bound by slot scope params
+///
+/// Handles plain identifiers and (nested) object/array destructuring, including
+/// renames (`{ item: row }`), defaults (`{ n = 1 }`) and rest elements.
+pub fn get_slot_scope_identifiers(params: &str) -> Vec<String> {
+    extract_slot_props(params)
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Split on commas that are not nested in brackets
+pub(crate) fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = s;
+    while let (head, Some(tail)) = split_top_level_at(rest, ',') {
+        parts.push(head);
+        rest = tail;
+    }
+    if !rest.trim().is_empty() {
+        parts.push(rest);
+    }
+    parts
+}
+
+/// Split at the first top-level occurrence of `sep`
+fn split_top_level_at(s: &str, sep: char) -> (&str, Option<&str>) {
+    let mut depth = 0i32;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' | '[' | '(' => depth += 1,
+            '}' | ']' | ')' => depth -= 1,
+            c if c == sep && depth == 0 => return (&s[..i], Some(&s[i + 1..])),
+            _ => {}
+        }
+    }
+    (s, None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,6 +169,19 @@ mod tests {
         assert!(!is_dynamic_slot_name("default"));
     }
 
+    #[test]
+    fn test_get_slot_scope_identifiers() {
+        assert_eq!(get_slot_scope_identifiers("props"), vec!["props"]);
+        assert_eq!(
+            get_slot_scope_identifiers("{ title, item: row, count = 1 }"),
+            vec!["title", "row", "count"]
+        );
+        assert_eq!(
+            get_slot_scope_identifiers("{ user: { name }, ...rest }"),
+            vec!["name", "rest"]
+        );
+    }
+
     #[test]
     fn test_generate_dynamic_slot_name() {
         let result = generate_dynamic_slot_name("dynamicName");
@@ -52,7 +52,7 @@ pub fn generate_to_display_string(expr: &str) -> String {
 }
 
 /// Escape text for JavaScript string
-fn escape_text(s: &str) -> String {
+pub fn escape_text(s: &str) -> String {
     s.replace('\\', "\\\\")
         .replace('"', "\\\"")
         .replace('\n', "\\n")
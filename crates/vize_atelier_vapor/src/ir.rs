@@ -1,9 +1,13 @@
 //! Vapor Intermediate Representation (IR) types.
 
 use serde::{Deserialize, Serialize};
-use vize_atelier_core::{Namespace, RootNode, SimpleExpressionNode, TemplateChildNode};
+use vize_atelier_core::{
+    CompilerError, Namespace, RootNode, SimpleExpressionNode, TemplateChildNode,
+};
 use vize_carton::{Box, Bump, FxHashMap, String, Vec};
 
+mod serialize;
+
 /// IR node type discriminant
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[repr(u8)]
@@ -26,6 +30,10 @@ pub enum IRNodeType {
     If = 15,
     For = 16,
     GetTextChild = 17,
+    SetDisplay = 18,
+    Teleport = 19,
+    Transition = 20,
+    Suspense = 21,
 }
 
 /// Dynamic flags for IR nodes
@@ -56,6 +64,10 @@ pub struct RootIRNode<'a> {
     pub templates: Vec<'a, String>,
     /// Mapping from element ID to template index
     pub element_template_map: FxHashMap<usize, usize>,
+    /// Errors reported while lowering to IR
+    pub errors: std::vec::Vec<CompilerError>,
+    /// Warnings reported while lowering to IR; the output is still usable
+    pub warnings: std::vec::Vec<CompilerError>,
 }
 
 /// Block IR node - unit of reactive computation
@@ -83,7 +95,8 @@ impl<'a> BlockIRNode<'a> {
 }
 
 /// Dynamic info for IR nodes
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct IRDynamicInfo {
     pub flags: u8,
     pub children: std::vec::Vec<IRDynamicInfo>,
@@ -94,6 +107,17 @@ pub struct IRDynamicInfo {
 #[derive(Debug)]
 pub struct IREffect<'a> {
     pub operations: Vec<'a, OperationNode<'a>>,
+    /// Set when the effect belongs to a `v-memo` subtree
+    pub memo: Option<IRMemo<'a>>,
+}
+
+/// v-memo guard for an effect
+#[derive(Debug)]
+pub struct IRMemo<'a> {
+    /// Dependency array expression
+    pub deps: Box<'a, SimpleExpressionNode<'a>>,
+    /// Slot in the enclosing block's `_cache`
+    pub cache_index: usize,
 }
 
 /// All operation node variants
@@ -104,12 +128,16 @@ pub enum OperationNode<'a> {
     SetText(SetTextIRNode<'a>),
     SetEvent(SetEventIRNode<'a>),
     SetHtml(SetHtmlIRNode<'a>),
+    SetDisplay(SetDisplayIRNode<'a>),
     SetTemplateRef(SetTemplateRefIRNode<'a>),
     InsertNode(InsertNodeIRNode),
     PrependNode(PrependNodeIRNode),
     Directive(DirectiveIRNode<'a>),
     If(Box<'a, IfIRNode<'a>>),
     For(Box<'a, ForIRNode<'a>>),
+    Teleport(Box<'a, TeleportIRNode<'a>>),
+    Transition(Box<'a, TransitionIRNode<'a>>),
+    Suspense(Box<'a, SuspenseIRNode<'a>>),
     CreateComponent(CreateComponentIRNode<'a>),
     SlotOutlet(SlotOutletIRNode<'a>),
     GetTextChild(GetTextChildIRNode),
@@ -129,6 +157,8 @@ pub struct IRProp<'a> {
     pub key: Box<'a, SimpleExpressionNode<'a>>,
     pub values: Vec<'a, Box<'a, SimpleExpressionNode<'a>>>,
     pub is_component: bool,
+    /// Whether this prop was expanded from a component `v-model`
+    pub model: bool,
 }
 
 /// Set dynamic props operation
@@ -157,7 +187,8 @@ pub struct SetEventIRNode<'a> {
 }
 
 /// Event modifiers
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct EventModifiers {
     pub keys: std::vec::Vec<String>,
     pub non_keys: std::vec::Vec<String>,
@@ -165,7 +196,8 @@ pub struct EventModifiers {
 }
 
 /// Event options
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct EventOptions {
     pub capture: bool,
     pub once: bool,
@@ -179,16 +211,27 @@ pub struct SetHtmlIRNode<'a> {
     pub value: Box<'a, SimpleExpressionNode<'a>>,
 }
 
+/// Set display operation (v-show)
+#[derive(Debug)]
+pub struct SetDisplayIRNode<'a> {
+    pub element: usize,
+    pub value: Box<'a, SimpleExpressionNode<'a>>,
+}
+
 /// Set template ref operation
 #[derive(Debug)]
 pub struct SetTemplateRefIRNode<'a> {
     pub element: usize,
     pub value: Box<'a, SimpleExpressionNode<'a>>,
+    /// Inside `v-for`: refs collect into an array
     pub ref_for: bool,
+    /// Bound `:ref`: re-applied in an effect, replacing the previous ref
+    pub effect: bool,
 }
 
 /// Insert node operation
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct InsertNodeIRNode {
     pub elements: std::vec::Vec<usize>,
     pub parent: usize,
@@ -196,7 +239,8 @@ pub struct InsertNodeIRNode {
 }
 
 /// Prepend node operation
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PrependNodeIRNode {
     pub elements: std::vec::Vec<usize>,
     pub parent: usize,
@@ -209,6 +253,19 @@ pub struct DirectiveIRNode<'a> {
     pub dir: Box<'a, vize_atelier_core::DirectiveNode<'a>>,
     pub name: String,
     pub builtin: bool,
+    /// Element kind for built-in `v-model` (None for other directives)
+    pub model_type: Option<VModelType>,
+}
+
+/// v-model element kind, selecting the runtime `applyXxxModel` helper
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VModelType {
+    Text,
+    Checkbox,
+    Radio,
+    Select,
+    /// `<input>` with a bound `type`, resolved at runtime
+    Dynamic,
 }
 
 /// If operation
@@ -245,11 +302,40 @@ pub struct ForIRNode<'a> {
     pub only_child: bool,
 }
 
+/// Teleport operation
+#[derive(Debug)]
+pub struct TeleportIRNode<'a> {
+    pub id: usize,
+    pub to: Option<Box<'a, SimpleExpressionNode<'a>>>,
+    pub disabled: Option<Box<'a, SimpleExpressionNode<'a>>>,
+    pub block: BlockIRNode<'a>,
+}
+
+/// Transition operation (`<Transition>` / `<TransitionGroup>`)
+#[derive(Debug)]
+pub struct TransitionIRNode<'a> {
+    pub id: usize,
+    /// `<TransitionGroup>`, wrapping a keyed `v-for` block
+    pub group: bool,
+    pub props: Vec<'a, IRProp<'a>>,
+    pub block: BlockIRNode<'a>,
+}
+
+/// Suspense operation, rendering its `#default` and `#fallback` slots
+#[derive(Debug)]
+pub struct SuspenseIRNode<'a> {
+    pub id: usize,
+    pub props: Vec<'a, IRProp<'a>>,
+    pub slots: Vec<'a, IRSlot<'a>>,
+}
+
 /// Create component operation
 #[derive(Debug)]
 pub struct CreateComponentIRNode<'a> {
     pub id: usize,
     pub tag: String,
+    /// `:is` of `<component>`, resolved at runtime instead of `tag`
+    pub dynamic: Option<Box<'a, SimpleExpressionNode<'a>>>,
     pub props: Vec<'a, IRProp<'a>>,
     pub slots: Vec<'a, IRSlot<'a>>,
     pub asset: bool,
@@ -275,7 +361,8 @@ pub struct SlotOutletIRNode<'a> {
 }
 
 /// Get text child operation
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GetTextChildIRNode {
     pub parent: usize,
 }
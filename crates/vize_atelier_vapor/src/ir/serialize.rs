@@ -0,0 +1,322 @@
+//! `Serialize` impls for the Vapor IR.
+//!
+//! IR nodes borrow template AST nodes from the arena, so they are serialized by
+//! hand: expressions become `{ content, isStatic }` and the AST back-references
+//! (`RootIRNode::node`, `BlockIRNode::node`) are left out.
+
+use serde::ser::{SerializeSeq, SerializeStruct, Serializer};
+use serde::Serialize;
+use vize_atelier_core::{DirectiveNode, ExpressionNode, SimpleExpressionNode};
+use vize_carton::Box;
+
+use super::*;
+
+/// Implement `Serialize` as a camelCase struct with the listed fields.
+macro_rules! serialize_struct {
+    ($ty:ty, $name:literal, |$this:ident| { $($key:literal => $value:expr),* $(,)? }) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let $this = self;
+                let mut state = serializer.serialize_struct($name, [$($key),*].len())?;
+                $(state.serialize_field($key, &$value)?;)*
+                state.end()
+            }
+        }
+    };
+}
+
+struct Expr<'b, 'a>(&'b SimpleExpressionNode<'a>);
+
+impl Serialize for Expr<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Expression", 2)?;
+        state.serialize_field("content", self.0.content.as_str())?;
+        state.serialize_field("isStatic", &self.0.is_static)?;
+        state.end()
+    }
+}
+
+struct Exprs<'b, 'a>(&'b [Box<'a, SimpleExpressionNode<'a>>]);
+
+impl Serialize for Exprs<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for exp in self.0 {
+            seq.serialize_element(&Expr(exp))?;
+        }
+        seq.end()
+    }
+}
+
+fn expr<'b, 'a>(exp: &'b Option<Box<'a, SimpleExpressionNode<'a>>>) -> Option<Expr<'b, 'a>> {
+    exp.as_deref().map(Expr)
+}
+
+fn expression_source<'b>(exp: &'b ExpressionNode<'_>) -> &'b str {
+    match exp {
+        ExpressionNode::Simple(simple) => simple.content.as_str(),
+        ExpressionNode::Compound(compound) => compound.loc.source.as_str(),
+    }
+}
+
+struct Directive<'b, 'a>(&'b DirectiveNode<'a>);
+
+impl Serialize for Directive<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let dir = self.0;
+        let modifiers: std::vec::Vec<&str> =
+            dir.modifiers.iter().map(|m| m.content.as_str()).collect();
+        let mut state = serializer.serialize_struct("Directive", 4)?;
+        state.serialize_field("name", dir.name.as_str())?;
+        state.serialize_field("arg", &dir.arg.as_ref().map(expression_source))?;
+        state.serialize_field("exp", &dir.exp.as_ref().map(expression_source))?;
+        state.serialize_field("modifiers", &modifiers)?;
+        state.end()
+    }
+}
+
+serialize_struct!(RootIRNode<'_>, "RootIRNode", |node| {
+    "source" => node.source,
+    "templates" => &node.templates[..],
+    "component" => &node.component[..],
+    "directive" => &node.directive[..],
+    "block" => node.block,
+    "hasTemplateRef" => node.has_template_ref,
+    "hasDeferredVShow" => node.has_deferred_v_show,
+    "errors" => node.errors.iter().map(|e| e.message.as_str()).collect::<std::vec::Vec<_>>(),
+    "warnings" => node.warnings.iter().map(|e| e.message.as_str()).collect::<std::vec::Vec<_>>(),
+});
+
+serialize_struct!(BlockIRNode<'_>, "BlockIRNode", |block| {
+    "dynamic" => block.dynamic,
+    "tempId" => block.temp_id,
+    "effect" => &block.effect[..],
+    "operation" => &block.operation[..],
+    "returns" => &block.returns[..],
+});
+
+serialize_struct!(IREffect<'_>, "IREffect", |effect| {
+    "operations" => &effect.operations[..],
+    "memo" => effect.memo,
+});
+
+serialize_struct!(IRMemo<'_>, "IRMemo", |memo| {
+    "deps" => Expr(&memo.deps),
+    "cacheIndex" => memo.cache_index,
+});
+
+impl Serialize for OperationNode<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        const NAME: &str = "OperationNode";
+        match self {
+            Self::SetProp(op) => serializer.serialize_newtype_variant(NAME, 0, "SetProp", op),
+            Self::SetDynamicProps(op) => {
+                serializer.serialize_newtype_variant(NAME, 1, "SetDynamicProps", op)
+            }
+            Self::SetText(op) => serializer.serialize_newtype_variant(NAME, 2, "SetText", op),
+            Self::SetEvent(op) => serializer.serialize_newtype_variant(NAME, 3, "SetEvent", op),
+            Self::SetHtml(op) => serializer.serialize_newtype_variant(NAME, 4, "SetHtml", op),
+            Self::SetDisplay(op) => serializer.serialize_newtype_variant(NAME, 5, "SetDisplay", op),
+            Self::SetTemplateRef(op) => {
+                serializer.serialize_newtype_variant(NAME, 6, "SetTemplateRef", op)
+            }
+            Self::InsertNode(op) => serializer.serialize_newtype_variant(NAME, 7, "InsertNode", op),
+            Self::PrependNode(op) => {
+                serializer.serialize_newtype_variant(NAME, 8, "PrependNode", op)
+            }
+            Self::Directive(op) => serializer.serialize_newtype_variant(NAME, 9, "Directive", op),
+            Self::If(op) => serializer.serialize_newtype_variant(NAME, 10, "If", &**op),
+            Self::For(op) => serializer.serialize_newtype_variant(NAME, 11, "For", &**op),
+            Self::Teleport(op) => serializer.serialize_newtype_variant(NAME, 12, "Teleport", &**op),
+            Self::Transition(op) => {
+                serializer.serialize_newtype_variant(NAME, 13, "Transition", &**op)
+            }
+            Self::Suspense(op) => serializer.serialize_newtype_variant(NAME, 14, "Suspense", &**op),
+            Self::CreateComponent(op) => {
+                serializer.serialize_newtype_variant(NAME, 15, "CreateComponent", op)
+            }
+            Self::SlotOutlet(op) => {
+                serializer.serialize_newtype_variant(NAME, 16, "SlotOutlet", op)
+            }
+            Self::GetTextChild(op) => {
+                serializer.serialize_newtype_variant(NAME, 17, "GetTextChild", op)
+            }
+        }
+    }
+}
+
+serialize_struct!(SetPropIRNode<'_>, "SetPropIRNode", |op| {
+    "element" => op.element,
+    "prop" => op.prop,
+    "tag" => op.tag,
+});
+
+serialize_struct!(IRProp<'_>, "IRProp", |prop| {
+    "key" => Expr(&prop.key),
+    "values" => Exprs(&prop.values),
+    "isComponent" => prop.is_component,
+    "model" => prop.model,
+});
+
+serialize_struct!(SetDynamicPropsIRNode<'_>, "SetDynamicPropsIRNode", |op| {
+    "element" => op.element,
+    "props" => Exprs(&op.props),
+});
+
+serialize_struct!(SetTextIRNode<'_>, "SetTextIRNode", |op| {
+    "element" => op.element,
+    "values" => Exprs(&op.values),
+});
+
+serialize_struct!(SetEventIRNode<'_>, "SetEventIRNode", |op| {
+    "element" => op.element,
+    "key" => Expr(&op.key),
+    "value" => expr(&op.value),
+    "modifiers" => op.modifiers,
+    "delegate" => op.delegate,
+    "effect" => op.effect,
+});
+
+serialize_struct!(SetHtmlIRNode<'_>, "SetHtmlIRNode", |op| {
+    "element" => op.element,
+    "value" => Expr(&op.value),
+});
+
+serialize_struct!(SetDisplayIRNode<'_>, "SetDisplayIRNode", |op| {
+    "element" => op.element,
+    "value" => Expr(&op.value),
+});
+
+serialize_struct!(SetTemplateRefIRNode<'_>, "SetTemplateRefIRNode", |op| {
+    "element" => op.element,
+    "value" => Expr(&op.value),
+    "refFor" => op.ref_for,
+    "effect" => op.effect,
+});
+
+serialize_struct!(DirectiveIRNode<'_>, "DirectiveIRNode", |op| {
+    "element" => op.element,
+    "dir" => Directive(&op.dir),
+    "name" => op.name,
+    "builtin" => op.builtin,
+    "modelType" => op.model_type,
+});
+
+serialize_struct!(IfIRNode<'_>, "IfIRNode", |op| {
+    "id" => op.id,
+    "condition" => Expr(&op.condition),
+    "positive" => op.positive,
+    "negative" => op.negative,
+    "once" => op.once,
+    "parent" => op.parent,
+    "anchor" => op.anchor,
+});
+
+impl Serialize for NegativeBranch<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Block(block) => {
+                serializer.serialize_newtype_variant("NegativeBranch", 0, "Block", block)
+            }
+            Self::If(if_node) => {
+                serializer.serialize_newtype_variant("NegativeBranch", 1, "If", &**if_node)
+            }
+        }
+    }
+}
+
+serialize_struct!(ForIRNode<'_>, "ForIRNode", |op| {
+    "id" => op.id,
+    "source" => Expr(&op.source),
+    "value" => expr(&op.value),
+    "key" => expr(&op.key),
+    "index" => expr(&op.index),
+    "keyProp" => expr(&op.key_prop),
+    "render" => op.render,
+    "once" => op.once,
+    "component" => op.component,
+    "onlyChild" => op.only_child,
+});
+
+serialize_struct!(TeleportIRNode<'_>, "TeleportIRNode", |op| {
+    "id" => op.id,
+    "to" => expr(&op.to),
+    "disabled" => expr(&op.disabled),
+    "block" => op.block,
+});
+
+serialize_struct!(TransitionIRNode<'_>, "TransitionIRNode", |op| {
+    "id" => op.id,
+    "group" => op.group,
+    "props" => &op.props[..],
+    "block" => op.block,
+});
+
+serialize_struct!(SuspenseIRNode<'_>, "SuspenseIRNode", |op| {
+    "id" => op.id,
+    "props" => &op.props[..],
+    "slots" => &op.slots[..],
+});
+
+serialize_struct!(CreateComponentIRNode<'_>, "CreateComponentIRNode", |op| {
+    "id" => op.id,
+    "tag" => op.tag,
+    "dynamic" => expr(&op.dynamic),
+    "props" => &op.props[..],
+    "slots" => &op.slots[..],
+    "asset" => op.asset,
+    "once" => op.once,
+    "dynamicSlots" => op.dynamic_slots,
+});
+
+serialize_struct!(IRSlot<'_>, "IRSlot", |slot| {
+    "name" => Expr(&slot.name),
+    "fnExp" => expr(&slot.fn_exp),
+    "block" => slot.block,
+});
+
+serialize_struct!(SlotOutletIRNode<'_>, "SlotOutletIRNode", |op| {
+    "id" => op.id,
+    "name" => Expr(&op.name),
+    "props" => &op.props[..],
+    "fallback" => op.fallback,
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::{compile_vapor_ir, VaporCompilerOptions};
+    use vize_carton::Bump;
+
+    #[test]
+    fn test_ir_to_json() {
+        let allocator = Bump::new();
+        let (ir, errors) = compile_vapor_ir(
+            &allocator,
+            r#"<div :id="id" @click="onClick">{{ msg }}</div>"#,
+            VaporCompilerOptions::default(),
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        let json = serde_json::to_string(&ir).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["templates"].as_array().unwrap().len(), 1);
+        let block = &value["block"];
+        assert_eq!(block["returns"], serde_json::json!([0]));
+
+        let mut ops: Vec<&serde_json::Value> =
+            block["operation"].as_array().unwrap().iter().collect();
+        for effect in block["effect"].as_array().unwrap() {
+            ops.extend(effect["operations"].as_array().unwrap());
+        }
+        let set_prop = ops.iter().find_map(|op| op.get("SetProp")).unwrap();
+        assert_eq!(set_prop["prop"]["key"]["content"], "id");
+        let set_event = ops.iter().find_map(|op| op.get("SetEvent")).unwrap();
+        assert_eq!(set_event["key"]["content"], "click");
+        let set_text = ops.iter().find_map(|op| op.get("SetText")).unwrap();
+        assert!(set_text["values"][0]["content"]
+            .as_str()
+            .unwrap()
+            .contains("msg"));
+    }
+}
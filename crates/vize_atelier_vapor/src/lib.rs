@@ -5,12 +5,15 @@
 
 #![allow(clippy::collapsible_match)]
 
+mod compiler;
 pub mod generate;
 pub mod generators;
 pub mod ir;
+pub mod source_map;
 pub mod transform;
 pub mod transforms;
 
+pub use compiler::VaporCompiler;
 pub use generate::*;
 pub use generators::*;
 pub use ir::*;
@@ -21,20 +24,68 @@ use vize_atelier_core::{
     options::{ParserOptions, TransformOptions},
     parser::parse_with_options,
     transform::transform,
+    CompilerError, RootNode,
 };
 use vize_carton::Bump;
 
 /// Vapor compiler options
-#[derive(Debug, Clone, Default)]
+#[derive(Debug)]
 pub struct VaporCompilerOptions {
     /// Whether to prefix identifiers
     pub prefix_identifiers: bool,
-    /// Whether in SSR mode
+    /// Whether in SSR mode (emits a string-building `ssrRender` function)
     pub ssr: bool,
     /// Binding metadata
     pub binding_metadata: Option<vize_atelier_core::options::BindingMetadata>,
     /// Whether to inline
     pub inline: bool,
+    /// Whether to generate a source map
+    pub source_map: bool,
+    /// Whitespace handling strategy
+    pub whitespace: vize_atelier_core::options::WhitespaceStrategy,
+    /// Scope ID for scoped CSS (data-v-xxx), added to elements in SSR output
+    pub scope_id: Option<vize_carton::String>,
+    /// Mark non-deterministic bindings with `data-allow-mismatch` in SSR output
+    pub ssr_hydration_hints: bool,
+    /// Module runtime helpers are imported from (e.g. `vue/vapor`)
+    pub runtime_module_name: vize_carton::String,
+    /// Croquis summary of `<script setup>`, handed to the SSR compiler
+    pub croquis: Option<Box<vize_croquis::analysis::Croquis>>,
+}
+
+impl Clone for VaporCompilerOptions {
+    fn clone(&self) -> Self {
+        Self {
+            prefix_identifiers: self.prefix_identifiers,
+            ssr: self.ssr,
+            binding_metadata: self.binding_metadata.clone(),
+            inline: self.inline,
+            source_map: self.source_map,
+            whitespace: self.whitespace,
+            scope_id: self.scope_id.clone(),
+            ssr_hydration_hints: self.ssr_hydration_hints,
+            runtime_module_name: self.runtime_module_name.clone(),
+            // Croquis is not cloneable
+            croquis: None,
+        }
+    }
+}
+
+impl Default for VaporCompilerOptions {
+    fn default() -> Self {
+        Self {
+            prefix_identifiers: false,
+            ssr: false,
+            binding_metadata: None,
+            inline: false,
+            source_map: false,
+            whitespace: Default::default(),
+            scope_id: None,
+            ssr_hydration_hints: false,
+            runtime_module_name: "vue".into(),
+            croquis: None,
+        }
+    }
 }
 
 /// Vapor compilation result
@@ -44,8 +95,48 @@ pub struct VaporCompileResult {
     pub code: std::string::String,
     /// Template strings for static parts
     pub templates: Vec<vize_carton::String>,
-    /// Error messages during compilation
-    pub error_messages: Vec<std::string::String>,
+    /// Diagnostics reported during compilation
+    pub errors: Vec<VaporDiagnostic>,
+    /// Warnings reported during compilation; the code is still usable
+    pub warnings: Vec<VaporDiagnostic>,
+    /// Source map (v3 JSON), when enabled
+    pub map: Option<std::string::String>,
+}
+
+impl VaporCompileResult {
+    /// Error messages without their spans
+    pub fn error_messages(&self) -> Vec<std::string::String> {
+        self.errors.iter().map(|e| e.message.clone()).collect()
+    }
+}
+
+/// Vapor compilation diagnostic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VaporDiagnostic {
+    /// Error message
+    pub message: std::string::String,
+    /// Start byte offset in the template source
+    pub start: u32,
+    /// End byte offset in the template source
+    pub end: u32,
+    /// Compiler error code
+    pub code: u16,
+}
+
+impl From<&CompilerError> for VaporDiagnostic {
+    fn from(error: &CompilerError) -> Self {
+        let (start, end) = error
+            .loc
+            .as_ref()
+            .map(|loc| (loc.start.offset, loc.end.offset))
+            .unwrap_or((0, 0));
+        Self {
+            message: error.message.clone(),
+            start,
+            end,
+            code: error.code as u16,
+        }
+    }
 }
 
 /// Compile a Vue template to Vapor mode
@@ -54,38 +145,146 @@ pub fn compile_vapor<'a>(
     source: &'a str,
     options: VaporCompilerOptions,
 ) -> VaporCompileResult {
+    compile_vapor_with_cache(
+        allocator,
+        source,
+        options,
+        &mut generate::HelperImportCache::default(),
+    )
+}
+
+/// Compile a Vue template to Vapor mode, reusing helper imports from `cache`
+pub(crate) fn compile_vapor_with_cache<'a>(
+    allocator: &'a Bump,
+    source: &'a str,
+    options: VaporCompilerOptions,
+    cache: &mut generate::HelperImportCache,
+) -> VaporCompileResult {
+    if options.ssr {
+        return compile_vapor_ssr(allocator, source, options);
+    }
+
     // Parse
-    let parser_opts = ParserOptions::default();
-    let (mut root, errors) = parse_with_options(allocator, source, parser_opts);
+    let (root, errors) = parse_template(allocator, source, &options);
 
     if !errors.is_empty() {
         return VaporCompileResult {
             code: String::new(),
             templates: Vec::new(),
-            error_messages: errors.iter().map(|e| e.message.clone()).collect(),
+            errors: errors.iter().map(VaporDiagnostic::from).collect(),
+            warnings: Vec::new(),
+            map: None,
         };
     }
 
     // Transform to Vapor IR
+    let ir = lower_to_ir(allocator, root, options);
+
+    // Generate Vapor code
+    let generate_opts = VaporGenerateOptions {
+        source_map: options.source_map,
+        runtime_module_name: options.runtime_module_name.clone(),
+    };
+    let result = generate::generate_vapor_with_cache(&ir, &generate_opts, cache);
+
+    VaporCompileResult {
+        code: result.code,
+        templates: result.templates,
+        errors: ir.errors.iter().map(VaporDiagnostic::from).collect(),
+        warnings: ir.warnings.iter().map(VaporDiagnostic::from).collect(),
+        map: result.map,
+    }
+}
+
+/// Lower a Vue template to Vapor IR without generating code
+///
+/// Returns the IR that `compile_vapor` would hand to codegen, along with
+/// parse and lowering diagnostics. Tooling can walk the IR (or serialize it
+/// to JSON) to show how a template is lowered.
+pub fn compile_vapor_ir<'a>(
+    allocator: &'a Bump,
+    source: &'a str,
+    options: VaporCompilerOptions,
+) -> (RootIRNode<'a>, Vec<VaporDiagnostic>) {
+    let (root, errors) = parse_template(allocator, source, &options);
+    let mut diagnostics: Vec<VaporDiagnostic> = errors.iter().map(VaporDiagnostic::from).collect();
+
+    let ir = lower_to_ir(allocator, root, options);
+    diagnostics.extend(ir.errors.iter().map(VaporDiagnostic::from));
+
+    (ir, diagnostics)
+}
+
+fn parse_template<'a>(
+    allocator: &'a Bump,
+    source: &'a str,
+    options: &VaporCompilerOptions,
+) -> (RootNode<'a>, vize_carton::Vec<'a, CompilerError>) {
+    let parser_opts = ParserOptions {
+        whitespace: options.whitespace,
+        ..Default::default()
+    };
+    parse_with_options(allocator, source, parser_opts)
+}
+
+fn lower_to_ir<'a>(
+    allocator: &'a Bump,
+    mut root: RootNode<'a>,
+    options: VaporCompilerOptions,
+) -> RootIRNode<'a> {
     let transform_opts = TransformOptions {
         prefix_identifiers: options.prefix_identifiers,
         ssr: options.ssr,
         binding_metadata: options.binding_metadata,
         inline: options.inline,
+        whitespace: options.whitespace,
         ..Default::default()
     };
-    transform(allocator, &mut root, transform_opts, None);
+    let bindings = transform_opts.binding_metadata.clone();
+    let errors = transform(allocator, &mut root, transform_opts, None);
+    let warnings = std::mem::take(&mut root.warnings);
 
-    // Transform to Vapor IR
-    let ir = transform_to_ir(allocator, &root);
+    let mut ir = transform_to_ir_with_bindings(allocator, &root, bindings);
+    ir.errors.splice(0..0, errors);
+    ir.warnings.splice(0..0, warnings);
+    ir
+}
 
-    // Generate Vapor code
-    let result = generate_vapor(&ir);
+/// Compile a Vue template to a Vapor SSR render function
+///
+/// Vapor components render to the same HTML as VDOM ones on the server, so
+/// this shares the SSR compiler: static subtrees become literal `_push` chunks
+/// and interpolations go through `_ssrInterpolate`.
+fn compile_vapor_ssr<'a>(
+    allocator: &'a Bump,
+    source: &'a str,
+    options: VaporCompilerOptions,
+) -> VaporCompileResult {
+    let ssr_opts = vize_atelier_ssr::SsrCompilerOptions {
+        inline: options.inline,
+        scope_id: options.scope_id,
+        ssr_hydration_hints: options.ssr_hydration_hints,
+        runtime_module_name: options.runtime_module_name,
+        croquis: options.croquis,
+        ..Default::default()
+    };
+    let (_, errors, result) =
+        vize_atelier_ssr::compile_ssr_with_options(allocator, source, ssr_opts);
+
+    let mut code =
+        std::string::String::with_capacity(result.preamble.len() + result.code.len() + 1);
+    code.push_str(&result.preamble);
+    if !result.preamble.is_empty() && !result.preamble.ends_with('\n') {
+        code.push('\n');
+    }
+    code.push_str(&result.code);
 
     VaporCompileResult {
-        code: result.code,
-        templates: result.templates,
-        error_messages: Vec::new(),
+        code,
+        templates: Vec::new(),
+        errors: errors.iter().map(VaporDiagnostic::from).collect(),
+        warnings: Vec::new(),
+        map: None,
     }
 }
 
@@ -106,7 +305,7 @@ mod tests {
         let allocator = Bump::new();
         let result = compile_vapor(&allocator, "<div>hello</div>", Default::default());
 
-        assert!(result.error_messages.is_empty(), "Expected no errors");
+        assert!(result.errors.is_empty(), "Expected no errors");
 
         let code = normalize_code(&result.code);
 
@@ -142,7 +341,7 @@ mod tests {
         let allocator = Bump::new();
         let result = compile_vapor(&allocator, "<div>{{ msg }}</div>", Default::default());
 
-        assert!(result.error_messages.is_empty(), "Expected no errors");
+        assert!(result.errors.is_empty(), "Expected no errors");
 
         let code = normalize_code(&result.code);
 
@@ -167,6 +366,39 @@ mod tests {
         assert!(code.contains("msg"), "Should reference msg variable");
     }
 
+    #[test]
+    fn test_compile_dedupes_static_templates() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<span>-</span><p>{{ msg }}</p><span>-</span>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert_eq!(
+            code.matches("_template(\"<span>-</span>\", true)").count(),
+            1,
+            "Identical templates should share a declaration: {}",
+            code
+        );
+        assert!(code.contains("const n0 = t0()"), "{}", code);
+        assert!(code.contains("const n2 = t0()"), "{}", code);
+    }
+
+    #[test]
+    fn test_compile_keeps_templates_with_different_scope_ids() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<span data-v-1>-</span><span data-v-2>-</span>"#,
+            Default::default(),
+        );
+
+        assert_eq!(result.templates.len(), 2, "{:?}", result.templates);
+    }
+
     #[test]
     fn test_compile_event() {
         let allocator = Bump::new();
@@ -176,7 +408,7 @@ mod tests {
             Default::default(),
         );
 
-        assert!(result.error_messages.is_empty(), "Expected no errors");
+        assert!(result.errors.is_empty(), "Expected no errors");
 
         let code = normalize_code(&result.code);
 
@@ -202,6 +434,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_compile_event_modifiers() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<button @click.stop.prevent="fn">Click</button>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("withModifiers as _withModifiers"),
+            "Should import withModifiers: {}",
+            code
+        );
+        assert!(
+            code.contains(
+                "_createInvoker(_withModifiers(e => _ctx.fn(e), [\"stop\", \"prevent\"]))"
+            ),
+            "Should guard the handler with the modifier list: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_key_and_mouse_modifiers() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<input @keyup.enter="submit"><div @click.right="menu"></div>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("_withKeys(e => _ctx.submit(e), [\"enter\"])"),
+            "Should filter keys with withKeys: {}",
+            code
+        );
+        assert!(
+            code.contains(
+                "$evtcontextmenu = _createInvoker(_withModifiers(e => _ctx.menu(e), [\"right\"]))"
+            ),
+            "Right click should listen to contextmenu: {}",
+            code
+        );
+    }
+
     #[test]
     fn test_compile_v_if() {
         let allocator = Bump::new();
@@ -212,9 +494,9 @@ mod tests {
         );
 
         assert!(
-            result.error_messages.is_empty(),
+            result.errors.is_empty(),
             "Expected no errors: {:?}",
-            result.error_messages
+            result.errors
         );
 
         let code = normalize_code(&result.code);
@@ -238,9 +520,9 @@ mod tests {
         );
 
         assert!(
-            result.error_messages.is_empty(),
+            result.errors.is_empty(),
             "Expected no errors: {:?}",
-            result.error_messages
+            result.errors
         );
 
         let code = normalize_code(&result.code);
@@ -253,4 +535,1195 @@ mod tests {
         );
         assert!(code.contains("items"), "Should reference items source");
     }
+
+    #[test]
+    fn test_compile_v_show() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<div v-show="visible">x</div>"#,
+            Default::default(),
+        );
+
+        assert!(
+            result.errors.is_empty(),
+            "Expected no errors: {:?}",
+            result.errors
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("_renderEffect(() => _setDisplay(n0, _ctx.visible))"),
+            "Should toggle display in a render effect: {}",
+            code
+        );
+        assert!(
+            code.contains("setDisplay as _setDisplay"),
+            "Should import setDisplay: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_v_show_with_v_if() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<div v-if="ok" v-show="visible">x</div>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("_createIf"),
+            "v-if should stay structural: {}",
+            code
+        );
+        assert!(
+            code.contains("_setDisplay(") && code.contains("_ctx.visible"),
+            "v-show should apply inside the branch: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_v_memo() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<div v-memo="[a, b]">{{ msg }}</div>"#,
+            Default::default(),
+        );
+
+        assert!(
+            result.errors.is_empty(),
+            "Expected no errors: {:?}",
+            result.errors
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("withMemo as _withMemo"),
+            "Should import withMemo: {}",
+            code
+        );
+        assert!(
+            code.contains("_renderEffect(() => _withMemo(() => [_ctx.a, _ctx.b], () => _setText("),
+            "Should guard the effect with the dependency array: {}",
+            code
+        );
+        assert!(
+            code.contains("_cache, 0))"),
+            "Should use cache slot 0: {}",
+            code
+        );
+        assert!(
+            !code.contains("_withDirectives"),
+            "v-memo is not a runtime directive: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_v_memo_in_v_for() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<ul><li v-for="item in items" v-memo="[selected]">{{ label }}</li></ul>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        let for_pos = code.find("_createFor(").expect("Should render a list");
+        let cache_pos = code
+            .find("const _cache = []")
+            .expect("Should declare a memo cache");
+        assert!(
+            cache_pos > for_pos,
+            "Each iteration should own its cache: {}",
+            code
+        );
+        assert!(code.contains("_withMemo(() => [_ctx.selected]"), "{}", code);
+    }
+
+    #[test]
+    fn test_compile_v_once() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<div><p v-once @click="onClick">{{ a }}</p><p>{{ b }}</p></div>"#,
+            Default::default(),
+        );
+
+        assert!(
+            result.errors.is_empty(),
+            "Expected no errors: {:?}",
+            result.errors
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.lines()
+                .any(|line| line.starts_with("_setText(") && line.contains("_ctx.a")),
+            "v-once text should be set once, outside an effect: {}",
+            code
+        );
+        assert!(
+            !code
+                .lines()
+                .any(|line| line.contains("_renderEffect") && line.contains("_ctx.a")),
+            "v-once text should not be wrapped in a render effect: {}",
+            code
+        );
+        assert!(
+            code.lines()
+                .any(|line| line.contains("_renderEffect(() => _setText(")
+                    && line.contains("_ctx.b")),
+            "Sibling without v-once should stay reactive: {}",
+            code
+        );
+        assert!(
+            code.contains("onClick"),
+            "Event handlers stay attached under v-once: {}",
+            code
+        );
+        assert!(
+            !code.contains("_withDirectives"),
+            "v-once is not a runtime directive: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_v_model_text() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<input v-model="text" />"#,
+            Default::default(),
+        );
+
+        assert!(
+            result.errors.is_empty(),
+            "Expected no errors: {:?}",
+            result.errors
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("applyTextModel as _applyTextModel"),
+            "Should import applyTextModel: {}",
+            code
+        );
+        assert!(
+            code.contains("_applyTextModel(n0, () => (_ctx.text), _value => (_ctx.text = _value))"),
+            "Should read and write the binding: {}",
+            code
+        );
+        assert!(
+            !code.contains("update:modelValue"),
+            "Should not emit a separate update listener: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_v_model_element_types() {
+        let cases = [
+            (
+                r#"<input type="checkbox" v-model="checked" />"#,
+                "_applyCheckboxModel",
+            ),
+            (
+                r#"<input type="radio" v-model="picked" value="a" />"#,
+                "_applyRadioModel",
+            ),
+            (
+                r#"<select v-model="selected"><option>A</option></select>"#,
+                "_applySelectModel",
+            ),
+            (
+                r#"<textarea v-model="content"></textarea>"#,
+                "_applyTextModel",
+            ),
+            (
+                r#"<input :type="kind" v-model="value" />"#,
+                "_applyDynamicModel",
+            ),
+        ];
+
+        for (source, helper) in cases {
+            let allocator = Bump::new();
+            let result = compile_vapor(&allocator, source, Default::default());
+            let code = normalize_code(&result.code);
+            assert!(
+                code.contains(helper),
+                "{} should use {}: {}",
+                source,
+                helper,
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_compile_v_model_modifiers() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<input v-model.lazy.number.trim="msg" />"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains(
+                "_applyTextModel(n0, () => (_ctx.msg), _value => (_ctx.msg = _value), { lazy: true, number: true, trim: true })"
+            ),
+            "Should pass modifiers: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_v_model_component() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<MyComponent v-model="value" />"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("modelValue: () => (_ctx.value)"),
+            "Should forward modelValue: {}",
+            code
+        );
+        assert!(
+            code.contains("\"onUpdate:modelValue\": () => _value => (_ctx.value = _value)"),
+            "Should forward update:modelValue: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_v_model_keeps_scope_aliases() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<div v-for="(item, i) in list"><input v-model="values[i]" /><MyComponent v-model="item.name" /></div>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("() => (_ctx.values[i]), _value => (_ctx.values[i] = _value)"),
+            "Should leave the index alias unprefixed: {}",
+            code
+        );
+        assert!(
+            code.contains("modelValue: () => (item.name)"),
+            "Should read the item alias: {}",
+            code
+        );
+        assert!(
+            code.contains("\"onUpdate:modelValue\": () => _value => (item.name = _value)"),
+            "Should assign through the item alias: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_same_name_bind_shorthand() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<Foo :count :item-id disabled /><div :title></div>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(
+            code.contains("count: () => (_ctx.count)"),
+            "`:count` should bind `count`: {}",
+            code
+        );
+        assert!(
+            code.contains("\"item-id\": () => (_ctx.itemId)"),
+            "Kebab-case names should bind the camelized variable: {}",
+            code
+        );
+        assert!(
+            code.contains("disabled: () => (\"\")"),
+            "Static attributes should not be expanded: {}",
+            code
+        );
+        assert!(
+            code.contains("_ctx.title"),
+            "Elements should expand the shorthand too: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_same_name_bind_without_binding() {
+        use vize_atelier_core::options::{BindingMetadata, BindingType};
+
+        let mut metadata = BindingMetadata::default();
+        metadata
+            .bindings
+            .insert("count".into(), BindingType::SetupRef);
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<Foo :count :total />"#,
+            VaporCompilerOptions {
+                binding_metadata: Some(metadata),
+                ..Default::default()
+            },
+        );
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let unresolved: Vec<_> = result
+            .warnings
+            .iter()
+            .filter(|e| e.code == vize_atelier_core::ErrorCode::VBindSameNameNoBinding as u16)
+            .collect();
+        assert_eq!(unresolved.len(), 1, "{:?}", result.warnings);
+        assert!(r#"<Foo :count :total />"#[unresolved[0].start as usize..].starts_with(":total"));
+    }
+
+    #[test]
+    fn test_compile_named_scoped_slot() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<MyComponent><template #header="{ title }"><h1>{{ title }}</h1></template></MyComponent>"#,
+            Default::default(),
+        );
+
+        assert!(
+            result.errors.is_empty(),
+            "Expected no errors: {:?}",
+            result.errors
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("header: ({ title }) => {"),
+            "Should emit a scoped slot function: {}",
+            code
+        );
+        assert!(
+            code.contains("_toDisplayString(title)"),
+            "Slot body should read the scope binding: {}",
+            code
+        );
+        assert!(
+            !code.contains("_ctx.title"),
+            "Scope binding should not be read from _ctx: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_default_slot() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<MyComponent><span>{{ msg }}</span></MyComponent>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("default: () => {"),
+            "Should emit the implicit default slot: {}",
+            code
+        );
+        assert!(
+            code.contains("_toDisplayString(_ctx.msg)"),
+            "Non-scope identifiers still come from _ctx: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_dynamic_slot_name() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<MyComponent><template #[name]>x</template></MyComponent>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(code.contains("$: ["), "Should mark dynamic slots: {}", code);
+        assert!(
+            code.contains("name: _ctx.name"),
+            "Should evaluate the slot name: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_teleport_static_to() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r##"<Teleport to="#modal"><div class="modal">hi</div></Teleport>"##,
+            Default::default(),
+        );
+
+        assert!(
+            result.errors.is_empty(),
+            "Expected no errors: {:?}",
+            result.errors
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("_createTeleport({ to: \"#modal\" }, () => {"),
+            "Should create a teleport with a static target: {}",
+            code
+        );
+        assert!(
+            !code.contains("_resolveComponent(\"Teleport\")"),
+            "Teleport should not be resolved as a component: {}",
+            code
+        );
+        assert!(
+            result
+                .templates
+                .iter()
+                .any(|t| t.contains("<div class=\"modal\">hi</div>")),
+            "Teleport children should be hoisted: {:?}",
+            result.templates
+        );
+    }
+
+    #[test]
+    fn test_compile_teleport_bound_to() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<Teleport :to="target" :disabled="off"><span>hi</span></Teleport>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains(
+                "_createTeleport({ to: () => (_ctx.target), disabled: () => (_ctx.off) }, () => {"
+            ),
+            "Bound target should be reactive: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_transition() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<Transition name="fade" :mode="mode" appear><div v-if="show">hi</div></Transition>"#,
+            Default::default(),
+        );
+
+        assert!(
+            result.errors.is_empty(),
+            "Expected no errors: {:?}",
+            result.errors
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains(
+                "_createTransition({ name: () => (\"fade\"), mode: () => (_ctx.mode), appear: undefined }, () => {"
+            ),
+            "Should create a transition forwarding its props: {}",
+            code
+        );
+        assert!(
+            code.contains("_createIf(() => (_ctx.show)"),
+            "Transition should wrap the v-if block: {}",
+            code
+        );
+        assert!(
+            !code.contains("_resolveComponent(\"Transition\")"),
+            "Transition should not be resolved as a component: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_component_key_is_not_a_prop() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<Foo :key="id" :title="title" /><Bar key="b" />"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+        assert!(
+            code.contains("{ title: () => (_ctx.title) }"),
+            "Other props should still be passed: {}",
+            code
+        );
+        assert!(!code.contains("key:"), "Keys should not be props: {}", code);
+    }
+
+    #[test]
+    fn test_compile_suspense() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<Suspense :timeout="delay"><template #default><AsyncView /></template><template #fallback><p>Loading...</p></template></Suspense>"#,
+            Default::default(),
+        );
+
+        assert!(
+            result.errors.is_empty(),
+            "Expected no errors: {:?}",
+            result.errors
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("_createSuspense({ timeout: () => (_ctx.delay) }, {"),
+            "Should create a suspense boundary: {}",
+            code
+        );
+        assert!(
+            code.contains("default: () => {") && code.contains("fallback: () => {"),
+            "Both slots should compile to render functions: {}",
+            code
+        );
+        assert!(
+            code.contains("_resolveComponent(\"AsyncView\")"),
+            "Async components inside should resolve normally: {}",
+            code
+        );
+        assert!(
+            !code.contains("_resolveComponent(\"Suspense\")"),
+            "Suspense should not be resolved as a component: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_transition_group() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<TransitionGroup tag="ul"><li v-for="item in items" :key="item.id">{{ item.name }}</li></TransitionGroup>"#,
+            Default::default(),
+        );
+
+        assert!(
+            result.errors.is_empty(),
+            "Expected no errors: {:?}",
+            result.errors
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("_createTransitionGroup({ tag: () => (\"ul\") }, () => {"),
+            "Should create a transition group: {}",
+            code
+        );
+        assert!(
+            code.contains("}, (item) => (item.id))"),
+            "The v-for should receive its key getter: {}",
+            code
+        );
+        assert!(
+            !code.contains("\"key\""),
+            "Keys should not be set as props: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_transition_group_warns_on_missing_keys() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<TransitionGroup><li v-for="item in items">{{ item }}</li></TransitionGroup>"#,
+            Default::default(),
+        );
+
+        assert!(
+            result.errors.is_empty(),
+            "Expected no errors: {:?}",
+            result.errors
+        );
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|e| e.code == vize_atelier_core::ErrorCode::VForMissingKey),
+            "Unkeyed TransitionGroup children should be warned about: {:?}",
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn test_compile_transition_group_template_v_for_key() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<TransitionGroup><template v-for="item in items" :key="item.id"><li>{{ item.name }}</li><li>{{ item.desc }}</li></template></TransitionGroup>"#,
+            Default::default(),
+        );
+
+        assert!(
+            !result
+                .errors
+                .iter()
+                .any(|e| e.code == vize_atelier_core::ErrorCode::VForMissingKey),
+            "A key on the <template v-for> host should count: {:?}",
+            result.errors
+        );
+        let code = normalize_code(&result.code);
+        assert!(
+            code.contains("}, (item) => (item.id))"),
+            "The v-for should receive the host's key getter: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_v_for_range() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<ul><li v-for="n in 5">{{ n }}</li></ul>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+        assert!(
+            code.contains("_createFor(() => 5, (n) => {"),
+            "Numeric bound should be passed through: {}",
+            code
+        );
+        assert!(
+            code.contains("_toDisplayString(n)") && !code.contains("_ctx.n"),
+            "Loop alias should not be prefixed: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_v_for_non_finite_name_is_expression() {
+        // `f64` parsing accepts `inf`/`nan`, which are identifiers here
+        for name in ["inf", "nan", "infinity"] {
+            let allocator = Bump::new();
+            let source = format!(r#"<ul><li v-for="n in {name}">{{{{ n }}}}</li></ul>"#);
+            let result = compile_vapor(&allocator, &source, Default::default());
+
+            let code = normalize_code(&result.code);
+            assert!(
+                code.contains(&format!("(_ctx.{name} || [])")),
+                "`{name}` should be read from the context: {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_compile_v_for_object() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<ul><li v-for="(val, key, index) in obj">{{ index }}. {{ key }}: {{ val }}</li></ul>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+        assert!(
+            code.contains("_createFor(() => (_ctx.obj || []), (val, key, index) => {"),
+            "Object source should receive value, key and index: {}",
+            code
+        );
+        for alias in ["index", "key", "val"] {
+            assert!(
+                code.contains(&format!("_toDisplayString({})", alias)),
+                "{} should be read from the loop: {}",
+                alias,
+                code
+            );
+        }
+        assert!(!code.contains("_ctx.val"), "{}", code);
+    }
+
+    #[test]
+    fn test_compile_v_for_destructured_alias() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<ul><li v-for="{ id } in items">{{ id }}</li></ul>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+        assert!(code.contains("({ id }) => {"), "{}", code);
+        assert!(
+            code.contains("_toDisplayString(id)") && !code.contains("_ctx.id"),
+            "Destructured alias should be exposed to the body: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_v_html() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<div v-html="content"><p>placeholder</p></div>"#,
+            Default::default(),
+        );
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let code = normalize_code(&result.code);
+        assert!(
+            code.contains("_renderEffect(() => _setHtml(n0, _ctx.content))"),
+            "Should set innerHTML from content: {}",
+            code
+        );
+        assert!(
+            !result.templates.iter().any(|t| t.contains("placeholder")),
+            "Children of v-html should be dropped: {:?}",
+            result.templates
+        );
+    }
+
+    #[test]
+    fn test_compile_v_text() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<span v-text="msg"></span>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+        assert!(
+            code.contains("_renderEffect(() => _setText(n0, _toDisplayString(_ctx.msg)))"),
+            "Should set text content from msg: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_v_html_with_interpolation_error() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<div v-html="content">{{ msg }}</div>"#,
+            Default::default(),
+        );
+
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.code == vize_atelier_core::ErrorCode::VHtmlWithChildren as u16),
+            "Expected a v-html children diagnostic: {:?}",
+            result.errors
+        );
+        assert!(!result.code.contains("_ctx.msg"), "{}", result.code);
+    }
+
+    #[test]
+    fn test_compile_merged_class_binding() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<div class="a" :class="{ active: isActive }"></div>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains(
+                r#"_renderEffect(() => _setClass(n0, ["a", { active: _ctx.isActive }]))"#
+            ),
+            "Static and dynamic classes should merge in one effect: {}",
+            code
+        );
+        assert!(
+            !result.templates.iter().any(|t| t.contains("class=")),
+            "Merged class should not stay in the template: {:?}",
+            result.templates
+        );
+    }
+
+    #[test]
+    fn test_compile_merged_class_array_and_style() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<p class="base" :class="[classes, { on }]" style="color: red" :style="styles"></p>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains(r#"_setClass(n0, ["base", [_ctx.classes, { on: _ctx.on }]])"#),
+            "Static class should merge with the binding: {}",
+            code
+        );
+        assert!(
+            code.contains(r#"_setStyle(n0, ["color: red", _ctx.styles])"#),
+            "Static style should merge with the binding: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_merged_class_escapes_and_keeps_scope() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<div v-for="item in items" class='say "hi"' :class="{ active: item.on }"></div>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains(r#"["say \"hi\"", { active: item.on }]"#),
+            "Static class should be escaped and the v-for alias left unprefixed: {}",
+            code
+        );
+        assert!(!code.contains("_ctx.item"), "{}", code);
+    }
+
+    #[test]
+    fn test_compile_dynamic_component() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<component :is="which" :msg="msg"><span>slot</span></component>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("createDynamicComponent as _createDynamicComponent"),
+            "Should import the dynamic component helper: {}",
+            code
+        );
+        assert!(
+            code.contains(
+                "_createDynamicComponent(() => (_ctx.which), { msg: () => (_ctx.msg) }, {"
+            ),
+            "Should resolve `which` reactively and forward props: {}",
+            code
+        );
+        assert!(
+            code.contains("default: () => {"),
+            "Should forward the default slot: {}",
+            code
+        );
+        assert!(
+            !code.contains("_resolveComponent") && !code.contains("is: "),
+            "`is` should not be passed as a prop: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_component_static_is() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<component is="div" class="box">hi</component>"#,
+            Default::default(),
+        );
+
+        assert!(
+            result
+                .templates
+                .iter()
+                .any(|t| t.contains(r#"<div class="box">hi</div>"#)),
+            "Static native `is` should render the element: {:?}",
+            result.templates
+        );
+        assert!(
+            !result.code.contains("_createDynamicComponent")
+                && !result.code.contains("_resolveComponent"),
+            "Static native `is` should not create a component: {}",
+            result.code
+        );
+
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<component is="MyComp" />"#,
+            Default::default(),
+        );
+        assert!(
+            result.code.contains(r#"_resolveComponent("MyComp")"#),
+            "Static component `is` should resolve the component: {}",
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_compile_static_template_ref() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<input ref="inputRef" />"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains("createTemplateRefSetter as _createTemplateRefSetter"),
+            "Should import the ref helper: {}",
+            code
+        );
+        assert!(
+            code.contains("const _setTemplateRef = _createTemplateRefSetter()"),
+            "Should create the ref setter: {}",
+            code
+        );
+        assert!(
+            code.contains(r#"_setTemplateRef(n0, "inputRef")"#),
+            "Should bind inputRef to the element: {}",
+            code
+        );
+        assert!(
+            !result.templates.iter().any(|t| t.contains("ref=")),
+            "ref should not be rendered: {:?}",
+            result.templates
+        );
+    }
+
+    #[test]
+    fn test_compile_template_ref_in_v_for() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<li v-for="item in items" ref="itemRefs">{{ item }}</li>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains(r#", "itemRefs", void 0, true)"#),
+            "Refs inside v-for should collect into an array: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_dynamic_template_ref() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<div :ref="el => (target = el)"></div>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains(
+                "_renderEffect(() => r0 = _setTemplateRef(n0, el => (_ctx.target = el), r0))"
+            ),
+            "Bound refs should be function refs updated in an effect: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_template_ref_escapes_static_name() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r#"<div ref='say"hi"'></div>"#,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains(r#"_setTemplateRef(n0, "say\"hi\"")"#),
+            "Static ref names should be escaped: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_escapes_static_strings() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            r##"<Teleport to='#a"b'><Foo title='say"hi"' /></Teleport><p v-for="i in list" key='k"1'></p>"##,
+            Default::default(),
+        );
+
+        let code = normalize_code(&result.code);
+
+        assert!(
+            code.contains(r##"to: "#a\"b""##),
+            "Static teleport targets should be escaped: {}",
+            code
+        );
+        assert!(
+            code.contains(r#"title: () => ("say\"hi\"")"#),
+            "Static component props should be escaped: {}",
+            code
+        );
+        assert!(
+            code.contains(r#"(i) => ("k\"1"))"#),
+            "Static v-for keys should be escaped: {}",
+            code
+        );
+    }
+
+    #[test]
+    fn test_compile_parse_error_span() {
+        let allocator = Bump::new();
+        let source = "<div></span></div>";
+        let result = compile_vapor(&allocator, source, Default::default());
+
+        assert!(!result.errors.is_empty(), "Expected a parse error");
+        let error = &result.errors[0];
+        assert!(
+            error.end > error.start,
+            "Diagnostic should carry a span: {:?}",
+            error
+        );
+        assert!(
+            source[error.start as usize..error.end as usize].contains("span"),
+            "Span should cover the stray end tag: {:?}",
+            error
+        );
+        assert_eq!(result.error_messages()[0], error.message);
+    }
+
+    #[test]
+    fn test_compile_transform_error_span() {
+        let allocator = Bump::new();
+        let source = "<div>ok</div><p v-show></p>";
+        let result = compile_vapor(&allocator, source, Default::default());
+
+        let error = result
+            .errors
+            .iter()
+            .find(|e| e.code == vize_atelier_core::ErrorCode::VShowNoExpression as u16)
+            .expect("Expected a v-show diagnostic");
+        assert!(
+            source[error.start as usize..error.end as usize].starts_with("v-show"),
+            "Span should cover the directive: {:?}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_compile_with_runtime_module_name() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            "<div>{{ msg }}</div>",
+            VaporCompilerOptions {
+                runtime_module_name: "@my/runtime".into(),
+                ..Default::default()
+            },
+        );
+
+        assert!(result.errors.is_empty(), "Expected no errors");
+        let import = result.code.lines().next().unwrap_or_default();
+        assert!(
+            import.starts_with("import {") && import.ends_with("from '@my/runtime';"),
+            "Imports should target the runtime module: {}",
+            result.code
+        );
+        assert!(!result.code.contains("from 'vue'"), "{}", result.code);
+    }
+
+    #[test]
+    fn test_compile_imports_are_deterministic() {
+        let source = r#"<div :class="cls" :style="css" :title="t" v-show="visible" @click="onClick">{{ msg }}<p v-html="html"></p><input v-model="text"></div>"#;
+        let import_line = || {
+            let allocator = Bump::new();
+            let result = compile_vapor(&allocator, source, VaporCompilerOptions::default());
+            assert!(result.errors.is_empty(), "Expected no errors");
+            result.code.lines().next().unwrap_or_default().to_string()
+        };
+
+        let first = import_line();
+        assert!(first.starts_with("import {"), "{}", first);
+        for _ in 0..8 {
+            assert_eq!(import_line(), first);
+        }
+    }
+
+    #[test]
+    fn test_compile_ssr() {
+        let allocator = Bump::new();
+        let result = compile_vapor(
+            &allocator,
+            "<div>{{ msg }}</div>",
+            VaporCompilerOptions {
+                ssr: true,
+                ..Default::default()
+            },
+        );
+
+        assert!(
+            result.errors.is_empty(),
+            "Expected no errors: {:?}",
+            result.errors
+        );
+
+        let code = &result.code;
+        assert!(
+            code.contains("_push("),
+            "Should push string buffers: {}",
+            code
+        );
+        assert!(
+            code.contains("_ssrInterpolate(_ctx.msg)"),
+            "Should escape interpolations: {}",
+            code
+        );
+        assert!(
+            !code.contains("_template(") && !code.contains("_renderEffect("),
+            "Should not create DOM nodes: {}",
+            code
+        );
+        assert!(result.templates.is_empty());
+    }
 }
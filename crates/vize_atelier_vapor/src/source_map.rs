@@ -0,0 +1,423 @@
+//! Source map generation for Vapor output.
+//!
+//! Collects generated-to-template position mappings during code generation
+//! and serializes them as a Source Map v3 JSON string. The builder and VLQ
+//! codec are shared with the SFC compiler, which merges block maps.
+
+use std::fmt::Write;
+
+/// Default source name used in emitted source maps
+pub(crate) const DEFAULT_SOURCE_NAME: &str = "template.vue";
+
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// A single mapping (all values 0-based)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Mapping {
+    pub generated_line: u32,
+    pub generated_column: u32,
+    pub source_line: u32,
+    pub source_column: u32,
+}
+
+/// Source map builder
+#[derive(Debug, Default)]
+pub struct SourceMapBuilder {
+    mappings: Vec<Mapping>,
+}
+
+impl SourceMapBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, mapping: Mapping) {
+        self.mappings.push(mapping);
+    }
+
+    /// Shift every generated line, used once the preamble length is known
+    pub fn offset_lines(&mut self, lines: u32) {
+        for mapping in self.mappings.iter_mut() {
+            mapping.generated_line += lines;
+        }
+    }
+
+    /// Serialize as a Source Map v3 JSON string
+    pub fn to_json(&self, source_name: &str, source_content: &str) -> String {
+        let mut json = String::with_capacity(128 + source_content.len());
+        json.push_str("{\"version\":3,\"sources\":[\"");
+        escape_json_into(source_name, &mut json);
+        json.push_str("\"],\"sourcesContent\":[\"");
+        escape_json_into(source_content, &mut json);
+        json.push_str("\"],\"names\":[],\"mappings\":\"");
+        json.push_str(&self.encode_mappings());
+        json.push_str("\"}");
+        json
+    }
+
+    /// Encode mappings as Base64 VLQ segments
+    fn encode_mappings(&self) -> String {
+        let mut sorted = self.mappings.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        let mut out = String::new();
+        let mut current_line = 0;
+        let mut prev_generated_column = 0i64;
+        let mut prev_source_line = 0i64;
+        let mut prev_source_column = 0i64;
+        let mut first_in_line = true;
+
+        for mapping in sorted.iter() {
+            while current_line < mapping.generated_line {
+                out.push(';');
+                current_line += 1;
+                prev_generated_column = 0;
+                first_in_line = true;
+            }
+            if !first_in_line {
+                out.push(',');
+            }
+            first_in_line = false;
+
+            encode_vlq(
+                mapping.generated_column as i64 - prev_generated_column,
+                &mut out,
+            );
+            // Single source, always index 0
+            encode_vlq(0, &mut out);
+            encode_vlq(mapping.source_line as i64 - prev_source_line, &mut out);
+            encode_vlq(mapping.source_column as i64 - prev_source_column, &mut out);
+
+            prev_generated_column = mapping.generated_column as i64;
+            prev_source_line = mapping.source_line as i64;
+            prev_source_column = mapping.source_column as i64;
+        }
+
+        out
+    }
+}
+
+/// Decode a Source Map v3 `mappings` string
+///
+/// Only the first source is tracked; segments without a source position
+/// are skipped. Decoding stops at the first malformed segment (invalid or
+/// truncated VLQ, or a delta that moves a position below zero).
+pub fn decode_mappings(mappings: &str) -> Vec<Mapping> {
+    let mut result = Vec::new();
+    let (mut source_line, mut source_column) = (0i64, 0i64);
+
+    for (line, segments) in mappings.split(';').enumerate() {
+        let mut generated_column = 0i64;
+        for segment in segments.split(',').filter(|s| !s.is_empty()) {
+            let Some(values) = decode_segment(segment) else {
+                return result;
+            };
+
+            generated_column += values[0];
+            if values.len() < 4 {
+                continue;
+            }
+            source_line += values[2];
+            source_column += values[3];
+
+            let (Ok(generated_column), Ok(source_line), Ok(source_column)) = (
+                u32::try_from(generated_column),
+                u32::try_from(source_line),
+                u32::try_from(source_column),
+            ) else {
+                return result;
+            };
+            result.push(Mapping {
+                generated_line: line as u32,
+                generated_column,
+                source_line,
+                source_column,
+            });
+        }
+    }
+
+    result
+}
+
+/// Decode the Base64 VLQ values of one segment
+///
+/// Returns `None` for invalid digits, a value cut off mid-continuation, or a
+/// value too large to fit in an `i64`.
+fn decode_segment(segment: &str) -> Option<Vec<i64>> {
+    let mut values = Vec::with_capacity(5);
+    let (mut value, mut shift) = (0i64, 0u32);
+    for c in segment.bytes() {
+        let digit = BASE64_CHARS.iter().position(|&b| b == c)? as i64;
+        if shift > 58 {
+            return None;
+        }
+        value += (digit & 0b11111) << shift;
+        if digit & 0b100000 != 0 {
+            shift += 5;
+        } else {
+            let negative = value & 1 == 1;
+            value >>= 1;
+            values.push(if negative { -value } else { value });
+            value = 0;
+            shift = 0;
+        }
+    }
+
+    // A trailing continuation digit means the last value was truncated
+    (shift == 0 && !values.is_empty()).then_some(values)
+}
+
+/// Encode a single value as Base64 VLQ
+fn encode_vlq(value: i64, out: &mut String) {
+    let mut vlq = if value < 0 {
+        ((-value) << 1) | 1
+    } else {
+        value << 1
+    };
+
+    loop {
+        let mut digit = (vlq & 0b11111) as usize;
+        vlq >>= 5;
+        if vlq > 0 {
+            digit |= 0b100000;
+        }
+        out.push(BASE64_CHARS[digit] as char);
+        if vlq == 0 {
+            break;
+        }
+    }
+}
+
+/// Escape a string for inclusion in a JSON string literal
+fn escape_json_into(s: &str, out: &mut String) {
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                write!(out, "\\u{:04x}", c as u32).ok();
+            }
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{compile_vapor, VaporCompilerOptions};
+    use vize_carton::Bump;
+
+    #[test]
+    fn test_encode_vlq() {
+        let mut out = String::new();
+        encode_vlq(0, &mut out);
+        encode_vlq(1, &mut out);
+        encode_vlq(-1, &mut out);
+        encode_vlq(16, &mut out);
+        assert_eq!(out, "ACDgB");
+    }
+
+    #[test]
+    fn test_decode_mappings_roundtrip() {
+        let mut builder = SourceMapBuilder::new();
+        let mappings = [
+            Mapping {
+                generated_line: 0,
+                generated_column: 4,
+                source_line: 2,
+                source_column: 6,
+            },
+            Mapping {
+                generated_line: 3,
+                generated_column: 1,
+                source_line: 1,
+                source_column: 0,
+            },
+        ];
+        for mapping in mappings {
+            builder.add(mapping);
+        }
+        assert_eq!(decode_mappings(&builder.encode_mappings()), mappings);
+        // Generated-only segments carry no source position
+        assert!(decode_mappings("A;C").is_empty());
+    }
+
+    #[test]
+    fn test_vlq_negative_roundtrip() {
+        for value in [-1i64, -15, -16, -1000, 1000, i32::MIN as i64] {
+            let mut out = String::new();
+            encode_vlq(value, &mut out);
+            assert_eq!(decode_segment(&out), Some(vec![value]), "{}", out);
+        }
+    }
+
+    #[test]
+    fn test_decode_mappings_negative_deltas() {
+        let mut builder = SourceMapBuilder::new();
+        // Source positions move backwards as generated positions advance
+        let mappings = [
+            Mapping {
+                generated_line: 0,
+                generated_column: 2,
+                source_line: 5,
+                source_column: 20,
+            },
+            Mapping {
+                generated_line: 0,
+                generated_column: 9,
+                source_line: 1,
+                source_column: 3,
+            },
+            Mapping {
+                generated_line: 1,
+                generated_column: 0,
+                source_line: 0,
+                source_column: 0,
+            },
+        ];
+        for mapping in mappings {
+            builder.add(mapping);
+        }
+        assert_eq!(decode_mappings(&builder.encode_mappings()), mappings);
+
+        // A delta that moves the source line below zero is rejected
+        assert!(decode_mappings("AADA").is_empty());
+    }
+
+    #[test]
+    fn test_decode_mappings_truncated() {
+        let complete = Mapping {
+            generated_line: 0,
+            generated_column: 0,
+            source_line: 0,
+            source_column: 0,
+        };
+        // The last segment ends on a continuation digit
+        assert_eq!(decode_mappings("AAAA,CAAg"), vec![complete]);
+        assert!(decode_mappings("g").is_empty());
+        // Overlong continuation runs do not overflow
+        assert!(decode_mappings("gggggggggggggggA").is_empty());
+        // Invalid characters stop decoding
+        assert_eq!(decode_mappings("AAAA;!AAA"), vec![complete]);
+    }
+
+    #[test]
+    fn test_escape_json() {
+        let mut out = String::new();
+        escape_json_into("<div class=\"a\">\n</div>", &mut out);
+        assert_eq!(out, "<div class=\\\"a\\\">\\n</div>");
+    }
+
+    #[test]
+    fn test_source_map_disabled_by_default() {
+        let allocator = Bump::new();
+        let result = compile_vapor(&allocator, "<div>{{ msg }}</div>", Default::default());
+        assert!(result.map.is_none());
+    }
+
+    #[test]
+    fn test_source_map_interpolation() {
+        let allocator = Bump::new();
+        let source = "<div>{{ msg }}</div>";
+        let result = compile_vapor(
+            &allocator,
+            source,
+            VaporCompilerOptions {
+                source_map: true,
+                ..Default::default()
+            },
+        );
+
+        let map = result.map.expect("source map should be generated");
+        assert!(map.starts_with("{\"version\":3,"), "{}", map);
+        assert!(map.contains("\"sources\":[\"template.vue\"]"), "{}", map);
+
+        let mappings_start = map.find("\"mappings\":\"").unwrap() + 12;
+        let mappings = &map[mappings_start..map.len() - 2];
+        let decoded = decode_mappings(mappings);
+
+        // `msg` starts at byte 8 on the first template line
+        let msg_offset = source.find("msg").unwrap() as u32;
+        let lines: Vec<&str> = result.code.lines().collect();
+        assert!(
+            decoded.iter().any(|m| {
+                m.source_line == 0
+                    && m.source_column == msg_offset
+                    && lines[m.generated_line as usize][m.generated_column as usize..]
+                        .starts_with("msg")
+            }),
+            "Expected a segment pointing at `msg`: {:?}\n{}",
+            decoded,
+            result.code
+        );
+    }
+
+    #[test]
+    fn test_source_map_event_handler() {
+        let allocator = Bump::new();
+        let source = "<button @click=\"onClick\">ok</button>";
+        let result = compile_vapor(
+            &allocator,
+            source,
+            VaporCompilerOptions {
+                source_map: true,
+                ..Default::default()
+            },
+        );
+
+        let map = result.map.unwrap();
+        let mappings_start = map.find("\"mappings\":\"").unwrap() + 12;
+        let decoded = decode_mappings(&map[mappings_start..map.len() - 2]);
+        let handler_offset = source.find("onClick").unwrap() as u32;
+
+        assert!(
+            decoded
+                .iter()
+                .any(|m| m.source_line == 0 && m.source_column == handler_offset),
+            "Expected a segment pointing at the handler: {:?}",
+            decoded
+        );
+    }
+
+    #[test]
+    fn test_source_map_records_expression_columns() {
+        let allocator = Bump::new();
+        // `n` also appears in `n0`, and `é` is two bytes but one UTF-16 unit
+        let source = "<div v-for=\"n in list\"><p>é{{ n }}</p></div>";
+        let result = compile_vapor(
+            &allocator,
+            source,
+            VaporCompilerOptions {
+                source_map: true,
+                ..Default::default()
+            },
+        );
+
+        let map = result.map.unwrap();
+        let mappings_start = map.find("\"mappings\":\"").unwrap() + 12;
+        let decoded = decode_mappings(&map[mappings_start..map.len() - 2]);
+        let alias_column = source[..source.rfind("n }}").unwrap()]
+            .encode_utf16()
+            .count() as u32;
+        let lines: Vec<&str> = result.code.lines().collect();
+
+        let mapping = decoded
+            .iter()
+            .find(|m| m.source_line == 0 && m.source_column == alias_column)
+            .unwrap_or_else(|| panic!("Expected a segment for `n`: {:?}", decoded));
+        let line: Vec<u16> = lines[mapping.generated_line as usize]
+            .encode_utf16()
+            .collect();
+        assert!(
+            String::from_utf16_lossy(&line[mapping.generated_column as usize..]).starts_with("n))"),
+            "Expected the segment at the alias read: {:?}\n{}",
+            mapping,
+            result.code
+        );
+    }
+}
@@ -5,31 +5,52 @@
 use vize_carton::{Box, Bump, FxHashMap, String, Vec};
 
 use crate::ir::*;
+use crate::transforms::{
+    collect_component_slots, expand_same_name_bind, is_component_is_prop, is_dynamic_component_tag,
+    is_slot_template, is_suspense_tag, is_teleport_tag, is_transition_group_tag, is_transition_tag,
+    parse_modifiers, resolve_click_event, resolve_component_is, transform_suspense,
+    transform_teleport, transform_transition, transform_v_model, transform_v_show, ComponentIs,
+};
+use vize_atelier_core::options::BindingMetadata;
 use vize_atelier_core::{
-    DirectiveNode, ElementNode, ElementType, ExpressionNode, ForNode, IfNode, InterpolationNode,
-    PropNode, RootNode, SimpleExpressionNode, SourceLocation, TemplateChildNode, TextNode,
+    CompilerError, DirectiveNode, ElementNode, ElementType, ErrorCode, ExpressionNode, ForNode,
+    IfNode, InterpolationNode, PropNode, RootNode, SimpleExpressionNode, SourceLocation,
+    TemplateChildNode, TextNode,
 };
 
 /// Transform AST to Vapor IR
 pub fn transform_to_ir<'a>(allocator: &'a Bump, root: &RootNode<'a>) -> RootIRNode<'a> {
+    transform_to_ir_with_bindings(allocator, root, None)
+}
+
+/// Transform AST to Vapor IR, checking template references against the
+/// script's bindings
+pub fn transform_to_ir_with_bindings<'a>(
+    allocator: &'a Bump,
+    root: &RootNode<'a>,
+    bindings: Option<BindingMetadata>,
+) -> RootIRNode<'a> {
     let mut ctx = TransformContext::new(allocator);
+    ctx.bindings = bindings;
 
     // Create block for root
     let block = transform_children(&mut ctx, &root.children);
 
     RootIRNode {
         node: RootNode::new(allocator, ""),
-        source: String::from(""),
+        source: root.source.clone(),
         template: Default::default(),
-        template_index_map: Default::default(),
+        template_index_map: ctx.template_index_map,
         root_template_indexes: Vec::new_in(allocator),
         component: Vec::new_in(allocator),
         directive: Vec::new_in(allocator),
         block,
-        has_template_ref: false,
+        has_template_ref: ctx.has_template_ref,
         has_deferred_v_show: false,
         templates: ctx.templates,
         element_template_map: ctx.element_template_map,
+        errors: ctx.errors,
+        warnings: ctx.warnings,
     }
 }
 
@@ -38,7 +59,21 @@ struct TransformContext<'a> {
     allocator: &'a Bump,
     temp_id: usize,
     templates: Vec<'a, String>,
+    /// Interned template HTML -> template index
+    template_index_map: FxHashMap<String, usize>,
     element_template_map: FxHashMap<usize, usize>,
+    errors: std::vec::Vec<CompilerError>,
+    warnings: std::vec::Vec<CompilerError>,
+    /// Whether any element has a template ref
+    has_template_ref: bool,
+    /// Number of enclosing `v-for` blocks
+    for_depth: usize,
+    /// Whether the current subtree is under `v-once`
+    in_v_once: bool,
+    /// Number of enclosing slot bodies
+    slot_depth: usize,
+    /// Script bindings, when known
+    bindings: Option<BindingMetadata>,
 }
 
 impl<'a> TransformContext<'a> {
@@ -47,10 +82,41 @@ impl<'a> TransformContext<'a> {
             allocator,
             temp_id: 0,
             templates: Vec::new_in(allocator),
+            template_index_map: FxHashMap::default(),
             element_template_map: FxHashMap::default(),
+            errors: std::vec::Vec::new(),
+            warnings: std::vec::Vec::new(),
+            has_template_ref: false,
+            for_depth: 0,
+            in_v_once: false,
+            slot_depth: 0,
+            bindings: None,
+        }
+    }
+
+    /// Whether `name` can resolve in the current scope
+    ///
+    /// Names of v-for and slot scopes are not tracked, so anything may resolve
+    /// inside them, as it does when there is no binding metadata.
+    fn may_resolve(&self, name: &str) -> bool {
+        match &self.bindings {
+            Some(metadata) if self.for_depth == 0 && self.slot_depth == 0 => {
+                metadata.bindings.contains_key(name)
+            }
+            _ => true,
         }
     }
 
+    fn on_error(&mut self, code: ErrorCode, loc: &SourceLocation) {
+        self.errors
+            .push(CompilerError::new(code, Some(loc.clone())));
+    }
+
+    fn on_warn(&mut self, code: ErrorCode, loc: &SourceLocation) {
+        self.warnings
+            .push(CompilerError::new(code, Some(loc.clone())));
+    }
+
     fn next_id(&mut self) -> usize {
         let id = self.temp_id;
         self.temp_id += 1;
@@ -58,9 +124,23 @@ impl<'a> TransformContext<'a> {
     }
 
     fn add_template(&mut self, element_id: usize, template: String) -> usize {
+        let template_index = self.intern_template(template);
+        self.element_template_map.insert(element_id, template_index);
+        template_index
+    }
+
+    /// Register a template, reusing the index of an identical one.
+    ///
+    /// Templates are keyed by their exact HTML, so trees that differ only in
+    /// attributes such as scope ids stay separate.
+    fn intern_template(&mut self, template: String) -> usize {
+        if let Some(&template_index) = self.template_index_map.get(&template) {
+            return template_index;
+        }
         let template_index = self.templates.len();
+        self.template_index_map
+            .insert(template.clone(), template_index);
         self.templates.push(template);
-        self.element_template_map.insert(element_id, template_index);
         template_index
     }
 }
@@ -73,33 +153,65 @@ fn transform_children<'a>(
     let mut block = BlockIRNode::new(ctx.allocator);
     // Note: Don't consume an ID for the block itself - element IDs should start from 0
 
+    for child in children {
+        transform_child(ctx, child, &mut block);
+    }
+
+    if ctx.in_v_once {
+        hoist_once_effects(&mut block, 0);
+    }
+
+    block
+}
+
+/// Transform slot body children, skipping nested `<template v-slot>` declarations
+fn transform_slot_children<'a>(
+    ctx: &mut TransformContext<'a>,
+    children: &[TemplateChildNode<'a>],
+) -> BlockIRNode<'a> {
+    let mut block = BlockIRNode::new(ctx.allocator);
+
+    ctx.slot_depth += 1;
     for child in children {
         match child {
-            TemplateChildNode::Element(el) => {
-                transform_element(ctx, el, &mut block);
-            }
-            TemplateChildNode::Text(text) => {
-                transform_text(ctx, text, &mut block);
-            }
-            TemplateChildNode::Interpolation(interp) => {
-                transform_interpolation(ctx, interp, &mut block);
-            }
-            TemplateChildNode::If(if_node) => {
-                transform_if_node(ctx, if_node, &mut block);
-            }
-            TemplateChildNode::For(for_node) => {
-                transform_for_node(ctx, for_node, &mut block);
-            }
-            TemplateChildNode::Comment(_) => {
-                // Comments are ignored in Vapor mode
-            }
-            _ => {}
+            TemplateChildNode::Element(el) if is_slot_template(el) => {}
+            _ => transform_child(ctx, child, &mut block),
         }
     }
+    ctx.slot_depth -= 1;
 
     block
 }
 
+/// Transform a single child node into the given block
+fn transform_child<'a>(
+    ctx: &mut TransformContext<'a>,
+    child: &TemplateChildNode<'a>,
+    block: &mut BlockIRNode<'a>,
+) {
+    match child {
+        TemplateChildNode::Element(el) => {
+            transform_element(ctx, el, block);
+        }
+        TemplateChildNode::Text(text) => {
+            transform_text(ctx, text, block);
+        }
+        TemplateChildNode::Interpolation(interp) => {
+            transform_interpolation(ctx, interp, block);
+        }
+        TemplateChildNode::If(if_node) => {
+            transform_if_node(ctx, if_node, block);
+        }
+        TemplateChildNode::For(for_node) => {
+            transform_for_node(ctx, for_node, block);
+        }
+        TemplateChildNode::Comment(_) => {
+            // Comments are ignored in Vapor mode
+        }
+        _ => {}
+    }
+}
+
 /// Transform element node
 fn transform_element<'a>(
     ctx: &mut TransformContext<'a>,
@@ -108,18 +220,116 @@ fn transform_element<'a>(
 ) {
     let element_id = ctx.next_id();
 
-    match el.tag_type {
+    // `<component is>` renders a native element or a (dynamic) component
+    let component_is = resolve_component_is(el);
+    let tag_type = match &component_is {
+        Some(ComponentIs::Element(_)) => ElementType::Element,
+        Some(_) => ElementType::Component,
+        None => el.tag_type,
+    };
+
+    match tag_type {
+        _ if is_teleport_tag(&el.tag) => {
+            // Teleport - children keep their hoisted templates, rendered into `to`
+            let teleport_block = transform_children(ctx, &el.children);
+            block.operation.push(transform_teleport(
+                ctx.allocator,
+                el,
+                element_id,
+                teleport_block,
+            ));
+        }
+        _ if is_suspense_tag(&el.tag) => {
+            // Suspense - `#default` and `#fallback` each get their own render function
+            let props = transform_component_props(ctx, el, false);
+            let allocator = ctx.allocator;
+            let slots = collect_component_slots(allocator, el, |children| {
+                transform_slot_children(ctx, children)
+            });
+            block
+                .operation
+                .push(transform_suspense(ctx.allocator, element_id, props, slots));
+        }
+        _ if is_transition_tag(&el.tag) || is_transition_group_tag(&el.tag) => {
+            // Transition - props are forwarded as getters, children render inside
+            let group = is_transition_group_tag(&el.tag);
+            if group {
+                // The group tracks its v-for children by key
+                for child in el.children.iter() {
+                    if let TemplateChildNode::For(for_node) = child {
+                        if for_key_prop(ctx.allocator, for_node).is_none() {
+                            ctx.on_warn(ErrorCode::VForMissingKey, &for_node.loc);
+                        }
+                    }
+                }
+            }
+            let props = transform_component_props(ctx, el, false);
+            let transition_block = transform_children(ctx, &el.children);
+            block.operation.push(transform_transition(
+                ctx.allocator,
+                element_id,
+                group,
+                props,
+                transition_block,
+            ));
+        }
         ElementType::Element => {
             // Generate template string and register it
             let template = generate_element_template(el);
             ctx.add_template(element_id, template);
+            let effect_start = block.effect.len();
+            let was_in_v_once = ctx.in_v_once;
+            ctx.in_v_once |= has_v_once(el);
+
+            // v-html / v-text replace the element's content, so children are dropped
+            let children: &[TemplateChildNode<'a>] = match content_directive(el) {
+                Some(dir) => {
+                    let has_interpolation = el
+                        .children
+                        .iter()
+                        .any(|c| matches!(c, TemplateChildNode::Interpolation(_)));
+                    if has_interpolation {
+                        let code = if dir.name == "html" {
+                            ErrorCode::VHtmlWithChildren
+                        } else {
+                            ErrorCode::VTextWithChildren
+                        };
+                        ctx.on_error(code, &dir.loc);
+                    }
+                    &[]
+                }
+                None => &el.children,
+            };
 
             // Process props and events
             for prop in el.props.iter() {
                 match prop {
+                    PropNode::Directive(dir) if is_ref_binding(dir) => {
+                        if let Some(ExpressionNode::Simple(exp)) = &dir.exp {
+                            let value = SimpleExpressionNode::new(
+                                exp.content.clone(),
+                                false,
+                                exp.loc.clone(),
+                            );
+                            transform_template_ref(ctx, element_id, value, block);
+                        }
+                    }
+                    PropNode::Directive(dir) if is_key_binding(dir) => {
+                        // Keys identify v-for items and are never set on the node
+                    }
                     PropNode::Directive(dir) => {
                         transform_directive(ctx, dir, element_id, el, block);
                     }
+                    PropNode::Attribute(attr) if attr.name == "ref" => {
+                        if let Some(ref value) = attr.value {
+                            let value = SimpleExpressionNode::new(
+                                value.content.clone(),
+                                true,
+                                attr.loc.clone(),
+                            );
+                            transform_template_ref(ctx, element_id, value, block);
+                        }
+                    }
                     PropNode::Attribute(_attr) => {
                         // Static attributes are included in the template
                     }
@@ -127,24 +337,23 @@ fn transform_element<'a>(
             }
 
             // Check if we have mixed text and interpolation children
-            let has_text_or_interpolation = el.children.iter().any(|c| {
+            let has_text_or_interpolation = children.iter().any(|c| {
                 matches!(
                     c,
                     TemplateChildNode::Text(_) | TemplateChildNode::Interpolation(_)
                 )
             });
-            let has_interpolation = el
-                .children
+            let has_interpolation = children
                 .iter()
                 .any(|c| matches!(c, TemplateChildNode::Interpolation(_)));
 
             if has_interpolation && has_text_or_interpolation {
                 // Collect all text parts and interpolations together
-                transform_text_children(ctx, &el.children, element_id, block);
+                transform_text_children(ctx, children, element_id, block);
             }
 
             // Process other dynamic children
-            for child in el.children.iter() {
+            for child in children.iter() {
                 match child {
                     TemplateChildNode::Interpolation(_) | TemplateChildNode::Text(_) => {
                         // Already handled above
@@ -164,130 +373,46 @@ fn transform_element<'a>(
                     _ => {}
                 }
             }
+
+            // v-memo - guard every update effect of this subtree
+            if let Some(deps) = get_memo_deps(el) {
+                apply_memo(ctx, block, effect_start, &deps);
+            }
+
+            // v-once - render the subtree a single time, without update effects
+            if ctx.in_v_once {
+                hoist_once_effects(block, effect_start);
+            }
+            ctx.in_v_once = was_in_v_once;
         }
         ElementType::Component => {
             // Component handling - process props and events
-            let mut props = Vec::new_in(ctx.allocator);
-            let slots = Vec::new_in(ctx.allocator);
+            let props = transform_component_props(ctx, el, component_is.is_some());
 
-            // Process props (v-bind and v-on directives, and static attributes)
-            for prop in el.props.iter() {
-                match prop {
-                    PropNode::Directive(dir) => {
-                        if dir.name.as_str() == "bind" {
-                            // v-bind -> prop
-                            if let Some(ref arg) = dir.arg {
-                                if let ExpressionNode::Simple(key_exp) = arg {
-                                    let key_node = SimpleExpressionNode::new(
-                                        key_exp.content.clone(),
-                                        key_exp.is_static,
-                                        key_exp.loc.clone(),
-                                    );
-                                    let key = Box::new_in(key_node, ctx.allocator);
-
-                                    let mut values = Vec::new_in(ctx.allocator);
-                                    if let Some(ref exp) = dir.exp {
-                                        if let ExpressionNode::Simple(val_exp) = exp {
-                                            let val_node = SimpleExpressionNode::new(
-                                                val_exp.content.clone(),
-                                                val_exp.is_static,
-                                                val_exp.loc.clone(),
-                                            );
-                                            values.push(Box::new_in(val_node, ctx.allocator));
-                                        }
-                                    }
-
-                                    props.push(IRProp {
-                                        key,
-                                        values,
-                                        is_component: true,
-                                    });
-                                }
-                            }
-                        } else if dir.name.as_str() == "on" {
-                            // v-on -> onXxx prop
-                            if let Some(ref arg) = dir.arg {
-                                if let ExpressionNode::Simple(event_exp) = arg {
-                                    // Convert event name to onXxx format
-                                    let event_name = event_exp.content.as_str();
-                                    let on_name = if event_name.is_empty() {
-                                        String::from("on")
-                                    } else {
-                                        let mut s = String::from("on");
-                                        let mut chars = event_name.chars();
-                                        if let Some(c) = chars.next() {
-                                            s.push(c.to_ascii_uppercase());
-                                        }
-                                        for c in chars {
-                                            s.push(c);
-                                        }
-                                        s
-                                    };
-
-                                    let key_node = SimpleExpressionNode::new(
-                                        on_name,
-                                        true,
-                                        event_exp.loc.clone(),
-                                    );
-                                    let key = Box::new_in(key_node, ctx.allocator);
-
-                                    let mut values = Vec::new_in(ctx.allocator);
-                                    if let Some(ref exp) = dir.exp {
-                                        if let ExpressionNode::Simple(val_exp) = exp {
-                                            let val_node = SimpleExpressionNode::new(
-                                                val_exp.content.clone(),
-                                                val_exp.is_static,
-                                                val_exp.loc.clone(),
-                                            );
-                                            values.push(Box::new_in(val_node, ctx.allocator));
-                                        }
-                                    }
-
-                                    props.push(IRProp {
-                                        key,
-                                        values,
-                                        is_component: true,
-                                    });
-                                }
-                            }
-                        }
-                    }
-                    PropNode::Attribute(attr) => {
-                        // Static attribute -> prop
-                        let key_node = SimpleExpressionNode::new(
-                            attr.name.clone(),
-                            true,
-                            SourceLocation::STUB,
-                        );
-                        let key = Box::new_in(key_node, ctx.allocator);
-
-                        let mut values = Vec::new_in(ctx.allocator);
-                        if let Some(ref value) = attr.value {
-                            let val_node = SimpleExpressionNode::new(
-                                value.content.clone(),
-                                true,
-                                SourceLocation::STUB,
-                            );
-                            values.push(Box::new_in(val_node, ctx.allocator));
-                        }
+            // Slot contents become blocks rendered by the component
+            let allocator = ctx.allocator;
+            let slots = collect_component_slots(allocator, el, |children| {
+                transform_slot_children(ctx, children)
+            });
+            let dynamic_slots = slots.iter().any(|slot| !slot.name.is_static);
 
-                        props.push(IRProp {
-                            key,
-                            values,
-                            is_component: true,
-                        });
-                    }
+            let (tag, dynamic) = match component_is {
+                Some(ComponentIs::Dynamic(exp)) => {
+                    (el.tag.clone(), Some(Box::new_in(exp, ctx.allocator)))
                 }
-            }
+                Some(ComponentIs::Component(name)) => (name, None),
+                _ => (el.tag.clone(), None),
+            };
 
             let create_component = CreateComponentIRNode {
                 id: element_id,
-                tag: el.tag.clone(),
+                tag,
+                dynamic,
                 props,
                 slots,
                 asset: true,
-                once: false,
-                dynamic_slots: false,
+                once: ctx.in_v_once || has_v_once(el),
+                dynamic_slots,
             };
 
             block
@@ -328,6 +453,142 @@ fn transform_element<'a>(
     block.returns.push(element_id);
 }
 
+/// Collect component props from v-bind, v-on (as `onXxx`) and static attributes
+///
+/// `skip_is` drops the `is` prop consumed by `<component is>`.
+fn transform_component_props<'a>(
+    ctx: &mut TransformContext<'a>,
+    el: &ElementNode<'a>,
+    skip_is: bool,
+) -> Vec<'a, IRProp<'a>> {
+    let mut props = Vec::new_in(ctx.allocator);
+    // Binding of the last `v-model` expansion, reused by its update listener
+    let mut model_value: Option<SimpleExpressionNode<'a>> = None;
+
+    // Process props (v-bind and v-on directives, and static attributes)
+    for prop in el.props.iter() {
+        if skip_is && is_component_is_prop(prop) {
+            continue;
+        }
+        // `key` identifies the component for the renderer; it is not a prop
+        if is_key_prop(prop) {
+            continue;
+        }
+        match prop {
+            PropNode::Directive(dir) => {
+                if dir.name.as_str() == "bind" {
+                    // v-bind -> prop
+                    if let Some(ref arg) = dir.arg {
+                        if let ExpressionNode::Simple(key_exp) = arg {
+                            let key_node = SimpleExpressionNode::new(
+                                key_exp.content.clone(),
+                                key_exp.is_static,
+                                key_exp.loc.clone(),
+                            );
+                            let key = Box::new_in(key_node, ctx.allocator);
+
+                            let model = dir.model_expansion;
+                            let mut values = Vec::new_in(ctx.allocator);
+                            if let Some(val_node) = bind_value(ctx, dir) {
+                                if model && !key_exp.content.ends_with("Modifiers") {
+                                    model_value = Some(SimpleExpressionNode::new(
+                                        val_node.content.clone(),
+                                        false,
+                                        val_node.loc.clone(),
+                                    ));
+                                }
+                                values.push(Box::new_in(val_node, ctx.allocator));
+                            }
+
+                            props.push(IRProp {
+                                key,
+                                values,
+                                is_component: true,
+                                model,
+                            });
+                        }
+                    }
+                } else if dir.name.as_str() == "on" {
+                    // v-on -> onXxx prop
+                    if let Some(ref arg) = dir.arg {
+                        if let ExpressionNode::Simple(event_exp) = arg {
+                            // Convert event name to onXxx format
+                            let event_name = event_exp.content.as_str();
+                            let on_name = if event_name.is_empty() {
+                                String::from("on")
+                            } else {
+                                let mut s = String::from("on");
+                                let mut chars = event_name.chars();
+                                if let Some(c) = chars.next() {
+                                    s.push(c.to_ascii_uppercase());
+                                }
+                                for c in chars {
+                                    s.push(c);
+                                }
+                                s
+                            };
+
+                            let key_node =
+                                SimpleExpressionNode::new(on_name, true, event_exp.loc.clone());
+                            let key = Box::new_in(key_node, ctx.allocator);
+
+                            let mut values = Vec::new_in(ctx.allocator);
+                            let model = dir.model_expansion;
+                            if model {
+                                // `@update:xxx` from v-model assigns back to the binding
+                                if let Some(value) = model_value.take() {
+                                    values.push(Box::new_in(value, ctx.allocator));
+                                }
+                            } else if let Some(ref exp) = dir.exp {
+                                if let ExpressionNode::Simple(val_exp) = exp {
+                                    let val_node = SimpleExpressionNode::new(
+                                        val_exp.content.clone(),
+                                        val_exp.is_static,
+                                        val_exp.loc.clone(),
+                                    );
+                                    values.push(Box::new_in(val_node, ctx.allocator));
+                                }
+                            }
+
+                            props.push(IRProp {
+                                key,
+                                values,
+                                is_component: true,
+                                model,
+                            });
+                        }
+                    }
+                }
+            }
+            PropNode::Attribute(attr) => {
+                // Static attribute -> prop
+                let key_node =
+                    SimpleExpressionNode::new(attr.name.clone(), true, SourceLocation::STUB);
+                let key = Box::new_in(key_node, ctx.allocator);
+
+                let mut values = Vec::new_in(ctx.allocator);
+                if let Some(ref value) = attr.value {
+                    let val_node = SimpleExpressionNode::new(
+                        value.content.clone(),
+                        true,
+                        SourceLocation::STUB,
+                    );
+                    values.push(Box::new_in(val_node, ctx.allocator));
+                }
+
+                props.push(IRProp {
+                    key,
+                    values,
+                    is_component: true,
+                    model: false,
+                });
+            }
+        }
+    }
+
+    props
+}
+
 /// Transform IfNode (from compiler-core v-if transform)
 fn transform_if_node<'a>(
     ctx: &mut TransformContext<'a>,
@@ -366,6 +627,7 @@ fn transform_if_node<'a>(
         }
     } else {
         // No condition means v-else, which shouldn't be the first branch
+        ctx.on_error(ErrorCode::VIfNoExpression, &first_branch.loc);
         let cond_node = SimpleExpressionNode::new("true", false, SourceLocation::STUB);
         Box::new_in(cond_node, ctx.allocator)
     };
@@ -388,7 +650,7 @@ fn transform_if_node<'a>(
         condition,
         positive,
         negative,
-        once: false,
+        once: ctx.in_v_once,
         parent: None,
         anchor: None,
     };
@@ -540,7 +802,9 @@ fn transform_for_node<'a>(
     });
 
     // Transform children as render block
+    ctx.for_depth += 1;
     let render = transform_children(ctx, &for_node.children);
+    ctx.for_depth -= 1;
 
     let ir_for = ForIRNode {
         id: ctx.next_id(),
@@ -548,9 +812,9 @@ fn transform_for_node<'a>(
         value,
         key,
         index,
-        key_prop: None, // TODO: Handle key prop from element
+        key_prop: for_key_prop(ctx.allocator, for_node),
         render,
-        once: false,
+        once: ctx.in_v_once,
         component: false,
         only_child: for_node.children.len() == 1,
     };
@@ -560,6 +824,138 @@ fn transform_for_node<'a>(
         .push(OperationNode::For(Box::new_in(ir_for, ctx.allocator)));
 }
 
+/// Get the `key` of a v-for, used to track its items
+///
+/// The key lives on the element carrying `v-for`, which is the ForNode's
+/// child: the repeated element itself, or the `<template>` wrapping a group.
+fn for_key_prop<'a>(
+    allocator: &'a Bump,
+    for_node: &ForNode<'a>,
+) -> Option<Box<'a, SimpleExpressionNode<'a>>> {
+    let Some(TemplateChildNode::Element(host)) = for_node.children.first() else {
+        return None;
+    };
+    for prop in host.props.iter() {
+        match prop {
+            PropNode::Attribute(attr) if attr.name == "key" => {
+                let value = attr.value.as_ref()?.content.clone();
+                let node = SimpleExpressionNode::new(value, true, attr.loc.clone());
+                return Some(Box::new_in(node, allocator));
+            }
+            PropNode::Directive(dir) if is_key_binding(dir) => {
+                let Some(ExpressionNode::Simple(exp)) = &dir.exp else {
+                    return None;
+                };
+                let node =
+                    SimpleExpressionNode::new(exp.content.clone(), exp.is_static, exp.loc.clone());
+                return Some(Box::new_in(node, allocator));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Whether a directive is a `:key` binding
+fn is_key_binding(dir: &DirectiveNode<'_>) -> bool {
+    dir.name.as_str() == "bind"
+        && matches!(&dir.arg, Some(ExpressionNode::Simple(arg)) if arg.is_static && arg.content == "key")
+}
+
+/// Whether a prop is a static `key` or a `:key` binding
+fn is_key_prop(prop: &PropNode<'_>) -> bool {
+    match prop {
+        PropNode::Attribute(attr) => attr.name == "key",
+        PropNode::Directive(dir) => is_key_binding(dir),
+    }
+}
+
+/// Whether a directive is a bound template ref (`:ref="fn"`)
+fn is_ref_binding(dir: &DirectiveNode<'_>) -> bool {
+    dir.name.as_str() == "bind"
+        && matches!(&dir.arg, Some(ExpressionNode::Simple(arg)) if arg.is_static && arg.content == "ref")
+}
+
+/// Wire a template ref (`ref="name"` or `:ref="fn"`) to an element
+fn transform_template_ref<'a>(
+    ctx: &mut TransformContext<'a>,
+    element_id: usize,
+    value: SimpleExpressionNode<'a>,
+    block: &mut BlockIRNode<'a>,
+) {
+    ctx.has_template_ref = true;
+    let effect = !value.is_static;
+    block
+        .operation
+        .push(OperationNode::SetTemplateRef(SetTemplateRefIRNode {
+            element: element_id,
+            value: Box::new_in(value, ctx.allocator),
+            ref_for: ctx.for_depth > 0,
+            effect,
+        }));
+}
+
+/// Get the v-memo dependency expression of an element
+fn get_memo_deps<'a>(el: &ElementNode<'a>) -> Option<SimpleExpressionNode<'a>> {
+    el.props.iter().find_map(|prop| match prop {
+        PropNode::Directive(dir) if dir.name == "memo" => match dir.exp.as_ref()? {
+            ExpressionNode::Simple(exp) => Some(SimpleExpressionNode::new(
+                exp.content.clone(),
+                exp.is_static,
+                exp.loc.clone(),
+            )),
+            ExpressionNode::Compound(exp) => Some(SimpleExpressionNode::new(
+                exp.loc.source.clone(),
+                false,
+                exp.loc.clone(),
+            )),
+        },
+        _ => None,
+    })
+}
+
+/// Wrap effects from `effect_start` on with a v-memo guard
+///
+/// Each guarded effect gets its own slot in the block's `_cache`. Blocks own
+/// their cache, so v-for iterations memoize independently.
+fn apply_memo<'a>(
+    ctx: &mut TransformContext<'a>,
+    block: &mut BlockIRNode<'a>,
+    effect_start: usize,
+    deps: &SimpleExpressionNode<'a>,
+) {
+    let mut cache_index = block.effect.iter().filter(|e| e.memo.is_some()).count();
+    for effect in block.effect[effect_start..].iter_mut() {
+        if effect.memo.is_some() {
+            continue;
+        }
+        let deps_node =
+            SimpleExpressionNode::new(deps.content.clone(), deps.is_static, deps.loc.clone());
+        effect.memo = Some(IRMemo {
+            deps: Box::new_in(deps_node, ctx.allocator),
+            cache_index,
+        });
+        cache_index += 1;
+    }
+}
+
+/// Check if an element has `v-once`
+fn has_v_once(el: &ElementNode<'_>) -> bool {
+    el.props
+        .iter()
+        .any(|prop| matches!(prop, PropNode::Directive(dir) if dir.name == "once"))
+}
+
+/// Turn effects from `effect_start` on into plain operations (v-once)
+///
+/// The operations run when the block is created and are never re-run, while
+/// event listeners are untouched since they already live in `operation`.
+fn hoist_once_effects(block: &mut BlockIRNode<'_>, effect_start: usize) {
+    for effect in block.effect.drain(effect_start..) {
+        block.operation.extend(effect.operations);
+    }
+}
+
 /// Transform text node
 fn transform_text<'a>(
     ctx: &mut TransformContext<'a>,
@@ -568,7 +964,7 @@ fn transform_text<'a>(
 ) {
     let element_id = ctx.next_id();
     let template: String = text.content.clone();
-    ctx.templates.push(template);
+    ctx.intern_template(template);
     block.returns.push(element_id);
 }
 
@@ -606,6 +1002,7 @@ fn transform_interpolation<'a>(
 
     block.effect.push(IREffect {
         operations: effect_ops,
+        memo: None,
     });
 
     block.returns.push(element_id);
@@ -658,10 +1055,40 @@ fn transform_text_children<'a>(
 
         block.effect.push(IREffect {
             operations: effect_ops,
+            memo: None,
         });
     }
 }
 
+/// Value of a v-bind with an argument, expanding the same-name shorthand
+///
+/// A shorthand without a matching script binding is warned about but still
+/// compiled, since the name may come from globals the metadata doesn't know.
+fn bind_value<'a>(
+    ctx: &mut TransformContext<'a>,
+    dir: &DirectiveNode<'a>,
+) -> Option<SimpleExpressionNode<'a>> {
+    match &dir.exp {
+        Some(ExpressionNode::Simple(val_exp)) => Some(SimpleExpressionNode::new(
+            val_exp.content.clone(),
+            val_exp.is_static,
+            val_exp.loc.clone(),
+        )),
+        Some(ExpressionNode::Compound(_)) => None,
+        None => {
+            // `:[key]` has no name to expand to
+            let Some(value) = expand_same_name_bind(dir) else {
+                ctx.on_error(ErrorCode::VBindSameNameShorthand, &dir.loc);
+                return None;
+            };
+            if !ctx.may_resolve(&value.content) {
+                ctx.on_warn(ErrorCode::VBindSameNameNoBinding, &dir.loc);
+            }
+            Some(value)
+        }
+    }
+}
+
 /// Transform directive
 fn transform_directive<'a>(
     ctx: &mut TransformContext<'a>,
@@ -670,6 +1097,22 @@ fn transform_directive<'a>(
     el: &ElementNode<'a>,
     block: &mut BlockIRNode<'a>,
 ) {
+    // Directives that are meaningless without an expression
+    let missing_expression = match dir.name.as_str() {
+        "show" => Some(ErrorCode::VShowNoExpression),
+        "model" => Some(ErrorCode::VModelNoExpression),
+        "on" if dir.modifiers.is_empty() => Some(ErrorCode::VOnNoExpression),
+        "html" => Some(ErrorCode::VHtmlNoExpression),
+        "text" => Some(ErrorCode::VTextNoExpression),
+        _ => None,
+    };
+    if let Some(code) = missing_expression {
+        if dir.exp.is_none() {
+            ctx.on_error(code, &dir.loc);
+            return;
+        }
+    }
+
     match dir.name.as_str() {
         "bind" => {
             // v-bind - SetProp
@@ -682,22 +1125,17 @@ fn transform_directive<'a>(
                     );
                     let key = Box::new_in(key_node, ctx.allocator);
 
-                    let values = if let Some(ref exp) = dir.exp {
-                        if let ExpressionNode::Simple(val_exp) = exp {
-                            let mut v = Vec::new_in(ctx.allocator);
-                            let val_node = SimpleExpressionNode::new(
-                                val_exp.content.clone(),
-                                val_exp.is_static,
-                                val_exp.loc.clone(),
-                            );
-                            v.push(Box::new_in(val_node, ctx.allocator));
-                            v
-                        } else {
-                            Vec::new_in(ctx.allocator)
-                        }
-                    } else {
-                        Vec::new_in(ctx.allocator)
-                    };
+                    let mut values = Vec::new_in(ctx.allocator);
+                    // A static `class`/`style` is merged with the binding instead of
+                    // living in the template, so updates keep it
+                    if let Some(static_value) = merged_static_attr(el, key_exp) {
+                        let static_node =
+                            SimpleExpressionNode::new(static_value, true, SourceLocation::STUB);
+                        values.push(Box::new_in(static_node, ctx.allocator));
+                    }
+                    if let Some(val_node) = bind_value(ctx, dir) {
+                        values.push(Box::new_in(val_node, ctx.allocator));
+                    }
 
                     let set_prop = SetPropIRNode {
                         element: element_id,
@@ -705,6 +1143,7 @@ fn transform_directive<'a>(
                             key,
                             values,
                             is_component: el.tag_type == ElementType::Component,
+                            model: false,
                         },
                         tag: el.tag.clone(),
                     };
@@ -714,6 +1153,7 @@ fn transform_directive<'a>(
                     effect_ops.push(OperationNode::SetProp(set_prop));
                     block.effect.push(IREffect {
                         operations: effect_ops,
+                        memo: None,
                     });
                 }
             }
@@ -722,8 +1162,18 @@ fn transform_directive<'a>(
             // v-on - SetEvent
             if let Some(ref arg) = dir.arg {
                 if let ExpressionNode::Simple(key_exp) = arg {
+                    // The core transform adds `@update:modelValue` next to v-model on
+                    // native elements; Vapor wires the update through the model helper
+                    if dir.model_expansion {
+                        return;
+                    }
+                    let modifiers = parse_modifiers(dir);
+                    let event_name = match resolve_click_event(&key_exp.content, &modifiers) {
+                        Some(name) if key_exp.is_static => String::from(name),
+                        _ => key_exp.content.clone(),
+                    };
                     let key_node = SimpleExpressionNode::new(
-                        key_exp.content.clone(),
+                        event_name,
                         key_exp.is_static,
                         key_exp.loc.clone(),
                     );
@@ -748,7 +1198,7 @@ fn transform_directive<'a>(
                         element: element_id,
                         key,
                         value,
-                        modifiers: Default::default(),
+                        modifiers,
                         delegate: true,
                         effect: false,
                     };
@@ -816,6 +1266,23 @@ fn transform_directive<'a>(
                 }
             }
         }
+        "model" => {
+            // v-model - applyXxxModel with getter and setter
+            block
+                .operation
+                .extend(transform_v_model(ctx.allocator, dir, el, element_id));
+        }
+        "show" => {
+            // v-show - SetDisplay re-run whenever the condition changes
+            if let Some(op) = transform_v_show(ctx.allocator, dir, element_id) {
+                let mut effect_ops = Vec::new_in(ctx.allocator);
+                effect_ops.push(op);
+                block.effect.push(IREffect {
+                    operations: effect_ops,
+                    memo: None,
+                });
+            }
+        }
         "html" => {
             // v-html
             if let Some(ref exp) = dir.exp {
@@ -835,6 +1302,7 @@ fn transform_directive<'a>(
                     effect_ops.push(OperationNode::SetHtml(set_html));
                     block.effect.push(IREffect {
                         operations: effect_ops,
+                        memo: None,
                     });
                 }
             }
@@ -860,10 +1328,17 @@ fn transform_directive<'a>(
                     effect_ops.push(OperationNode::SetText(set_text));
                     block.effect.push(IREffect {
                         operations: effect_ops,
+                        memo: None,
                     });
                 }
             }
         }
+        "memo" => {
+            // v-memo - applied to the element's effects once its subtree is lowered
+        }
+        "once" => {
+            // v-once - the element's effects are hoisted once its subtree is lowered
+        }
         _ => {
             // Custom directive - create a copy of the directive
             let new_dir = DirectiveNode::new(ctx.allocator, dir.name.clone(), dir.loc.clone());
@@ -873,6 +1348,7 @@ fn transform_directive<'a>(
                 dir: Box::new_in(new_dir, ctx.allocator),
                 name: dir.name.clone(),
                 builtin: false,
+                model_type: None,
             };
 
             block.operation.push(OperationNode::Directive(dir_node));
@@ -880,13 +1356,56 @@ fn transform_directive<'a>(
     }
 }
 
+/// The `v-html` / `v-text` directive replacing an element's content
+fn content_directive<'e, 'a>(el: &'e ElementNode<'a>) -> Option<&'e DirectiveNode<'a>> {
+    el.props.iter().find_map(|prop| match prop {
+        PropNode::Directive(dir) if matches!(dir.name.as_str(), "html" | "text") => Some(&**dir),
+        _ => None,
+    })
+}
+
+/// Whether a `class`/`style` attribute is merged into a binding of the same name
+fn is_merged_static_attr(el: &ElementNode<'_>, name: &str) -> bool {
+    matches!(name, "class" | "style")
+        && el.tag_type != ElementType::Component
+        && el.props.iter().any(|prop| {
+            matches!(prop, PropNode::Directive(dir) if dir.name == "bind"
+                && matches!(&dir.arg, Some(ExpressionNode::Simple(arg)) if arg.is_static && arg.content == name))
+        })
+}
+
+/// Static value of the `class`/`style` attribute merged into a binding
+fn merged_static_attr(el: &ElementNode<'_>, key: &SimpleExpressionNode<'_>) -> Option<String> {
+    if !key.is_static || !is_merged_static_attr(el, &key.content) {
+        return None;
+    }
+    el.props.iter().find_map(|prop| match prop {
+        PropNode::Attribute(attr) if attr.name == key.content => {
+            attr.value.as_ref().map(|value| value.content.clone())
+        }
+        _ => None,
+    })
+}
+
 /// Generate element template string (recursively includes static children)
 fn generate_element_template(el: &ElementNode<'_>) -> String {
-    let mut template = format!("<{}", el.tag);
+    // `<component is="div">` renders as `<div>`
+    let tag = match resolve_component_is(el) {
+        Some(ComponentIs::Element(tag)) => tag,
+        _ => el.tag.clone(),
+    };
+    let mut template = format!("<{}", tag);
 
     // Add static attributes
     for prop in el.props.iter() {
         if let PropNode::Attribute(attr) = prop {
+            // Template refs are wired at runtime, not rendered
+            if attr.name == "ref"
+                || (is_dynamic_component_tag(&el.tag) && attr.name == "is")
+                || is_merged_static_attr(el, &attr.name)
+            {
+                continue;
+            }
             if let Some(ref value) = attr.value {
                 template.push_str(&format!(" {}=\"{}\"", attr.name, value.content));
             } else {
@@ -897,6 +1416,9 @@ fn generate_element_template(el: &ElementNode<'_>) -> String {
 
     if el.is_self_closing {
         template.push_str(" />");
+    } else if content_directive(el).is_some() {
+        // Content is set at runtime by v-html / v-text
+        template.push_str(&format!("></{}>", tag));
     } else {
         template.push('>');
 
@@ -927,7 +1449,7 @@ fn generate_element_template(el: &ElementNode<'_>) -> String {
             }
         }
 
-        template.push_str(&format!("</{}>", el.tag));
+        template.push_str(&format!("</{}>", tag));
     }
 
     template.into()
@@ -951,10 +1473,17 @@ fn escape_html_text(s: &str) -> std::string::String {
 
 /// Check if an element is static (no dynamic directives)
 fn is_static_element(el: &ElementNode<'_>) -> bool {
-    // Check if any prop is a directive (dynamic)
+    // `<component is="MyComp">` is created at runtime
+    if matches!(resolve_component_is(el), Some(ComponentIs::Component(_))) {
+        return false;
+    }
+
+    // Check if any prop is a directive or template ref (dynamic)
     for prop in el.props.iter() {
-        if matches!(prop, PropNode::Directive(_)) {
-            return false;
+        match prop {
+            PropNode::Directive(_) => return false,
+            PropNode::Attribute(attr) if attr.name == "ref" => return false,
+            PropNode::Attribute(_) => {}
         }
     }
 
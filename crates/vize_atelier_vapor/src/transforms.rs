@@ -3,8 +3,12 @@
 //! Individual transform plugins for Vapor IR generation.
 
 pub mod element;
+pub mod transform_dynamic_component;
 pub mod transform_slot;
+pub mod transform_suspense;
+pub mod transform_teleport;
 pub mod transform_text;
+pub mod transform_transition;
 pub mod v_bind;
 pub mod v_for;
 pub mod v_if;
@@ -13,8 +17,12 @@ pub mod v_on;
 pub mod v_show;
 
 pub use element::*;
+pub use transform_dynamic_component::*;
 pub use transform_slot::*;
+pub use transform_suspense::*;
+pub use transform_teleport::*;
 pub use transform_text::*;
+pub use transform_transition::*;
 pub use v_bind::*;
 pub use v_for::*;
 pub use v_if::*;
@@ -0,0 +1,106 @@
+//! Dynamic component transform for Vapor mode.
+//!
+//! Resolves the `is` prop of the `<component>` built-in:
+//! - `:is="which"` creates a dynamic component, swapped when `which` changes
+//! - `is="div"` (a native tag) renders that element
+//! - `is="MyComp"` / `is="vue:my-comp"` resolves like a regular component tag
+
+use vize_carton::{is_native_tag, String};
+
+use vize_atelier_core::{ElementNode, ExpressionNode, PropNode, SimpleExpressionNode};
+
+/// Resolved target of `<component is>`
+#[derive(Debug)]
+pub enum ComponentIs<'a> {
+    /// Bound `:is`, resolved at runtime
+    Dynamic(SimpleExpressionNode<'a>),
+    /// Static native tag
+    Element(String),
+    /// Static component name
+    Component(String),
+}
+
+/// Check if a tag is the `component` built-in
+pub fn is_dynamic_component_tag(tag: &str) -> bool {
+    tag == "component"
+}
+
+/// Check if a prop is the `is` / `:is` prop of `<component>`
+pub fn is_component_is_prop(prop: &PropNode<'_>) -> bool {
+    match prop {
+        PropNode::Attribute(attr) => attr.name == "is",
+        PropNode::Directive(dir) => {
+            dir.name == "bind"
+                && matches!(&dir.arg, Some(ExpressionNode::Simple(arg)) if arg.is_static && arg.content == "is")
+        }
+    }
+}
+
+/// Resolve the `is` prop of a `<component>` element
+pub fn resolve_component_is<'a>(el: &ElementNode<'a>) -> Option<ComponentIs<'a>> {
+    if !is_dynamic_component_tag(&el.tag) {
+        return None;
+    }
+
+    el.props
+        .iter()
+        .filter(|prop| is_component_is_prop(prop))
+        .find_map(|prop| match prop {
+            PropNode::Attribute(attr) => {
+                let value = attr.value.as_ref()?.content.as_str();
+                // `vue:` forces component resolution of a native tag name
+                match value.strip_prefix("vue:") {
+                    Some(name) => Some(ComponentIs::Component(String::from(name))),
+                    None if is_native_tag(value) => Some(ComponentIs::Element(String::from(value))),
+                    None => Some(ComponentIs::Component(String::from(value))),
+                }
+            }
+            PropNode::Directive(dir) => {
+                let exp = match dir.exp.as_ref()? {
+                    ExpressionNode::Simple(exp) => SimpleExpressionNode::new(
+                        exp.content.clone(),
+                        exp.is_static,
+                        exp.loc.clone(),
+                    ),
+                    ExpressionNode::Compound(exp) => {
+                        SimpleExpressionNode::new(exp.loc.source.clone(), false, exp.loc.clone())
+                    }
+                };
+                Some(ComponentIs::Dynamic(exp))
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vize_atelier_core::parser::parse;
+    use vize_atelier_core::TemplateChildNode;
+    use vize_carton::Bump;
+
+    fn resolve(source: &str) -> Option<std::string::String> {
+        let allocator = Bump::new();
+        let (root, _) = parse(&allocator, source);
+        let TemplateChildNode::Element(el) = &root.children[0] else {
+            panic!("expected an element");
+        };
+        resolve_component_is(el).map(|is| format!("{:?}", is))
+    }
+
+    #[test]
+    fn test_resolve_component_is() {
+        assert!(resolve(r#"<component is="div" />"#)
+            .unwrap()
+            .starts_with("Element(\"div\""));
+        assert!(resolve(r#"<component is="MyComp" />"#)
+            .unwrap()
+            .starts_with("Component(\"MyComp\""));
+        assert!(resolve(r#"<component is="vue:button" />"#)
+            .unwrap()
+            .starts_with("Component(\"button\""));
+        assert!(resolve(r#"<component :is="which" />"#)
+            .unwrap()
+            .starts_with("Dynamic("));
+        assert!(resolve(r#"<div is="span" />"#).is_none());
+    }
+}
@@ -106,6 +106,7 @@ fn get_slot_outlet_props<'a>(
                             key,
                             values,
                             is_component: false,
+                            model: false,
                         });
                     }
                 }
@@ -117,13 +118,31 @@ fn get_slot_outlet_props<'a>(
 }
 
 /// Collect slots from component children
+///
+/// `transform_children` lowers a slot body into a block. It is also handed the
+/// component's own children for the implicit default slot, and is expected to
+/// skip `<template v-slot>` elements there.
 pub fn collect_component_slots<'a>(
     allocator: &'a Bump,
     el: &ElementNode<'a>,
-    transform_children: impl Fn(&'a Bump, &[TemplateChildNode<'a>]) -> BlockIRNode<'a>,
+    mut transform_children: impl FnMut(&[TemplateChildNode<'a>]) -> BlockIRNode<'a>,
 ) -> Vec<'a, IRSlot<'a>> {
     let mut slots = Vec::new_in(allocator);
 
+    // `v-slot` on the component itself scopes the whole default slot
+    for prop in el.props.iter() {
+        if let PropNode::Directive(dir) = prop {
+            if dir.name == "slot" {
+                slots.push(IRSlot {
+                    name: get_slot_name(allocator, dir),
+                    fn_exp: get_slot_params(allocator, dir),
+                    block: transform_children(&el.children),
+                });
+                return slots;
+            }
+        }
+    }
+
     for child in el.children.iter() {
         if let TemplateChildNode::Element(child_el) = child {
             if child_el.tag == "template" {
@@ -133,7 +152,7 @@ pub fn collect_component_slots<'a>(
                         if dir.name == "slot" {
                             let name = get_slot_name(allocator, dir);
                             let fn_exp = get_slot_params(allocator, dir);
-                            let block = transform_children(allocator, &child_el.children);
+                            let block = transform_children(&child_el.children);
 
                             slots.push(IRSlot {
                                 name,
@@ -148,17 +167,16 @@ pub fn collect_component_slots<'a>(
     }
 
     // Check for implicit default slot
-    let has_non_slot_children = el.children.iter().any(|child| {
-        if let TemplateChildNode::Element(child_el) = child {
-            !(child_el.tag == "template" && has_v_slot(child_el))
-        } else {
-            true
-        }
+    let has_non_slot_children = el.children.iter().any(|child| match child {
+        TemplateChildNode::Element(child_el) => !is_slot_template(child_el),
+        TemplateChildNode::Text(text) => !text.content.trim().is_empty(),
+        TemplateChildNode::Comment(_) => false,
+        _ => true,
     });
 
     if has_non_slot_children && !slots.iter().any(|s| s.name.content == "default") {
         let default_name = SimpleExpressionNode::new("default", true, SourceLocation::STUB);
-        let default_block = transform_children(allocator, &el.children);
+        let default_block = transform_children(&el.children);
 
         slots.push(IRSlot {
             name: Box::new_in(default_name, allocator),
@@ -170,6 +188,11 @@ pub fn collect_component_slots<'a>(
     slots
 }
 
+/// Check if element is a `<template v-slot>` slot declaration
+pub fn is_slot_template(el: &ElementNode<'_>) -> bool {
+    el.tag == "template" && has_v_slot(el)
+}
+
 /// Check if element has v-slot directive
 fn has_v_slot(el: &ElementNode<'_>) -> bool {
     el.props
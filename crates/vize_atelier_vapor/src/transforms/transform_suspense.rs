@@ -0,0 +1,42 @@
+//! Suspense transform for Vapor mode.
+//!
+//! Lowers the `<Suspense>` built-in into a suspense operation. Its `#default`
+//! and `#fallback` slots each compile to their own render function, and async
+//! components inside are created like any other component.
+
+use vize_carton::{Box, Bump, Vec};
+
+use crate::ir::{IRProp, IRSlot, OperationNode, SuspenseIRNode};
+
+/// Check if a tag is the `Suspense` built-in
+pub fn is_suspense_tag(tag: &str) -> bool {
+    matches!(tag, "Suspense" | "suspense")
+}
+
+/// Transform `<Suspense>` to IR
+pub fn transform_suspense<'a>(
+    allocator: &'a Bump,
+    element_id: usize,
+    props: Vec<'a, IRProp<'a>>,
+    slots: Vec<'a, IRSlot<'a>>,
+) -> OperationNode<'a> {
+    let suspense = SuspenseIRNode {
+        id: element_id,
+        props,
+        slots,
+    };
+
+    OperationNode::Suspense(Box::new_in(suspense, allocator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_suspense_tag() {
+        assert!(is_suspense_tag("Suspense"));
+        assert!(is_suspense_tag("suspense"));
+        assert!(!is_suspense_tag("MySuspense"));
+    }
+}
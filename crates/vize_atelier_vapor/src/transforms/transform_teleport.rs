@@ -0,0 +1,86 @@
+//! Teleport transform for Vapor mode.
+//!
+//! Lowers the `<Teleport>` built-in into a teleport operation rendering its
+//! children into the `to` target.
+
+use vize_carton::{Box, Bump};
+
+use crate::ir::{BlockIRNode, OperationNode, TeleportIRNode};
+use vize_atelier_core::{ElementNode, ExpressionNode, PropNode, SimpleExpressionNode};
+
+/// Check if a tag is the `Teleport` built-in
+pub fn is_teleport_tag(tag: &str) -> bool {
+    matches!(tag, "Teleport" | "teleport")
+}
+
+/// Transform `<Teleport>` to IR
+pub fn transform_teleport<'a>(
+    allocator: &'a Bump,
+    el: &ElementNode<'a>,
+    element_id: usize,
+    block: BlockIRNode<'a>,
+) -> OperationNode<'a> {
+    let teleport = TeleportIRNode {
+        id: element_id,
+        to: get_teleport_prop(allocator, el, "to"),
+        disabled: get_teleport_prop(allocator, el, "disabled"),
+        block,
+    };
+
+    OperationNode::Teleport(Box::new_in(teleport, allocator))
+}
+
+/// Get a static (`to="#modal"`) or bound (`:to="target"`) Teleport prop
+fn get_teleport_prop<'a>(
+    allocator: &'a Bump,
+    el: &ElementNode<'a>,
+    name: &str,
+) -> Option<Box<'a, SimpleExpressionNode<'a>>> {
+    for prop in el.props.iter() {
+        match prop {
+            PropNode::Attribute(attr) if attr.name == name => {
+                let value = attr
+                    .value
+                    .as_ref()
+                    .map(|v| v.content.clone())
+                    .unwrap_or_default();
+                let node = SimpleExpressionNode::new(value, true, attr.loc.clone());
+                return Some(Box::new_in(node, allocator));
+            }
+            PropNode::Directive(dir) if dir.name == "bind" => {
+                let Some(ExpressionNode::Simple(arg)) = dir.arg.as_ref() else {
+                    continue;
+                };
+                if arg.content != name {
+                    continue;
+                }
+                let node = match dir.exp.as_ref()? {
+                    ExpressionNode::Simple(exp) => SimpleExpressionNode::new(
+                        exp.content.clone(),
+                        exp.is_static,
+                        exp.loc.clone(),
+                    ),
+                    ExpressionNode::Compound(exp) => {
+                        SimpleExpressionNode::new(exp.loc.source.clone(), false, exp.loc.clone())
+                    }
+                };
+                return Some(Box::new_in(node, allocator));
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_teleport_tag() {
+        assert!(is_teleport_tag("Teleport"));
+        assert!(is_teleport_tag("teleport"));
+        assert!(!is_teleport_tag("MyTeleport"));
+    }
+}
@@ -0,0 +1,52 @@
+//! Transition transform for Vapor mode.
+//!
+//! Lowers the `<Transition>` and `<TransitionGroup>` built-ins into a
+//! transition operation wrapping their child block, forwarding props such as
+//! `name`, `mode` and `appear`.
+
+use vize_carton::{Box, Bump, Vec};
+
+use crate::ir::{BlockIRNode, IRProp, OperationNode, TransitionIRNode};
+
+/// Check if a tag is the `Transition` built-in
+pub fn is_transition_tag(tag: &str) -> bool {
+    matches!(tag, "Transition" | "transition")
+}
+
+/// Check if a tag is the `TransitionGroup` built-in
+pub fn is_transition_group_tag(tag: &str) -> bool {
+    matches!(tag, "TransitionGroup" | "transition-group")
+}
+
+/// Transform `<Transition>` / `<TransitionGroup>` to IR
+pub fn transform_transition<'a>(
+    allocator: &'a Bump,
+    element_id: usize,
+    group: bool,
+    props: Vec<'a, IRProp<'a>>,
+    block: BlockIRNode<'a>,
+) -> OperationNode<'a> {
+    let transition = TransitionIRNode {
+        id: element_id,
+        group,
+        props,
+        block,
+    };
+
+    OperationNode::Transition(Box::new_in(transition, allocator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_transition_tag() {
+        assert!(is_transition_tag("Transition"));
+        assert!(is_transition_tag("transition"));
+        assert!(!is_transition_tag("TransitionGroup"));
+        assert!(is_transition_group_tag("TransitionGroup"));
+        assert!(is_transition_group_tag("transition-group"));
+        assert!(!is_transition_group_tag("MyTransitionGroup"));
+    }
+}
@@ -25,6 +25,7 @@ pub fn transform_v_bind<'a>(
             key,
             values,
             is_component: el.tag_type == ElementType::Component,
+            model: false,
         },
         tag: el.tag.clone(),
     };
@@ -111,11 +112,31 @@ fn extract_prop_values<'a>(
                 values.push(Box::new_in(node, allocator));
             }
         }
+    } else if let Some(node) = expand_same_name_bind(dir) {
+        values.push(Box::new_in(node, allocator));
     }
 
     values
 }
 
+/// Expand the same-name shorthand, where `:count` means `:count="count"`
+///
+/// Returns the camelized argument as the bound expression for a v-bind with a
+/// static argument and no value, and `None` for anything else.
+pub fn expand_same_name_bind<'a>(dir: &DirectiveNode<'a>) -> Option<SimpleExpressionNode<'a>> {
+    if dir.name.as_str() != "bind" || dir.exp.is_some() {
+        return None;
+    }
+    match &dir.arg {
+        Some(ExpressionNode::Simple(arg)) if arg.is_static => Some(SimpleExpressionNode::new(
+            camelize(&arg.content),
+            false,
+            arg.loc.clone(),
+        )),
+        _ => None,
+    }
+}
+
 /// Check if directive has a specific modifier
 fn has_modifier(dir: &DirectiveNode<'_>, name: &str) -> bool {
     dir.modifiers.iter().any(|m| m.content == name)
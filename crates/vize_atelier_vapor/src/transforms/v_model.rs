@@ -4,29 +4,48 @@
 
 use vize_carton::{Box, Bump, String};
 
-use crate::ir::{DirectiveIRNode, OperationNode};
-use vize_atelier_core::{DirectiveNode, ElementNode, ExpressionNode};
+use crate::ir::{DirectiveIRNode, OperationNode, VModelType};
+use vize_atelier_core::{
+    DirectiveNode, ElementNode, ExpressionNode, PropNode, SimpleExpressionNode,
+};
 
 /// Transform v-model directive to IR
 pub fn transform_v_model<'a>(
     allocator: &'a Bump,
     dir: &DirectiveNode<'a>,
-    _el: &ElementNode<'a>,
+    el: &ElementNode<'a>,
     element_id: usize,
 ) -> Vec<OperationNode<'a>> {
     let mut operations = Vec::new();
 
-    // v-model is syntactic sugar for :value + @input
-    // For Vapor mode, we use the v-model directive directly
-
-    // Create a copy of the directive for IR
-    let new_dir = DirectiveNode::new(allocator, "model", dir.loc.clone());
+    // Copy the binding expression and modifiers so the generator can emit
+    // both the getter and the setter for the `applyXxxModel` helper
+    let mut new_dir = DirectiveNode::new(allocator, "model", dir.loc.clone());
+    if let Some(value) = get_model_value(dir) {
+        let loc = dir
+            .exp
+            .as_ref()
+            .map(|e| e.loc().clone())
+            .unwrap_or_default();
+        new_dir.exp = Some(ExpressionNode::Simple(Box::new_in(
+            SimpleExpressionNode::new(value, false, loc),
+            allocator,
+        )));
+    }
+    for modifier in dir.modifiers.iter() {
+        new_dir.modifiers.push(SimpleExpressionNode::new(
+            modifier.content.clone(),
+            true,
+            modifier.loc.clone(),
+        ));
+    }
 
     let dir_ir = DirectiveIRNode {
         element: element_id,
         dir: Box::new_in(new_dir, allocator),
         name: String::new("model"),
         builtin: true,
+        model_type: Some(get_model_type(el)),
     };
 
     operations.push(OperationNode::Directive(dir_ir));
@@ -34,6 +53,40 @@ pub fn transform_v_model<'a>(
     operations
 }
 
+/// Detect the v-model element kind from the tag and its `type` attribute
+pub fn get_model_type(el: &ElementNode<'_>) -> VModelType {
+    match el.tag.as_str() {
+        "select" => VModelType::Select,
+        "input" => {
+            for prop in el.props.iter() {
+                match prop {
+                    PropNode::Attribute(attr) if attr.name == "type" => {
+                        return match attr.value.as_ref().map(|v| v.content.as_str()) {
+                            Some("checkbox") => VModelType::Checkbox,
+                            Some("radio") => VModelType::Radio,
+                            _ => VModelType::Text,
+                        };
+                    }
+                    PropNode::Directive(dir) if dir.name == "bind" => {
+                        // `:type="t"` or `v-bind="obj"` can change the input kind at runtime
+                        let is_type = match &dir.arg {
+                            Some(ExpressionNode::Simple(arg)) => arg.content == "type",
+                            Some(ExpressionNode::Compound(_)) => false,
+                            None => true,
+                        };
+                        if is_type {
+                            return VModelType::Dynamic;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            VModelType::Text
+        }
+        _ => VModelType::Text,
+    }
+}
+
 /// Get v-model binding expression
 pub fn get_model_value(dir: &DirectiveNode<'_>) -> Option<String> {
     dir.exp.as_ref().map(|exp| match exp {
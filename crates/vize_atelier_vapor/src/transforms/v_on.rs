@@ -71,17 +71,29 @@ fn extract_handler<'a>(
 }
 
 /// Parse event modifiers
-fn parse_modifiers(dir: &DirectiveNode<'_>) -> EventModifiers {
+///
+/// `.left` / `.right` are key modifiers on keyboard events and mouse button
+/// guards otherwise.
+pub fn parse_modifiers(dir: &DirectiveNode<'_>) -> EventModifiers {
     let mut keys = Vec::new();
     let mut non_keys = Vec::new();
     let mut options = EventOptions::default();
 
+    let is_keyboard_event = matches!(
+        dir.arg.as_ref(),
+        Some(ExpressionNode::Simple(arg)) if arg.is_static && arg.content.starts_with("key")
+    );
+
     for modifier in dir.modifiers.iter() {
         match modifier.content.as_str() {
             "capture" => options.capture = true,
             "once" => options.once = true,
             "passive" => options.passive = true,
-            "stop" | "prevent" | "self" | "exact" | "left" | "right" | "middle" => {
+            "stop" | "prevent" | "self" | "exact" | "ctrl" | "shift" | "alt" | "meta"
+            | "middle" => {
+                non_keys.push(modifier.content.clone());
+            }
+            "left" | "right" if !is_keyboard_event => {
                 non_keys.push(modifier.content.clone());
             }
             _ => {
@@ -98,6 +110,23 @@ fn parse_modifiers(dir: &DirectiveNode<'_>) -> EventModifiers {
     }
 }
 
+/// Get the DOM event a mouse button modifier maps `click` to
+///
+/// Browsers never fire `click` for the right or middle button, so
+/// `@click.right` listens to `contextmenu` and `@click.middle` to `mouseup`.
+pub fn resolve_click_event(event_name: &str, modifiers: &EventModifiers) -> Option<&'static str> {
+    if event_name != "click" {
+        return None;
+    }
+    if modifiers.non_keys.iter().any(|m| m.as_str() == "right") {
+        Some("contextmenu")
+    } else if modifiers.non_keys.iter().any(|m| m.as_str() == "middle") {
+        Some("mouseup")
+    } else {
+        None
+    }
+}
+
 /// Check if event should use delegation
 fn should_delegate(_dir: &DirectiveNode<'_>) -> bool {
     // By default, use delegation for performance
@@ -4,26 +4,31 @@
 
 use vize_carton::{Box, Bump, String};
 
-use crate::ir::{DirectiveIRNode, OperationNode};
-use vize_atelier_core::{DirectiveNode, ExpressionNode};
+use crate::ir::{OperationNode, SetDisplayIRNode};
+use vize_atelier_core::{DirectiveNode, ExpressionNode, SimpleExpressionNode};
 
 /// Transform v-show directive to IR
+///
+/// Returns a `SetDisplay` operation to be wrapped in a render effect, so the
+/// display toggles whenever the condition changes.
 pub fn transform_v_show<'a>(
     allocator: &'a Bump,
     dir: &DirectiveNode<'a>,
     element_id: usize,
-) -> OperationNode<'a> {
-    // v-show is implemented as a directive that toggles display style
-    let new_dir = DirectiveNode::new(allocator, "show", dir.loc.clone());
-
-    let dir_ir = DirectiveIRNode {
-        element: element_id,
-        dir: Box::new_in(new_dir, allocator),
-        name: String::new("show"),
-        builtin: true,
+) -> Option<OperationNode<'a>> {
+    let value = match dir.exp.as_ref()? {
+        ExpressionNode::Simple(exp) => {
+            SimpleExpressionNode::new(exp.content.clone(), exp.is_static, exp.loc.clone())
+        }
+        ExpressionNode::Compound(exp) => {
+            SimpleExpressionNode::new(exp.loc.source.clone(), false, exp.loc.clone())
+        }
     };
 
-    OperationNode::Directive(dir_ir)
+    Some(OperationNode::SetDisplay(SetDisplayIRNode {
+        element: element_id,
+        value: Box::new_in(value, allocator),
+    }))
 }
 
 /// Get v-show condition expression
@@ -42,11 +47,7 @@ pub fn needs_transition(_el: &vize_atelier_core::ElementNode<'_>) -> bool {
 
 /// Generate v-show effect code
 pub fn generate_v_show_effect(element_var: &str, condition: &str) -> String {
-    format!(
-        "{}.style.display = {} ? '' : 'none'",
-        element_var, condition
-    )
-    .into()
+    format!("_setDisplay({}, {})", element_var, condition).into()
 }
 
 #[cfg(test)]
@@ -56,6 +57,6 @@ mod tests {
     #[test]
     fn test_generate_v_show_effect() {
         let result = generate_v_show_effect("_n1", "isVisible");
-        assert_eq!(result, "_n1.style.display = isVisible ? '' : 'none'");
+        assert_eq!(result, "_setDisplay(_n1, isVisible)");
     }
 }
@@ -94,7 +94,8 @@ pub use vize_carton::i18n::Locale;
 pub use tsgo_bridge::{
     LspCompletionItem, LspCompletionList, LspCompletionResponse, LspDefinitionResponse,
     LspDiagnostic, LspDocumentation, LspHover, LspHoverContents, LspLocation, LspLocationLink,
-    LspMarkedString, LspMarkupContent, LspPosition, LspRange, TsgoBridge, TsgoBridgeConfig,
+    LspMarkedString, LspMarkupContent, LspParameterInformation, LspParameterLabel, LspPosition,
+    LspRange, LspSignatureHelp, LspSignatureInformation, TsgoBridge, TsgoBridgeConfig,
     TsgoBridgeError, VIRTUAL_URI_SCHEME,
 };
 
@@ -1,6 +1,8 @@
 //! Type checking functions for Vue SFC diagnostics.
 
 use super::{SfcTypeCheckResult, SfcTypeDiagnostic, SfcTypeSeverity};
+use vize_carton::FxHashMap;
+use vize_croquis::analyzer::extract_slot_prop_keys;
 use vize_croquis::reactivity::ReactivityLossKind;
 use vize_croquis::setup_context::ViolationSeverity;
 
@@ -160,6 +162,1092 @@ pub fn check_emits_typing(
     }
 }
 
+/// The object literal declaring the props, when the props type is exactly one.
+///
+/// Accepts an inline `{ ... }` or a local `interface Props { ... }` /
+/// `type Props = { ... }`; anything with `extends` or `&` returns `None`.
+fn plain_props_literal<'a>(script_content: Option<&'a str>, type_args: &'a str) -> Option<&'a str> {
+    if type_args.starts_with('{') {
+        let end = crate::virtual_ts::find_matching_brace(type_args, 0);
+        return (end + 1 == type_args.len()).then_some(type_args);
+    }
+    if !type_args
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    {
+        return None;
+    }
+
+    let script = script_content?;
+    let body = crate::virtual_ts::find_type_body(script, type_args)?;
+    let brace = body.find('{')?;
+    let header = body[..brace].trim_end();
+    let plain_header = header.ends_with(type_args)
+        || header
+            .strip_suffix('=')
+            .is_some_and(|h| h.trim_end().ends_with(type_args));
+    if !plain_header {
+        return None;
+    }
+    // `type Props = { ... } & Base`
+    let after = &script[script.find(body)? + body.len()..];
+    if after.trim_start().starts_with(['&', '|']) {
+        return None;
+    }
+    Some(&body[brace..])
+}
+
+/// Check withDefaults() defaults against the declared prop types.
+pub fn check_with_defaults(
+    summary: &vize_croquis::Croquis,
+    script_content: Option<&str>,
+    script_offset: u32,
+    result: &mut SfcTypeCheckResult,
+    strict: bool,
+) {
+    use vize_croquis::macros::MacroKind;
+    use vize_croquis::types::TypeResolver;
+
+    let defaults = summary.macros.prop_defaults();
+    if defaults.is_empty() {
+        return;
+    }
+
+    let Some(type_args) = summary
+        .macros
+        .all_calls()
+        .iter()
+        .find(|c| matches!(c.kind, MacroKind::DefineProps))
+        .and_then(|c| c.type_args.as_ref())
+    else {
+        return;
+    };
+    let type_args = type_args.trim();
+    let type_args = type_args
+        .strip_prefix('<')
+        .and_then(|s| s.strip_suffix('>'))
+        .unwrap_or(type_args)
+        .trim();
+
+    // Inline types resolve directly; local interfaces are looked up in the script
+    let Some(literal) = plain_props_literal(script_content, type_args) else {
+        // Composed (extends, intersection, imported, mapped, ...) - props may be
+        // declared where we can't see them
+        return;
+    };
+    let properties = TypeResolver::new().extract_properties(literal);
+    if properties.is_empty() {
+        return;
+    }
+
+    for default in defaults {
+        let (start, end) = (default.start + script_offset, default.end + script_offset);
+
+        let Some(property) = properties.iter().find(|p| p.name == default.name) else {
+            result.add_diagnostic(SfcTypeDiagnostic {
+                severity: if strict {
+                    SfcTypeSeverity::Error
+                } else {
+                    SfcTypeSeverity::Warning
+                },
+                message: format!(
+                    "Default provided for '{}', which is not a declared prop",
+                    default.name
+                ),
+                start,
+                end,
+                code: Some("unknown-default-key".to_string()),
+                help: Some("Remove the default or add the prop to the Props type".to_string()),
+                related: Vec::new(),
+            });
+            continue;
+        };
+
+        let Some(ref prop_type) = property.prop_type else {
+            continue;
+        };
+        if !is_default_compatible(&default.value, prop_type, property.optional) {
+            result.add_diagnostic(SfcTypeDiagnostic {
+                severity: SfcTypeSeverity::Error,
+                message: format!(
+                    "Default value '{}' is not assignable to prop '{}' of type '{}'",
+                    default.value, default.name, prop_type
+                ),
+                start,
+                end,
+                code: Some("default-type-mismatch".to_string()),
+                help: None,
+                related: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Whether a default value expression fits a declared prop type.
+///
+/// Only literal defaults are judged; anything else is assumed to fit.
+fn is_default_compatible(value: &str, prop_type: &str, optional: bool) -> bool {
+    // Function props take the function itself; other types call it as a factory
+    let is_function_type = split_type_union(prop_type)
+        .iter()
+        .any(|m| m.contains("=>") || *m == "Function");
+    if is_function_type {
+        return true;
+    }
+    let value = match value.trim().strip_prefix("()") {
+        Some(rest) => match rest.trim_start().strip_prefix("=>") {
+            Some(body) => body.trim(),
+            None => return true,
+        },
+        None => value.trim(),
+    };
+    let value = value
+        .strip_prefix('(')
+        .and_then(|v| v.strip_suffix(')'))
+        .map(str::trim)
+        .unwrap_or(value);
+
+    let Some(kind) = literal_kind(value) else {
+        return true;
+    };
+
+    let mut kinds = Vec::new();
+    let mut string_literals = Vec::new();
+    for member in split_type_union(prop_type) {
+        let member_kind = match member {
+            "any" | "unknown" => return true,
+            "string" => "string",
+            "number" => "number",
+            "boolean" | "true" | "false" => "boolean",
+            "null" => "null",
+            "undefined" => "undefined",
+            "object" => "object",
+            "Function" => "function",
+            m if m.ends_with("[]")
+                || m.starts_with("Array<")
+                || m.starts_with("ReadonlyArray<")
+                || m.starts_with('[') =>
+            {
+                "array"
+            }
+            m if m.starts_with('{') || m.starts_with("Record<") => "object",
+            m if m.contains("=>") => "function",
+            m if m.len() >= 2 && (m.starts_with('\'') || m.starts_with('"')) => {
+                string_literals.push(&m[1..m.len() - 1]);
+                continue;
+            }
+            m if m.parse::<f64>().is_ok() => "number",
+            // Type references could be anything
+            _ => return true,
+        };
+        kinds.push(member_kind);
+    }
+
+    // The default replaces `undefined` for optional props
+    if optional {
+        kinds.push("undefined");
+    }
+
+    if kinds.contains(&kind) {
+        return true;
+    }
+    kind == "string" && string_literals.contains(&&value[1..value.len() - 1])
+}
+
+/// Classify a literal expression, returning None for non-literals.
+fn literal_kind(value: &str) -> Option<&'static str> {
+    match value {
+        "true" | "false" => Some("boolean"),
+        "null" => Some("null"),
+        "undefined" => Some("undefined"),
+        v if v.len() >= 2
+            && ((v.starts_with('\'') && v.ends_with('\''))
+                || (v.starts_with('"') && v.ends_with('"'))
+                || (v.starts_with('`') && v.ends_with('`') && !v.contains("${"))) =>
+        {
+            Some("string")
+        }
+        v if v.parse::<f64>().is_ok() => Some("number"),
+        v if v.starts_with('[') => Some("array"),
+        v if v.starts_with('{') => Some("object"),
+        _ => None,
+    }
+}
+
+/// Split a union type at top level.
+fn split_type_union(ty: &str) -> Vec<&str> {
+    let mut members = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    let mut prev = ' ';
+    for (i, c) in ty.char_indices() {
+        match c {
+            '<' | '(' | '[' | '{' => depth += 1,
+            // `=>` in function types is not a closing bracket
+            '>' if prev == '=' => {}
+            '>' | ')' | ']' | '}' => depth -= 1,
+            '|' if depth == 0 => {
+                members.push(ty[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+        prev = c;
+    }
+    members.push(ty[start..].trim());
+    members.retain(|m| !m.is_empty());
+    members
+}
+
+/// Check defineModel() declarations.
+///
+/// Each `defineModel()` generates a prop and an `update:*` emit, so explicit
+/// declarations of either are reported, as is a generic type that contradicts
+/// the runtime `type` option.
+pub fn check_define_model(
+    summary: &vize_croquis::Croquis,
+    script_content: Option<&str>,
+    script_offset: u32,
+    result: &mut SfcTypeCheckResult,
+    strict: bool,
+) {
+    use vize_croquis::macros::MacroKind;
+
+    let severity = if strict {
+        SfcTypeSeverity::Error
+    } else {
+        SfcTypeSeverity::Warning
+    };
+
+    let calls = summary
+        .macros
+        .all_calls()
+        .iter()
+        .filter(|c| matches!(c.kind, MacroKind::DefineModel));
+
+    for call in calls {
+        // Pair each call with its model by name rather than by position
+        let name = define_model_name(call, script_content.unwrap_or_default());
+        let Some(model) = summary.macros.models().iter().find(|m| m.name == name) else {
+            continue;
+        };
+        let (start, end) = (call.start + script_offset, call.end + script_offset);
+
+        if model.model_type.is_none() {
+            result.add_diagnostic(SfcTypeDiagnostic {
+                severity,
+                message: format!("Model '{}' should have a type definition", model.name),
+                start,
+                end,
+                code: Some("untyped-model".to_string()),
+                help: Some("Use defineModel<Type>() or pass a runtime `type` option".to_string()),
+                related: Vec::new(),
+            });
+        }
+
+        if let (Some(_), Some(ts_type), Some(runtime_type)) = (
+            call.type_args.as_ref(),
+            model.model_type.as_ref(),
+            model.runtime_type.as_ref(),
+        ) {
+            if !is_runtime_type_compatible(ts_type, runtime_type) {
+                result.add_diagnostic(SfcTypeDiagnostic {
+                    severity: SfcTypeSeverity::Error,
+                    message: format!(
+                        "Model '{}' is typed as '{}' but its runtime type is '{}'",
+                        model.name, ts_type, runtime_type
+                    ),
+                    start,
+                    end,
+                    code: Some("define-model-type".to_string()),
+                    help: Some(
+                        "Make the generic type and the `type` option agree, or drop one of them"
+                            .to_string(),
+                    ),
+                    related: Vec::new(),
+                });
+            }
+        }
+
+        let event = format!("update:{}", model.name);
+        let duplicate = if summary.macros.props().iter().any(|p| p.name == model.name) {
+            Some(format!("prop '{}'", model.name))
+        } else if summary.macros.emits().iter().any(|e| e.name == event) {
+            Some(format!("emit '{}'", event))
+        } else {
+            None
+        };
+
+        if let Some(duplicate) = duplicate {
+            result.add_diagnostic(SfcTypeDiagnostic {
+                severity,
+                message: format!(
+                    "defineModel('{}') already declares the {}",
+                    model.name, duplicate
+                ),
+                start,
+                end,
+                code: Some("define-model-conflict".to_string()),
+                help: Some(
+                    "Remove the explicit declaration from defineProps/defineEmits".to_string(),
+                ),
+                related: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Model name declared by a `defineModel()` call.
+///
+/// The name is the leading string argument; without one the model is `modelValue`.
+fn define_model_name<'s>(call: &vize_croquis::macros::MacroCall, script: &'s str) -> &'s str {
+    let text = script
+        .get(call.start as usize..call.end as usize)
+        .unwrap_or_default();
+    let args = text
+        .strip_prefix(call.name.as_str())
+        .map(|rest| {
+            let rest = rest.trim_start();
+            call.type_args
+                .as_deref()
+                .and_then(|ty| rest.strip_prefix(ty))
+                .unwrap_or(rest)
+        })
+        .and_then(|rest| rest.trim_start().strip_prefix('('))
+        .unwrap_or_default()
+        .trim_start();
+
+    let Some(quote) = args
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '\'' | '"' | '`'))
+    else {
+        return "modelValue";
+    };
+    args[1..]
+        .find(quote)
+        .map_or("modelValue", |end| &args[1..1 + end])
+}
+
+/// Whether a TypeScript type and a runtime `type` option can describe the same value.
+///
+/// Types without an obvious runtime constructor (interfaces, `any`, ...) are
+/// accepted.
+fn is_runtime_type_compatible(ts_type: &str, runtime_type: &str) -> bool {
+    let runtime: Vec<&str> = runtime_type
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    let mut expected = Vec::new();
+    for member in ts_type.split('|').map(str::trim) {
+        let constructor = match member {
+            "undefined" | "null" => continue,
+            "string" => "String",
+            "number" => "Number",
+            "boolean" | "true" | "false" => "Boolean",
+            "bigint" => "BigInt",
+            "symbol" => "Symbol",
+            "Date" => "Date",
+            t if t.ends_with("[]") || t.starts_with("Array<") => "Array",
+            t if t.contains("=>") => "Function",
+            t if t.starts_with('{') || t.starts_with("Record<") => "Object",
+            t if t.starts_with('\'') || t.starts_with('"') => "String",
+            t if t.parse::<f64>().is_ok() => "Number",
+            _ => return true,
+        };
+        expected.push(constructor);
+    }
+
+    expected.is_empty() || expected.iter().any(|c| runtime.contains(c))
+}
+
+/// A slot use found in the template: a `<slot>` outlet or a `v-slot` on the
+/// component itself.
+struct TemplateSlotUse {
+    name: String,
+    /// Prop names passed or destructured (None when not statically known)
+    props: Option<Vec<String>>,
+    start: u32,
+    end: u32,
+}
+
+/// Check slot usage against defineSlots() declarations.
+///
+/// `<slot>` outlets and `<template #name="{ ... }">` on a self-reference of the
+/// component are compared with the declared slot names and prop shapes.
+pub fn check_define_slots(
+    summary: &vize_croquis::Croquis,
+    template_ast: Option<&vize_relief::ast::RootNode<'_>>,
+    template_offset: u32,
+    component_name: &str,
+    result: &mut SfcTypeCheckResult,
+    strict: bool,
+) {
+    use vize_croquis::types::TypeResolver;
+
+    let declared = summary.macros.slots();
+    let Some(template_ast) = template_ast else {
+        return;
+    };
+    if declared.is_empty() {
+        return;
+    }
+
+    let severity = if strict {
+        SfcTypeSeverity::Error
+    } else {
+        SfcTypeSeverity::Warning
+    };
+
+    let mut uses = Vec::new();
+    collect_slot_uses(&template_ast.children, component_name, &mut uses);
+
+    let resolver = TypeResolver::new();
+    for slot_use in uses {
+        let (start, end) = (
+            slot_use.start + template_offset,
+            slot_use.end + template_offset,
+        );
+
+        let Some(slot) = declared.iter().find(|s| s.name == slot_use.name) else {
+            result.add_diagnostic(SfcTypeDiagnostic {
+                severity,
+                message: format!("Slot '{}' is not declared in defineSlots()", slot_use.name),
+                start,
+                end,
+                code: Some("unknown-slot".to_string()),
+                help: Some("Add the slot to the defineSlots() type".to_string()),
+                related: Vec::new(),
+            });
+            continue;
+        };
+
+        // Only inline object types have a known shape
+        let (Some(props), Some(props_type)) = (slot_use.props, slot.props_type.as_deref()) else {
+            continue;
+        };
+        if !props_type.trim_start().starts_with('{') {
+            continue;
+        }
+        let shape = resolver.extract_properties(props_type);
+
+        for prop in props {
+            if shape.iter().any(|p| p.name == prop) {
+                continue;
+            }
+            result.add_diagnostic(SfcTypeDiagnostic {
+                severity,
+                message: format!(
+                    "Slot '{}' has no prop '{}' (declared: {})",
+                    slot_use.name, prop, props_type
+                ),
+                start,
+                end,
+                code: Some("unknown-slot-prop".to_string()),
+                help: None,
+                related: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Collect slot outlets and self-referencing slot usages from template children.
+fn collect_slot_uses(
+    children: &[vize_relief::ast::TemplateChildNode<'_>],
+    component_name: &str,
+    uses: &mut Vec<TemplateSlotUse>,
+) {
+    use vize_croquis::naming::to_pascal_case;
+    use vize_relief::ast::{ExpressionNode, PropNode, TemplateChildNode};
+
+    for child in children {
+        let TemplateChildNode::Element(el) = child else {
+            continue;
+        };
+
+        if el.tag == "slot" {
+            let mut name = Some("default".to_string());
+            let mut props = Some(Vec::new());
+            for prop in el.props.iter() {
+                match prop {
+                    PropNode::Attribute(attr) if attr.name == "name" => {
+                        name = attr.value.as_ref().map(|v| v.content.to_string());
+                    }
+                    PropNode::Attribute(attr) => {
+                        if let Some(props) = props.as_mut() {
+                            props.push(attr.name.to_string());
+                        }
+                    }
+                    PropNode::Directive(dir) if dir.name == "bind" => match &dir.arg {
+                        Some(ExpressionNode::Simple(arg)) if arg.is_static => {
+                            if arg.content == "name" {
+                                // Dynamic slot name
+                                name = None;
+                            } else if let Some(props) = props.as_mut() {
+                                props.push(arg.content.to_string());
+                            }
+                        }
+                        // `v-bind="obj"` passes unknown props
+                        _ => props = None,
+                    },
+                    _ => {}
+                }
+            }
+            if let Some(name) = name {
+                uses.push(TemplateSlotUse {
+                    name,
+                    props,
+                    start: el.loc.start.offset,
+                    end: el.loc.end.offset,
+                });
+            }
+        } else if !component_name.is_empty()
+            && to_pascal_case(&el.tag) == to_pascal_case(component_name)
+        {
+            // `v-slot` on the component itself, or on its `<template>` children
+            let slot_hosts =
+                std::iter::once(&**el).chain(el.children.iter().filter_map(|c| match c {
+                    TemplateChildNode::Element(t) if t.tag == "template" => Some(&**t),
+                    _ => None,
+                }));
+            for host in slot_hosts {
+                for prop in host.props.iter() {
+                    let PropNode::Directive(dir) = prop else {
+                        continue;
+                    };
+                    if dir.name != "slot" {
+                        continue;
+                    }
+                    let name = match &dir.arg {
+                        None => "default".to_string(),
+                        Some(ExpressionNode::Simple(arg)) if arg.is_static => {
+                            arg.content.to_string()
+                        }
+                        _ => continue,
+                    };
+                    let props = dir.exp.as_ref().and_then(|exp| {
+                        let pattern = match exp {
+                            ExpressionNode::Simple(s) => s.content.as_str(),
+                            ExpressionNode::Compound(c) => c.loc.source.as_str(),
+                        };
+                        extract_slot_prop_keys(pattern)
+                            .map(|keys| keys.iter().map(|key| key.to_string()).collect())
+                    });
+                    uses.push(TemplateSlotUse {
+                        name,
+                        props,
+                        start: dir.loc.start.offset,
+                        end: dir.loc.end.offset,
+                    });
+                }
+            }
+        }
+
+        collect_slot_uses(&el.children, component_name, uses);
+    }
+}
+
+/// Check member access on component template refs against their defineExpose().
+///
+/// For `<Child ref="child" />`, accesses like `child.value.focus()` (or through
+/// `useTemplateRef('child')`) must name a key exposed by `Child`. Exposed keys
+/// come from `component_exposes`, or from this component's own defineExpose()
+/// for a self-reference. `$`-prefixed public instance members are always allowed.
+///
+/// Accesses come from the script AST. A defineExpose() whose keys can't all be
+/// listed (`...spread`, `defineExpose(api)`) suppresses the check.
+#[allow(clippy::too_many_arguments)]
+pub fn check_component_exposes(
+    summary: &vize_croquis::Croquis,
+    script_content: Option<&str>,
+    script_offset: u32,
+    template_ast: Option<&vize_relief::ast::RootNode<'_>>,
+    component_name: &str,
+    component_exposes: &FxHashMap<String, Vec<String>>,
+    result: &mut SfcTypeCheckResult,
+    strict: bool,
+) {
+    use oxc_ast_visit::Visit;
+    use vize_croquis::naming::to_pascal_case;
+
+    let (Some(script), Some(template_ast)) = (script_content, template_ast) else {
+        return;
+    };
+
+    let severity = if strict {
+        SfcTypeSeverity::Error
+    } else {
+        SfcTypeSeverity::Warning
+    };
+
+    let mut refs = Vec::new();
+    collect_component_refs(&template_ast.children, &mut refs);
+    if refs.is_empty() {
+        return;
+    }
+
+    let allocator = oxc_allocator::Allocator::default();
+    let parsed = oxc_parser::Parser::new(&allocator, script, oxc_span::SourceType::ts()).parse();
+    let mut collector = RefAccessCollector::default();
+    collector.visit_program(&parsed.program);
+
+    for (ref_name, tag) in refs {
+        let tag = to_pascal_case(&tag);
+        let exposed: Vec<&str> =
+            if !component_name.is_empty() && tag == to_pascal_case(component_name) {
+                if summary.macros.has_unknown_exposes() {
+                    continue;
+                }
+                summary
+                    .macros
+                    .exposes()
+                    .iter()
+                    .map(|e| e.name.as_str())
+                    .collect()
+            } else if let Some(keys) = component_exposes.get(tag.as_str()) {
+                keys.iter().map(String::as_str).collect()
+            } else {
+                continue;
+            };
+
+        let mut bindings = vec![ref_name.as_str()];
+        for (binding, target) in &collector.template_refs {
+            if target == &ref_name && !bindings.contains(&binding.as_str()) {
+                bindings.push(binding);
+            }
+        }
+
+        for access in &collector.accesses {
+            if !bindings.contains(&access.binding.as_str())
+                || access.member.starts_with('$')
+                || exposed.contains(&access.member.as_str())
+                || is_shadowed(summary, &access.binding, access.start)
+            {
+                continue;
+            }
+            result.add_diagnostic(SfcTypeDiagnostic {
+                severity,
+                message: format!(
+                    "'{}' is not exposed by <{}> (exposed: {})",
+                    access.member,
+                    tag,
+                    if exposed.is_empty() {
+                        "nothing".to_string()
+                    } else {
+                        exposed.join(", ")
+                    }
+                ),
+                start: access.start + script_offset,
+                end: access.end + script_offset,
+                code: Some("unexposed-member".to_string()),
+                help: Some(format!(
+                    "Add '{}' to defineExpose() in {}, or access it through a prop or event",
+                    access.member, tag
+                )),
+                related: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Collect static `ref="name"` attributes on component elements as (ref, tag).
+fn collect_component_refs(
+    children: &[vize_relief::ast::TemplateChildNode<'_>],
+    refs: &mut Vec<(String, String)>,
+) {
+    use vize_relief::ast::{ElementType, PropNode, TemplateChildNode};
+
+    for child in children {
+        let TemplateChildNode::Element(el) = child else {
+            continue;
+        };
+        if el.tag_type == ElementType::Component {
+            let ref_name = el.props.iter().find_map(|prop| match prop {
+                PropNode::Attribute(attr) if attr.name == "ref" => {
+                    attr.value.as_ref().map(|v| v.content.to_string())
+                }
+                _ => None,
+            });
+            if let Some(ref_name) = ref_name {
+                refs.push((ref_name, el.tag.to_string()));
+            }
+        }
+        collect_component_refs(&el.children, refs);
+    }
+}
+
+/// A `binding.value.member` access (also `?.` / `!.`).
+struct RefAccess {
+    binding: String,
+    member: String,
+    /// Span of `member`
+    start: u32,
+    end: u32,
+}
+
+/// Collects `useTemplateRef()` bindings and ref member accesses in a script.
+#[derive(Default)]
+struct RefAccessCollector {
+    /// (binding, template ref name) for `const x = useTemplateRef('name')`
+    template_refs: Vec<(String, String)>,
+    accesses: Vec<RefAccess>,
+}
+
+impl<'a> oxc_ast_visit::Visit<'a> for RefAccessCollector {
+    fn visit_variable_declarator(&mut self, decl: &oxc_ast::ast::VariableDeclarator<'a>) {
+        use oxc_ast::ast::{Argument, BindingPattern, Expression};
+
+        if let (BindingPattern::BindingIdentifier(id), Some(Expression::CallExpression(call))) =
+            (&decl.id, &decl.init)
+        {
+            if let (Expression::Identifier(callee), Some(Argument::StringLiteral(name))) =
+                (&call.callee, call.arguments.first())
+            {
+                if callee.name == "useTemplateRef" {
+                    self.template_refs
+                        .push((id.name.to_string(), name.value.to_string()));
+                }
+            }
+        }
+        oxc_ast_visit::walk::walk_variable_declarator(self, decl);
+    }
+
+    fn visit_static_member_expression(&mut self, expr: &oxc_ast::ast::StaticMemberExpression<'a>) {
+        use oxc_ast::ast::Expression;
+
+        let mut object = &expr.object;
+        while let Expression::TSNonNullExpression(inner) = object {
+            object = &inner.expression;
+        }
+        if let Expression::StaticMemberExpression(value) = object {
+            if let Expression::Identifier(binding) = &value.object {
+                if value.property.name == "value" {
+                    self.accesses.push(RefAccess {
+                        binding: binding.name.to_string(),
+                        member: expr.property.name.to_string(),
+                        start: expr.property.span.start,
+                        end: expr.property.span.end,
+                    });
+                }
+            }
+        }
+        oxc_ast_visit::walk::walk_static_member_expression(self, expr);
+    }
+}
+
+#[inline]
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Check for writes to props inside `<script setup>`.
+///
+/// Props are read-only: `props.count = 1`, `props.count++` and reassigning a
+/// destructured prop (`count = 1` after `const { count } = defineProps()`)
+/// are reported as `props-readonly` errors. Reads are fine.
+///
+/// Write targets come from the script AST, so comments and strings are never
+/// matched, and a name is only treated as the prop when no function or block
+/// scope recorded by croquis redeclares it at the write.
+pub fn check_props_mutation(
+    summary: &vize_croquis::Croquis,
+    script_content: Option<&str>,
+    script_offset: u32,
+    result: &mut SfcTypeCheckResult,
+) {
+    use oxc_ast_visit::Visit;
+
+    let (Some(script), Some(call)) = (script_content, summary.macros.define_props()) else {
+        return;
+    };
+
+    let allocator = oxc_allocator::Allocator::default();
+    let parsed = oxc_parser::Parser::new(&allocator, script, oxc_span::SourceType::ts()).parse();
+    let mut collector = PropsWriteCollector {
+        define_props_start: call.start,
+        props_binding: None,
+        writes: Vec::new(),
+    };
+    collector.visit_program(&parsed.program);
+
+    let destructure = summary.macros.props_destructure();
+    let mut writes: Vec<(String, u32, u32)> = collector
+        .writes
+        .into_iter()
+        .filter_map(|write| {
+            let target = match (&write.member, destructure) {
+                // `props.count = 1`
+                (Some(member), None) => (collector.props_binding.as_deref()
+                    == Some(write.object.as_str()))
+                .then(|| format!("{}.{}", write.object, member))?,
+                // `count = 1` after `const { count } = defineProps()`
+                (None, Some(destructure)) => destructure
+                    .bindings
+                    .values()
+                    .any(|binding| binding.local == write.object.as_str())
+                    .then(|| write.object.clone())?,
+                _ => return None,
+            };
+            (!is_shadowed(summary, &write.object, write.start)).then_some((
+                target,
+                write.start,
+                write.end,
+            ))
+        })
+        .collect();
+    writes.sort_by_key(|(_, start, _)| *start);
+
+    for (target, start, end) in writes {
+        result.add_diagnostic(SfcTypeDiagnostic {
+            severity: SfcTypeSeverity::Error,
+            message: format!("Cannot assign to '{}' because props are read-only", target),
+            start: start + script_offset,
+            end: end + script_offset,
+            code: Some("props-readonly".to_string()),
+            help: Some(
+                "Copy the prop into a local ref, or emit an event so the parent updates it"
+                    .to_string(),
+            ),
+            related: Vec::new(),
+        });
+    }
+}
+
+/// Whether `name` at `offset` refers to a local redeclared in a function or
+/// block scope rather than to the `<script setup>` binding.
+fn is_shadowed(summary: &vize_croquis::Croquis, name: &str, offset: u32) -> bool {
+    use vize_croquis::ScopeKind;
+
+    summary.scopes.iter().any(|scope| {
+        matches!(scope.kind, ScopeKind::Closure | ScopeKind::Block)
+            && scope.span.start <= offset
+            && offset < scope.span.end
+            && scope.has_binding(name)
+    })
+}
+
+/// An assignment or update target: `name` or `name.member`.
+struct ScriptWrite {
+    object: String,
+    member: Option<String>,
+    start: u32,
+    end: u32,
+}
+
+/// Collects the binding of `defineProps()` and every write target in a script.
+struct PropsWriteCollector {
+    /// Start of the `defineProps()` call
+    define_props_start: u32,
+    /// Name bound by `const props = defineProps()` (or `withDefaults(...)`)
+    props_binding: Option<String>,
+    writes: Vec<ScriptWrite>,
+}
+
+impl PropsWriteCollector {
+    fn push_member(
+        &mut self,
+        object: &oxc_ast::ast::Expression<'_>,
+        member: &str,
+        span: oxc_span::Span,
+    ) {
+        if let oxc_ast::ast::Expression::Identifier(id) = object {
+            self.writes.push(ScriptWrite {
+                object: id.name.to_string(),
+                member: Some(member.to_string()),
+                start: span.start,
+                end: span.end,
+            });
+        }
+    }
+
+    fn push_simple_target(&mut self, target: &oxc_ast::ast::SimpleAssignmentTarget<'_>) {
+        use oxc_ast::ast::{Expression, SimpleAssignmentTarget};
+
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(id) => {
+                self.writes.push(ScriptWrite {
+                    object: id.name.to_string(),
+                    member: None,
+                    start: id.span.start,
+                    end: id.span.end,
+                });
+            }
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                self.push_member(&member.object, member.property.name.as_str(), member.span);
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                if let Expression::StringLiteral(key) = &member.expression {
+                    self.push_member(&member.object, key.value.as_str(), member.span);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> oxc_ast_visit::Visit<'a> for PropsWriteCollector {
+    fn visit_variable_declarator(&mut self, decl: &oxc_ast::ast::VariableDeclarator<'a>) {
+        use oxc_ast::ast::{BindingPattern, Expression};
+
+        if let (BindingPattern::BindingIdentifier(id), Some(Expression::CallExpression(init))) =
+            (&decl.id, &decl.init)
+        {
+            // `withDefaults(defineProps(), ...)` wraps the macro call
+            let call_start = match init.arguments.first().and_then(|arg| arg.as_expression()) {
+                Some(Expression::CallExpression(inner)) => inner.span.start,
+                _ => init.span.start,
+            };
+            if init.span.start == self.define_props_start || call_start == self.define_props_start {
+                self.props_binding = Some(id.name.to_string());
+            }
+        }
+        oxc_ast_visit::walk::walk_variable_declarator(self, decl);
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &oxc_ast::ast::AssignmentExpression<'a>) {
+        if let Some(target) = expr.left.as_simple_assignment_target() {
+            self.push_simple_target(target);
+        }
+        oxc_ast_visit::walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_update_expression(&mut self, expr: &oxc_ast::ast::UpdateExpression<'a>) {
+        self.push_simple_target(&expr.argument);
+        oxc_ast_visit::walk::walk_update_expression(self, expr);
+    }
+}
+
+/// Check `v-model` modifiers against the declared type of a bound `defineModel()`.
+///
+/// `.number` casts the input value to a number and `.trim` keeps it a string,
+/// so `v-model.number="text"` with `const text = defineModel<string>()` is
+/// reported. Other bindings are checked by tsgo through the virtual TS, which
+/// passes them to `__vize_vModel`. Mismatches are warnings since the model type
+/// is read without tsgo.
+pub fn check_v_model_modifiers(
+    summary: &vize_croquis::Croquis,
+    template_ast: Option<&vize_relief::ast::RootNode<'_>>,
+    template_offset: u32,
+    result: &mut SfcTypeCheckResult,
+) {
+    let Some(template_ast) = template_ast else {
+        return;
+    };
+
+    let mut models = Vec::new();
+    collect_v_models(&template_ast.children, &mut models);
+
+    for (binding, modifier, start, end) in models {
+        let expected = if modifier == "number" {
+            "number"
+        } else {
+            "string"
+        };
+
+        let Some(declared) = summary
+            .macros
+            .models()
+            .iter()
+            .find(|m| m.local_name == binding)
+            .and_then(|m| m.model_type.as_ref())
+        else {
+            continue;
+        };
+        if modifier_accepts(declared, expected) {
+            continue;
+        }
+
+        result.add_diagnostic(SfcTypeDiagnostic {
+            severity: SfcTypeSeverity::Warning,
+            message: format!(
+                "v-model.{} on '{}' produces a {}, but '{}' is declared as '{}'",
+                modifier, binding, expected, binding, declared
+            ),
+            start: start + template_offset,
+            end: end + template_offset,
+            code: Some("v-model-modifier-type".to_string()),
+            help: Some(format!(
+                "Declare '{}' as a {} or drop the `.{}` modifier",
+                binding, expected, modifier
+            )),
+            related: Vec::new(),
+        });
+    }
+}
+
+/// Collect `v-model.number` / `v-model.trim` bindings to plain identifiers as
+/// (binding, modifier, start, end). `.number` wins when both are present.
+fn collect_v_models(
+    children: &[vize_relief::ast::TemplateChildNode<'_>],
+    models: &mut Vec<(String, &'static str, u32, u32)>,
+) {
+    use vize_relief::ast::{ExpressionNode, PropNode, TemplateChildNode};
+
+    for child in children {
+        let TemplateChildNode::Element(el) = child else {
+            continue;
+        };
+        for prop in el.props.iter() {
+            let PropNode::Directive(dir) = prop else {
+                continue;
+            };
+            if dir.name != "model" {
+                continue;
+            }
+            let Some(ExpressionNode::Simple(exp)) = &dir.exp else {
+                continue;
+            };
+            let has_modifier = |name: &str| dir.modifiers.iter().any(|m| m.content == name);
+            let modifier = if has_modifier("number") {
+                "number"
+            } else if has_modifier("trim") {
+                "trim"
+            } else {
+                continue;
+            };
+            let binding = exp.content.trim();
+            if is_identifier(binding) {
+                models.push((
+                    binding.to_string(),
+                    modifier,
+                    dir.loc.start.offset,
+                    dir.loc.end.offset,
+                ));
+            }
+        }
+        collect_v_models(&el.children, models);
+    }
+}
+
+/// Whether a declared type can hold the value a `v-model` modifier produces.
+///
+/// Type references and `any`/`unknown` are accepted; `null` and `undefined`
+/// members are ignored since a model often starts out empty.
+fn modifier_accepts(declared: &str, expected: &str) -> bool {
+    let mut kinds = Vec::new();
+    for member in split_type_union(declared) {
+        let kind = match member {
+            "null" | "undefined" => continue,
+            "string" => "string",
+            "number" => "number",
+            "boolean" | "true" | "false" => "boolean",
+            m if m.len() >= 2 && (m.starts_with('\'') || m.starts_with('"')) => "string",
+            m if m.parse::<f64>().is_ok() => "number",
+            _ => return true,
+        };
+        kinds.push(kind);
+    }
+    kinds.is_empty() || kinds.contains(&expected)
+}
+
 /// Check template bindings for undefined references.
 pub fn check_template_bindings(
     summary: &vize_croquis::Croquis,
@@ -187,6 +1275,145 @@ pub fn check_template_bindings(
     }
 }
 
+/// Check event handler calls against script function signatures.
+///
+/// Flags `@click="handler(a, b)"` when `handler` is a script function that
+/// takes a different number of arguments, and bare `@click="handler"` on
+/// native elements when the handler needs more than the event argument.
+pub fn check_event_handler_args(
+    summary: &vize_croquis::Croquis,
+    template_offset: u32,
+    result: &mut SfcTypeCheckResult,
+    strict: bool,
+) {
+    use vize_croquis::{ScopeData, TemplateExpressionKind};
+
+    for expr in &summary.template_expressions {
+        if expr.kind != TemplateExpressionKind::VOn {
+            continue;
+        }
+
+        let content = expr.content.trim();
+        let (name, arg_count, is_bare) = match parse_handler_call(content) {
+            Some((name, arg_count)) => (name, arg_count, false),
+            None => {
+                // Bare handler reference: the runtime passes the native event
+                let is_identifier = !content.is_empty()
+                    && content
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+                let is_native = summary
+                    .scopes
+                    .get_scope(expr.scope_id)
+                    .is_some_and(|scope| {
+                        matches!(scope.data(), ScopeData::EventHandler(data) if data.target_component.is_none())
+                    });
+                if !is_identifier || !is_native {
+                    continue;
+                }
+                (content, 1, true)
+            }
+        };
+
+        let Some(signature) = summary.function_signatures.get(name) else {
+            continue;
+        };
+        // A bare handler may ignore the event, since JS drops extra arguments;
+        // it only fails when it requires more than the event provides
+        let mismatched = if is_bare {
+            signature.min_params > arg_count
+        } else {
+            !signature.accepts(arg_count)
+        };
+        if !mismatched {
+            continue;
+        }
+
+        let expected = match signature.max_params {
+            Some(max) if max == signature.min_params => max.to_string(),
+            Some(max) => format!("{}-{}", signature.min_params, max),
+            None => format!("at least {}", signature.min_params),
+        };
+
+        result.add_diagnostic(SfcTypeDiagnostic {
+            severity: if strict {
+                SfcTypeSeverity::Error
+            } else {
+                SfcTypeSeverity::Warning
+            },
+            message: format!(
+                "Handler '{}' expects {} argument(s), but the template passes {}",
+                name, expected, arg_count
+            ),
+            start: expr.start + template_offset,
+            end: expr.end + template_offset,
+            code: Some("event-handler-args".to_string()),
+            help: Some(format!("Check the parameters of '{}' in the script", name)),
+            related: Vec::new(),
+        });
+    }
+}
+
+/// Split a handler call like `fn(a, b)` into its callee and argument count.
+fn parse_handler_call(content: &str) -> Option<(&str, u32)> {
+    let content = content.strip_suffix(';').unwrap_or(content).trim_end();
+    let open = content.find('(')?;
+    let name = content[..open].trim_end();
+    if name.is_empty()
+        || name.starts_with(|c: char| c.is_ascii_digit())
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    {
+        return None;
+    }
+
+    let args = content[open + 1..].strip_suffix(')')?;
+    let mut count = 0;
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut has_content = false;
+    for c in args.chars() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' | '`' => {
+                quote = Some(c);
+                has_content = true;
+            }
+            '(' | '[' | '{' => {
+                depth += 1;
+                has_content = true;
+            }
+            ')' | ']' | '}' => {
+                depth -= 1;
+                // The closing paren belongs to a nested call, e.g. `fn(a)(b)`
+                if depth < 0 {
+                    return None;
+                }
+            }
+            ',' if depth == 0 => {
+                count += 1;
+                has_content = false;
+            }
+            c if !c.is_whitespace() => has_content = true,
+            _ => {}
+        }
+    }
+
+    if depth != 0 {
+        return None;
+    }
+    if has_content {
+        count += 1;
+    }
+    Some((name, count))
+}
+
 /// Check for reactivity loss patterns.
 pub fn check_reactivity(
     summary: &vize_croquis::Croquis,
@@ -320,3 +1547,388 @@ pub fn check_fallthrough_attrs(
         related: Vec::new(),
     });
 }
+
+/// Check for props and emits that are declared but never used.
+///
+/// A prop counts as used when the template or `<style>` `v-bind()` reads it
+/// (bare, or via `props.x` / `$props.x`), or the script reads it through the
+/// props object or its destructured local. Passing the whole object on
+/// (`v-bind="props"`, `toRefs(props)`) uses every prop. An event counts as
+/// emitted when `emit('x')` / `$emit('x')` is called in script or template;
+/// dynamic event names disable the emit check.
+///
+/// Declarations, destructures and emit calls come from croquis. References
+/// come from the script and template expression ASTs, and a script reference
+/// only counts when no function or block scope recorded by croquis redeclares
+/// the name there.
+pub fn check_unused_props_and_emits(
+    summary: &vize_croquis::Croquis,
+    script_content: Option<&str>,
+    script_offset: u32,
+    template_ast: Option<&vize_relief::ast::RootNode<'_>>,
+    style_vars: &[String],
+    result: &mut SfcTypeCheckResult,
+) {
+    let script = script_content.unwrap_or_default();
+    let mut script_refs = ReferenceCollector::default();
+    if !collect_references(script, false, &mut script_refs) {
+        return;
+    }
+
+    let mut template = Vec::new();
+    if let Some(template_ast) = template_ast {
+        collect_template_code(&template_ast.children, &mut template);
+    }
+    template.extend(style_vars.iter().cloned());
+    let mut template_refs = ReferenceCollector::default();
+    // Code we can't parse may use anything
+    let template_parsed = template
+        .iter()
+        .all(|code| collect_references(code, true, &mut template_refs));
+
+    if let Some(call) = summary.macros.define_props() {
+        let binding = script_refs.call_binding(call.start);
+        let destructure = summary.macros.props_destructure();
+
+        let mut used: Vec<&str> = Vec::new();
+        // A rest element keeps the remaining props in an object we don't follow
+        let mut whole = !template_parsed || destructure.is_some_and(|d| d.rest_id.is_some());
+        for reference in macro_uses(summary, &script_refs, &template_refs, binding, "$props") {
+            match &reference.kind {
+                ReferenceKind::Member(member) => used.push(member),
+                _ => whole = true,
+            }
+        }
+
+        if !whole {
+            for prop in summary.macros.props() {
+                let local = destructure
+                    .and_then(|d| d.get(&prop.name))
+                    .map(|b| b.local.as_str());
+                let is_used = used.contains(&prop.name.as_str())
+                    || template_refs
+                        .references
+                        .iter()
+                        .any(|r| r.name == prop.name.as_str() || local == Some(r.name.as_str()))
+                    || local.is_some_and(|local| {
+                        script_refs
+                            .references
+                            .iter()
+                            .any(|r| r.name == local && !is_shadowed(summary, local, r.start))
+                    });
+                if is_used {
+                    continue;
+                }
+
+                let (start, end) = declared_name_span(script, call, &prop.name);
+                result.add_diagnostic(SfcTypeDiagnostic {
+                    severity: SfcTypeSeverity::Warning,
+                    message: format!("Prop '{}' is declared but never used", prop.name),
+                    start: start + script_offset,
+                    end: end + script_offset,
+                    code: Some("unused-prop".to_string()),
+                    help: Some(
+                        "Use it in the template or script, or remove it from defineProps"
+                            .to_string(),
+                    ),
+                    related: Vec::new(),
+                });
+            }
+        }
+    }
+
+    if let Some(call) = summary.macros.define_emits() {
+        if !template_parsed || summary.macros.emit_calls().iter().any(|c| c.is_dynamic) {
+            return;
+        }
+        let binding = script_refs.call_binding(call.start);
+
+        let mut emitted: Vec<&str> = summary
+            .macros
+            .emit_calls()
+            .iter()
+            .map(|c| c.event_name.as_str())
+            .collect();
+        for reference in macro_uses(summary, &script_refs, &template_refs, binding, "$emit") {
+            match &reference.kind {
+                ReferenceKind::Call(Some(event)) => emitted.push(event),
+                // A dynamic event name, or `emit` handed to a helper that may
+                // call it with anything
+                _ => return,
+            }
+        }
+
+        for emit in summary.macros.emits() {
+            let camel = vize_carton::camelize(&emit.name);
+            if emitted
+                .iter()
+                .any(|&e| e == emit.name.as_str() || vize_carton::camelize(e) == camel)
+            {
+                continue;
+            }
+
+            let (start, end) = declared_name_span(script, call, &emit.name);
+            result.add_diagnostic(SfcTypeDiagnostic {
+                severity: SfcTypeSeverity::Warning,
+                message: format!("Event '{}' is declared but never emitted", emit.name),
+                start: start + script_offset,
+                end: end + script_offset,
+                code: Some("unused-emit".to_string()),
+                help: Some(format!(
+                    "Emit it with emit('{}'), or remove it from defineEmits",
+                    emit.name
+                )),
+                related: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Collect the code of interpolations and directive expressions. A value-less
+/// `:name` stands for `:name="name"`, only the source of a `v-for` is an
+/// expression, and `v-slot` declares its props rather than reading anything.
+fn collect_template_code(
+    children: &[vize_relief::ast::TemplateChildNode<'_>],
+    code: &mut Vec<String>,
+) {
+    use vize_relief::ast::{ExpressionNode, PropNode, TemplateChildNode};
+
+    let source = |exp: &ExpressionNode<'_>| match exp {
+        ExpressionNode::Simple(s) => s.content.to_string(),
+        ExpressionNode::Compound(c) => c.loc.source.to_string(),
+    };
+
+    for child in children {
+        match child {
+            TemplateChildNode::Interpolation(interp) => code.push(source(&interp.content)),
+            TemplateChildNode::Element(el) => {
+                for prop in el.props.iter() {
+                    let PropNode::Directive(dir) = prop else {
+                        continue;
+                    };
+                    match (dir.name.as_str(), &dir.exp) {
+                        // Slot props are declared, not read
+                        ("slot", _) => {}
+                        ("for", Some(exp)) => {
+                            let (_, items) =
+                                vize_croquis::analyzer::parse_v_for_expression(&source(exp));
+                            code.push(items.to_string());
+                        }
+                        (_, Some(exp)) => code.push(source(exp)),
+                        ("bind", None) => {
+                            if let Some(ExpressionNode::Simple(arg)) = &dir.arg {
+                                code.push(vize_carton::camelize(&arg.content).to_string());
+                            }
+                        }
+                        _ => {}
+                    }
+                    if let Some(ExpressionNode::Simple(arg)) = &dir.arg {
+                        if !arg.is_static {
+                            code.push(arg.content.to_string());
+                        }
+                    }
+                }
+                collect_template_code(&el.children, code);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Script references to a macro's binding, and template references to it or
+/// to its `$` instance alias.
+fn macro_uses<'r>(
+    summary: &'r vize_croquis::Croquis,
+    script_refs: &'r ReferenceCollector,
+    template_refs: &'r ReferenceCollector,
+    binding: Option<&'r str>,
+    alias: &'r str,
+) -> impl Iterator<Item = &'r Reference> + 'r {
+    let in_script = script_refs.references.iter().filter(move |r| {
+        binding == Some(r.name.as_str()) && !is_shadowed(summary, &r.name, r.start)
+    });
+    let in_template = template_refs
+        .references
+        .iter()
+        .filter(move |r| r.name == alias || binding == Some(r.name.as_str()));
+    in_script.chain(in_template)
+}
+
+/// Parse `code` and collect its identifier references into `collector`.
+///
+/// Template code is tried as an expression first, then as statements (for
+/// event handlers). Returns false when the code doesn't parse.
+fn collect_references(code: &str, is_template: bool, collector: &mut ReferenceCollector) -> bool {
+    use oxc_ast_visit::Visit;
+
+    let allocator = oxc_allocator::Allocator::default();
+    let source_type = oxc_span::SourceType::ts();
+    if is_template {
+        if let Ok(expr) = oxc_parser::Parser::new(&allocator, code, source_type).parse_expression()
+        {
+            collector.visit_expression(&expr);
+            return true;
+        }
+    }
+    let parsed = oxc_parser::Parser::new(&allocator, code, source_type).parse();
+    if parsed.panicked || !parsed.errors.is_empty() {
+        return false;
+    }
+    collector.visit_program(&parsed.program);
+    true
+}
+
+/// How an identifier is referenced.
+enum ReferenceKind {
+    /// `x.member` / `x['member']` (also `?.` / `!.`)
+    Member(String),
+    /// `x(...)`, with the first argument when it is a plain string
+    Call(Option<String>),
+    /// Any other use of the value itself
+    Other,
+}
+
+/// A reference to an identifier.
+struct Reference {
+    name: String,
+    kind: ReferenceKind,
+    /// Offset of the identifier
+    start: u32,
+}
+
+/// Collects identifier references and the bindings of call results.
+#[derive(Default)]
+struct ReferenceCollector {
+    /// (call start, name) for `const name = call()`, where the call may be
+    /// wrapped as in `withDefaults(call(), ...)`
+    call_bindings: Vec<(u32, String)>,
+    references: Vec<Reference>,
+}
+
+impl ReferenceCollector {
+    /// Name bound to the result of the call starting at `call_start`.
+    fn call_binding(&self, call_start: u32) -> Option<&str> {
+        self.call_bindings
+            .iter()
+            .find(|(start, _)| *start == call_start)
+            .map(|(_, name)| name.as_str())
+    }
+
+    fn push(&mut self, (name, start): (&str, u32), kind: ReferenceKind) {
+        self.references.push(Reference {
+            name: name.to_string(),
+            kind,
+            start,
+        });
+    }
+}
+
+/// The identifier an expression refers to, looking through `x!` and `(x)`.
+fn referenced_identifier<'e>(expr: &'e oxc_ast::ast::Expression<'_>) -> Option<(&'e str, u32)> {
+    use oxc_ast::ast::Expression;
+
+    match expr {
+        Expression::Identifier(id) => Some((id.name.as_str(), id.span.start)),
+        Expression::TSNonNullExpression(inner) => referenced_identifier(&inner.expression),
+        Expression::ParenthesizedExpression(inner) => referenced_identifier(&inner.expression),
+        _ => None,
+    }
+}
+
+impl<'a> oxc_ast_visit::Visit<'a> for ReferenceCollector {
+    fn visit_variable_declarator(&mut self, decl: &oxc_ast::ast::VariableDeclarator<'a>) {
+        use oxc_ast::ast::{BindingPattern, Expression};
+
+        if let (BindingPattern::BindingIdentifier(id), Some(Expression::CallExpression(init))) =
+            (&decl.id, &decl.init)
+        {
+            self.call_bindings
+                .push((init.span.start, id.name.to_string()));
+            if let Some(Expression::CallExpression(inner)) =
+                init.arguments.first().and_then(|arg| arg.as_expression())
+            {
+                self.call_bindings
+                    .push((inner.span.start, id.name.to_string()));
+            }
+        }
+        oxc_ast_visit::walk::walk_variable_declarator(self, decl);
+    }
+
+    fn visit_static_member_expression(&mut self, expr: &oxc_ast::ast::StaticMemberExpression<'a>) {
+        match referenced_identifier(&expr.object) {
+            Some(object) => self.push(
+                object,
+                ReferenceKind::Member(expr.property.name.to_string()),
+            ),
+            None => oxc_ast_visit::walk::walk_static_member_expression(self, expr),
+        }
+    }
+
+    fn visit_computed_member_expression(
+        &mut self,
+        expr: &oxc_ast::ast::ComputedMemberExpression<'a>,
+    ) {
+        use oxc_ast::ast::Expression;
+
+        let Some(object) = referenced_identifier(&expr.object) else {
+            oxc_ast_visit::walk::walk_computed_member_expression(self, expr);
+            return;
+        };
+        match &expr.expression {
+            Expression::StringLiteral(key) => {
+                self.push(object, ReferenceKind::Member(key.value.to_string()));
+            }
+            key => {
+                self.push(object, ReferenceKind::Other);
+                self.visit_expression(key);
+            }
+        }
+    }
+
+    fn visit_call_expression(&mut self, expr: &oxc_ast::ast::CallExpression<'a>) {
+        use oxc_ast::ast::Argument;
+
+        let Some(callee) = referenced_identifier(&expr.callee) else {
+            oxc_ast_visit::walk::walk_call_expression(self, expr);
+            return;
+        };
+        let first = match expr.arguments.first() {
+            Some(Argument::StringLiteral(arg)) => Some(arg.value.to_string()),
+            Some(Argument::TemplateLiteral(arg)) if arg.expressions.is_empty() => {
+                arg.quasis.first().map(|quasi| quasi.value.raw.to_string())
+            }
+            _ => None,
+        };
+        self.push(callee, ReferenceKind::Call(first));
+        for arg in expr.arguments.iter() {
+            self.visit_argument(arg);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, id: &oxc_ast::ast::IdentifierReference<'a>) {
+        self.push((id.name.as_str(), id.span.start), ReferenceKind::Other);
+    }
+}
+
+/// Span of `name` inside a macro call, or the whole call when not found.
+fn declared_name_span(
+    script: &str,
+    call: &vize_croquis::macros::MacroCall,
+    name: &str,
+) -> (u32, u32) {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let (start, end) = (call.start as usize, (call.end as usize).min(script.len()));
+    script
+        .get(start..end)
+        .and_then(|args| {
+            args.match_indices(name).map(|(pos, _)| pos).find(|&pos| {
+                !args[..pos].ends_with(is_ident_char)
+                    && !args[pos + name.len()..].starts_with(is_ident_char)
+            })
+        })
+        .map(|pos| {
+            let name_start = (start + pos) as u32;
+            (name_start, name_start + name.len() as u32)
+        })
+        .unwrap_or((call.start, call.end))
+}
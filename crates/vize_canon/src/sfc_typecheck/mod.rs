@@ -6,8 +6,15 @@
 //! ## Features
 //!
 //! - Props type validation (defineProps)
+//! - Props mutation detection (props are read-only)
+//! - Default value validation (withDefaults)
 //! - Emits type validation (defineEmits)
+//! - Model validation (defineModel)
 //! - Template binding validation (undefined references)
+//! - Slot usage validation (defineSlots)
+//! - Event handler argument validation
+//! - v-model modifier validation (`.number`, `.trim`)
+//! - Unused props and emits (strict mode)
 //! - Virtual TypeScript generation with scope-aware code
 //!
 //! ## Architecture
@@ -32,8 +39,16 @@
 //! ┌─────────────────────────────────────┐
 //! │  type_check_sfc()                   │
 //! │  - check_props_typing()             │
+//! │  - check_props_mutation()           │
+//! │  - check_with_defaults()            │
 //! │  - check_emits_typing()             │
+//! │  - check_define_model()             │
 //! │  - check_template_bindings()        │
+//! │  - check_event_handler_args()       │
+//! │  - check_v_model_modifiers()        │
+//! │  - check_define_slots()             │
+//! │  - check_component_exposes()        │
+//! │  - check_unused_props_and_emits()   │
 //! │  - generate_virtual_ts_with_scopes()│
 //! └─────────────────────────────────────┘
 //! ```
@@ -42,11 +57,13 @@ mod checks;
 mod virtual_ts;
 
 use serde::Serialize;
-use vize_carton::Bump;
+use vize_carton::{Bump, FxHashMap};
 
 use checks::{
-    check_emits_typing, check_fallthrough_attrs, check_invalid_exports, check_props_typing,
-    check_reactivity, check_setup_context, check_template_bindings,
+    check_component_exposes, check_define_model, check_define_slots, check_emits_typing,
+    check_event_handler_args, check_fallthrough_attrs, check_invalid_exports, check_props_mutation,
+    check_props_typing, check_reactivity, check_setup_context, check_template_bindings,
+    check_unused_props_and_emits, check_v_model_modifiers, check_with_defaults,
 };
 use virtual_ts::generate_virtual_ts_with_scopes;
 
@@ -146,10 +163,22 @@ pub struct SfcTypeCheckOptions {
     pub include_virtual_ts: bool,
     /// Whether to check props types
     pub check_props: bool,
+    /// Whether to check writes to props
+    pub check_props_mutation: bool,
+    /// Whether to check withDefaults defaults against prop types
+    pub check_with_defaults: bool,
     /// Whether to check emits types
     pub check_emits: bool,
+    /// Whether to check defineModel declarations
+    pub check_define_model: bool,
     /// Whether to check template bindings
     pub check_template_bindings: bool,
+    /// Whether to check event handler call arguments
+    pub check_event_handler_args: bool,
+    /// Whether to check v-model modifiers against the bound variable's type
+    pub check_v_model_modifiers: bool,
+    /// Whether to check slot usage against defineSlots
+    pub check_define_slots: bool,
     /// Whether to check reactivity loss patterns
     pub check_reactivity: bool,
     /// Whether to check setup context violations
@@ -158,6 +187,13 @@ pub struct SfcTypeCheckOptions {
     pub check_invalid_exports: bool,
     /// Whether to check fallthrough attrs with multi-root
     pub check_fallthrough_attrs: bool,
+    /// Whether to check member access on component template refs (off by default)
+    pub check_component_exposes: bool,
+    /// Keys exposed by child components via defineExpose(), by component name.
+    /// Leave out components whose exposed keys are not fully known.
+    pub component_exposes: FxHashMap<String, Vec<String>>,
+    /// Whether to report props and emits that are never used (strict mode only)
+    pub check_unused: bool,
     /// Strict mode - report more potential issues
     pub strict: bool,
 }
@@ -169,12 +205,21 @@ impl SfcTypeCheckOptions {
             filename: filename.into(),
             include_virtual_ts: false,
             check_props: true,
+            check_props_mutation: true,
+            check_with_defaults: true,
             check_emits: true,
+            check_define_model: true,
             check_template_bindings: true,
+            check_event_handler_args: true,
+            check_v_model_modifiers: true,
+            check_define_slots: true,
             check_reactivity: true,
             check_setup_context: true,
             check_invalid_exports: true,
             check_fallthrough_attrs: true,
+            check_component_exposes: false,
+            component_exposes: FxHashMap::default(),
+            check_unused: true,
             strict: false,
         }
     }
@@ -190,6 +235,17 @@ impl SfcTypeCheckOptions {
         self.include_virtual_ts = true;
         self
     }
+
+    /// Register the keys a child component exposes via defineExpose().
+    pub fn with_component_exposes<K: Into<String>>(
+        mut self,
+        component: impl Into<String>,
+        keys: impl IntoIterator<Item = K>,
+    ) -> Self {
+        self.component_exposes
+            .insert(component.into(), keys.into_iter().map(Into::into).collect());
+        self
+    }
 }
 
 /// Perform type checking on a Vue SFC.
@@ -197,8 +253,16 @@ impl SfcTypeCheckOptions {
 /// This performs AST-based type analysis using croquis for semantic analysis.
 /// It checks:
 /// - Props typing (defineProps)
+/// - Writes to read-only props
+/// - Default values (withDefaults)
 /// - Emits typing (defineEmits)
+/// - Model declarations (defineModel)
 /// - Template binding references
+/// - Event handler call arguments
+/// - v-model modifiers against bound variable types
+/// - Slot usage (defineSlots)
+/// - Member access on component template refs (defineExpose)
+/// - Props and emits that are never used (strict mode)
 ///
 /// For full TypeScript type checking with tsgo, use `TypeCheckService`.
 pub fn type_check_sfc(source: &str, options: &SfcTypeCheckOptions) -> SfcTypeCheckResult {
@@ -275,16 +339,106 @@ pub fn type_check_sfc(source: &str, options: &SfcTypeCheckOptions) -> SfcTypeChe
         check_props_typing(&summary, script_offset, &mut result, options.strict);
     }
 
+    // Check writes to props
+    if options.check_props_mutation {
+        check_props_mutation(&summary, script_content, script_offset, &mut result);
+    }
+
+    // Check withDefaults defaults
+    if options.check_with_defaults {
+        check_with_defaults(
+            &summary,
+            script_content,
+            script_offset,
+            &mut result,
+            options.strict,
+        );
+    }
+
     // Check emits typing
     if options.check_emits {
         check_emits_typing(&summary, script_offset, &mut result, options.strict);
     }
 
+    // Check defineModel declarations
+    if options.check_define_model {
+        check_define_model(
+            &summary,
+            script_content,
+            script_offset,
+            &mut result,
+            options.strict,
+        );
+    }
+
     // Check template bindings
     if options.check_template_bindings {
         check_template_bindings(&summary, template_offset, &mut result, options.strict);
     }
 
+    // Check event handler arguments
+    if options.check_event_handler_args {
+        check_event_handler_args(&summary, template_offset, &mut result, options.strict);
+    }
+
+    // Check v-model modifiers against bound defineModel types
+    if options.check_v_model_modifiers {
+        check_v_model_modifiers(
+            &summary,
+            template_ast.as_ref(),
+            template_offset,
+            &mut result,
+        );
+    }
+
+    let component_name = std::path::Path::new(&options.filename)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+
+    // Check slot usage against defineSlots
+    if options.check_define_slots {
+        check_define_slots(
+            &summary,
+            template_ast.as_ref(),
+            template_offset,
+            component_name,
+            &mut result,
+            options.strict,
+        );
+    }
+
+    // Check template ref member access against defineExpose
+    if options.check_component_exposes {
+        check_component_exposes(
+            &summary,
+            script_content,
+            script_offset,
+            template_ast.as_ref(),
+            component_name,
+            &options.component_exposes,
+            &mut result,
+            options.strict,
+        );
+    }
+
+    // Check for unused props and emits
+    if options.check_unused && options.strict {
+        let style_vars: Vec<String> = descriptor
+            .styles
+            .iter()
+            .flat_map(|style| vize_atelier_sfc::style::extract_css_vars(&style.content))
+            .collect();
+        check_unused_props_and_emits(
+            &summary,
+            script_content,
+            script_offset,
+            template_ast.as_ref(),
+            &style_vars,
+            &mut result,
+        );
+    }
+
     // Check reactivity loss
     if options.check_reactivity {
         check_reactivity(&summary, script_offset, &mut result, options.strict);
@@ -927,4 +1081,755 @@ const msg = 'hello'
         });
         assert!(has_error, "Strict mode should report as Error");
     }
+
+    #[test]
+    fn test_check_define_model_typed_generic() {
+        let source = r#"<script setup lang="ts">
+const model = defineModel<string>()
+const count = defineModel<number>('count', { type: Number })
+</script>
+<template>
+  <input v-model="model" />
+  <span>{{ count }}</span>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        assert!(!result.diagnostics.iter().any(|d| matches!(
+            d.code.as_deref(),
+            Some("define-model-type" | "untyped-model" | "define-model-conflict")
+        )));
+    }
+
+    #[test]
+    fn test_check_define_model_type_mismatch() {
+        let source = r#"<script setup lang="ts">
+const count = defineModel<string>('count', { type: Number })
+</script>
+<template>
+  <span>{{ count }}</span>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        let mismatch = result
+            .diagnostics
+            .iter()
+            .find(|d| d.code.as_deref() == Some("define-model-type"))
+            .expect("mismatched model type should be reported");
+        assert!(mismatch.message.contains("count"));
+        assert_eq!(
+            &source[mismatch.start as usize..mismatch.start as usize + 11],
+            "defineModel"
+        );
+    }
+
+    #[test]
+    fn test_check_define_model_pairs_calls_by_name() {
+        let source = r#"<script setup lang="ts">
+const title = defineModel<string>('title', { type: String })
+const count = defineModel<string>("count", { type: Number })
+</script>
+<template>
+  <span>{{ title }} {{ count }}</span>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        let mismatches: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code.as_deref() == Some("define-model-type"))
+            .collect();
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches[0].message.contains("'count'"));
+        let count_call = source.find("defineModel<string>(\"count\"").unwrap();
+        assert_eq!(mismatches[0].start as usize, count_call);
+    }
+
+    #[test]
+    fn test_check_define_model_untyped() {
+        let source = r#"<script setup>
+const model = defineModel()
+const count = defineModel('count', { type: Number })
+</script>
+<template>
+  <input v-model="model" />
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        let untyped: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code.as_deref() == Some("untyped-model"))
+            .collect();
+        assert_eq!(untyped.len(), 1);
+        assert!(untyped[0].message.contains("modelValue"));
+        assert_eq!(untyped[0].severity, SfcTypeSeverity::Warning);
+        assert!(!result
+            .diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("define-model-type")));
+    }
+
+    #[test]
+    fn test_check_define_model_conflicting_prop() {
+        let source = r#"<script setup lang="ts">
+const props = defineProps<{ modelValue: string }>()
+const model = defineModel<string>()
+</script>
+<template>
+  <input v-model="model" />
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        assert!(result
+            .diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("define-model-conflict")));
+    }
+
+    #[test]
+    fn test_check_event_handler_too_many_args() {
+        let source = r#"<script setup lang="ts">
+const extraArg = 1
+function onClick(e: MouseEvent) {}
+</script>
+<template>
+  <button @click="onClick($event, extraArg)">click</button>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue").with_virtual_ts();
+        let result = type_check_sfc(source, &options);
+        let diagnostic = result
+            .diagnostics
+            .iter()
+            .find(|d| d.code.as_deref() == Some("event-handler-args"))
+            .expect("extra handler argument should be reported");
+        assert!(diagnostic.message.contains("onClick"));
+        assert!(source[diagnostic.start as usize..diagnostic.end as usize].starts_with("@click"));
+        assert_eq!(diagnostic.severity, SfcTypeSeverity::Warning);
+
+        // The handler runs in a closure with a DOM-typed `$event` for tsgo
+        let virtual_ts = result.virtual_ts.unwrap();
+        assert!(virtual_ts.contains("(($event: MouseEvent) => {"));
+        assert!(virtual_ts.contains("onClick($event, extraArg);"));
+    }
+
+    #[test]
+    fn test_check_event_handler_args_ok() {
+        let source = r#"<script setup lang="ts">
+const select = (id: number, label?: string) => {}
+function onClick(e: MouseEvent) {}
+</script>
+<template>
+  <button @click="onClick">a</button>
+  <button @click="select(1)">b</button>
+  <button @click="select(1, 'one')">c</button>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        assert!(!result
+            .diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("event-handler-args")));
+    }
+
+    #[test]
+    fn test_check_event_handler_bare_reference_without_params() {
+        let source = r#"<script setup lang="ts">
+function refresh() {}
+</script>
+<template>
+  <button @click="refresh">refresh</button>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        assert!(!result
+            .diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("event-handler-args")));
+    }
+
+    #[test]
+    fn test_check_event_handler_bare_reference_needs_args() {
+        let source = r#"<script setup lang="ts">
+function move(from: number, to: number) {}
+</script>
+<template>
+  <button @click="move">move</button>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue").strict();
+        let result = type_check_sfc(source, &options);
+        assert!(result.diagnostics.iter().any(|d| {
+            d.code.as_deref() == Some("event-handler-args") && d.severity == SfcTypeSeverity::Error
+        }));
+    }
+
+    #[test]
+    fn test_check_v_model_number_on_string_model() {
+        let source = r#"<script setup lang="ts">
+const age = defineModel<string>('age')
+const count = defineModel<number>('count')
+</script>
+<template>
+  <input v-model.number="age" />
+  <input v-model.number="count" />
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        let diagnostics: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code.as_deref() == Some("v-model-modifier-type"))
+            .collect();
+        assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+        assert!(diagnostics[0].message.contains("'age'"));
+        assert_eq!(diagnostics[0].severity, SfcTypeSeverity::Warning);
+        assert!(
+            source[diagnostics[0].start as usize..diagnostics[0].end as usize]
+                .starts_with("v-model.number")
+        );
+    }
+
+    #[test]
+    fn test_check_v_model_trim_on_number_model() {
+        let source = r#"<script setup lang="ts">
+const amount = defineModel<number>('amount')
+const name = defineModel<string | null>('name')
+</script>
+<template>
+  <input v-model.trim="amount" />
+  <input v-model.trim="name" />
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        let diagnostics: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code.as_deref() == Some("v-model-modifier-type"))
+            .collect();
+        assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+        assert!(diagnostics[0].message.contains("'amount'"));
+    }
+
+    #[test]
+    fn test_check_v_model_number_on_string_ref_goes_through_virtual_ts() {
+        let source = r#"<script setup lang="ts">
+import { ref } from 'vue'
+const age = ref('')
+</script>
+<template>
+  <input v-model.number="age" />
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue").with_virtual_ts();
+        let result = type_check_sfc(source, &options);
+        // Plain refs are left to tsgo, which checks the binding in the virtual TS
+        assert!(!result
+            .diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("v-model-modifier-type")));
+        let virtual_ts = result.virtual_ts.expect("virtual ts");
+        assert!(virtual_ts.contains("__vize_vModel((age), 0 as number);"));
+    }
+
+    #[test]
+    fn test_check_with_defaults_type_mismatch() {
+        let source = r#"<script setup lang="ts">
+interface Props {
+  count?: number
+}
+const props = withDefaults(defineProps<Props>(), { count: 'not a number' })
+</script>
+<template>
+  <div>{{ props.count }}</div>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        let mismatch = result
+            .diagnostics
+            .iter()
+            .find(|d| d.code.as_deref() == Some("default-type-mismatch"))
+            .expect("string default on a number prop should be reported");
+        assert_eq!(
+            &source[mismatch.start as usize..mismatch.end as usize],
+            "count: 'not a number'"
+        );
+    }
+
+    #[test]
+    fn test_check_with_defaults_unknown_key() {
+        let source = r#"<script setup lang="ts">
+const props = withDefaults(defineProps<{ count?: number }>(), {
+  count: 0,
+  missing: 'x',
+})
+</script>
+<template>
+  <div>{{ props.count }}</div>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        let unknown: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code.as_deref() == Some("unknown-default-key"))
+            .collect();
+        assert_eq!(unknown.len(), 1);
+        assert!(unknown[0].message.contains("missing"));
+        assert!(!result
+            .diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("default-type-mismatch")));
+    }
+
+    #[test]
+    fn test_check_with_defaults_compatible() {
+        let source = r#"<script setup lang="ts">
+interface Props {
+  size?: 'sm' | 'md'
+  label?: string
+  items?: string[]
+  onSave?: () => void
+}
+const props = withDefaults(defineProps<Props>(), {
+  size: 'md',
+  label: undefined,
+  items: () => [],
+  onSave: () => {},
+})
+</script>
+<template>
+  <div>{{ props.size }}</div>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        assert!(!result.diagnostics.iter().any(|d| matches!(
+            d.code.as_deref(),
+            Some("default-type-mismatch" | "unknown-default-key")
+        )));
+    }
+
+    #[test]
+    fn test_check_with_defaults_skips_composed_props() {
+        for props in [
+            "interface Base {\n  size?: string\n}\ninterface Props extends Base {\n  label?: string\n}",
+            "interface Base {\n  size?: string\n}\ntype Props = Base & {\n  label?: string\n}",
+            "interface Base {\n  size?: string\n}\ntype Props = {\n  label?: string\n} & Base",
+        ] {
+            let source = format!(
+                r#"<script setup lang="ts">
+{props}
+const props = withDefaults(defineProps<Props>(), {{ size: 'md', label: 'x' }})
+</script>
+<template>
+  <div>{{{{ props.size }}}}</div>
+</template>"#
+            );
+            let options = SfcTypeCheckOptions::new("test.vue");
+            let result = type_check_sfc(&source, &options);
+            assert!(
+                !result
+                    .diagnostics
+                    .iter()
+                    .any(|d| d.code.as_deref() == Some("unknown-default-key")),
+                "{}",
+                props
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_define_slots_valid() {
+        let source = r#"<script setup lang="ts">
+const props = defineProps<{ items: string[]; depth: number }>()
+defineSlots<{
+  default(props: { item: string; index: number }): any
+}>()
+</script>
+<template>
+  <ul>
+    <li v-for="(item, index) in props.items" :key="item">
+      <slot :item="item" :index="index" />
+    </li>
+    <List v-if="props.depth > 0" :items="props.items" :depth="props.depth - 1">
+      <template #default="{ item, index: i }">{{ i }}: {{ item }}</template>
+    </List>
+  </ul>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("List.vue");
+        let result = type_check_sfc(source, &options);
+        assert!(!result.diagnostics.iter().any(|d| matches!(
+            d.code.as_deref(),
+            Some("unknown-slot" | "unknown-slot-prop")
+        )));
+    }
+
+    #[test]
+    fn test_check_define_slots_misspelled_prop() {
+        let source = r#"<script setup lang="ts">
+const props = defineProps<{ items: string[] }>()
+defineSlots<{
+  default(props: { item: string }): any
+  footer(): any
+}>()
+</script>
+<template>
+  <List :items="props.items">
+    <template #default="{ itme }">{{ itme }}</template>
+  </List>
+  <slot name="header" />
+</template>"#;
+        let options = SfcTypeCheckOptions::new("List.vue");
+        let result = type_check_sfc(source, &options);
+
+        let prop = result
+            .diagnostics
+            .iter()
+            .find(|d| d.code.as_deref() == Some("unknown-slot-prop"))
+            .expect("misspelled slot prop should be reported");
+        assert!(prop.message.contains("itme"));
+        assert!(source[prop.start as usize..prop.end as usize].starts_with("#default"));
+
+        let slot = result
+            .diagnostics
+            .iter()
+            .find(|d| d.code.as_deref() == Some("unknown-slot"))
+            .expect("undeclared slot should be reported");
+        assert!(slot.message.contains("header"));
+    }
+
+    fn child_exposes() -> Vec<String> {
+        let mut analyzer = vize_croquis::Analyzer::new();
+        analyzer.analyze_script_setup(
+            r#"const focus = () => {}
+const secret = 1
+defineExpose({ focus })"#,
+        );
+        analyzer
+            .finish()
+            .macros
+            .exposes()
+            .iter()
+            .map(|e| e.name.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_type_check_exposed_member_access() {
+        let source = r#"<script setup lang="ts">
+import { ref, onMounted } from 'vue'
+import Child from './Child.vue'
+const child = ref<InstanceType<typeof Child>>()
+onMounted(() => child.value?.focus())
+</script>
+<template>
+  <Child ref="child" />
+</template>"#;
+        let mut options =
+            SfcTypeCheckOptions::new("Parent.vue").with_component_exposes("Child", child_exposes());
+        options.check_component_exposes = true;
+        let result = type_check_sfc(source, &options);
+        assert!(!result
+            .diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("unexposed-member")));
+    }
+
+    #[test]
+    fn test_type_check_unexposed_member_access() {
+        let source = r#"<script setup lang="ts">
+import { useTemplateRef, onMounted } from 'vue'
+import Child from './Child.vue'
+const child = useTemplateRef('child')
+onMounted(() => {
+  child.value!.focus()
+  child.value!.secret
+  child.value!.$el
+  // child.value.hidden
+})
+</script>
+<template>
+  <Child ref="child" />
+</template>"#;
+        let mut options =
+            SfcTypeCheckOptions::new("Parent.vue").with_component_exposes("Child", child_exposes());
+        options.check_component_exposes = true;
+        let result = type_check_sfc(source, &options);
+
+        let diags: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code.as_deref() == Some("unexposed-member"))
+            .collect();
+        assert_eq!(diags.len(), 1, "{:?}", diags);
+        assert_eq!(diags[0].severity, SfcTypeSeverity::Warning);
+        assert_eq!(
+            &source[diags[0].start as usize..diags[0].end as usize],
+            "secret"
+        );
+    }
+
+    #[test]
+    fn test_type_check_component_exposes_off_by_default() {
+        let source = r#"<script setup lang="ts">
+import { ref } from 'vue'
+const child = ref()
+child.value.secret
+</script>
+<template>
+  <Child ref="child" />
+</template>"#;
+        let options =
+            SfcTypeCheckOptions::new("Parent.vue").with_component_exposes("Child", child_exposes());
+        let result = type_check_sfc(source, &options);
+        assert!(!result
+            .diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("unexposed-member")));
+    }
+
+    #[test]
+    fn test_type_check_spread_expose_is_not_checked() {
+        let source = r#"<script setup lang="ts">
+import { ref } from 'vue'
+const api = { reset() {} }
+const sub = ref()
+sub.value.reset()
+defineExpose({ focus() {}, ...api })
+</script>
+<template>
+  <Tree ref="sub" />
+</template>"#;
+        let mut options = SfcTypeCheckOptions::new("Tree.vue");
+        options.check_component_exposes = true;
+        let result = type_check_sfc(source, &options);
+        assert!(!result
+            .diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("unexposed-member")));
+    }
+
+    fn props_readonly(result: &SfcTypeCheckResult) -> Vec<&SfcTypeDiagnostic> {
+        result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code.as_deref() == Some("props-readonly"))
+            .collect()
+    }
+
+    #[test]
+    fn test_type_check_props_mutation() {
+        let source = r#"<script setup lang="ts">
+const props = defineProps<{ count: number }>()
+function reset() {
+  props.count = 1
+}
+</script>
+<template>
+  <button @click="reset">{{ props.count }}</button>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+
+        let diags = props_readonly(&result);
+        assert_eq!(diags.len(), 1, "{:?}", result.diagnostics);
+        assert_eq!(diags[0].severity, SfcTypeSeverity::Error);
+        assert_eq!(
+            &source[diags[0].start as usize..diags[0].end as usize],
+            "props.count"
+        );
+        assert!(diags[0].help.is_some());
+    }
+
+    #[test]
+    fn test_type_check_props_read() {
+        let source = r#"<script setup lang="ts">
+const props = defineProps<{ count: number }>()
+const doubled = computed(() => props.count * 2)
+const isOne = props.count === 1
+</script>
+<template>
+  <div>{{ doubled }}</div>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        assert!(
+            props_readonly(&result).is_empty(),
+            "{:?}",
+            result.diagnostics
+        );
+    }
+
+    #[test]
+    fn test_type_check_destructured_prop_reassign() {
+        let source = r#"<script setup lang="ts">
+const { count = 0 } = defineProps<{ count?: number }>()
+function bump() {
+  count++
+}
+</script>
+<template>
+  <button @click="bump">{{ count }}</button>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+
+        let diags = props_readonly(&result);
+        assert_eq!(diags.len(), 1, "{:?}", result.diagnostics);
+        assert_eq!(
+            &source[diags[0].start as usize..diags[0].end as usize],
+            "count"
+        );
+    }
+
+    #[test]
+    fn test_type_check_props_mutation_ignores_shadowing_and_comments() {
+        let source = r#"<script setup lang="ts">
+const { count } = defineProps<{ count: number }>()
+function local() {
+  let count = 0
+  count++
+}
+// count = 1
+const text = "count += 1"
+</script>
+<template>
+  <div>{{ count }}</div>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        assert!(
+            props_readonly(&result).is_empty(),
+            "{:?}",
+            result.diagnostics
+        );
+    }
+
+    fn with_code<'a>(result: &'a SfcTypeCheckResult, code: &str) -> Vec<&'a SfcTypeDiagnostic> {
+        result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code.as_deref() == Some(code))
+            .collect()
+    }
+
+    #[test]
+    fn test_unused_prop_strict() {
+        let source = r#"<script setup lang="ts">
+const props = defineProps<{ title: string; subtitle: string }>()
+</script>
+<template>
+  <h1>{{ props.title }}</h1>
+</template>"#;
+        let result = type_check_sfc(source, &SfcTypeCheckOptions::new("test.vue").strict());
+
+        let diags = with_code(&result, "unused-prop");
+        assert_eq!(diags.len(), 1, "{:?}", result.diagnostics);
+        assert_eq!(
+            &source[diags[0].start as usize..diags[0].end as usize],
+            "subtitle"
+        );
+
+        let result = type_check_sfc(source, &SfcTypeCheckOptions::new("test.vue"));
+        assert!(with_code(&result, "unused-prop").is_empty());
+    }
+
+    #[test]
+    fn test_props_used_in_template() {
+        let source = r#"<script setup lang="ts">
+const { label } = defineProps<{ title: string; label: string; color: string }>()
+</script>
+<template>
+  <h1 :title>{{ label }}</h1>
+</template>
+<style scoped>
+h1 { color: v-bind(color); }
+</style>"#;
+        let result = type_check_sfc(source, &SfcTypeCheckOptions::new("test.vue").strict());
+
+        assert!(
+            with_code(&result, "unused-prop").is_empty(),
+            "{:?}",
+            result.diagnostics
+        );
+    }
+
+    #[test]
+    fn test_props_spread_counts_as_used() {
+        let source = r#"<script setup lang="ts">
+const props = defineProps<{ title: string; subtitle: string }>()
+</script>
+<template>
+  <Header v-bind="props" />
+</template>"#;
+        let result = type_check_sfc(source, &SfcTypeCheckOptions::new("test.vue").strict());
+
+        assert!(
+            with_code(&result, "unused-prop").is_empty(),
+            "{:?}",
+            result.diagnostics
+        );
+    }
+
+    #[test]
+    fn test_unused_prop_ignores_comments_strings_and_shadowing() {
+        let source = r#"<script setup lang="ts">
+const props = defineProps<{ title: string; subtitle: string }>()
+// props.subtitle is shown by the parent
+const hint = "props.subtitle"
+function describe(props: { subtitle: string }) {
+  return props.subtitle
+}
+</script>
+<template>
+  <h1 :title="hint">{{ props.title }}</h1>
+</template>"#;
+        let result = type_check_sfc(source, &SfcTypeCheckOptions::new("test.vue").strict());
+
+        let diags = with_code(&result, "unused-prop");
+        assert_eq!(diags.len(), 1, "{:?}", result.diagnostics);
+        assert_eq!(
+            &source[diags[0].start as usize..diags[0].end as usize],
+            "subtitle"
+        );
+    }
+
+    #[test]
+    fn test_unassigned_define_props_ignores_earlier_const() {
+        let source = r#"<script setup lang="ts">
+const props = { subtitle: 'local' }
+console.log(props.subtitle)
+defineProps<{ title: string; subtitle: string }>()
+</script>
+<template>
+  <h1 v-for="item in items" :key="item">{{ title }}</h1>
+</template>"#;
+        let result = type_check_sfc(source, &SfcTypeCheckOptions::new("test.vue").strict());
+
+        let diags = with_code(&result, "unused-prop");
+        assert_eq!(diags.len(), 1, "{:?}", result.diagnostics);
+        assert_eq!(
+            &source[diags[0].start as usize..diags[0].end as usize],
+            "subtitle"
+        );
+    }
+
+    #[test]
+    fn test_unused_emit_strict() {
+        let source = r#"<script setup lang="ts">
+const emit = defineEmits<{ (e: 'save'): void; (e: 'close'): void; (e: 'reset'): void }>()
+function save() {
+  emit('save')
+}
+</script>
+<template>
+  <button @click="save">Save</button>
+  <button @click="$emit('close')">Close</button>
+</template>"#;
+        let result = type_check_sfc(source, &SfcTypeCheckOptions::new("test.vue").strict());
+
+        let diags = with_code(&result, "unused-emit");
+        assert_eq!(diags.len(), 1, "{:?}", result.diagnostics);
+        assert_eq!(
+            &source[diags[0].start as usize..diags[0].end as usize],
+            "reset"
+        );
+    }
 }
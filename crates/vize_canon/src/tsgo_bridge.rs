@@ -210,6 +210,49 @@ impl LspCompletionResponse {
     }
 }
 
+/// LSP signature help response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LspSignatureHelp {
+    /// One or more signatures
+    pub signatures: Vec<LspSignatureInformation>,
+    /// The active signature
+    #[serde(rename = "activeSignature")]
+    pub active_signature: Option<u32>,
+    /// The active parameter of the active signature
+    #[serde(rename = "activeParameter")]
+    pub active_parameter: Option<u32>,
+}
+
+/// LSP signature information.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LspSignatureInformation {
+    /// The label of this signature
+    pub label: String,
+    /// The human-readable doc-comment of this signature
+    pub documentation: Option<LspDocumentation>,
+    /// The parameters of this signature
+    pub parameters: Option<Vec<LspParameterInformation>>,
+}
+
+/// LSP parameter information.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LspParameterInformation {
+    /// The label of this parameter
+    pub label: LspParameterLabel,
+    /// The human-readable doc-comment of this parameter
+    pub documentation: Option<LspDocumentation>,
+}
+
+/// LSP parameter label - can be a substring or an offset pair into the signature label.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum LspParameterLabel {
+    /// A substring of the signature label
+    Simple(String),
+    /// Inclusive start and exclusive end offsets within the signature label
+    LabelOffsets([u32; 2]),
+}
+
 /// LSP location link (for definition responses).
 #[derive(Debug, Clone, Deserialize)]
 pub struct LspLocationLink {
@@ -1315,6 +1358,101 @@ impl TsgoBridge {
         Ok(response.into_locations())
     }
 
+    /// Get reference locations for a symbol at a position.
+    ///
+    /// Sends a textDocument/references request to tsgo, excluding the declaration.
+    pub async fn references(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<Vec<LspLocation>, TsgoBridgeError> {
+        if !self.initialized.load(Ordering::SeqCst) {
+            return Err(TsgoBridgeError::NotInitialized);
+        }
+
+        let _timer = self.profiler.timer("tsgo_references");
+
+        let params = json!({
+            "textDocument": {
+                "uri": uri
+            },
+            "position": {
+                "line": line,
+                "character": character
+            },
+            "context": {
+                "includeDeclaration": false
+            }
+        });
+
+        let result = self
+            .send_request("textDocument/references", Some(params))
+            .await?;
+
+        if let Some(timer) = _timer {
+            timer.record(&self.profiler);
+        }
+
+        // null response means no references
+        if result.is_null() {
+            return Ok(Vec::new());
+        }
+
+        serde_json::from_value(result).map_err(|e| {
+            TsgoBridgeError::CommunicationError(format!("Failed to parse references: {}", e))
+        })
+    }
+
+    /// Get signature help at a position.
+    ///
+    /// Sends a textDocument/signatureHelp request to tsgo.
+    pub async fn signature_help(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<LspSignatureHelp>, TsgoBridgeError> {
+        if !self.initialized.load(Ordering::SeqCst) {
+            return Err(TsgoBridgeError::NotInitialized);
+        }
+
+        let _timer = self.profiler.timer("tsgo_signature_help");
+
+        let params = json!({
+            "textDocument": {
+                "uri": uri
+            },
+            "position": {
+                "line": line,
+                "character": character
+            },
+            "context": {
+                "triggerKind": 1,  // Invoked
+                "isRetrigger": false
+            }
+        });
+
+        let result = self
+            .send_request("textDocument/signatureHelp", Some(params))
+            .await?;
+
+        if let Some(timer) = _timer {
+            timer.record(&self.profiler);
+        }
+
+        // null response means no signature help
+        if result.is_null() {
+            return Ok(None);
+        }
+
+        let help: LspSignatureHelp = serde_json::from_value(result).map_err(|e| {
+            TsgoBridgeError::CommunicationError(format!("Failed to parse signature help: {}", e))
+        })?;
+
+        Ok(Some(help))
+    }
+
     /// Get completion items at a position.
     ///
     /// Sends a textDocument/completion request to tsgo.
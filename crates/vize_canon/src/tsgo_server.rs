@@ -428,11 +428,7 @@ impl TsgoServer {
                     Some(4) => "hint",
                     _ => "error",
                 };
-                let code = d.code.map(|c| match c {
-                    serde_json::Value::Number(n) => format!("TS{}", n),
-                    serde_json::Value::String(s) => s,
-                    _ => format!("{:?}", c),
-                });
+                let code = crate::virtual_ts::diagnostic_code(&d.message, d.code.as_ref());
                 Diagnostic {
                     message: d.message,
                     severity: severity.to_string(),
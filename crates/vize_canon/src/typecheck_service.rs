@@ -223,7 +223,7 @@ impl TypeCheckService {
                     severity,
                     start,
                     end,
-                    code: diag.code.map(|c| format!("TS{}", c)),
+                    code: crate::virtual_ts::diagnostic_code(&diag.message, diag.code.as_ref()),
                     related: diag
                         .related_information
                         .unwrap_or_default()
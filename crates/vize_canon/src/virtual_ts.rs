@@ -10,7 +10,7 @@
 use std::ops::Range;
 use vize_croquis::{
     analysis::ComponentUsage, naming::to_pascal_case, Croquis, EventHandlerScopeData, Scope,
-    ScopeData, ScopeId, ScopeKind,
+    ScopeData, ScopeId, ScopeKind, VForScopeData,
 };
 
 /// A mapping from generated virtual TS position to SFC source position.
@@ -185,44 +185,66 @@ fn is_type_declaration_start(trimmed: &str) -> bool {
     false
 }
 
-/// Strip TypeScript `as Type` assertion from a v-for source expression.
-/// Returns (source_expression, Option<type_annotation>).
-/// e.g., "(expr) as OptionSponsor[]" -> ("(expr)", Some("OptionSponsor[]"))
-fn strip_as_assertion(source: &str) -> (&str, Option<&str>) {
-    // Look for ` as ` in the source, but be careful with nested expressions.
-    // We scan from the end to find the last top-level ` as `.
-    let trimmed = source.trim();
-
-    // Simple approach: find the last ` as ` that is not inside parentheses
-    let mut paren_depth = 0i32;
-    let bytes = trimmed.as_bytes();
-    let mut last_as_pos = None;
-
-    let mut i = 0;
-    while i < bytes.len() {
-        match bytes[i] {
-            b'(' => paren_depth += 1,
-            b')' => paren_depth -= 1,
-            b' ' if paren_depth == 0 => {
-                // Check for " as "
-                if i + 4 <= bytes.len() && &bytes[i..i + 4] == b" as " {
-                    last_as_pos = Some(i);
-                }
-            }
-            _ => {}
-        }
-        i += 1;
+/// Diagnostic code for v-for sources that cannot be iterated.
+pub const NON_ITERABLE_V_FOR: &str = "non-iterable-v-for";
+
+/// v-for helper, emitted in the template scope when the template has v-for.
+///
+/// The source must be an array, iterable, string, object or numeric range
+/// literal (`n in 10`); anything else, including a wide `number`, fails to
+/// match `__NonIterableVForSource`. Alias types are inferred from the source.
+const VFOR_HELPER: &str = r#"    // v-for helper: arrays, iterables, strings, objects and numeric range literals
+    type __NonIterableVForSource = { readonly __vize_non_iterable_v_for: never };
+    type __VForSource<T> = T extends null | undefined | string | readonly unknown[] | Iterable<unknown> ? T : T extends number ? (number extends T ? __NonIterableVForSource : T) : T extends object ? T : __NonIterableVForSource;
+    type __VForValue<T> = T extends string ? string : T extends number ? number : T extends Iterable<infer V> ? V : T extends object ? T[keyof T] : never;
+    type __VForKey<T> = T extends string | number | Iterable<unknown> ? number : T extends object ? keyof T : never;
+    function __vize_vFor<const T>(_source: T & __VForSource<T>, _render: (value: __VForValue<T>, key: __VForKey<T>, index: number) => void): void { void _source; void _render; }
+"#;
+
+/// Checks that a `v-model.number` / `v-model.trim` binding can hold the cast value.
+const V_MODEL_HELPER: &str = r#"    // v-model helper: the bound value must accept what a casting modifier produces
+    function __vize_vModel<T>(_binding: T, _value: import('vue').UnwrapRef<T>): void { void _binding; void _value; }
+"#;
+
+/// Map a tsgo diagnostic raised by a generated helper to its vize code.
+pub fn vize_diagnostic_code(message: &str) -> Option<&'static str> {
+    message
+        .contains("__NonIterableVForSource")
+        .then_some(NON_ITERABLE_V_FOR)
+}
+
+/// Code to report for a tsgo diagnostic: the vize code for errors raised by
+/// generated helpers, otherwise `TS####` (or tsgo's own string code).
+pub fn diagnostic_code(message: &str, code: Option<&serde_json::Value>) -> Option<String> {
+    if let Some(code) = vize_diagnostic_code(message) {
+        return Some(code.to_string());
     }
+    match code? {
+        serde_json::Value::Number(n) => Some(format!("TS{}", n)),
+        serde_json::Value::String(s) => Some(s.clone()),
+        _ => None,
+    }
+}
 
-    if let Some(pos) = last_as_pos {
-        let expr = trimmed[..pos].trim();
-        let type_ann = trimmed[pos + 4..].trim();
-        if !type_ann.is_empty() {
-            return (expr, Some(type_ann));
+/// Open a v-for closure: `__vize_vFor(source, (value, key, index) => {`.
+fn push_vfor_open(ts: &mut String, data: &VForScopeData, indent: &str) {
+    ts.push_str(&format!("{}__vize_vFor({}, (", indent, data.source.trim()));
+    // Destructured aliases are flattened in the scope data; emit the pattern as written
+    if let Some(ref pattern) = data.alias_pattern {
+        ts.push_str(&format!("{}) => {{\n", pattern));
+        return;
+    }
+    ts.push_str(&data.value_alias);
+    if let Some(ref key) = data.key_alias {
+        ts.push_str(&format!(", {}", key));
+    }
+    if let Some(ref index) = data.index_alias {
+        if data.key_alias.is_none() {
+            ts.push_str(", _key");
         }
+        ts.push_str(&format!(", {}", index));
     }
-
-    (trimmed, None)
+    ts.push_str(") => {\n");
 }
 
 /// Get the TypeScript event type for a DOM event name.
@@ -606,6 +628,20 @@ pub fn generate_virtual_ts_with_offsets(
 
         // Vue template context (available in template expressions)
         ts.push_str(&generate_template_context(options));
+        if summary
+            .scopes
+            .iter()
+            .any(|scope| matches!(scope.kind, ScopeKind::VFor))
+        {
+            ts.push_str(VFOR_HELPER);
+        }
+        if summary
+            .template_expressions
+            .iter()
+            .any(|expr| expr.model_modifier.is_some())
+        {
+            ts.push_str(V_MODEL_HELPER);
+        }
         ts.push('\n');
 
         // Props are available in template as variables
@@ -917,7 +953,7 @@ fn extract_interface_fields(script: &str, type_name: &str) -> Vec<String> {
 }
 
 /// Find the body of an interface or type declaration in script content.
-fn find_type_body<'a>(script: &'a str, type_name: &str) -> Option<&'a str> {
+pub(crate) fn find_type_body<'a>(script: &'a str, type_name: &str) -> Option<&'a str> {
     for pattern in &[
         format!("interface {} ", type_name),
         format!("interface {}{}", type_name, '{'),
@@ -935,7 +971,7 @@ fn find_type_body<'a>(script: &'a str, type_name: &str) -> Option<&'a str> {
 }
 
 /// Find the matching closing brace for an opening brace at `start`.
-fn find_matching_brace(s: &str, start: usize) -> usize {
+pub(crate) fn find_matching_brace(s: &str, start: usize) -> usize {
     let mut depth = 0;
     for (i, c) in s[start..].char_indices() {
         match c {
@@ -976,9 +1012,9 @@ fn generate_expression(
         ts.push_str(&format!("{}if ({}) {{\n", indent, guard));
         let gen_expr_start = ts.len();
         ts.push_str(&format!(
-            "{}  void ({}); // {}\n",
+            "{}  {} // {}\n",
             indent,
-            expr.content,
+            expression_statement(expr),
             expr.kind.as_str()
         ));
         let gen_expr_end = ts.len();
@@ -994,9 +1030,9 @@ fn generate_expression(
     } else {
         let gen_expr_start = ts.len();
         ts.push_str(&format!(
-            "{}void ({}); // {}\n",
+            "{}{} // {}\n",
             indent,
-            expr.content,
+            expression_statement(expr),
             expr.kind.as_str()
         ));
         let gen_expr_end = ts.len();
@@ -1011,6 +1047,24 @@ fn generate_expression(
     }
 }
 
+/// Statement checking a template expression.
+///
+/// A casting `v-model` modifier also checks that the binding accepts the
+/// `number` or `string` value it produces.
+fn expression_statement(expr: &vize_croquis::TemplateExpression) -> String {
+    match expr.model_modifier {
+        Some(modifier) => {
+            let value = if modifier == "number" {
+                "0 as number"
+            } else {
+                "'' as string"
+            };
+            format!("__vize_vModel(({}), {});", expr.content, value)
+        }
+        None => format!("void ({});", expr.content),
+    }
+}
+
 /// Generate component prop value checks at the given indentation level.
 fn generate_component_prop_checks(
     ts: &mut String,
@@ -1320,36 +1374,8 @@ fn generate_scope_node(
                 indent, data.value_alias, data.source
             ));
 
-            // Strip TypeScript `as Type` assertion from v-for source expression.
-            // e.g., "(expr) as OptionSponsor[]" -> "(expr)" with type annotation
-            let (source_expr, type_annotation) = strip_as_assertion(&data.source);
-
-            let is_simple_identifier = source_expr.chars().all(|c| c.is_alphanumeric() || c == '_');
-            let element_type = if let Some(ref ta) = type_annotation {
-                // Use the asserted type's element type
-                format!("{}[number]", ta)
-            } else if is_simple_identifier {
-                format!("typeof {}[number]", source_expr)
-            } else {
-                "any".to_string()
-            };
-
-            ts.push_str(&format!(
-                "{}({}).forEach(({}: {}",
-                indent, source_expr, data.value_alias, element_type
-            ));
-
-            if let Some(ref key) = data.key_alias {
-                ts.push_str(&format!(", {}: number", key));
-            }
-            if let Some(ref index) = data.index_alias {
-                if data.key_alias.is_none() {
-                    ts.push_str(", _key: number");
-                }
-                ts.push_str(&format!(", {}: number", index));
-            }
-
-            ts.push_str(") => {\n");
+            // Alias types are inferred from the source by the helper
+            push_vfor_open(ts, data, indent);
 
             // Mark v-for variables as used to avoid TS6133
             ts.push_str(&format!("{}void {};\n", inner_indent, data.value_alias));
@@ -1555,35 +1581,11 @@ fn generate_vfor_component_props_recursive(
     let inner_indent = format!("{}  ", indent);
 
     if let ScopeData::VFor(data) = scope.data() {
-        let (source_expr, type_annotation) = strip_as_assertion(&data.source);
-
-        let is_simple_identifier = source_expr.chars().all(|c| c.is_alphanumeric() || c == '_');
-        let element_type = if let Some(ref ta) = type_annotation {
-            format!("{}[number]", ta)
-        } else if is_simple_identifier {
-            format!("typeof {}[number]", source_expr)
-        } else {
-            "any".to_string()
-        };
-
         ts.push_str(&format!(
             "\n{}// Component props in v-for scope: {} in {}\n",
             indent, data.value_alias, data.source
         ));
-        ts.push_str(&format!(
-            "{}({}).forEach(({}: {}",
-            indent, source_expr, data.value_alias, element_type
-        ));
-        if let Some(ref key) = data.key_alias {
-            ts.push_str(&format!(", {}: number", key));
-        }
-        if let Some(ref index) = data.index_alias {
-            if data.key_alias.is_none() {
-                ts.push_str(", _key: number");
-            }
-            ts.push_str(&format!(", {}: number", index));
-        }
-        ts.push_str(") => {\n");
+        push_vfor_open(ts, data, indent);
 
         // Mark v-for variables as used to avoid TS6133
         ts.push_str(&format!("{}void {};\n", inner_indent, data.value_alias));
@@ -1765,10 +1767,10 @@ const items = ref([{ id: 1, name: 'Hello' }])
 
         let output = generate_virtual_ts(&summary, Some(script), Some(&root), 0);
 
-        // v-for with destructuring should have a forEach
+        // v-for with destructuring should open a v-for closure
         assert!(
-            output.code.contains(".forEach("),
-            "Should generate forEach for destructured v-for"
+            output.code.contains("__vize_vFor(items, ({ id, name }"),
+            "Should generate a v-for closure for destructured v-for"
         );
     }
 
@@ -1915,7 +1917,7 @@ const msg = ref('Hello')
 
     #[test]
     fn test_vfor_component_props_in_scope() {
-        // Component inside v-for should have prop checks inside the forEach closure
+        // Component inside v-for should have prop checks inside the v-for closure
         use vize_croquis::{Analyzer, AnalyzerOptions};
 
         let script = r#"import { ref } from 'vue'
@@ -1937,16 +1939,109 @@ const todos = ref([{ id: 1, text: 'Hello' }])
 
         let output = generate_virtual_ts(&summary, Some(script), Some(&root), 0);
 
-        // The component prop check for `:item="todo"` should be inside a forEach
+        // The component prop check for `:item="todo"` should be inside a v-for
         // closure so that `todo` is in scope
         assert!(
-            output.code.contains(".forEach("),
-            "Should have a forEach for v-for component props"
+            output.code.contains("__vize_vFor(todos, (todo) => {"),
+            "Should have a v-for closure for v-for component props"
         );
         // The prop type assertion should exist (value cast to prop type)
         assert!(
             output.code.contains("(todo) as __TodoItem_"),
-            "Should check prop value `todo` inside forEach scope"
+            "Should check prop value `todo` inside v-for scope"
+        );
+    }
+
+    fn generate_for_template(script: &str, template: &str) -> String {
+        use vize_croquis::{Analyzer, AnalyzerOptions};
+
+        let allocator = vize_carton::Bump::new();
+        let (root, _) = vize_armature::parse(&allocator, template);
+
+        let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+        analyzer.analyze_script_setup(script);
+        analyzer.analyze_template(&root);
+        let summary = analyzer.finish();
+
+        generate_virtual_ts(&summary, Some(script), Some(&root), 0).code
+    }
+
+    #[test]
+    fn test_vfor_number_source_is_checked() {
+        // A wide `number` is not a range literal, so tsgo rejects the source
+        let code = generate_for_template(
+            "const count: number = 5\n",
+            r#"<span v-for="n in count" :key="n">{{ n }}</span>"#,
+        );
+        assert!(code.contains("function __vize_vFor<const T>"), "{}", code);
+        assert!(code.contains("__vize_vFor(count, (n) => {"), "{}", code);
+        assert_eq!(
+            vize_diagnostic_code(
+                "Argument of type 'number' is not assignable to parameter of type 'number & __NonIterableVForSource'."
+            ),
+            Some(NON_ITERABLE_V_FOR)
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_code() {
+        let ts_code = serde_json::json!(2345);
+        assert_eq!(
+            diagnostic_code(
+                "Argument of type 'number' is not assignable to parameter of type 'number & __NonIterableVForSource'.",
+                Some(&ts_code)
+            )
+            .as_deref(),
+            Some(NON_ITERABLE_V_FOR)
+        );
+        assert_eq!(
+            diagnostic_code("Cannot find name 'foo'.", Some(&serde_json::json!(2304))).as_deref(),
+            Some("TS2304")
+        );
+        assert_eq!(diagnostic_code("Cannot find name 'foo'.", None), None);
+    }
+
+    #[test]
+    fn test_vfor_typed_array_source() {
+        let code = generate_for_template(
+            "const items: string[] = []\n",
+            r#"<li v-for="(item, i) in items" :key="i">{{ item.toUpperCase() }}</li>"#,
+        );
+        assert!(
+            code.contains("__vize_vFor(items, (item, i) => {"),
+            "{}",
+            code
+        );
+        assert!(!code.contains("forEach"), "{}", code);
+        assert_eq!(
+            vize_diagnostic_code("Property 'foo' does not exist on type 'string'."),
+            None
+        );
+    }
+
+    #[test]
+    fn test_vfor_without_loops_omits_helper() {
+        let code = generate_for_template("const msg = 'hi'\n", "<p>{{ msg }}</p>");
+        assert!(!code.contains("__vize_vFor"), "{}", code);
+    }
+
+    #[test]
+    fn test_v_model_modifier_is_checked() {
+        let code = generate_for_template(
+            "import { ref } from 'vue'\nconst age = ref('')\nconst name = ref('')\n",
+            r#"<input v-model.number="age" /><input v-model.trim="name" /><input v-model="age" />"#,
+        );
+        assert!(code.contains("function __vize_vModel<T>"), "{}", code);
+        assert!(
+            code.contains("__vize_vModel((age), 0 as number);"),
+            "{}",
+            code
+        );
+        assert!(
+            code.contains("__vize_vModel((name), '' as string);"),
+            "{}",
+            code
         );
+        assert!(code.contains("void (age); // VModel"), "{}", code);
     }
 }
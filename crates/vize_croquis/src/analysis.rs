@@ -44,9 +44,10 @@ use crate::provide::ProvideInjectTracker;
 use crate::reactivity::ReactivityTracker;
 use crate::setup_context::SetupContextTracker;
 use crate::types::TypeResolver;
+use crate::watch::WatcherTracker;
 use crate::{ScopeChain, SymbolTable};
 use vize_carton::{CompactString, FxHashMap, FxHashSet};
-use vize_relief::BindingType;
+use vize_relief::{BindingType, SourceLocation};
 
 /// Template-level metadata collected during analysis.
 #[derive(Debug, Clone, Default)]
@@ -54,6 +55,8 @@ pub struct TemplateInfo {
     /// Number of root elements at depth 0 in template.
     /// A value > 1 indicates multi-root component (fragments).
     pub root_element_count: usize,
+    /// Location of the second root node, when there is one.
+    pub second_root_loc: Option<SourceLocation>,
     /// Whether $attrs is referenced anywhere in the template.
     pub uses_attrs: bool,
     /// Whether v-bind="$attrs" is explicitly used (not just $attrs.class etc.)
@@ -80,6 +83,30 @@ impl TemplateInfo {
     }
 }
 
+/// Component options declared with `defineOptions()`, or with
+/// `export default { ... }` in a plain `<script>`.
+#[derive(Debug, Clone, Default)]
+pub struct ComponentOptions {
+    /// `name` option, when given as a string literal.
+    pub name: Option<CompactString>,
+    /// `inheritAttrs` option, when given as a boolean literal.
+    pub inherit_attrs: Option<bool>,
+    /// Span of the `export default` options object in a plain `<script>`.
+    pub object_span: Option<(u32, u32)>,
+    /// Whether the options object sets anything besides `name` and `inheritAttrs`.
+    pub has_other_options: bool,
+    /// Whether the `export default` is the only statement of the plain `<script>`.
+    pub is_only_statement: bool,
+}
+
+impl ComponentOptions {
+    /// Check if attribute inheritance is explicitly disabled (`inheritAttrs: false`).
+    #[inline]
+    pub fn inherit_attrs_disabled(&self) -> bool {
+        self.inherit_attrs == Some(false)
+    }
+}
+
 /// Complete semantic analysis summary for a Vue SFC.
 ///
 /// This struct aggregates all analysis results and provides a unified
@@ -104,6 +131,9 @@ pub struct Croquis {
     /// Setup context violation tracking (CSRP/memory leaks)
     pub setup_context: SetupContextTracker,
 
+    /// watch()/watchEffect() calls and their sources
+    pub watchers: WatcherTracker,
+
     /// TypeScript type resolution
     pub types: TypeResolver,
 
@@ -116,6 +146,9 @@ pub struct Croquis {
     /// Template-level metadata (root count, $attrs usage, etc.)
     pub template_info: TemplateInfo,
 
+    /// Component options from defineOptions (name, inheritAttrs)
+    pub component_options: ComponentOptions,
+
     /// Components used in template (names only, for quick lookup)
     pub used_components: FxHashSet<CompactString>,
 
@@ -146,6 +179,41 @@ pub struct Croquis {
     /// Definition spans for bindings (name -> (start, end) offset in script)
     /// Used for Go-to-Definition support.
     pub binding_spans: FxHashMap<CompactString, (u32, u32)>,
+
+    /// Parameter counts of top-level functions (name -> signature)
+    /// Used to validate template event handler calls.
+    pub function_signatures: FxHashMap<CompactString, FunctionSignature>,
+
+    /// Spans of top-level function definitions (name -> (start, end) offset in script)
+    /// Used for call hierarchy support.
+    pub function_spans: FxHashMap<CompactString, (u32, u32)>,
+
+    /// Bindings initialized from non-deterministic values (`Date.now()`, `Math.random()`, ...)
+    /// Used to flag likely SSR hydration mismatches.
+    pub impure_bindings: FxHashSet<CompactString>,
+
+    /// Components created with `defineAsyncComponent()`, by binding name
+    /// Used by SSR codegen to render them through the async-aware path.
+    pub async_components: FxHashSet<CompactString>,
+}
+
+/// Parameter list of a script function binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionSignature {
+    /// Number of required parameters
+    pub min_params: u32,
+    /// Maximum number of parameters (None when a rest parameter is present)
+    pub max_params: Option<u32>,
+    /// Source text of each parameter, including the rest parameter (e.g. `id: number`)
+    pub params: Vec<CompactString>,
+}
+
+impl FunctionSignature {
+    /// Check whether a call with `count` arguments is valid.
+    #[inline]
+    pub fn accepts(&self, count: u32) -> bool {
+        count >= self.min_params && self.max_params.is_none_or(|max| count <= max)
+    }
 }
 
 /// Information about element IDs in template (for cross-file uniqueness checking).
@@ -220,6 +288,8 @@ pub struct TemplateExpression {
     pub scope_id: crate::scope::ScopeId,
     /// v-if guard condition (if this expression is inside a v-if block)
     pub vif_guard: Option<CompactString>,
+    /// Casting modifier of a v-model (`number` or `trim`; `number` wins)
+    pub model_modifier: Option<&'static str>,
 }
 
 /// Kind of template expression.
@@ -323,6 +393,8 @@ pub struct SlotUsage {
     pub end: u32,
     /// Whether this slot has scope (v-slot:name="scope")
     pub has_scope: bool,
+    /// Slot prop keys read by an object destructuring scope (None otherwise)
+    pub prop_keys: Option<SmallVec<[CompactString; 4]>>,
 }
 
 impl Croquis {
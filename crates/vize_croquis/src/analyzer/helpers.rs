@@ -7,7 +7,7 @@
 //! - Inline callback parameter extraction
 
 use oxc_allocator::Allocator;
-use oxc_ast::ast::BindingPattern;
+use oxc_ast::ast::{BindingPattern, PropertyKey};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 use vize_carton::{smallvec, CompactString, SmallVec};
@@ -513,14 +513,12 @@ fn extract_identifiers_oxc_slow(expr: &str) -> Vec<CompactString> {
     identifiers
 }
 
-/// Parse v-for expression into variables and source
+/// Find the position of the " in " / " of " separator of a v-for expression
 #[inline]
-pub fn parse_v_for_expression(expr: &str) -> (SmallVec<[CompactString; 3]>, CompactString) {
+fn find_v_for_separator(expr: &str) -> Option<usize> {
     let bytes = expr.as_bytes();
     let len = bytes.len();
 
-    // Find " in " or " of " separator
-    let mut split_pos = None;
     let mut i = 0;
     while i + 4 <= len {
         if bytes[i] == b' '
@@ -528,13 +526,32 @@ pub fn parse_v_for_expression(expr: &str) -> (SmallVec<[CompactString; 3]>, Comp
                 || (bytes[i + 1] == b'o' && bytes[i + 2] == b'f'))
             && bytes[i + 3] == b' '
         {
-            split_pos = Some(i);
-            break;
+            return Some(i);
         }
         i += 1;
     }
+    None
+}
+
+/// Get the raw alias list of a v-for expression if any alias is destructured.
+///
+/// `({ id, name }, index) in items` gives `{ id, name }, index`.
+pub fn v_for_alias_pattern(expr: &str) -> Option<CompactString> {
+    let alias = expr[..find_v_for_separator(expr)?].trim();
+    if !alias.contains('{') && !alias.contains('[') {
+        return None;
+    }
+    let inner = match alias.strip_prefix('(').and_then(|a| a.strip_suffix(')')) {
+        Some(inner) => inner.trim(),
+        None => alias,
+    };
+    Some(CompactString::new(inner))
+}
 
-    let Some(pos) = split_pos else {
+/// Parse v-for expression into variables and source
+#[inline]
+pub fn parse_v_for_expression(expr: &str) -> (SmallVec<[CompactString; 3]>, CompactString) {
+    let Some(pos) = find_v_for_separator(expr) else {
         return (smallvec![], CompactString::new(expr.trim()));
     };
 
@@ -696,40 +713,74 @@ pub fn extract_slot_props(pattern: &str) -> SmallVec<[CompactString; 4]> {
 /// Parse complex slot props using OXC
 #[cold]
 fn extract_slot_props_with_oxc(pattern: &str) -> SmallVec<[CompactString; 4]> {
+    with_slot_pattern(pattern, |binding| {
+        let mut props = SmallVec::new();
+        extract_slot_binding_names(binding, &mut props);
+        props
+    })
+    .unwrap_or_default()
+}
+
+/// Extract the slot prop keys destructured by a v-slot expression.
+///
+/// `{ item, index: i }` gives `["item", "index"]`. Returns None when the
+/// pattern is not an object destructuring; computed keys are skipped.
+pub fn extract_slot_prop_keys(pattern: &str) -> Option<SmallVec<[CompactString; 4]>> {
+    let pattern = pattern.trim();
+    if !pattern.starts_with('{') {
+        return None;
+    }
+
+    with_slot_pattern(pattern, |binding| {
+        let BindingPattern::ObjectPattern(obj) = binding else {
+            return None;
+        };
+        Some(
+            obj.properties
+                .iter()
+                .filter_map(|prop| match &prop.key {
+                    PropertyKey::StaticIdentifier(id) => Some(CompactString::new(id.name.as_str())),
+                    PropertyKey::StringLiteral(s) => Some(CompactString::new(s.value.as_str())),
+                    _ => None,
+                })
+                .collect(),
+        )
+    })
+    .flatten()
+}
+
+/// Parse a slot pattern as the binding of `let <pattern> = x` and hand it to `f`.
+///
+/// Every destructuring-pattern helper goes through this one parser.
+/// Returns None when the pattern does not form a variable declaration.
+fn with_slot_pattern<R>(pattern: &str, f: impl FnOnce(&BindingPattern<'_>) -> R) -> Option<R> {
     let mut buffer = [0u8; 256];
     let prefix = b"let ";
     let suffix = b" = x";
 
     let total_len = prefix.len() + pattern.len() + suffix.len();
-    if total_len > buffer.len() {
-        let pattern_str = format!("let {} = x", pattern);
-        return parse_slot_pattern(&pattern_str);
-    }
-
-    buffer[..prefix.len()].copy_from_slice(prefix);
-    buffer[prefix.len()..prefix.len() + pattern.len()].copy_from_slice(pattern.as_bytes());
-    buffer[prefix.len() + pattern.len()..total_len].copy_from_slice(suffix);
+    let heap;
+    let source = if total_len > buffer.len() {
+        heap = format!("let {} = x", pattern);
+        heap.as_str()
+    } else {
+        buffer[..prefix.len()].copy_from_slice(prefix);
+        buffer[prefix.len()..prefix.len() + pattern.len()].copy_from_slice(pattern.as_bytes());
+        buffer[prefix.len() + pattern.len()..total_len].copy_from_slice(suffix);
 
-    // SAFETY: we only copy ASCII bytes
-    let pattern_str = unsafe { std::str::from_utf8_unchecked(&buffer[..total_len]) };
-    parse_slot_pattern(pattern_str)
-}
+        // SAFETY: a `&str` between two ASCII byte strings is valid UTF-8
+        unsafe { std::str::from_utf8_unchecked(&buffer[..total_len]) }
+    };
 
-/// Parse slot pattern using OXC
-fn parse_slot_pattern(pattern_str: &str) -> SmallVec<[CompactString; 4]> {
     let allocator = Allocator::default();
     let source_type = SourceType::default().with_typescript(true);
-    let ret = Parser::new(&allocator, pattern_str, source_type).parse();
-
-    let mut props = SmallVec::new();
+    let ret = Parser::new(&allocator, source, source_type).parse();
 
-    if let Some(oxc_ast::ast::Statement::VariableDeclaration(var_decl)) = ret.program.body.first() {
-        if let Some(declarator) = var_decl.declarations.first() {
-            extract_slot_binding_names(&declarator.id, &mut props);
-        }
-    }
-
-    props
+    let Some(oxc_ast::ast::Statement::VariableDeclaration(var_decl)) = ret.program.body.first()
+    else {
+        return None;
+    };
+    Some(f(&var_decl.declarations.first()?.id))
 }
 
 /// Extract binding names from slot pattern
@@ -994,6 +1045,17 @@ mod tests {
         assert_eq!(ids, vec!["cond", "a", "b"]);
     }
 
+    #[test]
+    fn test_extract_slot_prop_keys() {
+        let keys = extract_slot_prop_keys("{ item, index: i, label = '', ...rest }").unwrap();
+        assert_eq!(keys.as_slice(), ["item", "index", "label"]);
+
+        let keys = extract_slot_prop_keys("{ user: { name } }").unwrap();
+        assert_eq!(keys.as_slice(), ["user"]);
+
+        assert!(extract_slot_prop_keys("slotProps").is_none());
+    }
+
     #[test]
     fn test_is_component_tag() {
         assert!(is_component_tag("MyComponent"));
@@ -29,8 +29,8 @@ mod helpers;
 mod template;
 
 pub use helpers::{
-    extract_identifiers_oxc, extract_inline_callback_params, extract_slot_props,
-    is_builtin_directive, is_component_tag, is_keyword, parse_v_for_expression,
+    extract_identifiers_oxc, extract_inline_callback_params, extract_slot_prop_keys,
+    extract_slot_props, is_builtin_directive, is_component_tag, is_keyword, parse_v_for_expression,
 };
 
 use crate::analysis::Croquis;
@@ -180,7 +180,13 @@ impl Analyzer {
         self.summary.scopes = result.scopes;
         self.summary.provide_inject = result.provide_inject;
         self.summary.binding_spans = result.binding_spans;
+        self.summary.function_signatures = result.function_signatures;
+        self.summary.function_spans = result.function_spans;
+        self.summary.impure_bindings = result.impure_bindings;
+        self.summary.async_components = result.async_components;
         self.summary.setup_context = result.setup_context;
+        self.summary.watchers = result.watchers;
+        self.summary.component_options = result.component_options;
 
         self
     }
@@ -205,7 +211,13 @@ impl Analyzer {
         self.summary.scopes = result.scopes;
         self.summary.provide_inject = result.provide_inject;
         self.summary.binding_spans = result.binding_spans;
+        self.summary.function_signatures = result.function_signatures;
+        self.summary.function_spans = result.function_spans;
+        self.summary.impure_bindings = result.impure_bindings;
+        self.summary.async_components = result.async_components;
         self.summary.setup_context = result.setup_context;
+        self.summary.watchers = result.watchers;
+        self.summary.component_options = result.component_options;
 
         self
     }
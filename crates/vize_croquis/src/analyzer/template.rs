@@ -8,7 +8,8 @@
 //! - Element IDs for cross-file uniqueness checking
 
 use crate::analysis::{
-    ComponentUsage, ElementIdInfo, ElementIdKind, EventListener, PassedProp, UndefinedRef,
+    ComponentUsage, ElementIdInfo, ElementIdKind, EventListener, PassedProp, SlotUsage,
+    UndefinedRef,
 };
 use crate::scope::{CallbackScopeData, EventHandlerScopeData, VForScopeData, VSlotScopeData};
 use crate::ScopeBinding;
@@ -19,8 +20,9 @@ use vize_relief::ast::{
 use vize_relief::BindingType;
 
 use super::helpers::{
-    extract_identifiers_oxc, extract_inline_callback_params, extract_slot_props,
-    is_builtin_directive, is_component_tag, is_keyword, parse_v_for_expression,
+    extract_identifiers_oxc, extract_inline_callback_params, extract_slot_prop_keys,
+    extract_slot_props, is_builtin_directive, is_component_tag, is_keyword, parse_v_for_expression,
+    v_for_alias_pattern,
 };
 use super::Analyzer;
 
@@ -65,11 +67,14 @@ impl Analyzer {
             return self;
         }
 
-        // Count root-level elements
+        // Count root nodes, remembering where a second one starts
         let mut root_element_count = 0;
         for child in root.children.iter() {
-            if Self::is_element_child(child) {
+            if Self::is_template_root(child) {
                 root_element_count += 1;
+                if root_element_count == 2 {
+                    self.summary.template_info.second_root_loc = Some(child.loc().clone());
+                }
             }
         }
         self.summary.template_info.root_element_count = root_element_count;
@@ -86,6 +91,22 @@ impl Analyzer {
         self
     }
 
+    /// Check if a template child renders as its own root node
+    ///
+    /// In an untransformed AST a `v-else`/`v-else-if` branch is a sibling
+    /// element that replaces the preceding `v-if`, so only the head of a chain
+    /// counts. Non-whitespace text and interpolations make a fragment too.
+    fn is_template_root(node: &TemplateChildNode<'_>) -> bool {
+        match node {
+            TemplateChildNode::Element(el) => !el.props.iter().any(|prop| {
+                matches!(prop, PropNode::Directive(dir) if matches!(dir.name.as_str(), "else" | "else-if"))
+            }),
+            TemplateChildNode::Text(text) => !text.content.trim().is_empty(),
+            TemplateChildNode::Interpolation(_) => true,
+            _ => Self::is_element_child(node),
+        }
+    }
+
     /// Check if a template child is an actual element
     pub(super) fn is_element_child(node: &TemplateChildNode<'_>) -> bool {
         match node {
@@ -133,6 +154,7 @@ impl Analyzer {
                             end: loc.end.offset,
                             scope_id,
                             vif_guard: self.current_vif_guard(),
+                            model_modifier: None,
                         });
                 }
                 if self.options.detect_undefined && self.script_analyzed {
@@ -223,8 +245,13 @@ impl Analyzer {
                         };
                         let (vars, source) = parse_v_for_expression(content);
                         if !vars.is_empty() {
-                            for_scope =
-                                Some((vars, source, el.loc.start.offset, el.loc.end.offset, None));
+                            for_scope = Some((
+                                vars,
+                                source,
+                                el.loc.start.offset,
+                                el.loc.end.offset,
+                                v_for_alias_pattern(content),
+                            ));
                         }
                     }
                 }
@@ -314,7 +341,7 @@ impl Analyzer {
             };
 
         // Enter v-for scope if present
-        let for_vars_count = if let Some((vars, source, start, end, _)) = for_scope {
+        let for_vars_count = if let Some((vars, source, start, end, alias_pattern)) = for_scope {
             let count = vars.len();
 
             if count > 0 {
@@ -330,6 +357,7 @@ impl Analyzer {
                         index_alias: vars.get(2).cloned(),
                         source,
                         key_expression,
+                        alias_pattern,
                     },
                     start,
                     end,
@@ -379,6 +407,7 @@ impl Analyzer {
                                     end: loc.end.offset,
                                     scope_id,
                                     vif_guard: self.current_vif_guard(),
+                                    model_modifier: None,
                                 },
                             );
                         }
@@ -402,6 +431,7 @@ impl Analyzer {
                                     end: loc.end.offset,
                                     scope_id,
                                     vif_guard: self.current_vif_guard(),
+                                    model_modifier: None,
                                 },
                             );
                         }
@@ -417,6 +447,15 @@ impl Analyzer {
                             };
                             let loc = exp.loc();
                             let scope_id = self.summary.scopes.current_id();
+                            let has_modifier =
+                                |name: &str| dir.modifiers.iter().any(|m| m.content == name);
+                            let model_modifier = if has_modifier("number") {
+                                Some("number")
+                            } else if has_modifier("trim") {
+                                Some("trim")
+                            } else {
+                                None
+                            };
                             self.summary.template_expressions.push(
                                 crate::analysis::TemplateExpression {
                                     content: CompactString::new(content),
@@ -425,6 +464,7 @@ impl Analyzer {
                                     end: loc.end.offset,
                                     scope_id,
                                     vif_guard: self.current_vif_guard(),
+                                    model_modifier,
                                 },
                             );
                         }
@@ -530,6 +570,7 @@ impl Analyzer {
                         end: loc.end.offset,
                         scope_id,
                         vif_guard: self.current_vif_guard(),
+                        model_modifier: None,
                     });
             }
 
@@ -641,6 +682,7 @@ impl Analyzer {
                             end: dir.loc.end.offset,
                             scope_id,
                             vif_guard: self.current_vif_guard(),
+                            model_modifier: None,
                         });
                 }
 
@@ -704,6 +746,7 @@ impl Analyzer {
                                 end: dir.loc.end.offset,
                                 scope_id,
                                 vif_guard: self.current_vif_guard(),
+                                model_modifier: None,
                             },
                         );
                     }
@@ -727,6 +770,7 @@ impl Analyzer {
                                 end: dir.loc.end.offset,
                                 scope_id,
                                 vif_guard: self.current_vif_guard(),
+                                model_modifier: None,
                             },
                         );
                     }
@@ -739,12 +783,46 @@ impl Analyzer {
         }
     }
 
-    /// Collect props and events from element for component usage tracking.
+    /// Collect props, events and slots from element for component usage tracking.
     pub(super) fn collect_component_props_events(
         &self,
         el: &ElementNode<'_>,
         usage: &mut ComponentUsage,
     ) {
+        // v-slot on the component itself, or on its `<template>` children
+        let slot_hosts = std::iter::once(el).chain(el.children.iter().filter_map(|c| match c {
+            TemplateChildNode::Element(t) if t.tag == "template" => Some(&**t),
+            _ => None,
+        }));
+        for host in slot_hosts {
+            for prop in &host.props {
+                let PropNode::Directive(dir) = prop else {
+                    continue;
+                };
+                if dir.name != "slot" {
+                    continue;
+                }
+                let name = match &dir.arg {
+                    None => CompactString::const_new("default"),
+                    Some(ExpressionNode::Simple(s)) if s.is_static => s.content.clone(),
+                    // Dynamic slot names can't be matched statically
+                    _ => continue,
+                };
+                let pattern = dir.exp.as_ref().map(|e| match e {
+                    ExpressionNode::Simple(s) => s.content.as_str(),
+                    ExpressionNode::Compound(c) => c.loc.source.as_str(),
+                });
+                usage.slots.push(SlotUsage {
+                    name,
+                    scope_vars: pattern.map(extract_slot_props).unwrap_or_default(),
+                    start: dir.loc.start.offset,
+                    end: dir.loc.end.offset,
+                    has_scope: pattern.is_some(),
+                    prop_keys: pattern.and_then(extract_slot_prop_keys),
+                });
+            }
+        }
+
         for prop in &el.props {
             match prop {
                 PropNode::Attribute(attr) => {
@@ -915,6 +993,7 @@ impl Analyzer {
                     index_alias: vars_added.get(2).cloned(),
                     source: source_content,
                     key_expression: None,
+                    alias_pattern: None,
                 },
                 for_node.loc.start.offset,
                 for_node.loc.end.offset,
@@ -12,6 +12,11 @@
 //! Note: For directive checking, use `vize_carton::is_builtin_directive` which
 //! provides the complete list for compilation purposes.
 
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{CallExpression, Expression, NewExpression};
+use oxc_ast_visit::{walk, Visit};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
 use phf::phf_set;
 
 // =============================================================================
@@ -147,6 +152,15 @@ static GLOBAL_ALLOWLIST_SET: phf::Set<&'static str> = phf_set! {
     "_toNumber",
 };
 
+/// Calls (`object.method()`) whose results differ between server and client renders.
+static NONDETERMINISTIC_CALLS: &[(&str, &str)] = &[
+    ("Date", "now"),
+    ("Math", "random"),
+    ("crypto", "randomUUID"),
+    ("crypto", "getRandomValues"),
+    ("performance", "now"),
+];
+
 // =============================================================================
 // Lookup Functions
 // =============================================================================
@@ -188,6 +202,54 @@ pub fn is_global_allowed(name: &str) -> bool {
     GLOBAL_ALLOWLIST_SET.contains(name)
 }
 
+/// Check if an expression produces non-deterministic values (`Date.now()`, `Math.random()`, ...).
+pub fn is_nondeterministic_expression(content: &str) -> bool {
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path("expr.ts").unwrap_or_default();
+    match Parser::new(&allocator, content, source_type).parse_expression() {
+        Ok(expr) => contains_nondeterministic_call(&expr),
+        Err(_) => false,
+    }
+}
+
+/// Check if a parsed expression calls `Date.now()`, `Math.random()`, `new Date()`, ...
+pub fn contains_nondeterministic_call(expr: &Expression<'_>) -> bool {
+    let mut finder = NondeterministicCallFinder { found: false };
+    finder.visit_expression(expr);
+    finder.found
+}
+
+/// Visitor looking for calls listed in `NONDETERMINISTIC_CALLS`
+struct NondeterministicCallFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for NondeterministicCallFinder {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Expression::StaticMemberExpression(member) = &call.callee {
+            if let Expression::Identifier(object) = &member.object {
+                let callee = (object.name.as_str(), member.property.name.as_str());
+                if NONDETERMINISTIC_CALLS.contains(&callee) {
+                    self.found = true;
+                    return;
+                }
+            }
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_new_expression(&mut self, new: &NewExpression<'a>) {
+        // `new Date()` reads the clock; `new Date(value)` does not
+        if matches!(&new.callee, Expression::Identifier(id) if id.name == "Date")
+            && new.arguments.is_empty()
+        {
+            self.found = true;
+            return;
+        }
+        walk::walk_new_expression(self, new);
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -248,4 +310,19 @@ mod tests {
         // Random identifiers
         assert!(!is_global_allowed("myVar"));
     }
+
+    #[test]
+    fn test_nondeterministic_expression() {
+        assert!(is_nondeterministic_expression("Date.now()"));
+        assert!(is_nondeterministic_expression("ref(Math.random() * 10)"));
+        assert!(is_nondeterministic_expression("new Date().getFullYear()"));
+        assert!(!is_nondeterministic_expression("new Date(props.timestamp)"));
+        assert!(!is_nondeterministic_expression("count.value + 1"));
+        // Only real calls count, not text that looks like one
+        assert!(!is_nondeterministic_expression("'Date.now()'"));
+        assert!(!is_nondeterministic_expression("myDate.now()"));
+        assert!(is_nondeterministic_expression(
+            "items.map(() => Math.random())"
+        ));
+    }
 }
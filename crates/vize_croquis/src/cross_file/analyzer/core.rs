@@ -3,9 +3,11 @@
 use super::super::analyzers;
 use super::super::graph::{DependencyEdge, DependencyGraph, ModuleNode};
 use super::super::registry::{FileId, ModuleRegistry};
+use super::resolve::PathAliases;
 use super::types::{CrossFileOptions, CrossFileResult, CrossFileStats};
 use crate::{Analyzer, AnalyzerOptions, Croquis};
 use std::path::Path;
+use vize_carton::FxHashMap;
 
 /// Cross-file analyzer for Vue projects.
 pub struct CrossFileAnalyzer {
@@ -17,6 +19,10 @@ pub struct CrossFileAnalyzer {
     graph: DependencyGraph,
     /// Single-file analyzer options.
     single_file_options: AnalyzerOptions,
+    /// tsconfig path aliases used for import resolution.
+    aliases: PathAliases,
+    /// Number of project imports that could not be resolved, per file.
+    unresolved_imports: FxHashMap<FileId, usize>,
 }
 
 impl CrossFileAnalyzer {
@@ -27,16 +33,23 @@ impl CrossFileAnalyzer {
             registry: ModuleRegistry::new(),
             graph: DependencyGraph::new(),
             single_file_options: AnalyzerOptions::full(),
+            aliases: PathAliases::default(),
+            unresolved_imports: FxHashMap::default(),
         }
     }
 
     /// Create with a project root directory.
+    ///
+    /// `compilerOptions.baseUrl` and `compilerOptions.paths` from the project's
+    /// tsconfig.json are used to resolve aliased imports such as `@/components/Foo.vue`.
     pub fn with_project_root(options: CrossFileOptions, root: impl AsRef<Path>) -> Self {
         Self {
             options,
             registry: ModuleRegistry::with_project_root(root.as_ref()),
             graph: DependencyGraph::new(),
             single_file_options: AnalyzerOptions::full(),
+            aliases: PathAliases::load(root.as_ref()),
+            unresolved_imports: FxHashMap::default(),
         }
     }
 
@@ -77,45 +90,28 @@ impl CrossFileAnalyzer {
             self.graph.add_node(node);
         }
 
-        // Update dependencies based on imports (get from registry)
-        if let Some(entry) = self.registry.get(file_id) {
-            // Collect data we need before calling update_dependencies
-            let imports_data: Vec<_> = entry
-                .analysis
-                .scopes
-                .iter()
-                .filter(|s| s.kind == crate::scope::ScopeKind::ExternalModule)
-                .filter_map(|s| {
-                    if let crate::scope::ScopeData::ExternalModule(data) = s.data() {
-                        Some((data.source.clone(), data.is_type_only))
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            let used_components: Vec<_> = entry.analysis.used_components.iter().cloned().collect();
-
-            // Now update dependencies
-            for (source, is_type_only) in imports_data {
-                if let Some(target_id) = self.resolve_import(&source) {
-                    // TODO: Distinguish type-only imports when tracking is needed
-                    let edge_type = if is_type_only {
-                        DependencyEdge::TypeImport
-                    } else {
-                        DependencyEdge::Import
-                    };
-                    self.graph.add_edge(file_id, target_id, edge_type);
-                }
-            }
+        self.link_dependencies(file_id);
 
-            for component in used_components {
-                if let Some(target_id) = self.graph.find_by_component(component.as_str()) {
-                    self.graph
-                        .add_edge(file_id, target_id, DependencyEdge::ComponentUsage);
-                }
-            }
-        }
+        file_id
+    }
+
+    /// Re-analyze a single file after an edit.
+    ///
+    /// Only this file is re-parsed. Its registry entry and outgoing dependency
+    /// edges are replaced, while edges from other files importing it are kept.
+    /// Files that are not registered yet are added as with [`Self::add_file`].
+    pub fn update_file(&mut self, path: impl AsRef<Path>, new_source: &str) -> FileId {
+        let path = path.as_ref();
+        let Some(file_id) = self.registry.get_id(path) else {
+            return self.add_file(path, new_source);
+        };
+
+        let analysis = self.analyze_single_file(new_source, path);
+        self.registry.register(path, new_source, analysis);
+
+        // Drop stale outgoing edges (and cached cycles) before relinking
+        self.graph.remove_outgoing_edges(file_id);
+        self.link_dependencies(file_id);
 
         file_id
     }
@@ -165,44 +161,7 @@ impl CrossFileAnalyzer {
             self.graph.add_node(node);
         }
 
-        // Update dependencies based on imports (get from registry)
-        if let Some(entry) = self.registry.get(file_id) {
-            // Collect data we need before calling update_dependencies
-            let imports_data: Vec<_> = entry
-                .analysis
-                .scopes
-                .iter()
-                .filter(|s| s.kind == crate::scope::ScopeKind::ExternalModule)
-                .filter_map(|s| {
-                    if let crate::scope::ScopeData::ExternalModule(data) = s.data() {
-                        Some((data.source.clone(), data.is_type_only))
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            let used_components: Vec<_> = entry.analysis.used_components.iter().cloned().collect();
-
-            // Now update dependencies
-            for (source, is_type_only) in imports_data {
-                if let Some(target_id) = self.resolve_import(&source) {
-                    let edge_type = if is_type_only {
-                        DependencyEdge::TypeImport
-                    } else {
-                        DependencyEdge::Import
-                    };
-                    self.graph.add_edge(file_id, target_id, edge_type);
-                }
-            }
-
-            for component in used_components {
-                if let Some(target_id) = self.graph.find_by_component(component.as_str()) {
-                    self.graph
-                        .add_edge(file_id, target_id, DependencyEdge::ComponentUsage);
-                }
-            }
-        }
+        self.link_dependencies(file_id);
 
         file_id
     }
@@ -249,6 +208,13 @@ impl CrossFileAnalyzer {
         if self.options.circular_dependencies {
             self.graph.detect_circular_dependencies();
             result.circular_deps = self.graph.circular_dependencies().to_vec();
+            result
+                .diagnostics
+                .extend(analyzers::analyze_circular_dependencies(
+                    &self.registry,
+                    &self.graph,
+                    |specifier, from| self.resolve_import(specifier, from),
+                ));
         }
 
         // Run enabled analyzers
@@ -276,6 +242,11 @@ impl CrossFileAnalyzer {
             result.diagnostics.extend(diags);
         }
 
+        if self.options.unused_provide {
+            let diags = analyzers::analyze_unused_provides(&self.registry, &self.graph);
+            result.diagnostics.extend(diags);
+        }
+
         if self.options.unique_ids {
             let (issues, diags) = analyzers::analyze_element_ids(&self.registry);
             result.unique_id_issues = issues;
@@ -322,6 +293,12 @@ impl CrossFileAnalyzer {
             result.diagnostics.extend(diags);
         }
 
+        if self.options.slot_validation {
+            let (issues, diags) = analyzers::analyze_slot_validation(&self.registry, &self.graph);
+            result.slot_validation_issues = issues;
+            result.diagnostics.extend(diags);
+        }
+
         // Calculate statistics
         let error_count = result.diagnostics.iter().filter(|d| d.is_error()).count();
         let warning_count = result.diagnostics.iter().filter(|d| d.is_warning()).count();
@@ -335,6 +312,7 @@ impl CrossFileAnalyzer {
             files_analyzed: self.registry.len(),
             vue_components: self.registry.vue_components().count(),
             dependency_edges: self.count_edges(),
+            unresolved_imports: self.unresolved_imports.values().sum(),
             error_count,
             warning_count,
             info_count: result.diagnostics.len() - error_count - warning_count,
@@ -370,6 +348,7 @@ impl CrossFileAnalyzer {
     pub fn clear(&mut self) {
         self.registry.clear();
         self.graph = DependencyGraph::new();
+        self.unresolved_imports.clear();
     }
 
     // === Private methods ===
@@ -395,13 +374,74 @@ impl CrossFileAnalyzer {
         analyzer.finish()
     }
 
-    fn resolve_import(&self, specifier: &str) -> Option<FileId> {
-        // Simple resolution - check if we have this file in the registry
-        // A full implementation would use import_resolver
+    /// Add outgoing import and component usage edges for a registered file.
+    fn link_dependencies(&mut self, file_id: FileId) {
+        let Some(entry) = self.registry.get(file_id) else {
+            return;
+        };
+
+        // Collect data up front to release the registry borrow
+        let from_path = entry.path.clone();
+        let imports_data: Vec<_> = entry
+            .analysis
+            .scopes
+            .iter()
+            .filter(|s| s.kind == crate::scope::ScopeKind::ExternalModule)
+            .filter_map(|s| {
+                if let crate::scope::ScopeData::ExternalModule(data) = s.data() {
+                    Some((data.source.clone(), data.is_type_only))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let used_components: Vec<_> = entry.analysis.used_components.iter().cloned().collect();
+
+        // Now update dependencies
+        let mut unresolved = 0;
+        for (source, is_type_only) in imports_data {
+            if let Some(target_id) = self.resolve_import(&source, &from_path) {
+                let edge_type = if is_type_only {
+                    DependencyEdge::TypeImport
+                } else {
+                    DependencyEdge::Import
+                };
+                self.graph.add_edge(file_id, target_id, edge_type);
+            } else if self.aliases.is_project_specifier(&source) {
+                unresolved += 1;
+            }
+        }
+        if unresolved > 0 {
+            self.unresolved_imports.insert(file_id, unresolved);
+        } else {
+            self.unresolved_imports.remove(&file_id);
+        }
+
+        for component in used_components {
+            if let Some(target_id) = self.graph.find_by_component(component.as_str()) {
+                self.graph
+                    .add_edge(file_id, target_id, DependencyEdge::ComponentUsage);
+            }
+        }
+    }
+
+    /// Resolve an import specifier to a registered file.
+    ///
+    /// Relative specifiers are resolved against the importing file, aliased
+    /// ones via tsconfig `paths`/`baseUrl`. Other specifiers fall back to
+    /// matching by bare filename.
+    fn resolve_import(&self, specifier: &str, from_file: &Path) -> Option<FileId> {
+        if let Some(id) = self
+            .aliases
+            .candidates(specifier, from_file)
+            .iter()
+            .find_map(|candidate| self.registry.get_id(candidate))
+        {
+            return Some(id);
+        }
 
-        // Handle relative imports
         if specifier.starts_with('.') {
-            // Would need current file context to resolve
             return None;
         }
 
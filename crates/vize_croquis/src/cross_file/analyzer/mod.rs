@@ -4,6 +4,7 @@
 //! and dependency graph.
 
 mod core;
+mod resolve;
 mod types;
 
 pub use core::CrossFileAnalyzer;
@@ -33,6 +34,7 @@ mod tests {
         let options = CrossFileOptions::strict();
         assert!(options.component_resolution);
         assert!(options.props_validation);
+        assert!(options.slot_validation);
         assert!(options.circular_dependencies);
         // Other options should be disabled
         assert!(!options.fallthrough_attrs);
@@ -78,6 +80,225 @@ mod tests {
         assert!(result.circular_deps.is_empty());
     }
 
+    #[test]
+    fn test_resolve_tsconfig_alias_import() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("tsconfig.json"),
+            r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@/*": ["src/*"] } } }"#,
+        )
+        .unwrap();
+
+        let mut analyzer =
+            CrossFileAnalyzer::with_project_root(CrossFileOptions::strict(), dir.path());
+        let foo = analyzer.add_file(Path::new("src/components/Foo.vue"), "const a = 1");
+        let app = analyzer.add_file(
+            Path::new("src/App.vue"),
+            "import Foo from '@/components/Foo.vue'\nimport Missing from '@/components/Missing.vue'\nimport { ref } from 'vue'",
+        );
+
+        let deps: Vec<_> = analyzer
+            .graph()
+            .dependencies(app)
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(deps, vec![foo]);
+
+        // Only the aliased import counts as unresolved; `vue` is a package
+        let result = analyzer.analyze();
+        assert_eq!(result.stats.unresolved_imports, 1);
+    }
+
+    #[test]
+    fn test_resolve_tsconfig_alias_prefers_longest_prefix() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("tsconfig.json"),
+            r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@/*": ["src/*"], "@/components/*": ["lib/components/*"] } } }"#,
+        )
+        .unwrap();
+
+        let mut analyzer =
+            CrossFileAnalyzer::with_project_root(CrossFileOptions::strict(), dir.path());
+        analyzer.add_file(Path::new("src/components/Foo.vue"), "const a = 1");
+        let lib_foo = analyzer.add_file(Path::new("lib/components/Foo.vue"), "const a = 1");
+        let app = analyzer.add_file(
+            Path::new("src/App.vue"),
+            "import Foo from '@/components/Foo.vue'",
+        );
+
+        let deps: Vec<_> = analyzer
+            .graph()
+            .dependencies(app)
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(deps, vec![lib_foo]);
+    }
+
+    #[test]
+    fn test_resolve_relative_import() {
+        let mut analyzer = CrossFileAnalyzer::new(CrossFileOptions::strict());
+        let button = analyzer.add_file(Path::new("src/components/Button.vue"), "const a = 1");
+        let types = analyzer.add_file(Path::new("src/types.ts"), "export type Size = 'sm'");
+        let card = analyzer.add_file(
+            Path::new("src/components/Card.vue"),
+            "import Button from './Button.vue'\nimport type { Size } from '../types'",
+        );
+
+        let deps: Vec<_> = analyzer.graph().dependencies(card).collect();
+        assert_eq!(
+            deps,
+            vec![
+                (button, crate::cross_file::DependencyEdge::Import),
+                (types, crate::cross_file::DependencyEdge::TypeImport),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_circular_dependency_diagnostic() {
+        let mut analyzer = CrossFileAnalyzer::new(CrossFileOptions::strict());
+
+        analyzer.add_file(Path::new("A.vue"), "import B from './B.vue'");
+        analyzer.add_file(Path::new("B.vue"), "import C from './C.vue'");
+        analyzer.add_file(Path::new("C.vue"), "import A from './A.vue'");
+        // A was added before B existed; relink it now that all files are registered
+        analyzer.update_file(Path::new("A.vue"), "import B from './B.vue'");
+
+        let result = analyzer.analyze();
+        assert_eq!(result.circular_deps.len(), 1);
+
+        let cycles: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| {
+                matches!(
+                    d.kind,
+                    crate::cross_file::diagnostics::CrossFileDiagnosticKind::CircularDependency { .. }
+                )
+            })
+            .collect();
+        assert_eq!(cycles.len(), 1);
+        assert!(cycles[0].is_error());
+        for name in ["A.vue", "B.vue", "C.vue"] {
+            assert!(cycles[0].message.contains(name), "{}", cycles[0].message);
+        }
+    }
+
+    #[test]
+    fn test_type_only_circular_dependency_is_warning() {
+        let mut analyzer = CrossFileAnalyzer::new(CrossFileOptions::strict());
+
+        analyzer.add_file(Path::new("a.ts"), "import type { B } from './b'");
+        analyzer.add_file(Path::new("b.ts"), "import { a } from './a'");
+        analyzer.update_file(Path::new("a.ts"), "import type { B } from './b'");
+
+        let result = analyzer.analyze();
+        let cycle = result
+            .diagnostics
+            .iter()
+            .find(|d| {
+                matches!(
+                    d.kind,
+                    crate::cross_file::diagnostics::CrossFileDiagnosticKind::CircularDependency { .. }
+                )
+            })
+            .expect("cycle diagnostic");
+        assert!(cycle.is_warning());
+    }
+
+    #[test]
+    fn test_recursive_components_are_not_circular_imports() {
+        let mut analyzer = CrossFileAnalyzer::new(CrossFileOptions::strict());
+
+        // Tree renders TreeNode, which renders Tree again
+        for (name, uses) in [("Tree", "TreeNode"), ("TreeNode", "Tree")] {
+            let mut file_analyzer = crate::Analyzer::with_options(AnalyzerOptions::full());
+            file_analyzer.analyze_script_setup("const a = 1");
+            file_analyzer
+                .croquis_mut()
+                .used_components
+                .insert(vize_carton::CompactString::new(uses));
+            analyzer.add_file_with_analysis(
+                Path::new(&format!("{}.vue", name)),
+                "script content",
+                file_analyzer.finish(),
+            );
+        }
+
+        let result = analyzer.analyze();
+        assert!(!result.diagnostics.iter().any(|d| matches!(
+            d.kind,
+            crate::cross_file::diagnostics::CrossFileDiagnosticKind::CircularDependency { .. }
+        )));
+    }
+
+    #[test]
+    fn test_circular_dependency_spans_use_resolved_imports() {
+        let mut analyzer = CrossFileAnalyzer::new(CrossFileOptions::strict());
+
+        let x_source = "import A from './a/Button.vue'\nimport B from './b/Button.vue'";
+        let b_source = "import X from '../X.vue'";
+        analyzer.add_file(Path::new("a/Button.vue"), "const a = 1");
+        analyzer.add_file(Path::new("b/Button.vue"), b_source);
+        let x = analyzer.add_file(Path::new("X.vue"), x_source);
+        analyzer.update_file(Path::new("b/Button.vue"), b_source);
+
+        let result = analyzer.analyze();
+        let cycle = result
+            .diagnostics
+            .iter()
+            .find(|d| {
+                matches!(
+                    d.kind,
+                    crate::cross_file::diagnostics::CrossFileDiagnosticKind::CircularDependency { .. }
+                )
+            })
+            .expect("cycle diagnostic");
+
+        // Both the closing import and the related import name the cycle's files
+        let span_text = |file, start: u32| {
+            let source = if file == x { x_source } else { b_source };
+            source[start as usize..].lines().next().unwrap().to_string()
+        };
+        let primary = span_text(cycle.primary_file, cycle.primary_offset);
+        let &(related_file, related_offset, _) = &cycle.related_files[0];
+        let related = span_text(related_file, related_offset);
+        for line in [primary, related] {
+            assert!(
+                line.contains("'./b/Button.vue'") || line.contains("'../X.vue'"),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn test_update_file_relinks_outgoing_edges() {
+        let mut analyzer = CrossFileAnalyzer::new(CrossFileOptions::strict());
+
+        let b = analyzer.add_file(Path::new("B.vue"), "<script setup>\n</script>");
+        let c = analyzer.add_file(Path::new("C.vue"), "<script setup>\n</script>");
+        let a = analyzer.add_file(Path::new("A.vue"), "import B from 'B.vue'");
+        let d = analyzer.add_file(Path::new("D.vue"), "import A from 'A.vue'");
+
+        let deps: Vec<_> = analyzer.graph().dependencies(a).map(|(id, _)| id).collect();
+        assert_eq!(deps, vec![b]);
+
+        let updated = analyzer.update_file(Path::new("A.vue"), "import C from 'C.vue'");
+        assert_eq!(updated, a);
+        assert_eq!(analyzer.registry().len(), 4);
+
+        // Outgoing edges replaced
+        let deps: Vec<_> = analyzer.graph().dependencies(a).map(|(id, _)| id).collect();
+        assert_eq!(deps, vec![c]);
+        assert_eq!(analyzer.graph().dependents(b).count(), 0);
+
+        // Incoming edge from D preserved
+        let dependents: Vec<_> = analyzer.graph().dependents(a).map(|(id, _)| id).collect();
+        assert_eq!(dependents, vec![d]);
+    }
+
     // === Provide/Inject Tests ===
     // NOTE: CrossFileAnalyzer.analyze_single_file doesn't parse SFC tags,
     // so we use .ts extension to pass raw script content
@@ -659,10 +880,13 @@ const { name } = inject('user')"#;
     fn test_provide_inject_with_component_usage_edge() {
         use crate::cross_file::diagnostics::CrossFileDiagnosticKind;
 
-        let mut analyzer =
-            CrossFileAnalyzer::new(CrossFileOptions::default().with_provide_inject(true));
+        let mut analyzer = CrossFileAnalyzer::new(
+            CrossFileOptions::default()
+                .with_provide_inject(true)
+                .with_unused_provide(true),
+        );
 
-        // App.vue provides 'theme' and 'user'
+        // App.vue provides 'theme', 'user' and 'locale'; Child injects the first two
         // App uses Child component in template (simulated via used_components)
         let mut app_analyzer = crate::Analyzer::with_options(AnalyzerOptions::full());
         app_analyzer.analyze_script_setup(
@@ -672,7 +896,8 @@ const theme = ref('dark')
 const user = ref({ name: 'Test' })
 
 provide('theme', theme)
-provide('user', user)"#,
+provide('user', user)
+provide('locale', 'en')"#,
         );
         // Manually add used component (normally from template analysis)
         app_analyzer
@@ -730,21 +955,83 @@ const user = inject('user')"#,
                 .collect::<Vec<_>>()
         );
 
-        // Should have NO unused provide warnings
-        let unused_provide_warnings: Vec<_> = result
+        // Only 'locale' is reported as an unused provide
+        let unused_provide_keys: Vec<_> = result
             .diagnostics
             .iter()
-            .filter(|d| matches!(d.kind, CrossFileDiagnosticKind::UnusedProvide { .. }))
+            .filter_map(|d| match &d.kind {
+                CrossFileDiagnosticKind::UnusedProvide { key } => Some(key.as_str()),
+                _ => None,
+            })
             .collect();
         assert_eq!(
-            unused_provide_warnings.len(),
-            0,
-            "Should have no unused provide warnings, but got: {:?}",
-            unused_provide_warnings
-                .iter()
-                .map(|d| &d.message)
-                .collect::<Vec<_>>()
+            unused_provide_keys,
+            vec!["locale"],
+            "Only 'locale' should be unused"
+        );
+    }
+
+    fn analyze_unused_provides(provider_uses: &str) -> Vec<String> {
+        use crate::cross_file::diagnostics::CrossFileDiagnosticKind;
+
+        let mut analyzer =
+            CrossFileAnalyzer::new(CrossFileOptions::default().with_unused_provide(true));
+
+        let analyze = |script: &str, uses: &[&str]| {
+            let mut single = crate::Analyzer::with_options(AnalyzerOptions::full());
+            single.analyze_script_setup(script);
+            for name in uses {
+                single
+                    .croquis_mut()
+                    .used_components
+                    .insert(vize_carton::CompactString::new(*name));
+            }
+            single.finish()
+        };
+
+        // App renders Provider and Sibling; Provider renders `provider_uses`
+        let app = analyze("", &["Provider", "Sibling"]);
+        let provider = analyze(
+            "import { provide } from 'vue'\nprovide('theme', 'dark')",
+            &[provider_uses],
         );
+        let consumer = analyze(
+            "import { inject } from 'vue'\nconst theme = inject('theme')",
+            &[],
+        );
+        let empty = analyze("", &[]);
+
+        let (sibling, child) = if provider_uses == "Child" {
+            (empty, consumer)
+        } else {
+            (consumer, empty)
+        };
+        analyzer.add_file_with_analysis(Path::new("App.vue"), "", app);
+        analyzer.add_file_with_analysis(Path::new("Provider.vue"), "", provider);
+        analyzer.add_file_with_analysis(Path::new("Sibling.vue"), "", sibling);
+        analyzer.add_file_with_analysis(Path::new("Child.vue"), "", child);
+        analyzer.rebuild_component_edges();
+
+        analyzer
+            .analyze()
+            .diagnostics
+            .into_iter()
+            .filter_map(|d| match d.kind {
+                CrossFileDiagnosticKind::UnusedProvide { key } => Some(key.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unused_provide_consumed_by_sibling() {
+        // Sibling injects 'theme' but is not below Provider
+        assert_eq!(analyze_unused_provides("Nothing"), vec!["theme"]);
+    }
+
+    #[test]
+    fn test_unused_provide_consumed_by_child() {
+        assert!(analyze_unused_provides("Child").is_empty());
     }
 
     #[test]
@@ -1139,4 +1426,65 @@ const comp = inject('computedValue')"#,
 
         assert_snapshot!(output);
     }
+
+    #[test]
+    fn test_inherit_attrs_false_suppresses_multi_root_fallthrough() {
+        use crate::analysis::{ComponentUsage, PassedProp};
+        use crate::cross_file::diagnostics::CrossFileDiagnosticKind;
+        use crate::scope::ScopeId;
+        use vize_carton::{smallvec, CompactString, SmallVec};
+
+        fn multi_root_diagnostics(child_script: &str) -> usize {
+            let mut analyzer =
+                CrossFileAnalyzer::new(CrossFileOptions::default().with_fallthrough_attrs(true));
+
+            // Parent.vue passes a non-prop attribute to Child
+            let mut parent_analyzer = crate::Analyzer::with_options(AnalyzerOptions::full());
+            parent_analyzer.analyze_script_setup("import Child from './Child.vue'");
+            let parent = parent_analyzer.croquis_mut();
+            parent.used_components.insert(CompactString::new("Child"));
+            parent.component_usages.push(ComponentUsage {
+                name: CompactString::new("Child"),
+                start: 0,
+                end: 0,
+                props: smallvec![PassedProp {
+                    name: CompactString::new("data-test"),
+                    value: Some(CompactString::new("x")),
+                    start: 0,
+                    end: 0,
+                    is_dynamic: false,
+                }],
+                events: SmallVec::new(),
+                slots: SmallVec::new(),
+                has_spread_attrs: false,
+                scope_id: ScopeId::ROOT,
+            });
+            let parent_analysis = parent_analyzer.finish();
+
+            // Child.vue renders two roots and reads $attrs
+            let mut child_analyzer = crate::Analyzer::with_options(AnalyzerOptions::full());
+            child_analyzer.analyze_script_setup(child_script);
+            let template_info = &mut child_analyzer.croquis_mut().template_info;
+            template_info.root_element_count = 2;
+            template_info.uses_attrs = true;
+            let child_analysis = child_analyzer.finish();
+
+            analyzer.add_file_with_analysis(Path::new("Parent.vue"), "", parent_analysis);
+            analyzer.add_file_with_analysis(Path::new("Child.vue"), "", child_analysis);
+            analyzer.rebuild_component_edges();
+
+            analyzer
+                .analyze()
+                .diagnostics
+                .iter()
+                .filter(|d| matches!(d.kind, CrossFileDiagnosticKind::MultiRootMissingAttrs))
+                .count()
+        }
+
+        assert_eq!(multi_root_diagnostics("const a = 1"), 1);
+        assert_eq!(
+            multi_root_diagnostics("defineOptions({ inheritAttrs: false })"),
+            0
+        );
+    }
 }
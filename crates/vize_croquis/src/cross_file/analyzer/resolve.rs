@@ -0,0 +1,132 @@
+//! Import specifier resolution against registered modules.
+//!
+//! Unlike [`crate::import_resolver::ImportResolver`], which probes the file
+//! system, this only computes candidate paths. The analyzer then looks them up
+//! in the module registry, so in-memory files (LSP buffers, WASM) resolve too.
+
+use crate::import_resolver::ImportResolver;
+use std::path::{Component, Path, PathBuf};
+use vize_carton::FxHashMap;
+
+/// Extensions tried when a specifier omits one.
+const EXTENSIONS: &[&str] = &[".vue", ".ts", ".tsx", ".js", ".jsx", ".d.ts"];
+
+/// `compilerOptions.baseUrl` / `compilerOptions.paths` from tsconfig.json.
+#[derive(Debug, Default)]
+pub(crate) struct PathAliases {
+    /// Directory that `paths` targets are relative to.
+    base_dir: Option<PathBuf>,
+    /// Explicit `baseUrl`, which also enables bare non-relative specifiers.
+    base_url: Option<PathBuf>,
+    /// Path mappings (`"@/*" -> ["src/*"]`).
+    paths: FxHashMap<String, Vec<String>>,
+}
+
+impl PathAliases {
+    /// Load aliases from the tsconfig.json reachable from the project root.
+    pub(crate) fn load(project_root: &Path) -> Self {
+        let resolver = ImportResolver::new(project_root);
+        let base_url = resolver.base_url().map(normalize_path);
+        Self {
+            base_dir: Some(
+                base_url
+                    .clone()
+                    .unwrap_or_else(|| normalize_path(project_root)),
+            ),
+            base_url,
+            paths: resolver.path_mappings().clone(),
+        }
+    }
+
+    /// Whether the specifier refers to a project file rather than a package.
+    ///
+    /// Only these are worth reporting when they fail to resolve.
+    pub(crate) fn is_project_specifier(&self, specifier: &str) -> bool {
+        specifier.starts_with('.')
+            || specifier.starts_with('/')
+            || self
+                .paths
+                .keys()
+                .any(|pattern| match pattern.strip_suffix('*') {
+                    Some(prefix) => specifier.starts_with(prefix),
+                    None => specifier == pattern,
+                })
+    }
+
+    /// The `paths` entry that applies to `specifier`, with the text matched by `*`.
+    ///
+    /// As in tsconfig, an exact pattern wins, then the wildcard pattern with
+    /// the longest prefix; only that entry's targets are tried.
+    fn match_path<'s>(&self, specifier: &'s str) -> Option<(&[String], &'s str)> {
+        if let Some(replacements) = self.paths.get(specifier) {
+            return Some((replacements, ""));
+        }
+        self.paths
+            .iter()
+            .filter_map(|(pattern, replacements)| {
+                let prefix = pattern.strip_suffix('*')?;
+                let rest = specifier.strip_prefix(prefix)?;
+                Some((prefix.len(), replacements.as_slice(), rest))
+            })
+            .max_by_key(|(prefix_len, ..)| *prefix_len)
+            .map(|(_, replacements, rest)| (replacements, rest))
+    }
+
+    /// Candidate paths for a specifier imported from `from_file`, in priority order.
+    pub(crate) fn candidates(&self, specifier: &str, from_file: &Path) -> Vec<PathBuf> {
+        let mut targets = Vec::new();
+
+        if specifier.starts_with('.') {
+            if let Some(dir) = from_file.parent() {
+                targets.push(dir.join(specifier));
+            }
+        } else {
+            if let Some(base_dir) = &self.base_dir {
+                if let Some((replacements, rest)) = self.match_path(specifier) {
+                    for replacement in replacements {
+                        targets.push(base_dir.join(replacement.replacen('*', rest, 1)));
+                    }
+                }
+            }
+            if let Some(base_url) = &self.base_url {
+                targets.push(base_url.join(specifier));
+            }
+        }
+
+        let mut candidates = Vec::with_capacity(targets.len() * (EXTENSIONS.len() * 2 + 1));
+        for target in targets {
+            let target = normalize_path(&target);
+            let display = target.to_string_lossy().into_owned();
+            candidates.push(target.clone());
+            for ext in EXTENSIONS {
+                candidates.push(PathBuf::from(format!("{}{}", display, ext)));
+            }
+            for ext in EXTENSIONS {
+                candidates.push(target.join(format!("index{}", ext)));
+            }
+        }
+        candidates
+    }
+}
+
+/// Lexically normalize a path, resolving `.` and `..` without touching the file system.
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) {
+                    normalized.pop();
+                } else {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
@@ -15,6 +15,8 @@ pub struct CrossFileOptions {
     pub event_bubbling: bool,
     /// Analyze provide/inject.
     pub provide_inject: bool,
+    /// Report provide() keys that no descendant component injects.
+    pub unused_provide: bool,
     /// Analyze unique element IDs.
     pub unique_ids: bool,
     /// Analyze server/client boundaries.
@@ -35,6 +37,8 @@ pub struct CrossFileOptions {
     pub component_resolution: bool,
     /// Validate props passed to child components.
     pub props_validation: bool,
+    /// Validate scoped slots provided to child components.
+    pub slot_validation: bool,
 }
 
 impl CrossFileOptions {
@@ -45,6 +49,7 @@ impl CrossFileOptions {
             component_emits: true,
             event_bubbling: true,
             provide_inject: true,
+            unused_provide: true,
             unique_ids: true,
             server_client_boundary: true,
             error_suspense_boundary: true,
@@ -54,6 +59,7 @@ impl CrossFileOptions {
             max_import_depth: Some(10),
             component_resolution: true,
             props_validation: true,
+            slot_validation: true,
         }
     }
 
@@ -62,6 +68,7 @@ impl CrossFileOptions {
         Self {
             component_resolution: true,
             props_validation: true,
+            slot_validation: true,
             circular_dependencies: true,
             ..Default::default()
         }
@@ -96,6 +103,12 @@ impl CrossFileOptions {
         self
     }
 
+    /// Enable unused provide detection.
+    pub fn with_unused_provide(mut self, enabled: bool) -> Self {
+        self.unused_provide = enabled;
+        self
+    }
+
     /// Enable unique ID analysis.
     pub fn with_unique_ids(mut self, enabled: bool) -> Self {
         self.unique_ids = enabled;
@@ -144,12 +157,19 @@ impl CrossFileOptions {
         self
     }
 
+    /// Enable slot validation.
+    pub fn with_slot_validation(mut self, enabled: bool) -> Self {
+        self.slot_validation = enabled;
+        self
+    }
+
     /// Check if any analysis is enabled.
     pub fn any_enabled(&self) -> bool {
         self.fallthrough_attrs
             || self.component_emits
             || self.event_bubbling
             || self.provide_inject
+            || self.unused_provide
             || self.unique_ids
             || self.server_client_boundary
             || self.error_suspense_boundary
@@ -158,6 +178,7 @@ impl CrossFileOptions {
             || self.circular_dependencies
             || self.component_resolution
             || self.props_validation
+            || self.slot_validation
     }
 
     /// Enable setup context violation analysis.
@@ -209,6 +230,9 @@ pub struct CrossFileResult {
     /// Props validation issues.
     pub props_validation_issues: Vec<analyzers::PropsValidationIssue>,
 
+    /// Slot validation issues.
+    pub slot_validation_issues: Vec<analyzers::SlotValidationIssue>,
+
     /// Statistics.
     pub stats: CrossFileStats,
 }
@@ -222,6 +246,8 @@ pub struct CrossFileStats {
     pub vue_components: usize,
     /// Number of edges in dependency graph.
     pub dependency_edges: usize,
+    /// Number of relative or aliased imports that did not resolve to a registered file.
+    pub unresolved_imports: usize,
     /// Number of diagnostics by severity.
     pub error_count: usize,
     pub warning_count: usize,
@@ -0,0 +1,117 @@
+//! Circular dependency diagnostics.
+//!
+//! Converts cycles detected by the dependency graph into diagnostics that
+//! name every file in the loop:
+//! - Runtime import cycles (error): modules may observe each other half-initialized
+//! - Type-only import cycles (warning): erased at compile time, usually harmless
+
+use crate::cross_file::diagnostics::{
+    CrossFileDiagnostic, CrossFileDiagnosticKind, DiagnosticSeverity,
+};
+use crate::cross_file::graph::{DependencyEdge, DependencyGraph};
+use crate::cross_file::registry::{FileId, ModuleRegistry};
+use crate::scope::{ScopeData, ScopeKind};
+use std::path::Path;
+use vize_carton::CompactString;
+
+/// Create a diagnostic for each import cycle.
+///
+/// The diagnostic is attached to the import that closes the loop, i.e. the
+/// edge from the last file in the cycle back to the first one. `resolve`
+/// maps an import specifier of a file to the registered file it refers to.
+pub fn analyze_circular_dependencies(
+    registry: &ModuleRegistry,
+    graph: &DependencyGraph,
+    resolve: impl Fn(&str, &Path) -> Option<FileId>,
+) -> Vec<CrossFileDiagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for cycle in graph.import_cycles() {
+        let (Some(&first), Some(&last)) = (cycle.first(), cycle.last()) else {
+            continue;
+        };
+
+        let names: Vec<CompactString> = cycle
+            .iter()
+            .chain(std::iter::once(&first))
+            .map(|&id| display_name(registry, id))
+            .collect();
+
+        // Erased at compile time if any edge in the loop is a type-only import
+        let type_only = cycle
+            .iter()
+            .zip(cycle.iter().skip(1).chain(std::iter::once(&first)))
+            .any(|(&from, &to)| edge_between(graph, from, to) == Some(DependencyEdge::TypeImport));
+
+        let (severity, message, suggestion) = if type_only {
+            (
+                DiagnosticSeverity::Warning,
+                format!("Type-only circular import: {}", names.join(" → ")),
+                "Type-only cycles are erased at runtime, but moving shared types into a separate module keeps the graph acyclic",
+            )
+        } else {
+            (
+                DiagnosticSeverity::Error,
+                format!("Circular dependency: {}", names.join(" → ")),
+                "Extract the shared code into a separate module, or use a dynamic import (`defineAsyncComponent`) to break the cycle",
+            )
+        };
+
+        let (start, end) = import_span(registry, &resolve, last, first).unwrap_or((0, 0));
+        let mut diagnostic = CrossFileDiagnostic::with_span(
+            CrossFileDiagnosticKind::CircularDependency { cycle: names },
+            severity,
+            last,
+            start,
+            end,
+            message,
+        );
+        // Point at the import in `first` that continues the loop
+        let next = cycle.get(1).copied().unwrap_or(first);
+        if let Some((related_start, _)) = import_span(registry, &resolve, first, next) {
+            diagnostic = diagnostic.with_related(
+                first,
+                related_start,
+                format!("imports {} here", display_name(registry, next)),
+            );
+        }
+        diagnostics.push(diagnostic.with_suggestion(suggestion));
+    }
+
+    diagnostics
+}
+
+fn display_name(registry: &ModuleRegistry, id: FileId) -> CompactString {
+    registry
+        .get(id)
+        .map(|entry| entry.filename.clone())
+        .unwrap_or_else(|| CompactString::new("<unknown>"))
+}
+
+fn edge_between(graph: &DependencyGraph, from: FileId, to: FileId) -> Option<DependencyEdge> {
+    graph
+        .dependencies(from)
+        .find(|(id, _)| *id == to)
+        .map(|(_, edge)| edge)
+}
+
+/// Find the span of the import statement in `from` that resolves to `to`.
+fn import_span(
+    registry: &ModuleRegistry,
+    resolve: &impl Fn(&str, &Path) -> Option<FileId>,
+    from: FileId,
+    to: FileId,
+) -> Option<(u32, u32)> {
+    let entry = registry.get(from)?;
+
+    entry.analysis.scopes.iter().find_map(|scope| {
+        if scope.kind != ScopeKind::ExternalModule {
+            return None;
+        }
+        let ScopeData::ExternalModule(data) = scope.data() else {
+            return None;
+        };
+        (resolve(&data.source, &entry.path) == Some(to))
+            .then_some((scope.span.start, scope.span.end))
+    })
+}
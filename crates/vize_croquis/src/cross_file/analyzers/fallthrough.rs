@@ -38,8 +38,9 @@ pub struct FallthroughInfo {
 impl FallthroughInfo {
     /// Check if fallthrough may cause issues.
     pub fn has_potential_issues(&self) -> bool {
-        // Multiple roots without explicit $attrs
-        if self.root_element_count > 1 && !self.binds_attrs {
+        // Multiple roots without explicit $attrs (nothing falls through when
+        // inheritance is disabled)
+        if self.root_element_count > 1 && !self.binds_attrs && !self.inherit_attrs_disabled {
             return true;
         }
 
@@ -55,7 +56,11 @@ impl FallthroughInfo {
             .filter(|attr| !self.declared_props.contains(*attr))
             .collect();
 
-        if !fallthrough_attrs.is_empty() && !self.uses_attrs && self.root_element_count > 1 {
+        if !fallthrough_attrs.is_empty()
+            && !self.uses_attrs
+            && self.root_element_count > 1
+            && !self.inherit_attrs_disabled
+        {
             return true;
         }
 
@@ -83,7 +88,7 @@ pub fn analyze_fallthrough(
         let template_info = &analysis.template_info;
 
         // Check for inheritAttrs option (from defineOptions macro)
-        let inherit_attrs_disabled = check_inherit_attrs_disabled(analysis);
+        let inherit_attrs_disabled = analysis.component_options.inherit_attrs_disabled();
 
         // Get declared props
         let declared_props: FxHashSet<_> = analysis
@@ -144,8 +149,9 @@ pub fn analyze_fallthrough(
 
     // Generate diagnostics
     for info in &infos {
-        // Check for multiple root elements without explicit $attrs binding
-        if info.root_element_count > 1 && !info.binds_attrs {
+        // Check for multiple root elements without explicit $attrs binding.
+        // With `inheritAttrs: false` the component opted out of fallthrough.
+        if info.root_element_count > 1 && !info.binds_attrs && !info.inherit_attrs_disabled {
             let has_fallthrough = info
                 .passed_attrs
                 .iter()
@@ -197,7 +203,11 @@ pub fn analyze_fallthrough(
             .cloned()
             .collect();
 
-        if !unused_attrs.is_empty() && !info.binds_attrs && info.root_element_count > 1 {
+        if !unused_attrs.is_empty()
+            && !info.binds_attrs
+            && info.root_element_count > 1
+            && !info.inherit_attrs_disabled
+        {
             // Use offset 0 to point to <template> tag start (wasm.rs adds tag_start offset)
             diagnostics.push(
                 CrossFileDiagnostic::with_span(
@@ -221,22 +231,6 @@ pub fn analyze_fallthrough(
     (infos, diagnostics)
 }
 
-/// Check if inheritAttrs: false is set in the component options.
-fn check_inherit_attrs_disabled(analysis: &crate::Croquis) -> bool {
-    // Look for defineOptions with inheritAttrs: false in runtime_args
-    analysis.macros.all_calls().iter().any(|call| {
-        if call.name != "defineOptions" {
-            return false;
-        }
-        // Check if runtime_args contains "inheritAttrs: false" or "inheritAttrs:false"
-        if let Some(ref args) = call.runtime_args {
-            args.contains("inheritAttrs") && args.contains("false")
-        } else {
-            false
-        }
-    })
-}
-
 /// Extract attributes passed to a child component.
 /// Uses component_usages for precise static analysis.
 fn extract_passed_attrs(analysis: &crate::Croquis, _child_id: &FileId) -> FxHashSet<CompactString> {
@@ -310,4 +304,22 @@ mod tests {
         info.uses_attrs = true;
         assert!(!info.has_potential_issues());
     }
+
+    #[test]
+    fn test_inherit_attrs_disabled_suppresses_multi_root() {
+        let mut passed_attrs = FxHashSet::default();
+        passed_attrs.insert(CompactString::new("data-test"));
+        let info = FallthroughInfo {
+            file_id: FileId::new(0),
+            inherit_attrs_disabled: true,
+            uses_attrs: true,
+            binds_attrs: false,
+            root_element_count: 2,
+            passed_attrs,
+            declared_props: FxHashSet::default(),
+            template_start: 0,
+            template_end: 0,
+        };
+        assert!(!info.has_potential_issues());
+    }
 }
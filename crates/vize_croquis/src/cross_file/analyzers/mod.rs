@@ -11,6 +11,7 @@
 //! ```
 
 mod boundary;
+mod circular;
 mod component_resolution;
 mod cross_file_reactivity;
 mod element_id;
@@ -21,17 +22,20 @@ mod props_validation;
 mod provide_inject;
 mod reactivity;
 mod setup_context;
+mod slot_validation;
 
 // Re-export analyzer types
 pub use boundary::{analyze_boundaries, BoundaryInfo, BoundaryKind};
+pub use circular::analyze_circular_dependencies;
 pub use component_resolution::{analyze_component_resolution, ComponentResolutionIssue};
 pub use element_id::{analyze_element_ids, UniqueIdIssue};
 pub use emit::{analyze_emits, EmitFlow};
 pub use event_bubbling::{analyze_event_bubbling, EventBubble};
 pub use fallthrough::{analyze_fallthrough, FallthroughInfo};
 pub use props_validation::{analyze_props_validation, PropsValidationIssue};
-pub use provide_inject::{analyze_provide_inject, ProvideInjectMatch};
+pub use provide_inject::{analyze_provide_inject, analyze_unused_provides, ProvideInjectMatch};
 pub use reactivity::{analyze_reactivity, ReactivityIssue, ReactivityIssueKind};
+pub use slot_validation::{analyze_slot_validation, SlotValidationIssue};
 
 // Cross-file reactivity tracking
 pub use cross_file_reactivity::{analyze_cross_file_reactivity, CrossFileReactivityIssue};
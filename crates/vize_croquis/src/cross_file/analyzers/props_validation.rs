@@ -1,13 +1,19 @@
 //! Props validation analyzer.
 //!
-//! Validates that props passed to child components match their declarations.
+//! Validates that props passed to child components match their declarations:
+//! - Undeclared props (warning): may be intentional `$attrs` fallthrough
+//! - Missing required props (error)
+//! - Type mismatches (error): literal bindings and bindings of the parent's
+//!   own typed props, checked against the child's declared prop type
 
+use crate::analysis::PassedProp;
 use crate::cross_file::diagnostics::{
     CrossFileDiagnostic, CrossFileDiagnosticKind, DiagnosticSeverity,
 };
 use crate::cross_file::graph::DependencyGraph;
 use crate::cross_file::registry::{FileId, ModuleRegistry};
-use vize_carton::{CompactString, FxHashMap, FxHashSet};
+use crate::macros::PropDefinition;
+use vize_carton::{camelize, CompactString, FxHashMap, FxHashSet};
 
 /// Information about a props validation issue.
 #[derive(Debug, Clone)]
@@ -30,8 +36,11 @@ pub enum PropsValidationIssueKind {
     /// Prop passed but not declared in child.
     UndeclaredProp { prop_name: CompactString },
     /// Required prop not passed.
-    MissingRequiredProp { prop_name: CompactString },
-    /// Type mismatch (if detectable statically).
+    MissingRequiredProp {
+        prop_name: CompactString,
+        expected: Option<CompactString>,
+    },
+    /// Bound value type is not assignable to the declared prop type.
     TypeMismatch {
         prop_name: CompactString,
         expected: CompactString,
@@ -49,7 +58,6 @@ struct ComponentPropsInfo {
 #[derive(Debug, Clone)]
 struct PropInfo {
     required: bool,
-    #[allow(dead_code)] // Will be used for type checking in future
     prop_type: Option<CompactString>,
 }
 
@@ -58,6 +66,7 @@ struct PropInfo {
 /// This analyzer checks:
 /// 1. Props passed to children are declared in their defineProps
 /// 2. Required props are always passed
+/// 3. Bound values match the declared prop types
 pub fn analyze_props_validation(
     registry: &ModuleRegistry,
     graph: &DependencyGraph,
@@ -112,12 +121,11 @@ pub fn analyze_props_validation(
         };
 
         // Get props passed by parent
-        // This requires parsing the template to find the actual props passed
-        // For now, we focus on checking required props from the child's perspective
-        let passed_props = extract_passed_props_for_component(
+        let passed = extract_passed_props_for_component(
             &parent_entry.analysis,
             child_component_name.as_str(),
         );
+        let passed_props: FxHashSet<&str> = passed.iter().map(|p| p.name.as_str()).collect();
 
         // Check for missing required props
         for (prop_name, prop_info) in &child_props_info.props {
@@ -128,6 +136,7 @@ pub fn analyze_props_validation(
                     component_name: child_component_name.clone(),
                     kind: PropsValidationIssueKind::MissingRequiredProp {
                         prop_name: prop_name.clone(),
+                        expected: prop_info.prop_type.clone(),
                     },
                     offset: 0,
                 };
@@ -208,28 +217,207 @@ pub fn analyze_props_validation(
                 diagnostics.push(diagnostic);
             }
         }
+
+        // Check bound values against declared prop types
+        let parent_props = parent_entry.analysis.macros.props();
+        for passed_prop in &passed {
+            let prop_name = passed_prop.name.as_str();
+            let Some(prop_info) = child_props_info
+                .props
+                .get(prop_name)
+                .or_else(|| child_props_info.props.get(camelize(prop_name).as_str()))
+            else {
+                continue;
+            };
+            let Some(ref expected) = prop_info.prop_type else {
+                continue;
+            };
+            let Some(actual) = infer_passed_type(passed_prop, expected, parent_props) else {
+                continue;
+            };
+            if is_type_compatible(expected, &actual, prop_info.required) {
+                continue;
+            }
+
+            issues.push(PropsValidationIssue {
+                parent_file: parent_id,
+                child_file: child_id,
+                component_name: child_component_name.clone(),
+                kind: PropsValidationIssueKind::TypeMismatch {
+                    prop_name: passed_prop.name.clone(),
+                    expected: expected.clone(),
+                    actual: actual.clone(),
+                },
+                offset: passed_prop.start,
+            });
+
+            let diagnostic = CrossFileDiagnostic::with_span(
+                CrossFileDiagnosticKind::PropTypeMismatch {
+                    prop_name: passed_prop.name.clone(),
+                    expected_type: expected.clone(),
+                    actual_type: actual.clone(),
+                },
+                DiagnosticSeverity::Error,
+                parent_id,
+                passed_prop.start,
+                passed_prop.end,
+                format!(
+                    "**Prop Type Mismatch**: `{}` of `<{}>` expects `{}`, but `{}` is passed",
+                    passed_prop.name, child_component_name, expected, actual
+                ),
+            )
+            .with_related(
+                child_id,
+                0,
+                format!(
+                    "Prop `{}` is declared as `{}` here",
+                    passed_prop.name, expected
+                ),
+            );
+
+            diagnostics.push(diagnostic);
+        }
     }
 
     (issues, diagnostics)
 }
 
+/// Infer the type of a value passed to a prop.
+///
+/// Only literals and the parent's own typed props are inferred; any other
+/// expression gives `None` and is left to the type checker.
+fn infer_passed_type(
+    prop: &PassedProp,
+    expected: &str,
+    parent_props: &[PropDefinition],
+) -> Option<CompactString> {
+    if !prop.is_dynamic {
+        // Static attributes on Boolean props are cast (`<Child disabled />`)
+        let is_boolean = split_type_union(expected)
+            .any(|member| matches!(primitive_of(member), Some("boolean")));
+        return (prop.value.is_some() && !is_boolean).then(|| CompactString::const_new("string"));
+    }
+
+    let value = prop.value.as_deref()?.trim();
+    match value {
+        "true" | "false" => return Some(CompactString::const_new("boolean")),
+        "null" | "undefined" => return Some(CompactString::new(value)),
+        _ => {}
+    }
+    if let Some(quote) = value
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '\'' | '"' | '`'))
+    {
+        // A single string literal, not `'a' + b` or a template with `${}`
+        let body = value.strip_prefix(quote)?.strip_suffix(quote)?;
+        let is_literal = !body.contains(quote) && !(quote == '`' && body.contains("${"));
+        return is_literal.then(|| CompactString::const_new("string"));
+    }
+    if value.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '.')
+        && value.parse::<f64>().is_ok()
+    {
+        return Some(CompactString::const_new("number"));
+    }
+
+    parent_props
+        .iter()
+        .find(|p| p.name == value)
+        .and_then(|p| p.prop_type.clone())
+}
+
+/// Check whether a value of type `actual` can be passed to a prop of type `expected`.
+///
+/// Both types are compared as unions of primitives; any member that is not a
+/// primitive or primitive literal (e.g. `User`, `T[]`) makes the check pass.
+fn is_type_compatible(expected: &str, actual: &str, required: bool) -> bool {
+    let Some(expected) = split_type_union(expected)
+        .map(primitive_of)
+        .collect::<Option<Vec<_>>>()
+    else {
+        return true;
+    };
+    let Some(actual) = split_type_union(actual)
+        .map(primitive_of)
+        .collect::<Option<Vec<_>>>()
+    else {
+        return true;
+    };
+
+    if expected.iter().any(|t| matches!(*t, "any" | "unknown")) {
+        return true;
+    }
+
+    actual
+        .iter()
+        .all(|t| expected.contains(t) || (*t == "undefined" && !required) || *t == "any")
+}
+
+/// Get the primitive a type (or literal type) belongs to.
+fn primitive_of(ty: &str) -> Option<&'static str> {
+    let ty = ty.trim();
+    match ty {
+        "string" => Some("string"),
+        "number" => Some("number"),
+        "boolean" | "true" | "false" => Some("boolean"),
+        "bigint" => Some("bigint"),
+        "symbol" => Some("symbol"),
+        "null" => Some("null"),
+        "undefined" | "void" => Some("undefined"),
+        "any" => Some("any"),
+        "unknown" => Some("unknown"),
+        _ if ty.len() >= 2
+            && (ty.starts_with('\'') && ty.ends_with('\'')
+                || ty.starts_with('"') && ty.ends_with('"')) =>
+        {
+            Some("string")
+        }
+        _ if ty.parse::<f64>().is_ok() => Some("number"),
+        _ => None,
+    }
+}
+
+/// Split a type into its top-level union members.
+fn split_type_union(ty: &str) -> impl Iterator<Item = &str> {
+    let mut members = Vec::new();
+    let mut depth = 0i32;
+    let mut quote = None;
+    let mut start = 0;
+
+    for (i, c) in ty.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, '(' | '[' | '{' | '<') => depth += 1,
+            (None, ')' | ']' | '}' | '>') => depth -= 1,
+            (None, '|') if depth == 0 => {
+                members.push(&ty[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    members.push(&ty[start..]);
+
+    members.into_iter().map(str::trim).filter(|m| !m.is_empty())
+}
+
 /// Extract props passed to a specific component from the analysis.
 ///
 /// Uses component_usages to find props passed to the component.
 fn extract_passed_props_for_component<'a>(
     analysis: &'a crate::Croquis,
     component_name: &str,
-) -> FxHashSet<&'a str> {
-    let mut props = FxHashSet::default();
+) -> Vec<&'a PassedProp> {
+    let mut props = Vec::new();
 
     for usage in &analysis.component_usages {
         // Match component name (case-insensitive for kebab-case vs PascalCase)
         if usage.name.as_str().eq_ignore_ascii_case(component_name)
             || to_pascal_case(usage.name.as_str()).eq_ignore_ascii_case(component_name)
         {
-            for prop in &usage.props {
-                props.insert(prop.name.as_str());
-            }
+            props.extend(usage.props.iter());
         }
     }
 
@@ -284,6 +472,77 @@ fn is_builtin_attr(name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cross_file::{CrossFileAnalyzer, CrossFileOptions};
+    use crate::{Analyzer, AnalyzerOptions};
+    use std::path::Path;
+
+    fn analyze_parent_child(
+        child_script: &str,
+        parent_script: &str,
+        parent_template: &str,
+    ) -> Vec<PropsValidationIssue> {
+        let mut analyzer =
+            CrossFileAnalyzer::new(CrossFileOptions::minimal().with_props_validation(true));
+        analyzer.add_file(Path::new("Child.vue"), child_script);
+
+        let allocator = vize_carton::Bump::new();
+        let (root, _) = vize_armature::parse(&allocator, parent_template);
+        let mut parent = Analyzer::with_options(AnalyzerOptions::full());
+        parent.analyze_script_setup(parent_script);
+        parent.analyze_template(&root);
+        analyzer.add_file_with_analysis(Path::new("Parent.vue"), parent_script, parent.finish());
+        analyzer.rebuild_component_edges();
+        analyzer.analyze().props_validation_issues
+    }
+
+    #[test]
+    fn test_string_bound_to_number_prop() {
+        let issues = analyze_parent_child(
+            "const props = defineProps<{ count: number }>()",
+            "import Child from './Child.vue'",
+            r#"<Child :count="'hi'" />"#,
+        );
+
+        assert_eq!(issues.len(), 1, "{:?}", issues);
+        assert_eq!(
+            issues[0].kind,
+            PropsValidationIssueKind::TypeMismatch {
+                prop_name: CompactString::new("count"),
+                expected: CompactString::new("number"),
+                actual: CompactString::new("string"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_compatible_and_missing_props() {
+        let issues = analyze_parent_child(
+            "const props = defineProps<{ count: number; label: string; size?: 'sm' | 'lg' }>()",
+            "import Child from './Child.vue'\nconst props = defineProps<{ total: number }>()",
+            r#"<Child :count="total" :size="undefined" />"#,
+        );
+
+        assert_eq!(issues.len(), 1, "{:?}", issues);
+        assert_eq!(
+            issues[0].kind,
+            PropsValidationIssueKind::MissingRequiredProp {
+                prop_name: CompactString::new("label"),
+                expected: Some(CompactString::new("string")),
+            }
+        );
+    }
+
+    #[test]
+    fn test_is_type_compatible() {
+        assert!(is_type_compatible("number", "number", true));
+        assert!(is_type_compatible("string | number", "number", true));
+        assert!(is_type_compatible("'sm' | 'lg'", "string", true));
+        assert!(is_type_compatible("User", "string", true));
+        assert!(is_type_compatible("number", "undefined", false));
+        assert!(!is_type_compatible("number", "undefined", true));
+        assert!(!is_type_compatible("number", "string", true));
+        assert!(!is_type_compatible("boolean", "string | number", true));
+    }
 
     #[test]
     fn test_is_builtin_attr() {
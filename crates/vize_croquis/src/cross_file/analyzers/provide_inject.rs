@@ -406,9 +406,6 @@ pub fn analyze_provide_inject(
         }
     }
 
-    // Track which provides are used
-    let mut used_provides: FxHashSet<(FileId, CompactString)> = FxHashSet::default();
-
     // For each inject, try to find a matching provide in ancestors
     for (&consumer_id, consumer_injects) in &injects {
         for inject in consumer_injects {
@@ -512,8 +509,6 @@ pub fn analyze_provide_inject(
             match provider_match {
                 Some((provider_id, provide_entry, path)) => {
                     // Found a match
-                    used_provides.insert((provider_id, key_str.clone()));
-
                     matches.push(ProvideInjectMatch {
                         provider: provider_id,
                         consumer: consumer_id,
@@ -573,43 +568,6 @@ pub fn analyze_provide_inject(
         }
     }
 
-    // Check for unused provides
-    for (&provider_id, provider_provides) in &provides {
-        for provide in provider_provides {
-            let key_str = match &provide.key {
-                ProvideKey::String(s) => s.clone(),
-                ProvideKey::Symbol(s) => s.clone(),
-            };
-
-            if !used_provides.contains(&(provider_id, key_str.clone())) {
-                // Check if any descendant injects this key
-                let has_descendant_inject =
-                    has_inject_in_descendants(provider_id, &key_str, &injects, graph);
-
-                if !has_descendant_inject {
-                    diagnostics.push(
-                        CrossFileDiagnostic::new(
-                            CrossFileDiagnosticKind::UnusedProvide {
-                                key: key_str.clone(),
-                            },
-                            DiagnosticSeverity::Warning,
-                            provider_id,
-                            provide.start,
-                            format!(
-                                "provide('{}') is not used by any descendant component",
-                                key_str
-                            ),
-                        )
-                        .with_end_offset(provide.end)
-                        .with_suggestion(
-                            "Remove if not needed, or add inject() in a child component",
-                        ),
-                    );
-                }
-            }
-        }
-    }
-
     (matches, diagnostics)
 }
 
@@ -667,10 +625,70 @@ fn find_provider(
     None
 }
 
+/// Report `provide()` keys that no descendant component injects.
+///
+/// Only the subtree below the providing component (following component
+/// usage edges) is searched, so an inject in a sibling or unrelated
+/// component does not count. Symbol keys match by identity: a `Symbol`
+/// key only matches an inject of the same symbol binding, never a string.
+pub fn analyze_unused_provides(
+    registry: &ModuleRegistry,
+    graph: &DependencyGraph,
+) -> Vec<CrossFileDiagnostic> {
+    let mut provides: FxHashMap<FileId, Vec<ProvideEntry>> = FxHashMap::default();
+    let mut injects: FxHashMap<FileId, Vec<InjectEntry>> = FxHashMap::default();
+
+    for entry in registry.vue_components() {
+        let (p, i) = extract_provide_inject(&entry.analysis);
+        if !p.is_empty() {
+            provides.insert(entry.id, p);
+        }
+        if !i.is_empty() {
+            injects.insert(entry.id, i);
+        }
+    }
+
+    let mut diagnostics = Vec::new();
+    for (&provider_id, provider_provides) in &provides {
+        for provide in provider_provides {
+            if has_inject_in_descendants(provider_id, &provide.key, &provides, &injects, graph) {
+                continue;
+            }
+
+            let key_str = match &provide.key {
+                ProvideKey::String(s) => s.clone(),
+                ProvideKey::Symbol(s) => s.clone(),
+            };
+            diagnostics.push(
+                CrossFileDiagnostic::new(
+                    CrossFileDiagnosticKind::UnusedProvide {
+                        key: key_str.clone(),
+                    },
+                    DiagnosticSeverity::Warning,
+                    provider_id,
+                    provide.start,
+                    format!(
+                        "provide('{}') is not used by any descendant component",
+                        key_str
+                    ),
+                )
+                .with_end_offset(provide.end)
+                .with_suggestion("Remove if not needed, or add inject() in a child component"),
+            );
+        }
+    }
+
+    diagnostics
+}
+
 /// Check if any descendant component injects a given key.
+///
+/// Descendants that provide the same key themselves shadow it for their
+/// own subtree, so the search does not continue below them.
 fn has_inject_in_descendants(
     provider: FileId,
-    key: &str,
+    key: &ProvideKey,
+    provides: &FxHashMap<FileId, Vec<ProvideEntry>>,
     injects: &FxHashMap<FileId, Vec<InjectEntry>>,
     graph: &DependencyGraph,
 ) -> bool {
@@ -685,23 +703,23 @@ fn has_inject_in_descendants(
 
         // Check descendants (components used by this one)
         for (child_id, edge_type) in graph.dependencies(current) {
-            if edge_type == DependencyEdge::ComponentUsage {
-                // Check if child injects this key
-                if let Some(child_injects) = injects.get(&child_id) {
-                    for inject in child_injects {
-                        let inject_key = match &inject.key {
-                            ProvideKey::String(s) => s.as_str(),
-                            ProvideKey::Symbol(s) => s.as_str(),
-                        };
-                        if inject_key == key {
-                            return true;
-                        }
-                    }
-                }
+            if edge_type != DependencyEdge::ComponentUsage || visited.contains(&child_id) {
+                continue;
+            }
 
-                if !visited.contains(&child_id) {
-                    queue.push(child_id);
-                }
+            // Check if child injects this key
+            if injects
+                .get(&child_id)
+                .is_some_and(|child_injects| child_injects.iter().any(|i| &i.key == key))
+            {
+                return true;
+            }
+
+            let reprovides = provides
+                .get(&child_id)
+                .is_some_and(|child_provides| child_provides.iter().any(|p| &p.key == key));
+            if !reprovides {
+                queue.push(child_id);
             }
         }
     }
@@ -0,0 +1,283 @@
+//! Slot validation analyzer.
+//!
+//! Validates scoped slots provided to child components against the child's
+//! `defineSlots()` declaration:
+//! - Slots that the child does not declare (warning)
+//! - Destructured slot props that the child does not pass (error)
+
+use crate::analysis::SlotUsage;
+use crate::cross_file::diagnostics::{
+    CrossFileDiagnostic, CrossFileDiagnosticKind, DiagnosticSeverity,
+};
+use crate::cross_file::graph::DependencyGraph;
+use crate::cross_file::registry::{FileId, ModuleRegistry};
+use crate::naming::to_pascal_case;
+use crate::types::TypeResolver;
+use vize_carton::CompactString;
+
+/// Information about a slot validation issue.
+#[derive(Debug, Clone)]
+pub struct SlotValidationIssue {
+    /// The file where the parent component is.
+    pub parent_file: FileId,
+    /// The file where the child component is.
+    pub child_file: FileId,
+    /// The component name.
+    pub component_name: CompactString,
+    /// Kind of issue.
+    pub kind: SlotValidationIssueKind,
+    /// Source offset in parent file.
+    pub offset: u32,
+}
+
+/// Kind of slot validation issue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlotValidationIssueKind {
+    /// Slot provided but not declared in child's defineSlots.
+    UndefinedSlot { slot_name: CompactString },
+    /// Destructured slot prop not declared in the slot's props type.
+    UnknownSlotProp {
+        slot_name: CompactString,
+        prop_name: CompactString,
+        available: Vec<CompactString>,
+    },
+}
+
+/// Analyze scoped slot usage across component boundaries.
+///
+/// Only children with a `defineSlots()` declaration are checked, and slot
+/// props only when the slot's props type is an inline object type.
+pub fn analyze_slot_validation(
+    registry: &ModuleRegistry,
+    graph: &DependencyGraph,
+) -> (Vec<SlotValidationIssue>, Vec<CrossFileDiagnostic>) {
+    let mut issues = Vec::new();
+    let mut diagnostics = Vec::new();
+    let resolver = TypeResolver::new();
+
+    for (parent_id, child_id) in graph.component_usage() {
+        let Some(parent_entry) = registry.get(parent_id) else {
+            continue;
+        };
+        let Some(child_entry) = registry.get(child_id) else {
+            continue;
+        };
+        let Some(ref child_component_name) = child_entry.component_name else {
+            continue;
+        };
+
+        let declared = child_entry.analysis.macros.slots();
+        if declared.is_empty() {
+            continue;
+        }
+        let define_slots_offset = child_entry
+            .analysis
+            .macros
+            .define_slots()
+            .map_or(0, |call| call.start);
+
+        for slot_use in slots_for_component(&parent_entry.analysis, child_component_name) {
+            let Some(slot) = declared.iter().find(|s| s.name == slot_use.name) else {
+                issues.push(SlotValidationIssue {
+                    parent_file: parent_id,
+                    child_file: child_id,
+                    component_name: child_component_name.clone(),
+                    kind: SlotValidationIssueKind::UndefinedSlot {
+                        slot_name: slot_use.name.clone(),
+                    },
+                    offset: slot_use.start,
+                });
+
+                diagnostics.push(
+                    CrossFileDiagnostic::with_span(
+                        CrossFileDiagnosticKind::UndefinedSlot {
+                            slot_name: slot_use.name.clone(),
+                            component_name: child_component_name.clone(),
+                        },
+                        DiagnosticSeverity::Warning,
+                        parent_id,
+                        slot_use.start,
+                        slot_use.end,
+                        format!(
+                            "**Undefined Slot**: `<{}>` does not declare a `{}` slot",
+                            child_component_name, slot_use.name
+                        ),
+                    )
+                    .with_related(
+                        child_id,
+                        define_slots_offset,
+                        "Slots are declared in `defineSlots` here",
+                    ),
+                );
+                continue;
+            };
+
+            // Only inline object types have a known shape
+            let (Some(keys), Some(props_type)) = (&slot_use.prop_keys, slot.props_type.as_deref())
+            else {
+                continue;
+            };
+            if !props_type.trim_start().starts_with('{') {
+                continue;
+            }
+            let available: Vec<CompactString> = resolver
+                .extract_properties(props_type)
+                .into_iter()
+                .map(|p| p.name)
+                .collect();
+
+            for key in keys {
+                if available.contains(key) {
+                    continue;
+                }
+
+                issues.push(SlotValidationIssue {
+                    parent_file: parent_id,
+                    child_file: child_id,
+                    component_name: child_component_name.clone(),
+                    kind: SlotValidationIssueKind::UnknownSlotProp {
+                        slot_name: slot_use.name.clone(),
+                        prop_name: key.clone(),
+                        available: available.clone(),
+                    },
+                    offset: slot_use.start,
+                });
+
+                let listed = if available.is_empty() {
+                    "none".to_string()
+                } else {
+                    available
+                        .iter()
+                        .map(|k| format!("`{}`", k))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                diagnostics.push(
+                    CrossFileDiagnostic::with_span(
+                        CrossFileDiagnosticKind::UnknownSlotProp {
+                            slot_name: slot_use.name.clone(),
+                            prop_name: key.clone(),
+                            component_name: child_component_name.clone(),
+                        },
+                        DiagnosticSeverity::Error,
+                        parent_id,
+                        slot_use.start,
+                        slot_use.end,
+                        format!(
+                            "**Unknown Slot Prop**: slot `{}` of `<{}>` has no prop `{}`\n\n\
+                            Available props: {}",
+                            slot_use.name, child_component_name, key, listed
+                        ),
+                    )
+                    .with_related(
+                        child_id,
+                        define_slots_offset,
+                        format!(
+                            "Slot `{}` is declared as `{}` here",
+                            slot_use.name, props_type
+                        ),
+                    ),
+                );
+            }
+        }
+    }
+
+    (issues, diagnostics)
+}
+
+/// Collect slots provided to a specific component from the analysis.
+fn slots_for_component<'a>(
+    analysis: &'a crate::Croquis,
+    component_name: &str,
+) -> impl Iterator<Item = &'a SlotUsage> {
+    let component_name = to_pascal_case(component_name);
+    analysis
+        .component_usages
+        .iter()
+        .filter(move |usage| to_pascal_case(usage.name.as_str()) == component_name)
+        .flat_map(|usage| usage.slots.iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cross_file::{CrossFileAnalyzer, CrossFileOptions, CrossFileResult};
+    use crate::{Analyzer, AnalyzerOptions};
+    use std::path::Path;
+
+    const CHILD_SCRIPT: &str =
+        "type Item = string\ndefineSlots<{\n  default(p: { item: Item; index: number }): any\n}>()";
+
+    fn analyze_parent_child(parent_template: &str) -> Vec<SlotValidationIssue> {
+        analyze_parent_child_result(parent_template).slot_validation_issues
+    }
+
+    fn analyze_parent_child_result(parent_template: &str) -> CrossFileResult {
+        let mut analyzer =
+            CrossFileAnalyzer::new(CrossFileOptions::minimal().with_slot_validation(true));
+        analyzer.add_file(Path::new("Child.vue"), CHILD_SCRIPT);
+
+        let parent_script = "import Child from './Child.vue'";
+        let allocator = vize_carton::Bump::new();
+        let (root, _) = vize_armature::parse(&allocator, parent_template);
+        let mut parent = Analyzer::with_options(AnalyzerOptions::full());
+        parent.analyze_script_setup(parent_script);
+        parent.analyze_template(&root);
+        analyzer.add_file_with_analysis(Path::new("Parent.vue"), parent_script, parent.finish());
+        analyzer.rebuild_component_edges();
+        analyzer.analyze()
+    }
+
+    #[test]
+    fn test_valid_slot_destructure() {
+        let issues = analyze_parent_child(
+            r#"<Child><template #default="{ item, index: i }">{{ i }}: {{ item }}</template></Child>"#,
+        );
+
+        assert!(issues.is_empty(), "{:?}", issues);
+    }
+
+    #[test]
+    fn test_misspelled_slot_prop() {
+        let template = r#"<Child #default="{ itm }">{{ itm }}</Child>"#;
+        let issues = analyze_parent_child(template);
+
+        assert_eq!(issues.len(), 1, "{:?}", issues);
+        assert_eq!(
+            issues[0].kind,
+            SlotValidationIssueKind::UnknownSlotProp {
+                slot_name: CompactString::new("default"),
+                prop_name: CompactString::new("itm"),
+                available: vec![CompactString::new("item"), CompactString::new("index")],
+            }
+        );
+        assert!(template[issues[0].offset as usize..].starts_with("#default"));
+    }
+
+    #[test]
+    fn test_undefined_slot() {
+        let issues = analyze_parent_child(r#"<Child><template #footer>Footer</template></Child>"#);
+
+        assert_eq!(issues.len(), 1, "{:?}", issues);
+        assert_eq!(
+            issues[0].kind,
+            SlotValidationIssueKind::UndefinedSlot {
+                slot_name: CompactString::new("footer"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_related_location_is_define_slots_call() {
+        let result =
+            analyze_parent_child_result(r#"<Child><template #footer>Footer</template></Child>"#);
+
+        let diagnostic = result
+            .diagnostics
+            .iter()
+            .find(|d| matches!(d.kind, CrossFileDiagnosticKind::UndefinedSlot { .. }))
+            .expect("undefined slot diagnostic");
+        let (_, offset, _) = &diagnostic.related_files[0];
+        assert!(CHILD_SCRIPT[*offset as usize..].starts_with("defineSlots"));
+    }
+}
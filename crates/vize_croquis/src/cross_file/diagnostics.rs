@@ -154,6 +154,12 @@ pub enum CrossFileDiagnosticKind {
         slot_name: CompactString,
         component_name: CompactString,
     },
+    /// Scoped slot destructures a prop the child's slot does not pass.
+    UnknownSlotProp {
+        slot_name: CompactString,
+        prop_name: CompactString,
+        component_name: CompactString,
+    },
 
     // === Setup Context Violations ===
     /// Reactivity API (ref, reactive, computed) called outside setup context.
@@ -497,6 +503,7 @@ impl CrossFileDiagnostic {
             }
             // Slot Validation
             CrossFileDiagnosticKind::UndefinedSlot { .. } => "vize:croquis/cf/undefined-slot",
+            CrossFileDiagnosticKind::UnknownSlotProp { .. } => "vize:croquis/cf/unknown-slot-prop",
             // Setup Context Violations
             CrossFileDiagnosticKind::ReactivityOutsideSetup { .. } => {
                 "vize:croquis/cf/reactivity-outside-setup"
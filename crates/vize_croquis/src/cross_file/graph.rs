@@ -140,6 +140,26 @@ impl DependencyGraph {
         }
     }
 
+    /// Remove all outgoing edges of a module, keeping edges that point to it.
+    ///
+    /// Used when a single file is re-analyzed: its imports are rebuilt while
+    /// other modules importing it stay linked. Previously detected cycles are
+    /// discarded since they may no longer exist.
+    pub fn remove_outgoing_edges(&mut self, id: FileId) {
+        let Some(node) = self.nodes.get_mut(&id) else {
+            return;
+        };
+        let targets: SmallVec<[FileId; 8]> = node.imports.drain(..).map(|(to, _)| to).collect();
+
+        for target in targets {
+            if let Some(to_node) = self.nodes.get_mut(&target) {
+                to_node.importers.retain(|(from, _)| *from != id);
+            }
+        }
+
+        self.circular_deps.clear();
+    }
+
     /// Get a node by file ID.
     #[inline]
     pub fn get_node(&self, id: FileId) -> Option<&ModuleNode> {
@@ -224,21 +244,35 @@ impl DependencyGraph {
 
     /// Detect circular dependencies using DFS.
     pub fn detect_circular_dependencies(&mut self) {
-        self.circular_deps.clear();
+        self.circular_deps = self.find_cycles(|_| true);
+    }
 
+    /// Find cycles formed by static module imports only.
+    ///
+    /// Component usage edges are skipped, so recursive components are not
+    /// reported, and dynamic imports are skipped as they break the cycle at
+    /// load time.
+    pub fn import_cycles(&self) -> Vec<Vec<FileId>> {
+        self.find_cycles(|edge| {
+            matches!(
+                edge,
+                DependencyEdge::Import | DependencyEdge::TypeImport | DependencyEdge::ReExport
+            )
+        })
+    }
+
+    fn find_cycles(&self, follow: fn(DependencyEdge) -> bool) -> Vec<Vec<FileId>> {
         let mut visited = FxHashSet::default();
         let mut rec_stack = FxHashSet::default();
         let mut path = Vec::new();
         let mut cycles = Vec::new();
 
-        // Collect all node IDs first to avoid borrow issues
-        let node_ids: Vec<_> = self.nodes.keys().copied().collect();
-
-        for start_id in node_ids {
+        for &start_id in self.nodes.keys() {
             if !visited.contains(&start_id) {
                 Self::dfs_cycle_static(
                     &self.nodes,
                     start_id,
+                    follow,
                     &mut visited,
                     &mut rec_stack,
                     &mut path,
@@ -247,12 +281,13 @@ impl DependencyGraph {
             }
         }
 
-        self.circular_deps = cycles;
+        cycles
     }
 
     fn dfs_cycle_static(
         nodes: &FxHashMap<FileId, ModuleNode>,
         id: FileId,
+        follow: fn(DependencyEdge) -> bool,
         visited: &mut FxHashSet<FileId>,
         rec_stack: &mut FxHashSet<FileId>,
         path: &mut Vec<FileId>,
@@ -263,12 +298,15 @@ impl DependencyGraph {
         path.push(id);
 
         if let Some(node) = nodes.get(&id) {
-            for (dep_id, _) in &node.imports {
-                if !visited.contains(dep_id) {
-                    Self::dfs_cycle_static(nodes, *dep_id, visited, rec_stack, path, cycles);
-                } else if rec_stack.contains(dep_id) {
+            for &(dep_id, edge) in &node.imports {
+                if !follow(edge) {
+                    continue;
+                }
+                if !visited.contains(&dep_id) {
+                    Self::dfs_cycle_static(nodes, dep_id, follow, visited, rec_stack, path, cycles);
+                } else if rec_stack.contains(&dep_id) {
                     // Found a cycle - extract the cycle from path
-                    if let Some(start) = path.iter().position(|p| p == dep_id) {
+                    if let Some(start) = path.iter().position(|p| *p == dep_id) {
                         let cycle: Vec<_> = path[start..].to_vec();
                         cycles.push(cycle);
                     }
@@ -461,4 +499,27 @@ mod tests {
 
         assert!(!graph.circular_dependencies().is_empty());
     }
+
+    #[test]
+    fn test_remove_outgoing_edges() {
+        let mut graph = DependencyGraph::new();
+
+        let id1 = FileId::new(0);
+        let id2 = FileId::new(1);
+        let id3 = FileId::new(2);
+
+        graph.add_node(ModuleNode::new(id1, "A.vue"));
+        graph.add_node(ModuleNode::new(id2, "B.vue"));
+        graph.add_node(ModuleNode::new(id3, "C.vue"));
+
+        graph.add_edge(id1, id2, DependencyEdge::Import);
+        graph.add_edge(id3, id1, DependencyEdge::Import);
+
+        graph.remove_outgoing_edges(id1);
+
+        assert_eq!(graph.dependencies(id1).count(), 0);
+        assert_eq!(graph.dependents(id2).count(), 0);
+        // Incoming edge from C is preserved
+        assert!(graph.has_path(id3, id1));
+    }
 }
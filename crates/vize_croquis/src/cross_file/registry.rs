@@ -280,6 +280,38 @@ impl ModuleRegistry {
             .values()
             .find(|e| e.component_name.as_deref() == Some(name))
     }
+
+    /// Keys each `<script setup>` component exposes via defineExpose(), by component name.
+    ///
+    /// Components without `<script setup>` expose their whole instance and are
+    /// left out, as are those whose exposed keys can't all be listed and names
+    /// shared by several files.
+    pub fn component_exposes(&self) -> FxHashMap<String, Vec<String>> {
+        let mut exposes: FxHashMap<String, Vec<String>> = FxHashMap::default();
+        let mut ambiguous: Vec<&str> = Vec::new();
+        for entry in self.vue_components() {
+            let Some(name) = entry.component_name.as_deref() else {
+                continue;
+            };
+            let macros = &entry.analysis.macros;
+            if !entry.analysis.bindings.is_script_setup || macros.has_unknown_exposes() {
+                ambiguous.push(name);
+                continue;
+            }
+            let keys = macros
+                .exposes()
+                .iter()
+                .map(|e| e.name.to_string())
+                .collect();
+            if exposes.insert(name.to_string(), keys).is_some() {
+                ambiguous.push(name);
+            }
+        }
+        for name in ambiguous {
+            exposes.remove(name);
+        }
+        exposes
+    }
 }
 
 /// Hash source code for change detection.
@@ -317,6 +349,36 @@ mod tests {
         assert_eq!(registry.len(), 1);
     }
 
+    #[test]
+    fn test_component_exposes() {
+        use crate::{Analyzer, AnalyzerOptions};
+
+        let analyze = |script: &str| {
+            let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+            analyzer.analyze_script_setup(script);
+            analyzer.finish()
+        };
+
+        let mut registry = ModuleRegistry::new();
+        registry.register(
+            "/src/Child.vue",
+            "child",
+            analyze("const focus = () => {}\ndefineExpose({ focus })"),
+        );
+        registry.register(
+            "/src/Spread.vue",
+            "spread",
+            analyze("const api = {}\ndefineExpose({ ...api })"),
+        );
+        registry.register("/src/a/Dup.vue", "a", analyze("defineExpose({ a: 1 })"));
+        registry.register("/src/b/Dup.vue", "b", analyze("defineExpose({ b: 1 })"));
+
+        let exposes = registry.component_exposes();
+        assert_eq!(exposes.get("Child"), Some(&vec!["focus".to_string()]));
+        assert!(!exposes.contains_key("Spread"));
+        assert!(!exposes.contains_key("Dup"));
+    }
+
     #[test]
     fn test_component_name_extraction() {
         let path = Path::new("/src/components/MyButton.vue");
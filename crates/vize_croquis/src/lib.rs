@@ -54,6 +54,7 @@ pub mod script_parser;
 pub mod setup_context;
 pub mod types;
 pub mod virtual_ts;
+pub mod watch;
 
 // Cross-file analysis (opt-in)
 pub mod cross_file;
@@ -70,9 +71,9 @@ pub use symbol::*;
 
 // Re-export analysis types
 pub use analysis::{
-    AnalysisStats, BindingMetadata, Croquis, InvalidExport, InvalidExportKind, TemplateExpression,
-    TemplateExpressionKind, TypeExport, TypeExportKind, UndefinedRef, UnusedTemplateVar,
-    UnusedVarContext,
+    AnalysisStats, BindingMetadata, ComponentOptions, Croquis, FunctionSignature, InvalidExport,
+    InvalidExportKind, TemplateExpression, TemplateExpressionKind, TypeExport, TypeExportKind,
+    UndefinedRef, UnusedTemplateVar, UnusedVarContext,
 };
 pub use analyzer::{Analyzer, AnalyzerOptions};
 
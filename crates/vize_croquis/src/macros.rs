@@ -153,10 +153,25 @@ pub struct ModelDefinition {
     pub name: CompactString,
     pub local_name: CompactString,
     pub model_type: Option<CompactString>,
+    /// Runtime `type` option (e.g. `Number`), kept separately from a generic type
+    pub runtime_type: Option<CompactString>,
     pub required: bool,
     pub default_value: Option<CompactString>,
 }
 
+/// Default value passed to withDefaults()
+#[derive(Debug, Clone)]
+pub struct PropDefault {
+    /// Prop name (object key)
+    pub name: CompactString,
+    /// Default value expression source
+    pub value: CompactString,
+    /// Source start offset of the property
+    pub start: u32,
+    /// Source end offset of the property
+    pub end: u32,
+}
+
 /// Top-level await in script setup
 #[derive(Debug, Clone)]
 pub struct TopLevelAwait {
@@ -203,8 +218,12 @@ pub struct MacroTracker {
     /// Actual emit() calls in the code (not declarations)
     emit_calls: Vec<EmitCall>,
     models: Vec<ModelDefinition>,
+    /// Defaults object entries from withDefaults
+    prop_defaults: Vec<PropDefault>,
     /// Exposed properties from defineExpose
     exposes: Vec<ExposeDefinition>,
+    /// defineExpose exposes keys that can't be listed (spread, non-literal)
+    exposes_unknown: bool,
     /// Slots from defineSlots
     slots: Vec<SlotsDefinition>,
     props_destructure: Option<PropsDestructuredBindings>,
@@ -367,6 +386,18 @@ impl MacroTracker {
         &self.models
     }
 
+    /// Add a withDefaults entry
+    #[inline]
+    pub fn add_prop_default(&mut self, default: PropDefault) {
+        self.prop_defaults.push(default);
+    }
+
+    /// Get all withDefaults entries
+    #[inline]
+    pub fn prop_defaults(&self) -> &[PropDefault] {
+        &self.prop_defaults
+    }
+
     /// Add an expose definition
     #[inline]
     pub fn add_expose(&mut self, expose: ExposeDefinition) {
@@ -379,6 +410,18 @@ impl MacroTracker {
         &self.exposes
     }
 
+    /// Mark the exposed keys as incomplete
+    #[inline]
+    pub fn mark_exposes_unknown(&mut self) {
+        self.exposes_unknown = true;
+    }
+
+    /// Whether defineExpose may expose keys missing from `exposes()`
+    #[inline]
+    pub fn has_unknown_exposes(&self) -> bool {
+        self.exposes_unknown
+    }
+
     /// Add a slot definition
     #[inline]
     pub fn add_slot(&mut self, slot: SlotsDefinition) {
@@ -403,6 +446,27 @@ impl MacroTracker {
         self.props_destructure.as_ref()
     }
 
+    /// Check if a prop (by its key in defineProps) is destructured
+    ///
+    /// References to destructured props are rewritten to `__props.<key>` by
+    /// the compiler to keep them reactive.
+    #[inline]
+    pub fn is_destructured_prop(&self, name: &str) -> bool {
+        self.props_destructure
+            .as_ref()
+            .is_some_and(|d| d.bindings.contains_key(name))
+    }
+
+    /// Get the destructure default of a prop, e.g. `1` in `{ count = 1 }`
+    #[inline]
+    pub fn destructured_prop_default(&self, name: &str) -> Option<&str> {
+        self.props_destructure
+            .as_ref()?
+            .get(name)?
+            .default
+            .as_deref()
+    }
+
     /// Add top-level await
     #[inline]
     pub fn add_top_level_await(&mut self, expression: CompactString, start: u32, end: u32) {
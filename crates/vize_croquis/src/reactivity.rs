@@ -128,6 +128,34 @@ pub struct ReactivityLoss {
     pub end: u32,
 }
 
+/// A ref used directly as an operand (`count + 1` instead of `count.value + 1`)
+#[derive(Debug, Clone)]
+pub struct RefOperand {
+    pub name: CompactString,
+    /// Span of the identifier operand
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Why a computed getter does not produce its value synchronously
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AsyncComputedKind {
+    /// `computed(async () => ...)`
+    AsyncGetter,
+    /// `computed(() => fetchData().then(...))`-style getters returning a Promise
+    ReturnsPromise,
+}
+
+/// A computed whose getter is async or returns a Promise
+#[derive(Debug, Clone)]
+pub struct AsyncComputed {
+    pub name: CompactString,
+    pub kind: AsyncComputedKind,
+    /// Span of the getter function
+    pub start: u32,
+    pub end: u32,
+}
+
 /// Tracks reactive sources during analysis
 #[derive(Debug, Default)]
 pub struct ReactivityTracker {
@@ -137,6 +165,10 @@ pub struct ReactivityTracker {
     reactive_names: FxHashSet<CompactString>,
     /// Detected reactivity losses
     losses: Vec<ReactivityLoss>,
+    /// Computed getters that are async or return a Promise
+    async_computeds: Vec<AsyncComputed>,
+    /// Refs used as binary operands without `.value`
+    ref_operands: Vec<RefOperand>,
     next_id: u32,
 }
 
@@ -294,6 +326,31 @@ impl ReactivityTracker {
     pub fn has_losses(&self) -> bool {
         !self.losses.is_empty()
     }
+
+    /// Record a computed whose getter is async or returns a Promise
+    #[inline]
+    pub fn record_async_computed(&mut self, computed: AsyncComputed) {
+        self.async_computeds.push(computed);
+    }
+
+    /// Get all computed declarations with async getters
+    #[inline]
+    pub fn async_computeds(&self) -> &[AsyncComputed] {
+        &self.async_computeds
+    }
+
+    /// Record a ref used as an operand without `.value`
+    pub fn record_ref_operand(&mut self, name: CompactString, start: u32, end: u32) {
+        if self.needs_value_access(name.as_str()) {
+            self.ref_operands.push(RefOperand { name, start, end });
+        }
+    }
+
+    /// Get all refs used as operands without `.value`
+    #[inline]
+    pub fn ref_operands(&self) -> &[RefOperand] {
+        &self.ref_operands
+    }
 }
 
 #[cfg(test)]
@@ -68,6 +68,7 @@ fn test_v_for_scope() {
             index_alias: Some(CompactString::new("index")),
             source: CompactString::new("items"),
             key_expression: Some(CompactString::new("item.id")),
+            alias_pattern: None,
         },
         0,
         100,
@@ -177,6 +178,7 @@ fn test_nested_v_for() {
             index_alias: Some(CompactString::new("rowIndex")),
             source: CompactString::new("rows"),
             key_expression: None,
+            alias_pattern: None,
         },
         0,
         200,
@@ -190,6 +192,7 @@ fn test_nested_v_for() {
             index_alias: Some(CompactString::new("cellIndex")),
             source: CompactString::new("row.cells"),
             key_expression: None,
+            alias_pattern: None,
         },
         50,
         150,
@@ -223,6 +226,7 @@ fn test_nested_callback_in_v_for() {
             index_alias: Some(CompactString::new("index")),
             source: CompactString::new("items"),
             key_expression: None,
+            alias_pattern: None,
         },
         0,
         200,
@@ -633,6 +637,7 @@ fn test_scope_chain_snapshot() {
             index_alias: None,
             source: CompactString::new("items"),
             key_expression: None,
+            alias_pattern: None,
         },
         100,
         200,
@@ -718,6 +723,7 @@ fn test_snapshot_complex_nested_scopes() {
             index_alias: Some(CompactString::new("index")),
             source: CompactString::new("items"),
             key_expression: Some(CompactString::new("item.id")),
+            alias_pattern: None,
         },
         200,
         400,
@@ -824,6 +830,7 @@ fn test_snapshot_scope_transitions() {
             index_alias: None,
             source: CompactString::new("list"),
             key_expression: None,
+            alias_pattern: None,
         },
         100,
         300,
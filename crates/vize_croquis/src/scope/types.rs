@@ -186,6 +186,8 @@ pub struct VForScopeData {
     pub source: CompactString,
     /// The :key expression if present (e.g., "item.id")
     pub key_expression: Option<CompactString>,
+    /// Raw alias list if an alias is destructured (e.g., "{ id, name }, index")
+    pub alias_pattern: Option<CompactString>,
 }
 
 /// Data specific to v-slot scope
@@ -1,16 +1,20 @@
 //! Extraction functions for props, emits, and reactivity detection.
 
 use oxc_ast::ast::{
-    Argument, CallExpression, Declaration, Expression, ObjectPropertyKind, PropertyKey, TSType,
-    VariableDeclarationKind,
+    Argument, CallExpression, Declaration, Expression, FunctionBody, ObjectPropertyKind,
+    PropertyKey, Statement, TSType, VariableDeclarationKind,
 };
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::analysis::{InvalidExport, InvalidExportKind, TypeExport, TypeExportKind};
-use crate::macros::{EmitDefinition, MacroKind, ModelDefinition, PropDefinition};
+use crate::macros::{
+    EmitDefinition, ExposeDefinition, MacroKind, ModelDefinition, PropDefault, PropDefinition,
+    SlotsDefinition,
+};
 use crate::provide::ProvideKey;
-use crate::reactivity::ReactiveKind;
+use crate::reactivity::{AsyncComputedKind, ReactiveKind};
 use crate::setup_context::SetupContextViolationKind;
+use crate::watch::{WatcherEntry, WatcherKind};
 use vize_carton::{CompactString, FxHashMap};
 use vize_relief::BindingType;
 
@@ -118,20 +122,104 @@ pub fn process_call_expression(
                 })
                 .unwrap_or("modelValue");
 
+            // Options follow the name, or come first in the unnamed form
+            let options = call.arguments.iter().find_map(|arg| {
+                if let Argument::ObjectExpression(obj) = arg {
+                    Some(obj)
+                } else {
+                    None
+                }
+            });
+
+            let runtime_type = options.and_then(|obj| {
+                find_object_property(obj, "type").map(|value| {
+                    let span = value.span();
+                    CompactString::new(&source[span.start as usize..span.end as usize])
+                })
+            });
+
+            // Prefer the generic type, then the runtime `type` option
+            let model_type = call
+                .type_arguments
+                .as_ref()
+                .and_then(|tp| tp.params.first())
+                .map(|ty| {
+                    let span = ty.span();
+                    CompactString::new(&source[span.start as usize..span.end as usize])
+                })
+                .or_else(|| runtime_type.clone());
+
+            let required = options.is_some_and(|obj| {
+                matches!(
+                    find_object_property(obj, "required"),
+                    Some(Expression::BooleanLiteral(b)) if b.value
+                )
+            });
+
+            let default_value = options
+                .and_then(|obj| find_object_property(obj, "default"))
+                .map(|value| {
+                    let span = value.span();
+                    CompactString::new(&source[span.start as usize..span.end as usize])
+                });
+
             result.macros.add_model(ModelDefinition {
                 name: CompactString::new(model_name),
                 local_name: CompactString::new(model_name),
-                model_type: None,
-                required: false,
-                default_value: None,
+                model_type,
+                runtime_type,
+                required,
+                default_value,
             });
         }
 
+        MacroKind::DefineExpose => match call.arguments.first() {
+            Some(Argument::ObjectExpression(obj)) => extract_exposes_from_runtime(result, obj),
+            // `defineExpose(api)` - keys are not statically known
+            Some(_) => result.macros.mark_exposes_unknown(),
+            None => {}
+        },
+
+        MacroKind::DefineOptions => {
+            if let Some(Argument::ObjectExpression(obj)) = call.arguments.first() {
+                extract_component_options(result, obj);
+            }
+        }
+
+        MacroKind::DefineSlots => {
+            if let Some(ref type_params) = call.type_arguments {
+                extract_slots_from_type(result, &type_params.params, source);
+            }
+        }
+
         MacroKind::WithDefaults => {
             // withDefaults wraps defineProps - find the inner call
             if let Some(Argument::CallExpression(inner_call)) = call.arguments.first() {
                 process_call_expression(result, inner_call, source);
             }
+
+            // Record the defaults object entries
+            if let Some(Argument::ObjectExpression(obj)) = call.arguments.get(1) {
+                for prop in obj.properties.iter() {
+                    let ObjectPropertyKind::ObjectProperty(p) = prop else {
+                        continue;
+                    };
+                    let name = match &p.key {
+                        PropertyKey::StaticIdentifier(id) => id.name.as_str(),
+                        PropertyKey::StringLiteral(s) => s.value.as_str(),
+                        _ => continue,
+                    };
+                    let value_span = p.value.span();
+                    result.macros.add_prop_default(PropDefault {
+                        name: CompactString::new(name),
+                        value: CompactString::new(
+                            &source[value_span.start as usize..value_span.end as usize],
+                        ),
+                        start: p.span.start,
+                        end: p.span.end,
+                    });
+                }
+            }
         }
 
         _ => {}
@@ -144,7 +232,7 @@ pub fn process_call_expression(
 pub fn extract_props_from_type(
     result: &mut ScriptParseResult,
     type_params: &oxc_allocator::Vec<'_, TSType<'_>>,
-    _source: &str,
+    source: &str,
 ) {
     for tp in type_params.iter() {
         if let TSType::TSTypeLiteral(lit) = tp {
@@ -152,10 +240,14 @@ pub fn extract_props_from_type(
                 if let oxc_ast::ast::TSSignature::TSPropertySignature(prop) = member {
                     if let PropertyKey::StaticIdentifier(id) = &prop.key {
                         let name = id.name.as_str();
+                        let prop_type = prop.type_annotation.as_ref().map(|ann| {
+                            let span = ann.type_annotation.span();
+                            CompactString::new(&source[span.start as usize..span.end as usize])
+                        });
                         result.macros.add_prop(PropDefinition {
                             name: CompactString::new(name),
                             required: !prop.optional,
-                            prop_type: None,
+                            prop_type,
                             default_value: None,
                         });
                         result.bindings.add(name, BindingType::Props);
@@ -199,7 +291,7 @@ pub fn extract_props_from_runtime(
                         result.macros.add_prop(PropDefinition {
                             name: CompactString::new(name),
                             required,
-                            prop_type: None,
+                            prop_type: runtime_prop_type(&p.value),
                             default_value: None,
                         });
                         result.bindings.add(name, BindingType::Props);
@@ -212,6 +304,46 @@ pub fn extract_props_from_runtime(
     }
 }
 
+/// Get the TypeScript type of a runtime prop declaration.
+///
+/// `Number`, `{ type: Number }` and `[String, Number]` give `number`,
+/// `number` and `string | number`. Unknown constructors give `None`.
+fn runtime_prop_type(value: &Expression<'_>) -> Option<CompactString> {
+    match value {
+        Expression::Identifier(id) => {
+            let ts_type = match id.name.as_str() {
+                "String" => "string",
+                "Number" => "number",
+                "Boolean" => "boolean",
+                "Array" => "unknown[]",
+                "Object" => "Record<string, unknown>",
+                "Function" => "Function",
+                "Symbol" => "symbol",
+                "BigInt" => "bigint",
+                "Date" => "Date",
+                _ => return None,
+            };
+            Some(CompactString::new(ts_type))
+        }
+        Expression::ArrayExpression(arr) => {
+            let mut types = Vec::with_capacity(arr.elements.len());
+            for elem in arr.elements.iter() {
+                types.push(runtime_prop_type(elem.as_expression()?)?);
+            }
+            (!types.is_empty()).then(|| CompactString::new(types.join(" | ")))
+        }
+        Expression::ObjectExpression(obj) => obj.properties.iter().find_map(|prop| match prop {
+            ObjectPropertyKind::ObjectProperty(p)
+                if matches!(&p.key, PropertyKey::StaticIdentifier(id) if id.name == "type") =>
+            {
+                runtime_prop_type(&p.value)
+            }
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
 /// Detect if a prop has required: true
 fn detect_required_prop(value: &Expression<'_>) -> bool {
     if let Expression::ObjectExpression(obj) = value {
@@ -230,6 +362,125 @@ fn detect_required_prop(value: &Expression<'_>) -> bool {
     false
 }
 
+/// Record `name`/`inheritAttrs` from a component options object
+/// (`defineOptions({ ... })` or `export default { ... }`)
+pub fn extract_component_options(
+    result: &mut ScriptParseResult,
+    obj: &oxc_ast::ast::ObjectExpression<'_>,
+) {
+    let options = &mut result.component_options;
+    if let Some(Expression::StringLiteral(name)) = find_object_property(obj, "name") {
+        options.name = Some(CompactString::new(name.value.as_str()));
+    }
+    if let Some(Expression::BooleanLiteral(b)) = find_object_property(obj, "inheritAttrs") {
+        options.inherit_attrs = Some(b.value);
+    }
+    options.object_span = Some((obj.span.start, obj.span.end));
+    options.has_other_options = !obj.properties.iter().all(|prop| match prop {
+        ObjectPropertyKind::ObjectProperty(p) if !p.computed && !p.method => {
+            let key = match &p.key {
+                PropertyKey::StaticIdentifier(id) => id.name.as_str(),
+                PropertyKey::StringLiteral(lit) => lit.value.as_str(),
+                _ => return false,
+            };
+            matches!(key, "name" | "inheritAttrs")
+        }
+        _ => false,
+    });
+}
+
+/// Find a statically keyed property value in an object literal
+fn find_object_property<'b, 'a>(
+    obj: &'b oxc_ast::ast::ObjectExpression<'a>,
+    name: &str,
+) -> Option<&'b Expression<'a>> {
+    obj.properties.iter().find_map(|prop| match prop {
+        ObjectPropertyKind::ObjectProperty(p) => match &p.key {
+            PropertyKey::StaticIdentifier(id) if id.name.as_str() == name => Some(&p.value),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// Extract exposed keys from a defineExpose object (`{ focus, reset: () => {} }`)
+fn extract_exposes_from_runtime(
+    result: &mut ScriptParseResult,
+    obj: &oxc_ast::ast::ObjectExpression<'_>,
+) {
+    for prop in obj.properties.iter() {
+        // `...extra` and computed keys expose names we can't list
+        let ObjectPropertyKind::ObjectProperty(p) = prop else {
+            result.macros.mark_exposes_unknown();
+            continue;
+        };
+        let name = match &p.key {
+            PropertyKey::StaticIdentifier(id) => id.name.as_str(),
+            PropertyKey::StringLiteral(s) => s.value.as_str(),
+            _ => {
+                result.macros.mark_exposes_unknown();
+                continue;
+            }
+        };
+        result.macros.add_expose(ExposeDefinition {
+            name: CompactString::new(name),
+            expose_type: None,
+        });
+    }
+}
+
+/// Extract slots from defineSlots type parameters
+///
+/// Handles method (`default(props: { item: T }): any`) and property
+/// (`header: (props: { title: string }) => any`) members.
+pub fn extract_slots_from_type(
+    result: &mut ScriptParseResult,
+    type_params: &oxc_allocator::Vec<'_, TSType<'_>>,
+    source: &str,
+) {
+    use oxc_ast::ast::TSSignature;
+
+    // The props type is whatever follows the first parameter's name
+    let first_param_type = |params: &oxc_ast::ast::FormalParameters<'_>| {
+        params.items.first().and_then(|param| {
+            let text = &source[param.span.start as usize..param.span.end as usize];
+            text.split_once(':')
+                .map(|(_, ty)| CompactString::new(ty.trim()))
+        })
+    };
+
+    for tp in type_params.iter() {
+        let TSType::TSTypeLiteral(lit) = tp else {
+            continue;
+        };
+        for member in lit.members.iter() {
+            let (key, props_type) = match member {
+                TSSignature::TSMethodSignature(method) => {
+                    (&method.key, first_param_type(&method.params))
+                }
+                TSSignature::TSPropertySignature(prop) => {
+                    let props_type = match prop.type_annotation.as_ref().map(|t| &t.type_annotation)
+                    {
+                        Some(TSType::TSFunctionType(func)) => first_param_type(&func.params),
+                        _ => None,
+                    };
+                    (&prop.key, props_type)
+                }
+                _ => continue,
+            };
+            let name = match key {
+                PropertyKey::StaticIdentifier(id) => id.name.as_str(),
+                PropertyKey::StringLiteral(s) => s.value.as_str(),
+                _ => continue,
+            };
+            result.macros.add_slot(SlotsDefinition {
+                name: CompactString::new(name),
+                props_type,
+            });
+        }
+    }
+}
+
 /// Extract emits from TypeScript type parameters
 pub fn extract_emits_from_type(
     result: &mut ScriptParseResult,
@@ -314,6 +565,82 @@ pub fn detect_reactivity_call(
     }
 }
 
+/// Detect a `computed()` getter that is async or returns a Promise.
+///
+/// Accepts both the getter form (`computed(() => ...)`) and the writable
+/// form (`computed({ get: () => ..., set })`). Only the getter itself is
+/// inspected; async callbacks nested inside a synchronous getter are fine.
+pub fn detect_async_computed(call: &CallExpression<'_>) -> Option<(AsyncComputedKind, Span)> {
+    let getter = match call.arguments.first()?.as_expression()? {
+        Expression::ObjectExpression(obj) => find_object_property(obj, "get")?,
+        expr => expr,
+    };
+
+    match getter {
+        Expression::ArrowFunctionExpression(arrow) => {
+            if arrow.r#async {
+                return Some((AsyncComputedKind::AsyncGetter, arrow.span));
+            }
+            let returns_promise = if arrow.expression {
+                matches!(
+                    arrow.body.statements.first(),
+                    Some(Statement::ExpressionStatement(stmt)) if is_promise_expression(&stmt.expression)
+                )
+            } else {
+                body_returns_promise(&arrow.body)
+            };
+            returns_promise.then_some((AsyncComputedKind::ReturnsPromise, arrow.span))
+        }
+        Expression::FunctionExpression(func) => {
+            if func.r#async {
+                return Some((AsyncComputedKind::AsyncGetter, func.span));
+            }
+            func.body
+                .as_ref()
+                .is_some_and(|body| body_returns_promise(body))
+                .then_some((AsyncComputedKind::ReturnsPromise, func.span))
+        }
+        _ => None,
+    }
+}
+
+/// Check the top-level `return` statements of a function body for Promises
+fn body_returns_promise(body: &FunctionBody<'_>) -> bool {
+    body.statements.iter().any(|stmt| {
+        matches!(
+            stmt,
+            Statement::ReturnStatement(ret)
+                if ret.argument.as_ref().is_some_and(is_promise_expression)
+        )
+    })
+}
+
+/// Check if an expression evidently evaluates to a Promise
+fn is_promise_expression(expr: &Expression<'_>) -> bool {
+    match expr {
+        // new Promise(...)
+        Expression::NewExpression(new) => {
+            matches!(&new.callee, Expression::Identifier(id) if id.name.as_str() == "Promise")
+        }
+        Expression::CallExpression(call) => match &call.callee {
+            // Promise.resolve(...), Promise.all(...), fetch().then(...)
+            Expression::StaticMemberExpression(member) => {
+                matches!(&member.object, Expression::Identifier(id) if id.name.as_str() == "Promise")
+                    || matches!(member.property.name.as_str(), "then" | "catch" | "finally")
+            }
+            // (async () => { ... })()
+            Expression::ParenthesizedExpression(paren) => match &paren.expression {
+                Expression::ArrowFunctionExpression(arrow) => arrow.r#async,
+                Expression::FunctionExpression(func) => func.r#async,
+                _ => false,
+            },
+            _ => false,
+        },
+        Expression::ParenthesizedExpression(paren) => is_promise_expression(&paren.expression),
+        _ => false,
+    }
+}
+
 /// Detect Vue API calls that violate setup context (CSRP/Memory Leak risks)
 /// Returns true if a violation was detected and recorded
 pub fn detect_setup_context_violation(
@@ -343,6 +670,27 @@ pub fn detect_setup_context_violation(
     false
 }
 
+/// Detect calls to the function returned by defineEmits() (e.g., `emit('change')`)
+pub fn detect_emit_call(result: &mut ScriptParseResult, call: &CallExpression<'_>) {
+    let Expression::Identifier(callee) = &call.callee else {
+        return;
+    };
+    if !result.emit_aliases.contains(callee.name.as_str()) {
+        return;
+    }
+
+    let Some(event) = call.arguments.first().and_then(|arg| arg.as_expression()) else {
+        return;
+    };
+    let (event_name, is_dynamic) = match event {
+        Expression::StringLiteral(lit) => (CompactString::new(lit.value.as_str()), false),
+        _ => (CompactString::default(), true),
+    };
+    result
+        .macros
+        .add_emit_call(event_name, is_dynamic, call.span.start, call.span.end);
+}
+
 /// Detect provide() and inject() calls and track them (including through aliases)
 pub fn detect_provide_inject_call(
     result: &mut ScriptParseResult,
@@ -391,6 +739,73 @@ pub fn detect_provide_inject_call(
     }
 }
 
+/// Detect watch() / watchEffect() calls and record their sources and options
+pub fn detect_watch_call(result: &mut ScriptParseResult, call: &CallExpression<'_>, source: &str) {
+    let Expression::Identifier(callee) = &call.callee else {
+        return;
+    };
+    let Some(kind) = WatcherKind::from_name(callee.name.as_str()) else {
+        return;
+    };
+
+    let span_source = |span: Span| {
+        CompactString::new(
+            source
+                .get(span.start as usize..span.end as usize)
+                .unwrap_or(""),
+        )
+    };
+
+    let mut args = call.arguments.iter();
+    let mut sources = Vec::new();
+    if kind == WatcherKind::Watch {
+        match args.next().and_then(|arg| arg.as_expression()) {
+            Some(Expression::ArrayExpression(array)) => {
+                sources.extend(array.elements.iter().map(|el| span_source(el.span())));
+            }
+            Some(expr) => sources.push(span_source(expr.span())),
+            None => return,
+        }
+    }
+    let callback = args
+        .next()
+        .map(|arg| span_source(arg.span()))
+        .unwrap_or_default();
+
+    let mut immediate = false;
+    let mut deep = false;
+    if let Some(Expression::ObjectExpression(options)) = args.next().and_then(|a| a.as_expression())
+    {
+        for prop in options.properties.iter() {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+                continue;
+            };
+            let enabled = match &prop.value {
+                Expression::BooleanLiteral(b) => b.value,
+                Expression::NumericLiteral(n) => n.value > 0.0,
+                _ => false,
+            };
+            if let PropertyKey::StaticIdentifier(id) = &prop.key {
+                match id.name.as_str() {
+                    "immediate" => immediate = enabled,
+                    "deep" => deep = enabled,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    result.watchers.add(WatcherEntry {
+        kind,
+        sources,
+        callback,
+        immediate,
+        deep,
+        start: call.span.start,
+        end: call.span.end,
+    });
+}
+
 /// Check for ref.value extraction to a plain variable (loses reactivity)
 /// e.g., `const x = someRef.value` or `const primitiveValue = countRef.value`
 #[inline]
@@ -5,6 +5,7 @@
 //! - Top-level bindings (const, let, function, class)
 //! - Import statements
 //! - Reactivity wrappers (ref, computed, reactive)
+//! - Watchers (watch, watchEffect) and their sources
 //! - Invalid exports in script setup
 //! - Nested function scopes (arrow functions, callbacks)
 //!
@@ -19,11 +20,12 @@ mod process;
 mod walk;
 
 use oxc_allocator::Allocator;
+use oxc_ast::ast::Statement;
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
 use crate::analysis::BindingMetadata;
-use crate::analysis::{InvalidExport, TypeExport};
+use crate::analysis::{ComponentOptions, FunctionSignature, InvalidExport, TypeExport};
 use crate::macros::MacroTracker;
 use crate::provide::ProvideInjectTracker;
 use crate::reactivity::ReactivityTracker;
@@ -32,6 +34,7 @@ use crate::scope::{
     VueGlobalScopeData,
 };
 use crate::setup_context::SetupContextTracker;
+use crate::watch::WatcherTracker;
 use vize_carton::{CompactString, FxHashMap, FxHashSet};
 
 pub use process::process_statement;
@@ -54,6 +57,8 @@ pub struct ScriptParseResult {
     pub(crate) inject_aliases: FxHashSet<CompactString>,
     /// Track aliases for provide function (e.g., const p = provide; p('key', val))
     pub(crate) provide_aliases: FxHashSet<CompactString>,
+    /// Local names bound to defineEmits() (e.g., const emit = defineEmits())
+    pub(crate) emit_aliases: FxHashSet<CompactString>,
     /// Track aliases for reactivity APIs (e.g., const r = ref; r(0))
     /// Maps alias name to the original function name
     pub(crate) reactivity_aliases: FxHashMap<CompactString, CompactString>,
@@ -63,6 +68,18 @@ pub struct ScriptParseResult {
     pub(crate) is_non_setup_script: bool,
     /// Definition spans for bindings (name -> (start, end) offset in script)
     pub binding_spans: FxHashMap<CompactString, (u32, u32)>,
+    /// Parameter counts of top-level functions
+    pub function_signatures: FxHashMap<CompactString, FunctionSignature>,
+    /// Spans of top-level function definitions, including the body
+    pub function_spans: FxHashMap<CompactString, (u32, u32)>,
+    /// Bindings initialized from non-deterministic values
+    pub impure_bindings: FxHashSet<CompactString>,
+    /// Bindings created with defineAsyncComponent()
+    pub async_components: FxHashSet<CompactString>,
+    /// watch()/watchEffect() calls
+    pub watchers: WatcherTracker,
+    /// Options declared with defineOptions()
+    pub component_options: ComponentOptions,
 }
 
 /// Setup global scopes hierarchy:
@@ -268,12 +285,23 @@ pub fn parse_script(source: &str) -> ScriptParseResult {
         process::process_statement(&mut result, stmt, source);
     }
 
+    let mut statements = ret
+        .program
+        .body
+        .iter()
+        .filter(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+    result.component_options.is_only_statement = matches!(
+        statements.next(),
+        Some(Statement::ExportDefaultDeclaration(_))
+    ) && statements.next().is_none();
+
     result
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::watch::WatcherKind;
 
     #[test]
     fn test_parse_define_props_type() {
@@ -322,6 +350,268 @@ mod tests {
         assert_eq!(result.macros.emits().len(), 2);
     }
 
+    #[test]
+    fn test_parse_define_slots() {
+        let result = parse_script_setup(
+            r#"
+            defineSlots<{
+                default(props: { item: string }): any
+                header: (props: { title: string }) => any
+            }>()
+        "#,
+        );
+
+        let slots = result.macros.slots();
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[0].name, "default");
+        assert_eq!(slots[0].props_type.as_deref(), Some("{ item: string }"));
+        assert_eq!(slots[1].name, "header");
+        assert_eq!(slots[1].props_type.as_deref(), Some("{ title: string }"));
+    }
+
+    #[test]
+    fn test_parse_define_expose() {
+        let result = parse_script_setup(
+            r#"
+            const focus = () => {}
+            defineExpose({ focus, reset() {}, 'count': 0, ...extra })
+        "#,
+        );
+
+        let names: Vec<_> = result
+            .macros
+            .exposes()
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(names, ["focus", "reset", "count"]);
+        assert!(result.macros.has_unknown_exposes());
+    }
+
+    #[test]
+    fn test_parse_define_expose_literal_keys() {
+        let result = parse_script_setup("const focus = () => {}\ndefineExpose({ focus })");
+        assert!(!result.macros.has_unknown_exposes());
+
+        let result = parse_script_setup("const api = {}\ndefineExpose(api)");
+        assert!(result.macros.exposes().is_empty());
+        assert!(result.macros.has_unknown_exposes());
+    }
+
+    #[test]
+    fn test_parse_define_options() {
+        let result = parse_script_setup(
+            r#"
+            defineOptions({ name: 'FancyInput', inheritAttrs: false })
+        "#,
+        );
+
+        let options = &result.component_options;
+        assert_eq!(options.name.as_deref(), Some("FancyInput"));
+        assert_eq!(options.inherit_attrs, Some(false));
+        assert!(options.inherit_attrs_disabled());
+
+        let result = parse_script_setup("defineOptions({ name: 'Plain' })");
+        assert_eq!(result.component_options.inherit_attrs, None);
+        assert!(!result.component_options.inherit_attrs_disabled());
+    }
+
+    #[test]
+    fn test_parse_plain_script_default_export_options() {
+        let result = parse_script("export default { name: 'UserCard', inheritAttrs: false }");
+        let options = &result.component_options;
+        assert_eq!(options.name.as_deref(), Some("UserCard"));
+        assert_eq!(options.inherit_attrs, Some(false));
+        assert_eq!(options.object_span, Some((15, 56)));
+        assert!(!options.has_other_options);
+        assert!(options.is_only_statement);
+
+        let result =
+            parse_script("export const SIZES = []\nexport default { name: 'A', components: {} }");
+        let options = &result.component_options;
+        assert!(options.has_other_options);
+        assert!(!options.is_only_statement);
+    }
+
+    #[test]
+    fn test_parse_props_destructure_defaults() {
+        let result = parse_script_setup(
+            r#"
+            const { count = 1, label: text = 'none', flag } = defineProps<{
+                count?: number
+                label?: string
+                flag: boolean
+            }>()
+        "#,
+        );
+
+        let macros = &result.macros;
+        assert!(macros.is_destructured_prop("count"));
+        assert_eq!(macros.destructured_prop_default("count"), Some("1"));
+        assert!(macros.is_destructured_prop("label"));
+        assert_eq!(macros.destructured_prop_default("label"), Some("'none'"));
+        let label = macros.props_destructure().unwrap().get("label").unwrap();
+        assert_eq!(label.local.as_str(), "text");
+        assert!(macros.is_destructured_prop("flag"));
+        assert_eq!(macros.destructured_prop_default("flag"), None);
+        assert!(!macros.is_destructured_prop("text"));
+    }
+
+    #[test]
+    fn test_parse_with_defaults() {
+        let result = parse_script_setup(
+            r#"
+            const props = withDefaults(defineProps<{ count?: number; label?: string }>(), {
+                count: 0,
+                label: () => 'none',
+            })
+        "#,
+        );
+
+        assert_eq!(result.macros.props().len(), 2);
+        let defaults = result.macros.prop_defaults();
+        assert_eq!(defaults.len(), 2);
+        assert_eq!(defaults[0].name, "count");
+        assert_eq!(defaults[0].value, "0");
+        assert_eq!(defaults[1].name, "label");
+        assert_eq!(defaults[1].value, "() => 'none'");
+    }
+
+    #[test]
+    fn test_parse_emit_calls() {
+        let result = parse_script_setup(
+            r#"
+            const emit = defineEmits(['change', 'close'])
+            function onClick() {
+                emit('change', 1)
+            }
+            emit(eventName)
+        "#,
+        );
+
+        let calls = result.macros.emit_calls();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].event_name.as_str(), "change");
+        assert!(!calls[0].is_dynamic);
+        assert!(calls[1].is_dynamic);
+    }
+
+    #[test]
+    fn test_parse_impure_bindings() {
+        let result = parse_script_setup(
+            r#"
+            const now = ref(Date.now())
+            const id = Math.random().toString(36)
+            const count = ref(0)
+            const format = () => new Date().toISOString()
+        "#,
+        );
+
+        let impure = &result.impure_bindings;
+        assert!(impure.contains("now"));
+        assert!(impure.contains("id"));
+        assert!(!impure.contains("count"));
+        // Functions are only impure when called
+        assert!(!impure.contains("format"));
+    }
+
+    #[test]
+    fn test_parse_async_components() {
+        let result = parse_script_setup(
+            r#"
+            import { defineAsyncComponent } from 'vue'
+            import Eager from './Eager.vue'
+            const dac = defineAsyncComponent
+            const Lazy = defineAsyncComponent(() => import('./Lazy.vue'))
+            const Aliased = dac({ loader: () => import('./Aliased.vue') })
+        "#,
+        );
+
+        let async_components = &result.async_components;
+        assert!(async_components.contains("Lazy"));
+        assert!(async_components.contains("Aliased"));
+        assert!(!async_components.contains("Eager"));
+        assert!(!async_components.contains("dac"));
+    }
+
+    #[test]
+    fn test_parse_function_signatures() {
+        let result = parse_script_setup(
+            r#"
+            function onClick(e: MouseEvent) {}
+            const select = (id: number, label?: string) => {}
+            const log = function (level = 'info', ...args: unknown[]) {}
+            function watch(cb: (value: number) => void, { deep }: { deep?: boolean } = {}) {}
+        "#,
+        );
+
+        let signatures = &result.function_signatures;
+        assert_eq!(
+            signatures["onClick"],
+            FunctionSignature {
+                min_params: 1,
+                max_params: Some(1),
+                params: vec!["e: MouseEvent".into()],
+            }
+        );
+        assert_eq!(
+            signatures["select"],
+            FunctionSignature {
+                min_params: 1,
+                max_params: Some(2),
+                params: vec!["id: number".into(), "label?: string".into()],
+            }
+        );
+        assert_eq!(
+            signatures["log"],
+            FunctionSignature {
+                min_params: 0,
+                max_params: None,
+                params: vec!["level = 'info'".into(), "...args: unknown[]".into()],
+            }
+        );
+        assert_eq!(signatures["watch"].min_params, 1);
+        assert_eq!(signatures["watch"].max_params, Some(2));
+    }
+
+    #[test]
+    fn test_parse_function_spans() {
+        let source = r#"
+function save() { persist() }
+const load = async () => fetch()
+const count = 1
+"#;
+        let result = parse_script_setup(source);
+
+        let (start, end) = result.function_spans["save"];
+        assert_eq!(
+            &source[start as usize..end as usize],
+            "function save() { persist() }"
+        );
+        let (start, end) = result.function_spans["load"];
+        assert_eq!(&source[start as usize..end as usize], "async () => fetch()");
+        assert!(!result.function_spans.contains_key("count"));
+    }
+
+    #[test]
+    fn test_parse_define_model() {
+        let result = parse_script_setup(
+            r#"
+            const model = defineModel<string>()
+            const count = defineModel('count', { type: Number, required: true, default: 0 })
+        "#,
+        );
+
+        let models = result.macros.models();
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0].name, "modelValue");
+        assert_eq!(models[0].model_type.as_deref(), Some("string"));
+        assert_eq!(models[1].name, "count");
+        assert_eq!(models[1].model_type.as_deref(), Some("Number"));
+        assert!(models[1].required);
+        assert_eq!(models[1].default_value.as_deref(), Some("0"));
+    }
+
     #[test]
     fn test_parse_reactivity() {
         let result = parse_script_setup(
@@ -340,6 +630,38 @@ mod tests {
         assert!(result.reactivity.is_reactive("state"));
     }
 
+    #[test]
+    fn test_parse_watchers() {
+        let result = parse_script_setup(
+            r#"
+            const count = ref(0)
+            const state = reactive({ name: 'hello' })
+            watch(count, (value) => console.log(value), { immediate: true })
+            const stop = watch([count, () => state.name], () => {}, { deep: true })
+            watchEffect(() => console.log(count.value))
+        "#,
+        );
+
+        let watchers = result.watchers.watchers();
+        assert_eq!(watchers.len(), 3);
+
+        assert_eq!(watchers[0].kind, WatcherKind::Watch);
+        assert_eq!(watchers[0].sources, vec!["count"]);
+        assert_eq!(watchers[0].callback, "(value) => console.log(value)");
+        assert!(watchers[0].immediate);
+        assert!(!watchers[0].deep);
+
+        assert_eq!(watchers[1].sources, vec!["count", "() => state.name"]);
+        assert!(watchers[1].deep);
+        assert!(!watchers[1].immediate);
+
+        assert_eq!(watchers[2].kind, WatcherKind::WatchEffect);
+        assert!(watchers[2].sources.is_empty());
+        assert_eq!(watchers[2].callback, "() => console.log(count.value)");
+
+        assert_eq!(result.watchers.watching("count").count(), 2);
+    }
+
     #[test]
     fn test_parse_imports() {
         let result = parse_script_setup(
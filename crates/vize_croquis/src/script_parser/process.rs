@@ -7,12 +7,14 @@
 //! - Type declarations
 
 use oxc_ast::ast::{
-    Argument, BindingPattern, Declaration, Expression, PropertyKey, Statement,
-    VariableDeclarationKind,
+    Argument, BindingPattern, Declaration, ExportDefaultDeclarationKind, Expression, PropertyKey,
+    Statement, VariableDeclarationKind,
 };
 use oxc_span::GetSpan;
 
-use crate::analysis::{InvalidExport, InvalidExportKind, TypeExport, TypeExportKind};
+use crate::analysis::{
+    FunctionSignature, InvalidExport, InvalidExportKind, TypeExport, TypeExportKind,
+};
 use crate::macros::PropsDestructuredBindings;
 use crate::provide::InjectPattern;
 use crate::scope::{BlockKind, BlockScopeData, ClosureScopeData, ExternalModuleScopeData};
@@ -21,15 +23,15 @@ use vize_carton::CompactString;
 use vize_relief::BindingType;
 
 use super::extract::{
-    check_ref_value_extraction, detect_reactivity_call, detect_setup_context_violation,
-    extract_argument_source, extract_call_expression, extract_provide_key,
-    get_binding_type_from_kind, process_call_expression, process_invalid_export,
-    process_type_export,
+    check_ref_value_extraction, detect_async_computed, detect_reactivity_call,
+    detect_setup_context_violation, extract_argument_source, extract_call_expression,
+    extract_component_options, extract_provide_key, get_binding_type_from_kind,
+    process_call_expression, process_invalid_export, process_type_export,
 };
 use super::walk::{extract_function_params, walk_call_arguments, walk_expression, walk_statement};
 use super::ScriptParseResult;
 use crate::macros::MacroKind;
-use crate::reactivity::ReactiveKind;
+use crate::reactivity::{AsyncComputed, ReactiveKind};
 
 /// Process a single statement
 pub fn process_statement(result: &mut ScriptParseResult, stmt: &Statement<'_>, source: &str) {
@@ -51,6 +53,14 @@ pub fn process_statement(result: &mut ScriptParseResult, stmt: &Statement<'_>, s
                     .insert(CompactString::new(name), (id.span.start, id.span.end));
             }
 
+            if let Some(id) = &func.id {
+                record_function_signature(result, id.name.as_str(), &func.params, source);
+                result.function_spans.insert(
+                    CompactString::new(id.name.as_str()),
+                    (func.span.start, func.span.end),
+                );
+            }
+
             // Create closure scope and walk body
             let params = extract_function_params(&func.params);
             let name = func
@@ -188,6 +198,12 @@ pub fn process_statement(result: &mut ScriptParseResult, stmt: &Statement<'_>, s
         }
 
         Statement::ExportDefaultDeclaration(export) => {
+            // In a plain <script>, `export default { ... }` holds the component options
+            if result.is_non_setup_script {
+                if let ExportDefaultDeclarationKind::ObjectExpression(obj) = &export.declaration {
+                    extract_component_options(result, obj);
+                }
+            }
             // Default exports are invalid in script setup
             result.invalid_exports.push(InvalidExport {
                 name: CompactString::new("default"),
@@ -237,6 +253,18 @@ pub fn process_statement(result: &mut ScriptParseResult, stmt: &Statement<'_>, s
             result.scopes.exit_scope();
         }
 
+        // Control flow at top level (walk for nested scopes and expressions)
+        Statement::IfStatement(_)
+        | Statement::ForStatement(_)
+        | Statement::ForInStatement(_)
+        | Statement::ForOfStatement(_)
+        | Statement::WhileStatement(_)
+        | Statement::DoWhileStatement(_)
+        | Statement::SwitchStatement(_)
+        | Statement::TryStatement(_) => {
+            walk_statement(result, stmt, source);
+        }
+
         _ => {}
     }
 }
@@ -258,6 +286,39 @@ fn process_variable_declarator(
                 .binding_spans
                 .insert(CompactString::new(name), (id.span.start, id.span.end));
 
+            match &declarator.init {
+                Some(Expression::ArrowFunctionExpression(arrow)) => {
+                    record_function_signature(result, name, &arrow.params, source);
+                    result
+                        .function_spans
+                        .insert(CompactString::new(name), (arrow.span.start, arrow.span.end));
+                }
+                Some(Expression::FunctionExpression(func)) => {
+                    record_function_signature(result, name, &func.params, source);
+                    result
+                        .function_spans
+                        .insert(CompactString::new(name), (func.span.start, func.span.end));
+                }
+                Some(init) => {
+                    if crate::builtins::contains_nondeterministic_call(init) {
+                        result.impure_bindings.insert(CompactString::new(name));
+                    }
+                    if let Expression::CallExpression(call) = init {
+                        if let Expression::Identifier(callee) = &call.callee {
+                            let callee = callee.name.as_str();
+                            let api_name = result
+                                .reactivity_aliases
+                                .get(callee)
+                                .map_or(callee, |s| s.as_str());
+                            if api_name == "defineAsyncComponent" {
+                                result.async_components.insert(CompactString::new(name));
+                            }
+                        }
+                    }
+                }
+                None => {}
+            }
+
             // Check if the init is a macro or reactivity call
             // Use extract_call_expression to handle type assertions (as/satisfies)
             let call_extracted = if let Some(call) =
@@ -273,6 +334,10 @@ fn process_variable_declarator(
                         MacroKind::DefineModel => BindingType::SetupRef,
                         _ => get_binding_type_from_kind(kind),
                     };
+                    // Remember the emit function so emit('event') calls can be tracked
+                    if macro_kind == MacroKind::DefineEmits {
+                        result.emit_aliases.insert(CompactString::new(name));
+                    }
                     // defineModel returns a ref, register in reactivity tracker
                     if macro_kind == MacroKind::DefineModel {
                         result
@@ -295,6 +360,16 @@ fn process_variable_declarator(
                     result
                         .reactivity
                         .register(CompactString::new(name), reactive_kind, 0);
+                    if reactive_kind == ReactiveKind::Computed {
+                        if let Some((kind, span)) = detect_async_computed(call) {
+                            result.reactivity.record_async_computed(AsyncComputed {
+                                name: CompactString::new(name),
+                                kind,
+                                start: span.start,
+                                end: span.end,
+                            });
+                        }
+                    }
                     result.bindings.add(name, binding_type);
                     // Walk into the call's callback arguments to track nested scopes
                     walk_call_arguments(result, call, source);
@@ -601,9 +676,9 @@ fn process_variable_declarator(
                             .map(CompactString::new)
                             .unwrap_or_else(|| CompactString::new(&local_name));
 
-                        // Extract default value if present (assignment pattern)
-                        let default_value = if prop.shorthand {
-                            // Check if the value is an assignment pattern with default
+                        // Extract default value if present, for both `{ count = 1 }`
+                        // and aliased `{ count: c = 1 }`
+                        let default_value =
                             if let BindingPattern::AssignmentPattern(assign) = &prop.value {
                                 Some(CompactString::new(
                                     &source[assign.right.span().start as usize
@@ -611,10 +686,7 @@ fn process_variable_declarator(
                                 ))
                             } else {
                                 None
-                            }
-                        } else {
-                            None
-                        };
+                            };
 
                         destructure.insert(key, CompactString::new(&local_name), default_value);
                     }
@@ -718,6 +790,48 @@ fn is_literal_expression(expr: &Expression<'_>) -> bool {
     }
 }
 
+/// Record the parameter count of a top-level function binding
+fn record_function_signature(
+    result: &mut ScriptParseResult,
+    name: &str,
+    params: &oxc_ast::ast::FormalParameters<'_>,
+    source: &str,
+) {
+    let mut min_params = 0;
+    for (i, param) in params.items.iter().enumerate() {
+        // `a = 1` and `a?: T` may be omitted
+        let has_default = param.initializer.is_some()
+            || matches!(param.pattern, BindingPattern::AssignmentPattern(_));
+        let is_optional = param.optional;
+        if !has_default && !is_optional {
+            min_params = i as u32 + 1;
+        }
+    }
+
+    let max_params = if params.rest.is_some() {
+        None
+    } else {
+        Some(params.items.len() as u32)
+    };
+
+    let param_labels = params
+        .items
+        .iter()
+        .map(|param| param.span)
+        .chain(params.rest.as_ref().map(|rest| rest.span))
+        .map(|span| CompactString::new(source[span.start as usize..span.end as usize].trim()))
+        .collect();
+
+    result.function_signatures.insert(
+        CompactString::new(name),
+        FunctionSignature {
+            min_params,
+            max_params,
+            params: param_labels,
+        },
+    );
+}
+
 /// Check if an expression is a function expression (arrow function or function expression)
 fn is_function_expression(expr: &Expression<'_>) -> bool {
     matches!(
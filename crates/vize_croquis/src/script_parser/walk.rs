@@ -7,17 +7,17 @@
 //! - Reactivity losses (destructuring, spreading, reassignment)
 
 use oxc_ast::ast::{
-    Argument, AssignmentTarget, BindingPattern, CallExpression, Expression, ObjectPropertyKind,
-    Statement,
+    Argument, AssignmentTarget, BinaryOperator, BindingPattern, CallExpression, Expression,
+    ObjectPropertyKind, Statement,
 };
 use oxc_span::GetSpan;
 
-use crate::scope::{BlockKind, BlockScopeData, ClientOnlyScopeData, ClosureScopeData};
+use crate::scope::{BlockKind, BlockScopeData, ClientOnlyScopeData, ClosureScopeData, ScopeKind};
 use crate::ScopeBinding;
 use vize_carton::CompactString;
 use vize_relief::BindingType;
 
-use super::extract::detect_provide_inject_call;
+use super::extract::{detect_emit_call, detect_provide_inject_call, detect_watch_call};
 use super::ScriptParseResult;
 
 /// Check if a function name is a client-only lifecycle hook
@@ -157,6 +157,13 @@ pub(super) fn walk_expression(result: &mut ScriptParseResult, expr: &Expression<
             walk_expression(result, &logical.right, source);
         }
         Expression::BinaryExpression(binary) => {
+            if !matches!(
+                binary.operator,
+                BinaryOperator::In | BinaryOperator::Instanceof
+            ) {
+                check_ref_operand(result, &binary.left);
+                check_ref_operand(result, &binary.right);
+            }
             walk_expression(result, &binary.left, source);
             walk_expression(result, &binary.right, source);
         }
@@ -265,6 +272,12 @@ pub(super) fn walk_call_arguments(
     // Check for provide/inject calls
     detect_provide_inject_call(result, call, source);
 
+    // Check for emit('event') calls
+    detect_emit_call(result, call);
+
+    // Check for watch()/watchEffect() calls
+    detect_watch_call(result, call, source);
+
     // Check if this is a client-only lifecycle hook
     let is_lifecycle_hook = if let Expression::Identifier(id) = &call.callee {
         is_client_only_hook(id.name.as_str())
@@ -400,6 +413,32 @@ pub(super) fn add_binding_pattern_to_scope(
     }
 }
 
+/// Record a ref identifier used as a binary operand (`count > 5`).
+///
+/// Identifiers shadowed by a nested binding (e.g. a parameter with the
+/// same name) are skipped.
+fn check_ref_operand(result: &mut ScriptParseResult, operand: &Expression<'_>) {
+    let Expression::Identifier(id) = operand else {
+        return;
+    };
+    let name = id.name.as_str();
+    if !result.reactivity.needs_value_access(name) {
+        return;
+    }
+    let shadowed = result.scopes.lookup(name).is_some_and(|(scope, _)| {
+        matches!(
+            scope.kind,
+            ScopeKind::Function | ScopeKind::Block | ScopeKind::Callback | ScopeKind::Closure
+        )
+    });
+    if shadowed {
+        return;
+    }
+    result
+        .reactivity
+        .record_ref_operand(CompactString::new(name), id.span.start, id.span.end);
+}
+
 /// Walk a statement to find nested scopes
 #[inline]
 pub(super) fn walk_statement(result: &mut ScriptParseResult, stmt: &Statement<'_>, source: &str) {
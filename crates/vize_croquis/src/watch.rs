@@ -0,0 +1,104 @@
+//! Watcher tracking for Vue components.
+//!
+//! Records each `watch()` / `watchEffect()` call with the sources it observes,
+//! so rules can tell what a watcher depends on.
+
+use vize_carton::CompactString;
+
+/// Kind of watcher API call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatcherKind {
+    /// `watch(source, cb, options?)` - explicit sources
+    Watch,
+    /// `watchEffect(cb, options?)` - dependencies collected while running
+    WatchEffect,
+    /// `watchPostEffect(cb)`
+    WatchPostEffect,
+    /// `watchSyncEffect(cb)`
+    WatchSyncEffect,
+}
+
+impl WatcherKind {
+    /// Get the kind for a callee name.
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "watch" => Some(Self::Watch),
+            "watchEffect" => Some(Self::WatchEffect),
+            "watchPostEffect" => Some(Self::WatchPostEffect),
+            "watchSyncEffect" => Some(Self::WatchSyncEffect),
+            _ => None,
+        }
+    }
+
+    /// Get the API name.
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Watch => "watch",
+            Self::WatchEffect => "watchEffect",
+            Self::WatchPostEffect => "watchPostEffect",
+            Self::WatchSyncEffect => "watchSyncEffect",
+        }
+    }
+
+    /// Whether dependencies are collected implicitly from the callback.
+    #[inline]
+    pub const fn is_effect(&self) -> bool {
+        !matches!(self, Self::Watch)
+    }
+}
+
+/// A watch()/watchEffect() call
+#[derive(Debug, Clone)]
+pub struct WatcherEntry {
+    pub kind: WatcherKind,
+    /// Source expressions (`count`, `() => state.x`); an array source is split
+    /// into its elements. Empty for effect watchers.
+    pub sources: Vec<CompactString>,
+    /// Callback source text
+    pub callback: CompactString,
+    /// `immediate: true` option
+    pub immediate: bool,
+    /// `deep: true` (or a positive depth) option
+    pub deep: bool,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Tracks watchers during analysis
+#[derive(Debug, Default)]
+pub struct WatcherTracker {
+    watchers: Vec<WatcherEntry>,
+}
+
+impl WatcherTracker {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a watcher call
+    #[inline]
+    pub fn add(&mut self, entry: WatcherEntry) {
+        self.watchers.push(entry);
+    }
+
+    /// Get all watchers in source order
+    #[inline]
+    pub fn watchers(&self) -> &[WatcherEntry] {
+        &self.watchers
+    }
+
+    /// Watchers that list `name` as a source
+    pub fn watching(&self, name: &str) -> impl Iterator<Item = &WatcherEntry> {
+        self.watchers
+            .iter()
+            .filter(move |w| w.sources.iter().any(|s| s == name))
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.watchers.is_empty()
+    }
+}
@@ -7,7 +7,7 @@ use crate::options::FormatOptions;
 use crate::script;
 use crate::style;
 use crate::template;
-use vize_atelier_sfc::{parse_sfc, SfcParseOptions};
+use vize_atelier_sfc::{parse_sfc, BlockLocation, SfcParseOptions};
 use vize_carton::Allocator;
 
 /// Result of formatting a Vue SFC
@@ -47,7 +47,7 @@ impl<'a> GlyphFormatter<'a> {
 
         // Collect all blocks with their sort keys
         enum Block<'b> {
-            Script(&'b vize_atelier_sfc::SfcScriptBlock<'b>, bool), // (block, is_setup)
+            Script(&'b vize_atelier_sfc::SfcScriptBlock<'b>),
             Template(&'b vize_atelier_sfc::SfcTemplateBlock<'b>),
             Style(&'b vize_atelier_sfc::SfcStyleBlock<'b>),
             Custom(&'b vize_atelier_sfc::SfcCustomBlock<'b>),
@@ -61,7 +61,7 @@ impl<'a> GlyphFormatter<'a> {
             } else {
                 script.loc.tag_start
             };
-            blocks.push((order, Block::Script(script, false)));
+            blocks.push((order, Block::Script(script)));
         }
         if let Some(script_setup) = &descriptor.script_setup {
             let order = if self.options.sort_blocks {
@@ -69,7 +69,7 @@ impl<'a> GlyphFormatter<'a> {
             } else {
                 script_setup.loc.tag_start
             };
-            blocks.push((order, Block::Script(script_setup, true)));
+            blocks.push((order, Block::Script(script_setup)));
         }
         if let Some(template) = &descriptor.template {
             let order = if self.options.sort_blocks {
@@ -109,31 +109,33 @@ impl<'a> GlyphFormatter<'a> {
                 output.extend_from_slice(newline);
             }
             match block {
-                Block::Script(script, is_setup) => {
+                Block::Script(script) => {
                     self.format_script_block_fast(
                         &mut output,
+                        opening_tag(source, &script.loc),
                         &script.content,
-                        *is_setup,
-                        &script.lang,
                     )?;
                 }
                 Block::Template(template) => {
                     self.format_template_block_fast(
                         &mut output,
+                        opening_tag(source, &template.loc),
                         &template.content,
-                        &template.lang,
                     )?;
                 }
                 Block::Style(style) => {
                     self.format_style_block_fast(
                         &mut output,
+                        opening_tag(source, &style.loc),
                         &style.content,
-                        style.scoped,
                         &style.lang,
                     )?;
                 }
                 Block::Custom(block) => {
-                    self.format_custom_block_fast(&mut output, &block.block_type, &block.content)?;
+                    // Custom blocks (i18n, docs, ...) are emitted exactly as written
+                    output.extend_from_slice(
+                        source[block.loc.tag_start..block.loc.tag_end].as_bytes(),
+                    );
                 }
             }
         }
@@ -179,24 +181,13 @@ impl<'a> GlyphFormatter<'a> {
     fn format_script_block_fast(
         &self,
         output: &mut Vec<u8>,
+        open_tag: &str,
         content: &str,
-        is_setup: bool,
-        lang: &Option<std::borrow::Cow<'_, str>>,
     ) -> Result<(), FormatError> {
         let formatted_content =
             script::format_script_content(content.trim(), self.options, self.allocator)?;
 
-        // Build the opening tag using byte operations
-        output.extend_from_slice(b"<script");
-        if is_setup {
-            output.extend_from_slice(b" setup");
-        }
-        if let Some(lang) = lang {
-            output.extend_from_slice(b" lang=\"");
-            output.extend_from_slice(lang.as_bytes());
-            output.push(b'"');
-        }
-        output.push(b'>');
+        output.extend_from_slice(open_tag.as_bytes());
         output.extend_from_slice(self.options.newline_bytes());
 
         // Add content with indentation if configured
@@ -229,19 +220,12 @@ impl<'a> GlyphFormatter<'a> {
     fn format_template_block_fast(
         &self,
         output: &mut Vec<u8>,
+        open_tag: &str,
         content: &str,
-        lang: &Option<std::borrow::Cow<'_, str>>,
     ) -> Result<(), FormatError> {
         let formatted_content = template::format_template_content(content, self.options)?;
 
-        // Build the opening tag
-        output.extend_from_slice(b"<template");
-        if let Some(lang) = lang {
-            output.extend_from_slice(b" lang=\"");
-            output.extend_from_slice(lang.as_bytes());
-            output.push(b'"');
-        }
-        output.push(b'>');
+        output.extend_from_slice(open_tag.as_bytes());
         output.extend_from_slice(self.options.newline_bytes());
 
         // Template content is always indented by one level from the template tag
@@ -267,31 +251,15 @@ impl<'a> GlyphFormatter<'a> {
     fn format_style_block_fast(
         &self,
         output: &mut Vec<u8>,
+        open_tag: &str,
         content: &str,
-        scoped: bool,
         lang: &Option<std::borrow::Cow<'_, str>>,
     ) -> Result<(), FormatError> {
-        // Use lightningcss for plain CSS; for preprocessor languages, just trim
-        let is_plain_css = lang.as_ref().is_none_or(|l| l.as_ref() == "css");
-        let formatted_content = if is_plain_css {
-            style::format_style_content(content, self.options)
-                .unwrap_or_else(|_| content.trim().to_string())
-        } else {
-            content.trim().to_string()
-        };
-        let formatted_content = formatted_content.as_str();
-
-        // Build the opening tag
-        output.extend_from_slice(b"<style");
-        if scoped {
-            output.extend_from_slice(b" scoped");
-        }
-        if let Some(lang) = lang {
-            output.extend_from_slice(b" lang=\"");
-            output.extend_from_slice(lang.as_bytes());
-            output.push(b'"');
-        }
-        output.push(b'>');
+        // Content lightningcss can't format is kept as written
+        let formatted_content = style::format_style_content(content, lang.as_deref(), self.options);
+        let formatted_content = formatted_content.trim();
+
+        output.extend_from_slice(open_tag.as_bytes());
         output.extend_from_slice(self.options.newline_bytes());
 
         // Add content with indentation if configured
@@ -318,25 +286,10 @@ impl<'a> GlyphFormatter<'a> {
 
         Ok(())
     }
+}
 
-    /// Format a custom block using byte operations
-    #[inline]
-    fn format_custom_block_fast(
-        &self,
-        output: &mut Vec<u8>,
-        block_type: &str,
-        content: &str,
-    ) -> Result<(), FormatError> {
-        output.push(b'<');
-        output.extend_from_slice(block_type.as_bytes());
-        output.push(b'>');
-        output.extend_from_slice(self.options.newline_bytes());
-        output.extend_from_slice(content.trim().as_bytes());
-        output.extend_from_slice(self.options.newline_bytes());
-        output.extend_from_slice(b"</");
-        output.extend_from_slice(block_type.as_bytes());
-        output.push(b'>');
-
-        Ok(())
-    }
+/// The original opening tag of a block, attributes included.
+#[inline]
+fn opening_tag<'s>(source: &'s str, loc: &BlockLocation) -> &'s str {
+    &source[loc.tag_start..loc.start]
 }
@@ -0,0 +1,285 @@
+//! Import sorting for `<script>` / `<script setup>` blocks.
+//!
+//! Reorders the import declarations at the top of a script into groups,
+//! separated by a blank line and alphabetized by specifier within each group:
+//! 1. Builtins and Vue (`node:*`, `vue`, `@vue/*`)
+//! 2. External packages
+//! 3. Aliased project paths (`@/`, `~/`, `#/`)
+//! 4. Relative paths
+//!
+//! Side-effect imports (`import './x.css'`) are never moved, and imports are
+//! never moved across them, so module evaluation order is preserved.
+
+use oxc_allocator::Allocator as OxcAllocator;
+use oxc_ast::ast::Statement;
+use oxc_formatter::get_parse_options;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+/// Node.js builtin modules importable without the `node:` prefix
+const NODE_BUILTINS: &[&str] = &[
+    "assert",
+    "buffer",
+    "child_process",
+    "crypto",
+    "events",
+    "fs",
+    "fs/promises",
+    "http",
+    "https",
+    "module",
+    "net",
+    "os",
+    "path",
+    "process",
+    "stream",
+    "url",
+    "util",
+    "worker_threads",
+    "zlib",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ImportGroup {
+    Builtin,
+    External,
+    Aliased,
+    Relative,
+}
+
+impl ImportGroup {
+    fn of(specifier: &str) -> Self {
+        if specifier.starts_with('.') {
+            Self::Relative
+        } else if specifier.starts_with("@/")
+            || specifier.starts_with("~/")
+            || specifier.starts_with("#/")
+        {
+            Self::Aliased
+        } else if specifier.starts_with("node:")
+            || NODE_BUILTINS.contains(&specifier)
+            || specifier == "vue"
+            || specifier.starts_with("vue/")
+            || specifier.starts_with("@vue/")
+        {
+            Self::Builtin
+        } else {
+            Self::External
+        }
+    }
+}
+
+/// An import declaration together with its leading comments
+struct ImportChunk<'s> {
+    text: &'s str,
+    specifier: &'s str,
+    group: ImportGroup,
+    side_effect: bool,
+}
+
+/// Sort the leading import declarations of a script.
+///
+/// Returns `None` if the source has no imports to sort or cannot be parsed.
+pub fn sort_imports(source: &str) -> Option<String> {
+    let allocator = OxcAllocator::default();
+    let source_type = SourceType::ts().with_module(true);
+    let parsed = Parser::new(&allocator, source, source_type)
+        .with_options(get_parse_options())
+        .parse();
+
+    if !parsed.errors.is_empty() {
+        return None;
+    }
+
+    let mut chunks = Vec::new();
+    let mut block_start = None;
+    let mut prev_end = 0;
+
+    for stmt in &parsed.program.body {
+        let Statement::ImportDeclaration(decl) = stmt else {
+            break;
+        };
+        let start = decl.span.start as usize;
+        let end = line_end_with_trailing_comment(source, decl.span.end as usize);
+
+        // Comments between imports belong to the import below them. Before the
+        // first import, only comments not separated by a blank line do.
+        let chunk_start = if block_start.is_none() {
+            attached_comment_start(source, start)
+        } else {
+            start - source[prev_end..start].trim_start().len()
+        };
+        block_start.get_or_insert(chunk_start);
+
+        let specifier = decl.source.value.as_str();
+        chunks.push(ImportChunk {
+            text: &source[chunk_start..end],
+            specifier,
+            group: ImportGroup::of(specifier),
+            side_effect: decl.specifiers.is_none(),
+        });
+        prev_end = end;
+    }
+
+    if chunks.len() < 2 {
+        return None;
+    }
+    let block_start = block_start?;
+
+    // Sort each run of imports between side-effect imports independently
+    let mut sorted = String::with_capacity(prev_end - block_start);
+    let mut run: Vec<&ImportChunk<'_>> = Vec::new();
+    for chunk in &chunks {
+        if chunk.side_effect {
+            write_run(&mut sorted, &mut run);
+            push_line(&mut sorted, chunk.text);
+        } else {
+            run.push(chunk);
+        }
+    }
+    write_run(&mut sorted, &mut run);
+
+    let mut result = String::with_capacity(source.len() + 8);
+    result.push_str(&source[..block_start]);
+    result.push_str(sorted.trim_end());
+    result.push_str(&source[prev_end..]);
+    Some(result)
+}
+
+/// Write a run of imports grouped and alphabetized, then clear it.
+fn write_run(output: &mut String, run: &mut Vec<&ImportChunk<'_>>) {
+    run.sort_by(|a, b| {
+        a.group
+            .cmp(&b.group)
+            .then_with(|| a.specifier.to_lowercase().cmp(&b.specifier.to_lowercase()))
+    });
+
+    let mut prev_group = None;
+    for chunk in run.iter() {
+        if prev_group.is_some_and(|group| group != chunk.group) {
+            output.push('\n');
+        }
+        push_line(output, chunk.text);
+        prev_group = Some(chunk.group);
+    }
+    run.clear();
+}
+
+#[inline]
+fn push_line(output: &mut String, text: &str) {
+    output.push_str(text);
+    output.push('\n');
+}
+
+/// Extend an import's end over a comment on the same line (`import a from 'a' // note`).
+fn line_end_with_trailing_comment(source: &str, end: usize) -> usize {
+    let rest = &source[end..];
+    let line_len = rest.find('\n').unwrap_or(rest.len());
+    let trailing = rest[..line_len].trim();
+    if trailing.starts_with("//") || trailing.starts_with("/*") {
+        end + rest[..line_len].trim_end().len()
+    } else {
+        end
+    }
+}
+
+/// Start of the comment lines directly above `start`, with no blank line in between.
+fn attached_comment_start(source: &str, start: usize) -> usize {
+    let before = &source[..start];
+    // The import does not start its own line
+    if !before.is_empty() && !before.ends_with('\n') {
+        return start;
+    }
+
+    let mut chunk_start = start;
+    let mut offset = start;
+    for line in before.strip_suffix('\n').unwrap_or(before).rsplit('\n') {
+        let trimmed = line.trim();
+        let is_comment = trimmed.starts_with("//")
+            || trimmed.starts_with("/*")
+            || trimmed.starts_with('*')
+            || trimmed.ends_with("*/");
+        if !is_comment {
+            break;
+        }
+        offset -= line.len() + 1;
+        chunk_start = offset;
+    }
+    chunk_start
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_shuffled_imports_into_groups() {
+        let source = "\
+import { helper } from './utils'
+import Button from '@/components/Button.vue'
+import { ref } from 'vue'
+import axios from 'axios'
+import { readFile } from 'node:fs'
+import dayjs from 'dayjs'
+
+const count = ref(0)
+";
+        let result = sort_imports(source).unwrap();
+        assert_eq!(
+            result,
+            "\
+import { readFile } from 'node:fs'
+import { ref } from 'vue'
+
+import axios from 'axios'
+import dayjs from 'dayjs'
+
+import Button from '@/components/Button.vue'
+
+import { helper } from './utils'
+
+const count = ref(0)
+"
+        );
+    }
+
+    #[test]
+    fn test_side_effect_import_is_a_barrier() {
+        let source = "\
+import b from 'b'
+import './setup.css'
+import a from 'a'
+";
+        let result = sort_imports(source).unwrap();
+        assert_eq!(result, source);
+    }
+
+    #[test]
+    fn test_leading_comments_move_with_import() {
+        let source = "\
+// License header
+
+import b from 'b'
+// Needed for a
+import a from 'a' // trailing
+";
+        let result = sort_imports(source).unwrap();
+        assert_eq!(
+            result,
+            "\
+// License header
+
+// Needed for a
+import a from 'a' // trailing
+import b from 'b'
+"
+        );
+    }
+
+    #[test]
+    fn test_scoped_package_is_external() {
+        assert_eq!(ImportGroup::of("@vueuse/core"), ImportGroup::External);
+        assert_eq!(ImportGroup::of("@vue/reactivity"), ImportGroup::Builtin);
+        assert_eq!(ImportGroup::of("@/stores/user"), ImportGroup::Aliased);
+    }
+}
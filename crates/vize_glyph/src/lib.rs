@@ -40,6 +40,7 @@
 
 mod error;
 mod formatter;
+mod imports;
 mod options;
 mod script;
 mod style;
@@ -91,7 +92,15 @@ pub fn format_template(source: &str, options: &FormatOptions) -> Result<String,
 /// Format only the CSS/style content
 #[inline]
 pub fn format_style(source: &str, options: &FormatOptions) -> Result<String, FormatError> {
-    style::format_style_content(source, options)
+    style::format_css(source, options)
+}
+
+/// Format the content of a `<style>` block in the given language.
+///
+/// Content that cannot be formatted is returned unchanged.
+#[inline]
+pub fn format_style_content(source: &str, lang: Option<&str>, options: &FormatOptions) -> String {
+    style::format_style_content(source, lang, options)
 }
 
 #[cfg(test)]
@@ -158,6 +167,87 @@ const msg = 'hello'
         assert!(result.code.contains("</style>"));
     }
 
+    #[test]
+    fn test_format_sfc_reindents_script_setup_and_template() {
+        let source = r#"<script setup lang="ts">
+      import {ref} from 'vue'
+          const count=ref(0)
+    function inc(){count.value++}
+</script>
+
+<template>
+<div><button @click="inc">{{count}}</button></div>
+</template>
+"#;
+        let options = FormatOptions::default();
+        let result = format_sfc(source, &options).unwrap();
+
+        assert!(result.changed);
+        // Script statements are dedented to the block's top level
+        assert!(result.code.contains("\nimport { ref } from"));
+        assert!(result.code.contains("\nconst count = ref(0)"));
+        assert!(result.code.contains("\nfunction inc() {\n  count.value++"));
+        // Template formatting still applies
+        assert!(result.code.contains("\n  <div>"));
+        assert!(result.code.contains("{{ count }}"));
+    }
+
+    #[test]
+    fn test_format_sfc_preserves_block_attributes() {
+        let source = r#"<script setup lang="ts" generic="T extends string">
+const a=1
+</script>
+
+<style module="classes" lang="css">
+.a{color:red}
+</style>
+"#;
+        let options = FormatOptions::default();
+        let result = format_sfc(source, &options).unwrap();
+
+        assert!(result
+            .code
+            .contains(r#"<script setup lang="ts" generic="T extends string">"#));
+        assert!(result
+            .code
+            .contains(r#"<style module="classes" lang="css">"#));
+        assert!(result.code.contains("const a = 1;"));
+    }
+
+    #[test]
+    fn test_format_sfc_formats_style_blocks() {
+        let source = r#"<style scoped>
+.a :deep(.x){color:red}
+</style>
+
+<style lang="scss">
+$gap: 4px;
+.b{margin:$gap}
+</style>
+"#;
+        let options = FormatOptions::default();
+        let result = format_sfc(source, &options).unwrap();
+
+        assert!(result
+            .code
+            .contains("<style scoped>\n.a :deep(.x) {\n  color: red;\n}\n</style>"));
+        assert!(result
+            .code
+            .contains("$gap: 4px;\n.b{margin:$gap}\n</style>"));
+    }
+
+    #[test]
+    fn test_format_sfc_preserves_custom_blocks() {
+        let custom = r#"<i18n lang="json">
+{ "en":   { "hello":"Hello" } }
+</i18n>"#;
+        let source = format!("<template>\n<div/>\n</template>\n\n{}\n", custom);
+        let options = FormatOptions::default();
+        let result = format_sfc(&source, &options).unwrap();
+
+        assert!(result.code.contains(custom));
+    }
+
     #[test]
     fn test_allocator_reuse() {
         let allocator = Allocator::with_capacity(4096);
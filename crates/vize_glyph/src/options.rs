@@ -2,14 +2,17 @@
 //!
 //! These options are designed to be compatible with Prettier and oxfmt.
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Formatting options for Vue SFC
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FormatOptions {
     /// Maximum line width (default: 100)
-    #[serde(default = "default_print_width")]
+    #[serde(
+        default = "default_print_width",
+        deserialize_with = "deserialize_print_width"
+    )]
     pub print_width: u32,
 
     /// Number of spaces per indentation level (default: 2)
@@ -100,6 +103,24 @@ pub struct FormatOptions {
     /// When false, blocks are preserved in their original source order.
     #[serde(default = "default_true")]
     pub sort_blocks: bool,
+
+    /// Keep blank lines between sibling template nodes (default: true)
+    /// Runs of blank lines collapse to one; blank lines at the start or end of
+    /// an element's children are still removed.
+    #[serde(default = "default_true")]
+    pub preserve_blank_lines: bool,
+
+    /// Sort and group leading import declarations in scripts (default: false)
+    /// Groups: builtins/vue → external packages → aliased (`@/`) → relative.
+    /// Side-effect imports keep their position.
+    #[serde(default)]
+    pub sort_imports: bool,
+
+    /// Self-closing style per element category (default: preserve as written)
+    /// Mirrors the `vue/html-self-closing` lint rule: `void` (`<br />`),
+    /// `normal` (empty `<div />`) and `component` (empty `<Foo />`).
+    #[serde(default)]
+    pub html_self_closing: HtmlSelfClosing,
 }
 
 impl Default for FormatOptions {
@@ -126,6 +147,9 @@ impl Default for FormatOptions {
             attribute_groups: None,
             normalize_directive_shorthands: true,
             sort_blocks: true,
+            preserve_blank_lines: true,
+            sort_imports: false,
+            html_self_closing: HtmlSelfClosing::default(),
         }
     }
 }
@@ -134,6 +158,11 @@ fn default_print_width() -> u32 {
     100
 }
 
+fn deserialize_print_width<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    let width = u32::deserialize(deserializer)?;
+    FormatOptions::validate_print_width(width).map_err(serde::de::Error::custom)
+}
+
 fn default_tab_width() -> u8 {
     2
 }
@@ -205,6 +234,36 @@ pub enum QuoteProps {
     Preserve,
 }
 
+/// Self-closing style for each element category
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HtmlSelfClosing {
+    /// Void HTML elements such as `<br>` and `<img>` (default: Any)
+    #[serde(default)]
+    pub void: SelfClosingStyle,
+
+    /// Empty native HTML elements such as `<div></div>` (default: Any)
+    #[serde(default)]
+    pub normal: SelfClosingStyle,
+
+    /// Empty components such as `<MyButton></MyButton>` (default: Any)
+    #[serde(default)]
+    pub component: SelfClosingStyle,
+}
+
+/// Self-closing style options
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SelfClosingStyle {
+    /// Keep the style as written
+    #[default]
+    Any,
+    /// Self-close (`<br />`, `<Foo />`)
+    Always,
+    /// Never self-close (`<br>`, `<Foo></Foo>`)
+    Never,
+}
+
 impl FormatOptions {
     /// Create options with Prettier defaults
     #[inline]
@@ -215,6 +274,18 @@ impl FormatOptions {
         }
     }
 
+    /// Check that `width` is a `print_width` oxc_formatter accepts.
+    ///
+    /// Options read from config files or the command line go through this, so
+    /// the conversion to `oxc_formatter::FormatOptions` never has to fail.
+    pub fn validate_print_width(width: u32) -> Result<u32, String> {
+        u16::try_from(width)
+            .ok()
+            .and_then(|w| oxc_formatter::LineWidth::try_from(w).ok())
+            .map(|_| width)
+            .ok_or_else(|| format!("unsupported printWidth {width}"))
+    }
+
     /// Convert to `oxc_formatter::FormatOptions`
     pub fn to_oxc_format_options(&self) -> oxc_formatter::FormatOptions {
         use oxc_formatter::{
@@ -229,7 +300,8 @@ impl FormatOptions {
                 IndentStyle::Space
             },
             indent_width: IndentWidth::try_from(self.tab_width).unwrap_or_default(),
-            line_width: LineWidth::try_from(self.print_width as u16).unwrap_or_default(),
+            line_width: LineWidth::try_from(self.print_width.min(u16::MAX as u32) as u16)
+                .unwrap_or_default(),
             line_ending: match self.end_of_line {
                 EndOfLine::Lf | EndOfLine::Auto => LineEnding::Lf,
                 EndOfLine::Crlf => LineEnding::Crlf,
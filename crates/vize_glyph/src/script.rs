@@ -4,9 +4,10 @@
 //! code using OXC's formatter (oxfmt).
 
 use crate::error::FormatError;
+use crate::imports;
 use crate::options::FormatOptions;
 use oxc_allocator::Allocator as OxcAllocator;
-use oxc_formatter::{get_parse_options, Formatter as OxcFormatter};
+use oxc_formatter::{get_parse_options, Formatter as OxcFormatter, LineWidth};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 use vize_carton::Allocator;
@@ -26,6 +27,12 @@ pub fn format_script_content(
         return Ok(String::new());
     }
 
+    let sorted = options
+        .sort_imports
+        .then(|| imports::sort_imports(source))
+        .flatten();
+    let source = sorted.as_deref().unwrap_or(source);
+
     // Use OXC's allocator for parsing (required by oxc_parser)
     let oxc_allocator = OxcAllocator::default();
 
@@ -51,7 +58,20 @@ pub fn format_script_content(
 
 /// Format a JS expression (for use in template directive values and interpolations).
 /// Returns None if the expression cannot be parsed/formatted.
+#[inline]
 pub fn format_js_expression(expr: &str, options: &FormatOptions) -> Option<String> {
+    format_js_expression_with_width(expr, options, options.print_width)
+}
+
+/// Format a JS expression so that its lines fit within `print_width` columns.
+///
+/// Used for directive values, whose available width depends on where the
+/// attribute starts. Continuation lines are indented relative to column 0.
+pub fn format_js_expression_with_width(
+    expr: &str,
+    options: &FormatOptions,
+    print_width: u32,
+) -> Option<String> {
     let trimmed = expr.trim();
     if trimmed.is_empty() {
         return Some(String::new());
@@ -72,7 +92,10 @@ pub fn format_js_expression(expr: &str, options: &FormatOptions) -> Option<Strin
         return None;
     }
 
-    let oxc_options = options.to_oxc_format_options();
+    // The `void ` prefix occupies the first line too
+    let mut oxc_options = options.to_oxc_format_options();
+    oxc_options.line_width =
+        LineWidth::try_from((print_width + 5).min(u16::MAX as u32) as u16).unwrap_or_default();
     let formatted = OxcFormatter::new(&oxc_allocator, oxc_options).build(&parsed.program);
 
     // Extract the expression back from the formatted output.
@@ -164,4 +187,19 @@ mod tests {
         let result = format_js_expression("", &options);
         assert_eq!(result, Some(String::new()));
     }
+
+    #[test]
+    fn test_format_with_sorted_imports() {
+        let source = "import { helper } from './utils'\nimport axios from 'axios'\nimport { ref } from 'vue'\nconst a = ref(helper(axios))";
+        let mut options = FormatOptions::default();
+        options.sort_imports = true;
+        let allocator = Allocator::default();
+        let result = format_script_content(source, &options, &allocator).unwrap();
+
+        let vue = result.find("\"vue\"").unwrap();
+        let axios = result.find("\"axios\"").unwrap();
+        let utils = result.find("\"./utils\"").unwrap();
+        assert!(vue < axios && axios < utils, "{result}");
+        assert!(result.contains("\"vue\";\n\nimport axios"), "{result}");
+    }
 }
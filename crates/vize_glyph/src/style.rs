@@ -2,19 +2,40 @@
 //!
 //! This module provides formatting for CSS/SCSS/Less content
 //! in Vue SFC `<style>` blocks using lightningcss for parsing and printing.
+//!
+//! Vue-specific selectors (`:deep()`, `:slotted()`, `:global()`) are kept as
+//! written. Content lightningcss cannot parse (e.g. SCSS variables or Less
+//! mixins) is left unchanged.
 
 use crate::error::FormatError;
 use crate::options::FormatOptions;
-use lightningcss::stylesheet::{ParserOptions, PrinterOptions, StyleSheet};
+use lightningcss::stylesheet::{ParserFlags, ParserOptions, PrinterOptions, StyleSheet};
+
+/// Format the content of a `<style>` block.
+///
+/// Returns the source unchanged when it cannot be formatted safely: for
+/// indentation-based languages, malformed or non-CSS syntax, and content with
+/// comments (which lightningcss does not print).
+pub fn format_style_content(source: &str, lang: Option<&str>, options: &FormatOptions) -> String {
+    if matches!(lang, Some("sass" | "stylus" | "styl")) || source.contains("/*") {
+        return source.to_string();
+    }
+
+    format_css(source, options).unwrap_or_else(|_| source.to_string())
+}
 
 /// Format CSS content using lightningcss
-pub fn format_style_content(source: &str, options: &FormatOptions) -> Result<String, FormatError> {
+pub fn format_css(source: &str, options: &FormatOptions) -> Result<String, FormatError> {
     let trimmed = source.trim();
     if trimmed.is_empty() {
         return Ok(String::new());
     }
 
-    let stylesheet = StyleSheet::parse(trimmed, ParserOptions::default())
+    let parser_options = ParserOptions {
+        flags: ParserFlags::NESTING | ParserFlags::DEEP_SELECTOR_COMBINATOR,
+        ..Default::default()
+    };
+    let stylesheet = StyleSheet::parse(trimmed, parser_options)
         .map_err(|e| FormatError::StyleFormatError(e.to_string()))?;
 
     let indent_width = options.tab_width;
@@ -78,7 +99,7 @@ mod tests {
     fn test_format_simple_css() {
         let source = ".container{color:red;display:flex;gap:8px}";
         let options = FormatOptions::default();
-        let result = format_style_content(source, &options).unwrap();
+        let result = format_css(source, &options).unwrap();
 
         assert!(result.contains(".container"));
         assert!(result.contains("color:"));
@@ -89,7 +110,7 @@ mod tests {
     fn test_format_empty_css() {
         let source = "";
         let options = FormatOptions::default();
-        let result = format_style_content(source, &options).unwrap();
+        let result = format_css(source, &options).unwrap();
         assert!(result.is_empty());
     }
 
@@ -97,7 +118,42 @@ mod tests {
     fn test_format_css_whitespace_only() {
         let source = "   \n\t  ";
         let options = FormatOptions::default();
-        let result = format_style_content(source, &options).unwrap();
+        let result = format_css(source, &options).unwrap();
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn test_format_style_expands_minified_css() {
+        let source = ".a{COLOR:red;display:flex}.b{margin:0}";
+        let options = FormatOptions::default();
+        let result = format_style_content(source, None, &options);
+
+        assert!(result.contains(".a {\n  color: red;\n  display: flex;\n}"));
+        assert!(result.contains(".b {\n  margin: 0;\n}"));
+    }
+
+    #[test]
+    fn test_format_style_preserves_vue_selectors() {
+        let source = ".a :deep(.x){color:red}:slotted(.s){margin:0}:global(.g){padding:0}";
+        let options = FormatOptions::default();
+        let result = format_style_content(source, Some("css"), &options);
+
+        assert!(result.contains(".a :deep(.x) {"));
+        assert!(result.contains(":slotted(.s) {"));
+        assert!(result.contains(":global(.g) {"));
+    }
+
+    #[test]
+    fn test_format_style_leaves_unparsable_content_unchanged() {
+        let options = FormatOptions::default();
+
+        let scss = "$primary: red;\n.a{color:$primary}";
+        assert_eq!(format_style_content(scss, Some("scss"), &options), scss);
+
+        let commented = "/* keep */\n.a{color:red}";
+        assert_eq!(format_style_content(commented, None, &options), commented);
+
+        let sass = ".a\n  color: red";
+        assert_eq!(format_style_content(sass, Some("sass"), &options), sass);
+    }
 }
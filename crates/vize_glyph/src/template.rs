@@ -4,14 +4,28 @@
 //! - Proper indentation and nesting
 //! - Directive shorthand normalization (`v-bind:` → `:`, `v-on:` → `@`, `v-slot:` → `#`)
 //! - Interpolation spacing normalization (`{{expr}}` → `{{ expr }}`)
+//! - Slot props destructuring spacing (`#default="{a,b}"` → `#default="{ a, b }"`)
 //! - JS expression formatting in directive values via oxc_formatter
 //! - Attribute sorting following Vue style guide order
 //! - `single_attribute_per_line` support with `bracket_same_line`
+//! - Configurable self-closing style for void, normal and component elements
+//!
+//! ## Idempotency
+//!
+//! Formatting is idempotent: `format(format(x)) == format(x)` for every input.
+//! Editors run the formatter on save, so output that keeps changing on each
+//! pass is a bug. Every layout decision is therefore made from the normalized
+//! content alone, never from whitespace left over from a previous pass:
+//! interpolations are kept whole on one line, and multi-line attribute values
+//! are dedented before they are re-indented.
 
 use crate::error::FormatError;
-use crate::options::{AttributeSortOrder, FormatOptions};
+use crate::options::{AttributeSortOrder, FormatOptions, SelfClosingStyle};
 use crate::script;
 
+/// Narrowest width a directive value is formatted to, however deep the nesting.
+const MIN_EXPRESSION_WIDTH: usize = 40;
+
 /// Parsed attribute with structured information for sorting and rendering.
 #[derive(Debug, Clone)]
 struct ParsedAttribute {
@@ -65,6 +79,10 @@ impl<'a> TemplateFormatter<'a> {
         let mut pos = 0;
         let mut depth: usize = 0;
         let mut line_buffer = Vec::with_capacity(256);
+        // Newlines seen since the last emitted node, and whether that node
+        // opened an element (blank lines right after it are dropped)
+        let mut newline_run: usize = 0;
+        let mut after_open_tag = true;
 
         while pos < len {
             // Skip whitespace at line start (except newlines)
@@ -78,6 +96,7 @@ impl<'a> TemplateFormatter<'a> {
 
             // Handle newlines
             if source[pos] == b'\n' {
+                newline_run += 1;
                 pos += 1;
                 continue;
             }
@@ -85,6 +104,9 @@ impl<'a> TemplateFormatter<'a> {
             // HTML comment <!-- ... -->
             if pos + 3 < len && &source[pos..pos + 4] == b"<!--" {
                 self.flush_text_buffer(&mut output, &mut line_buffer, depth);
+                self.write_blank_line(&mut output, newline_run, after_open_tag);
+                newline_run = 0;
+                after_open_tag = false;
                 let comment_start = pos;
                 if let Some(end_offset) = find_bytes(&source[pos..], b"-->") {
                     let comment_end = pos + end_offset + 3;
@@ -104,11 +126,13 @@ impl<'a> TemplateFormatter<'a> {
 
             // Tag start
             if source[pos] == b'<' {
-                self.flush_text_buffer(&mut output, &mut line_buffer, depth);
-
                 // Closing tag
                 if pos + 1 < len && source[pos + 1] == b'/' {
                     if let Some((tag_name, end_pos)) = parse_closing_tag(source, pos) {
+                        self.flush_text_buffer(&mut output, &mut line_buffer, depth);
+                        // Trailing blank lines inside the parent are dropped
+                        newline_run = 0;
+                        after_open_tag = false;
                         depth = depth.saturating_sub(1);
                         self.write_indent(&mut output, depth);
                         output.extend_from_slice(b"</");
@@ -124,12 +148,18 @@ impl<'a> TemplateFormatter<'a> {
                 if let Some((tag_name, attrs, is_self_closing, end_pos)) =
                     self.parse_opening_tag(source, pos)
                 {
+                    self.flush_text_buffer(&mut output, &mut line_buffer, depth);
+
                     // Sort attributes if enabled
                     let mut sorted_attrs = attrs;
                     if self.options.sort_attributes {
                         sort_attributes(&mut sorted_attrs, self.options);
                     }
 
+                    self.write_blank_line(&mut output, newline_run, after_open_tag);
+                    newline_run = 0;
+                    after_open_tag = false;
+
                     self.write_indent(&mut output, depth);
                     output.push(b'<');
                     output.extend_from_slice(tag_name.as_bytes());
@@ -154,7 +184,7 @@ impl<'a> TemplateFormatter<'a> {
                                 } else {
                                     output.push(b' ');
                                 }
-                                output.extend_from_slice(render_attribute(attr).as_bytes());
+                                self.write_attribute(&mut output, attr, depth + 1);
                                 line_count += 1;
                                 if line_count >= max_per_line {
                                     line_count = 0;
@@ -167,18 +197,46 @@ impl<'a> TemplateFormatter<'a> {
                         } else {
                             for attr in &sorted_attrs {
                                 output.push(b' ');
-                                output.extend_from_slice(render_attribute(attr).as_bytes());
+                                self.write_attribute(&mut output, attr, depth);
                             }
                         }
                     }
 
-                    if is_self_closing {
+                    let is_void = is_void_element_str(&tag_name);
+                    let style = self.self_closing_style(&tag_name, is_void);
+                    let mut end_pos = end_pos;
+
+                    if is_void {
+                        if style == SelfClosingStyle::Always
+                            || (is_self_closing && style == SelfClosingStyle::Any)
+                        {
+                            output.extend_from_slice(b" />");
+                        } else {
+                            output.push(b'>');
+                        }
+                    } else if is_self_closing {
+                        if style == SelfClosingStyle::Never {
+                            // Expand to the same shape as an empty element
+                            output.push(b'>');
+                            output.extend_from_slice(self.newline);
+                            self.write_indent(&mut output, depth);
+                            output.extend_from_slice(b"</");
+                            output.extend_from_slice(tag_name.as_bytes());
+                            output.push(b'>');
+                        } else {
+                            output.extend_from_slice(b" />");
+                        }
+                    } else if let Some(close_end) = (style == SelfClosingStyle::Always)
+                        .then(|| find_empty_element_end(source, end_pos, &tag_name))
+                        .flatten()
+                    {
+                        // Empty element collapses into a self-closing tag
                         output.extend_from_slice(b" />");
+                        end_pos = close_end;
                     } else {
                         output.push(b'>');
-                        if !is_void_element_str(&tag_name) {
-                            depth += 1;
-                        }
+                        depth += 1;
+                        after_open_tag = true;
                     }
                     output.extend_from_slice(self.newline);
                     pos = end_pos;
@@ -186,9 +244,20 @@ impl<'a> TemplateFormatter<'a> {
                 }
             }
 
-            // Accumulate text content until newline or tag
+            // Accumulate text content until newline or tag. A `<` that does not
+            // start a tag is text, and an interpolation is taken whole even if
+            // it spans lines or contains `<`. An unclosed `{{` is plain text.
             let content_start = pos;
+            if source[pos] == b'<' {
+                pos += 1;
+            }
             while pos < len && source[pos] != b'\n' && source[pos] != b'<' {
+                if source[pos] == b'{' && source.get(pos + 1) == Some(&b'{') {
+                    if let Some(end) = interpolation_end(source, pos) {
+                        pos = end;
+                        continue;
+                    }
+                }
                 pos += 1;
             }
 
@@ -200,7 +269,11 @@ impl<'a> TemplateFormatter<'a> {
                 }
 
                 if content_end > content_start {
-                    if !line_buffer.is_empty() {
+                    if line_buffer.is_empty() {
+                        self.write_blank_line(&mut output, newline_run, after_open_tag);
+                        newline_run = 0;
+                        after_open_tag = false;
+                    } else {
                         line_buffer.push(b' ');
                     }
                     line_buffer.extend_from_slice(&source[content_start..content_end]);
@@ -210,6 +283,7 @@ impl<'a> TemplateFormatter<'a> {
             // Handle newline
             if pos < len && source[pos] == b'\n' {
                 self.flush_text_buffer(&mut output, &mut line_buffer, depth);
+                newline_run += 1;
                 pos += 1;
             }
         }
@@ -226,6 +300,18 @@ impl<'a> TemplateFormatter<'a> {
         Ok(unsafe { String::from_utf8_unchecked(output) })
     }
 
+    /// Configured self-closing style for a tag's category
+    fn self_closing_style(&self, tag_name: &str, is_void: bool) -> SelfClosingStyle {
+        let config = &self.options.html_self_closing;
+        if is_void {
+            config.void
+        } else if is_component_tag(tag_name) {
+            config.component
+        } else {
+            config.normal
+        }
+    }
+
     /// Flush accumulated text content with interpolation formatting
     #[inline]
     fn flush_text_buffer(&self, output: &mut Vec<u8>, buffer: &mut Vec<u8>, depth: usize) {
@@ -233,11 +319,24 @@ impl<'a> TemplateFormatter<'a> {
             return;
         }
         let text = std::str::from_utf8(buffer).unwrap_or("");
-        let formatted = format_interpolations(text, self.options);
+        let mut line_break = String::from_utf8_lossy(self.newline).into_owned();
+        for _ in 0..depth {
+            line_break.push_str(std::str::from_utf8(self.indent).unwrap_or(""));
+        }
+        let formatted = format_interpolations(text, self.options, &line_break);
         self.write_indented_line(output, formatted.as_bytes(), depth);
         buffer.clear();
     }
 
+    /// Emit one blank line if the source separated this node from the previous
+    /// sibling with at least one
+    #[inline]
+    fn write_blank_line(&self, output: &mut Vec<u8>, newline_run: usize, after_open_tag: bool) {
+        if self.options.preserve_blank_lines && newline_run > 1 && !after_open_tag {
+            output.extend_from_slice(self.newline);
+        }
+    }
+
     #[inline]
     fn write_indent(&self, output: &mut Vec<u8>, depth: usize) {
         for _ in 0..depth {
@@ -252,6 +351,63 @@ impl<'a> TemplateFormatter<'a> {
         output.extend_from_slice(self.newline);
     }
 
+    /// Write an attribute, breaking its expression value across lines when the
+    /// attribute would overflow `print_width`.
+    ///
+    /// Continuation lines are indented from `depth`, the indentation level of
+    /// the line the attribute starts on. Only lines produced by the expression
+    /// formatter are re-indented; any other value is written verbatim.
+    fn write_attribute(&self, output: &mut Vec<u8>, attr: &ParsedAttribute, depth: usize) {
+        let rendered = render_attribute(attr);
+        let line_start = output
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        let column = output.len() - line_start;
+        let fits =
+            !rendered.contains('\n') && column + rendered.len() < self.options.print_width as usize;
+
+        let value = match &attr.value {
+            Some(value)
+                if !fits && attr.name != "v-for" && should_format_expression(&attr.name) =>
+            {
+                value
+            }
+            _ => {
+                output.extend_from_slice(rendered.as_bytes());
+                return;
+            }
+        };
+
+        let available = (self.options.print_width as usize)
+            .saturating_sub(self.indent.len() * depth)
+            .max(MIN_EXPRESSION_WIDTH);
+        // A line break inside a template literal is part of the string
+        let has_multiline_literal = value.contains('`') && value.contains('\n');
+        let wrapped = if has_multiline_literal {
+            None
+        } else {
+            script::format_js_expression_with_width(value, self.options, available as u32)
+        };
+        let Some(wrapped) = wrapped else {
+            output.extend_from_slice(rendered.as_bytes());
+            return;
+        };
+
+        output.extend_from_slice(attr.name.as_bytes());
+        output.extend_from_slice(b"=\"");
+        for (i, line) in wrapped.lines().enumerate() {
+            if i > 0 {
+                output.extend_from_slice(self.newline);
+                if !line.is_empty() {
+                    self.write_indent(output, depth);
+                }
+            }
+            output.extend_from_slice(line.as_bytes());
+        }
+        output.push(b'"');
+    }
+
     /// Determine whether attributes should be rendered in multiline mode.
     fn should_use_multiline_attrs(
         &self,
@@ -273,13 +429,17 @@ impl<'a> TemplateFormatter<'a> {
             return true;
         }
 
+        // A multi-line value keeps its tag in multiline mode; measuring it
+        // would depend on how the previous pass indented its lines
+        let rendered: Vec<String> = attrs.iter().map(render_attribute).collect();
+        if rendered.iter().any(|attr| attr.contains('\n')) {
+            return true;
+        }
+
         // Check if all attributes on one line would exceed print_width
         let indent_len = self.indent.len() * depth;
         let tag_len = 1 + tag_name.len(); // '<' + tag_name
-        let attrs_len: usize = attrs
-            .iter()
-            .map(|a| 1 + render_attribute(a).len()) // ' ' + attr
-            .sum();
+        let attrs_len: usize = rendered.iter().map(|attr| 1 + attr.len()).sum(); // ' ' + attr
         let closing_len = 1; // '>'
         let total = indent_len + tag_len + attrs_len + closing_len;
 
@@ -501,6 +661,12 @@ fn should_format_expression(name: &str) -> bool {
         || name.starts_with("v-on")
         || name == "v-html"
         || name == "v-text"
+        || is_slot_directive(name)
+}
+
+/// Check if an attribute is a `v-slot` / `#slot` directive
+fn is_slot_directive(name: &str) -> bool {
+    name.starts_with('#') || name == "v-slot" || name.starts_with("v-slot:")
 }
 
 /// Format a directive value expression
@@ -515,8 +681,51 @@ fn format_directive_value(name: &str, value: &str, options: &FormatOptions) -> S
         return format_v_for_expression(trimmed);
     }
 
+    // Slot props are a function parameter, not an expression: `{ item, index }`
+    if is_slot_directive(name) {
+        return format_destructuring_pattern(trimmed);
+    }
+
     // Try to format as JS expression via oxc_formatter
-    script::format_js_expression(trimmed, options).unwrap_or_else(|| value.to_string())
+    script::format_js_expression(trimmed, options).unwrap_or_else(|| dedent(value))
+}
+
+/// Strip the common indentation of a multi-line value's continuation lines.
+///
+/// The lines get re-indented from the attribute's depth when written, so
+/// keeping the old indentation would grow it on every pass.
+fn dedent(value: &str) -> String {
+    if !value.contains('\n') {
+        return value.to_string();
+    }
+
+    let mut lines = value.lines();
+    let first = lines.next().unwrap_or("");
+    let rest: Vec<&str> = lines.collect();
+    let common = rest
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let mut result = String::from(first.trim_end());
+    for line in rest {
+        result.push('\n');
+        if line.len() >= common {
+            result.push_str(line[common..].trim_end());
+        }
+    }
+    result
+}
+
+/// Join the lines of an expression into a single line.
+fn collapse_lines(expr: &str) -> String {
+    expr.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 /// Format `v-for` expression: normalize spacing in `(item, index) in items`
@@ -546,6 +755,89 @@ fn format_v_for_expression(expr: &str) -> String {
     format!("{normalized_iter}{keyword}{collection_trimmed}")
 }
 
+/// Normalize spacing in a destructuring pattern: `{a,b:c=1}` → `{ a, b: c = 1 }`
+///
+/// Type-annotated patterns (`{ item }: { item: Foo }`) are returned as written.
+fn format_destructuring_pattern(pattern: &str) -> String {
+    let pattern = pattern.trim();
+
+    if pattern.starts_with(['{', '['])
+        && find_top_level(pattern, |bytes, i| bytes[i] == b':').is_some()
+    {
+        return pattern.to_string();
+    }
+
+    let (open, close, inner) =
+        if let Some(inner) = pattern.strip_prefix('{').and_then(|p| p.strip_suffix('}')) {
+            ("{ ", " }", inner)
+        } else if let Some(inner) = pattern.strip_prefix('[').and_then(|p| p.strip_suffix(']')) {
+            ("[", "]", inner)
+        } else {
+            // `name = default` and `key: value` inside a pattern
+            if let Some(idx) = find_top_level(pattern, |bytes, i| {
+                bytes[i] == b'='
+                    && bytes
+                        .get(i + 1)
+                        .is_none_or(|&next| next != b'=' && next != b'>')
+                    && (i == 0 || !matches!(bytes[i - 1], b'=' | b'!' | b'<' | b'>'))
+            }) {
+                let target = format_destructuring_pattern(&pattern[..idx]);
+                let default = pattern[idx + 1..].trim();
+                return format!("{target} = {default}");
+            }
+            if let Some(idx) = find_top_level(pattern, |bytes, i| bytes[i] == b':') {
+                let key = pattern[..idx].trim();
+                let value = format_destructuring_pattern(&pattern[idx + 1..]);
+                return format!("{key}: {value}");
+            }
+            return pattern.to_string();
+        };
+
+    let mut parts = Vec::new();
+    let mut rest = inner;
+    while let Some(idx) = find_top_level(rest, |bytes, i| bytes[i] == b',') {
+        parts.push(&rest[..idx]);
+        rest = &rest[idx + 1..];
+    }
+    parts.push(rest);
+
+    let parts: Vec<String> = parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(format_destructuring_pattern)
+        .collect();
+    if parts.is_empty() {
+        return format!("{}{}", open.trim(), close.trim());
+    }
+    format!("{open}{}{close}", parts.join(", "))
+}
+
+/// Find the first byte matching `is_match` outside nested brackets and strings
+fn find_top_level(text: &str, is_match: impl Fn(&[u8], usize) -> bool) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0i32;
+    let mut quote = None;
+
+    for i in 0..bytes.len() {
+        let b = bytes[i];
+        if let Some(q) = quote {
+            if b == q && (i == 0 || bytes[i - 1] != b'\\') {
+                quote = None;
+            }
+            continue;
+        }
+        match b {
+            b'\'' | b'"' | b'`' => quote = Some(b),
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            _ if depth == 0 && is_match(bytes, i) => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
 /// Find `keyword` in a v-for expression while respecting nested parens/brackets
 fn find_v_for_keyword(expr: &str, keyword: &str) -> Option<usize> {
     let bytes = expr.as_bytes();
@@ -573,7 +865,11 @@ fn find_v_for_keyword(expr: &str, keyword: &str) -> Option<usize> {
 // ---------------------------------------------------------------------------
 
 /// Format interpolations in text content: `{{expr}}` → `{{ expr }}`
-fn format_interpolations(text: &str, options: &FormatOptions) -> String {
+///
+/// An expression the formatter breaks across lines keeps its layout, with
+/// each continuation line starting at `line_break` (a newline plus the
+/// indentation of the text line).
+fn format_interpolations(text: &str, options: &FormatOptions, line_break: &str) -> String {
     let bytes = text.as_bytes();
     let len = bytes.len();
     let mut result = String::with_capacity(len + 16);
@@ -603,11 +899,14 @@ fn format_interpolations(text: &str, options: &FormatOptions) -> String {
 
             if depth == 0 {
                 let expr = &text[expr_start..expr_end];
-                let formatted_expr = script::format_js_expression(expr, options)
-                    .unwrap_or_else(|| expr.trim().to_string());
-                result.push_str("{{ ");
-                result.push_str(&formatted_expr);
-                result.push_str(" }}");
+                match format_interpolation(expr, options, line_break) {
+                    Some(formatted) => {
+                        result.push_str("{{ ");
+                        result.push_str(&formatted);
+                        result.push_str(" }}");
+                    }
+                    None => result.push_str(&text[pos..expr_end + 2]),
+                }
                 pos = expr_end + 2;
             } else {
                 // Unclosed interpolation – keep as-is
@@ -628,6 +927,35 @@ fn format_interpolations(text: &str, options: &FormatOptions) -> String {
     result
 }
 
+/// Format one interpolation expression, or `None` to keep it as written.
+///
+/// Line breaks inside template literals and line comments are significant,
+/// so an expression that has them is only rewritten by the formatter itself,
+/// and only when its lines need no re-indenting.
+fn format_interpolation(expr: &str, options: &FormatOptions, line_break: &str) -> Option<String> {
+    let significant_breaks = expr.contains('`') || expr.contains("//") || expr.contains("/*");
+    match script::format_js_expression(expr, options) {
+        Some(formatted) if !formatted.contains('\n') => Some(formatted),
+        Some(_) if significant_breaks => None,
+        Some(formatted) => {
+            let blank_line_break = line_break.trim_end_matches([' ', '\t']);
+            let mut lines = formatted.lines().map(str::trim_end);
+            let mut result = lines.next().unwrap_or_default().to_string();
+            for line in lines {
+                result.push_str(if line.is_empty() {
+                    blank_line_break
+                } else {
+                    line_break
+                });
+                result.push_str(line);
+            }
+            Some(result)
+        }
+        None if significant_breaks => None,
+        None => Some(collapse_lines(expr)),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Attribute sorting
 // ---------------------------------------------------------------------------
@@ -798,6 +1126,30 @@ fn render_attribute(attr: &ParsedAttribute) -> String {
 // Utility functions
 // ---------------------------------------------------------------------------
 
+/// End of the interpolation opened at `start`, or `None` if a tag or comment
+/// comes before its `}}`.
+///
+/// A `<` followed by a letter, `/` or `!` opens a tag once a `>` shows up
+/// before the `}}`, so `{{ a<b }}` stays one interpolation.
+fn interpolation_end(source: &[u8], start: usize) -> Option<usize> {
+    let mut in_tag = false;
+    let mut pos = start + 2;
+    while pos + 1 < source.len() {
+        match source[pos] {
+            b'}' if source[pos + 1] == b'}' => return Some(pos + 2),
+            b'<' if source[pos + 1].is_ascii_alphabetic()
+                || matches!(source[pos + 1], b'/' | b'!') =>
+            {
+                in_tag = true;
+            }
+            b'>' if in_tag => return None,
+            _ => {}
+        }
+        pos += 1;
+    }
+    None
+}
+
 /// Find a byte subsequence in a slice
 fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
     haystack.windows(needle.len()).position(|w| w == needle)
@@ -843,6 +1195,29 @@ fn is_whitespace(b: u8) -> bool {
     matches!(b, b' ' | b'\t' | b'\n' | b'\r')
 }
 
+/// Find the end of the closing tag when an element has no content.
+///
+/// `start` is the position right after the opening tag. Whitespace-only
+/// content counts as empty, except in elements where it is significant.
+fn find_empty_element_end(source: &[u8], start: usize, tag_name: &str) -> Option<usize> {
+    let mut pos = start;
+    if !matches!(tag_name, "pre" | "textarea") {
+        while pos < source.len() && is_whitespace(source[pos]) {
+            pos += 1;
+        }
+    }
+    if !source[pos..].starts_with(b"</") {
+        return None;
+    }
+    let (closing_name, end_pos) = parse_closing_tag(source, pos)?;
+    (closing_name == tag_name).then_some(end_pos)
+}
+
+/// Check if a tag refers to a component (PascalCase or kebab-case)
+fn is_component_tag(tag: &str) -> bool {
+    tag.contains('-') || tag.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
 /// Check if an element is a void element (self-closing in HTML)
 fn is_void_element_str(tag: &str) -> bool {
     matches!(
@@ -939,10 +1314,73 @@ mod tests {
         assert!(!result.contains("v-slot:default"));
     }
 
+    #[test]
+    fn test_scoped_slot_destructuring() {
+        let options = FormatOptions::default();
+        let result =
+            format_template_content(r#"<template #default="{a,b}"></template>"#, &options).unwrap();
+        assert!(result.contains(r#"#default="{ a, b }""#), "{result}");
+
+        let result = format_template_content(
+            r#"<template v-slot:item="{item:{id},index=0}"></template>"#,
+            &options,
+        )
+        .unwrap();
+        assert!(
+            result.contains(r#"#item="{ item: { id }, index = 0 }""#),
+            "{result}"
+        );
+
+        let result = format_template_content(
+            r#"<template #item="{ item }: { item: Foo }"></template>"#,
+            &options,
+        )
+        .unwrap();
+        assert!(
+            result.contains(r#"#item="{ item }: { item: Foo }""#),
+            "{result}"
+        );
+    }
+
+    #[test]
+    fn test_slot_shorthand_without_value_and_dynamic_name() {
+        let options = FormatOptions::default();
+        let result =
+            format_template_content(r#"<template #default></template>"#, &options).unwrap();
+        assert!(result.contains("<template #default>"), "{result}");
+
+        let result = format_template_content(
+            r#"<template v-slot:[dynamic]="{item}"></template><template #[dynamic]></template>"#,
+            &options,
+        )
+        .unwrap();
+        assert!(result.contains(r#"#[dynamic]="{ item }""#), "{result}");
+        assert!(result.contains("<template #[dynamic]>"), "{result}");
+    }
+
+    #[test]
+    fn test_format_destructuring_pattern() {
+        assert_eq!(format_destructuring_pattern("props"), "props");
+        assert_eq!(format_destructuring_pattern("{ }"), "{}");
+        assert_eq!(format_destructuring_pattern("[a,b]"), "[a, b]");
+        assert_eq!(
+            format_destructuring_pattern("{label='a,b',...rest}"),
+            "{ label = 'a,b', ...rest }"
+        );
+        assert_eq!(
+            format_destructuring_pattern("{ item }: { item: Foo }"),
+            "{ item }: { item: Foo }"
+        );
+        assert_eq!(
+            format_destructuring_pattern("[a,b]: [string, number]"),
+            "[a,b]: [string, number]"
+        );
+    }
+
     #[test]
     fn test_interpolation_spacing_normalized() {
         let options = FormatOptions::default();
-        let result = format_interpolations("{{count}}", &options);
+        let result = format_interpolations("{{count}}", &options, "\n");
         assert!(result.contains("{{ "));
         assert!(result.contains(" }}"));
     }
@@ -950,7 +1388,7 @@ mod tests {
     #[test]
     fn test_interpolation_already_spaced() {
         let options = FormatOptions::default();
-        let result = format_interpolations("{{ count }}", &options);
+        let result = format_interpolations("{{ count }}", &options, "\n");
         assert!(result.contains("{{ "));
         assert!(result.contains(" }}"));
     }
@@ -958,7 +1396,7 @@ mod tests {
     #[test]
     fn test_interpolation_in_text() {
         let options = FormatOptions::default();
-        let result = format_interpolations("Hello {{name}} world", &options);
+        let result = format_interpolations("Hello {{name}} world", &options, "\n");
         assert!(result.starts_with("Hello "));
         assert!(result.contains("{{ "));
         assert!(result.contains(" }}"));
@@ -1291,4 +1729,261 @@ mod tests {
             "Narrow print_width should trigger multiline attributes"
         );
     }
+
+    #[test]
+    fn test_preserve_blank_lines_collapses_runs() {
+        let source = "<div>\n\n  <p>a</p>\n\n\n\n  <p>b</p>\n\n</div>";
+        let options = FormatOptions::default();
+        let result = format_template_content(source, &options).unwrap();
+
+        assert_eq!(
+            result,
+            "<div>\n  <p>\n    a\n  </p>\n\n  <p>\n    b\n  </p>\n</div>"
+        );
+    }
+
+    #[test]
+    fn test_preserve_blank_lines_disabled() {
+        let source = "<p>a</p>\n\n<p>b</p>";
+        let mut options = FormatOptions::default();
+        options.preserve_blank_lines = false;
+        let result = format_template_content(source, &options).unwrap();
+
+        assert!(!result.contains("\n\n"));
+    }
+
+    #[test]
+    fn test_preserve_blank_lines_ignores_attribute_newlines() {
+        let source = "<div\n\n  class=\"a\"\n\n  id=\"b\"\n>\n</div>\n<span />";
+        let options = FormatOptions::default();
+        let result = format_template_content(source, &options).unwrap();
+
+        assert!(!result.contains("\n\n"), "{result}");
+    }
+
+    #[test]
+    fn test_wide_class_object_wraps_under_attribute() {
+        let source = r#"<div class="card" :class="{ active: isActive, disabled: isDisabled, highlighted: isHighlighted, selected: isSelected, expanded: isExpanded }"></div>"#;
+        let options = FormatOptions::default();
+        let result = format_template_content(source, &options).unwrap();
+
+        assert!(
+            result.contains("\n  :class=\"{\n    active: isActive,\n    disabled: isDisabled,"),
+            "{result}"
+        );
+        assert!(result.contains("\n  }\"\n>"), "{result}");
+        for line in result.lines() {
+            assert!(line.len() <= options.print_width as usize, "{line}");
+        }
+
+        // The wrapped output is stable when formatted again
+        let again = format_template_content(&result, &options).unwrap();
+        assert_eq!(again, result);
+    }
+
+    fn self_closing_options(
+        void: SelfClosingStyle,
+        normal: SelfClosingStyle,
+        component: SelfClosingStyle,
+    ) -> FormatOptions {
+        FormatOptions {
+            html_self_closing: crate::options::HtmlSelfClosing {
+                void,
+                normal,
+                component,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_self_closing_preserved_by_default() {
+        let source = "<br>\n<img />\n<div />\n<MyButton></MyButton>";
+        let result = format_template_content(source, &FormatOptions::default()).unwrap();
+
+        assert_eq!(result, "<br>\n<img />\n<div />\n<MyButton>\n</MyButton>");
+    }
+
+    #[test]
+    fn test_self_closing_void_elements() {
+        use SelfClosingStyle::{Always, Any, Never};
+        let source = "<br>\n<img src=\"a.png\" />";
+
+        let options = self_closing_options(Always, Any, Any);
+        let result = format_template_content(source, &options).unwrap();
+        assert_eq!(result, "<br />\n<img src=\"a.png\" />");
+
+        let options = self_closing_options(Never, Any, Any);
+        let result = format_template_content(source, &options).unwrap();
+        assert_eq!(result, "<br>\n<img src=\"a.png\">");
+    }
+
+    #[test]
+    fn test_self_closing_normal_elements() {
+        use SelfClosingStyle::{Always, Any, Never};
+
+        let options = self_closing_options(Any, Always, Any);
+        let source = "<div></div>\n<span>\n</span>\n<p>text</p>\n<MyButton></MyButton>";
+        let result = format_template_content(source, &options).unwrap();
+        assert_eq!(
+            result,
+            "<div />\n<span />\n<p>\n  text\n</p>\n<MyButton>\n</MyButton>"
+        );
+
+        let options = self_closing_options(Any, Never, Any);
+        let source = "<section><div /></section>\n<MyButton />";
+        let result = format_template_content(source, &options).unwrap();
+        assert_eq!(
+            result,
+            "<section>\n  <div>\n  </div>\n</section>\n<MyButton />"
+        );
+    }
+
+    #[test]
+    fn test_self_closing_components() {
+        use SelfClosingStyle::{Always, Any, Never};
+
+        let options = self_closing_options(Any, Any, Always);
+        let source = "<MyButton :size=\"sm\"></MyButton>\n<my-icon>\n</my-icon>\n<MyCard>body</MyCard>\n<div></div>";
+        let result = format_template_content(source, &options).unwrap();
+        assert_eq!(
+            result,
+            "<MyButton :size=\"sm\" />\n<my-icon />\n<MyCard>\n  body\n</MyCard>\n<div>\n</div>"
+        );
+
+        let options = self_closing_options(Any, Any, Never);
+        let result = format_template_content("<MyButton />", &options).unwrap();
+        assert_eq!(result, "<MyButton>\n</MyButton>");
+    }
+
+    /// Templates exercising the layout decisions that could drift between passes.
+    const IDEMPOTENCY_CORPUS: &[&str] = &[
+        "<div>Hello</div>",
+        "<div><span>Hello</span><p>World</p></div>",
+        "<div>\n\n\n<p>a</p>\n\n<p>b</p>\n</div>",
+        "<p>Hello {{name}}, you have {{ count+1 }} messages</p>",
+        "<p>{{ a < b ? 'less' : 'more' }}</p>",
+        "<p>1 < 2 and 3 > 2</p>",
+        "<p>{{\n  items\n    .map(item => item.label)\n    .join(', ')\n}}</p>",
+        "<p>{{ someVeryLongFunctionName(firstArgument, secondArgument, thirdArgument, fourthArgument) }}</p>",
+        "<p>{{ not valid js ( }}</p>",
+        "<p>{{ unclosed</p>",
+        "<p>{{ unclosed</p><div><span>{{ later }}</span></div>",
+        r#"<div v-bind:class="{active:isActive}" v-on:click="toggle" id="main" class="box"></div>"#,
+        r#"<input v-model="form.name" type="text" :placeholder="t('name')" required>"#,
+        r#"<MyComponent :options="{ first: 'value', second: 'another value', third: [1, 2, 3], fourth: true }" @update="onUpdate" />"#,
+        r#"<div :style="{ color: activeColor, fontSize: fontSize + 'px', backgroundColor: background }">x</div>"#,
+        "<div :class=\"[\n      a,\n      b &&\n    ]\" id=\"x\"></div>",
+        r#"<li v-for="(item,index) in items" :key="item.id">{{item.name}}</li>"#,
+        r##"<MyList #item="{item,index}"><span>{{ item }}</span></MyList>"##,
+        r#"<template v-if="ok"><p>yes</p></template><template v-else><p>no</p></template>"#,
+        "<!-- a comment --><div><!-- nested --></div>",
+        "<br><img src=\"a.png\"><hr/>",
+        "<textarea></textarea><div></div><MyButton></MyButton>",
+    ];
+
+    #[test]
+    fn test_idempotent_over_corpus() {
+        let mut single_attribute = FormatOptions::default();
+        single_attribute.single_attribute_per_line = true;
+        let mut tabs = FormatOptions::default();
+        tabs.use_tabs = true;
+        let mut narrow = FormatOptions::default();
+        narrow.print_width = 40;
+
+        for options in [FormatOptions::default(), single_attribute, tabs, narrow] {
+            for source in IDEMPOTENCY_CORPUS {
+                let once = format_template_content(source, &options).unwrap();
+                let twice = format_template_content(&once, &options).unwrap();
+                assert_eq!(
+                    once, twice,
+                    "formatting is not idempotent for {source:?}\nfirst:\n{once}\nsecond:\n{twice}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_stray_lt_is_text() {
+        let options = FormatOptions::default();
+        let result = format_template_content("<p>{{ a < b }}</p>", &options).unwrap();
+        assert_eq!(result, "<p>\n  {{ a < b }}\n</p>");
+    }
+
+    #[test]
+    fn test_unclosed_interpolation_is_text() {
+        let options = FormatOptions::default();
+        let result = format_template_content("<p>{{ open</p>\n<p>{{ b }}</p>", &options).unwrap();
+        assert_eq!(result, "<p>\n  {{ open\n</p>\n<p>\n  {{ b }}\n</p>");
+    }
+
+    #[test]
+    fn test_wrapped_interpolation_keeps_its_layout() {
+        let options = FormatOptions::default();
+        let source = "<div><p>{{ someVeryLongFunctionName(firstArgument, secondArgument, thirdArgument, fourthArgument) }}</p></div>";
+        let once = format_template_content(source, &options).unwrap();
+        assert!(once.lines().all(|line| line == line.trim_end()), "{once}");
+        assert!(once.contains("firstArgument,"), "{once}");
+        assert_eq!(format_template_content(&once, &options).unwrap(), once);
+    }
+
+    #[test]
+    fn test_interpolation_with_significant_line_breaks_kept() {
+        let options = FormatOptions::default();
+        for source in [
+            "<p>{{ `line one\n  line two` }}</p>",
+            "<p>{{ items // all of them\n  .length }}</p>",
+        ] {
+            let result = format_template_content(source, &options).unwrap();
+            let expr = &source[source.find("{{").unwrap()..source.find("}}").unwrap() + 2];
+            assert!(result.contains(expr), "{result}");
+        }
+    }
+
+    #[test]
+    fn test_interpolation_scan_stops_at_tags() {
+        assert_eq!(interpolation_end(b"{{ a<b }}", 0), Some(9));
+        assert_eq!(interpolation_end(b"{{ a < b }}", 0), Some(11));
+        assert_eq!(interpolation_end(b"{{ a</p><p>{{ b }}", 0), None);
+        assert_eq!(interpolation_end(b"{{ a<!-- c -->}}", 0), None);
+    }
+
+    #[test]
+    fn test_multiline_interpolation_joined() {
+        let options = FormatOptions::default();
+        let result = format_template_content("<p>{{\n  a +\n  b\n}}</p>", &options).unwrap();
+        assert_eq!(result, "<p>\n  {{ a + b }}\n</p>");
+    }
+
+    #[test]
+    fn test_dedent_continuation_lines() {
+        assert_eq!(dedent("a"), "a");
+        assert_eq!(
+            dedent("[\n      a,\n      b &&\n    ]"),
+            "[\n  a,\n  b &&\n]"
+        );
+    }
+
+    #[test]
+    fn test_multiline_template_literal_kept_verbatim() {
+        let options = FormatOptions::default();
+
+        // Line breaks inside a template literal belong to the string
+        let result = format_template_content(
+            "<div>\n<p :title=\"`line one\n  line two`\" id=\"x\"></p>\n</div>",
+            &options,
+        )
+        .unwrap();
+        assert!(
+            result.contains(":title=\"`line one\n  line two`\""),
+            "{result}"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_print_width_is_rejected() {
+        assert_eq!(FormatOptions::validate_print_width(100), Ok(100));
+        assert!(FormatOptions::validate_print_width(0).is_err());
+        assert!(FormatOptions::validate_print_width(100_000).is_err());
+    }
 }
@@ -0,0 +1,474 @@
+//! Call hierarchy provider.
+//!
+//! Navigates call relationships between top-level functions of `<script setup>`
+//! using the function spans recorded by croquis:
+//! - Prepare: maps the function binding under the cursor to an item
+//! - Incoming calls: script functions calling it (resolved through tsgo
+//!   references when available), plus template event handlers
+//! - Outgoing calls: script functions invoked in its body
+
+use tower_lsp::lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Position, Range,
+    SymbolKind, Url,
+};
+use vize_croquis::{Analyzer, AnalyzerOptions, Croquis};
+use vize_relief::ast::{ExpressionNode, PropNode, TemplateChildNode};
+
+#[cfg(feature = "native")]
+use std::sync::Arc;
+#[cfg(feature = "native")]
+use vize_canon::TsgoBridge;
+
+use super::{IdeContext, ReferencesService};
+
+/// Call hierarchy service for script functions.
+pub struct CallHierarchyService;
+
+/// Script analysis of an SFC, with offsets relative to the script content.
+struct ScriptFunctions {
+    summary: Croquis,
+    script_start: usize,
+    script_content: String,
+    /// Whether the analyzed block is `<script setup>`
+    is_setup: bool,
+}
+
+/// A template event handler calling a function.
+struct TemplateHandler {
+    /// Event name with the `@` prefix (e.g. `@click`)
+    name: String,
+    /// Range of the whole directive
+    range: (usize, usize),
+    /// Call sites of the function within the handler expression
+    call_sites: Vec<usize>,
+}
+
+impl CallHierarchyService {
+    /// Prepare call hierarchy items for the function under the cursor.
+    pub fn prepare(ctx: &IdeContext) -> Option<Vec<CallHierarchyItem>> {
+        Self::prepare_for_sfc(&ctx.content, ctx.uri, ctx.offset)
+    }
+
+    /// Prepare call hierarchy items for the function at an offset in SFC source.
+    pub fn prepare_for_sfc(
+        content: &str,
+        uri: &Url,
+        offset: usize,
+    ) -> Option<Vec<CallHierarchyItem>> {
+        let word = ReferencesService::get_word_at_offset(content, offset)?;
+        let functions = Self::analyze(content, uri)?;
+        let item = Self::function_item(content, uri, &functions, &word)?;
+        Some(vec![item])
+    }
+
+    /// Find the callers of a function item in the SFC.
+    pub fn incoming_calls(
+        content: &str,
+        uri: &Url,
+        item: &CallHierarchyItem,
+    ) -> Option<Vec<CallHierarchyIncomingCall>> {
+        let functions = Self::analyze(content, uri)?;
+        let target = item.name.as_str();
+        functions.summary.function_spans.get(target)?;
+
+        let mut sites = Vec::new();
+        for &(start, end) in functions.summary.function_spans.values() {
+            let body = &functions.script_content[start as usize..end as usize];
+            sites.extend(
+                Self::find_call_sites(body, target)
+                    .into_iter()
+                    .map(|pos| start as usize + pos),
+            );
+        }
+
+        Some(Self::incoming_calls_from_sites(
+            content, uri, &functions, target, sites,
+        ))
+    }
+
+    /// Find the callers of a function item with tsgo support.
+    ///
+    /// Script callers are resolved through tsgo references on the virtual
+    /// script document, falling back to croquis analysis when tsgo is
+    /// unavailable or finds nothing.
+    #[cfg(feature = "native")]
+    pub async fn incoming_calls_with_tsgo(
+        ctx: &IdeContext<'_>,
+        item: &CallHierarchyItem,
+        tsgo_bridge: Option<Arc<TsgoBridge>>,
+    ) -> Option<Vec<CallHierarchyIncomingCall>> {
+        if let Some(bridge) = tsgo_bridge {
+            if let Some(calls) = Self::incoming_calls_from_tsgo(ctx, item, &bridge).await {
+                return Some(calls);
+            }
+        }
+
+        Self::incoming_calls(&ctx.content, ctx.uri, item)
+    }
+
+    #[cfg(feature = "native")]
+    async fn incoming_calls_from_tsgo(
+        ctx: &IdeContext<'_>,
+        item: &CallHierarchyItem,
+        bridge: &TsgoBridge,
+    ) -> Option<Vec<CallHierarchyIncomingCall>> {
+        if !bridge.is_initialized() {
+            return None;
+        }
+
+        let functions = Self::analyze(&ctx.content, ctx.uri)?;
+        let target = item.name.as_str();
+        functions.summary.function_spans.get(target)?;
+        let &(name_start, _) = functions.summary.binding_spans.get(target)?;
+
+        let virtual_docs = ctx.virtual_docs.as_ref()?;
+        let (script, suffix) = if functions.is_setup {
+            (virtual_docs.script_setup.as_ref()?, "setup.ts")
+        } else {
+            (virtual_docs.script.as_ref()?, "script.ts")
+        };
+
+        let vts_offset = crate::ide::hover::HoverService::sfc_to_virtual_ts_script_offset(
+            ctx,
+            functions.script_start + name_start as usize,
+        )?;
+        let (line, character) = super::offset_to_position(&script.content, vts_offset);
+        let path = format!("{}.{}", ctx.uri.path(), suffix);
+        let uri = format!("vize-virtual://{}", path);
+
+        let _ = bridge
+            .open_or_update_virtual_document(&path, &script.content)
+            .await;
+        let locations = bridge.references(&uri, line, character).await.ok()?;
+
+        let sites: Vec<usize> = locations
+            .into_iter()
+            .filter(|loc| loc.uri == uri)
+            .filter_map(|loc| {
+                let offset = crate::utils::position_to_offset_str(
+                    &script.content,
+                    loc.range.start.line,
+                    loc.range.start.character,
+                );
+                let offset = script
+                    .source_map
+                    .to_source(offset as u32)
+                    .map(|o| o as usize)
+                    .unwrap_or(offset);
+                // References also cover reads such as `const f = helper`
+                let after = functions.script_content.get(offset + target.len()..)?;
+                after.trim_start().starts_with('(').then_some(offset)
+            })
+            .collect();
+
+        if sites.is_empty() {
+            return None;
+        }
+
+        Some(Self::incoming_calls_from_sites(
+            &ctx.content,
+            ctx.uri,
+            &functions,
+            target,
+            sites,
+        ))
+    }
+
+    /// Group script call sites (relative to the script content) by their
+    /// enclosing function, and add the template event handlers calling `target`.
+    fn incoming_calls_from_sites(
+        content: &str,
+        uri: &Url,
+        functions: &ScriptFunctions,
+        target: &str,
+        sites: Vec<usize>,
+    ) -> Vec<CallHierarchyIncomingCall> {
+        let mut calls = Vec::new();
+
+        for (name, &(start, end)) in &functions.summary.function_spans {
+            let (start, end) = (start as usize, end as usize);
+            let mut call_sites: Vec<usize> = sites
+                .iter()
+                .copied()
+                .filter(|&pos| pos >= start && pos < end)
+                .collect();
+            if call_sites.is_empty() {
+                continue;
+            }
+            call_sites.sort_unstable();
+            call_sites.dedup();
+            let Some(from) = Self::function_item(content, uri, functions, name) else {
+                continue;
+            };
+            let base = functions.script_start;
+            calls.push(CallHierarchyIncomingCall {
+                from,
+                from_ranges: call_sites
+                    .into_iter()
+                    .map(|pos| Self::range(content, base + pos, base + pos + target.len()))
+                    .collect(),
+            });
+        }
+
+        for handler in Self::template_handlers(content, uri, target) {
+            let range = Self::range(content, handler.range.0, handler.range.1);
+            calls.push(CallHierarchyIncomingCall {
+                from: CallHierarchyItem {
+                    name: handler.name,
+                    kind: SymbolKind::EVENT,
+                    tags: None,
+                    detail: Some("template".to_string()),
+                    uri: uri.clone(),
+                    range,
+                    selection_range: range,
+                    data: None,
+                },
+                from_ranges: handler
+                    .call_sites
+                    .into_iter()
+                    .map(|pos| Self::range(content, pos, pos + target.len()))
+                    .collect(),
+            });
+        }
+
+        calls.sort_by_key(|call| (call.from.range.start.line, call.from.range.start.character));
+        calls
+    }
+
+    /// Find the script functions called in the body of a function item.
+    pub fn outgoing_calls(
+        content: &str,
+        uri: &Url,
+        item: &CallHierarchyItem,
+    ) -> Option<Vec<CallHierarchyOutgoingCall>> {
+        let functions = Self::analyze(content, uri)?;
+        let &(start, end) = functions.summary.function_spans.get(item.name.as_str())?;
+        let body = &functions.script_content[start as usize..end as usize];
+        let base = functions.script_start + start as usize;
+
+        let mut calls = Vec::new();
+        for name in functions.summary.function_spans.keys() {
+            let call_sites = Self::find_call_sites(body, name);
+            if call_sites.is_empty() {
+                continue;
+            }
+            let Some(to) = Self::function_item(content, uri, &functions, name) else {
+                continue;
+            };
+            calls.push(CallHierarchyOutgoingCall {
+                to,
+                from_ranges: call_sites
+                    .into_iter()
+                    .map(|pos| Self::range(content, base + pos, base + pos + name.len()))
+                    .collect(),
+            });
+        }
+
+        calls.sort_by_key(|call| {
+            call.from_ranges
+                .first()
+                .map(|range| (range.start.line, range.start.character))
+        });
+        Some(calls)
+    }
+
+    /// Analyze the script block of an SFC.
+    fn analyze(content: &str, uri: &Url) -> Option<ScriptFunctions> {
+        let options = vize_atelier_sfc::SfcParseOptions {
+            filename: uri.path().to_string(),
+            ..Default::default()
+        };
+        let descriptor = vize_atelier_sfc::parse_sfc(content, options).ok()?;
+
+        let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+        let script = if let Some(ref script_setup) = descriptor.script_setup {
+            analyzer.analyze_script_setup(&script_setup.content);
+            script_setup
+        } else {
+            let script = descriptor.script.as_ref()?;
+            analyzer.analyze_script_plain(&script.content);
+            script
+        };
+
+        Some(ScriptFunctions {
+            summary: analyzer.finish(),
+            script_start: script.loc.start,
+            script_content: script.content.to_string(),
+            is_setup: descriptor.script_setup.is_some(),
+        })
+    }
+
+    /// Build the item for a top-level script function.
+    fn function_item(
+        content: &str,
+        uri: &Url,
+        functions: &ScriptFunctions,
+        name: &str,
+    ) -> Option<CallHierarchyItem> {
+        let &(start, end) = functions.summary.function_spans.get(name)?;
+        let &(name_start, name_end) = functions.summary.binding_spans.get(name)?;
+        let base = functions.script_start;
+
+        let detail = functions
+            .summary
+            .function_signatures
+            .get(name)
+            .map(|signature| {
+                let params: Vec<&str> = signature.params.iter().map(|p| p.as_str()).collect();
+                format!("({})", params.join(", "))
+            });
+
+        Some(CallHierarchyItem {
+            name: name.to_string(),
+            kind: SymbolKind::FUNCTION,
+            tags: None,
+            detail,
+            uri: uri.clone(),
+            range: Self::range(content, base + start as usize, base + end as usize),
+            selection_range: Self::range(
+                content,
+                base + name_start as usize,
+                base + name_end as usize,
+            ),
+            data: None,
+        })
+    }
+
+    /// Collect template event handlers that call `name`.
+    ///
+    /// Offsets are absolute in the SFC source.
+    fn template_handlers(content: &str, uri: &Url, name: &str) -> Vec<TemplateHandler> {
+        let options = vize_atelier_sfc::SfcParseOptions {
+            filename: uri.path().to_string(),
+            ..Default::default()
+        };
+        let Ok(descriptor) = vize_atelier_sfc::parse_sfc(content, options) else {
+            return Vec::new();
+        };
+        let Some(ref template) = descriptor.template else {
+            return Vec::new();
+        };
+
+        let allocator = vize_carton::Bump::new();
+        let (ast, _) = vize_armature::parse(&allocator, &template.content);
+        let mut handlers = Vec::new();
+        Self::collect_handlers(
+            &ast.children,
+            name,
+            &template.content,
+            template.loc.start,
+            &mut handlers,
+        );
+        handlers
+    }
+
+    fn collect_handlers(
+        children: &[TemplateChildNode<'_>],
+        name: &str,
+        source: &str,
+        base: usize,
+        handlers: &mut Vec<TemplateHandler>,
+    ) {
+        for child in children {
+            match child {
+                TemplateChildNode::Element(el) => {
+                    for prop in &el.props {
+                        let PropNode::Directive(dir) = prop else {
+                            continue;
+                        };
+                        if dir.name != "on" {
+                            continue;
+                        }
+                        let Some(ref exp) = dir.exp else {
+                            continue;
+                        };
+                        let Some((text, expr_offset)) = ReferencesService::get_expression_info(exp)
+                        else {
+                            continue;
+                        };
+
+                        // `@click="save"` passes the function itself as the handler
+                        let call_sites = if text.trim() == name {
+                            vec![text.find(name).unwrap_or(0)]
+                        } else {
+                            Self::find_call_sites(&text, name)
+                        };
+                        if call_sites.is_empty() {
+                            continue;
+                        }
+
+                        let event = match &dir.arg {
+                            Some(ExpressionNode::Simple(arg)) => arg.content.as_str(),
+                            _ => "",
+                        };
+                        // `loc.end` stops before the closing quote of the value
+                        let mut end = dir.loc.end.offset as usize;
+                        if matches!(source.as_bytes().get(end), Some(b'"' | b'\'')) {
+                            end += 1;
+                        }
+                        handlers.push(TemplateHandler {
+                            name: format!("@{}", event),
+                            range: (base + dir.loc.start.offset as usize, base + end),
+                            call_sites: call_sites
+                                .into_iter()
+                                .map(|pos| base + expr_offset + pos)
+                                .collect(),
+                        });
+                    }
+                    Self::collect_handlers(&el.children, name, source, base, handlers);
+                }
+                TemplateChildNode::If(if_node) => {
+                    for branch in &if_node.branches {
+                        Self::collect_handlers(&branch.children, name, source, base, handlers);
+                    }
+                }
+                TemplateChildNode::For(for_node) => {
+                    Self::collect_handlers(&for_node.children, name, source, base, handlers);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Find calls of `name` in `text` (`name(...)`), skipping member calls
+    /// (`obj.name()`) and the name of a function declaration.
+    fn find_call_sites(text: &str, name: &str) -> Vec<usize> {
+        ReferencesService::find_word_occurrences(text, name)
+            .into_iter()
+            .filter(|&pos| {
+                let before = text[..pos].trim_end();
+                let after = text[pos + name.len()..].trim_start();
+                after.starts_with('(') && !before.ends_with('.') && !before.ends_with("function")
+            })
+            .collect()
+    }
+
+    fn range(content: &str, start: usize, end: usize) -> Range {
+        let (start_line, start_char) = super::offset_to_position(content, start);
+        let (end_line, end_char) = super::offset_to_position(content, end);
+        Range {
+            start: Position {
+                line: start_line,
+                character: start_char,
+            },
+            end: Position {
+                line: end_line,
+                character: end_char,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_call_sites() {
+        let text = "function save() { save(); obj.save(); const s = save }";
+        assert_eq!(
+            CallHierarchyService::find_call_sites(text, "save"),
+            vec![18]
+        );
+    }
+}
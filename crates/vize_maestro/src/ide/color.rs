@@ -0,0 +1,282 @@
+//! Document color provider.
+//!
+//! Reports color values in `<style>` blocks so editors can show swatches
+//! and a color picker. Supports:
+//! - Hex colors (`#f00`, `#ff000080`)
+//! - `rgb()` / `rgba()` functions
+//! - Named colors (`rebeccapurple`)
+//!
+//! Candidate values are located by scanning declaration values and parsed
+//! with lightning-css, so only valid colors are reported.
+
+use lightningcss::traits::Parse;
+use lightningcss::values::color::CssColor;
+use tower_lsp::lsp_types::{Color, ColorInformation, ColorPresentation, Position, Range, TextEdit};
+
+/// Color service for style blocks.
+pub struct ColorService;
+
+impl ColorService {
+    /// Get all colors in the style blocks of an SFC.
+    pub fn document_colors(content: &str, filename: &str) -> Vec<ColorInformation> {
+        let options = vize_atelier_sfc::SfcParseOptions {
+            filename: filename.to_string(),
+            ..Default::default()
+        };
+        let Ok(descriptor) = vize_atelier_sfc::parse_sfc(content, options) else {
+            return vec![];
+        };
+
+        let mut colors = Vec::new();
+        for style in &descriptor.styles {
+            let start = style.loc.start;
+            for (color_start, color_end, color) in Self::find_colors(&content[start..style.loc.end])
+            {
+                colors.push(ColorInformation {
+                    range: Self::to_range(content, start + color_start, start + color_end),
+                    color,
+                });
+            }
+        }
+        colors
+    }
+
+    /// Get presentations for a color at `range`.
+    ///
+    /// Offers hex and `rgb()` forms, keeping the form currently used in the
+    /// document first so picker edits do not change notation unprompted.
+    pub fn color_presentations(
+        content: &str,
+        color: Color,
+        range: Range,
+    ) -> Vec<ColorPresentation> {
+        let start = super::position_to_offset(content, range.start.line, range.start.character);
+        let is_hex = start.is_some_and(|offset| content[offset..].starts_with('#'));
+
+        let hex = Self::to_hex(color);
+        let rgb = Self::to_rgb_function(color);
+        let labels = if is_hex { [hex, rgb] } else { [rgb, hex] };
+
+        labels
+            .into_iter()
+            .map(|label| ColorPresentation {
+                text_edit: Some(TextEdit {
+                    range,
+                    new_text: label.clone(),
+                }),
+                label,
+                additional_text_edits: None,
+            })
+            .collect()
+    }
+
+    /// Find color values in CSS source, returning `(start, end, color)` offsets.
+    ///
+    /// Only declaration values are considered; candidates followed by `{`
+    /// (i.e. inside a selector such as `a:hover #id`) are discarded.
+    fn find_colors(css: &str) -> Vec<(usize, usize, Color)> {
+        let bytes = css.as_bytes();
+        let mut colors = Vec::new();
+        let mut pending = Vec::new();
+        let mut in_value = false;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let b = bytes[i];
+            match b {
+                b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                    i = css[i + 2..]
+                        .find("*/")
+                        .map_or(bytes.len(), |end| i + 2 + end + 2);
+                    continue;
+                }
+                b'"' | b'\'' => {
+                    i = Self::skip_string(bytes, i);
+                    continue;
+                }
+                b':' => in_value = true,
+                b';' | b'}' => {
+                    in_value = false;
+                    colors.append(&mut pending);
+                }
+                b'{' => {
+                    in_value = false;
+                    pending.clear();
+                }
+                b'#' if in_value => {
+                    let end = Self::ident_end(bytes, i + 1);
+                    if let Some(color) = Self::parse_color(&css[i..end]) {
+                        pending.push((i, end, color));
+                    }
+                    i = end;
+                    continue;
+                }
+                _ if in_value && b.is_ascii_alphabetic() => {
+                    let end = Self::ident_end(bytes, i);
+                    let prev = i.checked_sub(1).map(|p| bytes[p]);
+                    let is_word = !matches!(prev, Some(b'-' | b'.' | b'$' | b'@' | b'_'))
+                        && !prev.is_some_and(|p| p.is_ascii_alphanumeric());
+                    let name = css[i..end].to_ascii_lowercase();
+
+                    if bytes.get(end) == Some(&b'(') {
+                        if is_word && (name == "rgb" || name == "rgba") {
+                            if let Some(close) = css[end..].find(')') {
+                                let close = end + close + 1;
+                                if let Some(color) = Self::parse_color(&css[i..close]) {
+                                    pending.push((i, close, color));
+                                }
+                                i = close;
+                                continue;
+                            }
+                        }
+                    } else if is_word {
+                        if let Some(color) = Self::parse_color(&css[i..end]) {
+                            pending.push((i, end, color));
+                        }
+                    }
+                    i = end;
+                    continue;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+
+        colors.append(&mut pending);
+        colors
+    }
+
+    /// Parse a color with lightning-css and convert it to an LSP color.
+    ///
+    /// Keywords without a fixed value (e.g. `currentColor`) yield `None`.
+    fn parse_color(text: &str) -> Option<Color> {
+        let color = CssColor::parse_string(text).ok()?;
+        let CssColor::RGBA(rgba) = color.to_rgb().ok()? else {
+            return None;
+        };
+        Some(Color {
+            red: rgba.red as f32 / 255.0,
+            green: rgba.green as f32 / 255.0,
+            blue: rgba.blue as f32 / 255.0,
+            alpha: rgba.alpha,
+        })
+    }
+
+    /// Format a color as `#rrggbb` (or `#rrggbbaa` when translucent).
+    fn to_hex(color: Color) -> String {
+        let [r, g, b] = Self::channels(color);
+        if color.alpha < 1.0 {
+            let a = (color.alpha * 255.0).round() as u8;
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+        } else {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        }
+    }
+
+    /// Format a color as `rgb(r, g, b)` (or `rgba(r, g, b, a)` when translucent).
+    fn to_rgb_function(color: Color) -> String {
+        let [r, g, b] = Self::channels(color);
+        if color.alpha < 1.0 {
+            let a = (color.alpha * 100.0).round() / 100.0;
+            format!("rgba({}, {}, {}, {})", r, g, b, a)
+        } else {
+            format!("rgb({}, {}, {})", r, g, b)
+        }
+    }
+
+    #[inline]
+    fn channels(color: Color) -> [u8; 3] {
+        [color.red, color.green, color.blue].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    #[inline]
+    fn ident_end(bytes: &[u8], start: usize) -> usize {
+        bytes[start..]
+            .iter()
+            .position(|b| !(b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_')))
+            .map_or(bytes.len(), |len| start + len)
+    }
+
+    #[inline]
+    fn skip_string(bytes: &[u8], start: usize) -> usize {
+        let quote = bytes[start];
+        let mut i = start + 1;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' => i += 1,
+                b if b == quote => return i + 1,
+                _ => {}
+            }
+            i += 1;
+        }
+        bytes.len()
+    }
+
+    fn to_range(content: &str, start: usize, end: usize) -> Range {
+        let (start_line, start_char) = super::offset_to_position(content, start);
+        let (end_line, end_char) = super::offset_to_position(content, end);
+        Range {
+            start: Position {
+                line: start_line,
+                character: start_char,
+            },
+            end: Position {
+                line: end_line,
+                character: end_char,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color_texts(css: &str) -> Vec<&str> {
+        ColorService::find_colors(css)
+            .into_iter()
+            .map(|(start, end, _)| &css[start..end])
+            .collect()
+    }
+
+    #[test]
+    fn test_find_colors() {
+        let css = ".a { color: #f00; background: rgba(0, 0, 0, 0.5); border: 1px solid navy; }";
+        assert_eq!(color_texts(css), ["#f00", "rgba(0, 0, 0, 0.5)", "navy"]);
+    }
+
+    #[test]
+    fn test_ignores_selectors_and_non_colors() {
+        let css =
+            "a:hover #fff { color: currentColor; content: 'red'; }\n.red { width: var(--red); }";
+        assert!(color_texts(css).is_empty());
+    }
+
+    #[test]
+    fn test_presentations_keep_notation_first() {
+        let content = "color: #ff0000;";
+        let color = Color {
+            red: 1.0,
+            green: 0.0,
+            blue: 0.0,
+            alpha: 1.0,
+        };
+        let range = Range::new(Position::new(0, 7), Position::new(0, 14));
+        let labels: Vec<_> = ColorService::color_presentations(content, color, range)
+            .into_iter()
+            .map(|p| p.label)
+            .collect();
+        assert_eq!(labels, ["#ff0000", "rgb(255, 0, 0)"]);
+
+        let translucent = Color {
+            alpha: 0.5,
+            ..color
+        };
+        let range = Range::new(Position::new(0, 0), Position::new(0, 3));
+        let labels: Vec<_> = ColorService::color_presentations("red", translucent, range)
+            .into_iter()
+            .map(|p| p.label)
+            .collect();
+        assert_eq!(labels, ["rgba(255, 0, 0, 0.5)", "#ff000080"]);
+    }
+}
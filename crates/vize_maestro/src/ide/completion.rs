@@ -8,11 +8,13 @@
 //!
 //! Uses vize_croquis for accurate scope analysis and type information.
 
+use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
 use tower_lsp::lsp_types::{
     CompletionItem, CompletionItemKind, CompletionItemLabelDetails, CompletionResponse,
-    Documentation, InsertTextFormat, MarkupContent, MarkupKind,
+    Documentation, InsertTextFormat, MarkupContent, MarkupKind, Position, Range, TextEdit, Url,
 };
 use vize_croquis::{Analyzer, AnalyzerOptions};
 use vize_relief::BindingType;
@@ -26,6 +28,19 @@ use crate::virtual_code::BlockType;
 /// Completion service for providing context-aware completions.
 pub struct CompletionService;
 
+/// Data attached to workspace component completions, consumed by
+/// `completionItem/resolve` to compute the import edit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoImportData {
+    /// Document the completion was requested in
+    pub uri: Url,
+    /// Component binding name (e.g. `MyButton`)
+    pub name: String,
+    /// Import specifier relative to the document (e.g. `./components/MyButton.vue`)
+    pub path: String,
+}
+
 impl CompletionService {
     /// Get completions for the given context.
     pub fn complete(ctx: &IdeContext) -> Option<CompletionResponse> {
@@ -98,7 +113,11 @@ impl CompletionService {
                 // Merge tsgo items with static completions
                 let mut items = tsgo_items;
                 items.extend(match block_type {
-                    BlockType::Template => Self::directive_completions(),
+                    BlockType::Template => {
+                        let mut v = Self::directive_completions();
+                        v.extend(Self::workspace_component_completions(ctx));
+                        v
+                    }
                     BlockType::Script => Self::composition_api_completions(),
                     BlockType::ScriptSetup => {
                         let mut v = Self::composition_api_completions();
@@ -478,6 +497,9 @@ impl CompletionService {
         // Add built-in components
         items.extend(Self::builtin_component_completions());
 
+        // Add workspace components that are not imported yet
+        items.extend(Self::workspace_component_completions(ctx));
+
         // Use vize_croquis for accurate scope analysis and type information
         let options = vize_atelier_sfc::SfcParseOptions {
             filename: ctx.uri.path().to_string(),
@@ -925,6 +947,148 @@ impl CompletionService {
         }
     }
 
+    /// Workspace component completions for the given context.
+    fn workspace_component_completions(ctx: &IdeContext) -> Vec<CompletionItem> {
+        let files = ctx.state.workspace_vue_files();
+        Self::component_import_completions(&ctx.content, ctx.uri, ctx.offset, &files)
+    }
+
+    /// Offer workspace `.vue` files as components while typing a tag name.
+    ///
+    /// Components already bound in `<script setup>` are skipped. The import
+    /// edit itself is computed lazily by [`Self::resolve`].
+    pub fn component_import_completions(
+        content: &str,
+        uri: &Url,
+        offset: usize,
+        files: &[PathBuf],
+    ) -> Vec<CompletionItem> {
+        if files.is_empty() || tag_name_prefix(&content[..offset.min(content.len())]).is_none() {
+            return vec![];
+        }
+        let Ok(current) = uri.to_file_path() else {
+            return vec![];
+        };
+        let Some(current_dir) = current.parent() else {
+            return vec![];
+        };
+
+        let options = vize_atelier_sfc::SfcParseOptions {
+            filename: uri.path().to_string(),
+            ..Default::default()
+        };
+        let Ok(descriptor) = vize_atelier_sfc::parse_sfc(content, options) else {
+            return vec![];
+        };
+        let bindings = descriptor.script_setup.as_ref().map(|script_setup| {
+            let mut analyzer = Analyzer::with_options(AnalyzerOptions {
+                analyze_script: true,
+                ..Default::default()
+            });
+            analyzer.analyze_script_setup(&script_setup.content);
+            analyzer.finish().bindings
+        });
+
+        let mut items = Vec::new();
+        for file in files {
+            if *file == current {
+                continue;
+            }
+            let Some(name) = component_name_from_path(file) else {
+                continue;
+            };
+            if bindings.as_ref().is_some_and(|b| b.contains(&name)) {
+                continue;
+            }
+
+            let path = relative_import_path(current_dir, file);
+            let data = AutoImportData {
+                uri: uri.clone(),
+                name: name.clone(),
+                path: path.clone(),
+            };
+            items.push(CompletionItem {
+                label: name.clone(),
+                kind: Some(CompletionItemKind::CLASS),
+                label_details: Some(CompletionItemLabelDetails {
+                    detail: None,
+                    description: Some(path.clone()),
+                }),
+                detail: Some(format!("Auto import from '{}'", path)),
+                insert_text: Some(name),
+                sort_text: Some(format!("1{}", path)),
+                data: serde_json::to_value(data).ok(),
+                ..Default::default()
+            });
+        }
+
+        items
+    }
+
+    /// Resolve a completion item, filling in lazily computed fields.
+    ///
+    /// Workspace component items get an `additionalTextEdits` entry that
+    /// imports the component into `<script setup>`.
+    pub fn resolve(mut item: CompletionItem, content: &str) -> CompletionItem {
+        let Some(data) = item
+            .data
+            .as_ref()
+            .and_then(|d| serde_json::from_value::<AutoImportData>(d.clone()).ok())
+        else {
+            return item;
+        };
+
+        if let Some(edit) = Self::auto_import_edit(content, &data.name, &data.path) {
+            item.additional_text_edits = Some(vec![edit]);
+        }
+        item
+    }
+
+    /// Build the edit importing `name` from `path` into `<script setup>`.
+    ///
+    /// The import goes at the top of an existing `<script setup>` block. Without
+    /// one, a new block is created after the plain `<script>` block (reusing its
+    /// `lang`), or at the start of the file.
+    pub fn auto_import_edit(content: &str, name: &str, path: &str) -> Option<TextEdit> {
+        let options = vize_atelier_sfc::SfcParseOptions::default();
+        let descriptor = vize_atelier_sfc::parse_sfc(content, options).ok()?;
+        let import = format!("import {} from '{}'\n", name, path);
+
+        let (offset, new_text) = if let Some(ref script_setup) = descriptor.script_setup {
+            let start = script_setup.loc.start;
+            let offset = if content[start..].starts_with("\r\n") {
+                start + 2
+            } else if content[start..].starts_with('\n') {
+                start + 1
+            } else {
+                start
+            };
+            (offset, import)
+        } else {
+            let lang = descriptor
+                .script
+                .as_ref()
+                .and_then(|script| script.lang.as_deref())
+                .map(|lang| format!(" lang=\"{}\"", lang))
+                .unwrap_or_default();
+            let block = format!("<script setup{}>\n{}</script>\n", lang, import);
+            match descriptor.script {
+                Some(ref script) => (script.loc.tag_end, format!("\n\n{}", block.trim_end())),
+                None => (0, format!("{}\n", block)),
+            }
+        };
+
+        let (line, character) = super::offset_to_position(content, offset);
+        let position = Position { line, character };
+        Some(TextEdit {
+            range: Range {
+                start: position,
+                end: position,
+            },
+            new_text,
+        })
+    }
+
     /// Template snippet completions.
     fn template_snippets() -> Vec<CompletionItem> {
         vec![
@@ -1259,6 +1423,62 @@ pub fn trigger_characters() -> Vec<String> {
     TRIGGER_CHARACTERS.iter().map(|c| c.to_string()).collect()
 }
 
+// =============================================================================
+// Workspace component helpers
+// =============================================================================
+
+/// Return the partial tag name if the cursor is right after `<name`.
+///
+/// A `<` inside a `{{ }}` interpolation is a comparison, not a tag.
+fn tag_name_prefix(before: &str) -> Option<&str> {
+    if is_inside_interpolation(before) {
+        return None;
+    }
+    let start = before.rfind('<')? + 1;
+    let prefix = &before[start..];
+    prefix
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        .then_some(prefix)
+}
+
+/// Derive a PascalCase component name from a `.vue` file path.
+///
+/// Returns `None` for names that are not valid identifiers (e.g. `Foo.art.vue`).
+fn component_name_from_path(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?;
+    let name = super::kebab_to_pascal(stem);
+    let mut chars = name.chars();
+    let first = chars.next()?;
+    (first.is_ascii_uppercase() && chars.all(|c| c.is_ascii_alphanumeric() || c == '_'))
+        .then_some(name)
+}
+
+/// Build a relative import specifier from `from_dir` to `to`.
+fn relative_import_path(from_dir: &Path, to: &Path) -> String {
+    let from: Vec<Component> = from_dir.components().collect();
+    let to_components: Vec<Component> = to.components().collect();
+    let common = from
+        .iter()
+        .zip(&to_components)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut parts: Vec<String> = vec!["..".to_string(); from.len() - common];
+    parts.extend(
+        to_components[common..]
+            .iter()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned()),
+    );
+
+    let path = parts.join("/");
+    if path.starts_with("..") {
+        path
+    } else {
+        format!("./{}", path)
+    }
+}
+
 // =============================================================================
 // HTML comment and Art file context detection helpers
 // =============================================================================
@@ -1277,6 +1497,15 @@ fn is_inside_html_comment(content: &str, offset: usize) -> bool {
     }
 }
 
+/// Check if cursor is inside an unclosed `{{ ... }}` interpolation.
+fn is_inside_interpolation(before: &str) -> bool {
+    if let Some(open) = before.rfind("{{") {
+        !before[open + 2..].contains("}}")
+    } else {
+        false
+    }
+}
+
 /// Check if cursor is inside <art ...> opening tag.
 fn is_inside_art_tag(before: &str) -> bool {
     // Find last <art and check if we're before the closing >
@@ -1446,4 +1675,40 @@ mod tests {
         // Between two comments, after both closed
         assert!(!is_inside_html_comment("<!-- a --> <!-- b --> after", 26));
     }
+
+    #[test]
+    fn test_tag_name_prefix() {
+        assert_eq!(tag_name_prefix("<template>\n  <MyButt"), Some("MyButt"));
+        assert_eq!(tag_name_prefix("<template>\n  <my-butt"), Some("my-butt"));
+        assert_eq!(tag_name_prefix("<template>\n  <"), Some(""));
+        assert_eq!(tag_name_prefix("<template>\n  <div cla"), None);
+        assert_eq!(tag_name_prefix("<template>\n  {{ a"), None);
+        assert_eq!(tag_name_prefix("<template>\n  {{ count <limit"), None);
+        assert_eq!(
+            tag_name_prefix("<template>\n  {{ a }}\n  <MyButt"),
+            Some("MyButt")
+        );
+    }
+
+    #[test]
+    fn test_component_name_from_path() {
+        let name = |p: &str| component_name_from_path(Path::new(p));
+        assert_eq!(name("/src/MyButton.vue").as_deref(), Some("MyButton"));
+        assert_eq!(name("/src/my-button.vue").as_deref(), Some("MyButton"));
+        assert_eq!(name("/src/Button.art.vue"), None);
+    }
+
+    #[test]
+    fn test_relative_import_path() {
+        let rel = |from: &str, to: &str| relative_import_path(Path::new(from), Path::new(to));
+        assert_eq!(
+            rel("/app/src", "/app/src/components/MyButton.vue"),
+            "./components/MyButton.vue"
+        );
+        assert_eq!(
+            rel("/app/src/pages", "/app/src/components/MyButton.vue"),
+            "../components/MyButton.vue"
+        );
+        assert_eq!(rel("/app/src", "/app/src/Foo.vue"), "./Foo.vue");
+    }
 }
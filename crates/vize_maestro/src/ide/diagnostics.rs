@@ -7,7 +7,10 @@
 //! - Future: vize_canon (type checker)
 
 use tower_lsp::lsp_types::{
-    CodeDescription, Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range, Url,
+    CodeDescription, Diagnostic, DiagnosticSeverity, DocumentDiagnosticReport,
+    FullDocumentDiagnosticReport, NumberOrString, Position, Range,
+    RelatedFullDocumentDiagnosticReport, RelatedUnchangedDocumentDiagnosticReport,
+    UnchangedDocumentDiagnosticReport, Url,
 };
 
 use vize_patina::{render_help, HelpRenderTarget};
@@ -154,6 +157,63 @@ impl DiagnosticService {
         diagnostics
     }
 
+    /// Build a pull diagnostic report (`textDocument/diagnostic`) for a document.
+    ///
+    /// Uses the same sources as [`Self::collect`]. When `previous_result_id`
+    /// matches the current result id, an unchanged report is returned without
+    /// collecting so the client can keep its cached diagnostics.
+    pub fn pull(
+        state: &ServerState,
+        uri: &Url,
+        previous_result_id: Option<&str>,
+    ) -> DocumentDiagnosticReport {
+        let result_id = Self::result_id(state, uri);
+        if previous_result_id == Some(result_id.as_str()) {
+            return Self::unchanged_report(result_id);
+        }
+        Self::full_report(result_id, Self::collect(state, uri))
+    }
+
+    /// Build a pull diagnostic report asynchronously (includes tsgo diagnostics).
+    #[cfg(feature = "native")]
+    pub async fn pull_async(
+        state: &ServerState,
+        uri: &Url,
+        previous_result_id: Option<&str>,
+    ) -> DocumentDiagnosticReport {
+        let result_id = Self::result_id(state, uri);
+        if previous_result_id == Some(result_id.as_str()) {
+            return Self::unchanged_report(result_id);
+        }
+        Self::full_report(result_id, Self::collect_async(state, uri).await)
+    }
+
+    fn unchanged_report(result_id: String) -> DocumentDiagnosticReport {
+        DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+            related_documents: None,
+            unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport { result_id },
+        })
+    }
+
+    fn full_report(result_id: String, diagnostics: Vec<Diagnostic>) -> DocumentDiagnosticReport {
+        DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+            related_documents: None,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                result_id: Some(result_id),
+                items: diagnostics,
+            },
+        })
+    }
+
+    /// Result id identifying a document version and the diagnostic generation.
+    ///
+    /// Diagnostics from other files (e.g. type errors) and settings can change
+    /// without a new version; those bump the generation instead.
+    fn result_id(state: &ServerState, uri: &Url) -> String {
+        let version = state.documents.get(uri).map_or(0, |doc| doc.version);
+        format!("{}-{}", version, state.diagnostic_generation())
+    }
+
     /// Collect diagnostics from tsgo LSP.
     #[cfg(feature = "native")]
     async fn collect_tsgo_diagnostics(state: &ServerState, uri: &Url) -> Vec<Diagnostic> {
@@ -384,6 +444,11 @@ impl DiagnosticService {
                         3 => DiagnosticSeverity::INFORMATION,
                         _ => DiagnosticSeverity::HINT,
                     }),
+                    code: vize_canon::virtual_ts::diagnostic_code(
+                        &diag.message,
+                        diag.code.as_ref(),
+                    )
+                    .map(NumberOrString::String),
                     source: Some("vize/tsgo".to_string()),
                     message: diag.message,
                     ..Default::default()
@@ -1054,6 +1119,45 @@ mod tests {
         assert_eq!(diagnostic.code, Some(NumberOrString::Number(42)));
     }
 
+    #[test]
+    fn test_pull_matches_push_diagnostics() {
+        let state = ServerState::new();
+        let uri = Url::parse("file:///test.vue").unwrap();
+        state.documents.open(
+            uri.clone(),
+            r#"<template><li v-for="item in items">{{ item }}</li></template>"#.to_string(),
+            1,
+            "vue".to_string(),
+        );
+
+        let pushed = DiagnosticService::collect(&state, &uri);
+        assert!(pushed
+            .iter()
+            .any(|d| d.source.as_deref() == Some(sources::LINTER)));
+
+        let DocumentDiagnosticReport::Full(report) = DiagnosticService::pull(&state, &uri, None)
+        else {
+            panic!("expected a full report");
+        };
+        let report = report.full_document_diagnostic_report;
+        assert_eq!(report.items, pushed);
+
+        // The same result id yields an unchanged report
+        let result_id = report.result_id.unwrap();
+        assert!(matches!(
+            DiagnosticService::pull(&state, &uri, Some(&result_id)),
+            DocumentDiagnosticReport::Unchanged(ref unchanged)
+                if unchanged.unchanged_document_diagnostic_report.result_id == result_id
+        ));
+
+        // A new generation reports in full again
+        state.bump_diagnostic_generation();
+        assert!(matches!(
+            DiagnosticService::pull(&state, &uri, Some(&result_id)),
+            DocumentDiagnosticReport::Full(_)
+        ));
+    }
+
     #[test]
     fn test_severity_conversion() {
         assert_eq!(
@@ -0,0 +1,259 @@
+//! Document highlight provider.
+//!
+//! Highlights every occurrence of the identifier under the cursor within the
+//! current SFC, using the same matching as find-references:
+//! - Script bindings: declarations and assignments are writes, other uses reads
+//! - Template expressions: `v-model` and assignments are writes, other uses reads
+//! - Style `v-bind()` and static `ref="name"` attributes are text matches
+
+use tower_lsp::lsp_types::{DocumentHighlight, DocumentHighlightKind, Position, Range};
+use vize_relief::ast::{ExpressionNode, PropNode, TemplateChildNode};
+
+use super::{IdeContext, ReferencesService};
+
+/// Document highlight service for identifiers.
+pub struct DocumentHighlightService;
+
+impl DocumentHighlightService {
+    /// Get highlights for the symbol under the cursor.
+    pub fn document_highlights(ctx: &IdeContext) -> Option<Vec<DocumentHighlight>> {
+        Self::document_highlights_for_sfc(&ctx.content, ctx.uri.path(), ctx.offset)
+    }
+
+    /// Get highlights for the symbol at an offset in SFC source.
+    pub fn document_highlights_for_sfc(
+        content: &str,
+        filename: &str,
+        offset: usize,
+    ) -> Option<Vec<DocumentHighlight>> {
+        let word = ReferencesService::get_word_at_offset(content, offset)?;
+
+        let options = vize_atelier_sfc::SfcParseOptions {
+            filename: filename.to_string(),
+            ..Default::default()
+        };
+        let descriptor = vize_atelier_sfc::parse_sfc(content, options).ok()?;
+
+        // (absolute offset, kind)
+        let mut occurrences = Vec::new();
+
+        for script in [&descriptor.script_setup, &descriptor.script]
+            .into_iter()
+            .flatten()
+        {
+            let script_content = script.content.as_ref();
+            for pos in ReferencesService::find_word_occurrences(script_content, &word) {
+                let kind = Self::classify(script_content, pos, word.len());
+                occurrences.push((script.loc.start + pos, kind));
+            }
+        }
+
+        if let Some(ref template) = descriptor.template {
+            let allocator = vize_carton::Bump::new();
+            let (ast, _) = vize_armature::parse(&allocator, &template.content);
+            let mut template_occurrences = Vec::new();
+            Self::collect_template_occurrences(&ast.children, &word, &mut template_occurrences);
+            occurrences.extend(
+                template_occurrences
+                    .into_iter()
+                    .map(|(pos, kind)| (template.loc.start + pos, kind)),
+            );
+        }
+
+        for style in &descriptor.styles {
+            let style_content = style.content.as_ref();
+            for (pos, _) in style_content.match_indices("v-bind(") {
+                let inner = &style_content[pos + 7..];
+                let Some(close) = inner.find(')') else {
+                    continue;
+                };
+                let arg = &inner[..close];
+                if arg.trim() == word {
+                    let arg_start = pos + 7 + (arg.len() - arg.trim_start().len());
+                    occurrences.push((style.loc.start + arg_start, DocumentHighlightKind::TEXT));
+                }
+            }
+        }
+
+        if occurrences.is_empty() {
+            return None;
+        }
+
+        occurrences.sort_by_key(|(pos, _)| *pos);
+        occurrences.dedup_by_key(|(pos, _)| *pos);
+
+        Some(
+            occurrences
+                .into_iter()
+                .map(|(start, kind)| DocumentHighlight {
+                    range: Self::range(content, start, start + word.len()),
+                    kind: Some(kind),
+                })
+                .collect(),
+        )
+    }
+
+    /// Collect word occurrences in template expressions and `ref` attributes.
+    ///
+    /// Offsets are relative to the template content.
+    fn collect_template_occurrences(
+        children: &[TemplateChildNode<'_>],
+        word: &str,
+        occurrences: &mut Vec<(usize, DocumentHighlightKind)>,
+    ) {
+        for child in children {
+            match child {
+                TemplateChildNode::Element(el) => {
+                    for prop in &el.props {
+                        match prop {
+                            PropNode::Directive(dir) => {
+                                if let Some(ref exp) = dir.exp {
+                                    Self::push_expression(
+                                        exp,
+                                        word,
+                                        dir.name == "model",
+                                        occurrences,
+                                    );
+                                }
+                            }
+                            PropNode::Attribute(attr) if attr.name == "ref" => {
+                                let is_match =
+                                    attr.value.as_ref().is_some_and(|v| v.content == word);
+                                if let Some(pos) = attr.loc.source.rfind(word).filter(|_| is_match)
+                                {
+                                    occurrences.push((
+                                        attr.loc.start.offset as usize + pos,
+                                        DocumentHighlightKind::TEXT,
+                                    ));
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    Self::collect_template_occurrences(&el.children, word, occurrences);
+                }
+                TemplateChildNode::Interpolation(interp) => {
+                    Self::push_expression(&interp.content, word, false, occurrences);
+                }
+                TemplateChildNode::If(if_node) => {
+                    for branch in &if_node.branches {
+                        if let Some(ref cond) = branch.condition {
+                            Self::push_expression(cond, word, false, occurrences);
+                        }
+                        Self::collect_template_occurrences(&branch.children, word, occurrences);
+                    }
+                }
+                TemplateChildNode::For(for_node) => {
+                    Self::push_expression(&for_node.source, word, false, occurrences);
+                    Self::collect_template_occurrences(&for_node.children, word, occurrences);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Collect word occurrences in a template expression.
+    fn push_expression(
+        exp: &ExpressionNode<'_>,
+        word: &str,
+        is_model: bool,
+        occurrences: &mut Vec<(usize, DocumentHighlightKind)>,
+    ) {
+        let Some((text, expr_offset)) = ReferencesService::get_expression_info(exp) else {
+            return;
+        };
+        for pos in ReferencesService::find_word_occurrences(&text, word) {
+            let kind = if is_model {
+                DocumentHighlightKind::WRITE
+            } else {
+                Self::classify(&text, pos, word.len())
+            };
+            occurrences.push((expr_offset + pos, kind));
+        }
+    }
+
+    /// Classify an occurrence as a write (declaration, assignment, update) or read.
+    ///
+    /// Assigning through `.value` counts as writing the ref itself.
+    fn classify(text: &str, pos: usize, len: usize) -> DocumentHighlightKind {
+        let before = text[..pos].trim_end();
+        let after = text[pos + len..].trim_start();
+        let after = match after.strip_prefix(".value") {
+            Some(rest) if !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_') => {
+                rest.trim_start()
+            }
+            _ => after,
+        };
+
+        let is_declaration = ["const", "let", "var", "function", "class"]
+            .iter()
+            .any(|keyword| {
+                before.ends_with(keyword)
+                    && !before[..before.len() - keyword.len()]
+                        .ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+            });
+        let is_assignment = (after.starts_with('=') && !after.starts_with("=="))
+            || [
+                "+=", "-=", "*=", "/=", "%=", "**=", "&&=", "||=", "??=", "++", "--",
+            ]
+            .iter()
+            .any(|op| after.starts_with(op))
+            || before.ends_with("++")
+            || before.ends_with("--");
+
+        if is_declaration || is_assignment {
+            DocumentHighlightKind::WRITE
+        } else {
+            DocumentHighlightKind::READ
+        }
+    }
+
+    fn range(content: &str, start: usize, end: usize) -> Range {
+        let (start_line, start_char) = super::offset_to_position(content, start);
+        let (end_line, end_char) = super::offset_to_position(content, end);
+        Range {
+            start: Position {
+                line: start_line,
+                character: start_char,
+            },
+            end: Position {
+                line: end_line,
+                character: end_char,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        let text = "const count = ref(0)\ncount.value++\nfoo(count.valueOf)";
+        assert_eq!(
+            DocumentHighlightService::classify(text, 6, 5),
+            DocumentHighlightKind::WRITE
+        );
+        assert_eq!(
+            DocumentHighlightService::classify(text, 21, 5),
+            DocumentHighlightKind::WRITE
+        );
+        assert_eq!(
+            DocumentHighlightService::classify(text, 39, 5),
+            DocumentHighlightKind::READ
+        );
+        assert_eq!(
+            DocumentHighlightService::classify("count = 1", 0, 5),
+            DocumentHighlightKind::WRITE
+        );
+        assert_eq!(
+            DocumentHighlightService::classify("count == 1", 0, 5),
+            DocumentHighlightKind::READ
+        );
+        assert_eq!(
+            DocumentHighlightService::classify("count++", 0, 5),
+            DocumentHighlightKind::WRITE
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! Document formatting provider.
+//!
+//! Formats a whole SFC with `vize_glyph`, returning a single full-document
+//! edit. Custom blocks are left exactly as written.
+
+use tower_lsp::lsp_types::{FormattingOptions, Position, Range, TextEdit};
+
+/// Document formatting service.
+pub struct FormattingService;
+
+impl FormattingService {
+    /// Format a document and return TextEdits for the LSP client.
+    ///
+    /// Returns `Some(vec![])` if no changes needed, `Some(vec![edit])` with the
+    /// full-document replacement, or `None` on formatting error.
+    pub fn format_document(
+        content: &str,
+        options: &vize_glyph::FormatOptions,
+    ) -> Option<Vec<TextEdit>> {
+        let allocator = vize_glyph::Allocator::with_capacity(content.len());
+
+        let formatted = match vize_glyph::format_sfc_with_allocator(content, options, &allocator) {
+            Ok(result) => result,
+            Err(_) => return None,
+        };
+
+        if !formatted.changed {
+            return Some(vec![]);
+        }
+
+        let line_count = content.lines().count() as u32;
+        let last_line_len = content.lines().last().map_or(0, |l| l.len()) as u32;
+        Some(vec![TextEdit {
+            range: Range {
+                start: Position::new(0, 0),
+                end: Position::new(line_count, last_line_len),
+            },
+            new_text: formatted.code,
+        }])
+    }
+
+    /// Apply the client's indentation settings on top of the configured options.
+    pub fn format_options(
+        base: &vize_glyph::FormatOptions,
+        client: &FormattingOptions,
+    ) -> vize_glyph::FormatOptions {
+        let mut options = base.clone();
+        options.tab_width = client.tab_size.min(u8::MAX as u32) as u8;
+        options.use_tabs = !client.insert_spaces;
+        options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_document_is_idempotent() {
+        let source = "<template>\n<div>hello</div>\n</template>\n";
+        let options = vize_glyph::FormatOptions::default();
+
+        // First format
+        let result = FormattingService::format_document(source, &options);
+        assert!(result.is_some());
+        let edits = result.unwrap();
+        assert!(!edits.is_empty(), "expected edits on first format");
+
+        // Second format on the already-formatted output should produce no changes
+        let formatted = &edits[0].new_text;
+        let result2 = FormattingService::format_document(formatted, &options);
+        assert!(result2.is_some());
+        let edits2 = result2.unwrap();
+        assert!(
+            edits2.is_empty(),
+            "expected no edits on second format (idempotent)"
+        );
+    }
+
+    #[test]
+    fn format_document_returns_edit_for_unformatted() {
+        // Missing indentation should trigger formatting
+        let source = "<template>\n<div>hello</div>\n</template>\n";
+        let options = vize_glyph::FormatOptions::default();
+        let result = FormattingService::format_document(source, &options);
+        assert!(result.is_some());
+        let edits = result.unwrap();
+        if !edits.is_empty() {
+            assert_eq!(edits.len(), 1);
+            let edit = &edits[0];
+            // Range should start at beginning of document
+            assert_eq!(edit.range.start, Position::new(0, 0));
+            // new_text should contain the formatted content
+            assert!(edit.new_text.contains("<template>"));
+        }
+    }
+
+    #[test]
+    fn format_document_respects_options() {
+        let source = "<script>\nconst x = 1;\n</script>\n";
+        let mut options = vize_glyph::FormatOptions::default();
+        options.semi = false;
+        let result = FormattingService::format_document(source, &options);
+        assert!(result.is_some());
+        let edits = result.unwrap();
+        if !edits.is_empty() {
+            // With semi: false, semicolons should be removed
+            assert!(
+                !edits[0].new_text.contains("const x = 1;")
+                    || edits[0].new_text.contains("const x = 1\n")
+            );
+        }
+    }
+
+    #[test]
+    fn format_document_edit_covers_full_range() {
+        let source = "<template>\n<div   class=\"a\"   id=\"b\" >\nhello\n</div>\n</template>\n";
+        let options = vize_glyph::FormatOptions::default();
+        let result = FormattingService::format_document(source, &options);
+        assert!(result.is_some());
+        let edits = result.unwrap();
+        if !edits.is_empty() {
+            let edit = &edits[0];
+            assert_eq!(edit.range.start, Position::new(0, 0));
+            // End line should cover the full document
+            let line_count = source.lines().count() as u32;
+            assert_eq!(edit.range.end.line, line_count);
+        }
+    }
+
+    #[test]
+    fn format_document_with_single_quote() {
+        let source = "<script>\nconst x = \"hello\";\n</script>\n";
+        let mut options = vize_glyph::FormatOptions::default();
+        options.single_quote = true;
+        let result = FormattingService::format_document(source, &options);
+        assert!(result.is_some());
+        let edits = result.unwrap();
+        if !edits.is_empty() {
+            assert!(edits[0].new_text.contains("'hello'"));
+        }
+    }
+
+    #[test]
+    fn format_options_follow_client_indentation() {
+        let client = FormattingOptions {
+            tab_size: 4,
+            insert_spaces: false,
+            ..Default::default()
+        };
+        let options =
+            FormattingService::format_options(&vize_glyph::FormatOptions::default(), &client);
+        assert_eq!(options.tab_width, 4);
+        assert!(options.use_tabs);
+    }
+
+    #[test]
+    fn format_document_keeps_custom_blocks() {
+        let source = "<template>\n<div>hello</div>\n</template>\n\n<i18n lang=\"json\">\n{\"en\":   {}}\n</i18n>\n";
+        let options = vize_glyph::FormatOptions::default();
+        let edits = FormattingService::format_document(source, &options).unwrap();
+        assert_eq!(edits.len(), 1);
+        assert!(edits[0].new_text.contains("{\"en\":   {}}"));
+    }
+}
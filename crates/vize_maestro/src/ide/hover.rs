@@ -253,7 +253,7 @@ impl HoverService {
 
     /// Convert tsgo LspHover to tower-lsp Hover.
     #[cfg(feature = "native")]
-    fn convert_lsp_hover(lsp_hover: LspHover) -> Hover {
+    pub(crate) fn convert_lsp_hover(lsp_hover: LspHover) -> Hover {
         let contents = match lsp_hover.contents {
             LspHoverContents::Markup(markup) => {
                 let value = if markup.kind == "markdown" {
@@ -2,20 +2,91 @@
 //!
 //! Provides inlay hints for:
 //! - Props destructure (show `#props.` prefix for destructured props in template and script)
+//! - Ref types (show `: Ref<T>` after `const x = ref(...)` in script setup)
 //!
-//! Uses vize_croquis for proper scope analysis to accurately identify destructured props.
-
-use tower_lsp::lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Position, Range, Url};
+//! Uses vize_croquis for proper scope analysis to accurately identify destructured props
+//! and reactive bindings.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{ArrayExpressionElement, BindingPattern, CallExpression, Expression, Statement};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType};
+use serde::{Deserialize, Serialize};
+use tower_lsp::lsp_types::{
+    Hover, HoverContents, InlayHint, InlayHintKind, InlayHintLabel, InlayHintTooltip,
+    MarkupContent, MarkupKind, Position, Range, Url,
+};
+use vize_croquis::reactivity::ReactiveKind;
 use vize_croquis::{Analyzer, AnalyzerOptions};
 
 use super::offset_to_position;
 
+/// Which inlay hint kinds are enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InlayHintConfig {
+    /// Show `#props.` before destructured props.
+    pub props_prefix: bool,
+    /// Show `: Ref<T>` after ref declarations.
+    pub ref_types: bool,
+}
+
+impl Default for InlayHintConfig {
+    fn default() -> Self {
+        Self {
+            props_prefix: true,
+            ref_types: true,
+        }
+    }
+}
+
+/// Data attached to ref type hints, used to resolve the tooltip lazily.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefTypeHintData {
+    /// Document the hint belongs to.
+    pub uri: Url,
+    /// SFC offset of the variable name.
+    pub offset: usize,
+}
+
+/// Document and range that hints are collected for.
+struct HintTarget<'a> {
+    /// Full SFC content.
+    content: &'a str,
+    /// Document URI, attached to hints for resolving.
+    uri: &'a Url,
+    /// Requested range; hints outside it are dropped.
+    range: Range,
+}
+
+/// A top-level `ref()`/`shallowRef()` declaration in script setup.
+struct RefDeclaration {
+    /// SFC offset of the variable name.
+    start: usize,
+    /// SFC offset right after the variable name.
+    end: usize,
+    /// `Ref` or `ShallowRef`.
+    wrapper: &'static str,
+    /// Value type inferred from the type argument or initial value.
+    value_type: Option<String>,
+}
+
 /// Inlay hint service.
 pub struct InlayHintService;
 
 impl InlayHintService {
     /// Get inlay hints for a document range.
     pub fn get_hints(content: &str, uri: &Url, range: Range) -> Vec<InlayHint> {
+        Self::get_hints_with_config(content, uri, range, InlayHintConfig::default())
+    }
+
+    /// Get inlay hints for a document range, limited to the enabled hint kinds.
+    pub fn get_hints_with_config(
+        content: &str,
+        uri: &Url,
+        range: Range,
+        config: InlayHintConfig,
+    ) -> Vec<InlayHint> {
         let mut hints = Vec::new();
 
         let options = vize_atelier_sfc::SfcParseOptions {
@@ -40,6 +111,26 @@ impl InlayHintService {
         analyzer.analyze_script_setup(&script_setup.content);
         let croquis = analyzer.finish();
 
+        if config.ref_types {
+            let target = HintTarget {
+                content,
+                uri,
+                range,
+            };
+            Self::collect_ref_type_hints(
+                &script_setup.content,
+                script_setup.loc.start,
+                script_setup.lang.as_deref(),
+                &croquis,
+                &target,
+                &mut hints,
+            );
+        }
+
+        if !config.props_prefix {
+            return hints;
+        }
+
         // Get all prop names from defineProps (for template hints)
         let all_prop_names: Vec<String> = croquis
             .macros
@@ -94,6 +185,184 @@ impl InlayHintService {
         hints
     }
 
+    /// Resolve the tooltip of a ref type hint from the hover at its variable.
+    ///
+    /// Falls back to a generic note when no hover information is available.
+    pub fn resolve(mut hint: InlayHint, hover: Option<Hover>) -> InlayHint {
+        let tooltip = match hover.map(|hover| hover.contents) {
+            Some(HoverContents::Markup(markup)) => InlayHintTooltip::MarkupContent(markup),
+            _ => InlayHintTooltip::MarkupContent(MarkupContent {
+                kind: MarkupKind::PlainText,
+                value: "Inferred from the initial value".to_string(),
+            }),
+        };
+        hint.tooltip = Some(tooltip);
+        hint
+    }
+
+    /// Collect `: Ref<T>` hints for top-level ref declarations in script setup.
+    ///
+    /// Labels use the Croquis-inferred type; the checker's type is filled in
+    /// as the tooltip on resolve.
+    fn collect_ref_type_hints(
+        script: &str,
+        script_offset: usize,
+        lang: Option<&str>,
+        croquis: &vize_croquis::Croquis,
+        target: &HintTarget<'_>,
+        hints: &mut Vec<InlayHint>,
+    ) {
+        for decl in Self::collect_ref_declarations(script, script_offset, lang, croquis) {
+            let Some(value_type) = decl.value_type.as_deref() else {
+                continue;
+            };
+            let label = format!(": {}<{}>", decl.wrapper, value_type);
+            if let Some(hint) = Self::ref_type_hint(&decl, label, target) {
+                hints.push(hint);
+            }
+        }
+    }
+
+    /// Collect top-level ref declarations without a type annotation.
+    fn collect_ref_declarations(
+        script: &str,
+        script_offset: usize,
+        lang: Option<&str>,
+        croquis: &vize_croquis::Croquis,
+    ) -> Vec<RefDeclaration> {
+        let source_type = match lang {
+            Some("ts") => SourceType::ts(),
+            Some("tsx") => SourceType::tsx(),
+            Some("jsx") => SourceType::jsx(),
+            _ => SourceType::mjs(),
+        };
+        let allocator = Allocator::default();
+        let parsed = Parser::new(&allocator, script, source_type).parse();
+
+        let mut declarations = Vec::new();
+        for stmt in &parsed.program.body {
+            let Statement::VariableDeclaration(decl) = stmt else {
+                continue;
+            };
+            for declarator in &decl.declarations {
+                let BindingPattern::BindingIdentifier(id) = &declarator.id else {
+                    continue;
+                };
+                if declarator.type_annotation.is_some() {
+                    continue;
+                }
+                let Some(Expression::CallExpression(call)) = &declarator.init else {
+                    continue;
+                };
+                // defineModel is tracked as a ref but returns a ModelRef
+                if matches!(&call.callee, Expression::Identifier(callee) if callee.name == "defineModel")
+                {
+                    continue;
+                }
+                let wrapper = match croquis.reactivity.lookup(id.name.as_str()).map(|s| s.kind) {
+                    Some(ReactiveKind::Ref) => "Ref",
+                    Some(ReactiveKind::ShallowRef) => "ShallowRef",
+                    _ => continue,
+                };
+                declarations.push(RefDeclaration {
+                    start: script_offset + id.span.start as usize,
+                    end: script_offset + id.span.end as usize,
+                    wrapper,
+                    value_type: Self::infer_ref_value_type(call, script),
+                });
+            }
+        }
+        declarations
+    }
+
+    /// Build the type hint placed right after a ref declaration's name.
+    fn ref_type_hint(
+        decl: &RefDeclaration,
+        label: String,
+        target: &HintTarget<'_>,
+    ) -> Option<InlayHint> {
+        let (line, character) = offset_to_position(target.content, decl.end);
+        let position = Position { line, character };
+        if !Self::position_in_range(position, target.range) {
+            return None;
+        }
+
+        let data = RefTypeHintData {
+            uri: target.uri.clone(),
+            offset: decl.start,
+        };
+        Some(InlayHint {
+            position,
+            label: InlayHintLabel::String(label),
+            kind: Some(InlayHintKind::TYPE),
+            text_edits: None,
+            tooltip: None,
+            padding_left: None,
+            padding_right: None,
+            data: serde_json::to_value(data).ok(),
+        })
+    }
+
+    /// Infer the value type of a `ref()` call from its type argument or initial value.
+    fn infer_ref_value_type(call: &CallExpression<'_>, source: &str) -> Option<String> {
+        if let Some(type_args) = &call.type_arguments {
+            let param = type_args.params.first()?;
+            let span = param.span();
+            let ty = &source[span.start as usize..span.end as usize];
+            // `ref<T>()` without an initial value is `Ref<T | undefined>`
+            if call.arguments.is_empty() {
+                return Some(format!("{ty} | undefined"));
+            }
+            return Some(ty.to_string());
+        }
+        let arg = call.arguments.first()?.as_expression()?;
+        Self::infer_literal_type(arg)
+    }
+
+    /// Infer the type of a literal expression.
+    fn infer_literal_type(expr: &Expression<'_>) -> Option<String> {
+        match expr {
+            Expression::NumericLiteral(_) => Some("number".to_string()),
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => {
+                Some("string".to_string())
+            }
+            Expression::BooleanLiteral(_) => Some("boolean".to_string()),
+            Expression::NullLiteral(_) => Some("null".to_string()),
+            Expression::BigIntLiteral(_) => Some("bigint".to_string()),
+            Expression::UnaryExpression(unary)
+                if matches!(
+                    unary.operator,
+                    oxc_ast::ast::UnaryOperator::UnaryNegation
+                        | oxc_ast::ast::UnaryOperator::UnaryPlus
+                ) && matches!(unary.argument, Expression::NumericLiteral(_)) =>
+            {
+                Some("number".to_string())
+            }
+            Expression::ParenthesizedExpression(paren) => {
+                Self::infer_literal_type(&paren.expression)
+            }
+            Expression::ArrayExpression(array) => {
+                let mut types: Vec<String> = Vec::new();
+                for element in &array.elements {
+                    // Spreads and holes make the element type unknowable here
+                    if matches!(element, ArrayExpressionElement::SpreadElement(_)) {
+                        return None;
+                    }
+                    let ty = Self::infer_literal_type(element.as_expression()?)?;
+                    if !types.contains(&ty) {
+                        types.push(ty);
+                    }
+                }
+                match types.len() {
+                    0 => None,
+                    1 => Some(format!("{}[]", types[0])),
+                    _ => Some(format!("({})[]", types.join(" | "))),
+                }
+            }
+            _ => None,
+        }
+    }
+
     /// Collect inlay hints for props usages in script setup.
     fn collect_script_props_hints(
         script: &str,
@@ -777,4 +1046,107 @@ console.log(props.title)
             "Should have hints for props in template even without destructuring"
         );
     }
+
+    fn labels(hints: &[InlayHint]) -> Vec<String> {
+        hints
+            .iter()
+            .filter_map(|h| match &h.label {
+                InlayHintLabel::String(label) => Some(label.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ref_type_hint() {
+        let content = r#"<script setup lang="ts">
+import { ref, shallowRef } from 'vue'
+const count = ref(0)
+const name = ref<string>()
+const items = shallowRef([1, 'a'])
+const typed: Ref<number> = ref(1)
+const unknown = ref(load())
+</script>"#;
+
+        let uri = Url::parse("file:///test.vue").unwrap();
+        let range = Range {
+            start: Position {
+                line: 0,
+                character: 0,
+            },
+            end: Position {
+                line: 100,
+                character: 0,
+            },
+        };
+
+        let hints = InlayHintService::get_hints(content, &uri, range);
+
+        assert_eq!(
+            labels(&hints),
+            vec![
+                ": Ref<number>",
+                ": Ref<string | undefined>",
+                ": ShallowRef<(number | string)[]>"
+            ]
+        );
+        // Placed right after the variable name
+        assert_eq!(
+            hints[0].position,
+            Position {
+                line: 2,
+                character: 11
+            }
+        );
+        assert!(hints[0].data.is_some());
+    }
+
+    #[test]
+    fn test_ref_type_hint_disabled() {
+        let content = r#"<script setup lang="ts">
+import { ref } from 'vue'
+const count = ref(0)
+</script>"#;
+
+        let uri = Url::parse("file:///test.vue").unwrap();
+        let range = Range {
+            start: Position {
+                line: 0,
+                character: 0,
+            },
+            end: Position {
+                line: 100,
+                character: 0,
+            },
+        };
+        let config = InlayHintConfig {
+            ref_types: false,
+            ..Default::default()
+        };
+
+        let hints = InlayHintService::get_hints_with_config(content, &uri, range, config);
+
+        assert!(hints.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_ref_type_hint_without_hover() {
+        let hint = InlayHint {
+            position: Position {
+                line: 0,
+                character: 0,
+            },
+            label: InlayHintLabel::String(": Ref<number>".to_string()),
+            kind: Some(InlayHintKind::TYPE),
+            text_edits: None,
+            tooltip: None,
+            padding_left: None,
+            padding_right: None,
+            data: None,
+        };
+
+        let resolved = InlayHintService::resolve(hint, None);
+
+        assert!(resolved.tooltip.is_some());
+    }
 }
@@ -0,0 +1,170 @@
+//! Linked editing ranges provider.
+//!
+//! Keeps the opening and closing tag names of a template element in sync
+//! while either one is being edited.
+
+use tower_lsp::lsp_types::{LinkedEditingRanges, Position, Range};
+use vize_relief::ast::{ElementNode, TemplateChildNode};
+
+use super::IdeContext;
+
+/// Characters the client may treat as part of a linked tag name.
+pub const TAG_NAME_WORD_PATTERN: &str = r"[a-zA-Z][\w.:-]*";
+
+/// Linked editing service for template tag names.
+pub struct LinkedEditingService;
+
+impl LinkedEditingService {
+    /// Get linked editing ranges for the given context.
+    pub fn linked_editing_ranges(ctx: &IdeContext) -> Option<LinkedEditingRanges> {
+        if !ctx.is_in_template() {
+            return None;
+        }
+        Self::linked_editing_ranges_for_sfc(&ctx.content, ctx.uri.path(), ctx.offset)
+    }
+
+    /// Get linked editing ranges for an offset in SFC source.
+    pub fn linked_editing_ranges_for_sfc(
+        content: &str,
+        filename: &str,
+        offset: usize,
+    ) -> Option<LinkedEditingRanges> {
+        let options = vize_atelier_sfc::SfcParseOptions {
+            filename: filename.to_string(),
+            ..Default::default()
+        };
+        let descriptor = vize_atelier_sfc::parse_sfc(content, options).ok()?;
+
+        let template = descriptor.template.as_ref()?;
+        let template_start = template.loc.start;
+        if offset < template_start || offset > template.loc.end {
+            return None;
+        }
+
+        let allocator = vize_carton::Bump::new();
+        let (ast, _) = vize_armature::parse(&allocator, &template.content);
+
+        let name_ranges = Self::find_tag_name_ranges(&ast.children, offset - template_start)?;
+
+        let ranges = name_ranges
+            .into_iter()
+            .map(|(start, end)| {
+                let (start_line, start_char) =
+                    super::offset_to_position(content, template_start + start);
+                let (end_line, end_char) = super::offset_to_position(content, template_start + end);
+                Range {
+                    start: Position {
+                        line: start_line,
+                        character: start_char,
+                    },
+                    end: Position {
+                        line: end_line,
+                        character: end_char,
+                    },
+                }
+            })
+            .collect();
+
+        Some(LinkedEditingRanges {
+            ranges,
+            word_pattern: Some(TAG_NAME_WORD_PATTERN.to_string()),
+        })
+    }
+
+    /// Find the element whose tag name contains `offset` (relative to the template
+    /// content) and return its open and close tag name ranges.
+    fn find_tag_name_ranges(
+        children: &[TemplateChildNode<'_>],
+        offset: usize,
+    ) -> Option<Vec<(usize, usize)>> {
+        for child in children {
+            let TemplateChildNode::Element(el) = child else {
+                continue;
+            };
+
+            let start = el.loc.start.offset as usize;
+            let end = el.loc.end.offset as usize;
+            if offset < start || offset > end {
+                continue;
+            }
+
+            let open = Self::open_tag_name_range(el);
+            let close = Self::close_tag_name_range(el);
+
+            let contains = |(s, e): (usize, usize)| offset >= s && offset <= e;
+            if contains(open) || close.is_some_and(contains) {
+                let mut ranges = vec![open];
+                ranges.extend(close);
+                return Some(ranges);
+            }
+
+            return Self::find_tag_name_ranges(&el.children, offset);
+        }
+
+        None
+    }
+
+    /// Range of the tag name in `<tag ...>`.
+    #[inline]
+    fn open_tag_name_range(el: &ElementNode<'_>) -> (usize, usize) {
+        let start = el.loc.start.offset as usize + 1;
+        (start, start + el.tag.len())
+    }
+
+    /// Range of the tag name in `</tag>`, if the element has a closing tag.
+    ///
+    /// Self-closing and void elements have none.
+    fn close_tag_name_range(el: &ElementNode<'_>) -> Option<(usize, usize)> {
+        if el.is_self_closing {
+            return None;
+        }
+
+        let source = el.loc.source.as_str();
+        let close_start = source.len().checked_sub(el.tag.len() + 3)?;
+        let closing = &source[close_start..];
+        if !closing.starts_with("</") || !closing[2..].starts_with(el.tag.as_str()) {
+            return None;
+        }
+
+        let end = el.loc.end.offset as usize - 1;
+        Some((end - el.tag.len(), end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(source: &str) -> Option<Vec<Range>> {
+        let offset = source.find('|').unwrap();
+        let content = format!("{}{}", &source[..offset], &source[offset + 1..]);
+        LinkedEditingService::linked_editing_ranges_for_sfc(&content, "Test.vue", offset)
+            .map(|r| r.ranges)
+    }
+
+    #[test]
+    fn test_self_closing_returns_single_range() {
+        let ranges = ranges("<template>\n  <My|Comp />\n</template>\n").unwrap();
+        assert_eq!(ranges.len(), 1);
+    }
+
+    #[test]
+    fn test_void_element_returns_single_range() {
+        let ranges = ranges("<template>\n  <div><i|nput></div>\n</template>\n").unwrap();
+        assert_eq!(ranges.len(), 1);
+    }
+
+    #[test]
+    fn test_outside_tag_name_returns_none() {
+        assert!(ranges("<template>\n  <div class=\"a|\"></div>\n</template>\n").is_none());
+        assert!(ranges("<template>\n  <div>te|xt</div>\n</template>\n").is_none());
+    }
+
+    #[test]
+    fn test_cursor_in_close_tag() {
+        let ranges = ranges("<template>\n  <span>x</sp|an>\n</template>\n").unwrap();
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[0].start, Position::new(1, 3));
+        assert_eq!(ranges[1].start, Position::new(1, 11));
+    }
+}
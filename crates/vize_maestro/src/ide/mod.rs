@@ -6,38 +6,66 @@
 //! - Code completion provider
 //! - Go to definition
 //! - Find references
+//! - Call hierarchy
+//! - Document highlights
 //! - Code actions (quick fixes)
 //! - Type checking and type information
 //! - Rename refactoring
+//! - Document formatting
+//! - Linked editing of tag names
+//! - On-type formatting of template markup
+//! - Selection ranges (expand selection)
 //! - Semantic tokens
+//! - Signature help
 //! - Code lens
+//! - Document colors in style blocks
 //! - Workspace symbols
 
+pub mod call_hierarchy;
 pub mod code_action;
 pub mod code_lens;
+pub mod color;
 pub mod completion;
 pub mod definition;
 pub mod diagnostics;
+pub mod document_highlight;
 pub mod document_link;
+#[cfg(feature = "glyph")]
+pub mod formatting;
 pub mod hover;
 pub mod inlay_hint;
+pub mod linked_editing;
+#[cfg(feature = "glyph")]
+pub mod on_type_formatting;
 pub mod references;
 pub mod rename;
+pub mod selection_range;
 pub mod semantic_tokens;
+pub mod signature_help;
 pub mod type_service;
 pub mod workspace_symbols;
 
+pub use call_hierarchy::CallHierarchyService;
 pub use code_action::CodeActionService;
 pub use code_lens::CodeLensService;
-pub use completion::{trigger_characters, CompletionService, TRIGGER_CHARACTERS};
+pub use color::ColorService;
+pub use completion::{trigger_characters, AutoImportData, CompletionService, TRIGGER_CHARACTERS};
 pub use definition::{BindingKind, BindingLocation, DefinitionService};
 pub use diagnostics::{sources, DiagnosticBuilder, DiagnosticService, Severity};
+pub use document_highlight::DocumentHighlightService;
 pub use document_link::DocumentLinkService;
+#[cfg(feature = "glyph")]
+pub use formatting::FormattingService;
 pub use hover::{HoverBuilder, HoverService};
-pub use inlay_hint::InlayHintService;
+pub use inlay_hint::{InlayHintConfig, InlayHintService, RefTypeHintData};
+pub use linked_editing::{LinkedEditingService, TAG_NAME_WORD_PATTERN};
+#[cfg(feature = "glyph")]
+pub use on_type_formatting::{OnTypeFormattingService, ON_TYPE_FORMATTING_TRIGGERS};
 pub use references::ReferencesService;
 pub use rename::RenameService;
+pub use selection_range::SelectionRangeService;
 pub use semantic_tokens::{SemanticTokensService, TokenModifier, TokenType};
+pub use signature_help::{CallContext, SignatureHelpService};
 pub use type_service::{LspTypeCheckOptions, TypeService};
 pub use workspace_symbols::WorkspaceSymbolsService;
 
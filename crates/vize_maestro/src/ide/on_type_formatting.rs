@@ -0,0 +1,250 @@
+//! On-type formatting provider.
+//!
+//! Reindents template markup while typing: a `>` that closes an element
+//! reformats just that element with `vize_glyph`, and a newline indents the
+//! new line from the one above it.
+
+use tower_lsp::lsp_types::{Position, Range, TextEdit};
+use vize_relief::ast::TemplateChildNode;
+
+/// Characters that trigger on-type formatting.
+pub const ON_TYPE_FORMATTING_TRIGGERS: &[&str] = &[">", "\n"];
+
+/// On-type formatting service for template blocks.
+pub struct OnTypeFormattingService;
+
+impl OnTypeFormattingService {
+    /// Get the edits for a character typed at `offset` (just after `ch`).
+    ///
+    /// `options` should already carry the client's indentation settings
+    /// (see [`FormattingService::format_options`](super::FormattingService::format_options)).
+    ///
+    /// Returns `None` outside the template block or when the edit cannot be
+    /// formatted in isolation.
+    pub fn format_on_type(
+        content: &str,
+        offset: usize,
+        ch: &str,
+        options: &vize_glyph::FormatOptions,
+    ) -> Option<Vec<TextEdit>> {
+        let sfc_options = vize_atelier_sfc::SfcParseOptions::default();
+        let descriptor = vize_atelier_sfc::parse_sfc(content, sfc_options).ok()?;
+        let template = descriptor.template.as_ref()?;
+        if offset < template.loc.start || offset > template.loc.end {
+            return None;
+        }
+
+        match ch {
+            ">" => {
+                let allocator = vize_carton::Bump::new();
+                let (ast, _) = vize_armature::parse(&allocator, &template.content);
+                let (start, end) =
+                    Self::find_closed_element(&ast.children, offset - template.loc.start)?;
+                Self::format_element(
+                    content,
+                    template.loc.start + start,
+                    template.loc.start + end,
+                    options,
+                )
+            }
+            "\n" => Self::indent_line(content, offset, options),
+            _ => None,
+        }
+    }
+
+    /// Find the innermost element whose closing `>` ends at `offset`
+    /// (relative to the template content).
+    fn find_closed_element(
+        children: &[TemplateChildNode<'_>],
+        offset: usize,
+    ) -> Option<(usize, usize)> {
+        for child in children {
+            let TemplateChildNode::Element(el) = child else {
+                continue;
+            };
+
+            let start = el.loc.start.offset as usize;
+            let end = el.loc.end.offset as usize;
+            if offset <= start || offset > end {
+                continue;
+            }
+            if end == offset {
+                return Some((start, end));
+            }
+            return Self::find_closed_element(&el.children, offset);
+        }
+
+        None
+    }
+
+    /// Reformat the element at `start..end`, keeping the indentation of its
+    /// first line.
+    fn format_element(
+        content: &str,
+        start: usize,
+        end: usize,
+        options: &vize_glyph::FormatOptions,
+    ) -> Option<Vec<TextEdit>> {
+        let line_start = content[..start].rfind('\n').map_or(0, |i| i + 1);
+        let base_indent = &content[line_start..start];
+        if !base_indent.chars().all(|c| c == ' ' || c == '\t') {
+            // Only whole-line elements can be reindented
+            return None;
+        }
+
+        let formatted = vize_glyph::format_template(&content[start..end], options).ok()?;
+        let new_text = formatted
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    String::new()
+                } else {
+                    [base_indent, line].concat()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if new_text == content[line_start..end] {
+            return Some(vec![]);
+        }
+
+        Some(vec![TextEdit {
+            range: Self::range(content, line_start, end),
+            new_text,
+        }])
+    }
+
+    /// Indent the line at `offset` relative to the previous non-blank line.
+    fn indent_line(
+        content: &str,
+        offset: usize,
+        options: &vize_glyph::FormatOptions,
+    ) -> Option<Vec<TextEdit>> {
+        let line_start = content[..offset].rfind('\n')? + 1;
+        let line = content[line_start..].lines().next().unwrap_or("");
+        let current_indent = line.len() - line.trim_start().len();
+
+        let previous = content[..line_start - 1]
+            .lines()
+            .rev()
+            .find(|l| !l.trim().is_empty())?;
+        let previous_indent = &previous[..previous.len() - previous.trim_start().len()];
+
+        let mut indent = previous_indent.to_string();
+        if opens_element(previous.trim()) && !line.trim_start().starts_with("</") {
+            indent.push_str(&options.indent_string());
+        }
+
+        if line[..current_indent] == indent {
+            return Some(vec![]);
+        }
+
+        Some(vec![TextEdit {
+            range: Self::range(content, line_start, line_start + current_indent),
+            new_text: indent,
+        }])
+    }
+
+    fn range(content: &str, start: usize, end: usize) -> Range {
+        let (start_line, start_char) = super::offset_to_position(content, start);
+        let (end_line, end_char) = super::offset_to_position(content, end);
+        Range {
+            start: Position::new(start_line, start_char),
+            end: Position::new(end_line, end_char),
+        }
+    }
+}
+
+/// Whether a (trimmed) line ends with an opening tag left open, so the next
+/// line belongs inside it.
+fn opens_element(line: &str) -> bool {
+    let Some((_, tag)) = line.rsplit_once('<') else {
+        return false;
+    };
+    if !line.ends_with('>') || line.ends_with("/>") {
+        return false;
+    }
+    if tag.starts_with('/') || tag.starts_with('!') {
+        return false;
+    }
+
+    let name_end = tag
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(tag.len());
+    let name = &tag[..name_end];
+    !name.is_empty() && !vize_carton::is_void_tag(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ide::FormattingService;
+    use tower_lsp::lsp_types::FormattingOptions;
+
+    fn edits_with(source: &str, ch: &str, client: FormattingOptions) -> Option<Vec<TextEdit>> {
+        let offset = source.find('|').unwrap();
+        let content = format!("{}{}", &source[..offset], &source[offset + 1..]);
+        let options =
+            FormattingService::format_options(&vize_glyph::FormatOptions::default(), &client);
+        OnTypeFormattingService::format_on_type(&content, offset, ch, &options)
+    }
+
+    fn edits(source: &str, ch: &str) -> Option<Vec<TextEdit>> {
+        let client = FormattingOptions {
+            tab_size: 2,
+            insert_spaces: true,
+            ..Default::default()
+        };
+        edits_with(source, ch, client)
+    }
+
+    #[test]
+    fn test_closing_tag_reindents_element() {
+        let edits = edits("<template>\n  <p>\n  Hello\n  </p>|\n</template>\n", ">").unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.start, Position::new(1, 0));
+        assert_eq!(edits[0].range.end, Position::new(3, 6));
+        assert_eq!(edits[0].new_text, "  <p>\n    Hello\n  </p>");
+    }
+
+    #[test]
+    fn test_formatted_element_has_no_edits() {
+        let edits = edits("<template>\n  <p>\n    Hello\n  </p>|\n</template>\n", ">").unwrap();
+        assert!(edits.is_empty());
+    }
+
+    #[test]
+    fn test_newline_after_open_tag_indents() {
+        let edits = edits("<template>\n  <div>\n|\n</template>\n", "\n").unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "    ");
+    }
+
+    #[test]
+    fn test_newline_respects_tabs() {
+        let client = FormattingOptions {
+            tab_size: 4,
+            insert_spaces: false,
+            ..Default::default()
+        };
+        let edits = edits_with("<template>\n\t<div>\n|\n</template>\n", "\n", client).unwrap();
+        assert_eq!(edits[0].new_text, "\t\t");
+    }
+
+    #[test]
+    fn test_outside_template() {
+        assert!(edits("<script>\nconst a = 1 >|\n</script>\n", ">").is_none());
+    }
+
+    #[test]
+    fn test_opens_element() {
+        assert!(opens_element("<div>"));
+        assert!(opens_element(r#"<div class="a">"#));
+        assert!(!opens_element("<div></div>"));
+        assert!(!opens_element("<br>"));
+        assert!(!opens_element("<span />"));
+        assert!(!opens_element("</div>"));
+        assert!(!opens_element("<!-- note -->"));
+    }
+}
@@ -341,7 +341,9 @@ impl ReferencesService {
     }
 
     /// Get expression text and offset from ExpressionNode.
-    fn get_expression_info(expr: &vize_relief::ast::ExpressionNode) -> Option<(String, usize)> {
+    pub(crate) fn get_expression_info(
+        expr: &vize_relief::ast::ExpressionNode,
+    ) -> Option<(String, usize)> {
         use vize_relief::ast::*;
 
         match expr {
@@ -361,7 +363,7 @@ impl ReferencesService {
     }
 
     /// Find all occurrences of a word in a string.
-    fn find_word_occurrences(text: &str, word: &str) -> Vec<usize> {
+    pub(crate) fn find_word_occurrences(text: &str, word: &str) -> Vec<usize> {
         let mut positions = Vec::new();
         let mut start = 0;
 
@@ -539,7 +541,7 @@ impl ReferencesService {
     }
 
     /// Get the word at an offset.
-    fn get_word_at_offset(content: &str, offset: usize) -> Option<String> {
+    pub(crate) fn get_word_at_offset(content: &str, offset: usize) -> Option<String> {
         if offset >= content.len() {
             return None;
         }
@@ -4,6 +4,7 @@
 //! - Template bindings (variables, functions, etc.)
 //! - Script identifiers
 //! - CSS variables in v-bind()
+//! - Components, emitted events and provide keys across workspace files
 
 use std::collections::HashMap;
 
@@ -11,12 +12,21 @@ use tower_lsp::lsp_types::{Position, PrepareRenameResponse, Range, TextEdit, Wor
 
 use super::IdeContext;
 
+mod workspace;
+
+pub use workspace::WorkspaceIndex;
+
 /// Rename service for identifier renaming across SFC.
 pub struct RenameService;
 
 impl RenameService {
     /// Check if rename is valid at the given position.
     pub fn prepare_rename(ctx: &IdeContext) -> Option<PrepareRenameResponse> {
+        // Kebab-case tags, event names and provide keys are not plain identifiers
+        if let Some(response) = Self::prepare_rename_workspace(ctx) {
+            return Some(response);
+        }
+
         let word = Self::get_word_at_offset(&ctx.content, ctx.offset)?;
 
         if word.is_empty() {
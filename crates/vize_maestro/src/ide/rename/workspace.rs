@@ -0,0 +1,919 @@
+//! Workspace-wide rename of cross-file symbols.
+//!
+//! Components, emitted events and provide/inject keys are referenced from
+//! other `.vue` files. The symbol under the cursor is classified from the
+//! current document alone; only then are related files found through the
+//! croquis cross-file dependency graph, which the server caches between
+//! requests:
+//! - Component: import bindings and template tags in every file using it
+//! - Event: `defineEmits`/`emit()` strings in the component and `$emit()`
+//!   in its template, `@event` listeners on its tags in parent files
+//! - Provide key: string keys of matching `provide()`/`inject()` calls
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Argument, CallExpression, Expression, StringLiteral};
+use oxc_ast_visit::{walk, Visit};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use tower_lsp::lsp_types::{PrepareRenameResponse, TextEdit, Url, WorkspaceEdit};
+use vize_carton::FxHashMap;
+use vize_croquis::cross_file::{CrossFileAnalyzer, CrossFileOptions, FileId};
+use vize_croquis::provide::ProvideKey;
+use vize_croquis::{Analyzer, AnalyzerOptions, Croquis, ScopeData};
+use vize_relief::ast::{ElementNode, ExpressionNode, PropNode, TemplateChildNode};
+
+use super::RenameService;
+use crate::ide::{is_component_tag, kebab_to_pascal, pascal_to_kebab, IdeContext};
+use crate::server::ServerState;
+
+/// A symbol referenced from several files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CrossFileSymbol {
+    /// Component, by PascalCase name
+    Component(String),
+    /// Event emitted by a component
+    Event { component: String, name: String },
+    /// String key of `provide()`/`inject()`
+    ProvideKey(String),
+}
+
+/// Script block of a file.
+struct ScriptBlock {
+    /// Start offset in the file
+    start: usize,
+    content: String,
+    source_type: SourceType,
+}
+
+/// Blocks and single-file analysis of an SFC.
+struct ParsedFile {
+    analysis: Croquis,
+    script: Option<ScriptBlock>,
+    /// Template block (start offset, content)
+    template: Option<(usize, String)>,
+}
+
+/// A workspace file registered in the cross-file analyzer.
+struct SourceFile {
+    uri: Url,
+    content: String,
+    id: FileId,
+    script: Option<ScriptBlock>,
+    template: Option<(usize, String)>,
+}
+
+/// Cross-file index of the workspace `.vue` files.
+///
+/// Built on the first cross-file rename and cached on the server until a
+/// document changes.
+pub struct WorkspaceIndex {
+    analyzer: CrossFileAnalyzer,
+    files: Vec<SourceFile>,
+}
+
+/// Text edit as (start, end, new text) offsets in a file.
+type OffsetEdit = (usize, usize, String);
+
+impl RenameService {
+    /// Range of the component, event or provide key under the cursor.
+    ///
+    /// Returns `None` when the symbol is local to the document.
+    pub fn prepare_rename_workspace(ctx: &IdeContext) -> Option<PrepareRenameResponse> {
+        let (start, end) = Self::symbol_range_in_document(ctx.uri, &ctx.content, ctx.offset)?;
+        let range = Self::offset_range_to_lsp(&ctx.content, start, end);
+        Some(PrepareRenameResponse::Range(range))
+    }
+
+    /// Rename a component, event or provide key across the workspace.
+    ///
+    /// Returns `Ok(None)` when the symbol under the cursor is local to the
+    /// document, and an error when `new_name` is already taken.
+    pub fn rename_workspace(
+        ctx: &IdeContext,
+        new_name: &str,
+    ) -> Result<Option<WorkspaceEdit>, String> {
+        let Some((symbol, _, _)) = Self::classify_symbol(ctx.uri, &ctx.content, ctx.offset) else {
+            return Ok(None);
+        };
+        let index = ctx
+            .state
+            .workspace_index(|| WorkspaceIndex::load(&Self::workspace_files(ctx)));
+        index.rename(&symbol, new_name)
+    }
+
+    /// The current document and every other `.vue` file in the workspace.
+    fn workspace_files(ctx: &IdeContext) -> Vec<(Url, String)> {
+        Self::workspace_files_with(ctx.state, ctx.uri, &ctx.content)
+    }
+
+    /// Every workspace `.vue` file, with `content` for the document at `uri`.
+    pub(crate) fn workspace_files_with(
+        state: &ServerState,
+        uri: &Url,
+        content: &str,
+    ) -> Vec<(Url, String)> {
+        let mut files = vec![(uri.clone(), content.to_string())];
+        for path in state.workspace_vue_files().iter() {
+            let Ok(file_uri) = Url::from_file_path(path) else {
+                continue;
+            };
+            if file_uri == *uri {
+                continue;
+            }
+            // Prefer unsaved editor contents over the file on disk
+            let content = match state.documents.get(&file_uri) {
+                Some(doc) => doc.text(),
+                None => match std::fs::read_to_string(path) {
+                    Ok(content) => content,
+                    Err(_) => continue,
+                },
+            };
+            files.push((file_uri, content));
+        }
+        files
+    }
+
+    /// Range of the cross-file symbol at `offset` in a document.
+    pub fn symbol_range_in_document(
+        uri: &Url,
+        content: &str,
+        offset: usize,
+    ) -> Option<(usize, usize)> {
+        let (_, start, end) = Self::classify_symbol(uri, content, offset)?;
+        Some((start, end))
+    }
+
+    /// Rename the cross-file symbol at `offset` in `uri`, given all workspace files.
+    pub fn rename_across_files(
+        files: &[(Url, String)],
+        uri: &Url,
+        offset: usize,
+        new_name: &str,
+    ) -> Result<Option<WorkspaceEdit>, String> {
+        let Some((_, content)) = files.iter().find(|(file_uri, _)| file_uri == uri) else {
+            return Ok(None);
+        };
+        let Some((symbol, _, _)) = Self::classify_symbol(uri, content, offset) else {
+            return Ok(None);
+        };
+        WorkspaceIndex::load(files).rename(&symbol, new_name)
+    }
+
+    /// Classify the cross-file symbol at `offset` from the document alone.
+    fn classify_symbol(
+        uri: &Url,
+        content: &str,
+        offset: usize,
+    ) -> Option<(CrossFileSymbol, usize, usize)> {
+        let path = uri.to_file_path().ok()?;
+        let file = ParsedFile::parse(&path, content)?;
+        // Components are named after their file, as in the cross-file registry
+        let component = path.file_stem().map(|stem| stem.to_string_lossy());
+        file.symbol_at(component.as_deref(), offset)
+    }
+}
+
+impl ParsedFile {
+    fn parse(path: &Path, content: &str) -> Option<Self> {
+        let options = vize_atelier_sfc::SfcParseOptions {
+            filename: path.to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        let descriptor = vize_atelier_sfc::parse_sfc(content, options).ok()?;
+
+        let mut single = Analyzer::with_options(AnalyzerOptions::full());
+        let script = if let Some(ref script_setup) = descriptor.script_setup {
+            single.analyze_script_setup(&script_setup.content);
+            Some(script_setup)
+        } else if let Some(ref script) = descriptor.script {
+            single.analyze_script_plain(&script.content);
+            Some(script)
+        } else {
+            None
+        };
+        let script = script.map(|script| ScriptBlock {
+            start: script.loc.start,
+            content: script.content.to_string(),
+            source_type: match script.lang.as_deref() {
+                Some("ts") => SourceType::ts(),
+                Some("tsx") => SourceType::tsx(),
+                Some("jsx") => SourceType::jsx(),
+                _ => SourceType::mjs(),
+            },
+        });
+        let template = descriptor
+            .template
+            .as_ref()
+            .map(|template| (template.loc.start, template.content.to_string()));
+        if let Some((_, ref template_content)) = template {
+            let allocator = vize_carton::Bump::new();
+            let (root, _) = vize_armature::parse(&allocator, template_content);
+            single.analyze_template(&root);
+        }
+
+        Some(Self {
+            analysis: single.finish(),
+            script,
+            template,
+        })
+    }
+
+    /// Resolve the cross-file symbol at an offset, with its range in the file.
+    ///
+    /// `component` is the name of the component the file defines.
+    fn symbol_at(
+        &self,
+        component: Option<&str>,
+        offset: usize,
+    ) -> Option<(CrossFileSymbol, usize, usize)> {
+        if let Some((start, ref content)) = self.template {
+            if (start..=start + content.len()).contains(&offset) {
+                let (symbol, symbol_start, symbol_end) =
+                    template_symbol_at(content, component, offset - start)?;
+                return Some((symbol, start + symbol_start, start + symbol_end));
+            }
+        }
+
+        let script = self.script.as_ref()?;
+        let (start, content) = (script.start, script.content.as_str());
+        if !(start..=start + content.len()).contains(&offset) {
+            return None;
+        }
+        let local = offset - start;
+        let analysis = &self.analysis;
+
+        // String keys of provide()/inject()
+        let literals = string_literals(content, script.source_type);
+        if let Some((key_start, key_end, key)) = literals
+            .into_iter()
+            .find(|&(lit_start, lit_end, _)| (lit_start..=lit_end).contains(&local))
+        {
+            let tracker = &analysis.provide_inject;
+            let calls = tracker
+                .provides()
+                .iter()
+                .map(|p| (&p.key, p.start, p.end))
+                .chain(tracker.injects().iter().map(|i| (&i.key, i.start, i.end)));
+            for (provide_key, call_start, call_end) in calls {
+                if matches!(provide_key, ProvideKey::String(k) if k.as_str() == key)
+                    && (call_start as usize..call_end as usize).contains(&local)
+                {
+                    return Some((
+                        CrossFileSymbol::ProvideKey(key),
+                        start + key_start,
+                        start + key_end,
+                    ));
+                }
+            }
+
+            // Event names in defineEmits() or emit()
+            let in_emits = emit_call_ranges(analysis)
+                .any(|(call_start, call_end)| (call_start..call_end).contains(&local));
+            if in_emits {
+                return Some((
+                    CrossFileSymbol::Event {
+                        component: component?.to_string(),
+                        name: key,
+                    },
+                    start + key_start,
+                    start + key_end,
+                ));
+            }
+            return None;
+        }
+
+        // Import binding of another component
+        let (word_start, word_end) = RenameService::get_word_range(content, local)?;
+        let word = &content[word_start..word_end];
+        let imports_component = analysis.scopes.iter().any(|scope| {
+            scope.has_binding(word)
+                && matches!(
+                    scope.data(),
+                    ScopeData::ExternalModule(data) if is_component_module(&data.source, word)
+                )
+        });
+        imports_component.then(|| {
+            (
+                CrossFileSymbol::Component(word.to_string()),
+                start + word_start,
+                start + word_end,
+            )
+        })
+    }
+}
+
+impl WorkspaceIndex {
+    /// Parse and link every workspace file.
+    pub fn load(files: &[(Url, String)]) -> Self {
+        let mut analyzer = CrossFileAnalyzer::new(CrossFileOptions::minimal());
+
+        // Register every file before linking so imports resolve in any order
+        let mut paths = Vec::with_capacity(files.len());
+        for (uri, content) in files {
+            let Ok(path) = uri.to_file_path() else {
+                continue;
+            };
+            analyzer.add_file_with_analysis(&path, content, Croquis::default());
+            paths.push((uri, content, path));
+        }
+
+        let mut parsed = Vec::with_capacity(paths.len());
+        for (uri, content, path) in paths {
+            let Some(file) = ParsedFile::parse(&path, content) else {
+                continue;
+            };
+            let id = analyzer.add_file_with_analysis(&path, content, file.analysis);
+            parsed.push(SourceFile {
+                uri: uri.clone(),
+                content: content.clone(),
+                id,
+                script: file.script,
+                template: file.template,
+            });
+        }
+        analyzer.rebuild_component_edges();
+
+        Self {
+            analyzer,
+            files: parsed,
+        }
+    }
+
+    /// Keys each workspace component exposes via defineExpose(), by component name.
+    pub fn component_exposes(&self) -> FxHashMap<String, Vec<String>> {
+        self.analyzer.registry().component_exposes()
+    }
+
+    /// Rename `symbol` in every file referencing it.
+    fn rename(
+        &self,
+        symbol: &CrossFileSymbol,
+        new_name: &str,
+    ) -> Result<Option<WorkspaceEdit>, String> {
+        self.check_conflict(symbol, new_name)?;
+
+        let mut changes = HashMap::new();
+        for file in &self.files {
+            let mut edits = self.edits_in(file, symbol, new_name);
+            if edits.is_empty() {
+                continue;
+            }
+            edits.sort();
+            edits.dedup();
+            let text_edits = edits
+                .into_iter()
+                .map(|(start, end, new_text)| TextEdit {
+                    range: RenameService::offset_range_to_lsp(&file.content, start, end),
+                    new_text,
+                })
+                .collect();
+            changes.insert(file.uri.clone(), text_edits);
+        }
+
+        if changes.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }))
+    }
+
+    /// Files importing or using the component defined by `target`.
+    fn dependents(&self, target: FileId) -> Vec<FileId> {
+        self.analyzer
+            .graph()
+            .dependents(target)
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Reject renames that would collide with an existing symbol.
+    fn check_conflict(&self, symbol: &CrossFileSymbol, new_name: &str) -> Result<(), String> {
+        match symbol {
+            CrossFileSymbol::Component(name) => {
+                if !RenameService::is_valid_identifier(new_name) {
+                    return Err(format!("`{}` is not a valid component name", new_name));
+                }
+                if self.analyzer.graph().find_by_component(new_name).is_some() {
+                    return Err(format!("A component named `{}` already exists", new_name));
+                }
+                let Some(target) = self.analyzer.graph().find_by_component(name) else {
+                    return Ok(());
+                };
+                for id in self.dependents(target) {
+                    let taken = self
+                        .analyzer
+                        .get_analysis(id)
+                        .is_some_and(|analysis| analysis.bindings.contains(new_name));
+                    if taken {
+                        let path = self.analyzer.get_file_path(id);
+                        return Err(format!(
+                            "`{}` is already defined in {}",
+                            new_name,
+                            path.map(|p| p.display().to_string()).unwrap_or_default()
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            CrossFileSymbol::Event { component, .. } => {
+                if new_name.is_empty()
+                    || new_name.contains(|c: char| c.is_whitespace() || c == '"' || c == '\'')
+                {
+                    return Err(format!("`{}` is not a valid event name", new_name));
+                }
+                let emits_new_name = self
+                    .analyzer
+                    .graph()
+                    .find_by_component(component)
+                    .and_then(|id| self.analyzer.get_analysis(id))
+                    .is_some_and(|analysis| {
+                        analysis
+                            .macros
+                            .emits()
+                            .iter()
+                            .any(|emit| emit.name == new_name)
+                    });
+                if emits_new_name {
+                    return Err(format!(
+                        "`{}` already emits an event named `{}`",
+                        component, new_name
+                    ));
+                }
+                Ok(())
+            }
+            CrossFileSymbol::ProvideKey(_) => {
+                if new_name.is_empty() || new_name.contains(['"', '\'', '`']) {
+                    return Err(format!("`{}` is not a valid provide key", new_name));
+                }
+                let provided = self.files.iter().any(|file| {
+                    self.analyzer.get_analysis(file.id).is_some_and(|analysis| {
+                        analysis
+                            .provide_inject
+                            .provides()
+                            .iter()
+                            .any(|p| matches!(&p.key, ProvideKey::String(k) if k == new_name))
+                    })
+                });
+                if provided {
+                    return Err(format!("The key `{}` is already provided", new_name));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Collect the edits renaming `symbol` in one file.
+    fn edits_in(
+        &self,
+        file: &SourceFile,
+        symbol: &CrossFileSymbol,
+        new_name: &str,
+    ) -> Vec<OffsetEdit> {
+        let mut edits = Vec::new();
+        let Some(analysis) = self.analyzer.get_analysis(file.id) else {
+            return edits;
+        };
+
+        match symbol {
+            CrossFileSymbol::Component(name) => {
+                let Some(target) = self.analyzer.graph().find_by_component(name) else {
+                    return edits;
+                };
+                if !self.dependents(target).contains(&file.id) {
+                    return edits;
+                }
+
+                if let Some(ref script) = file.script {
+                    let literals = string_literals(&script.content, script.source_type);
+                    for (offset, len) in
+                        RenameService::find_identifier_occurrences(&script.content, name)
+                    {
+                        // Module specifiers such as './MyButton.vue' keep the file name
+                        if literals
+                            .iter()
+                            .any(|&(start, end, _)| (start..=end).contains(&offset))
+                        {
+                            continue;
+                        }
+                        let start = script.start + offset;
+                        edits.push((start, start + len, new_name.to_string()));
+                    }
+                }
+
+                let kebab = pascal_to_kebab(name);
+                if let Some((start, ref content)) = file.template {
+                    let allocator = vize_carton::Bump::new();
+                    let (root, _) = vize_armature::parse(&allocator, content);
+                    for_each_element(&root.children, &mut |el| {
+                        let new_text = if el.tag.as_str() == name.as_str() {
+                            new_name.to_string()
+                        } else if el.tag.as_str() == kebab.as_str() {
+                            pascal_to_kebab(new_name)
+                        } else {
+                            return;
+                        };
+                        for (tag_start, tag_end) in tag_ranges(el) {
+                            edits.push((start + tag_start, start + tag_end, new_text.clone()));
+                        }
+                    });
+                }
+            }
+            CrossFileSymbol::Event { component, name } => {
+                let Some(target) = self.analyzer.graph().find_by_component(component) else {
+                    return edits;
+                };
+
+                if file.id == target {
+                    if let Some(ref script) = file.script {
+                        let literals = string_literals(&script.content, script.source_type);
+                        for (call_start, call_end) in emit_call_ranges(analysis) {
+                            for &(key_start, key_end, ref value) in &literals {
+                                if value == name && call_start <= key_start && key_end <= call_end {
+                                    edits.push((
+                                        script.start + key_start,
+                                        script.start + key_end,
+                                        new_name.to_string(),
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                    if let Some((start, ref content)) = file.template {
+                        for (key_start, key_end, value) in template_emits(content) {
+                            if value == *name {
+                                edits.push((
+                                    start + key_start,
+                                    start + key_end,
+                                    new_name.to_string(),
+                                ));
+                            }
+                        }
+                    }
+                } else if self.dependents(target).contains(&file.id) {
+                    let kebab = pascal_to_kebab(component);
+                    if let Some((start, ref content)) = file.template {
+                        let allocator = vize_carton::Bump::new();
+                        let (root, _) = vize_armature::parse(&allocator, content);
+                        for_each_element(&root.children, &mut |el| {
+                            if el.tag.as_str() != component.as_str()
+                                && el.tag.as_str() != kebab.as_str()
+                            {
+                                return;
+                            }
+                            for (event, arg_start, arg_end) in listeners(el) {
+                                if event == name.as_str() {
+                                    edits.push((
+                                        start + arg_start,
+                                        start + arg_end,
+                                        new_name.to_string(),
+                                    ));
+                                }
+                            }
+                        });
+                    }
+                }
+            }
+            CrossFileSymbol::ProvideKey(key) => {
+                let Some(ref script) = file.script else {
+                    return edits;
+                };
+                let literals = string_literals(&script.content, script.source_type);
+                let tracker = &analysis.provide_inject;
+                let calls = tracker
+                    .provides()
+                    .iter()
+                    .map(|p| (&p.key, p.start, p.end))
+                    .chain(tracker.injects().iter().map(|i| (&i.key, i.start, i.end)));
+                for (provide_key, call_start, call_end) in calls {
+                    if !matches!(provide_key, ProvideKey::String(k) if k == key) {
+                        continue;
+                    }
+                    let (call_start, call_end) = (call_start as usize, call_end as usize);
+                    // The key is the first argument
+                    if let Some(&(key_start, key_end, _)) =
+                        literals.iter().find(|(start, end, value)| {
+                            value == key && call_start <= *start && *end <= call_end
+                        })
+                    {
+                        edits.push((
+                            script.start + key_start,
+                            script.start + key_end,
+                            new_name.to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        edits
+    }
+}
+
+/// Visit every element in a template, including those under `v-if`/`v-for`.
+fn for_each_element<'a>(children: &[TemplateChildNode<'a>], f: &mut impl FnMut(&ElementNode<'a>)) {
+    for child in children {
+        match child {
+            TemplateChildNode::Element(el) => {
+                f(el);
+                for_each_element(&el.children, f);
+            }
+            TemplateChildNode::If(if_node) => {
+                for branch in &if_node.branches {
+                    for_each_element(&branch.children, f);
+                }
+            }
+            TemplateChildNode::For(for_node) => for_each_element(&for_node.children, f),
+            _ => {}
+        }
+    }
+}
+
+/// Ranges of the tag name in the opening and closing tags of an element.
+fn tag_ranges(el: &ElementNode<'_>) -> Vec<(usize, usize)> {
+    let start = el.loc.start.offset as usize;
+    let mut ranges = vec![(start + 1, start + 1 + el.tag.len())];
+
+    if !el.is_self_closing {
+        if let Some(close) = el.loc.source.rfind("</") {
+            if el.loc.source[close + 2..].starts_with(el.tag.as_str()) {
+                let close_start = start + close + 2;
+                ranges.push((close_start, close_start + el.tag.len()));
+            }
+        }
+    }
+    ranges
+}
+
+/// Static `@event` / `v-on:event` listeners of an element with the event name range.
+fn listeners<'e>(el: &'e ElementNode<'_>) -> Vec<(&'e str, usize, usize)> {
+    el.props
+        .iter()
+        .filter_map(|prop| match prop {
+            PropNode::Directive(dir) if dir.name == "on" => match &dir.arg {
+                Some(ExpressionNode::Simple(arg)) if arg.is_static => Some((
+                    arg.content.as_str(),
+                    arg.loc.start.offset as usize,
+                    arg.loc.end.offset as usize,
+                )),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// `defineEmits()` and `emit()` call ranges in a script.
+fn emit_call_ranges(analysis: &Croquis) -> impl Iterator<Item = (usize, usize)> + '_ {
+    analysis
+        .macros
+        .define_emits()
+        .map(|call| (call.start, call.end))
+        .into_iter()
+        .chain(
+            analysis
+                .macros
+                .emit_calls()
+                .iter()
+                .map(|call| (call.start, call.end)),
+        )
+        .map(|(start, end)| (start as usize, end as usize))
+}
+
+/// Whether an import from `source` is the component `name` defined by a `.vue` file.
+fn is_component_module(source: &str, name: &str) -> bool {
+    source
+        .strip_suffix(".vue")
+        .is_some_and(|path| path.rsplit('/').next() == Some(name))
+}
+
+/// Resolve a component tag, a listener on a component or an event name in
+/// `$emit()` in a template, with its range in the template.
+///
+/// `component` is the name of the component the template belongs to.
+fn template_symbol_at(
+    template: &str,
+    component: Option<&str>,
+    offset: usize,
+) -> Option<(CrossFileSymbol, usize, usize)> {
+    let allocator = vize_carton::Bump::new();
+    let (root, _) = vize_armature::parse(&allocator, template);
+
+    let mut symbol = None;
+    for_each_element(&root.children, &mut |el| {
+        if symbol.is_some() || !is_component_tag(&el.tag) {
+            return;
+        }
+        let component = kebab_to_pascal(&el.tag);
+
+        if let Some(&(start, end)) = tag_ranges(el)
+            .iter()
+            .find(|&&(start, end)| (start..=end).contains(&offset))
+        {
+            symbol = Some((CrossFileSymbol::Component(component), start, end));
+            return;
+        }
+
+        for (name, start, end) in listeners(el) {
+            if (start..=end).contains(&offset) {
+                symbol = Some((
+                    CrossFileSymbol::Event {
+                        component,
+                        name: name.to_string(),
+                    },
+                    start,
+                    end,
+                ));
+                return;
+            }
+        }
+    });
+    if symbol.is_some() {
+        return symbol;
+    }
+
+    let (start, end, name) = collect_template_emits(&root.children)
+        .into_iter()
+        .find(|&(start, end, _)| (start..=end).contains(&offset))?;
+    Some((
+        CrossFileSymbol::Event {
+            component: component?.to_string(),
+            name,
+        },
+        start,
+        end,
+    ))
+}
+
+/// Event names passed to `$emit()` in a template, with their inner ranges.
+fn template_emits(template: &str) -> Vec<(usize, usize, String)> {
+    let allocator = vize_carton::Bump::new();
+    let (root, _) = vize_armature::parse(&allocator, template);
+    collect_template_emits(&root.children)
+}
+
+fn collect_template_emits(children: &[TemplateChildNode<'_>]) -> Vec<(usize, usize, String)> {
+    let mut emits = Vec::new();
+    for_each_expression(children, &mut |expression, offset| {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, expression, SourceType::ts()).parse();
+        let mut collector = EmitArgCollector::default();
+        collector.visit_program(&ret.program);
+        emits.extend(
+            collector
+                .names
+                .into_iter()
+                .map(|(start, end, name)| (offset + start, offset + end, name)),
+        );
+    });
+    emits
+}
+
+/// Visit the source and template offset of every expression in a template.
+fn for_each_expression(children: &[TemplateChildNode<'_>], f: &mut impl FnMut(&str, usize)) {
+    for child in children {
+        match child {
+            TemplateChildNode::Element(el) => {
+                for prop in el.props.iter() {
+                    if let PropNode::Directive(dir) = prop {
+                        if let Some(ExpressionNode::Simple(exp)) = &dir.exp {
+                            f(&exp.content, exp.loc.start.offset as usize);
+                        }
+                    }
+                }
+                for_each_expression(&el.children, f);
+            }
+            TemplateChildNode::Interpolation(interpolation) => {
+                if let ExpressionNode::Simple(exp) = &interpolation.content {
+                    f(&exp.content, exp.loc.start.offset as usize);
+                }
+            }
+            TemplateChildNode::If(if_node) => {
+                for branch in &if_node.branches {
+                    for_each_expression(&branch.children, f);
+                }
+            }
+            TemplateChildNode::For(for_node) => for_each_expression(&for_node.children, f),
+            _ => {}
+        }
+    }
+}
+
+/// String literals in a script, as (inner start, inner end, value).
+fn string_literals(source: &str, source_type: SourceType) -> Vec<(usize, usize, String)> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    let mut collector = StringLiteralCollector::default();
+    collector.visit_program(&ret.program);
+    collector.literals
+}
+
+/// Inner range and value of a string literal, without its quotes.
+fn literal_range(literal: &StringLiteral<'_>) -> (usize, usize, String) {
+    (
+        literal.span.start as usize + 1,
+        literal.span.end as usize - 1,
+        literal.value.to_string(),
+    )
+}
+
+/// Collects every string literal.
+#[derive(Default)]
+struct StringLiteralCollector {
+    literals: Vec<(usize, usize, String)>,
+}
+
+impl<'a> Visit<'a> for StringLiteralCollector {
+    fn visit_string_literal(&mut self, literal: &StringLiteral<'a>) {
+        self.literals.push(literal_range(literal));
+    }
+}
+
+/// Collects the string literal event names of `$emit()` calls.
+#[derive(Default)]
+struct EmitArgCollector {
+    names: Vec<(usize, usize, String)>,
+}
+
+impl<'a> Visit<'a> for EmitArgCollector {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if matches!(&call.callee, Expression::Identifier(id) if id.name == "$emit") {
+            if let Some(Argument::StringLiteral(name)) = call.arguments.first() {
+                self.names.push(literal_range(name));
+            }
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol_range_of_kebab_tag() {
+        let uri = Url::parse("file:///project/App.vue").unwrap();
+        let app = "<script setup>\nimport MyButton from './MyButton.vue'\n</script>\n\n<template>\n  <my-button />\n</template>\n";
+
+        let tag = app.find("my-button").unwrap();
+        let range = RenameService::symbol_range_in_document(&uri, app, tag + 3);
+        assert_eq!(range, Some((tag, tag + "my-button".len())));
+
+        // The import binding is the component
+        let binding = app.find("MyButton").unwrap();
+        assert_eq!(
+            RenameService::symbol_range_in_document(&uri, app, binding),
+            Some((binding, binding + "MyButton".len()))
+        );
+
+        // The module specifier is not part of the symbol
+        let specifier = app.find("MyButton.vue").unwrap();
+        assert_eq!(
+            RenameService::symbol_range_in_document(&uri, app, specifier),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rename_event_covers_template_emit() {
+        let uri = |path: &str| Url::parse(&format!("file:///project/{}", path)).unwrap();
+        let child = "<script setup>\ndefineEmits(['change'])\n</script>\n\n<template>\n  <button @click=\"$emit('change', 1)\">{{ label }}</button>\n</template>\n";
+        let app = "<script setup>\nimport MyInput from './MyInput.vue'\n</script>\n\n<template>\n  <MyInput @change=\"onChange\" />\n</template>\n";
+        let files = vec![
+            (uri("MyInput.vue"), child.to_string()),
+            (uri("App.vue"), app.to_string()),
+        ];
+
+        // Classified from the template `$emit()` alone
+        let emit = child.find("'change', 1").unwrap() + 1;
+        assert_eq!(
+            RenameService::symbol_range_in_document(&uri("MyInput.vue"), child, emit),
+            Some((emit, emit + "change".len()))
+        );
+
+        let edit = RenameService::rename_across_files(&files, &uri("MyInput.vue"), emit, "update")
+            .unwrap()
+            .unwrap();
+        let changes = edit.changes.unwrap();
+        assert_eq!(changes[&uri("MyInput.vue")].len(), 2);
+        assert_eq!(changes[&uri("App.vue")].len(), 1);
+    }
+
+    #[test]
+    fn test_string_literals() {
+        let text = r#"defineEmits(['change', "change", 'changed'])"#;
+        let literals = string_literals(text, SourceType::mjs());
+        let ranges: Vec<_> = literals
+            .iter()
+            .filter(|(_, _, value)| value == "change")
+            .map(|&(start, end, _)| (start, end))
+            .collect();
+        assert_eq!(ranges, vec![(14, 20), (24, 30)]);
+    }
+}
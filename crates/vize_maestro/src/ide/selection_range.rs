@@ -0,0 +1,421 @@
+//! Selection range provider.
+//!
+//! Powers "expand selection" by returning nested ranges around a position:
+//! - Template: expression → attribute value → attribute → element → parent
+//!   elements → block
+//! - Script: JavaScript/TypeScript AST nodes (via oxc) → block
+//! - Style and other blocks: block content → block
+
+use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
+use oxc_ast_visit::Visit;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType};
+use tower_lsp::lsp_types::{Position, Range, SelectionRange};
+use vize_atelier_sfc::BlockLocation;
+use vize_relief::ast::{ExpressionNode, PropNode, TemplateChildNode};
+
+/// Selection range service.
+pub struct SelectionRangeService;
+
+impl SelectionRangeService {
+    /// Get the selection range hierarchy for each position.
+    ///
+    /// The result has one entry per position, as required by the LSP spec;
+    /// positions outside any block get an empty range.
+    pub fn selection_ranges(content: &str, positions: &[Position]) -> Vec<SelectionRange> {
+        positions
+            .iter()
+            .map(|position| {
+                super::position_to_offset(content, position.line, position.character)
+                    .and_then(|offset| Self::selection_range(content, offset))
+                    .unwrap_or(SelectionRange {
+                        range: Range {
+                            start: *position,
+                            end: *position,
+                        },
+                        parent: None,
+                    })
+            })
+            .collect()
+    }
+
+    /// Get the selection range hierarchy for an offset in SFC source.
+    pub fn selection_range(content: &str, offset: usize) -> Option<SelectionRange> {
+        let options = vize_atelier_sfc::SfcParseOptions::default();
+        let descriptor = vize_atelier_sfc::parse_sfc(content, options).ok()?;
+
+        let mut ranges = Vec::new();
+
+        if let Some(ref template) = descriptor.template {
+            if Self::block_contains(&template.loc, offset) {
+                if Self::content_contains(&template.loc, offset) {
+                    Self::collect_template(
+                        &template.content,
+                        template.loc.start,
+                        offset,
+                        &mut ranges,
+                    );
+                }
+                Self::push_block(&template.loc, &mut ranges);
+            }
+        }
+
+        for script in descriptor
+            .script
+            .iter()
+            .chain(descriptor.script_setup.iter())
+        {
+            if Self::block_contains(&script.loc, offset) {
+                let source_type = match script.lang.as_deref() {
+                    Some("ts") => SourceType::ts(),
+                    Some("tsx") => SourceType::tsx(),
+                    Some("jsx") => SourceType::jsx(),
+                    _ => SourceType::mjs(),
+                };
+                if Self::content_contains(&script.loc, offset) {
+                    Self::collect_program(
+                        &script.content,
+                        script.loc.start,
+                        offset,
+                        source_type,
+                        &mut ranges,
+                    );
+                }
+                Self::push_block(&script.loc, &mut ranges);
+            }
+        }
+
+        for loc in descriptor
+            .styles
+            .iter()
+            .map(|style| &style.loc)
+            .chain(descriptor.custom_blocks.iter().map(|block| &block.loc))
+        {
+            if Self::block_contains(loc, offset) {
+                Self::push_block(loc, &mut ranges);
+            }
+        }
+
+        Self::build_hierarchy(content, ranges)
+    }
+
+    /// Collect template ranges (relative to `base`) containing `offset`.
+    fn collect_template(
+        source: &str,
+        base: usize,
+        offset: usize,
+        ranges: &mut Vec<(usize, usize)>,
+    ) {
+        let allocator = vize_carton::Bump::new();
+        let (ast, _) = vize_armature::parse(&allocator, source);
+
+        let relative = offset - base;
+        let mut children = &ast.children;
+        'descend: loop {
+            for child in children.iter() {
+                match child {
+                    TemplateChildNode::Element(el) => {
+                        let (start, end) =
+                            (el.loc.start.offset as usize, el.loc.end.offset as usize);
+                        if relative < start || relative > end {
+                            continue;
+                        }
+                        ranges.push((base + start, base + end));
+
+                        for prop in el.props.iter() {
+                            Self::collect_prop(source, base, relative, prop, ranges);
+                        }
+
+                        children = &el.children;
+                        continue 'descend;
+                    }
+                    TemplateChildNode::Interpolation(interp) => {
+                        let (start, end) = (
+                            interp.loc.start.offset as usize,
+                            interp.loc.end.offset as usize,
+                        );
+                        if relative < start || relative > end {
+                            continue;
+                        }
+                        ranges.push((base + start, base + end));
+                        Self::collect_expression(source, base, relative, &interp.content, ranges);
+                    }
+                    _ => {}
+                }
+            }
+            break;
+        }
+    }
+
+    /// Collect ranges for an attribute or directive containing `relative`.
+    fn collect_prop(
+        source: &str,
+        base: usize,
+        relative: usize,
+        prop: &PropNode<'_>,
+        ranges: &mut Vec<(usize, usize)>,
+    ) {
+        let loc = prop.loc();
+        let (start, end) = (loc.start.offset as usize, loc.end.offset as usize);
+        if relative < start || relative > end {
+            return;
+        }
+
+        // The prop location stops before the closing quote of its value
+        let value_loc = match prop {
+            PropNode::Attribute(attr) => attr.value.as_ref().map(|value| &value.loc),
+            PropNode::Directive(dir) => dir.exp.as_ref().map(|exp| exp.loc()),
+        };
+        let quoted = value_loc.and_then(|loc| {
+            let (value_start, value_end) = (loc.start.offset as usize, loc.end.offset as usize);
+            let bytes = source.as_bytes();
+            let quote = *bytes.get(value_start.checked_sub(1)?)?;
+            (matches!(quote, b'"' | b'\'') && bytes.get(value_end) == Some(&quote))
+                .then_some((value_start - 1, value_end + 1))
+        });
+        let end = quoted.map_or(end, |(_, quoted_end)| end.max(quoted_end));
+        ranges.push((base + start, base + end));
+
+        match prop {
+            PropNode::Attribute(attr) => {
+                let Some(ref value) = attr.value else {
+                    return;
+                };
+                let (start, end) = (
+                    value.loc.start.offset as usize,
+                    value.loc.end.offset as usize,
+                );
+                if relative < start || relative > end {
+                    return;
+                }
+
+                // Quoted value, then the value without quotes
+                if let Some((quoted_start, quoted_end)) = quoted {
+                    ranges.push((base + quoted_start, base + quoted_end));
+                }
+                ranges.push((base + start, base + end));
+            }
+            PropNode::Directive(dir) => {
+                if let Some(ref exp) = dir.exp {
+                    if let Some((quoted_start, quoted_end)) = quoted {
+                        if relative > quoted_start && relative < quoted_end {
+                            ranges.push((base + quoted_start, base + quoted_end));
+                        }
+                    }
+                    Self::collect_expression(source, base, relative, exp, ranges);
+                }
+                if let Some(ref arg) = dir.arg {
+                    let loc = arg.loc();
+                    let (start, end) = (loc.start.offset as usize, loc.end.offset as usize);
+                    if relative >= start && relative <= end {
+                        ranges.push((base + start, base + end));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Collect ranges for a template expression and its subexpressions.
+    fn collect_expression(
+        source: &str,
+        base: usize,
+        relative: usize,
+        exp: &ExpressionNode<'_>,
+        ranges: &mut Vec<(usize, usize)>,
+    ) {
+        let loc = exp.loc();
+        let (start, end) = (loc.start.offset as usize, loc.end.offset as usize);
+        if relative < start || relative > end || end > source.len() {
+            return;
+        }
+        ranges.push((base + start, base + end));
+
+        let allocator = Allocator::default();
+        let Ok(expr) =
+            Parser::new(&allocator, &source[start..end], SourceType::ts()).parse_expression()
+        else {
+            return;
+        };
+        let mut collector = SpanCollector::new(relative - start);
+        collector.visit_expression(&expr);
+        ranges.extend(
+            collector
+                .spans
+                .into_iter()
+                .map(|(s, e)| (base + start + s, base + start + e)),
+        );
+    }
+
+    /// Collect ranges for script AST nodes containing `offset`.
+    fn collect_program(
+        source: &str,
+        base: usize,
+        offset: usize,
+        source_type: SourceType,
+        ranges: &mut Vec<(usize, usize)>,
+    ) {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        if ret.panicked {
+            return;
+        }
+        let mut collector = SpanCollector::new(offset - base);
+        collector.visit_program(&ret.program);
+        ranges.extend(
+            collector
+                .spans
+                .into_iter()
+                .map(|(s, e)| (base + s, base + e)),
+        );
+    }
+
+    /// Push the block content and the whole block (including tags).
+    fn push_block(loc: &BlockLocation, ranges: &mut Vec<(usize, usize)>) {
+        ranges.push((loc.start, loc.end));
+        ranges.push((loc.tag_start, loc.tag_end));
+    }
+
+    #[inline]
+    fn content_contains(loc: &BlockLocation, offset: usize) -> bool {
+        offset >= loc.start && offset <= loc.end
+    }
+
+    #[inline]
+    fn block_contains(loc: &BlockLocation, offset: usize) -> bool {
+        offset >= loc.tag_start && offset <= loc.tag_end
+    }
+
+    /// Turn the collected ranges into a strictly nesting chain, innermost first.
+    fn build_hierarchy(content: &str, mut ranges: Vec<(usize, usize)>) -> Option<SelectionRange> {
+        ranges.sort_by_key(|&(start, end)| (end - start, std::cmp::Reverse(start)));
+
+        let mut chain: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            let nests = chain.last().is_none_or(|&(inner_start, inner_end)| {
+                start <= inner_start && end >= inner_end && (start, end) != (inner_start, inner_end)
+            });
+            if nests {
+                chain.push((start, end));
+            }
+        }
+
+        chain.into_iter().rev().fold(None, |parent, (start, end)| {
+            Some(SelectionRange {
+                range: Self::to_range(content, start, end),
+                parent: parent.map(Box::new),
+            })
+        })
+    }
+
+    fn to_range(content: &str, start: usize, end: usize) -> Range {
+        let (start_line, start_char) = super::offset_to_position(content, start);
+        let (end_line, end_char) = super::offset_to_position(content, end);
+        Range {
+            start: Position {
+                line: start_line,
+                character: start_char,
+            },
+            end: Position {
+                line: end_line,
+                character: end_char,
+            },
+        }
+    }
+}
+
+/// Collects spans of AST nodes that contain an offset.
+struct SpanCollector {
+    offset: u32,
+    spans: Vec<(usize, usize)>,
+}
+
+impl SpanCollector {
+    fn new(offset: usize) -> Self {
+        Self {
+            offset: offset as u32,
+            spans: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Visit<'a> for SpanCollector {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let span = kind.span();
+        if span.start <= self.offset && self.offset <= span.end && !span.is_empty() {
+            self.spans.push((span.start as usize, span.end as usize));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(source: &str) -> Vec<String> {
+        let offset = source.find('|').unwrap();
+        let content = format!("{}{}", &source[..offset], &source[offset + 1..]);
+        let mut texts = Vec::new();
+        let mut current = SelectionRangeService::selection_range(&content, offset);
+        while let Some(range) = current {
+            let start = super::super::position_to_offset(
+                &content,
+                range.range.start.line,
+                range.range.start.character,
+            )
+            .unwrap();
+            let end = super::super::position_to_offset(
+                &content,
+                range.range.end.line,
+                range.range.end.character,
+            )
+            .unwrap();
+            texts.push(content[start..end].to_string());
+            current = range.parent.map(|p| *p);
+        }
+        texts
+    }
+
+    #[test]
+    fn test_attribute_hierarchy() {
+        let texts = chain("<template>\n  <div><img alt=\"a ph|oto\"></div>\n</template>\n");
+        assert_eq!(
+            &texts[..5],
+            [
+                "a photo",
+                "\"a photo\"",
+                "alt=\"a photo\"",
+                "<img alt=\"a photo\">",
+                "<div><img alt=\"a photo\"></div>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_script_hierarchy() {
+        let texts = chain("<script setup>\nconst total = price * cou|nt\n</script>\n");
+        assert_eq!(texts[0], "count");
+        assert!(texts.contains(&"price * count".to_string()));
+        assert_eq!(
+            texts.last().unwrap(),
+            "<script setup>\nconst total = price * count\n</script>"
+        );
+    }
+
+    #[test]
+    fn test_ranges_strictly_nest() {
+        let source = "<template>\n  <p>{{ a|b }}</p>\n</template>\n";
+        let offset = source.find('|').unwrap();
+        let content = source.replace('|', "");
+        let mut current = SelectionRangeService::selection_range(&content, offset);
+        let mut previous: Option<Range> = None;
+        while let Some(range) = current {
+            if let Some(inner) = previous {
+                assert_ne!(inner, range.range);
+                assert!(range.range.start <= inner.start && range.range.end >= inner.end);
+            }
+            previous = Some(range.range);
+            current = range.parent.map(|p| *p);
+        }
+    }
+}
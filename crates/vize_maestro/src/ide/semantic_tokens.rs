@@ -6,9 +6,18 @@
 //! - Script bindings
 //! - CSS v-bind variables
 
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    BindingIdentifier, CallExpression, Expression, IdentifierReference, ObjectProperty,
+    PropertyKey, StaticMemberExpression,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
 use tower_lsp::lsp_types::{
     SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokensResult,
 };
+use vize_carton::FxHashMap;
 
 /// Token types supported by the semantic tokens provider.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -124,6 +133,54 @@ struct AbsoluteToken {
 /// Semantic tokens service.
 pub struct SemanticTokensService;
 
+/// Collects token types for identifiers in a parsed template expression.
+#[derive(Default)]
+struct ExpressionRoleCollector {
+    roles: FxHashMap<usize, TokenType>,
+}
+
+impl ExpressionRoleCollector {
+    fn mark(&mut self, start: u32, token_type: TokenType) {
+        self.roles.entry(start as usize).or_insert(token_type);
+    }
+}
+
+impl<'a> Visit<'a> for ExpressionRoleCollector {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        // Callees are marked first so they win over the plain reference
+        match &call.callee {
+            Expression::Identifier(ident) => self.mark(ident.span.start, TokenType::Function),
+            Expression::StaticMemberExpression(member) => {
+                self.mark(member.property.span.start, TokenType::Function)
+            }
+            _ => {}
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_static_member_expression(&mut self, member: &StaticMemberExpression<'a>) {
+        self.mark(member.property.span.start, TokenType::Property);
+        walk::walk_static_member_expression(self, member);
+    }
+
+    fn visit_object_property(&mut self, prop: &ObjectProperty<'a>) {
+        if let PropertyKey::StaticIdentifier(key) = &prop.key {
+            if !prop.shorthand {
+                self.mark(key.span.start, TokenType::Property);
+            }
+        }
+        walk::walk_object_property(self, prop);
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        self.mark(ident.span.start, TokenType::Parameter);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.mark(ident.span.start, TokenType::Variable);
+    }
+}
+
 impl SemanticTokensService {
     /// Get semantic tokens for a document.
     pub fn get_tokens(
@@ -144,11 +201,15 @@ impl SemanticTokensService {
 
         let mut tokens: Vec<AbsoluteToken> = Vec::new();
 
+        // Block contents start on the (1-based) line of their opening tag;
+        // collectors add 1-based content lines, so pass the 0-based tag line.
+        let base_line = |start_line: usize| start_line.saturating_sub(1) as u32;
+
         // Collect tokens from template
         if let Some(ref template) = descriptor.template {
             Self::collect_template_tokens(
                 &template.content,
-                template.loc.start_line as u32,
+                base_line(template.loc.start_line),
                 &mut tokens,
             );
         }
@@ -157,19 +218,27 @@ impl SemanticTokensService {
         if let Some(ref script_setup) = descriptor.script_setup {
             Self::collect_script_tokens(
                 &script_setup.content,
-                script_setup.loc.start_line as u32,
+                base_line(script_setup.loc.start_line),
                 &mut tokens,
             );
         }
 
         // Collect tokens from script
         if let Some(ref script) = descriptor.script {
-            Self::collect_script_tokens(&script.content, script.loc.start_line as u32, &mut tokens);
+            Self::collect_script_tokens(
+                &script.content,
+                base_line(script.loc.start_line),
+                &mut tokens,
+            );
         }
 
         // Collect tokens from styles
         for style in &descriptor.styles {
-            Self::collect_style_tokens(&style.content, style.loc.start_line as u32, &mut tokens);
+            Self::collect_style_tokens(
+                &style.content,
+                base_line(style.loc.start_line),
+                &mut tokens,
+            );
         }
 
         // Collect tokens from inline <art> custom blocks
@@ -426,6 +495,7 @@ impl SemanticTokensService {
         base_line: u32,
         tokens: &mut Vec<AbsoluteToken>,
     ) {
+        let roles = Self::expression_roles(expr);
         let bytes = expr.as_bytes();
         let mut i = 0;
 
@@ -511,6 +581,8 @@ impl SemanticTokensService {
                 // Determine token type
                 let token_type = if Self::is_keyword(ident) || Self::is_boolean_or_null(ident) {
                     TokenType::Keyword
+                } else if let Some(role) = roles.get(&start) {
+                    *role
                 } else if Self::looks_like_function_call(expr, start) {
                     TokenType::Function
                 } else if Self::looks_like_property_access(expr, start) {
@@ -552,6 +624,22 @@ impl SemanticTokensService {
         }
     }
 
+    /// Classify identifiers in an expression by parsing it as JavaScript.
+    ///
+    /// Returns token types keyed by offset within `expr`. Empty when the
+    /// expression does not parse (e.g. `v-for` aliases or statement lists),
+    /// in which case callers fall back to lexical heuristics.
+    fn expression_roles(expr: &str) -> FxHashMap<usize, TokenType> {
+        let allocator = Allocator::default();
+        let Ok(expression) = Parser::new(&allocator, expr, SourceType::ts()).parse_expression()
+        else {
+            return FxHashMap::default();
+        };
+        let mut collector = ExpressionRoleCollector::default();
+        collector.visit_expression(&expression);
+        collector.roles
+    }
+
     /// Check if character starts an operator.
     fn is_operator_start(c: char) -> bool {
         matches!(
@@ -1650,6 +1738,64 @@ const count = ref(0)
         assert!(tokens.len() >= 7, "Expected at least 7 tokens");
     }
 
+    /// Decode delta-encoded tokens into (line, start, length, type).
+    fn absolute_tokens(result: SemanticTokensResult) -> Vec<(u32, u32, u32, u32)> {
+        let SemanticTokensResult::Tokens(tokens) = result else {
+            return Vec::new();
+        };
+        let (mut line, mut start) = (0, 0);
+        tokens
+            .data
+            .iter()
+            .map(|t| {
+                if t.delta_line > 0 {
+                    start = 0;
+                }
+                line += t.delta_line;
+                start += t.delta_start;
+                (line, start, t.length, t.token_type)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_interpolation_member_access_tokens() {
+        let content = r#"<template>
+  <p>{{ user.name }}</p>
+</template>
+
+<script setup>
+const user = { name: 'vize' }
+</script>
+"#;
+        let uri = tower_lsp::lsp_types::Url::parse("file:///test.vue").unwrap();
+        let tokens = absolute_tokens(SemanticTokensService::get_tokens(content, &uri).unwrap());
+
+        assert!(
+            tokens.contains(&(1, 8, 4, TokenType::Variable as u32)),
+            "{:?}",
+            tokens
+        );
+        assert!(
+            tokens.contains(&(1, 13, 4, TokenType::Property as u32)),
+            "{:?}",
+            tokens
+        );
+    }
+
+    #[test]
+    fn test_expression_roles_from_ast() {
+        let expr = "items.map((item) => format(item.label))";
+        let roles = SemanticTokensService::expression_roles(expr);
+
+        assert_eq!(roles.get(&0), Some(&TokenType::Variable)); // items
+        assert_eq!(roles.get(&6), Some(&TokenType::Function)); // map
+        assert_eq!(roles.get(&11), Some(&TokenType::Parameter)); // item
+        assert_eq!(roles.get(&20), Some(&TokenType::Function)); // format
+        assert_eq!(roles.get(&32), Some(&TokenType::Property)); // label
+        assert!(SemanticTokensService::expression_roles("item in items").is_empty());
+    }
+
     #[test]
     fn test_inline_art_tokens_in_vue() {
         let content = r#"<template>
@@ -0,0 +1,380 @@
+//! Signature help provider.
+//!
+//! Provides parameter hints for function calls in template expressions:
+//! - Real signatures from tsgo via virtual TypeScript (when available)
+//! - Parameter lists recorded by croquis for script functions (fallback)
+
+#[cfg(feature = "native")]
+use std::sync::Arc;
+
+use tower_lsp::lsp_types::{
+    ParameterInformation, ParameterLabel, SignatureHelp, SignatureInformation,
+};
+use vize_croquis::{Analyzer, AnalyzerOptions, FunctionSignature};
+
+#[cfg(feature = "native")]
+use tower_lsp::lsp_types::{Documentation, MarkupContent, MarkupKind};
+#[cfg(feature = "native")]
+use vize_canon::{LspDocumentation, LspParameterLabel, LspSignatureHelp, TsgoBridge};
+
+use super::IdeContext;
+#[cfg(feature = "native")]
+use crate::virtual_code::VirtualDocument;
+
+/// Signature help service for function calls in templates.
+pub struct SignatureHelpService;
+
+/// The call surrounding the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallContext {
+    /// Callee expression text (e.g. `select` or `utils.format`)
+    pub callee: String,
+    /// Zero-based index of the argument under the cursor
+    pub active_parameter: u32,
+}
+
+impl SignatureHelpService {
+    /// Get signature help for the given context.
+    pub fn signature_help(ctx: &IdeContext) -> Option<SignatureHelp> {
+        if !ctx.is_in_template() {
+            return None;
+        }
+        Self::signature_help_for_sfc(&ctx.content, ctx.uri.path(), ctx.offset)
+    }
+
+    /// Get signature help with tsgo support (async version).
+    ///
+    /// This method first asks tsgo for the signature at the mapped position
+    /// in the virtual TypeScript, then falls back to croquis analysis.
+    #[cfg(feature = "native")]
+    pub async fn signature_help_with_tsgo(
+        ctx: &IdeContext<'_>,
+        tsgo_bridge: Option<Arc<TsgoBridge>>,
+    ) -> Option<SignatureHelp> {
+        if !ctx.is_in_template() {
+            return None;
+        }
+
+        let options = vize_atelier_sfc::SfcParseOptions {
+            filename: ctx.uri.path().to_string(),
+            ..Default::default()
+        };
+        let descriptor = vize_atelier_sfc::parse_sfc(&ctx.content, options).ok()?;
+        let template_block = descriptor.template.as_ref()?;
+        if ctx.offset < template_block.loc.start || ctx.offset > template_block.loc.end {
+            return None;
+        }
+        let call = Self::find_call_context(&ctx.content[template_block.loc.start..ctx.offset])?;
+
+        let template = ctx
+            .virtual_docs
+            .as_ref()
+            .and_then(|docs| docs.template.as_ref());
+        if let (Some(bridge), Some(template)) = (tsgo_bridge, template) {
+            // The template source map works on template-relative byte offsets
+            let relative_offset = (ctx.offset - template_block.loc.start) as u32;
+            if let Some(help) =
+                Self::tsgo_signature_help(ctx, &bridge, template, relative_offset, &call).await
+            {
+                return Some(help);
+            }
+        }
+
+        // Fall back to croquis analysis
+        Self::signature_help_for_call(&descriptor, &call)
+    }
+
+    /// Ask tsgo for the signature at a template offset.
+    ///
+    /// Returns `None` when the offset has no mapping into the virtual
+    /// TypeScript, rather than querying an unrelated position.
+    #[cfg(feature = "native")]
+    async fn tsgo_signature_help(
+        ctx: &IdeContext<'_>,
+        bridge: &TsgoBridge,
+        template: &VirtualDocument,
+        relative_offset: u32,
+        call: &CallContext,
+    ) -> Option<SignatureHelp> {
+        if !bridge.is_initialized() {
+            return None;
+        }
+
+        let vts_offset = template.source_map.to_generated(relative_offset)?;
+        let (line, character) = template.offset_to_position(vts_offset as usize);
+        let uri = format!("vize-virtual://{}.template.ts", ctx.uri.path());
+
+        let _ = bridge
+            .open_or_update_virtual_document(
+                &format!("{}.template.ts", ctx.uri.path()),
+                &template.content,
+            )
+            .await;
+
+        let help = bridge.signature_help(&uri, line, character).await.ok()??;
+        if help.signatures.is_empty() {
+            return None;
+        }
+        Some(Self::convert_lsp_signature_help(
+            help,
+            call.active_parameter,
+        ))
+    }
+
+    /// Get signature help from croquis analysis for an offset in SFC source.
+    pub fn signature_help_for_sfc(
+        content: &str,
+        filename: &str,
+        offset: usize,
+    ) -> Option<SignatureHelp> {
+        let options = vize_atelier_sfc::SfcParseOptions {
+            filename: filename.to_string(),
+            ..Default::default()
+        };
+        let descriptor = vize_atelier_sfc::parse_sfc(content, options).ok()?;
+
+        let template = descriptor.template.as_ref()?;
+        if offset < template.loc.start || offset > template.loc.end {
+            return None;
+        }
+
+        let call = Self::find_call_context(&content[template.loc.start..offset])?;
+        Self::signature_help_for_call(&descriptor, &call)
+    }
+
+    /// Look up the callee's parameters in the croquis analysis of the script.
+    fn signature_help_for_call(
+        descriptor: &vize_atelier_sfc::SfcDescriptor<'_>,
+        call: &CallContext,
+    ) -> Option<SignatureHelp> {
+        let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+        if let Some(ref script_setup) = descriptor.script_setup {
+            analyzer.analyze_script_setup(&script_setup.content);
+        } else if let Some(ref script) = descriptor.script {
+            analyzer.analyze_script_plain(&script.content);
+        }
+        let summary = analyzer.finish();
+
+        let signature = summary.function_signatures.get(call.callee.as_str())?;
+        Some(Self::build_signature_help(
+            &call.callee,
+            signature,
+            call.active_parameter,
+        ))
+    }
+
+    /// Find the innermost unclosed call before the end of `text`.
+    ///
+    /// Scans backwards, skipping nested brackets and string literals, and counts
+    /// top-level commas to determine the active parameter. Stops at the start of
+    /// an interpolation or attribute value.
+    pub fn find_call_context(text: &str) -> Option<CallContext> {
+        let bytes = text.as_bytes();
+        let mut depth = 0u32;
+        let mut commas = 0u32;
+        let mut quote: Option<u8> = None;
+        let mut i = bytes.len();
+
+        while i > 0 {
+            i -= 1;
+            let b = bytes[i];
+
+            if let Some(q) = quote {
+                if b == q && (i == 0 || bytes[i - 1] != b'\\') {
+                    quote = None;
+                }
+                continue;
+            }
+
+            match b {
+                b'\'' | b'`' => quote = Some(b),
+                // Attribute value boundary (`@click="...`)
+                b'"' if i > 0 && bytes[i - 1] == b'=' => return None,
+                b'"' => quote = Some(b),
+                // Interpolation boundary (`{{ ...`)
+                b'{' if i > 0 && bytes[i - 1] == b'{' && depth == 0 => return None,
+                b')' | b']' | b'}' => depth += 1,
+                b'[' | b'{' => {
+                    if depth == 0 {
+                        return None;
+                    }
+                    depth -= 1;
+                }
+                b'(' => {
+                    if depth > 0 {
+                        depth -= 1;
+                        continue;
+                    }
+                    let callee = Self::callee_before(&text[..i])?;
+                    return Some(CallContext {
+                        callee,
+                        active_parameter: commas,
+                    });
+                }
+                b',' if depth == 0 => commas += 1,
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// Extract the callee expression (identifier or member chain) ending at `text`.
+    fn callee_before(text: &str) -> Option<String> {
+        let trimmed = text.trim_end();
+        let start = trimmed
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| c.is_alphanumeric() || matches!(c, '_' | '$' | '.'))
+            .last()
+            .map(|(i, _)| i)?;
+        let callee = trimmed[start..].trim_start_matches('.');
+
+        let first = callee.chars().next()?;
+        if first.is_ascii_digit() || callee.ends_with('.') {
+            return None;
+        }
+        Some(callee.to_string())
+    }
+
+    /// Build an LSP signature from a croquis-recorded parameter list.
+    fn build_signature_help(
+        name: &str,
+        signature: &FunctionSignature,
+        active_parameter: u32,
+    ) -> SignatureHelp {
+        let mut label = String::with_capacity(name.len() + 2);
+        label.push_str(name);
+        label.push('(');
+
+        let mut parameters = Vec::with_capacity(signature.params.len());
+        for (i, param) in signature.params.iter().enumerate() {
+            if i > 0 {
+                label.push_str(", ");
+            }
+            let start = label.encode_utf16().count() as u32;
+            label.push_str(param);
+            let end = label.encode_utf16().count() as u32;
+            parameters.push(ParameterInformation {
+                label: ParameterLabel::LabelOffsets([start, end]),
+                documentation: None,
+            });
+        }
+        label.push(')');
+
+        // Extra arguments land on the rest parameter, if any
+        let param_count = signature.params.len() as u32;
+        let active_parameter = if signature.max_params.is_none() && param_count > 0 {
+            active_parameter.min(param_count - 1)
+        } else {
+            active_parameter
+        };
+
+        SignatureHelp {
+            signatures: vec![SignatureInformation {
+                label,
+                documentation: None,
+                parameters: Some(parameters),
+                active_parameter: None,
+            }],
+            active_signature: Some(0),
+            active_parameter: Some(active_parameter),
+        }
+    }
+
+    /// Convert tsgo LspSignatureHelp to tower-lsp SignatureHelp.
+    #[cfg(feature = "native")]
+    fn convert_lsp_signature_help(help: LspSignatureHelp, active_parameter: u32) -> SignatureHelp {
+        let convert_doc = |doc: LspDocumentation| match doc {
+            LspDocumentation::String(s) => Documentation::String(s),
+            LspDocumentation::Markup(markup) => Documentation::MarkupContent(MarkupContent {
+                kind: if markup.kind == "markdown" {
+                    MarkupKind::Markdown
+                } else {
+                    MarkupKind::PlainText
+                },
+                value: markup.value,
+            }),
+        };
+
+        let signatures = help
+            .signatures
+            .into_iter()
+            .map(|sig| SignatureInformation {
+                label: sig.label,
+                documentation: sig.documentation.map(convert_doc),
+                parameters: sig.parameters.map(|params| {
+                    params
+                        .into_iter()
+                        .map(|param| ParameterInformation {
+                            label: match param.label {
+                                LspParameterLabel::Simple(s) => ParameterLabel::Simple(s),
+                                LspParameterLabel::LabelOffsets(offsets) => {
+                                    ParameterLabel::LabelOffsets(offsets)
+                                }
+                            },
+                            documentation: param.documentation.map(convert_doc),
+                        })
+                        .collect()
+                }),
+                active_parameter: None,
+            })
+            .collect();
+
+        SignatureHelp {
+            signatures,
+            active_signature: help.active_signature.or(Some(0)),
+            active_parameter: help.active_parameter.or(Some(active_parameter)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_call_context() {
+        let call = SignatureHelpService::find_call_context("{{ select(1, ").unwrap();
+        assert_eq!(call.callee, "select");
+        assert_eq!(call.active_parameter, 1);
+
+        let call = SignatureHelpService::find_call_context("@click=\"select(").unwrap();
+        assert_eq!(call.callee, "select");
+        assert_eq!(call.active_parameter, 0);
+    }
+
+    #[test]
+    fn test_find_call_context_skips_nested() {
+        let call =
+            SignatureHelpService::find_call_context("{{ format(a, inner(b, c), [d, e], 'x,y', ")
+                .unwrap();
+        assert_eq!(call.callee, "format");
+        assert_eq!(call.active_parameter, 4);
+
+        let call = SignatureHelpService::find_call_context("{{ utils.format(x").unwrap();
+        assert_eq!(call.callee, "utils.format");
+    }
+
+    #[test]
+    fn test_find_call_context_outside_call() {
+        assert!(SignatureHelpService::find_call_context("{{ count ").is_none());
+        assert!(SignatureHelpService::find_call_context("{{ select(1) + ").is_none());
+        assert!(SignatureHelpService::find_call_context(":title=\"(a, ").is_none());
+    }
+
+    #[test]
+    fn test_rest_parameter_stays_active() {
+        let signature = FunctionSignature {
+            min_params: 1,
+            max_params: None,
+            params: vec!["level: string".into(), "...args: unknown[]".into()],
+        };
+        let help = SignatureHelpService::build_signature_help("log", &signature, 3);
+        assert_eq!(
+            help.signatures[0].label,
+            "log(level: string, ...args: unknown[])"
+        );
+        assert_eq!(help.active_parameter, Some(1));
+    }
+}
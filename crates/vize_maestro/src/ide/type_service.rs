@@ -10,7 +10,8 @@ use tower_lsp::lsp_types::{
 };
 use vize_vitrine::{type_check_sfc, TypeCheckOptions, TypeSeverity};
 
-use super::IdeContext;
+use super::rename::WorkspaceIndex;
+use super::{IdeContext, RenameService};
 use crate::server::ServerState;
 
 /// Batch type check result summary.
@@ -34,10 +35,18 @@ pub struct LspTypeCheckOptions {
     pub strict: bool,
     /// Check props type definitions
     pub check_props: bool,
+    /// Check withDefaults defaults against prop types
+    pub check_with_defaults: bool,
     /// Check emits type definitions
     pub check_emits: bool,
+    /// Check defineModel declarations
+    pub check_define_model: bool,
     /// Check template bindings
     pub check_template_bindings: bool,
+    /// Check event handler call arguments
+    pub check_event_handler_args: bool,
+    /// Check slot usage against defineSlots
+    pub check_define_slots: bool,
     /// Check reactivity loss patterns
     pub check_reactivity: bool,
     /// Check setup context violations
@@ -46,6 +55,9 @@ pub struct LspTypeCheckOptions {
     pub check_invalid_exports: bool,
     /// Check fallthrough attrs with multi-root
     pub check_fallthrough_attrs: bool,
+    /// Check member access on component template refs against the
+    /// workspace components' defineExpose()
+    pub check_component_exposes: bool,
 }
 
 impl Default for LspTypeCheckOptions {
@@ -53,12 +65,17 @@ impl Default for LspTypeCheckOptions {
         Self {
             strict: true, // Strict by default for IDE integration
             check_props: true,
+            check_with_defaults: true,
             check_emits: true,
+            check_define_model: true,
             check_template_bindings: true,
+            check_event_handler_args: true,
+            check_define_slots: true,
             check_reactivity: true,
             check_setup_context: true,
             check_invalid_exports: true,
             check_fallthrough_attrs: true,
+            check_component_exposes: true,
         }
     }
 }
@@ -85,18 +102,31 @@ impl TypeService {
         let content = doc.text();
 
         // Use vize_vitrine's strict type checker
-        let options = TypeCheckOptions {
+        let mut options = TypeCheckOptions {
             filename: uri.path().to_string(),
             strict: lsp_options.strict,
             check_props: lsp_options.check_props,
+            check_with_defaults: lsp_options.check_with_defaults,
             check_emits: lsp_options.check_emits,
+            check_define_model: lsp_options.check_define_model,
             check_template_bindings: lsp_options.check_template_bindings,
+            check_event_handler_args: lsp_options.check_event_handler_args,
+            check_define_slots: lsp_options.check_define_slots,
             check_reactivity: lsp_options.check_reactivity,
             check_setup_context: lsp_options.check_setup_context,
             check_invalid_exports: lsp_options.check_invalid_exports,
             check_fallthrough_attrs: lsp_options.check_fallthrough_attrs,
+            check_component_exposes: lsp_options.check_component_exposes,
             include_virtual_ts: false,
+            ..TypeCheckOptions::new(uri.path())
         };
+        if options.check_component_exposes {
+            let exposes = state.component_exposes(|| {
+                let files = RenameService::workspace_files_with(state, uri, &content);
+                WorkspaceIndex::load(&files).component_exposes()
+            });
+            options.component_exposes = (*exposes).clone();
+        }
 
         let result = type_check_sfc(&content, &options);
 
@@ -48,6 +48,7 @@
 //! - Code completion and IntelliSense
 //! - Go to definition and references
 //! - Hover information
+//! - Signature help
 //! - Diagnostics and error reporting
 //! - Code actions and quick fixes
 //! - Rename refactoring
@@ -70,8 +71,8 @@ pub mod virtual_code;
 
 pub use ide::{
     CodeActionService, CodeLensService, CompletionService, DefinitionService, DiagnosticService,
-    HoverService, IdeContext, ReferencesService, RenameService, SemanticTokensService, TypeService,
-    WorkspaceSymbolsService,
+    HoverService, IdeContext, ReferencesService, RenameService, SemanticTokensService,
+    SignatureHelpService, TypeService, WorkspaceSymbolsService,
 };
 pub use server::MaestroServer;
 pub use virtual_code::{VirtualCodeGenerator, VirtualDocuments};
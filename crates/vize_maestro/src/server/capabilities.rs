@@ -46,6 +46,20 @@ pub fn server_capabilities() -> ServerCapabilities {
         // Find references
         references_provider: Some(OneOf::Left(true)),
 
+        // Highlight occurrences of the symbol under the cursor
+        document_highlight_provider: Some(OneOf::Left(true)),
+
+        // Pull diagnostics (textDocument/diagnostic, workspace/diagnostic)
+        diagnostic_provider: Some(DiagnosticServerCapabilities::Options(DiagnosticOptions {
+            identifier: Some("vize".to_string()),
+            inter_file_dependencies: true,
+            workspace_diagnostics: true,
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+        })),
+
+        // Call hierarchy for script functions
+        call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+
         // Document symbols (outline)
         document_symbol_provider: Some(OneOf::Left(true)),
 
@@ -75,6 +89,10 @@ pub fn server_capabilities() -> ServerCapabilities {
         // Range formatting
         document_range_formatting_provider: Some(OneOf::Left(true)),
 
+        // On-type formatting (reindent template markup)
+        #[cfg(feature = "glyph")]
+        document_on_type_formatting_provider: Some(on_type_formatting_options()),
+
         // Signature help
         signature_help_provider: Some(SignatureHelpOptions {
             trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
@@ -148,7 +166,15 @@ pub fn server_capabilities() -> ServerCapabilities {
         selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
 
         // Inlay hints
-        inlay_hint_provider: Some(OneOf::Left(true)),
+        inlay_hint_provider: Some(OneOf::Right(InlayHintServerCapabilities::Options(
+            InlayHintOptions {
+                resolve_provider: Some(true),
+                work_done_progress_options: Default::default(),
+            },
+        ))),
+
+        // Linked editing of open/close tag names
+        linked_editing_range_provider: Some(LinkedEditingRangeServerCapabilities::Simple(true)),
 
         // Workspace capabilities
         workspace: Some(WorkspaceServerCapabilities {
@@ -163,11 +189,8 @@ pub fn server_capabilities() -> ServerCapabilities {
         type_definition_provider: None,
         implementation_provider: None,
         declaration_provider: None,
-        color_provider: None,
-        document_on_type_formatting_provider: None,
+        color_provider: Some(ColorProviderCapability::Simple(true)),
         execute_command_provider: None,
-        linked_editing_range_provider: None,
-        call_hierarchy_provider: None,
         moniker_provider: None,
         experimental: None,
 
@@ -175,3 +198,15 @@ pub fn server_capabilities() -> ServerCapabilities {
         ..Default::default()
     }
 }
+
+/// Trigger characters for `textDocument/onTypeFormatting`.
+#[cfg(feature = "glyph")]
+fn on_type_formatting_options() -> DocumentOnTypeFormattingOptions {
+    let mut triggers = crate::ide::ON_TYPE_FORMATTING_TRIGGERS
+        .iter()
+        .map(|ch| ch.to_string());
+    DocumentOnTypeFormattingOptions {
+        first_trigger_character: triggers.next().unwrap_or_default(),
+        more_trigger_character: Some(triggers.collect()),
+    }
+}
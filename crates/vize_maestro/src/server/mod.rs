@@ -14,10 +14,14 @@ use tower_lsp::{Client, LanguageServer};
 
 use crate::document::DocumentStore;
 use crate::ide::{
-    CodeActionService, CodeLensService, CompletionService, DefinitionService, DiagnosticService,
-    DocumentLinkService, HoverService, IdeContext, InlayHintService, ReferencesService,
-    RenameService, SemanticTokensService, WorkspaceSymbolsService,
+    AutoImportData, CallHierarchyService, CodeActionService, CodeLensService, ColorService,
+    CompletionService, DefinitionService, DiagnosticService, DocumentHighlightService,
+    DocumentLinkService, HoverService, IdeContext, InlayHintConfig, InlayHintService,
+    LinkedEditingService, RefTypeHintData, ReferencesService, RenameService, SemanticTokensService,
+    SignatureHelpService, WorkspaceSymbolsService,
 };
+#[cfg(feature = "glyph")]
+use crate::ide::{FormattingService, OnTypeFormattingService};
 
 /// The Maestro LSP server.
 pub struct MaestroServer {
@@ -43,6 +47,11 @@ impl MaestroServer {
 
     /// Publish diagnostics for a document.
     async fn publish_diagnostics(&self, uri: &Url) {
+        // Clients using the pull model request diagnostics themselves
+        if self.state.pull_diagnostics() {
+            return;
+        }
+
         // Use async version when native feature is enabled (includes tsgo diagnostics)
         #[cfg(feature = "native")]
         let diagnostics = DiagnosticService::collect_async(&self.state, uri).await;
@@ -55,6 +64,22 @@ impl MaestroServer {
             .await;
     }
 
+    /// Ask a pull-diagnostics client to request diagnostics again.
+    ///
+    /// Used when diagnostics change without an edit to the documents themselves.
+    async fn refresh_pull_diagnostics(&self) {
+        if !self.state.pull_diagnostics() || !self.state.diagnostic_refresh() {
+            return;
+        }
+        if let Err(err) = self
+            .client
+            .send_request::<request::WorkspaceDiagnosticRefresh>(())
+            .await
+        {
+            tracing::warn!("workspace/diagnostic/refresh failed: {}", err);
+        }
+    }
+
     /// Get block snippet completions (when outside all blocks)
     fn get_block_snippets(&self) -> Vec<CompletionItem> {
         vec![
@@ -231,13 +256,47 @@ impl LanguageServer for MaestroServer {
             self.state.load_format_config(path);
         }
 
-        // Set workspace root for native features (tsgo, batch checker)
-        #[cfg(feature = "native")]
+        // Set workspace root (component discovery, tsgo, batch checker)
         if let Some(path) = workspace_path {
             tracing::info!("Setting workspace root: {:?}", path);
             self.state.set_workspace_root(path);
         }
 
+        let supports_pull_diagnostics = params
+            .capabilities
+            .text_document
+            .as_ref()
+            .is_some_and(|text_document| text_document.diagnostic.is_some());
+        self.state.set_pull_diagnostics(supports_pull_diagnostics);
+        let supports_diagnostic_refresh = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.diagnostic.as_ref())
+            .and_then(|diagnostic| diagnostic.refresh_support)
+            .unwrap_or(false);
+        self.state
+            .set_diagnostic_refresh(supports_diagnostic_refresh);
+
+        // Inlay hint toggles: `{ "inlayHints": { "propsPrefix": bool, "refTypes": bool } }`
+        if let Some(inlay_hints) = params
+            .initialization_options
+            .as_ref()
+            .and_then(|options| options.get("inlayHints"))
+        {
+            let defaults = InlayHintConfig::default();
+            let flag = |key: &str, default: bool| {
+                inlay_hints
+                    .get(key)
+                    .and_then(|value| value.as_bool())
+                    .unwrap_or(default)
+            };
+            self.state.set_inlay_hint_config(InlayHintConfig {
+                props_prefix: flag("propsPrefix", defaults.props_prefix),
+                ref_types: flag("refTypes", defaults.ref_types),
+            });
+        }
+
         Ok(InitializeResult {
             capabilities: server_capabilities(),
             server_info: Some(ServerInfo {
@@ -266,6 +325,9 @@ impl LanguageServer for MaestroServer {
         self.state
             .documents
             .open(uri.clone(), content.clone(), version, language_id);
+        self.state.invalidate_workspace_index();
+        // A reopened document may reuse a version it had before
+        self.state.bump_diagnostic_generation();
 
         // Generate virtual documents for the SFC
         self.state.update_virtual_docs(&uri, &content);
@@ -280,6 +342,7 @@ impl LanguageServer for MaestroServer {
         self.state
             .documents
             .apply_changes(&uri, params.content_changes, version);
+        self.state.invalidate_workspace_index();
 
         // Regenerate virtual documents with updated content
         if let Some(doc) = self.state.documents.get(&uri) {
@@ -292,12 +355,34 @@ impl LanguageServer for MaestroServer {
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
         let uri = params.text_document.uri;
+
+        // A saved file may be new to the workspace component list
+        self.state.invalidate_workspace_files();
+        self.state.bump_diagnostic_generation();
         self.publish_diagnostics(&uri).await;
+        // Cross-file diagnostics of other open documents may have changed
+        self.refresh_pull_diagnostics().await;
+    }
+
+    async fn did_change_configuration(&self, _params: DidChangeConfigurationParams) {
+        // Settings may change what is reported, so drop cached type check
+        // results and report every open document again
+        #[cfg(feature = "native")]
+        self.state.invalidate_batch_cache();
+        self.state.bump_diagnostic_generation();
+
+        for uri in self.state.documents.uris() {
+            self.publish_diagnostics(&uri).await;
+        }
+        self.refresh_pull_diagnostics().await;
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri;
         self.state.documents.close(&uri);
+        // Unsaved edits are dropped, so the index reads the file on disk again
+        self.state.invalidate_workspace_index();
+        self.state.invalidate_component_exposes();
 
         // Clean up virtual documents cache
         self.state.remove_virtual_docs(&uri);
@@ -347,6 +432,35 @@ impl LanguageServer for MaestroServer {
         Ok(hover_result)
     }
 
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        let Some(doc) = self.state.documents.get(uri) else {
+            return Ok(None);
+        };
+
+        let content = doc.text();
+        let offset =
+            crate::utils::position_to_offset_str(&content, position.line, position.character);
+
+        let Some(ctx) = IdeContext::new(&self.state, uri, offset) else {
+            return Ok(None);
+        };
+
+        // Try tsgo-based signature help first (when native feature is enabled)
+        #[cfg(feature = "native")]
+        let help = {
+            let tsgo_bridge = self.state.get_tsgo_bridge().await;
+            SignatureHelpService::signature_help_with_tsgo(&ctx, tsgo_bridge).await
+        };
+
+        #[cfg(not(feature = "native"))]
+        let help = SignatureHelpService::signature_help(&ctx);
+
+        Ok(help)
+    }
+
     async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
         let uri = &params.text_document_position.text_document.uri;
         let position = params.text_document_position.position;
@@ -376,9 +490,20 @@ impl LanguageServer for MaestroServer {
     }
 
     async fn completion_resolve(&self, item: CompletionItem) -> Result<CompletionItem> {
-        // Return the item as-is for now
-        // TODO: Add documentation, additional text edits, etc.
-        Ok(item)
+        let Some(data) = item
+            .data
+            .as_ref()
+            .and_then(|d| serde_json::from_value::<AutoImportData>(d.clone()).ok())
+        else {
+            return Ok(item);
+        };
+
+        let Some(doc) = self.state.documents.get(&data.uri) else {
+            return Ok(item);
+        };
+
+        let content = doc.text();
+        Ok(CompletionService::resolve(item, &content))
     }
 
     async fn goto_definition(
@@ -442,6 +567,178 @@ impl LanguageServer for MaestroServer {
         Ok(None)
     }
 
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> Result<Option<Vec<DocumentHighlight>>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        let Some(doc) = self.state.documents.get(uri) else {
+            return Ok(None);
+        };
+
+        let content = doc.text();
+        let offset =
+            crate::utils::position_to_offset_str(&content, position.line, position.character);
+
+        if let Some(ctx) = IdeContext::new(&self.state, uri, offset) {
+            return Ok(DocumentHighlightService::document_highlights(&ctx));
+        }
+
+        Ok(None)
+    }
+
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> Result<DocumentDiagnosticReportResult> {
+        let uri = &params.text_document.uri;
+        let previous_result_id = params.previous_result_id.as_deref();
+
+        #[cfg(feature = "native")]
+        let report = DiagnosticService::pull_async(&self.state, uri, previous_result_id).await;
+
+        #[cfg(not(feature = "native"))]
+        let report = DiagnosticService::pull(&self.state, uri, previous_result_id);
+
+        Ok(DocumentDiagnosticReportResult::Report(report))
+    }
+
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> Result<WorkspaceDiagnosticReportResult> {
+        // Only open documents are reported, pulled concurrently
+        let reports = self.state.documents.uris().into_iter().map(|uri| {
+            let previous_result_id = params
+                .previous_result_ids
+                .iter()
+                .find(|previous| previous.uri == uri)
+                .map(|previous| previous.value.clone());
+            async move {
+                let version = self
+                    .state
+                    .documents
+                    .get(&uri)
+                    .map(|doc| i64::from(doc.version));
+
+                #[cfg(feature = "native")]
+                let report =
+                    DiagnosticService::pull_async(&self.state, &uri, previous_result_id.as_deref())
+                        .await;
+
+                #[cfg(not(feature = "native"))]
+                let report =
+                    DiagnosticService::pull(&self.state, &uri, previous_result_id.as_deref());
+
+                match report {
+                    DocumentDiagnosticReport::Full(report) => {
+                        WorkspaceDocumentDiagnosticReport::Full(
+                            WorkspaceFullDocumentDiagnosticReport {
+                                uri,
+                                version,
+                                full_document_diagnostic_report: report
+                                    .full_document_diagnostic_report,
+                            },
+                        )
+                    }
+                    DocumentDiagnosticReport::Unchanged(report) => {
+                        WorkspaceDocumentDiagnosticReport::Unchanged(
+                            WorkspaceUnchangedDocumentDiagnosticReport {
+                                uri,
+                                version,
+                                unchanged_document_diagnostic_report: report
+                                    .unchanged_document_diagnostic_report,
+                            },
+                        )
+                    }
+                }
+            }
+        });
+        let items = futures::future::join_all(reports).await;
+
+        Ok(WorkspaceDiagnosticReportResult::Report(
+            WorkspaceDiagnosticReport { items },
+        ))
+    }
+
+    async fn prepare_call_hierarchy(
+        &self,
+        params: CallHierarchyPrepareParams,
+    ) -> Result<Option<Vec<CallHierarchyItem>>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        let Some(doc) = self.state.documents.get(uri) else {
+            return Ok(None);
+        };
+
+        let content = doc.text();
+        let offset =
+            crate::utils::position_to_offset_str(&content, position.line, position.character);
+
+        if let Some(ctx) = IdeContext::new(&self.state, uri, offset) {
+            return Ok(CallHierarchyService::prepare(&ctx));
+        }
+
+        Ok(None)
+    }
+
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyIncomingCall>>> {
+        let uri = &params.item.uri;
+
+        let Some(doc) = self.state.documents.get(uri) else {
+            return Ok(None);
+        };
+
+        let content = doc.text();
+
+        // Resolve script callers through tsgo first (when native feature is enabled)
+        #[cfg(feature = "native")]
+        {
+            let start = params.item.selection_range.start;
+            let offset =
+                crate::utils::position_to_offset_str(&content, start.line, start.character);
+            if let Some(ctx) = IdeContext::new(&self.state, uri, offset) {
+                let tsgo_bridge = self.state.get_tsgo_bridge().await;
+                return Ok(CallHierarchyService::incoming_calls_with_tsgo(
+                    &ctx,
+                    &params.item,
+                    tsgo_bridge,
+                )
+                .await);
+            }
+        }
+
+        Ok(CallHierarchyService::incoming_calls(
+            &content,
+            uri,
+            &params.item,
+        ))
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyOutgoingCall>>> {
+        let uri = &params.item.uri;
+
+        let Some(doc) = self.state.documents.get(uri) else {
+            return Ok(None);
+        };
+
+        let content = doc.text();
+        Ok(CallHierarchyService::outgoing_calls(
+            &content,
+            uri,
+            &params.item,
+        ))
+    }
+
     #[allow(deprecated)] // DocumentSymbol.deprecated is deprecated in favor of tags
     async fn document_symbol(
         &self,
@@ -664,6 +961,12 @@ impl LanguageServer for MaestroServer {
             crate::utils::position_to_offset_str(&content, position.line, position.character);
 
         if let Some(ctx) = IdeContext::new(&self.state, uri, offset) {
+            // Components, events and provide keys are renamed across files
+            match RenameService::rename_workspace(&ctx, new_name) {
+                Ok(Some(edit)) => return Ok(Some(edit)),
+                Ok(None) => {}
+                Err(message) => return Err(tower_lsp::jsonrpc::Error::invalid_params(message)),
+            }
             return Ok(RenameService::rename(&ctx, new_name));
         }
 
@@ -732,6 +1035,35 @@ impl LanguageServer for MaestroServer {
         }
     }
 
+    async fn document_color(&self, params: DocumentColorParams) -> Result<Vec<ColorInformation>> {
+        let uri = &params.text_document.uri;
+
+        let Some(doc) = self.state.documents.get(uri) else {
+            return Ok(vec![]);
+        };
+
+        let content = doc.text();
+        Ok(ColorService::document_colors(&content, uri.path()))
+    }
+
+    async fn color_presentation(
+        &self,
+        params: ColorPresentationParams,
+    ) -> Result<Vec<ColorPresentation>> {
+        let uri = &params.text_document.uri;
+
+        let Some(doc) = self.state.documents.get(uri) else {
+            return Ok(vec![]);
+        };
+
+        let content = doc.text();
+        Ok(ColorService::color_presentations(
+            &content,
+            params.color,
+            params.range,
+        ))
+    }
+
     async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
         let uri = &params.text_document.uri;
         let range = params.range;
@@ -741,7 +1073,14 @@ impl LanguageServer for MaestroServer {
         };
 
         let content = doc.text();
-        let hints = InlayHintService::get_hints(&content, uri, range);
+
+        // Ref types are labelled from Croquis; tsgo fills the tooltip on resolve
+        let hints = InlayHintService::get_hints_with_config(
+            &content,
+            uri,
+            range,
+            self.state.inlay_hint_config(),
+        );
 
         if hints.is_empty() {
             Ok(None)
@@ -750,6 +1089,54 @@ impl LanguageServer for MaestroServer {
         }
     }
 
+    async fn inlay_hint_resolve(&self, hint: InlayHint) -> Result<InlayHint> {
+        let Some(data) = hint
+            .data
+            .as_ref()
+            .and_then(|d| serde_json::from_value::<RefTypeHintData>(d.clone()).ok())
+        else {
+            return Ok(hint);
+        };
+
+        let mut hover: Option<Hover> = None;
+        if let Some(ctx) = IdeContext::new(&self.state, &data.uri, data.offset) {
+            #[cfg(feature = "native")]
+            {
+                let tsgo_bridge = self.state.get_tsgo_bridge().await;
+                hover = HoverService::hover_with_tsgo(&ctx, tsgo_bridge).await;
+            }
+
+            #[cfg(not(feature = "native"))]
+            {
+                hover = HoverService::hover(&ctx);
+            }
+        }
+
+        Ok(InlayHintService::resolve(hint, hover))
+    }
+
+    async fn linked_editing_range(
+        &self,
+        params: LinkedEditingRangeParams,
+    ) -> Result<Option<LinkedEditingRanges>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        let Some(doc) = self.state.documents.get(uri) else {
+            return Ok(None);
+        };
+
+        let content = doc.text();
+        let offset =
+            crate::utils::position_to_offset_str(&content, position.line, position.character);
+
+        if let Some(ctx) = IdeContext::new(&self.state, uri, offset) {
+            return Ok(LinkedEditingService::linked_editing_ranges(&ctx));
+        }
+
+        Ok(None)
+    }
+
     async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
         let uri = &params.text_document.uri;
 
@@ -831,6 +1218,23 @@ impl LanguageServer for MaestroServer {
         }
     }
 
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<Option<Vec<SelectionRange>>> {
+        let uri = &params.text_document.uri;
+
+        let Some(doc) = self.state.documents.get(uri) else {
+            return Ok(None);
+        };
+
+        let content = doc.text();
+        Ok(Some(SelectionRangeService::selection_ranges(
+            &content,
+            &params.positions,
+        )))
+    }
+
     async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
         let uri = &params.text_document.uri;
 
@@ -841,8 +1245,11 @@ impl LanguageServer for MaestroServer {
         let _content = doc.text();
         #[cfg(feature = "glyph")]
         {
-            let options = self.state.get_format_options();
-            return Ok(format_document(&_content, &options));
+            let options = FormattingService::format_options(
+                &self.state.get_format_options(),
+                &params.options,
+            );
+            return Ok(FormattingService::format_document(&_content, &options));
         }
         #[cfg(not(feature = "glyph"))]
         Ok(None)
@@ -863,131 +1270,51 @@ impl LanguageServer for MaestroServer {
         let _content = doc.text();
         #[cfg(feature = "glyph")]
         {
-            let options = self.state.get_format_options();
-            return Ok(format_document(&_content, &options));
+            let options = FormattingService::format_options(
+                &self.state.get_format_options(),
+                &params.options,
+            );
+            return Ok(FormattingService::format_document(&_content, &options));
         }
         #[cfg(not(feature = "glyph"))]
         Ok(None)
     }
-}
 
-/// Format a document and return TextEdits for the LSP client.
-///
-/// Returns `Some(vec![])` if no changes needed, `Some(vec![edit])` with the
-/// full-document replacement, or `None` on formatting error.
-#[cfg(feature = "glyph")]
-fn format_document(content: &str, options: &vize_glyph::FormatOptions) -> Option<Vec<TextEdit>> {
-    let allocator = vize_glyph::Allocator::with_capacity(content.len());
-
-    let formatted = match vize_glyph::format_sfc_with_allocator(content, options, &allocator) {
-        Ok(result) => result,
-        Err(_) => return None,
-    };
-
-    if !formatted.changed {
-        return Some(vec![]);
-    }
-
-    let line_count = content.lines().count() as u32;
-    let last_line_len = content.lines().last().map_or(0, |l| l.len()) as u32;
-    Some(vec![TextEdit {
-        range: Range {
-            start: Position::new(0, 0),
-            end: Position::new(line_count, last_line_len),
-        },
-        new_text: formatted.code,
-    }])
-}
-
-#[cfg(all(test, feature = "glyph"))]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn format_document_is_idempotent() {
-        let source = "<template>\n<div>hello</div>\n</template>\n";
-        let options = vize_glyph::FormatOptions::default();
-
-        // First format
-        let result = format_document(source, &options);
-        assert!(result.is_some());
-        let edits = result.unwrap();
-        assert!(!edits.is_empty(), "expected edits on first format");
-
-        // Second format on the already-formatted output should produce no changes
-        let formatted = &edits[0].new_text;
-        let result2 = format_document(formatted, &options);
-        assert!(result2.is_some());
-        let edits2 = result2.unwrap();
-        assert!(
-            edits2.is_empty(),
-            "expected no edits on second format (idempotent)"
-        );
-    }
+    async fn on_type_formatting(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
 
-    #[test]
-    fn format_document_returns_edit_for_unformatted() {
-        // Missing indentation should trigger formatting
-        let source = "<template>\n<div>hello</div>\n</template>\n";
-        let options = vize_glyph::FormatOptions::default();
-        let result = format_document(source, &options);
-        assert!(result.is_some());
-        let edits = result.unwrap();
-        if !edits.is_empty() {
-            assert_eq!(edits.len(), 1);
-            let edit = &edits[0];
-            // Range should start at beginning of document
-            assert_eq!(edit.range.start, Position::new(0, 0));
-            // new_text should contain the formatted content
-            assert!(edit.new_text.contains("<template>"));
-        }
-    }
+        let Some(doc) = self.state.documents.get(uri) else {
+            return Ok(None);
+        };
 
-    #[test]
-    fn format_document_respects_options() {
-        let source = "<script>\nconst x = 1;\n</script>\n";
-        let mut options = vize_glyph::FormatOptions::default();
-        options.semi = false;
-        let result = format_document(source, &options);
-        assert!(result.is_some());
-        let edits = result.unwrap();
-        if !edits.is_empty() {
-            // With semi: false, semicolons should be removed
-            assert!(
-                !edits[0].new_text.contains("const x = 1;")
-                    || edits[0].new_text.contains("const x = 1\n")
+        let _content = doc.text();
+        #[cfg(feature = "glyph")]
+        {
+            let offset =
+                crate::utils::position_to_offset_str(&_content, position.line, position.character);
+            let options = FormattingService::format_options(
+                &self.state.get_format_options(),
+                &params.options,
             );
+            return Ok(OnTypeFormattingService::format_on_type(
+                &_content, offset, &params.ch, &options,
+            ));
         }
-    }
-
-    #[test]
-    fn format_document_edit_covers_full_range() {
-        let source = "<template>\n<div   class=\"a\"   id=\"b\" >\nhello\n</div>\n</template>\n";
-        let options = vize_glyph::FormatOptions::default();
-        let result = format_document(source, &options);
-        assert!(result.is_some());
-        let edits = result.unwrap();
-        if !edits.is_empty() {
-            let edit = &edits[0];
-            assert_eq!(edit.range.start, Position::new(0, 0));
-            // End line should cover the full document
-            let line_count = source.lines().count() as u32;
-            assert_eq!(edit.range.end.line, line_count);
+        #[cfg(not(feature = "glyph"))]
+        {
+            let _ = position;
+            Ok(None)
         }
     }
+}
 
-    #[test]
-    fn format_document_with_single_quote() {
-        let source = "<script>\nconst x = \"hello\";\n</script>\n";
-        let mut options = vize_glyph::FormatOptions::default();
-        options.single_quote = true;
-        let result = format_document(source, &options);
-        assert!(result.is_some());
-        let edits = result.unwrap();
-        if !edits.is_empty() {
-            assert!(edits[0].new_text.contains("'hello'"));
-        }
-    }
+#[cfg(all(test, feature = "glyph"))]
+mod tests {
+    use super::*;
 
     #[test]
     fn format_document_with_config_loaded_from_state() {
@@ -1004,7 +1331,7 @@ mod tests {
         assert!(options.single_quote);
 
         let source = "<script>\nconst x = \"hello\";\n</script>\n";
-        let result = format_document(source, &options);
+        let result = FormattingService::format_document(source, &options);
         assert!(result.is_some());
         let edits = result.unwrap();
         if !edits.is_empty() {
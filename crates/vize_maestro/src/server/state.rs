@@ -7,6 +7,7 @@ use dashmap::DashMap;
 use parking_lot::RwLock;
 use tokio::sync::OnceCell;
 use tower_lsp::lsp_types::Url;
+use vize_carton::FxHashMap;
 
 #[cfg(feature = "native")]
 use std::sync::OnceLock;
@@ -15,6 +16,8 @@ use std::sync::OnceLock;
 use vize_canon::{BatchTypeChecker, BatchTypeCheckerTrait, TsgoBridge, TsgoBridgeConfig};
 
 use crate::document::DocumentStore;
+use crate::ide::rename::WorkspaceIndex;
+use crate::ide::InlayHintConfig;
 use crate::virtual_code::{VirtualCodeGenerator, VirtualDocuments};
 
 /// Batch type check result cache.
@@ -90,9 +93,22 @@ pub struct ServerState {
     /// Flag to track if tsgo initialization has been attempted and failed
     #[cfg(feature = "native")]
     tsgo_init_failed: std::sync::atomic::AtomicBool,
+    /// Whether the client pulls diagnostics (`textDocument/diagnostic`)
+    pull_diagnostics: std::sync::atomic::AtomicBool,
+    /// Whether the client handles `workspace/diagnostic/refresh`
+    diagnostic_refresh: std::sync::atomic::AtomicBool,
+    /// Bumped when diagnostics may change without a document edit
+    diagnostic_generation: std::sync::atomic::AtomicU64,
+    /// Enabled inlay hint kinds
+    inlay_hint_config: RwLock<InlayHintConfig>,
     /// Workspace root path
-    #[cfg(feature = "native")]
     workspace_root: RwLock<Option<PathBuf>>,
+    /// `.vue` files under the workspace root (lazy scanned)
+    workspace_vue_files: RwLock<Option<Arc<Vec<PathBuf>>>>,
+    /// Cross-file index for workspace rename (lazy built)
+    workspace_index: RwLock<Option<Arc<WorkspaceIndex>>>,
+    /// Keys workspace components expose via defineExpose() (lazy built)
+    component_exposes: RwLock<Option<Arc<FxHashMap<String, Vec<String>>>>>,
     /// Batch type checker (lazy initialized, sync)
     #[cfg(feature = "native")]
     batch_checker: OnceLock<Arc<RwLock<BatchTypeChecker>>>,
@@ -120,8 +136,14 @@ impl ServerState {
             tsgo_bridge: OnceCell::new(),
             #[cfg(feature = "native")]
             tsgo_init_failed: std::sync::atomic::AtomicBool::new(false),
-            #[cfg(feature = "native")]
+            pull_diagnostics: std::sync::atomic::AtomicBool::new(false),
+            diagnostic_refresh: std::sync::atomic::AtomicBool::new(false),
+            diagnostic_generation: std::sync::atomic::AtomicU64::new(0),
+            inlay_hint_config: RwLock::new(InlayHintConfig::default()),
             workspace_root: RwLock::new(None),
+            workspace_vue_files: RwLock::new(None),
+            workspace_index: RwLock::new(None),
+            component_exposes: RwLock::new(None),
             #[cfg(feature = "native")]
             batch_checker: OnceLock::new(),
             #[cfg(feature = "native")]
@@ -130,19 +152,133 @@ impl ServerState {
     }
 
     /// Set the workspace root path.
-    #[cfg(feature = "native")]
     pub fn set_workspace_root(&self, path: PathBuf) {
         *self.workspace_root.write() = Some(path);
+        self.invalidate_workspace_files();
         // Invalidate batch cache when workspace changes
+        #[cfg(feature = "native")]
         self.batch_cache.invalidate();
     }
 
+    /// Record whether the client supports pull diagnostics.
+    pub fn set_pull_diagnostics(&self, enabled: bool) {
+        self.pull_diagnostics
+            .store(enabled, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Whether diagnostics are pulled by the client instead of pushed.
+    pub fn pull_diagnostics(&self) -> bool {
+        self.pull_diagnostics
+            .load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Record whether the client supports `workspace/diagnostic/refresh`.
+    pub fn set_diagnostic_refresh(&self, enabled: bool) {
+        self.diagnostic_refresh
+            .store(enabled, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Whether pulled diagnostics can be refreshed by the server.
+    pub fn diagnostic_refresh(&self) -> bool {
+        self.diagnostic_refresh
+            .load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Invalidate pulled diagnostics of every document.
+    ///
+    /// Called when settings or other files change, since neither bumps the
+    /// version of the affected documents.
+    pub fn bump_diagnostic_generation(&self) {
+        self.diagnostic_generation
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Current diagnostic generation, part of pull diagnostic result ids.
+    pub fn diagnostic_generation(&self) -> u64 {
+        self.diagnostic_generation
+            .load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Set which inlay hint kinds are enabled.
+    pub fn set_inlay_hint_config(&self, config: InlayHintConfig) {
+        *self.inlay_hint_config.write() = config;
+    }
+
+    /// Get which inlay hint kinds are enabled.
+    pub fn inlay_hint_config(&self) -> InlayHintConfig {
+        *self.inlay_hint_config.read()
+    }
+
     /// Get the workspace root path.
-    #[cfg(feature = "native")]
     pub fn get_workspace_root(&self) -> Option<PathBuf> {
         self.workspace_root.read().clone()
     }
 
+    /// Get all `.vue` files under the workspace root.
+    ///
+    /// The list is scanned on first use and cached until invalidated.
+    pub fn workspace_vue_files(&self) -> Arc<Vec<PathBuf>> {
+        if let Some(files) = self.workspace_vue_files.read().as_ref() {
+            return Arc::clone(files);
+        }
+
+        let mut files = Vec::new();
+        if let Some(root) = self.get_workspace_root() {
+            collect_vue_files(&root, &mut files);
+            files.sort();
+        }
+        let files = Arc::new(files);
+        *self.workspace_vue_files.write() = Some(Arc::clone(&files));
+        files
+    }
+
+    /// Drop the cached workspace file list so the next request rescans it.
+    pub fn invalidate_workspace_files(&self) {
+        *self.workspace_vue_files.write() = None;
+        self.invalidate_workspace_index();
+        self.invalidate_component_exposes();
+    }
+
+    /// Get the cross-file index of the workspace.
+    ///
+    /// The index is built with `build` on first use and cached until invalidated.
+    pub fn workspace_index(&self, build: impl FnOnce() -> WorkspaceIndex) -> Arc<WorkspaceIndex> {
+        if let Some(index) = self.workspace_index.read().as_ref() {
+            return Arc::clone(index);
+        }
+
+        let index = Arc::new(build());
+        *self.workspace_index.write() = Some(Arc::clone(&index));
+        index
+    }
+
+    /// Drop the cached cross-file index after a document changed.
+    pub fn invalidate_workspace_index(&self) {
+        *self.workspace_index.write() = None;
+    }
+
+    /// Get the keys workspace components expose via defineExpose(), by component name.
+    ///
+    /// Built with `build` on first use and cached until a file is saved or
+    /// closed, so unsaved edits to other documents are picked up on save.
+    pub fn component_exposes(
+        &self,
+        build: impl FnOnce() -> FxHashMap<String, Vec<String>>,
+    ) -> Arc<FxHashMap<String, Vec<String>>> {
+        if let Some(exposes) = self.component_exposes.read().as_ref() {
+            return Arc::clone(exposes);
+        }
+
+        let exposes = Arc::new(build());
+        *self.component_exposes.write() = Some(Arc::clone(&exposes));
+        exposes
+    }
+
+    /// Drop the cached component exposes after a file was saved or closed.
+    pub fn invalidate_component_exposes(&self) {
+        *self.component_exposes.write() = None;
+    }
+
     /// Get or initialize the batch type checker.
     #[cfg(feature = "native")]
     pub fn get_batch_checker(&self) -> Option<Arc<RwLock<BatchTypeChecker>>> {
@@ -337,6 +473,31 @@ impl ServerState {
     }
 }
 
+/// Directories never scanned for workspace components.
+const IGNORED_DIRS: &[&str] = &["node_modules", "dist", "target"];
+
+/// Recursively collect `.vue` files, skipping hidden and ignored directories.
+fn collect_vue_files(dir: &std::path::Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if !name.starts_with('.') && !IGNORED_DIRS.contains(&name.as_ref()) {
+                collect_vue_files(&path, files);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "vue") {
+            files.push(path);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -436,4 +597,21 @@ mod tests {
         let opts = state.get_format_options();
         assert_eq!(opts.print_width, 100);
     }
+
+    #[test]
+    fn workspace_vue_files_skips_ignored_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let components = dir.path().join("src/components");
+        let deps = dir.path().join("node_modules/lib");
+        std::fs::create_dir_all(&components).unwrap();
+        std::fs::create_dir_all(&deps).unwrap();
+        std::fs::write(components.join("MyButton.vue"), "<template />").unwrap();
+        std::fs::write(components.join("util.ts"), "").unwrap();
+        std::fs::write(deps.join("Dep.vue"), "<template />").unwrap();
+
+        let state = ServerState::new();
+        state.set_workspace_root(dir.path().to_path_buf());
+        let files = state.workspace_vue_files();
+        assert_eq!(files.as_slice(), &[components.join("MyButton.vue")]);
+    }
 }
@@ -85,17 +85,14 @@ pub struct VirtualDocument {
     pub language: VirtualLanguage,
     /// Source mappings for position translation
     pub source_map: SourceMap,
+    /// Byte offset of each line start in `content`
+    line_starts: Vec<u32>,
 }
 
 impl VirtualDocument {
     /// Create a new virtual document.
     pub fn new(uri: String, content: String, language: VirtualLanguage) -> Self {
-        Self {
-            uri,
-            content,
-            language,
-            source_map: SourceMap::new(),
-        }
+        Self::with_source_map(uri, content, language, SourceMap::new())
     }
 
     /// Create with source mappings.
@@ -105,13 +102,45 @@ impl VirtualDocument {
         language: VirtualLanguage,
         mappings: Vec<SourceMapping>,
     ) -> Self {
+        Self::with_source_map(uri, content, language, SourceMap::from_mappings(mappings))
+    }
+
+    /// Create with a prepared source map.
+    pub fn with_source_map(
+        uri: String,
+        content: String,
+        language: VirtualLanguage,
+        source_map: SourceMap,
+    ) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(i, _)| i as u32 + 1))
+            .collect();
         Self {
             uri,
             content,
             language,
-            source_map: SourceMap::from_mappings(mappings),
+            source_map,
+            line_starts,
         }
     }
+
+    /// Convert a byte offset in the generated content to a (line, character) position.
+    ///
+    /// Characters are counted in UTF-16 code units, as LSP positions are.
+    /// Looks the line up in the line index, so only the target line is scanned.
+    pub fn offset_to_position(&self, offset: usize) -> (u32, u32) {
+        let offset = offset.min(self.content.len());
+        let line = self
+            .line_starts
+            .partition_point(|&start| start as usize <= offset)
+            .saturating_sub(1);
+        let line_start = self.line_starts[line] as usize;
+        let character = self
+            .content
+            .get(line_start..offset)
+            .map_or(0, |text| text.encode_utf16().count());
+        (line as u32, character as u32)
+    }
 }
 
 /// Collection of virtual documents for an SFC.
@@ -211,3 +240,24 @@ impl From<&vize_relief::SourceLocation> for SourceRange {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_to_position() {
+        let doc = VirtualDocument::new(
+            String::new(),
+            "const a = 1\nfoo(é, b)\n😀(x)\n".to_string(),
+            VirtualLanguage::Template,
+        );
+        assert_eq!(doc.offset_to_position(0), (0, 0));
+        assert_eq!(doc.offset_to_position(12), (1, 0));
+        // `é` is two bytes but one UTF-16 code unit
+        assert_eq!(doc.offset_to_position(18), (1, 5));
+        // `😀` is four bytes and two UTF-16 code units
+        assert_eq!(doc.offset_to_position(27), (2, 2));
+        assert_eq!(doc.offset_to_position(100), (3, 0));
+    }
+}
@@ -74,16 +74,16 @@ impl ScriptCodeGenerator {
         let mut source_map = SourceMap::from_mappings(self.mappings.clone());
         source_map.set_block_offset(self.block_offset);
 
-        VirtualDocument {
-            uri: String::new(), // Will be set by generator
-            content: self.output.clone(),
-            language: if is_setup {
+        VirtualDocument::with_source_map(
+            String::new(), // Will be set by generator
+            self.output.clone(),
+            if is_setup {
                 VirtualLanguage::ScriptSetup
             } else {
                 VirtualLanguage::Script
             },
             source_map,
-        }
+        )
     }
 
     /// Generate with binding exports for template usage.
@@ -143,16 +143,16 @@ impl ScriptCodeGenerator {
         let mut source_map = SourceMap::from_mappings(self.mappings.clone());
         source_map.set_block_offset(self.block_offset);
 
-        VirtualDocument {
-            uri: String::new(),
-            content: self.output.clone(),
-            language: if is_setup {
+        VirtualDocument::with_source_map(
+            String::new(),
+            self.output.clone(),
+            if is_setup {
                 VirtualLanguage::ScriptSetup
             } else {
                 VirtualLanguage::Script
             },
             source_map,
-        }
+        )
     }
 
     fn write(&mut self, s: &str) {
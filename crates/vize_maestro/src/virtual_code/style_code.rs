@@ -50,12 +50,12 @@ impl StyleCodeGenerator {
         // Determine the language based on the lang attribute
         let extension = style.lang.as_ref().map(|l| l.as_ref()).unwrap_or("css");
 
-        VirtualDocument {
-            uri: format!("__style_{}.{}", index, extension),
-            content: content.to_string(),
-            language: VirtualLanguage::Style,
+        VirtualDocument::with_source_map(
+            format!("__style_{}.{}", index, extension),
+            content.to_string(),
+            VirtualLanguage::Style,
             source_map,
-        }
+        )
     }
 
     /// Generate with scoped CSS transformation info.
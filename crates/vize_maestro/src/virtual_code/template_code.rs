@@ -65,12 +65,12 @@ impl TemplateCodeGenerator {
         let mut source_map = SourceMap::from_mappings(self.mappings.clone());
         source_map.set_block_offset(self.block_offset);
 
-        VirtualDocument {
-            uri: String::new(), // Will be set by generator
-            content: self.output.clone(),
-            language: VirtualLanguage::Template,
+        VirtualDocument::with_source_map(
+            String::new(), // Will be set by generator
+            self.output.clone(),
+            VirtualLanguage::Template,
             source_map,
-        }
+        )
     }
 
     /// Visit child nodes.
@@ -0,0 +1,123 @@
+//! Shared helpers for LSP golden fixture tests.
+
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+/// Run every `tests/lsp/<feature>/<name>.vue` fixture through `request`.
+///
+/// Each fixture marks the cursor with `|`; the expected response is stored
+/// next to it as `<name>.json` (`null` for no response).
+pub fn run_fixtures(feature: &str, request: impl Fn(&str, usize) -> serde_json::Value) {
+    for fixture in fixtures(feature) {
+        let source = std::fs::read_to_string(&fixture).unwrap();
+        let offset = source
+            .find('|')
+            .unwrap_or_else(|| panic!("{}: missing `|` cursor marker", fixture.display()));
+        let content = format!("{}{}", &source[..offset], &source[offset + 1..]);
+
+        assert_expected(&fixture, request(&content, offset));
+    }
+}
+
+/// Run every `tests/lsp/<feature>/<name>.vue` fixture through a
+/// document-wide `request` (no cursor marker).
+pub fn run_document_fixtures(feature: &str, request: impl Fn(&str) -> serde_json::Value) {
+    for fixture in fixtures(feature) {
+        let content = std::fs::read_to_string(&fixture).unwrap();
+        assert_expected(&fixture, request(&content));
+    }
+}
+
+/// A `.vue` file of a fixture project: (path relative to the project, content).
+pub type ProjectFile = (String, String);
+
+/// Run every `tests/lsp/<feature>/<project>/` fixture project through `request`.
+///
+/// Exactly one `.vue` file of the project marks the cursor with `|`; the
+/// request receives all files, the cursor file's relative path and the
+/// offset. The expected response is stored as `<project>.json`.
+pub fn run_project_fixtures(
+    feature: &str,
+    request: impl Fn(&[ProjectFile], &str, usize) -> serde_json::Value,
+) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/lsp")
+        .join(feature);
+    let mut projects: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    projects.sort();
+    assert!(
+        !projects.is_empty(),
+        "no fixture projects in {}",
+        dir.display()
+    );
+
+    for project in projects {
+        let mut paths = Vec::new();
+        collect_vue_files(&project, &mut paths);
+        paths.sort();
+
+        let mut files = Vec::with_capacity(paths.len());
+        let mut cursor = None;
+        for path in paths {
+            let relative = path
+                .strip_prefix(&project)
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/");
+            let mut content = std::fs::read_to_string(&path).unwrap();
+            if let Some(offset) = content.find('|') {
+                content.remove(offset);
+                cursor = Some((relative.clone(), offset));
+            }
+            files.push((relative, content));
+        }
+
+        let (cursor_path, offset) =
+            cursor.unwrap_or_else(|| panic!("{}: missing `|` cursor marker", project.display()));
+        assert_expected(&project, request(&files, &cursor_path, offset));
+    }
+}
+
+fn collect_vue_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_vue_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "vue") {
+            files.push(path);
+        }
+    }
+}
+
+fn fixtures(feature: &str) -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/lsp")
+        .join(feature);
+    let mut fixtures: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "vue"))
+        .collect();
+    fixtures.sort();
+    assert!(!fixtures.is_empty(), "no fixtures in {}", dir.display());
+    fixtures
+}
+
+fn assert_expected(fixture: &Path, actual: serde_json::Value) {
+    let expected_path = fixture.with_extension("json");
+    let expected: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&expected_path).unwrap()).unwrap();
+
+    assert_eq!(
+        actual,
+        expected,
+        "{}: response mismatch\nactual: {}",
+        fixture.display(),
+        serde_json::to_string_pretty(&actual).unwrap()
+    );
+}
@@ -0,0 +1,25 @@
+//! Golden tests for workspace component auto-import completions.
+
+mod common;
+
+use std::path::PathBuf;
+
+use tower_lsp::lsp_types::Url;
+use vize_maestro::ide::CompletionService;
+
+#[test]
+fn auto_import_fixtures() {
+    let uri = Url::parse("file:///workspace/src/App.vue").unwrap();
+    let files = vec![
+        PathBuf::from("/workspace/src/App.vue"),
+        PathBuf::from("/workspace/src/components/MyButton.vue"),
+    ];
+
+    common::run_fixtures("auto_import", |content, offset| {
+        let item = CompletionService::component_import_completions(content, &uri, offset, &files)
+            .into_iter()
+            .find(|item| item.label == "MyButton");
+        let resolved = item.map(|item| CompletionService::resolve(item, content));
+        serde_json::to_value(resolved).unwrap()
+    });
+}
@@ -0,0 +1,19 @@
+//! Golden tests for `textDocument/prepareCallHierarchy` and `callHierarchy/incomingCalls`.
+
+mod common;
+
+use tower_lsp::lsp_types::Url;
+use vize_maestro::ide::CallHierarchyService;
+
+#[test]
+fn call_hierarchy_fixtures() {
+    let uri = Url::parse("file:///Fixture.vue").unwrap();
+    common::run_fixtures("call_hierarchy", |content, offset| {
+        let items = CallHierarchyService::prepare_for_sfc(content, &uri, offset).unwrap();
+        let incoming = CallHierarchyService::incoming_calls(content, &uri, &items[0]);
+        serde_json::json!({
+            "prepare": items,
+            "incoming": incoming,
+        })
+    });
+}
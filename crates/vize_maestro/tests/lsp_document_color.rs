@@ -0,0 +1,13 @@
+//! Golden tests for `textDocument/documentColor`.
+
+mod common;
+
+use vize_maestro::ide::ColorService;
+
+#[test]
+fn document_color_fixtures() {
+    common::run_document_fixtures("document_color", |content| {
+        let colors = ColorService::document_colors(content, "Fixture.vue");
+        serde_json::to_value(colors).unwrap()
+    });
+}
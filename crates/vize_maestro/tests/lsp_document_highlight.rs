@@ -0,0 +1,14 @@
+//! Golden tests for `textDocument/documentHighlight`.
+
+mod common;
+
+use vize_maestro::ide::DocumentHighlightService;
+
+#[test]
+fn document_highlight_fixtures() {
+    common::run_fixtures("document_highlight", |content, offset| {
+        let highlights =
+            DocumentHighlightService::document_highlights_for_sfc(content, "Fixture.vue", offset);
+        serde_json::to_value(highlights).unwrap()
+    });
+}
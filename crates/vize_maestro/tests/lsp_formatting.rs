@@ -0,0 +1,23 @@
+//! Golden tests for `textDocument/formatting`.
+
+#![cfg(feature = "glyph")]
+
+mod common;
+
+use tower_lsp::lsp_types::FormattingOptions;
+use vize_maestro::ide::FormattingService;
+
+#[test]
+fn formatting_fixtures() {
+    // Client indentation (4 spaces) overrides the 2-space default
+    let client = FormattingOptions {
+        tab_size: 4,
+        insert_spaces: true,
+        ..Default::default()
+    };
+    let options = FormattingService::format_options(&vize_glyph::FormatOptions::default(), &client);
+    common::run_document_fixtures("formatting", |content| {
+        let edits = FormattingService::format_document(content, &options);
+        serde_json::to_value(edits).unwrap()
+    });
+}
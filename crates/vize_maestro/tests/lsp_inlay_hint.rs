@@ -0,0 +1,33 @@
+//! Golden tests for `textDocument/inlayHint`.
+
+mod common;
+
+use tower_lsp::lsp_types::{InlayHintLabel, Position, Range, Url};
+use vize_maestro::ide::InlayHintService;
+
+#[test]
+fn inlay_hint_fixtures() {
+    common::run_document_fixtures("inlay_hint", |content| {
+        let uri = Url::parse("file:///Fixture.vue").unwrap();
+        let range = Range {
+            start: Position {
+                line: 0,
+                character: 0,
+            },
+            end: Position {
+                line: u32::MAX,
+                character: 0,
+            },
+        };
+        let hints: Vec<serde_json::Value> = InlayHintService::get_hints(content, &uri, range)
+            .into_iter()
+            .map(|hint| {
+                let InlayHintLabel::String(label) = hint.label else {
+                    panic!("unexpected label parts");
+                };
+                serde_json::json!({ "position": hint.position, "label": label })
+            })
+            .collect();
+        serde_json::to_value(hints).unwrap()
+    });
+}
@@ -0,0 +1,14 @@
+//! Golden tests for `textDocument/linkedEditingRange`.
+
+mod common;
+
+use vize_maestro::ide::LinkedEditingService;
+
+#[test]
+fn linked_editing_fixtures() {
+    common::run_fixtures("linked_editing", |content, offset| {
+        let ranges =
+            LinkedEditingService::linked_editing_ranges_for_sfc(content, "Fixture.vue", offset);
+        serde_json::to_value(ranges).unwrap()
+    });
+}
@@ -0,0 +1,23 @@
+//! Golden tests for `textDocument/onTypeFormatting`.
+
+#![cfg(feature = "glyph")]
+
+mod common;
+
+use tower_lsp::lsp_types::FormattingOptions;
+use vize_maestro::ide::{FormattingService, OnTypeFormattingService};
+
+#[test]
+fn on_type_formatting_fixtures() {
+    // Each fixture's cursor sits right after a typed `>`
+    let client = FormattingOptions {
+        tab_size: 2,
+        insert_spaces: true,
+        ..Default::default()
+    };
+    let options = FormattingService::format_options(&vize_glyph::FormatOptions::default(), &client);
+    common::run_fixtures("on_type_formatting", |content, offset| {
+        let edits = OnTypeFormattingService::format_on_type(content, offset, ">", &options);
+        serde_json::to_value(edits).unwrap()
+    });
+}
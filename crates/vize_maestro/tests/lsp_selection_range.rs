@@ -0,0 +1,13 @@
+//! Golden tests for `textDocument/selectionRange`.
+
+mod common;
+
+use vize_maestro::ide::SelectionRangeService;
+
+#[test]
+fn selection_range_fixtures() {
+    common::run_fixtures("selection_range", |content, offset| {
+        let range = SelectionRangeService::selection_range(content, offset);
+        serde_json::to_value(range).unwrap()
+    });
+}
@@ -0,0 +1,13 @@
+//! Golden tests for `textDocument/signatureHelp`.
+
+mod common;
+
+use vize_maestro::ide::SignatureHelpService;
+
+#[test]
+fn signature_help_fixtures() {
+    common::run_fixtures("signature_help", |content, offset| {
+        let help = SignatureHelpService::signature_help_for_sfc(content, "Fixture.vue", offset);
+        serde_json::to_value(help).unwrap()
+    });
+}
@@ -0,0 +1,22 @@
+//! Golden tests for `textDocument/rename` across workspace files.
+
+mod common;
+
+use tower_lsp::lsp_types::Url;
+use vize_maestro::ide::RenameService;
+
+#[test]
+fn workspace_rename_fixtures() {
+    common::run_project_fixtures("workspace_rename", |files, cursor_path, offset| {
+        let uri = |path: &str| Url::parse(&format!("file:///project/{}", path)).unwrap();
+        let files: Vec<_> = files
+            .iter()
+            .map(|(path, content)| (uri(path), content.clone()))
+            .collect();
+
+        let edit =
+            RenameService::rename_across_files(&files, &uri(cursor_path), offset, "BaseButton")
+                .unwrap();
+        serde_json::to_value(edit).unwrap()
+    });
+}
@@ -0,0 +1,265 @@
+//! Programmatic linter configuration.
+//!
+//! [`LinterBuilder`] assembles a [`Linter`] from a base rule set and a map of
+//! rule name → [`RuleSeverity`], for embedders (e.g. bundler plugins) that
+//! configure rules from code rather than from a config file.
+
+use crate::diagnostic::{HelpLevel, Severity};
+use crate::linter::Linter;
+use crate::rule::{Rule, RuleRegistry};
+use serde::{Deserialize, Serialize};
+use vize_carton::i18n::Locale;
+use vize_carton::FxHashMap;
+
+/// Configured severity of a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    /// Disable the rule
+    Off,
+    /// Report diagnostics as warnings
+    #[serde(alias = "warn")]
+    Warning,
+    /// Report diagnostics as errors
+    Error,
+}
+
+impl From<Severity> for RuleSeverity {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Error => Self::Error,
+            Severity::Warning => Self::Warning,
+        }
+    }
+}
+
+/// Error returned by [`LinterBuilder::build`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LinterBuildError {
+    /// Rule names that match no built-in or registered rule
+    #[error("unknown lint rule(s): {}", .0.join(", "))]
+    UnknownRules(Vec<String>),
+}
+
+/// Builder for a [`Linter`] with per-rule severities.
+///
+/// Rules start from a base registry ([`RuleRegistry::with_recommended`] unless
+/// [`Self::registry`] is called) with their default severities:
+/// - `Off` removes a rule from the active set
+/// - `Warning`/`Error` overrides the severity, enabling the rule if the base
+///   set does not include it (e.g. opt-in rules from [`RuleRegistry::with_all`])
+///
+/// ```ignore
+/// let linter = Linter::builder()
+///     .rule("vue/no-v-html", RuleSeverity::Off)
+///     .rule("vue/require-v-for-key", RuleSeverity::Error)
+///     .build()?;
+/// ```
+pub struct LinterBuilder {
+    base: Option<RuleRegistry>,
+    /// Rule settings in insertion order (later settings replace earlier ones)
+    settings: Vec<(String, RuleSeverity)>,
+    locale: Locale,
+    help_level: HelpLevel,
+}
+
+impl LinterBuilder {
+    /// Create a builder based on the recommended rules
+    pub fn new() -> Self {
+        Self {
+            base: None,
+            settings: Vec::new(),
+            locale: Locale::default(),
+            help_level: HelpLevel::default(),
+        }
+    }
+
+    /// Use a different base rule set
+    pub fn registry(mut self, registry: RuleRegistry) -> Self {
+        self.base = Some(registry);
+        self
+    }
+
+    /// Configure a single rule
+    pub fn rule(mut self, name: impl Into<String>, severity: RuleSeverity) -> Self {
+        let name = name.into();
+        match self.settings.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => *existing = severity,
+            None => self.settings.push((name, severity)),
+        }
+        self
+    }
+
+    /// Configure several rules
+    pub fn rules<N: Into<String>>(
+        mut self,
+        rules: impl IntoIterator<Item = (N, RuleSeverity)>,
+    ) -> Self {
+        for (name, severity) in rules {
+            self = self.rule(name, severity);
+        }
+        self
+    }
+
+    /// Set the locale for i18n messages
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Set the help display level
+    pub fn help_level(mut self, level: HelpLevel) -> Self {
+        self.help_level = level;
+        self
+    }
+
+    /// Assemble the linter.
+    ///
+    /// Fails if any configured rule name matches no rule in the base set or
+    /// in the built-in catalog.
+    pub fn build(self) -> Result<Linter, LinterBuildError> {
+        let base = self
+            .base
+            .unwrap_or_else(RuleRegistry::with_recommended)
+            .into_rules();
+        let mut catalog = RuleRegistry::with_all().into_rules();
+
+        let has_rule =
+            |rules: &[Box<dyn Rule>], name: &str| rules.iter().any(|rule| rule.meta().name == name);
+
+        let unknown: Vec<String> = self
+            .settings
+            .iter()
+            .filter(|(name, _)| !has_rule(&base, name) && !has_rule(&catalog, name))
+            .map(|(name, _)| name.clone())
+            .collect();
+        if !unknown.is_empty() {
+            return Err(LinterBuildError::UnknownRules(unknown));
+        }
+
+        let setting = |name: &str| {
+            self.settings
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, severity)| *severity)
+        };
+
+        let mut registry = RuleRegistry::new();
+        for rule in base {
+            if setting(rule.meta().name) != Some(RuleSeverity::Off) {
+                registry.register(rule);
+            }
+        }
+
+        let mut severities = FxHashMap::default();
+        for (name, severity) in &self.settings {
+            let severity = match severity {
+                RuleSeverity::Off => continue,
+                RuleSeverity::Warning => Severity::Warning,
+                RuleSeverity::Error => Severity::Error,
+            };
+            if !has_rule(registry.rules(), name) {
+                if let Some(index) = catalog.iter().position(|rule| rule.meta().name == name) {
+                    registry.register(catalog.swap_remove(index));
+                }
+            }
+            severities.insert(name.clone(), severity);
+        }
+
+        Ok(Linter::with_registry(registry)
+            .with_locale(self.locale)
+            .with_help_level(self.help_level)
+            .with_rule_severities(severities))
+    }
+}
+
+impl Default for LinterBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_overrides_severities() {
+        let linter = Linter::builder()
+            .rule("vue/no-v-html", RuleSeverity::Off)
+            .rule("vue/require-v-for-key", RuleSeverity::Error)
+            .build()
+            .unwrap();
+
+        assert_eq!(linter.rule_severity("vue/no-v-html"), None);
+        assert_eq!(
+            linter.rule_severity("vue/require-v-for-key"),
+            Some(Severity::Error)
+        );
+        // Unconfigured rules keep their defaults
+        assert_eq!(
+            linter.rule_severity("vue/valid-v-for"),
+            Some(Severity::Error)
+        );
+
+        let result = linter.lint_template(
+            r#"<div v-html="raw"></div><p v-for="i in items">{{ i }}</p>"#,
+            "test.vue",
+        );
+        assert!(result
+            .diagnostics
+            .iter()
+            .all(|d| d.rule_name != "vue/no-v-html"));
+        assert!(result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule_name == "vue/require-v-for-key" && d.severity == Severity::Error));
+    }
+
+    #[test]
+    fn test_builder_downgrades_to_warning() {
+        let linter = Linter::builder()
+            .rule("vue/require-v-for-key", RuleSeverity::Warning)
+            .build()
+            .unwrap();
+
+        let result = linter.lint_template(r#"<p v-for="i in items">{{ i }}</p>"#, "test.vue");
+        let diag = result
+            .diagnostics
+            .iter()
+            .find(|d| d.rule_name == "vue/require-v-for-key")
+            .unwrap();
+        assert_eq!(diag.severity, Severity::Warning);
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_builder_enables_opt_in_rule() {
+        let base = Linter::new();
+        assert_eq!(base.rule_severity("vue/no-inline-style"), None);
+
+        let linter = Linter::builder()
+            .rule("vue/no-inline-style", RuleSeverity::Error)
+            .build()
+            .unwrap();
+        assert_eq!(
+            linter.rule_severity("vue/no-inline-style"),
+            Some(Severity::Error)
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_unknown_rules() {
+        let result = Linter::builder()
+            .rule("vue/no-such-rule", RuleSeverity::Warning)
+            .rule("vue/no-v-html", RuleSeverity::Off)
+            .build();
+
+        assert_eq!(
+            result.err(),
+            Some(LinterBuildError::UnknownRules(vec![
+                "vue/no-such-rule".to_string()
+            ]))
+        );
+    }
+}
@@ -104,6 +104,8 @@ pub struct LintContext<'a> {
     enabled_rules: Option<FxHashSet<String>>,
     /// Optional semantic analysis from croquis
     analysis: Option<&'a Croquis>,
+    /// Offset of the analyzed script content within `source`
+    script_offset: u32,
     /// SSR mode for linting
     ssr_mode: SsrMode,
     /// Help display level
@@ -112,6 +114,8 @@ pub struct LintContext<'a> {
     expected_error_lines: FxHashSet<u32>,
     /// Severity overrides from `@vize:level(...)` keyed by next-line number
     severity_overrides: FxHashMap<u32, DirectiveSeverity>,
+    /// Per-rule severities configured on the linter
+    rule_severities: Option<&'a FxHashMap<String, Severity>>,
 }
 
 impl<'a> LintContext<'a> {
@@ -150,10 +154,12 @@ impl<'a> LintContext<'a> {
             line_offsets: Self::compute_line_offsets(source),
             enabled_rules: None,
             analysis: None,
+            script_offset: 0,
             ssr_mode: SsrMode::default(),
             help_level: HelpLevel::default(),
             expected_error_lines: FxHashSet::default(),
             severity_overrides: FxHashMap::default(),
+            rule_severities: None,
         }
     }
 
@@ -181,10 +187,12 @@ impl<'a> LintContext<'a> {
             line_offsets: Self::compute_line_offsets(source),
             enabled_rules: None,
             analysis: Some(analysis),
+            script_offset: 0,
             ssr_mode: SsrMode::default(),
             help_level: HelpLevel::default(),
             expected_error_lines: FxHashSet::default(),
             severity_overrides: FxHashMap::default(),
+            rule_severities: None,
         }
     }
 
@@ -200,6 +208,21 @@ impl<'a> LintContext<'a> {
         self.analysis
     }
 
+    /// Set where the analyzed script content starts within the source.
+    ///
+    /// Croquis offsets are relative to the script block; rules add this to
+    /// report and fix script locations in the linted source.
+    #[inline]
+    pub fn set_script_offset(&mut self, offset: u32) {
+        self.script_offset = offset;
+    }
+
+    /// Offset of the analyzed script content within the source
+    #[inline]
+    pub fn script_offset(&self) -> u32 {
+        self.script_offset
+    }
+
     /// Check if semantic analysis is available
     #[inline]
     pub fn has_analysis(&self) -> bool {
@@ -245,6 +268,16 @@ impl<'a> LintContext<'a> {
         self.enabled_rules = enabled;
     }
 
+    /// Set per-rule severities
+    ///
+    /// Diagnostics from rules in the map are reported with the given severity
+    /// instead of the one chosen by the rule. `@vize:level(...)` comments still
+    /// take precedence.
+    #[inline]
+    pub fn set_rule_severities(&mut self, severities: &'a FxHashMap<String, Severity>) {
+        self.rule_severities = Some(severities);
+    }
+
     /// Check if a rule is enabled
     #[inline]
     pub fn is_rule_enabled(&self, rule_name: &str) -> bool {
@@ -324,6 +357,14 @@ impl<'a> LintContext<'a> {
             return;
         }
 
+        // Apply configured per-rule severity
+        if let Some(severity) = self
+            .rule_severities
+            .and_then(|severities| severities.get(diagnostic.rule_name))
+        {
+            diagnostic.severity = *severity;
+        }
+
         // Apply @vize:level severity override
         if let Some(override_severity) = self.severity_overrides.remove(&line) {
             match override_severity {
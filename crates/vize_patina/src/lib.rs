@@ -59,6 +59,9 @@
 //! - `vue/v-bind-style` - Enforce v-bind directive style (shorthand or longform)
 //! - `vue/v-on-style` - Enforce v-on directive style (shorthand or longform)
 //!
+//! ### Recommended Rules (opt-in, default off)
+//! - `vue/no-multiple-template-root` - Disallow multiple template roots (Vue 2 compat)
+//!
 //! ### Vapor Migration Rules (based on Vue 3.6.0-beta.1)
 //!
 //! Template rules:
@@ -83,6 +86,7 @@
 //! - `script/prefer-import-from-vue` - Prefer importing from 'vue' instead of internal packages
 //! - `script/no-internal-imports` - Disallow importing from Vue internal modules
 
+mod builder;
 mod context;
 mod diagnostic;
 mod linter;
@@ -92,6 +96,7 @@ pub mod rules;
 pub mod telegraph;
 mod visitor;
 
+pub use builder::{LinterBuildError, LinterBuilder, RuleSeverity};
 pub use context::LintContext;
 pub use diagnostic::{
     render_help, Fix, HelpLevel, HelpRenderTarget, LintDiagnostic, LintSummary, Severity, TextEdit,
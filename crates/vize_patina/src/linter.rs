@@ -2,13 +2,17 @@
 //!
 //! High-performance Vue template linter with arena allocation.
 
+use crate::builder::LinterBuilder;
 use crate::context::LintContext;
-use crate::diagnostic::{HelpLevel, LintDiagnostic, LintSummary};
+use crate::diagnostic::{HelpLevel, LintDiagnostic, LintSummary, Severity};
 use crate::rule::RuleRegistry;
 use crate::visitor::LintVisitor;
 use vize_armature::Parser;
+use vize_atelier_sfc::{parse_sfc, SfcParseOptions};
 use vize_carton::i18n::Locale;
-use vize_carton::{Allocator, FxHashSet};
+use vize_carton::{Allocator, FxHashMap, FxHashSet};
+use vize_croquis::{Analyzer, AnalyzerOptions, Croquis};
+use vize_relief::ast::RootNode;
 
 /// Lint result for a single file
 #[derive(Debug, Clone)]
@@ -53,6 +57,8 @@ pub struct Linter {
     enabled_rules: Option<FxHashSet<String>>,
     /// Help display level
     help_level: HelpLevel,
+    /// Per-rule severity overrides
+    rule_severities: FxHashMap<String, Severity>,
 }
 
 impl Linter {
@@ -68,6 +74,7 @@ impl Linter {
             locale: Locale::default(),
             enabled_rules: None,
             help_level: HelpLevel::default(),
+            rule_severities: FxHashMap::default(),
         }
     }
 
@@ -80,9 +87,16 @@ impl Linter {
             locale: Locale::default(),
             enabled_rules: None,
             help_level: HelpLevel::default(),
+            rule_severities: FxHashMap::default(),
         }
     }
 
+    /// Create a builder for configuring rules and their severities from code
+    #[inline]
+    pub fn builder() -> LinterBuilder {
+        LinterBuilder::new()
+    }
+
     /// Set the initial allocator capacity
     #[inline]
     pub fn with_capacity(mut self, capacity: usize) -> Self {
@@ -114,6 +128,13 @@ impl Linter {
         self
     }
 
+    /// Set per-rule severity overrides
+    #[inline]
+    pub(crate) fn with_rule_severities(mut self, severities: FxHashMap<String, Severity>) -> Self {
+        self.rule_severities = severities;
+        self
+    }
+
     /// Get the current locale
     #[inline]
     pub fn locale(&self) -> Locale {
@@ -129,6 +150,26 @@ impl Linter {
         }
     }
 
+    /// Get the effective severity of a rule
+    ///
+    /// Returns `None` if the rule is not registered or not enabled.
+    pub fn rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        if !self.is_rule_enabled(rule_name) {
+            return None;
+        }
+        let rule = self
+            .registry
+            .rules()
+            .iter()
+            .find(|rule| rule.meta().name == rule_name)?;
+        Some(
+            self.rule_severities
+                .get(rule_name)
+                .copied()
+                .unwrap_or(rule.meta().default_severity),
+        )
+    }
+
     /// Lint a Vue template source
     #[inline]
     pub fn lint_template(&self, source: &str, filename: &str) -> LintResult {
@@ -150,14 +191,36 @@ impl Linter {
         let parser = Parser::new(allocator.as_bump(), source);
         let (root, _parse_errors) = parser.parse();
 
+        self.lint_root(allocator, &root, source, filename, None)
+    }
+
+    /// Run the rules over a parsed template.
+    ///
+    /// `analysis` is the script analysis with the offset of the analyzed
+    /// script content within `source`.
+    fn lint_root<'a>(
+        &self,
+        allocator: &'a Allocator,
+        root: &RootNode<'a>,
+        source: &'a str,
+        filename: &'a str,
+        analysis: Option<(&'a Croquis, u32)>,
+    ) -> LintResult {
         // Create lint context with locale, help level, and enabled rules filter
         let mut ctx = LintContext::with_locale(allocator, source, filename, self.locale);
         ctx.set_enabled_rules(self.enabled_rules.clone());
         ctx.set_help_level(self.help_level);
+        if !self.rule_severities.is_empty() {
+            ctx.set_rule_severities(&self.rule_severities);
+        }
+        if let Some((analysis, script_offset)) = analysis {
+            ctx.set_analysis(analysis);
+            ctx.set_script_offset(script_offset);
+        }
 
         // Run visitor with all rules (filtering happens in context)
         let mut visitor = LintVisitor::new(&mut ctx, self.registry.rules());
-        visitor.visit_root(&root);
+        visitor.visit_root(root);
 
         // Collect results (error/warning counts are cached)
         let error_count = ctx.error_count();
@@ -208,43 +271,76 @@ impl Linter {
 
     /// Lint a full Vue SFC file
     ///
-    /// Uses ultra-fast template extraction optimized for linting.
-    #[inline]
+    /// Uses ultra-fast template extraction optimized for linting. The script
+    /// is analyzed with croquis so rules can check it against the template,
+    /// and every diagnostic is reported at its offset in the SFC.
     pub fn lint_sfc(&self, source: &str, filename: &str) -> LintResult {
         // Fast template extraction using memchr
-        let (content, byte_offset) = match extract_template_fast(source) {
-            Some(r) => r,
-            None => {
-                return LintResult {
-                    filename: filename.to_string(),
-                    diagnostics: Vec::new(),
-                    error_count: 0,
-                    warning_count: 0,
-                };
-            }
+        let Some((content_start, content_end)) = extract_template_fast(source) else {
+            return LintResult {
+                filename: filename.to_string(),
+                diagnostics: Vec::new(),
+                error_count: 0,
+                warning_count: 0,
+            };
         };
 
-        let mut result = self.lint_template(&content, filename);
-
-        // Adjust byte offsets in diagnostics to match original file positions
-        if byte_offset > 0 {
-            for diag in &mut result.diagnostics {
-                diag.start += byte_offset;
-                diag.end += byte_offset;
-                for label in &mut diag.labels {
-                    label.start += byte_offset;
-                    label.end += byte_offset;
-                }
+        // Blank everything but the template content so template nodes carry
+        // SFC offsets, while rules still read the whole SFC from the context
+        let mut template = String::with_capacity(source.len());
+        for (i, c) in source.char_indices() {
+            if (content_start..content_end).contains(&i) || c == '\n' {
+                template.push(c);
+            } else {
+                template.extend((0..c.len_utf8()).map(|_| ' '));
             }
         }
 
-        result
+        let capacity = (source.len() * 4).max(self.initial_capacity);
+        let allocator = Allocator::with_capacity(capacity);
+        let (root, _parse_errors) = Parser::new(allocator.as_bump(), &template).parse();
+
+        let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+        let Some(script_offset) = analyze_script(&mut analyzer, source) else {
+            return self.lint_root(&allocator, &root, source, filename, None);
+        };
+        analyzer.analyze_template(&root);
+        let analysis = analyzer.finish();
+
+        self.lint_root(
+            &allocator,
+            &root,
+            source,
+            filename,
+            Some((&analysis, script_offset)),
+        )
+    }
+}
+
+/// Analyze the `<script setup>` block, or the plain `<script>` without one.
+///
+/// Returns the offset of the analyzed content in the SFC.
+fn analyze_script(analyzer: &mut Analyzer, source: &str) -> Option<u32> {
+    if memchr::memmem::find(source.as_bytes(), b"<script").is_none() {
+        return None;
+    }
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).ok()?;
+    if let Some(script_setup) = &descriptor.script_setup {
+        analyzer.analyze_script_setup(&script_setup.content);
+        Some(script_setup.loc.start as u32)
+    } else if let Some(script) = &descriptor.script {
+        analyzer.analyze_script_plain(&script.content);
+        Some(script.loc.start as u32)
+    } else {
+        None
     }
 }
 
 /// Ultra-fast template extraction using memchr for SIMD-accelerated search
+///
+/// Returns the byte range of the template content.
 #[inline]
-fn extract_template_fast(source: &str) -> Option<(String, u32)> {
+fn extract_template_fast(source: &str) -> Option<(usize, usize)> {
     let bytes = source.as_bytes();
 
     // Find <template using memchr (SIMD accelerated)
@@ -283,8 +379,7 @@ fn extract_template_fast(source: &str) -> Option<(String, u32)> {
         } else if bytes.len() > next_lt + 11 && &bytes[next_lt..next_lt + 11] == b"</template>" {
             depth -= 1;
             if depth == 0 {
-                let content = std::str::from_utf8(&bytes[content_start..next_lt]).ok()?;
-                return Some((content.to_string(), content_start as u32));
+                return Some((content_start, next_lt));
             }
             pos = next_lt + 11;
         } else {
@@ -509,6 +604,38 @@ const foo = 'bar';
         }
     }
 
+    #[test]
+    fn test_lint_sfc_reports_script_analysis_at_sfc_offsets() {
+        let linter = Linter::new();
+        let sfc = r#"<template>
+  <button @click="$emit('close')">x</button>
+</template>
+
+<script setup>
+const emit = defineEmits(['submit'])
+emit('cancel')
+</script>
+"#;
+        let result = linter.lint_sfc(sfc, "test.vue");
+        let emits: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|diag| diag.rule_name == "vue/require-explicit-emits")
+            .collect();
+        assert_eq!(emits.len(), 2, "{:?}", result.diagnostics);
+
+        // Template and script locations are both SFC offsets
+        let template_emit = sfc.find("$emit('close'").unwrap() as u32;
+        let script_emit = sfc.find("emit('cancel')").unwrap() as u32;
+        assert!(emits.iter().any(|diag| diag.start == template_emit));
+        assert!(emits.iter().any(|diag| diag.start == script_emit));
+
+        // The fix inserts into the defineEmits array
+        let bracket = sfc.find("'submit']").unwrap() as u32 + "'submit'".len() as u32;
+        let fix = emits[0].fix.as_ref().expect("fix");
+        assert_eq!(fix.edits[0].start, bracket);
+    }
+
     #[test]
     fn test_lint_sfc_with_nested_templates() {
         let linter = Linter::new();
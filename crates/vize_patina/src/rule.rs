@@ -113,6 +113,11 @@ impl RuleRegistry {
         &self.rules
     }
 
+    /// Take ownership of all registered rules
+    pub fn into_rules(self) -> Vec<Box<dyn Rule>> {
+        self.rules
+    }
+
     /// Create registry with all built-in rules enabled
     ///
     /// This includes:
@@ -243,6 +248,7 @@ impl RuleRegistry {
         registry.register(Box::new(crate::rules::vue::UseUniqueElementIds::default()));
         registry.register(Box::new(crate::rules::vue::PermittedContents));
         registry.register(Box::new(crate::rules::a11y::HeadingLevels));
+        registry.register(Box::new(crate::rules::a11y::NoPositiveAriaLevelSkips));
         registry.register(Box::new(crate::rules::a11y::LandmarkRoles));
         registry.register(Box::new(crate::rules::a11y::PlaceholderLabelOption));
 
@@ -274,6 +280,7 @@ impl RuleRegistry {
         registry.register(Box::new(crate::rules::vue::NoUnusedComponents::default()));
         registry.register(Box::new(crate::rules::vue::NoUnusedProperties::default()));
         registry.register(Box::new(crate::rules::vue::NoMutatingProps));
+        registry.register(Box::new(crate::rules::vue::RequireExplicitEmits));
 
         registry
     }
@@ -330,6 +337,7 @@ impl RuleRegistry {
 
         // Opt-in rules
         registry.register(Box::new(crate::rules::vue::NoMultiSpaces::default()));
+        registry.register(Box::new(crate::rules::vue::NoMultipleTemplateRoot));
         registry.register(Box::new(
             crate::rules::vue::ComponentNameInTemplateCasing::default(),
         ));
@@ -340,6 +348,7 @@ impl RuleRegistry {
         registry.register(Box::new(crate::rules::vue::NoTemplateLang));
         registry.register(Box::new(crate::rules::vue::NoSrcAttribute));
         registry.register(Box::new(crate::rules::vue::SingleStyleBlock));
+        registry.register(Box::new(crate::rules::vue::PreferDefineOptions));
 
         // Component registration (opt-in)
         registry.register(Box::new(
@@ -358,6 +367,7 @@ impl RuleRegistry {
         registry.register(Box::new(crate::rules::a11y::UseList));
         registry.register(Box::new(crate::rules::vue::NoBooleanAttrValue));
         registry.register(Box::new(crate::rules::html::NoEmptyPalpableContent));
+        registry.register(Box::new(crate::rules::html::RequireLangAttrConsistency));
 
         registry
     }
@@ -378,6 +388,7 @@ impl RuleRegistry {
         registry.register(Box::new(crate::rules::vue::NoTemplateLang));
         registry.register(Box::new(crate::rules::vue::NoSrcAttribute));
         registry.register(Box::new(crate::rules::vue::SingleStyleBlock));
+        registry.register(Box::new(crate::rules::vue::PreferDefineOptions));
 
         // Nuxt mode: skip component registration warnings (auto-imported)
         // RequireComponentRegistration is not added here
@@ -28,6 +28,7 @@ mod no_aria_hidden_on_focusable;
 mod no_autofocus;
 mod no_distracting_elements;
 mod no_i_for_icon;
+mod no_positive_aria_level_skips;
 mod no_redundant_roles;
 mod no_refer_to_non_existent_id;
 mod no_role_presentation_on_focusable;
@@ -59,6 +60,7 @@ pub use no_aria_hidden_on_focusable::NoAriaHiddenOnFocusable;
 pub use no_autofocus::NoAutofocus;
 pub use no_distracting_elements::NoDistractingElements;
 pub use no_i_for_icon::NoIForIcon;
+pub use no_positive_aria_level_skips::NoPositiveAriaLevelSkips;
 pub use no_redundant_roles::NoRedundantRoles;
 pub use no_refer_to_non_existent_id::NoReferToNonExistentId;
 pub use no_role_presentation_on_focusable::NoRolePresentationOnFocusable;
@@ -0,0 +1,203 @@
+//! a11y/no-positive-aria-level-skips
+//!
+//! Detect `role="heading"` elements whose `aria-level` skips a level
+//! relative to the previous heading in document order. Both native
+//! `<h1>`-`<h6>` and ARIA headings count as the previous heading.
+//! Skips between native headings are reported by `a11y/heading-levels`.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <template>
+//!   <h2>Section</h2>
+//!   <div role="heading" aria-level="4">Subsection</div>
+//! </template>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <template>
+//!   <div role="heading" aria-level="1">Title</div>
+//!   <h2>Section</h2>
+//!   <div role="heading" aria-level="3">Subsection</div>
+//! </template>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{LintDiagnostic, Severity};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use crate::rules::html::helpers::walk_elements;
+use vize_relief::ast::{ElementNode, ExpressionNode, PropNode, RootNode};
+
+use super::helpers::get_static_attribute_value;
+
+static META: RuleMeta = RuleMeta {
+    name: "a11y/no-positive-aria-level-skips",
+    description: "Disallow aria-level values that skip heading levels",
+    category: RuleCategory::Accessibility,
+    fixable: false,
+    default_severity: Severity::Warning,
+};
+
+/// Implicit `aria-level` of `role="heading"` per WAI-ARIA
+const DEFAULT_ARIA_LEVEL: u8 = 2;
+
+#[derive(Default)]
+pub struct NoPositiveAriaLevelSkips;
+
+struct HeadingInfo {
+    level: u8,
+    is_aria: bool,
+    start: u32,
+    end: u32,
+}
+
+/// Heading level of an element, and whether it comes from `role="heading"`.
+///
+/// Returns `None` for non-headings and for headings with a dynamic level.
+fn heading_info(element: &ElementNode) -> Option<(u8, bool)> {
+    if get_static_attribute_value(element, "role").is_some_and(|role| role.trim() == "heading") {
+        let level = match get_static_attribute_value(element, "aria-level") {
+            Some(value) => value.trim().parse::<u8>().ok().filter(|l| *l > 0)?,
+            None if has_dynamic_aria_level(element) => return None,
+            None => DEFAULT_ARIA_LEVEL,
+        };
+        return Some((level, true));
+    }
+
+    match element.tag.as_str() {
+        "h1" => Some((1, false)),
+        "h2" => Some((2, false)),
+        "h3" => Some((3, false)),
+        "h4" => Some((4, false)),
+        "h5" => Some((5, false)),
+        "h6" => Some((6, false)),
+        _ => None,
+    }
+}
+
+fn has_dynamic_aria_level(element: &ElementNode) -> bool {
+    element.props.iter().any(|prop| {
+        matches!(
+            prop,
+            PropNode::Directive(dir)
+                if dir.name == "bind"
+                    && matches!(
+                        &dir.arg,
+                        Some(ExpressionNode::Simple(arg))
+                            if arg.content == "aria-level"
+                    )
+        )
+    })
+}
+
+impl Rule for NoPositiveAriaLevelSkips {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, root: &RootNode<'a>) {
+        let mut headings: Vec<HeadingInfo> = Vec::new();
+
+        walk_elements(&root.children, &mut |element| {
+            if let Some((level, is_aria)) = heading_info(element) {
+                headings.push(HeadingInfo {
+                    level,
+                    is_aria,
+                    start: element.loc.start.offset,
+                    end: element.loc.end.offset,
+                });
+            }
+        });
+
+        // Sort by document order (source offset)
+        headings.sort_by_key(|h| h.start);
+
+        let mut prev_level: u8 = 0;
+        for heading in &headings {
+            if heading.is_aria && prev_level > 0 && heading.level > prev_level + 1 {
+                let from = prev_level.to_string();
+                let to = heading.level.to_string();
+                let max = (prev_level + 1).to_string();
+                let message = ctx.t_fmt(
+                    "a11y/no-positive-aria-level-skips.message",
+                    &[("from", &from), ("to", &to)],
+                );
+                let help = ctx.t_fmt("a11y/no-positive-aria-level-skips.help", &[("max", &max)]);
+                let diag = LintDiagnostic::warn(META.name, message, heading.start, heading.end)
+                    .with_help(help);
+                ctx.report(diag);
+            }
+            prev_level = heading.level;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoPositiveAriaLevelSkips));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid_sequential() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<div role="heading" aria-level="1">T</div><h2>S</h2><div role="heading" aria-level="3">Sub</div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_valid_default_level() {
+        let linter = create_linter();
+        let result =
+            linter.lint_template(r#"<h1>Title</h1><div role="heading">S</div>"#, "test.vue");
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_valid_dynamic_level() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<h1>Title</h1><div role="heading" :aria-level="level">S</div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_valid_native_skip_not_reported() {
+        let linter = create_linter();
+        let result = linter.lint_template(r#"<h2>S</h2><h4>Sub</h4>"#, "test.vue");
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_invalid_skip_2_to_4() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<h2>Section</h2><div role="heading" aria-level="4">Sub</div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 1);
+    }
+
+    #[test]
+    fn test_invalid_aria_to_aria_skip() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<div role="heading" aria-level="2">S</div><div role="heading" aria-level="4">Sub</div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 1);
+    }
+}
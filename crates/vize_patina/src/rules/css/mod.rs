@@ -29,9 +29,11 @@
 //! ```
 
 mod no_display_none;
+mod no_duplicate_selectors;
 mod no_hardcoded_values;
 mod no_id_selectors;
 mod no_important;
+mod no_unknown_pseudo;
 mod no_utility_classes;
 mod no_v_bind_performance;
 mod prefer_logical_properties;
@@ -41,15 +43,18 @@ mod require_font_display;
 
 use std::collections::HashSet;
 
+use lightningcss::rules::Location;
 use lightningcss::stylesheet::{ParserOptions, StyleSheet};
 use memchr::memmem;
 
 use crate::diagnostic::{LintDiagnostic, Severity};
 
 pub use no_display_none::NoDisplayNone;
+pub use no_duplicate_selectors::NoDuplicateSelectors;
 pub use no_hardcoded_values::NoHardcodedValues;
 pub use no_id_selectors::NoIdSelectors;
 pub use no_important::NoImportant;
+pub use no_unknown_pseudo::NoUnknownPseudo;
 pub use no_utility_classes::NoUtilityClasses;
 pub use no_v_bind_performance::NoVBindPerformance;
 pub use prefer_logical_properties::PreferLogicalProperties;
@@ -335,6 +340,75 @@ pub fn strip_vize_comments(source: &str) -> String {
     result
 }
 
+/// Maps lightning-css rule locations back to byte offsets in the style block
+pub(crate) struct SourcePositions<'s> {
+    source: &'s str,
+    line_starts: Vec<usize>,
+    offset: usize,
+}
+
+impl<'s> SourcePositions<'s> {
+    pub(crate) fn new(source: &'s str, offset: usize) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(
+                source
+                    .bytes()
+                    .enumerate()
+                    .filter_map(|(i, b)| (b == b'\n').then_some(i + 1)),
+            )
+            .collect();
+        Self {
+            source,
+            line_starts,
+            offset,
+        }
+    }
+
+    /// Byte offset (within the style block) of a lightning-css location
+    ///
+    /// Lightning-css counts columns in UTF-16 code units, so they are walked
+    /// over the line's characters rather than added as bytes.
+    fn position(&self, loc: &Location) -> usize {
+        let line_start = self
+            .line_starts
+            .get(loc.line as usize)
+            .copied()
+            .unwrap_or(0)
+            .min(self.source.len());
+        let mut units = (loc.column as usize).saturating_sub(1);
+        let mut offset = line_start;
+        for c in self.source[line_start..].chars() {
+            if units == 0 || c == '\n' {
+                break;
+            }
+            units = units.saturating_sub(c.len_utf16());
+            offset += c.len_utf8();
+        }
+        offset
+    }
+
+    /// Source text of a rule's selector (from its location up to `{`) and its start offset
+    pub(crate) fn selector_text(&self, loc: &Location) -> (&'s str, usize) {
+        let start = self.position(loc);
+        let Some(rest) = self.source.get(start..) else {
+            return ("", start);
+        };
+        let text = rest.find('{').map_or("", |i| rest[..i].trim_end());
+        (text, start)
+    }
+
+    /// Absolute byte range of a rule's selector (from its location up to `{`)
+    pub(crate) fn selector_range(&self, loc: &Location) -> (u32, u32) {
+        let (text, start) = self.selector_text(loc);
+        self.absolute(start, start + text.len())
+    }
+
+    /// Convert a block-relative range to absolute file offsets
+    pub(crate) fn absolute(&self, start: usize, end: usize) -> (u32, u32) {
+        ((self.offset + start) as u32, (self.offset + end) as u32)
+    }
+}
+
 /// Linter for style blocks using lightning-css
 pub struct CssLinter {
     rules: Vec<Box<dyn CssRule>>,
@@ -360,6 +434,8 @@ impl CssLinter {
                 Box::new(NoHardcodedValues::default()),
                 Box::new(NoUtilityClasses),
                 Box::new(PreferSlotted),
+                Box::new(NoDuplicateSelectors),
+                Box::new(NoUnknownPseudo),
             ],
         }
     }
@@ -0,0 +1,166 @@
+//! css/no-duplicate-selectors
+//!
+//! Disallow the same selector list appearing more than once in a style block.
+//!
+//! Duplicated rules are usually a copy-paste mistake; the later rule silently
+//! overrides or extends the earlier one. Selectors are compared after
+//! serialization, so whitespace differences do not matter. Rules inside
+//! different at-rule blocks (e.g. two `@media` queries) are not compared.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```css
+//! .btn { color: red; }
+//! .btn { padding: 4px; }
+//! ```
+//!
+//! ### Valid
+//! ```css
+//! .btn { color: red; padding: 4px; }
+//! @media (min-width: 640px) {
+//!   .btn { padding: 8px; }
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use lightningcss::rules::{CssRule as LCssRule, CssRuleList};
+use lightningcss::stylesheet::{PrinterOptions, StyleSheet};
+use lightningcss::traits::ToCss;
+
+use crate::diagnostic::{LintDiagnostic, Severity};
+
+use super::{CssLintResult, CssRule, CssRuleMeta, SourcePositions};
+
+static META: CssRuleMeta = CssRuleMeta {
+    name: "css/no-duplicate-selectors",
+    description: "Disallow duplicate selectors within the same style block",
+    default_severity: Severity::Warning,
+};
+
+/// No duplicate selectors rule
+pub struct NoDuplicateSelectors;
+
+impl CssRule for NoDuplicateSelectors {
+    fn meta(&self) -> &'static CssRuleMeta {
+        &META
+    }
+
+    fn check<'i>(
+        &self,
+        source: &'i str,
+        stylesheet: &StyleSheet<'i, 'i>,
+        offset: usize,
+        result: &mut CssLintResult,
+    ) {
+        let positions = SourcePositions::new(source, offset);
+        self.check_rules(&positions, &stylesheet.rules, result);
+    }
+}
+
+impl NoDuplicateSelectors {
+    /// Check one rule list; each block (top level, `@media`, nesting) is its own scope
+    fn check_rules(
+        &self,
+        positions: &SourcePositions<'_>,
+        rules: &CssRuleList,
+        result: &mut CssLintResult,
+    ) {
+        let mut seen: HashMap<String, (u32, u32)> = HashMap::new();
+
+        for rule in &rules.0 {
+            match rule {
+                LCssRule::Style(style_rule) => {
+                    if let Ok(selector) = style_rule
+                        .selectors
+                        .to_css_string(PrinterOptions::default())
+                    {
+                        let (start, end) = positions.selector_range(&style_rule.loc);
+                        if let Some(&(first_start, first_end)) = seen.get(&selector) {
+                            result.add_diagnostic(
+                                LintDiagnostic::warn(
+                                    META.name,
+                                    format!("Duplicate selector \"{}\"", selector),
+                                    start,
+                                    end,
+                                )
+                                .with_label("first defined here", first_start, first_end)
+                                .with_help("Merge the declarations into the first rule"),
+                            );
+                        } else {
+                            seen.insert(selector, (start, end));
+                        }
+                    }
+                    self.check_rules(positions, &style_rule.rules, result);
+                }
+                LCssRule::Media(media) => self.check_rules(positions, &media.rules, result),
+                LCssRule::Supports(supports) => {
+                    self.check_rules(positions, &supports.rules, result)
+                }
+                LCssRule::LayerBlock(layer) => self.check_rules(positions, &layer.rules, result),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::css::CssLinter;
+
+    fn create_linter() -> CssLinter {
+        let mut linter = CssLinter::new();
+        linter.add_rule(Box::new(NoDuplicateSelectors));
+        linter
+    }
+
+    #[test]
+    fn test_invalid_duplicate_selector() {
+        let linter = create_linter();
+        let source = ".btn { color: red; }\n.card { color: blue; }\n.btn { padding: 4px; }";
+        let result = linter.lint(source, 0);
+        assert_eq!(result.warning_count, 1);
+
+        let diagnostic = &result.diagnostics[0];
+        assert_eq!(
+            &source[diagnostic.start as usize..diagnostic.end as usize],
+            ".btn"
+        );
+        assert_eq!(diagnostic.start, 44);
+        assert_eq!(diagnostic.labels[0].start, 0);
+    }
+
+    #[test]
+    fn test_span_after_non_ascii() {
+        let linter = create_linter();
+        let source = ".btn { content: \"→\"; } .btn { color: red; }";
+        let result = linter.lint(source, 0);
+        assert_eq!(result.warning_count, 1);
+
+        let diagnostic = &result.diagnostics[0];
+        assert_eq!(diagnostic.start as usize, source.rfind(".btn").unwrap());
+        assert_eq!(
+            &source[diagnostic.start as usize..diagnostic.end as usize],
+            ".btn"
+        );
+    }
+
+    #[test]
+    fn test_whitespace_is_normalized() {
+        let linter = create_linter();
+        let result = linter.lint(".a   >  .b { color: red; }\n.a > .b { color: blue; }", 0);
+        assert_eq!(result.warning_count, 1);
+    }
+
+    #[test]
+    fn test_valid_different_media_blocks() {
+        let linter = create_linter();
+        let result = linter.lint(
+            ".btn { color: red; }\n@media (min-width: 640px) { .btn { color: blue; } }",
+            0,
+        );
+        assert_eq!(result.warning_count, 0);
+    }
+}
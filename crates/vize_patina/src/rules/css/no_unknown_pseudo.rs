@@ -0,0 +1,393 @@
+//! css/no-unknown-pseudo
+//!
+//! Disallow unknown pseudo-classes and pseudo-elements.
+//!
+//! Misspelled pseudos such as `:hoverr` or `::befor` are silently ignored by
+//! browsers, so the whole rule never applies. Vue's scoped style pseudos
+//! (`:deep()`, `:slotted()`, `:global()`) and vendor-prefixed pseudos
+//! (`::-webkit-scrollbar`) are allowed.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```css
+//! .btn:hoverr { color: red; }
+//! .icon::befor { content: ''; }
+//! ```
+//!
+//! ### Valid
+//! ```css
+//! .btn:hover { color: red; }
+//! .wrapper :deep(.child) { color: blue; }
+//! .list::-webkit-scrollbar { display: none; }
+//! ```
+
+use lightningcss::rules::{CssRule as LCssRule, CssRuleList};
+use lightningcss::selector::{Component, PseudoClass, PseudoElement, Selector};
+use lightningcss::stylesheet::StyleSheet;
+
+use crate::diagnostic::{LintDiagnostic, Severity};
+
+use super::{CssLintResult, CssRule, CssRuleMeta, SourcePositions};
+
+static META: CssRuleMeta = CssRuleMeta {
+    name: "css/no-unknown-pseudo",
+    description: "Disallow unknown pseudo-classes and pseudo-elements",
+    default_severity: Severity::Warning,
+};
+
+/// Standard pseudo-classes
+const PSEUDO_CLASSES: &[&str] = &[
+    "active",
+    "any-link",
+    "autofill",
+    "blank",
+    "buffering",
+    "checked",
+    "closed",
+    "current",
+    "default",
+    "defined",
+    "dir",
+    "disabled",
+    "empty",
+    "enabled",
+    "first",
+    "first-child",
+    "first-of-type",
+    "focus",
+    "focus-visible",
+    "focus-within",
+    "fullscreen",
+    "future",
+    "has",
+    "host",
+    "host-context",
+    "hover",
+    "in-range",
+    "indeterminate",
+    "invalid",
+    "is",
+    "lang",
+    "last-child",
+    "last-of-type",
+    "left",
+    "link",
+    "local-link",
+    "modal",
+    "muted",
+    "not",
+    "nth-child",
+    "nth-last-child",
+    "nth-last-of-type",
+    "nth-of-type",
+    "only-child",
+    "only-of-type",
+    "open",
+    "optional",
+    "out-of-range",
+    "past",
+    "paused",
+    "picture-in-picture",
+    "placeholder-shown",
+    "playing",
+    "popover-open",
+    "read-only",
+    "read-write",
+    "required",
+    "right",
+    "root",
+    "scope",
+    "seeking",
+    "stalled",
+    "state",
+    "target",
+    "target-within",
+    "user-invalid",
+    "user-valid",
+    "valid",
+    "visited",
+    "volume-locked",
+    "where",
+];
+
+/// Standard pseudo-elements
+const PSEUDO_ELEMENTS: &[&str] = &[
+    "after",
+    "backdrop",
+    "before",
+    "cue",
+    "cue-region",
+    "details-content",
+    "file-selector-button",
+    "first-letter",
+    "first-line",
+    "grammar-error",
+    "highlight",
+    "marker",
+    "part",
+    "placeholder",
+    "selection",
+    "slotted",
+    "spelling-error",
+    "target-text",
+    "view-transition",
+    "view-transition-group",
+    "view-transition-image-pair",
+    "view-transition-new",
+    "view-transition-old",
+];
+
+/// Vue scoped style pseudos
+const VUE_PSEUDOS: &[&str] = &[
+    "deep",
+    "slotted",
+    "global",
+    "v-deep",
+    "v-slotted",
+    "v-global",
+];
+
+/// No unknown pseudo rule
+pub struct NoUnknownPseudo;
+
+impl CssRule for NoUnknownPseudo {
+    fn meta(&self) -> &'static CssRuleMeta {
+        &META
+    }
+
+    fn check<'i>(
+        &self,
+        source: &'i str,
+        stylesheet: &StyleSheet<'i, 'i>,
+        offset: usize,
+        result: &mut CssLintResult,
+    ) {
+        let positions = SourcePositions::new(source, offset);
+        self.check_rules(&positions, &stylesheet.rules, result);
+    }
+}
+
+impl NoUnknownPseudo {
+    fn check_rules(
+        &self,
+        positions: &SourcePositions<'_>,
+        rules: &CssRuleList,
+        result: &mut CssLintResult,
+    ) {
+        for rule in &rules.0 {
+            match rule {
+                LCssRule::Style(style_rule) => {
+                    let mut unknown = Vec::new();
+                    for selector in style_rule.selectors.0.iter() {
+                        collect_unknown(selector, &mut unknown);
+                    }
+                    if !unknown.is_empty() {
+                        let (text, start) = positions.selector_text(&style_rule.loc);
+                        for (name, is_element) in unknown {
+                            self.report(positions, text, start, &name, is_element, result);
+                        }
+                    }
+                    self.check_rules(positions, &style_rule.rules, result);
+                }
+                LCssRule::Media(media) => self.check_rules(positions, &media.rules, result),
+                LCssRule::Supports(supports) => {
+                    self.check_rules(positions, &supports.rules, result)
+                }
+                LCssRule::LayerBlock(layer) => self.check_rules(positions, &layer.rules, result),
+                _ => {}
+            }
+        }
+    }
+
+    fn report(
+        &self,
+        positions: &SourcePositions<'_>,
+        selector_text: &str,
+        selector_start: usize,
+        name: &str,
+        is_element: bool,
+        result: &mut CssLintResult,
+    ) {
+        let (prefix, kind, known) = if is_element {
+            ("::", "pseudo-element", PSEUDO_ELEMENTS)
+        } else {
+            (":", "pseudo-class", PSEUDO_CLASSES)
+        };
+
+        // Locate `:name` (or legacy single-colon pseudo-elements) in the selector text
+        let token = format!(":{}", name);
+        let (start, end) = match find_token(selector_text, &token) {
+            Some(pos) => {
+                let pos = if pos > 0 && selector_text.as_bytes()[pos - 1] == b':' {
+                    pos - 1
+                } else {
+                    pos
+                };
+                let end = selector_start + pos + prefix.len() + name.len();
+                positions.absolute(
+                    selector_start + pos,
+                    end.min(selector_start + selector_text.len()),
+                )
+            }
+            None => positions.absolute(selector_start, selector_start + selector_text.len()),
+        };
+
+        let mut diagnostic = LintDiagnostic::warn(
+            META.name,
+            format!("Unknown {} \"{}{}\"", kind, prefix, name),
+            start,
+            end,
+        );
+        if let Some(suggestion) = known
+            .iter()
+            .chain(VUE_PSEUDOS)
+            .find(|candidate| is_within_one_edit(name, candidate))
+        {
+            diagnostic =
+                diagnostic.with_help(format!("Did you mean \"{}{}\"?", prefix, suggestion));
+        }
+        result.add_diagnostic(diagnostic);
+    }
+}
+
+/// Collect unknown pseudo names as `(name, is_pseudo_element)`
+fn collect_unknown(selector: &Selector, unknown: &mut Vec<(String, bool)>) {
+    for component in selector.iter_raw_match_order() {
+        match component {
+            Component::NonTSPseudoClass(
+                PseudoClass::Custom { name } | PseudoClass::CustomFunction { name, .. },
+            ) => {
+                if !is_allowed(name, PSEUDO_CLASSES) {
+                    unknown.push((name.to_string(), false));
+                }
+            }
+            Component::PseudoElement(
+                PseudoElement::Custom { name } | PseudoElement::CustomFunction { name, .. },
+            ) => {
+                if !is_allowed(name, PSEUDO_ELEMENTS) {
+                    unknown.push((name.to_string(), true));
+                }
+            }
+            Component::Negation(selectors)
+            | Component::Is(selectors)
+            | Component::Where(selectors) => {
+                for selector in selectors.iter() {
+                    collect_unknown(selector, unknown);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_allowed(name: &str, known: &[&str]) -> bool {
+    // Vendor-prefixed pseudos (e.g. ::-webkit-scrollbar, :-moz-focusring)
+    if name.starts_with('-') {
+        return true;
+    }
+    let name = name.to_ascii_lowercase();
+    known.contains(&name.as_str()) || VUE_PSEUDOS.contains(&name.as_str())
+}
+
+/// Find `token` in `text` where it is not followed by another identifier character
+fn find_token(text: &str, token: &str) -> Option<usize> {
+    let mut from = 0;
+    while let Some(pos) = text[from..].find(token) {
+        let abs = from + pos;
+        let next = text[abs + token.len()..].chars().next();
+        if !next.is_some_and(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Some(abs);
+        }
+        from = abs + token.len();
+    }
+    None
+}
+
+/// Whether two names differ by at most one insertion, deletion or substitution
+fn is_within_one_edit(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len().abs_diff(b.len()) > 1 {
+        return false;
+    }
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (ra, rb) = (&a[prefix..], &b[prefix..]);
+    match ra.len().cmp(&rb.len()) {
+        std::cmp::Ordering::Equal => ra.get(1..) == rb.get(1..),
+        std::cmp::Ordering::Less => ra == &rb[1..],
+        std::cmp::Ordering::Greater => &ra[1..] == rb,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::css::CssLinter;
+
+    fn create_linter() -> CssLinter {
+        let mut linter = CssLinter::new();
+        linter.add_rule(Box::new(NoUnknownPseudo));
+        linter
+    }
+
+    #[test]
+    fn test_valid_vue_deep() {
+        let linter = create_linter();
+        let result = linter.lint(".wrapper :deep(.child) { color: red; }", 0);
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_invalid_misspelled_pseudo_element() {
+        let linter = create_linter();
+        let source = ".icon::befor { content: ''; }";
+        let result = linter.lint(source, 0);
+        assert_eq!(result.warning_count, 1);
+
+        let diagnostic = &result.diagnostics[0];
+        assert_eq!(
+            &source[diagnostic.start as usize..diagnostic.end as usize],
+            "::befor"
+        );
+        assert_eq!(
+            diagnostic.help.as_deref(),
+            Some("Did you mean \"::before\"?")
+        );
+    }
+
+    #[test]
+    fn test_span_after_non_ascii() {
+        let linter = create_linter();
+        let source = ".arrow::after { content: \"→\"; } .btn:hoverr { color: red; }";
+        let result = linter.lint(source, 0);
+        assert_eq!(result.warning_count, 1);
+
+        let diagnostic = &result.diagnostics[0];
+        assert_eq!(
+            &source[diagnostic.start as usize..diagnostic.end as usize],
+            ":hoverr"
+        );
+    }
+
+    #[test]
+    fn test_invalid_misspelled_pseudo_class() {
+        let linter = create_linter();
+        let result = linter.lint(".btn:hoverr { color: red; }", 0);
+        assert_eq!(result.warning_count, 1);
+    }
+
+    #[test]
+    fn test_valid_vendor_pseudo() {
+        let linter = create_linter();
+        let result = linter.lint(".list::-webkit-scrollbar { display: none; }", 0);
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_is_within_one_edit() {
+        assert!(is_within_one_edit("befor", "before"));
+        assert!(is_within_one_edit("hoverr", "hover"));
+        assert!(is_within_one_edit("hovar", "hover"));
+        assert!(!is_within_one_edit("hvr", "hover"));
+    }
+}
@@ -12,6 +12,7 @@ mod no_consecutive_br;
 mod no_duplicate_dt;
 mod no_empty_palpable_content;
 mod require_datetime;
+mod require_lang_attr_consistency;
 
 pub use deprecated_attr::DeprecatedAttr;
 pub use deprecated_element::DeprecatedElement;
@@ -20,3 +21,4 @@ pub use no_consecutive_br::NoConsecutiveBr;
 pub use no_duplicate_dt::NoDuplicateDt;
 pub use no_empty_palpable_content::NoEmptyPalpableContent;
 pub use require_datetime::RequireDatetime;
+pub use require_lang_attr_consistency::RequireLangAttrConsistency;
@@ -0,0 +1,284 @@
+//! html/require-lang-attr-consistency
+//!
+//! Disallow a `lang` attribute that repeats the language of its nearest
+//! ancestor with a `lang` attribute.
+//!
+//! The language is inherited by all descendants, so repeating it adds noise
+//! and makes genuine language switches harder to spot.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <template>
+//!   <article lang="en">
+//!     <p lang="en">Hello</p>
+//!   </article>
+//! </template>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <template>
+//!   <article lang="en">
+//!     <p>Hello</p>
+//!     <blockquote lang="ja">こんにちは</blockquote>
+//!   </article>
+//! </template>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{
+    AttributeNode, ElementNode, ElementType, ExpressionNode, PropNode, RootNode, TemplateChildNode,
+};
+
+static META: RuleMeta = RuleMeta {
+    name: "html/require-lang-attr-consistency",
+    description: "Disallow lang attributes that repeat the inherited language",
+    category: RuleCategory::HtmlConformance,
+    fixable: true,
+    default_severity: Severity::Warning,
+};
+
+#[derive(Default)]
+pub struct RequireLangAttrConsistency;
+
+/// Language declared on an element
+enum Lang<'e> {
+    /// Static `lang="..."`
+    Static(&'e AttributeNode),
+    /// Dynamic `:lang="..."`, value unknown
+    Dynamic,
+}
+
+struct RedundantLang {
+    lang: String,
+    start: u32,
+    end: u32,
+    /// Start and end of the attribute value's content, excluding quotes
+    value: Option<(u32, u32)>,
+    ancestor_start: u32,
+    ancestor_end: u32,
+}
+
+impl Rule for RequireLangAttrConsistency {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, root: &RootNode<'a>) {
+        let mut redundant = Vec::new();
+        collect_redundant(&root.children, None, &mut redundant);
+
+        for entry in redundant {
+            let message = ctx.t_fmt(
+                "html/require-lang-attr-consistency.message",
+                &[("lang", entry.lang.as_str())],
+            );
+            let help = ctx.t("html/require-lang-attr-consistency.help");
+
+            // Remove the attribute together with the whitespace before it.
+            // The attribute location stops before a closing quote.
+            let before = &ctx.source[..entry.start as usize];
+            let delete_start = before.trim_end().len() as u32;
+            let delete_end = entry.end.max(attribute_end(ctx.source, entry.value));
+
+            let diag = LintDiagnostic::warn(META.name, message, entry.start, entry.end)
+                .with_help(help.into_owned())
+                .with_label(
+                    "language inherited from here".to_string(),
+                    entry.ancestor_start,
+                    entry.ancestor_end,
+                )
+                .with_fix(Fix::new(
+                    "Remove redundant lang attribute",
+                    TextEdit::delete(delete_start, delete_end),
+                ));
+            ctx.report(diag);
+        }
+    }
+}
+
+/// Walk the tree carrying the nearest ancestor's static `lang` attribute.
+///
+/// A dynamic `:lang` on an ancestor makes the inherited language unknown.
+fn collect_redundant<'e>(
+    children: &'e [TemplateChildNode<'_>],
+    inherited: Option<&'e AttributeNode>,
+    redundant: &mut Vec<RedundantLang>,
+) {
+    for child in children {
+        match child {
+            TemplateChildNode::Element(el) => {
+                let next = match element_lang(el) {
+                    Some(Lang::Static(attr)) => {
+                        if el.tag_type != ElementType::Component {
+                            if let Some(parent) = inherited.filter(|p| same_lang(p, attr)) {
+                                redundant.push(RedundantLang {
+                                    lang: lang_value(attr).to_string(),
+                                    start: attr.loc.start.offset,
+                                    end: attr.loc.end.offset,
+                                    value: attr
+                                        .value
+                                        .as_ref()
+                                        .map(|v| (v.loc.start.offset, v.loc.end.offset)),
+                                    ancestor_start: parent.loc.start.offset,
+                                    ancestor_end: parent.loc.end.offset,
+                                });
+                            }
+                        }
+                        Some(attr)
+                    }
+                    Some(Lang::Dynamic) => None,
+                    None => inherited,
+                };
+                collect_redundant(&el.children, next, redundant);
+            }
+            TemplateChildNode::If(if_node) => {
+                for branch in if_node.branches.iter() {
+                    collect_redundant(&branch.children, inherited, redundant);
+                }
+            }
+            TemplateChildNode::For(for_node) => {
+                collect_redundant(&for_node.children, inherited, redundant);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// End of an attribute including its closing quote, derived from the value location
+fn attribute_end(source: &str, value: Option<(u32, u32)>) -> u32 {
+    let Some((start, end)) = value else {
+        return 0;
+    };
+    let bytes = source.as_bytes();
+    let opening = (start as usize).checked_sub(1).and_then(|i| bytes.get(i));
+    match (opening, bytes.get(end as usize)) {
+        (Some(&open), Some(&close)) if matches!(open, b'"' | b'\'') && open == close => end + 1,
+        _ => end,
+    }
+}
+
+fn element_lang<'e>(element: &'e ElementNode<'_>) -> Option<Lang<'e>> {
+    element.props.iter().find_map(|prop| match prop {
+        PropNode::Attribute(attr) if attr.name == "lang" => Some(Lang::Static(attr)),
+        PropNode::Directive(dir)
+            if dir.name == "bind"
+                && matches!(&dir.arg, Some(ExpressionNode::Simple(arg)) if arg.content == "lang") =>
+        {
+            Some(Lang::Dynamic)
+        }
+        _ => None,
+    })
+}
+
+fn lang_value(attr: &AttributeNode) -> &str {
+    attr.value
+        .as_ref()
+        .map_or("", |value| value.content.as_str())
+        .trim()
+}
+
+/// Language tags are case-insensitive (BCP 47)
+fn same_lang(a: &AttributeNode, b: &AttributeNode) -> bool {
+    let (a, b) = (lang_value(a), lang_value(b));
+    !a.is_empty() && a.eq_ignore_ascii_case(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(RequireLangAttrConsistency));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_invalid_redundant_nested_lang() {
+        let linter = create_linter();
+        let source = r#"<article lang="en"><p lang="en">Hello</p></article>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.warning_count, 1);
+
+        let fix = result.diagnostics[0].fix.as_ref().expect("fix");
+        assert_eq!(
+            fix.apply(source),
+            r#"<article lang="en"><p>Hello</p></article>"#
+        );
+    }
+
+    #[test]
+    fn test_fix_removes_single_quoted_value() {
+        let linter = create_linter();
+        let source = r#"<div lang="en"><span class="a" lang='en'>Hi</span></div>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.warning_count, 1);
+
+        let fix = result.diagnostics[0].fix.as_ref().expect("fix");
+        assert_eq!(
+            fix.apply(source),
+            r#"<div lang="en"><span class="a">Hi</span></div>"#
+        );
+    }
+
+    #[test]
+    fn test_fix_removes_unquoted_value() {
+        let linter = create_linter();
+        let source = r#"<div lang="en"><span lang=en title="x">Hi</span></div>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.warning_count, 1);
+
+        let fix = result.diagnostics[0].fix.as_ref().expect("fix");
+        assert_eq!(
+            fix.apply(source),
+            r#"<div lang="en"><span title="x">Hi</span></div>"#
+        );
+    }
+
+    #[test]
+    fn test_invalid_case_insensitive() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<div lang="en-US"><section><p lang="en-us">Hi</p></section></div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 1);
+    }
+
+    #[test]
+    fn test_valid_language_switch() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<article lang="en"><p>Hello</p><blockquote lang="ja">こんにちは</blockquote></article>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_valid_switch_back() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<div lang="en"><div lang="ja"><span lang="en">OK</span></div></div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_valid_dynamic_ancestor() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<div :lang="locale"><p lang="en">Hello</p></div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 0);
+    }
+}
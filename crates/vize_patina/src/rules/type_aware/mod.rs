@@ -9,14 +9,23 @@
 //! - `type/require-typed-props` - Require type definition for defineProps
 //! - `type/require-typed-emits` - Require type definition for defineEmits
 //! - `type/no-floating-promises` - Disallow unhandled Promise results
+//! - `type/no-async-in-computed` - Disallow async getters in computed()
+//! - `type/no-ref-as-operand` - Disallow using a ref as an operand without .value
+//! - `type/no-watch-non-reactive` - Disallow watching non-reactive values
 //!
 //! ### Template Rules
 //! - `type/no-unsafe-template-binding` - Disallow type-unsafe template bindings
 
+mod no_async_in_computed;
 mod no_floating_promises;
+mod no_ref_as_operand;
+mod no_watch_non_reactive;
 mod require_typed_emits;
 mod require_typed_props;
 
+pub use no_async_in_computed::NoAsyncInComputed;
 pub use no_floating_promises::NoFloatingPromises;
+pub use no_ref_as_operand::NoRefAsOperand;
+pub use no_watch_non_reactive::NoWatchNonReactive;
 pub use require_typed_emits::RequireTypedEmits;
 pub use require_typed_props::RequireTypedProps;
@@ -0,0 +1,188 @@
+//! type/no-async-in-computed
+//!
+//! Disallow async getters and Promise-returning getters in `computed()`.
+//!
+//! Computed values are evaluated synchronously. An async getter makes the
+//! computed hold a Promise instead of the resolved value, and dependencies
+//! read after the first `await` are never tracked.
+//!
+//! Unlike `script/no-async-in-computed`, this rule uses semantic analysis
+//! (Croquis) to find computed declarations, so it also understands the
+//! writable `computed({ get, set })` form and getters that return a Promise.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <script setup>
+//! const user = computed(async () => {
+//!   const res = await fetch(`/api/users/${id.value}`)
+//!   return res.json()
+//! })
+//!
+//! const posts = computed(() => fetchPosts().then((res) => res.items))
+//! </script>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <script setup>
+//! const user = ref(null)
+//! watchEffect(async () => {
+//!   const res = await fetch(`/api/users/${id.value}`)
+//!   user.value = await res.json()
+//! })
+//!
+//! // Async callbacks created inside a synchronous getter are fine
+//! const loader = computed(() => {
+//!   const load = async () => await fetchPosts(page.value)
+//!   return load
+//! })
+//! </script>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{LintDiagnostic, Severity};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_croquis::reactivity::AsyncComputedKind;
+use vize_relief::ast::RootNode;
+
+static META: RuleMeta = RuleMeta {
+    name: "type/no-async-in-computed",
+    description: "Disallow async getters in computed()",
+    category: RuleCategory::TypeAware,
+    fixable: false,
+    default_severity: Severity::Error,
+};
+
+/// No async in computed rule
+#[derive(Default)]
+pub struct NoAsyncInComputed;
+
+impl Rule for NoAsyncInComputed {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, _root: &RootNode<'a>) {
+        // Skip if no analysis available
+        let Some(analysis) = ctx.analysis() else {
+            return;
+        };
+
+        let diagnostics: Vec<_> = analysis
+            .reactivity
+            .async_computeds()
+            .iter()
+            .map(|computed| {
+                let message = match computed.kind {
+                    AsyncComputedKind::AsyncGetter => {
+                        format!("Computed '{}' has an async getter", computed.name)
+                    }
+                    AsyncComputedKind::ReturnsPromise => {
+                        format!("Computed '{}' returns a Promise", computed.name)
+                    }
+                };
+                LintDiagnostic::error(ctx.current_rule, message, computed.start, computed.end)
+                    .with_help(
+                        "Computed getters must be synchronous. Use `watchEffect` to assign \
+                         async results to a ref, or an `asyncComputed` helper such as VueUse's \
+                         `computedAsync`",
+                    )
+            })
+            .collect();
+
+        for diagnostic in diagnostics {
+            ctx.report(diagnostic);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vize_armature::Parser;
+    use vize_carton::Allocator;
+    use vize_croquis::{Analyzer, Croquis};
+
+    fn analyze(script: &str) -> Croquis {
+        let mut analyzer = Analyzer::new();
+        analyzer.analyze_script_setup(script);
+        analyzer.finish()
+    }
+
+    fn lint(script: &str) -> Vec<LintDiagnostic> {
+        let analysis = analyze(script);
+        let allocator = Allocator::with_capacity(1024);
+        let (root, _) = Parser::new(allocator.as_bump(), "").parse();
+        let mut ctx = LintContext::with_analysis(&allocator, "", "test.vue", &analysis);
+        ctx.current_rule = META.name;
+        NoAsyncInComputed.run_on_template(&mut ctx, &root);
+        ctx.into_diagnostics()
+    }
+
+    #[test]
+    fn test_meta() {
+        let rule = NoAsyncInComputed;
+        assert_eq!(rule.meta().name, "type/no-async-in-computed");
+        assert_eq!(rule.meta().category, RuleCategory::TypeAware);
+        assert_eq!(rule.meta().default_severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_invalid_async_arrow_getter() {
+        let diagnostics = lint(
+            r#"
+const user = computed(async () => {
+  const res = await fetch('/api/user')
+  return res.json()
+})
+"#,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("'user'"));
+        assert!(diagnostics[0].message.contains("async getter"));
+    }
+
+    #[test]
+    fn test_invalid_writable_async_getter() {
+        let diagnostics = lint(
+            r#"
+const name = computed({
+  get: async () => await load(),
+  set: (value) => save(value),
+})
+"#,
+        );
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_promise_returning_getter() {
+        let diagnostics = lint("const posts = computed(() => fetchPosts().then((r) => r.items))");
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("returns a Promise"));
+    }
+
+    #[test]
+    fn test_valid_inner_async_iife() {
+        let diagnostics = lint(
+            r#"
+const loader = computed(() => {
+  const pending = (async () => {
+    await fetchPosts(page.value)
+  })()
+  pending.catch(console.error)
+  return page.value * 2
+})
+"#,
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_valid_sync_getter() {
+        let diagnostics = lint("const doubled = computed(() => count.value * 2)");
+        assert!(diagnostics.is_empty());
+    }
+}
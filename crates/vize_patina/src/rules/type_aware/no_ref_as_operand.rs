@@ -0,0 +1,180 @@
+//! type/no-ref-as-operand
+//!
+//! Disallow using a ref as an operand without `.value` in script setup.
+//!
+//! A ref is an object, so `count + 1` concatenates `"[object Object]1"`
+//! and `count > 5` is always false. Template expressions are exempt because
+//! Vue unwraps refs there automatically.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <script setup>
+//! const count = ref(0)
+//! const next = count + 1
+//! if (count > 5) reset()
+//! </script>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <script setup>
+//! const count = ref(0)
+//! const next = count.value + 1
+//! </script>
+//!
+//! <template>
+//!   <span>{{ count + 1 }}</span>
+//! </template>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::RootNode;
+
+static META: RuleMeta = RuleMeta {
+    name: "type/no-ref-as-operand",
+    description: "Disallow using a ref as an operand without .value",
+    category: RuleCategory::TypeAware,
+    fixable: true,
+    default_severity: Severity::Error,
+};
+
+/// No ref as operand rule
+#[derive(Default)]
+pub struct NoRefAsOperand;
+
+impl Rule for NoRefAsOperand {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, _root: &RootNode<'a>) {
+        // Skip if no analysis available
+        let Some(analysis) = ctx.analysis() else {
+            return;
+        };
+
+        // Operand offsets are relative to the script block
+        let script_offset = ctx.script_offset();
+        let diagnostics: Vec<_> = analysis
+            .reactivity
+            .ref_operands()
+            .iter()
+            .map(|operand| {
+                let (start, end) = (script_offset + operand.start, script_offset + operand.end);
+                LintDiagnostic::error(
+                    ctx.current_rule,
+                    format!("Ref '{}' must be accessed with .value", operand.name),
+                    start,
+                    end,
+                )
+                .with_help(format!("Use `{}.value` to read the ref", operand.name))
+                .with_fix(Fix::new("Add .value", TextEdit::insert(end, ".value")))
+            })
+            .collect();
+
+        for diagnostic in diagnostics {
+            ctx.report(diagnostic);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vize_armature::Parser;
+    use vize_carton::Allocator;
+    use vize_croquis::Analyzer;
+
+    fn lint(script: &str, template: &str) -> Vec<LintDiagnostic> {
+        let mut analyzer = Analyzer::new();
+        analyzer.analyze_script_setup(script);
+        let analysis = analyzer.finish();
+
+        let allocator = Allocator::with_capacity(1024);
+        let (root, _) = Parser::new(allocator.as_bump(), template).parse();
+        let mut ctx = LintContext::with_analysis(&allocator, template, "test.vue", &analysis);
+        ctx.current_rule = META.name;
+        NoRefAsOperand.run_on_template(&mut ctx, &root);
+        ctx.into_diagnostics()
+    }
+
+    #[test]
+    fn test_meta() {
+        let rule = NoRefAsOperand;
+        assert_eq!(rule.meta().name, "type/no-ref-as-operand");
+        assert_eq!(rule.meta().category, RuleCategory::TypeAware);
+        assert!(rule.meta().fixable);
+    }
+
+    #[test]
+    fn test_invalid_ref_operand() {
+        let script = "const n = ref(0)\nconst next = n + 1";
+        let diagnostics = lint(script, "");
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("'n'"));
+
+        let fix = diagnostics[0].fix.as_ref().expect("fix");
+        assert_eq!(
+            fix.apply(script),
+            "const n = ref(0)\nconst next = n.value + 1"
+        );
+    }
+
+    #[test]
+    fn test_invalid_ref_in_condition() {
+        let diagnostics = lint(
+            "const n = ref(0)\nfunction check() { if (n > 5) reset() }",
+            "",
+        );
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn test_fix_in_full_sfc() {
+        let source = "<template>\n  <span>{{ next }}</span>\n</template>\n\n<script setup>\nconst n = ref(0)\nconst next = n + 1\n</script>\n";
+        let script_start = source.find("<script setup>").unwrap() + "<script setup>".len();
+        let script_end = source.find("</script>").unwrap();
+        let mut analyzer = Analyzer::new();
+        analyzer.analyze_script_setup(&source[script_start..script_end]);
+        let analysis = analyzer.finish();
+
+        let allocator = Allocator::with_capacity(1024);
+        let (root, _) = Parser::new(allocator.as_bump(), source).parse();
+        let mut ctx = LintContext::with_analysis(&allocator, source, "test.vue", &analysis);
+        ctx.set_script_offset(script_start as u32);
+        ctx.current_rule = META.name;
+        NoRefAsOperand.run_on_template(&mut ctx, &root);
+        let diagnostics = ctx.into_diagnostics();
+
+        assert_eq!(diagnostics.len(), 1);
+        let diagnostic = &diagnostics[0];
+        assert_eq!(
+            &source[diagnostic.start as usize..diagnostic.end as usize],
+            "n"
+        );
+        let fixed = diagnostic.fix.as_ref().expect("fix").apply(source);
+        assert!(fixed.contains("const next = n.value + 1"), "{}", fixed);
+    }
+
+    #[test]
+    fn test_valid_value_access() {
+        let diagnostics = lint("const n = ref(0)\nconst next = n.value + 1", "");
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_valid_shadowed_param() {
+        let diagnostics = lint("const n = ref(0)\nconst inc = (n) => n + 1", "");
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_valid_template_usage() {
+        let diagnostics = lint("const n = ref(0)", "<span>{{ n + 1 }}</span>");
+        assert!(diagnostics.is_empty());
+    }
+}
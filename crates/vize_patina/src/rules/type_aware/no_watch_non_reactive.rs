@@ -0,0 +1,201 @@
+//! type/no-watch-non-reactive
+//!
+//! Disallow watching a plain (non-reactive) value.
+//!
+//! `watch()` only fires when one of its sources changes reactively. Passing a
+//! literal constant or a plain `let` binding reads it once, so the watcher
+//! never runs. Sources are taken from Croquis's watcher tracking; getters,
+//! refs, reactive objects, and bindings of unknown origin are accepted.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <script setup>
+//! const limit = 10
+//! watch(limit, (value) => console.log(value))
+//! </script>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <script setup>
+//! const count = ref(0)
+//! watch(count, (value) => console.log(value))
+//!
+//! const limit = 10
+//! watch(() => limit, (value) => console.log(value))
+//! </script>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{LintDiagnostic, Severity};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_croquis::watch::WatcherKind;
+use vize_croquis::Croquis;
+use vize_relief::ast::RootNode;
+use vize_relief::BindingType;
+
+static META: RuleMeta = RuleMeta {
+    name: "type/no-watch-non-reactive",
+    description: "Disallow watching non-reactive values",
+    category: RuleCategory::TypeAware,
+    fixable: false,
+    default_severity: Severity::Warning,
+};
+
+/// No watch non-reactive rule
+#[derive(Default)]
+pub struct NoWatchNonReactive;
+
+impl Rule for NoWatchNonReactive {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, _root: &RootNode<'a>) {
+        // Skip if no analysis available
+        let Some(analysis) = ctx.analysis() else {
+            return;
+        };
+        let rule = ctx.current_rule;
+
+        let diagnostics: Vec<_> = analysis
+            .watchers
+            .watchers()
+            .iter()
+            .filter(|watcher| watcher.kind == WatcherKind::Watch)
+            .flat_map(|watcher| {
+                watcher
+                    .sources
+                    .iter()
+                    .filter(|source| is_plain_binding(analysis, source))
+                    .map(move |source| {
+                        LintDiagnostic::warn(
+                            rule,
+                            format!(
+                                "'{}' is not reactive, so this watcher will never fire",
+                                source
+                            ),
+                            watcher.start,
+                            watcher.end,
+                        )
+                        .with_help(format!(
+                            "Watch a getter instead (`watch(() => {}, ...)`), or make '{}' \
+                             reactive with `ref()`",
+                            source, source
+                        ))
+                    })
+            })
+            .collect();
+
+        for diagnostic in diagnostics {
+            ctx.report(diagnostic);
+        }
+    }
+}
+
+/// Whether a watch source names a binding known to hold a plain value.
+fn is_plain_binding(analysis: &Croquis, source: &str) -> bool {
+    if analysis.reactivity.is_reactive(source) || analysis.function_signatures.contains_key(source)
+    {
+        return false;
+    }
+    matches!(
+        analysis.bindings.get(source),
+        Some(BindingType::LiteralConst | BindingType::SetupLet)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vize_armature::Parser;
+    use vize_carton::Allocator;
+    use vize_croquis::Analyzer;
+
+    fn analyze(script: &str) -> Croquis {
+        let mut analyzer = Analyzer::new();
+        analyzer.analyze_script_setup(script);
+        analyzer.finish()
+    }
+
+    fn lint(script: &str) -> Vec<LintDiagnostic> {
+        let analysis = analyze(script);
+        let allocator = Allocator::with_capacity(1024);
+        let (root, _) = Parser::new(allocator.as_bump(), "").parse();
+        let mut ctx = LintContext::with_analysis(&allocator, "", "test.vue", &analysis);
+        ctx.current_rule = META.name;
+        NoWatchNonReactive.run_on_template(&mut ctx, &root);
+        ctx.into_diagnostics()
+    }
+
+    #[test]
+    fn test_meta() {
+        let rule = NoWatchNonReactive;
+        assert_eq!(rule.meta().name, "type/no-watch-non-reactive");
+        assert_eq!(rule.meta().category, RuleCategory::TypeAware);
+        assert_eq!(rule.meta().default_severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_valid_ref_source() {
+        let diagnostics = lint(
+            r#"
+const count = ref(0)
+watch(count, (value) => console.log(value))
+"#,
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_literal_source() {
+        let diagnostics = lint(
+            r#"
+const plain = 1
+watch(plain, (value) => console.log(value))
+"#,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("'plain'"));
+        assert!(diagnostics[0].help.is_some());
+    }
+
+    #[test]
+    fn test_valid_getter_source() {
+        let diagnostics = lint(
+            r#"
+const plain = 1
+watch(() => plain, (value) => console.log(value))
+"#,
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_plain_source_in_array() {
+        let diagnostics = lint(
+            r#"
+const count = ref(0)
+let step = 1
+watch([count, step], () => {})
+"#,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("'step'"));
+    }
+
+    #[test]
+    fn test_valid_unknown_binding() {
+        let diagnostics = lint(
+            r#"
+const { page } = usePagination()
+const data = useFetch()
+watch(data, () => {})
+watch(page, () => {})
+"#,
+        );
+        assert!(diagnostics.is_empty());
+    }
+}
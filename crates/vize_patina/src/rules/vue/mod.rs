@@ -14,6 +14,7 @@ mod multi_word_component_names;
 mod no_child_content;
 mod no_dupe_v_else_if;
 mod no_duplicate_attributes;
+mod no_multiple_template_root;
 mod no_reserved_component_names;
 mod no_template_key;
 mod no_textarea_mustache;
@@ -53,6 +54,7 @@ mod attribute_order;
 mod component_name_in_template_casing;
 mod no_inline_style;
 mod no_lone_template;
+mod prefer_define_options;
 mod prefer_props_shorthand;
 mod require_component_registration;
 mod scoped_event_names;
@@ -67,6 +69,7 @@ mod no_mutating_props;
 mod no_undefined_refs;
 mod no_unused_components;
 mod no_unused_properties;
+mod require_explicit_emits;
 
 // Accessibility rules
 mod a11y_img_alt;
@@ -93,6 +96,7 @@ pub use multi_word_component_names::MultiWordComponentNames;
 pub use no_child_content::NoChildContent;
 pub use no_dupe_v_else_if::NoDupeVElseIf;
 pub use no_duplicate_attributes::NoDuplicateAttributes;
+pub use no_multiple_template_root::NoMultipleTemplateRoot;
 pub use no_reserved_component_names::NoReservedComponentNames;
 pub use no_template_key::NoTemplateKey;
 pub use no_textarea_mustache::NoTextareaMustache;
@@ -132,6 +136,7 @@ pub use attribute_order::AttributeOrder;
 pub use component_name_in_template_casing::ComponentNameInTemplateCasing;
 pub use no_inline_style::NoInlineStyle;
 pub use no_lone_template::NoLoneTemplate;
+pub use prefer_define_options::PreferDefineOptions;
 pub use prefer_props_shorthand::PreferPropsShorthand;
 pub use require_component_registration::RequireComponentRegistration;
 pub use scoped_event_names::ScopedEventNames;
@@ -146,6 +151,7 @@ pub use no_mutating_props::NoMutatingProps;
 pub use no_undefined_refs::NoUndefinedRefs;
 pub use no_unused_components::NoUnusedComponents;
 pub use no_unused_properties::NoUnusedProperties;
+pub use require_explicit_emits::RequireExplicitEmits;
 
 // Accessibility rules exports
 pub use a11y_img_alt::A11yImgAlt;
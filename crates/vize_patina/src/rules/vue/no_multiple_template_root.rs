@@ -0,0 +1,137 @@
+//! vue/no-multiple-template-root
+//!
+//! Disallow adding multiple root nodes to the template.
+//!
+//! Vue 3 supports fragments, so this rule is opt-in. It is meant for code
+//! that still has to run on Vue 2 or on tooling that expects a single root.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <template>
+//!   <header>Title</header>
+//!   <main>Content</main>
+//! </template>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <template>
+//!   <!-- comments are not roots -->
+//!   <div>
+//!     <header>Title</header>
+//!     <main>Content</main>
+//!   </div>
+//! </template>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::Severity;
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_croquis::Analyzer;
+use vize_relief::ast::RootNode;
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-multiple-template-root",
+    description: "Disallow adding multiple root nodes to the template",
+    category: RuleCategory::Recommended,
+    fixable: false,
+    default_severity: Severity::Error,
+};
+
+/// Disallow multiple template roots (Vue 2 compatibility)
+#[derive(Default)]
+pub struct NoMultipleTemplateRoot;
+
+impl Rule for NoMultipleTemplateRoot {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, root: &RootNode<'a>) {
+        // Roots are counted by the same analysis that checks attribute fallthrough
+        let second_root = match ctx.analysis() {
+            Some(analysis) => analysis.template_info.second_root_loc.clone(),
+            None => {
+                let mut analyzer = Analyzer::new();
+                analyzer.analyze_template(root);
+                analyzer.finish().template_info.second_root_loc
+            }
+        };
+
+        if let Some(loc) = second_root {
+            ctx.error_with_help(
+                ctx.t("vue/no-multiple-template-root.message"),
+                &loc,
+                ctx.t("vue/no-multiple-template-root.help"),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoMultipleTemplateRoot));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid_single_root() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<!-- header --><div><header>Title</header><main>Content</main></div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_invalid_two_roots() {
+        let linter = create_linter();
+        let source = r#"<header>Title</header><main>Content</main>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 1);
+        assert_eq!(
+            result.diagnostics[0].start as usize,
+            source.find("<main>").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_valid_if_else_chain() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<div v-if="a">A</div>
+<div v-else-if="b">B</div>
+<div v-else>C</div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_invalid_root_text() {
+        let linter = create_linter();
+        let source = r#"<div>Content</div> trailing text"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 1);
+    }
+
+    #[test]
+    fn test_off_by_default() {
+        let linter = Linter::new();
+        let result =
+            linter.lint_template(r#"<header>Title</header><main>Content</main>"#, "test.vue");
+        assert!(result
+            .diagnostics
+            .iter()
+            .all(|d| d.rule_name != "vue/no-multiple-template-root"));
+    }
+}
@@ -0,0 +1,169 @@
+//! vue/prefer-define-options
+//!
+//! Prefer `defineOptions` over a separate `<script>` block that only sets
+//! component options.
+//!
+//! Since Vue 3.3, `name` and `inheritAttrs` can be declared directly in
+//! `<script setup>` with the `defineOptions` macro, so a second script block
+//! just for these options is no longer needed.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <script>
+//! export default { name: 'UserCard', inheritAttrs: false }
+//! </script>
+//!
+//! <script setup>
+//! const props = defineProps<{ id: string }>()
+//! </script>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <script setup>
+//! defineOptions({ name: 'UserCard', inheritAttrs: false })
+//! const props = defineProps<{ id: string }>()
+//! </script>
+//! ```
+//!
+//! ### Valid (plain script does more than set options)
+//! ```vue
+//! <script>
+//! export const SIZES = ['sm', 'md', 'lg']
+//! export default { name: 'UserCard' }
+//! </script>
+//!
+//! <script setup>
+//! const props = defineProps<{ id: string }>()
+//! </script>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_atelier_sfc::{parse_sfc, SfcParseOptions};
+use vize_croquis::Analyzer;
+use vize_relief::ast::RootNode;
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/prefer-define-options",
+    description: "Prefer defineOptions over a separate <script> block for component options",
+    category: RuleCategory::Recommended,
+    fixable: true,
+    default_severity: Severity::Warning,
+};
+
+/// Prefer defineOptions rule
+#[derive(Default)]
+pub struct PreferDefineOptions;
+
+impl Rule for PreferDefineOptions {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, _root: &RootNode<'a>) {
+        let source = ctx.source;
+        let Ok(descriptor) = parse_sfc(source, SfcParseOptions::default()) else {
+            return;
+        };
+        let (Some(plain), Some(setup)) = (&descriptor.script, &descriptor.script_setup) else {
+            return;
+        };
+
+        let mut analyzer = Analyzer::new();
+        analyzer.analyze_script_plain(&plain.content);
+        let analysis = analyzer.finish();
+        let options = &analysis.component_options;
+        let Some((object_start, object_end)) = options.object_span else {
+            return;
+        };
+        let sets_options = options.name.is_some() || options.inherit_attrs.is_some();
+        if !options.is_only_statement || options.has_other_options || !sets_options {
+            return;
+        }
+        let options_object = &plain.content[object_start as usize..object_end as usize];
+
+        // Remove the plain block together with the line breaks that follow it
+        let block_start = plain.loc.tag_start;
+        let mut block_end = plain.loc.tag_end;
+        while source[block_end..].starts_with('\n') || source[block_end..].starts_with("\r\n") {
+            block_end += if source[block_end..].starts_with('\n') {
+                1
+            } else {
+                2
+            };
+        }
+
+        let fix = Fix::with_edits(
+            "Move options into defineOptions()",
+            vec![
+                TextEdit::delete(block_start as u32, block_end as u32),
+                TextEdit::insert(
+                    setup.loc.start as u32,
+                    format!("\ndefineOptions({})", options_object),
+                ),
+            ],
+        );
+
+        ctx.report(
+            LintDiagnostic::warn(
+                META.name,
+                "Use defineOptions() instead of a separate <script> block for component options",
+                plain.loc.tag_start as u32,
+                plain.loc.tag_end as u32,
+            )
+            .with_help("Move `name`/`inheritAttrs` into `defineOptions({ ... })` in <script setup>")
+            .with_fix(fix),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(PreferDefineOptions));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_invalid_name_only() {
+        let source = "<script>\nexport default { name: 'UserCard' }\n</script>\n<script setup>\nconst a = 1\n</script>\n";
+        let result = create_linter().lint_template(source, "test.vue");
+        assert_eq!(result.warning_count, 1);
+
+        let fix = result.diagnostics[0].fix.as_ref().expect("fix");
+        assert_eq!(
+            fix.apply(source),
+            "<script setup>\ndefineOptions({ name: 'UserCard' })\nconst a = 1\n</script>\n"
+        );
+    }
+
+    #[test]
+    fn test_valid_plain_script_with_constant() {
+        let source = "<script>\nexport const SIZES = ['sm', 'md']\nexport default { name: 'UserCard' }\n</script>\n<script setup>\nconst a = 1\n</script>\n";
+        let result = create_linter().lint_template(source, "test.vue");
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_valid_unsupported_option() {
+        let source = "<script>\nexport default { name: 'UserCard', components: { Foo } }\n</script>\n<script setup>\n</script>\n";
+        let result = create_linter().lint_template(source, "test.vue");
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_valid_without_script_setup() {
+        let source = "<script>\nexport default { name: 'UserCard' }\n</script>\n";
+        let result = create_linter().lint_template(source, "test.vue");
+        assert_eq!(result.warning_count, 0);
+    }
+}
@@ -0,0 +1,386 @@
+//! vue/require-explicit-emits
+//!
+//! Require events to be declared in `defineEmits`.
+//!
+//! Emitting an undeclared event makes the listener fall through to the root
+//! element as a native listener and hides the component's contract from
+//! tooling. `defineModel('name')` implicitly declares `update:name`.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <script setup>
+//! const emit = defineEmits(['submit'])
+//! emit('cancel')
+//! </script>
+//!
+//! <template>
+//!   <button @click="$emit('close')">Close</button>
+//! </template>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <script setup>
+//! const emit = defineEmits(['submit', 'cancel', 'close'])
+//! const title = defineModel('title')
+//! emit('cancel')
+//! </script>
+//!
+//! <template>
+//!   <button @click="$emit('close')">Close</button>
+//!   <input @input="$emit('update:title', $event.target.value)" />
+//! </template>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use oxc_ast::ast::{Argument, CallExpression, Expression};
+use oxc_ast_visit::{walk, Visit};
+use oxc_span::SourceType;
+use vize_carton::{camelize, FxHashSet};
+use vize_croquis::Croquis;
+use vize_relief::ast::{DirectiveNode, ElementNode, ExpressionNode, RootNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/require-explicit-emits",
+    description: "Require events to be declared in defineEmits",
+    category: RuleCategory::StronglyRecommended,
+    fixable: true,
+    default_severity: Severity::Warning,
+};
+
+/// Require explicit emits rule
+#[derive(Default)]
+pub struct RequireExplicitEmits;
+
+impl RequireExplicitEmits {
+    /// Collect declared event names, or `None` when emits cannot be checked
+    /// (no analysis, or a component that is not using `<script setup>`).
+    fn declared_events(analysis: &Croquis) -> Option<FxHashSet<String>> {
+        let macros = &analysis.macros;
+        if !analysis.bindings.is_script_setup
+            && macros.define_emits().is_none()
+            && macros.models().is_empty()
+        {
+            return None;
+        }
+
+        let mut declared: FxHashSet<String> = macros
+            .emits()
+            .iter()
+            .map(|emit| camelize(emit.name.as_str()))
+            .collect();
+        // defineModel('name') emits `update:name`
+        for model in macros.models() {
+            declared.insert(camelize(&format!("update:{}", model.name)));
+        }
+        Some(declared)
+    }
+
+    /// Build a fix that appends the event to an array `defineEmits([...])`
+    /// declaration, which starts `script_offset` bytes into the linted source.
+    fn declaration_fix(analysis: &Croquis, script_offset: u32, event: &str) -> Option<Fix> {
+        let call = analysis.macros.define_emits()?;
+        if call.type_args.is_some() {
+            return None;
+        }
+
+        // For literal arguments the recorded runtime source spans the whole call
+        let text = call.runtime_args.as_deref()?.trim_end();
+        let before_paren = text.strip_suffix(')')?.trim_end();
+        let elements = before_paren.strip_suffix(']')?;
+        let bracket = script_offset + call.end - (text.len() - elements.len()) as u32;
+
+        let new_text = if elements.trim_end().ends_with('[') {
+            format!("'{}'", event)
+        } else if elements.trim_end().ends_with(',') {
+            format!(" '{}'", event)
+        } else {
+            format!(", '{}'", event)
+        };
+
+        Some(Fix::new(
+            format!("Add '{}' to defineEmits", event),
+            TextEdit::insert(bracket, new_text),
+        ))
+    }
+
+    fn undeclared_diagnostic(
+        rule_name: &'static str,
+        analysis: &Croquis,
+        script_offset: u32,
+        event: &str,
+        start: u32,
+        end: u32,
+    ) -> LintDiagnostic {
+        let diagnostic = LintDiagnostic::warn(
+            rule_name,
+            format!(
+                "The \"{}\" event has been triggered but not declared in defineEmits",
+                event
+            ),
+            start,
+            end,
+        )
+        .with_help(format!(
+            "Declare the event: defineEmits(['{}']) or defineEmits<{{ '{}': [] }}>()",
+            event, event
+        ));
+        match Self::declaration_fix(analysis, script_offset, event) {
+            Some(fix) => diagnostic.with_fix(fix),
+            None => diagnostic,
+        }
+    }
+}
+
+impl Rule for RequireExplicitEmits {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, _root: &RootNode<'a>) {
+        let Some(analysis) = ctx.analysis() else {
+            return;
+        };
+        let Some(declared) = Self::declared_events(analysis) else {
+            return;
+        };
+
+        // emit('event') calls in script setup
+        let script_offset = ctx.script_offset();
+        let undeclared: Vec<_> = analysis
+            .macros
+            .emit_calls()
+            .iter()
+            .filter(|call| !call.is_dynamic)
+            .filter(|call| !declared.contains(&camelize(call.event_name.as_str())))
+            .map(|call| {
+                Self::undeclared_diagnostic(
+                    ctx.current_rule,
+                    analysis,
+                    script_offset,
+                    &call.event_name,
+                    script_offset + call.start,
+                    script_offset + call.end,
+                )
+            })
+            .collect();
+
+        for diagnostic in undeclared {
+            ctx.report(diagnostic);
+        }
+    }
+
+    fn check_directive<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        _element: &ElementNode<'a>,
+        directive: &DirectiveNode<'a>,
+    ) {
+        let Some(ExpressionNode::Simple(exp)) = &directive.exp else {
+            return;
+        };
+        if !exp.content.contains("$emit") {
+            return;
+        }
+        let Some(analysis) = ctx.analysis() else {
+            return;
+        };
+        let Some(declared) = Self::declared_events(analysis) else {
+            return;
+        };
+
+        // $emit('event') calls in template expressions
+        let base = exp.loc.start.offset;
+        let script_offset = ctx.script_offset();
+        let undeclared: Vec<_> = find_template_emits(exp.content.as_str())
+            .into_iter()
+            .filter(|(event, _, _)| !declared.contains(&camelize(event)))
+            .map(|(event, start, end)| {
+                Self::undeclared_diagnostic(
+                    ctx.current_rule,
+                    analysis,
+                    script_offset,
+                    event,
+                    base + start as u32,
+                    base + end as u32,
+                )
+            })
+            .collect();
+
+        for diagnostic in undeclared {
+            ctx.report(diagnostic);
+        }
+    }
+}
+
+/// Find `$emit('event')` calls with a static event name.
+///
+/// Returns the event name with the byte range of the `$emit(...)` callee and argument.
+fn find_template_emits(content: &str) -> Vec<(&str, usize, usize)> {
+    let allocator = oxc_allocator::Allocator::default();
+    let ret = oxc_parser::Parser::new(&allocator, content, SourceType::ts()).parse();
+    let mut collector = EmitCallCollector {
+        content,
+        emits: Vec::new(),
+    };
+    collector.visit_program(&ret.program);
+    collector.emits
+}
+
+/// Collects `$emit()` calls whose first argument is a static string.
+struct EmitCallCollector<'s> {
+    content: &'s str,
+    emits: Vec<(&'s str, usize, usize)>,
+}
+
+impl<'a> Visit<'a> for EmitCallCollector<'_> {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if matches!(&call.callee, Expression::Identifier(id) if id.name == "$emit") {
+            // Template literals with interpolations are dynamic
+            let name_span = match call.arguments.first() {
+                Some(Argument::StringLiteral(name)) => Some(name.span),
+                Some(Argument::TemplateLiteral(name)) if name.expressions.is_empty() => {
+                    Some(name.span)
+                }
+                _ => None,
+            };
+            if let Some(span) = name_span {
+                let (start, end) = (span.start as usize, span.end as usize);
+                self.emits.push((
+                    &self.content[start + 1..end - 1],
+                    call.span.start as usize,
+                    end,
+                ));
+            }
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::visitor::LintVisitor;
+    use vize_armature::Parser;
+    use vize_carton::Allocator;
+    use vize_croquis::Analyzer;
+
+    fn lint(script: &str, template: &str) -> Vec<LintDiagnostic> {
+        let mut analyzer = Analyzer::new();
+        analyzer.analyze_script_setup(script);
+        let analysis = analyzer.finish();
+
+        let allocator = Allocator::with_capacity(1024);
+        let (root, _) = Parser::new(allocator.as_bump(), template).parse();
+        let mut ctx = LintContext::with_analysis(&allocator, template, "test.vue", &analysis);
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(RequireExplicitEmits)];
+        let mut visitor = LintVisitor::new(&mut ctx, &rules);
+        visitor.visit_root(&root);
+        ctx.into_diagnostics()
+    }
+
+    /// Lint a whole SFC, with script offsets translated into the SFC.
+    fn lint_sfc(source: &str) -> Vec<LintDiagnostic> {
+        let script_start = source.find("<script setup>").unwrap() + "<script setup>".len();
+        let script_end = source.find("</script>").unwrap();
+        let mut analyzer = Analyzer::new();
+        analyzer.analyze_script_setup(&source[script_start..script_end]);
+        let analysis = analyzer.finish();
+
+        let allocator = Allocator::with_capacity(1024);
+        let (root, _) = Parser::new(allocator.as_bump(), source).parse();
+        let mut ctx = LintContext::with_analysis(&allocator, source, "test.vue", &analysis);
+        ctx.set_script_offset(script_start as u32);
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(RequireExplicitEmits)];
+        let mut visitor = LintVisitor::new(&mut ctx, &rules);
+        visitor.visit_root(&root);
+        ctx.into_diagnostics()
+    }
+
+    #[test]
+    fn test_meta() {
+        let rule = RequireExplicitEmits;
+        assert_eq!(rule.meta().name, "vue/require-explicit-emits");
+        assert!(rule.meta().fixable);
+    }
+
+    #[test]
+    fn test_find_template_emits() {
+        let emits = find_template_emits("$emit('close'); $emit(\"update:value\", 1); $emit(name)");
+        let names: Vec<_> = emits.iter().map(|(name, _, _)| *name).collect();
+        assert_eq!(names, vec!["close", "update:value"]);
+        assert_eq!(emits[0].1, 0);
+        assert_eq!(emits[0].2, "$emit('close'".len());
+    }
+
+    #[test]
+    fn test_valid_declared_emits() {
+        let diagnostics = lint(
+            "const emit = defineEmits(['submit', 'close'])\nemit('submit')",
+            r#"<button @click="$emit('close')">x</button>"#,
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_undeclared_template_emit() {
+        let diagnostics = lint(
+            "const emit = defineEmits(['submit'])",
+            r#"<button @click="$emit('close')">x</button>"#,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("\"close\""));
+
+        let fix = diagnostics[0].fix.as_ref().expect("fix");
+        assert_eq!(fix.edits[0].new_text, ", 'close'");
+    }
+
+    #[test]
+    fn test_invalid_undeclared_script_emit() {
+        let diagnostics = lint(
+            "const emit = defineEmits(['submit'])\nemit('cancel')",
+            "<div></div>",
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("\"cancel\""));
+    }
+
+    #[test]
+    fn test_valid_define_model_update_event() {
+        let diagnostics = lint(
+            "const emit = defineEmits(['submit'])\nconst title = defineModel('title')",
+            r#"<input @input="$emit('update:title', $event.target.value)" />"#,
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_fix_in_full_sfc() {
+        let source = "<script setup>\nconst emit = defineEmits(['submit'])\nemit('cancel')\n</script>\n\n<template>\n  <button @click=\"$emit('close')\">x</button>\n</template>\n";
+        let diagnostics = lint_sfc(source);
+        assert_eq!(diagnostics.len(), 2);
+
+        let cancel = diagnostics
+            .iter()
+            .find(|d| d.message.contains("\"cancel\""))
+            .expect("script emit");
+        assert_eq!(
+            &source[cancel.start as usize..cancel.end as usize],
+            "emit('cancel')"
+        );
+
+        for diagnostic in &diagnostics {
+            let fixed = diagnostic.fix.as_ref().expect("fix").apply(source);
+            assert!(
+                fixed.contains("defineEmits(['submit', 'cancel'])")
+                    || fixed.contains("defineEmits(['submit', 'close'])"),
+                "{}",
+                fixed
+            );
+        }
+    }
+}
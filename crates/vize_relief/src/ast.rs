@@ -168,6 +168,8 @@ pub struct RootNode<'a> {
     pub loc: SourceLocation,
     pub codegen_node: Option<CodegenNode<'a>>,
     pub transformed: bool,
+    /// Dev-mode warnings reported during transform
+    pub warnings: std::vec::Vec<crate::CompilerError>,
 }
 
 impl<'a> RootNode<'a> {
@@ -185,6 +187,7 @@ impl<'a> RootNode<'a> {
             loc: SourceLocation::STUB,
             codegen_node: None,
             transformed: false,
+            warnings: std::vec::Vec::new(),
         }
     }
 
@@ -552,6 +555,8 @@ pub struct DirectiveNode<'a> {
     pub modifiers: Vec<'a, SimpleExpressionNode<'a>>,
     /// Parsed result for v-for
     pub for_parse_result: Option<ForParseResult<'a>>,
+    /// Whether the transform synthesized this directive from a `v-model`
+    pub model_expansion: bool,
     pub loc: SourceLocation,
 }
 
@@ -564,6 +569,7 @@ impl<'a> DirectiveNode<'a> {
             arg: None,
             modifiers: Vec::new_in(allocator),
             for_parse_result: None,
+            model_expansion: false,
             loc,
         }
     }
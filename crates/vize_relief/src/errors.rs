@@ -99,6 +99,14 @@ pub enum ErrorCode {
     CacheHandlerNotSupported = 51,
     ScopeIdNotSupported = 52,
 
+    // DOM transform errors
+    VHtmlNoExpression = 53,
+    VHtmlWithChildren = 54,
+    VTextNoExpression = 55,
+    VTextWithChildren = 56,
+    VForMissingKey = 57,
+    VBindSameNameNoBinding = 58,
+
     // Extended errors
     UnhandledCodePath = 100,
     ExtendPoint = 1000,
@@ -178,6 +186,15 @@ impl ErrorCode {
             Self::CacheHandlerNotSupported => "cacheHandlers option is not supported in this mode.",
             Self::ScopeIdNotSupported => "scopeId option is not supported in this mode.",
 
+            Self::VHtmlNoExpression => "v-html is missing expression.",
+            Self::VHtmlWithChildren => "v-html will override element children.",
+            Self::VTextNoExpression => "v-text is missing expression.",
+            Self::VTextWithChildren => "v-text will override element children.",
+            Self::VForMissingKey => "Elements in v-for should have a :key for efficient updates.",
+            Self::VBindSameNameNoBinding => {
+                "v-bind same-name shorthand refers to a binding that does not exist."
+            }
+
             Self::UnhandledCodePath => "Unhandled code path.",
             Self::ExtendPoint => "Extension point.",
         }
@@ -189,7 +206,9 @@ impl ErrorCode {
 
     pub fn is_transform_error(&self) -> bool {
         let code = *self as u16;
-        code >= (Self::VIfNoExpression as u16) && code < (Self::PrefixIdNotSupported as u16)
+        (code >= (Self::VIfNoExpression as u16) && code < (Self::PrefixIdNotSupported as u16))
+            || (code >= (Self::VHtmlNoExpression as u16)
+                && code <= (Self::VBindSameNameNoBinding as u16))
     }
 }
 
@@ -280,6 +299,10 @@ mod tests {
             ErrorCode::VOnNoExpression,
             ErrorCode::VModelNoExpression,
             ErrorCode::VShowNoExpression,
+            ErrorCode::VHtmlWithChildren,
+            ErrorCode::VTextNoExpression,
+            ErrorCode::VForMissingKey,
+            ErrorCode::VBindSameNameNoBinding,
         ];
         for code in &transform_errors {
             assert!(
@@ -374,6 +397,7 @@ mod tests {
             ErrorCode::ModuleModeNotSupported,
             ErrorCode::CacheHandlerNotSupported,
             ErrorCode::ScopeIdNotSupported,
+            ErrorCode::VBindSameNameNoBinding,
             ErrorCode::UnhandledCodePath,
             ErrorCode::ExtendPoint,
         ];
@@ -76,7 +76,8 @@ impl Default for ParserOptions {
 }
 
 /// Whitespace handling strategy
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum WhitespaceStrategy {
     /// Condense whitespace (default)
     #[default]
@@ -108,6 +109,19 @@ pub struct TransformOptions {
     pub inline: bool,
     /// Whether is TypeScript
     pub is_ts: bool,
+    /// How whitespace in text nodes is handled
+    pub whitespace: WhitespaceStrategy,
+    /// Whether to preserve HTML comments (defaults to `!is_prod`)
+    pub comments: Option<bool>,
+    /// Production mode: dev-only warnings (e.g. an unkeyed `v-for`) are skipped
+    pub is_prod: bool,
+}
+
+impl TransformOptions {
+    /// Whether HTML comments are kept: stripped in production unless set explicitly
+    pub fn keep_comments(&self) -> bool {
+        self.comments.unwrap_or(!self.is_prod)
+    }
 }
 
 impl Default for TransformOptions {
@@ -123,6 +137,9 @@ impl Default for TransformOptions {
             binding_metadata: None,
             inline: false,
             is_ts: false,
+            whitespace: WhitespaceStrategy::Condense,
+            comments: None,
+            is_prod: false,
         }
     }
 }
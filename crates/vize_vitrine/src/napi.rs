@@ -91,14 +91,16 @@ pub fn compile_vapor(template: String, options: Option<CompilerOptions>) -> Resu
     let vapor_opts = VaporCompilerOptions {
         prefix_identifiers: opts.prefix_identifiers.unwrap_or(false),
         ssr: opts.ssr.unwrap_or(false),
+        source_map: opts.source_map.unwrap_or(false),
+        scope_id: opts.scope_id.clone().map(|s| s.into()),
         ..Default::default()
     };
     let result = vapor_compile(&allocator, &template, vapor_opts);
 
-    if !result.error_messages.is_empty() {
+    if !result.errors.is_empty() {
         return Err(Error::new(
             Status::GenericFailure,
-            result.error_messages.join("\n"),
+            result.error_messages().join("\n"),
         ));
     }
 
@@ -106,7 +108,7 @@ pub fn compile_vapor(template: String, options: Option<CompilerOptions>) -> Resu
         code: result.code,
         preamble: String::new(),
         ast: serde_json::json!({}),
-        map: None,
+        map: result.map.and_then(|map| serde_json::from_str(&map).ok()),
         helpers: vec![],
         templates: Some(result.templates.iter().map(|s| s.to_string()).collect()),
     })
@@ -315,6 +317,7 @@ pub fn compile_sfc(
             scoped: has_scoped,
             ..Default::default()
         },
+        ..Default::default()
     };
 
     match sfc_compile(&descriptor, compile_opts) {
@@ -511,6 +514,7 @@ pub fn compile_sfc_batch(
                 scoped: has_scoped,
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         match sfc_compile(&descriptor, compile_opts) {
@@ -650,6 +654,7 @@ pub fn compile_sfc_batch_with_results(
                 scoped: actual_has_scoped,
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         match sfc_compile(&descriptor, compile_opts) {
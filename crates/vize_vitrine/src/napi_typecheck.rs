@@ -13,12 +13,17 @@ pub struct TypeCheckOptionsNapi {
     pub strict: Option<bool>,
     pub include_virtual_ts: Option<bool>,
     pub check_props: Option<bool>,
+    pub check_with_defaults: Option<bool>,
     pub check_emits: Option<bool>,
+    pub check_define_model: Option<bool>,
     pub check_template_bindings: Option<bool>,
+    pub check_event_handler_args: Option<bool>,
+    pub check_define_slots: Option<bool>,
     pub check_reactivity: Option<bool>,
     pub check_setup_context: Option<bool>,
     pub check_invalid_exports: Option<bool>,
     pub check_fallthrough_attrs: Option<bool>,
+    pub check_component_exposes: Option<bool>,
 }
 
 /// Related location for diagnostic (NAPI)
@@ -57,12 +62,44 @@ fn apply_napi_options(opts: &TypeCheckOptionsNapi, check_opts: &mut TypeCheckOpt
     check_opts.strict = opts.strict.unwrap_or(false);
     check_opts.include_virtual_ts = opts.include_virtual_ts.unwrap_or(false);
     check_opts.check_props = opts.check_props.unwrap_or(true);
+    check_opts.check_with_defaults = opts.check_with_defaults.unwrap_or(true);
     check_opts.check_emits = opts.check_emits.unwrap_or(true);
+    check_opts.check_define_model = opts.check_define_model.unwrap_or(true);
     check_opts.check_template_bindings = opts.check_template_bindings.unwrap_or(true);
+    check_opts.check_event_handler_args = opts.check_event_handler_args.unwrap_or(true);
+    check_opts.check_define_slots = opts.check_define_slots.unwrap_or(true);
     check_opts.check_reactivity = opts.check_reactivity.unwrap_or(true);
     check_opts.check_setup_context = opts.check_setup_context.unwrap_or(true);
     check_opts.check_invalid_exports = opts.check_invalid_exports.unwrap_or(true);
     check_opts.check_fallthrough_attrs = opts.check_fallthrough_attrs.unwrap_or(true);
+    check_opts.check_component_exposes = opts.check_component_exposes.unwrap_or(false);
+}
+
+/// Keys each component exposes via defineExpose(), from a cross-file analysis of `files`.
+fn component_exposes(
+    files: &[(std::path::PathBuf, String)],
+) -> vize_carton::FxHashMap<String, Vec<String>> {
+    use vize_croquis::cross_file::{CrossFileAnalyzer, CrossFileOptions};
+    use vize_croquis::{Analyzer, AnalyzerOptions};
+
+    let mut analyzer = CrossFileAnalyzer::new(CrossFileOptions::minimal());
+    for (path, source) in files {
+        let parse_opts = vize_atelier_sfc::SfcParseOptions {
+            filename: path.to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        let Ok(descriptor) = vize_atelier_sfc::parse_sfc(source, parse_opts) else {
+            continue;
+        };
+        let mut single = Analyzer::with_options(AnalyzerOptions::full());
+        if let Some(ref script_setup) = descriptor.script_setup {
+            single.analyze_script_setup(&script_setup.content);
+        } else if let Some(ref script) = descriptor.script {
+            single.analyze_script_plain(&script.content);
+        }
+        analyzer.add_file_with_analysis(path, source, single.finish());
+    }
+    analyzer.registry().component_exposes()
 }
 
 /// Perform type checking on a Vue SFC
@@ -245,13 +282,20 @@ pub fn type_check_batch_napi(
 
     let start = Instant::now();
 
-    // Type check files in parallel using rayon
-    files.par_iter().for_each(|path| {
-        let source = match fs::read_to_string(path) {
-            Ok(s) => s,
-            Err(_) => return,
-        };
+    let sources: Vec<_> = files
+        .par_iter()
+        .filter_map(|path| Some((path.clone(), fs::read_to_string(path).ok()?)))
+        .collect();
+
+    // Template ref accesses are checked against the other components' exposes
+    let exposes = if opts.check_component_exposes.unwrap_or(false) {
+        component_exposes(&sources)
+    } else {
+        Default::default()
+    };
 
+    // Type check files in parallel using rayon
+    sources.par_iter().for_each(|(path, source)| {
         let filename = path
             .file_name()
             .and_then(|n| n.to_str())
@@ -261,8 +305,9 @@ pub fn type_check_batch_napi(
         let mut check_opts = TypeCheckOptions::new(filename);
         apply_napi_options(&opts, &mut check_opts);
         check_opts.include_virtual_ts = false; // Don't generate virtual TS for batch
+        check_opts.component_exposes = exposes.clone();
 
-        let result = type_check_sfc(&source, &check_opts);
+        let result = type_check_sfc(source, &check_opts);
 
         files_checked.fetch_add(1, Ordering::Relaxed);
         if result.error_count > 0 {
@@ -341,6 +341,7 @@ fn parse_cross_file_options(options: &JsValue) -> vize_croquis::cross_file::Cros
         component_emits: get_bool("componentEmits"),
         event_bubbling: get_bool("eventBubbling"),
         provide_inject: get_bool("provideInject"),
+        unused_provide: get_bool("unusedProvide"),
         unique_ids: get_bool("uniqueIds"),
         server_client_boundary: get_bool("serverClientBoundary"),
         error_suspense_boundary: get_bool("errorSuspenseBoundary"),
@@ -353,6 +354,7 @@ fn parse_cross_file_options(options: &JsValue) -> vize_croquis::cross_file::Cros
             .map(|v| v as usize),
         component_resolution: get_bool("componentResolution"),
         props_validation: get_bool("propsValidation"),
+        slot_validation: get_bool("slotValidation"),
     }
 }
 
@@ -401,6 +403,7 @@ fn diagnostic_kind_to_string(
         PropTypeMismatch { .. } => "props-validation",
         // Slot validation
         UndefinedSlot { .. } => "slot-validation",
+        UnknownSlotProp { .. } => "slot-validation",
         // Setup context violations
         ReactivityOutsideSetup { .. } => "setup-context",
         LifecycleOutsideSetup { .. } => "setup-context",
@@ -466,6 +469,7 @@ fn is_template_related_diagnostic(
             | MissingRequiredProp { .. }
             | PropTypeMismatch { .. }
             | UndefinedSlot { .. }
+            | UnknownSlotProp { .. }
             | UnregisteredComponent { .. }
             | UnusedFallthroughAttrs { .. }
             | MultiRootMissingAttrs
@@ -32,7 +32,7 @@ use vize_atelier_sfc::{
     compile_sfc as sfc_compile, parse_sfc, CssCompileOptions, CssTargets, ScriptCompileOptions,
     SfcCompileOptions, SfcDescriptor, SfcParseOptions, StyleCompileOptions, TemplateCompileOptions,
 };
-use vize_atelier_ssr::compile_ssr as ssr_compile;
+use vize_atelier_ssr::{compile_ssr_with_options, SsrCompilerOptions};
 use vize_atelier_vapor::{compile_vapor as vapor_compile, VaporCompilerOptions};
 
 /// Helper function to serialize values to JsValue with maps as objects
@@ -326,6 +326,7 @@ impl Compiler {
                 scoped: descriptor.styles.iter().any(|s| s.scoped),
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         // Compile the full SFC
@@ -376,7 +377,11 @@ fn compile_internal(
 
     // SSR mode - use dedicated SSR compiler
     if opts.ssr.unwrap_or(false) && !vapor {
-        let (root, errors, result) = ssr_compile(&allocator, template);
+        let ssr_opts = SsrCompilerOptions {
+            scope_id: opts.scope_id.clone().map(|s| s.into()),
+            ..Default::default()
+        };
+        let (root, errors, result) = compile_ssr_with_options(&allocator, template, ssr_opts);
 
         if !errors.is_empty() {
             return Err(format!("SSR compile errors: {:?}", errors));
@@ -403,19 +408,21 @@ fn compile_internal(
         let vapor_opts = VaporCompilerOptions {
             prefix_identifiers: opts.prefix_identifiers.unwrap_or(false),
             ssr: opts.ssr.unwrap_or(false),
+            source_map: opts.source_map.unwrap_or(false),
+            scope_id: opts.scope_id.clone().map(|s| s.into()),
             ..Default::default()
         };
         let result = vapor_compile(&allocator, template, vapor_opts);
 
-        if !result.error_messages.is_empty() {
-            return Err(result.error_messages.join("\n"));
+        if !result.errors.is_empty() {
+            return Err(result.error_messages().join("\n"));
         }
 
         return Ok(CompileResult {
             code: result.code.to_string(),
             preamble: String::new(),
             ast: serde_json::json!({}),
-            map: None,
+            map: result.map.and_then(|map| serde_json::from_str(&map).ok()),
             helpers: vec![],
             templates: Some(
                 result
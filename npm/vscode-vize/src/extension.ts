@@ -50,6 +50,10 @@ export async function activate(context: ExtensionContext): Promise<void> {
       hover: config.get<boolean>("hover.enable", true),
       codeLens: config.get<boolean>("codeLens.enable", true),
       formatting: config.get<boolean>("formatting.enable", true),
+      inlayHints: {
+        propsPrefix: config.get<boolean>("inlayHints.propsPrefix", true),
+        refTypes: config.get<boolean>("inlayHints.refTypes", true),
+      },
     },
   };
 
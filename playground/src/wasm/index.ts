@@ -192,6 +192,13 @@ export interface FormatOptions {
   attributeGroups?: string[][] | null;
   normalizeDirectiveShorthands?: boolean;
   sortBlocks?: boolean;
+  preserveBlankLines?: boolean;
+  sortImports?: boolean;
+  htmlSelfClosing?: {
+    void?: 'any' | 'always' | 'never';
+    normal?: 'any' | 'always' | 'never';
+    component?: 'any' | 'always' | 'never';
+  };
 }
 
 export interface FormatResult {
@@ -458,6 +465,7 @@ export interface CrossFileOptions {
   componentEmits?: boolean;
   eventBubbling?: boolean;
   provideInject?: boolean;
+  unusedProvide?: boolean;
   uniqueIds?: boolean;
   serverClientBoundary?: boolean;
   errorSuspenseBoundary?: boolean;
@@ -467,6 +475,7 @@ export interface CrossFileOptions {
   maxImportDepth?: number;
   componentResolution?: boolean;
   propsValidation?: boolean;
+  slotValidation?: boolean;
 }
 
 export interface CrossFileDiagnostic {